@@ -0,0 +1,92 @@
+//! IMA ADPCM encoding of 16-bit samples to 4-bit codes.
+
+/// Quantizer step size for each step index
+const STEP_TABLE: [i32; 89] = [
+    7, 8, 9, 10, 11, 12, 13, 14, 16, 17, 19, 21, 23, 25, 28, 31, 34, 37, 41, 45, 50, 55, 60, 66,
+    73, 80, 88, 97, 107, 118, 130, 143, 157, 173, 190, 209, 230, 253, 279, 307, 337, 371, 408, 449,
+    494, 544, 598, 658, 724, 796, 876, 963, 1060, 1166, 1282, 1411, 1552, 1707, 1878, 2066, 2272,
+    2499, 2749, 3024, 3327, 3660, 4026, 4428, 4871, 5358, 5894, 6484, 7132, 7845, 8630, 9493,
+    10442, 11487, 12635, 13899, 15289, 16818, 18500, 20350, 22385, 24623, 27086, 29794, 32767,
+];
+
+/// Step index adjustment for the magnitude bits of a code
+const INDEX_TABLE: [i32; 8] = [-1, -1, -1, -1, 2, 4, 6, 8];
+
+/// Initial predictor of the encoder, emitted as `<NAME>_PREDICTOR`
+pub(crate) const INITIAL_PREDICTOR: i32 = 0;
+/// Initial step index of the encoder, emitted as `<NAME>_STEP_INDEX`
+pub(crate) const INITIAL_STEP_INDEX: i32 = 0;
+
+/// Comment describing how to decode the packed array, one entry per line
+pub(crate) const DECODE_COMMENT: &[&str] = &[
+    "IMA ADPCM, two 4-bit codes per byte, low nibble first.",
+    "Decode starting with predictor = <NAME>_PREDICTOR and index = <NAME>_STEP_INDEX:",
+    "  step = step_table[index]",
+    "  diff = step >> 3",
+    "  if (code & 4) diff += step",
+    "  if (code & 2) diff += step >> 1",
+    "  if (code & 1) diff += step >> 2",
+    "  predictor += (code & 8) ? -diff : diff, clamped to int16_t",
+    "  index += index_table[code & 7], clamped to 0..88",
+    "  sample = predictor",
+    "step_table and index_table are the standard IMA ADPCM tables.",
+];
+
+/// Scale a sample of `bits_per_sample` to the 16-bit range the encoder works in
+pub(crate) fn to_i16(value: i64, bits_per_sample: u16) -> i32 {
+    if bits_per_sample <= 16 {
+        (value << (16 - bits_per_sample)) as i32
+    } else {
+        (value >> (bits_per_sample - 16)) as i32
+    }
+}
+
+/// IMA ADPCM encoder state
+#[derive(Debug)]
+pub(crate) struct ImaEncoder {
+    predictor: i32,
+    step_index: i32,
+}
+
+impl Default for ImaEncoder {
+    fn default() -> Self {
+        ImaEncoder {
+            predictor: INITIAL_PREDICTOR,
+            step_index: INITIAL_STEP_INDEX,
+        }
+    }
+}
+
+impl ImaEncoder {
+    /// Encode `sample` to a 4-bit code, updating the state as the decoder will
+    pub(crate) fn encode(&mut self, sample: i32) -> u8 {
+        let mut step = STEP_TABLE[self.step_index as usize];
+        let mut diff = sample - self.predictor;
+        let sign = if diff < 0 { 8 } else { 0 };
+        if sign != 0 {
+            diff = -diff;
+        }
+
+        // quantize with the same shifts as the decoder so the predictors track
+        let mut code = 0;
+        let mut delta = step >> 3;
+        for bit in [4, 2, 1] {
+            if diff >= step {
+                code |= bit;
+                diff -= step;
+                delta += step;
+            }
+            step >>= 1;
+        }
+
+        self.predictor = if sign != 0 {
+            self.predictor - delta
+        } else {
+            self.predictor + delta
+        }
+        .clamp(i16::MIN as i32, i16::MAX as i32);
+        self.step_index = (self.step_index + INDEX_TABLE[code as usize]).clamp(0, 88);
+
+        code | sign
+    }
+}
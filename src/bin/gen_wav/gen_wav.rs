@@ -1,36 +1,364 @@
+use clap::ValueEnum;
 use hound::{WavSpec, WavWriter};
-use std::f32::consts::PI;
+use std::f64::consts::PI;
+use std::fs::OpenOptions;
+use std::io::{Seek, SeekFrom, Write};
 use std::path::Path;
 use std::time::Duration;
 
-/// Generates simple sine wave WAV files for testing
+/// Shape of the generated tone
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Waveform {
+    #[default]
+    Sine,
+    Square,
+    Saw,
+    Triangle,
+    /// Uniform white noise from a seeded generator, the pitch is ignored
+    Noise,
+}
+
+/// Tone written by [`generate_wav`]
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Tone {
+    pub waveform: Waveform,
+    /// Frequency in Hz
+    pub pitch: f64,
+    /// Sum only the harmonics below Nyquist for square, saw and triangle so they do not alias
+    pub band_limited: bool,
+    /// Peak as a fraction of full scale
+    pub amplitude: f64,
+    /// Bias by a fraction of full scale, shrinking the tone to avoid clipping
+    pub dc_offset: f64,
+    /// Stereo balance from -1.0 (left only) to 1.0 (right only), attenuating the other side
+    pub pan: f64,
+    /// Phase of each channel ahead of the previous in degrees
+    pub channel_phase: f64,
+    /// Seed of the noise
+    pub seed: u64,
+    /// Compute the sine in f64 as the other waveforms are, rather than in f32 as the existing
+    /// fixtures were generated
+    pub f64_phase: bool,
+}
+
+impl Tone {
+    /// Value of the tone from -1.0 to 1.0 at sample `t` of `channel`, `noise` being the noise
+    /// value of the frame
+    ///
+    /// The phase is computed from `t` in f64 rather than accumulated so it does not drift over
+    /// long durations.
+    fn value(&self, t: u64, sample_rate: u32, channel: u16, noise: f64) -> f64 {
+        let shift = channel as f64 * self.channel_phase / 360.0;
+        let phase = (self.pitch * t as f64 / sample_rate as f64 + shift).rem_euclid(1.0);
+        let harmonics = if self.pitch > 0.0 {
+            (sample_rate as f64 / 2.0 / self.pitch) as u64
+        } else {
+            0
+        };
+        // Fourier series of the shape up to Nyquist, every `step` harmonic weighted
+        let additive = |step: usize, weight: fn(u64) -> f64| {
+            (1..=harmonics)
+                .step_by(step)
+                .map(|k| weight(k) * (2.0 * PI * k as f64 * phase).sin())
+                .sum::<f64>()
+                .clamp(-1.0, 1.0)
+        };
+        match self.waveform {
+            Waveform::Sine => (2.0 * PI * phase).sin(),
+            Waveform::Square if self.band_limited => additive(2, |k| 4.0 / (PI * k as f64)),
+            Waveform::Square => {
+                if phase < 0.5 {
+                    1.0
+                } else {
+                    -1.0
+                }
+            }
+            Waveform::Saw if self.band_limited => additive(1, |k| {
+                let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+                sign * 2.0 / (PI * k as f64)
+            }),
+            // rising from 0 at the start of each period, through 1 to -1 at half period
+            Waveform::Saw => 2.0 * (phase + 0.5).fract() - 1.0,
+            Waveform::Triangle if self.band_limited => additive(2, |k| {
+                let sign = if k % 4 == 1 { 1.0 } else { -1.0 };
+                sign * 8.0 / (PI * PI * (k * k) as f64)
+            }),
+            Waveform::Triangle => 4.0 * ((phase + 0.75).fract() - 0.5).abs() - 1.0,
+            Waveform::Noise => noise,
+        }
+    }
+
+    /// Sine of the tone at sample `t` of `channel` in f32, which loses precision as `t` grows
+    fn sine_f32(&self, t: u32, sample_rate: u32, channel: u16) -> f32 {
+        use std::f32::consts::PI;
+        let shift = channel as f32 * self.channel_phase as f32 / 360.0;
+        (2.0 * PI * self.pitch as f32 * t as f32 / sample_rate as f32 + 2.0 * PI * shift).sin()
+    }
+
+    /// Gain of `channel` of `channels` from the pan, which only applies to the first two of
+    /// stereo or more
+    fn gain(&self, channel: u16, channels: u16) -> f64 {
+        match (channels, channel) {
+            (0..=1, _) => 1.0,
+            (_, 0) => (1.0 - self.pan).min(1.0),
+            (_, 1) => (1.0 + self.pan).min(1.0),
+            _ => 1.0,
+        }
+    }
+}
+
+/// SplitMix64 generator so noise fixtures are reproducible
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value from -1.0 to 1.0
+    fn next_f64(&mut self) -> f64 {
+        (self.next() >> 11) as f64 / (1u64 << 52) as f64 - 1.0
+    }
+}
+
+/// Generates `tone` WAV files for testing
+///
+/// `silence` is the duration of zero samples written before and after the tone.
 pub(crate) fn generate_wav<P: AsRef<Path>>(
     path: P,
     spec: WavSpec,
-    pitch: f32,
+    tone: Tone,
     duration_secs: Duration,
+    silence: (Duration, Duration),
 ) {
     let mut writer = WavWriter::create(path, spec).unwrap();
     let amplitude = match spec.bits_per_sample {
         0..=8 => 127.0,
         9..=16 => 32_767.0,
-        17..=32 => 2_147_483_647.0,
+        17..=24 => 8_388_607.0,
+        25..=32 => 2_147_483_647.0,
         _ => panic!("Unsupported bits per sample"),
     };
 
-    let sample_count = (spec.sample_rate as u128 * duration_secs.as_micros() / 1_000_000) as u32;
+    let frames = |secs: Duration| (spec.sample_rate as u128 * secs.as_micros() / 1_000_000) as u32;
+    let mut write_sample = |value: i32| match spec.bits_per_sample {
+        0..=8 => writer.write_sample(value as i8).unwrap(),
+        9..=16 => writer.write_sample(value as i16).unwrap(),
+        17..=32 => writer.write_sample(value).unwrap(),
+        _ => unreachable!(),
+    };
+
+    let (lead_silence, trail_silence) = silence;
+    for _ in 0..frames(lead_silence) * spec.channels as u32 {
+        write_sample(0);
+    }
+
+    let mut noise = SplitMix64(tone.seed);
+    for t in 0..frames(duration_secs) {
+        let noise = noise.next_f64();
+        for channel in 0..spec.channels {
+            let gain = tone.amplitude * tone.gain(channel, spec.channels);
+            let value = if tone.waveform == Waveform::Sine && !tone.f64_phase {
+                let wave = gain as f32 * tone.sine_f32(t, spec.sample_rate, channel);
+                let dc_offset = tone.dc_offset as f32;
+                (amplitude as f32 * ((1.0 - dc_offset.abs()) * wave + dc_offset)) as i32
+            } else {
+                let wave = gain * tone.value(t as u64, spec.sample_rate, channel, noise);
+                let dc_offset = tone.dc_offset;
+                (amplitude * ((1.0 - dc_offset.abs()) * wave + dc_offset)) as i32
+            };
+            write_sample(value);
+        }
+    }
+
+    for _ in 0..frames(trail_silence) * spec.channels as u32 {
+        write_sample(0);
+    }
+
+    writer.finalize().unwrap();
+}
+
+/// Generates a 64-bit float sine WAV file, which hound cannot write, from raw bytes
+pub(crate) fn generate_wav_f64<P: AsRef<Path>>(
+    path: P,
+    channels: u16,
+    sample_rate: u32,
+    pitch: f32,
+    duration_secs: Duration,
+) {
+    let sample_count = (sample_rate as u128 * duration_secs.as_micros() / 1_000_000) as u32;
+    let block_align = channels as u32 * 8;
+    let data_len = sample_count * block_align;
+
+    let mut bytes = Vec::with_capacity(44 + data_len as usize);
+    bytes.extend_from_slice(b"RIFF");
+    bytes.extend_from_slice(&(36 + data_len).to_le_bytes());
+    bytes.extend_from_slice(b"WAVE");
+    bytes.extend_from_slice(b"fmt ");
+    bytes.extend_from_slice(&16u32.to_le_bytes());
+    // WAVE_FORMAT_IEEE_FLOAT
+    bytes.extend_from_slice(&3u16.to_le_bytes());
+    bytes.extend_from_slice(&channels.to_le_bytes());
+    bytes.extend_from_slice(&sample_rate.to_le_bytes());
+    bytes.extend_from_slice(&(sample_rate * block_align).to_le_bytes());
+    bytes.extend_from_slice(&(block_align as u16).to_le_bytes());
+    bytes.extend_from_slice(&64u16.to_le_bytes());
+    bytes.extend_from_slice(b"data");
+    bytes.extend_from_slice(&data_len.to_le_bytes());
+
     for t in 0..sample_count {
         let value =
-            (amplitude * (2.0 * PI * pitch * t as f32 / spec.sample_rate as f32).sin()) as i32;
-        for _ in 0..spec.channels {
-            match spec.bits_per_sample {
-                0..=8 => writer.write_sample(value as i8).unwrap(),
-                9..=16 => writer.write_sample(value as i16).unwrap(),
-                17..=32 => writer.write_sample(value).unwrap(),
-                _ => unreachable!(),
+            (2.0 * std::f64::consts::PI * pitch as f64 * t as f64 / sample_rate as f64).sin();
+        for _ in 0..channels {
+            bytes.extend_from_slice(&value.to_le_bytes());
+        }
+    }
+
+    std::fs::write(path, bytes).unwrap();
+}
+
+/// `KSDATAFORMAT_SUBTYPE_PCM` sub format GUID of `WAVE_FORMAT_EXTENSIBLE`
+const SUBTYPE_PCM: [u8; 16] = [
+    0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71,
+];
+
+/// Rewrites an int PCM WAV file as `WAVE_FORMAT_EXTENSIBLE` with `valid_bits` of precision in
+/// each sample container, which hound cannot write
+///
+/// The valid bits are kept from the most significant of each sample as the format specifies,
+/// the bits below them cleared. Must be called before appending other chunks.
+pub(crate) fn make_extensible<P: AsRef<Path>>(path: P, valid_bits: u16) {
+    let bytes = std::fs::read(&path).unwrap();
+    let chunk = |id: &[u8; 4]| {
+        let mut offset = 12;
+        while offset + 8 <= bytes.len() {
+            let size = u32::from_le_bytes(bytes[offset + 4..offset + 8].try_into().unwrap());
+            let start = offset + 8;
+            if &bytes[offset..offset + 4] == id {
+                return &bytes[start..start + size as usize];
             }
+            offset = start + size as usize + (size as usize & 1);
         }
+        panic!("No {:?} chunk", id);
+    };
+    let fmt = chunk(b"fmt ");
+    let mut data = chunk(b"data").to_vec();
+
+    let container_bits = u16::from_le_bytes([fmt[14], fmt[15]]);
+    assert!(valid_bits > 0 && valid_bits <= container_bits);
+    let width = container_bits.div_ceil(8) as usize;
+    let mask = !((1u32 << (container_bits - valid_bits)) - 1);
+    for sample in data.chunks_exact_mut(width) {
+        let mut word = [0u8; 4];
+        word[..width].copy_from_slice(sample);
+        let value = u32::from_le_bytes(word) & mask;
+        sample.copy_from_slice(&value.to_le_bytes()[..width]);
     }
 
-    writer.finalize().unwrap();
+    // cbSize, valid bits and an unspecified channel mask follow the PCM fields
+    let mut extensible = 0xfffeu16.to_le_bytes().to_vec();
+    extensible.extend_from_slice(&fmt[2..16]);
+    extensible.extend_from_slice(&22u16.to_le_bytes());
+    extensible.extend_from_slice(&valid_bits.to_le_bytes());
+    extensible.extend_from_slice(&0u32.to_le_bytes());
+    extensible.extend_from_slice(&SUBTYPE_PCM);
+
+    let mut wav = b"RIFF\0\0\0\0WAVE".to_vec();
+    for (id, chunk) in [(b"fmt ", &extensible), (b"data", &data)] {
+        wav.extend_from_slice(id);
+        wav.extend_from_slice(&(chunk.len() as u32).to_le_bytes());
+        wav.extend_from_slice(chunk);
+        if chunk.len() % 2 == 1 {
+            wav.push(0);
+        }
+    }
+    let riff_size = (wav.len() - 8) as u32;
+    wav[4..8].copy_from_slice(&riff_size.to_le_bytes());
+    std::fs::write(path, wav).unwrap();
+}
+
+/// Appends a RIFF chunk to the end of a WAV file and updates the RIFF size
+pub(crate) fn append_chunk<P: AsRef<Path>>(path: P, id: &[u8; 4], data: &[u8]) {
+    let mut file = OpenOptions::new().write(true).open(path).unwrap();
+
+    file.seek(SeekFrom::End(0)).unwrap();
+    file.write_all(id).unwrap();
+    file.write_all(&(data.len() as u32).to_le_bytes()).unwrap();
+    file.write_all(data).unwrap();
+    if data.len() % 2 == 1 {
+        file.write_all(&[0]).unwrap();
+    }
+
+    let riff_size = file.seek(SeekFrom::End(0)).unwrap() - 8;
+    file.seek(SeekFrom::Start(4)).unwrap();
+    file.write_all(&(riff_size as u32).to_le_bytes()).unwrap();
+}
+
+/// `cue ` chunk data with a point at each sample offset, ids counting from 1
+pub(crate) fn cue_chunk(offsets: &[u32]) -> Vec<u8> {
+    let mut data = (offsets.len() as u32).to_le_bytes().to_vec();
+    for (id, &offset) in (1u32..).zip(offsets) {
+        // id, position, data chunk id, chunk start, block start, sample offset
+        data.extend_from_slice(&id.to_le_bytes());
+        data.extend_from_slice(&offset.to_le_bytes());
+        data.extend_from_slice(b"data");
+        data.extend_from_slice(&[0; 8]);
+        data.extend_from_slice(&offset.to_le_bytes());
+    }
+    data
+}
+
+/// `LIST` chunk data of type `adtl` with a `labl` for each labelled cue point, ids counting from 1
+pub(crate) fn adtl_chunk(labels: &[Option<String>]) -> Vec<u8> {
+    let mut data = b"adtl".to_vec();
+    for (id, label) in (1u32..).zip(labels) {
+        let Some(label) = label else {
+            continue;
+        };
+        let size = 4 + label.len() + 1;
+        data.extend_from_slice(b"labl");
+        data.extend_from_slice(&(size as u32).to_le_bytes());
+        data.extend_from_slice(&id.to_le_bytes());
+        data.extend_from_slice(label.as_bytes());
+        data.push(0);
+        if size % 2 == 1 {
+            data.push(0);
+        }
+    }
+    data
+}
+
+/// `LIST` chunk data of type `INFO` with a null terminated sub chunk for each tag
+pub(crate) fn info_chunk(tags: &[([u8; 4], String)]) -> Vec<u8> {
+    let mut data = b"INFO".to_vec();
+    for (id, text) in tags {
+        let size = text.len() + 1;
+        data.extend_from_slice(id);
+        data.extend_from_slice(&(size as u32).to_le_bytes());
+        data.extend_from_slice(text.as_bytes());
+        data.push(0);
+        if size % 2 == 1 {
+            data.push(0);
+        }
+    }
+    data
+}
+
+/// `smpl` chunk data with a single forward loop between `start` and `end`
+pub(crate) fn smpl_chunk(spec: WavSpec, start: u32, end: u32) -> Vec<u8> {
+    let sample_period = 1_000_000_000 / spec.sample_rate;
+    // manufacturer, product, period, unity note, pitch fraction, smpte format/offset, loops, sampler data
+    let header = [0, 0, sample_period, 60, 0, 0, 0, 1, 0];
+    // cue id, type, start, end, fraction, play count
+    let sample_loop = [0, 0, start, end, 0, 0];
+
+    header
+        .iter()
+        .chain(sample_loop.iter())
+        .flat_map(|v| v.to_le_bytes())
+        .collect()
 }
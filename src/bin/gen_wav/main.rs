@@ -1,7 +1,10 @@
 pub mod gen_wav;
 
 use clap::{Parser, ValueEnum};
-use gen_wav::generate_wav;
+use gen_wav::{
+    adtl_chunk, append_chunk, cue_chunk, generate_wav, generate_wav_f64, info_chunk,
+    make_extensible, smpl_chunk, Tone, Waveform,
+};
 use std::path::PathBuf;
 
 #[derive(Debug, Default, Clone, Copy, ValueEnum)]
@@ -42,6 +45,24 @@ struct Args {
     #[arg(short, long, default_value = "440")]
     pitch: u32,
 
+    /// Shape of the tone
+    #[arg(short, long, value_enum, default_value_t = Waveform::Sine)]
+    waveform: Waveform,
+
+    /// Sum only the harmonics below Nyquist for square, saw and triangle so they do not alias
+    #[arg(long)]
+    band_limit: bool,
+
+    /// Seed of the noise waveform
+    #[arg(long, default_value = "0")]
+    seed: u64,
+
+    /// Compute the sine phase in f64 so it does not lose precision over long durations
+    ///
+    /// Off by default so the existing fixtures, generated in f32, stay the same.
+    #[arg(long)]
+    f64_phase: bool,
+
     /// Duration in seconds
     #[arg(short, long, default_value = "1.0")]
     duration: f32,
@@ -49,6 +70,93 @@ struct Args {
     /// Sample format
     #[arg(short = 'F', long, value_enum, default_value_t = SampleFormat::Int)]
     sample_format: SampleFormat,
+
+    /// Write `WAVE_FORMAT_EXTENSIBLE` with only N valid bits in each bits per sample container
+    #[arg(long, value_name = "N", conflicts_with = "sample_format")]
+    valid_bits: Option<u16>,
+
+    /// Peak amplitude as a fraction of full scale, 0.0 to 1.0
+    #[arg(short, long, default_value = "1.0", value_parser = parse_amplitude)]
+    amplitude: f64,
+
+    /// DC offset as a fraction of full scale, -1.0 to 1.0
+    #[arg(long, default_value = "0.0", allow_negative_numbers = true)]
+    dc_offset: f32,
+
+    /// Stereo balance from -1.0 (left only) to 1.0 (right only)
+    #[arg(long, default_value = "0.0", allow_negative_numbers = true, value_parser = parse_pan)]
+    pan: f64,
+
+    /// Phase of each channel ahead of the previous in degrees, so stereo channels differ
+    #[arg(long, default_value = "0.0", allow_negative_numbers = true)]
+    channel_phase: f64,
+
+    /// Seconds of silence before the tone
+    #[arg(long, default_value = "0.0")]
+    lead_silence: f32,
+
+    /// Seconds of silence after the tone
+    #[arg(long, default_value = "0.0")]
+    trail_silence: f32,
+
+    /// Loop start sample offset written to a `smpl` chunk
+    #[arg(long, requires = "loop_end")]
+    loop_start: Option<u32>,
+
+    /// Loop end sample offset written to a `smpl` chunk
+    #[arg(long, requires = "loop_start")]
+    loop_end: Option<u32>,
+
+    /// Cue point sample offset written to a `cue ` chunk, optionally labelled as OFFSET:LABEL
+    /// (repeatable)
+    #[arg(long, value_parser = parse_cue)]
+    cue: Vec<(u32, Option<String>)>,
+
+    /// Tag written to a `LIST` `INFO` chunk as ID=TEXT, such as INAM=Title (repeatable)
+    #[arg(long, value_parser = parse_info_tag)]
+    info_tag: Vec<([u8; 4], String)>,
+
+    /// Frame count written to a `fact` chunk, which need not match the data
+    #[arg(long, value_name = "N")]
+    fact_frames: Option<u32>,
+}
+
+/// Amplitude from 0.0 to 1.0
+fn parse_amplitude(arg: &str) -> Result<f64, String> {
+    let amplitude: f64 = arg.parse().map_err(|e| format!("{}", e))?;
+    if !(0.0..=1.0).contains(&amplitude) {
+        return Err("must be between 0.0 and 1.0".to_string());
+    }
+    Ok(amplitude)
+}
+
+/// Pan from -1.0 to 1.0
+fn parse_pan(arg: &str) -> Result<f64, String> {
+    let pan: f64 = arg.parse().map_err(|e| format!("{}", e))?;
+    if !(-1.0..=1.0).contains(&pan) {
+        return Err("must be between -1.0 and 1.0".to_string());
+    }
+    Ok(pan)
+}
+
+/// Cue point `OFFSET` or `OFFSET:LABEL`
+fn parse_cue(arg: &str) -> Result<(u32, Option<String>), String> {
+    let (offset, label) = match arg.split_once(':') {
+        Some((offset, label)) => (offset, Some(label.to_string())),
+        None => (arg, None),
+    };
+    let offset = offset.parse().map_err(|e| format!("{}", e))?;
+    Ok((offset, label))
+}
+
+/// `INFO` tag `ID=TEXT` with a four character id
+fn parse_info_tag(arg: &str) -> Result<([u8; 4], String), String> {
+    let (id, text) = arg.split_once('=').ok_or("must be ID=TEXT")?;
+    let id = id
+        .as_bytes()
+        .try_into()
+        .map_err(|_| "ID must be four characters".to_string())?;
+    Ok((id, text.to_string()))
 }
 
 fn main() {
@@ -63,5 +171,57 @@ fn main() {
 
     let duration = std::time::Duration::from_secs_f32(args.duration);
 
-    generate_wav(args.output, spec, args.pitch as f32, duration);
+    match (args.sample_format, args.bits_per_sample) {
+        (SampleFormat::Float, 64) => generate_wav_f64(
+            &args.output,
+            args.channels,
+            args.sample_rate,
+            args.pitch as f32,
+            duration,
+        ),
+        _ => generate_wav(
+            &args.output,
+            spec,
+            Tone {
+                waveform: args.waveform,
+                pitch: args.pitch as f64,
+                band_limited: args.band_limit,
+                amplitude: args.amplitude,
+                dc_offset: args.dc_offset as f64,
+                pan: args.pan,
+                channel_phase: args.channel_phase,
+                seed: args.seed,
+                f64_phase: args.f64_phase,
+            },
+            duration,
+            (
+                std::time::Duration::from_secs_f32(args.lead_silence),
+                std::time::Duration::from_secs_f32(args.trail_silence),
+            ),
+        ),
+    }
+
+    if let Some(valid_bits) = args.valid_bits {
+        make_extensible(&args.output, valid_bits);
+    }
+
+    if let (Some(start), Some(end)) = (args.loop_start, args.loop_end) {
+        append_chunk(&args.output, b"smpl", &smpl_chunk(spec, start, end));
+    }
+
+    if !args.cue.is_empty() {
+        let (offsets, labels): (Vec<_>, Vec<_>) = args.cue.into_iter().unzip();
+        append_chunk(&args.output, b"cue ", &cue_chunk(&offsets));
+        if labels.iter().any(Option::is_some) {
+            append_chunk(&args.output, b"LIST", &adtl_chunk(&labels));
+        }
+    }
+
+    if !args.info_tag.is_empty() {
+        append_chunk(&args.output, b"LIST", &info_chunk(&args.info_tag));
+    }
+
+    if let Some(frames) = args.fact_frames {
+        append_chunk(&args.output, b"fact", &frames.to_le_bytes());
+    }
 }
@@ -0,0 +1,54 @@
+//! CRC-32 of the emitted samples for verifying the data on target.
+use crate::{SampleEmitter, WavMetadata};
+
+/// CRC-32 (IEEE 802.3) polynomial, reflected
+const CRC32_POLY: u32 = 0xedb8_8320;
+
+/// Update `crc` with `byte` using the bitwise CRC-32 algorithm
+///
+/// Bitwise rather than table driven so the C harness can use the same short loop.
+pub(crate) fn crc32_update(mut crc: u32, byte: u8) -> u32 {
+    crc ^= byte as u32;
+    for _ in 0..8 {
+        crc = (crc >> 1) ^ (CRC32_POLY & (crc & 1).wrapping_neg());
+    }
+    crc
+}
+
+/// Computes the CRC-32 of the samples as little endian bytes of the array type width
+#[derive(Debug)]
+pub struct Crc32Emitter {
+    crc: u32,
+    width: usize,
+}
+
+impl Default for Crc32Emitter {
+    fn default() -> Self {
+        Crc32Emitter {
+            crc: 0xffff_ffff,
+            width: 0,
+        }
+    }
+}
+
+impl Crc32Emitter {
+    /// CRC-32 of the samples emitted so far
+    pub fn crc32(&self) -> u32 {
+        !self.crc
+    }
+}
+
+impl SampleEmitter for Crc32Emitter {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.crc = 0xffff_ffff;
+        self.width = meta.element_width();
+    }
+
+    fn sample(&mut self, value: i64) {
+        for byte in &(value as u64).to_le_bytes()[..self.width] {
+            self.crc = crc32_update(self.crc, *byte);
+        }
+    }
+
+    fn end(&mut self) {}
+}
@@ -0,0 +1,47 @@
+//! Check that the generated source builds by compiling it with the system compiler.
+use crate::{Lang, WavToCError};
+use std::path::Path;
+use std::process::Command;
+
+/// Compile `source_path` of `lang` to a discarded object with `$CC`, or `$CXX` for C++, failing
+/// with the compiler errors if it does not build
+///
+/// C is compiled with `stdint.h` and `stddef.h` included as the output expects.
+pub(crate) fn check(source_path: &Path, lang: Lang) -> Result<(), WavToCError> {
+    let (variable, default, lang_args): (_, _, &[&str]) = match lang {
+        Lang::Cpp => ("CXX", "c++", &["-x", "c++", "-std=c++17"]),
+        _ => (
+            "CC",
+            "cc",
+            &["-x", "c", "-include", "stdint.h", "-include", "stddef.h"],
+        ),
+    };
+    let compiler = std::env::var(variable).unwrap_or_else(|_| default.to_string());
+    // the variable may hold a launcher and flags, such as `ccache gcc -Wall`
+    let mut words = compiler.split_whitespace();
+    let Some(program) = words.next() else {
+        return Err(WavToCError::VerifyFailed(format!("{} is empty", variable)));
+    };
+
+    let object_path = source_path.with_extension("o");
+    let output = Command::new(program)
+        .args(words)
+        .args(lang_args)
+        .arg("-c")
+        .arg(source_path)
+        .arg("-o")
+        .arg(&object_path)
+        .output()
+        .map_err(|e| WavToCError::VerifyFailed(format!("could not run '{}': {}", compiler, e)))?;
+    let _ = std::fs::remove_file(&object_path);
+
+    if !output.status.success() {
+        return Err(WavToCError::VerifyFailed(format!(
+            "'{}' failed to compile the output:\n{}",
+            compiler,
+            String::from_utf8_lossy(&output.stderr).trim_end()
+        )));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,122 @@
+//! C++17 output of the array as a `constexpr std::array` in a namespace of the array name.
+use crate::{duration_ms, sanitize_c_identifier, WavMetadata, WavToCOptions};
+
+/// Includes, namespace, constants and the opening of the `samples` array definition
+///
+/// The definitions are `inline constexpr` so the file can be included from many sources.
+pub(crate) fn preamble(meta: &WavMetadata, options: &WavToCOptions) -> String {
+    let mut code = format!(
+        "#include <array>\n#include <cstddef>\n#include <cstdint>\n\nnamespace {} {{\n\n",
+        meta.name
+    );
+
+    if !options.no_define {
+        code.push_str(&format!(
+            "inline constexpr std::size_t SAMPLE_NO = {};\n",
+            meta.sample_count
+        ));
+        if meta.frame_channels > 1 {
+            code.push_str(&format!(
+                "inline constexpr std::size_t FRAME_NO = {};\n",
+                meta.frame_count()
+            ));
+        }
+        code.push_str(&format!(
+            "inline constexpr std::uint32_t SAMPLE_RATE = {};\n",
+            meta.sample_rate
+        ));
+        if let Some(version) = options.asset_version {
+            code.push_str(&format!(
+                "inline constexpr std::uint32_t VERSION = {};\n",
+                version
+            ));
+        }
+        if let Some((start, end)) = meta.loop_points {
+            code.push_str(&format!(
+                "inline constexpr std::size_t LOOP_START = {};\n\
+                inline constexpr std::size_t LOOP_END = {};\n",
+                start, end
+            ));
+        }
+        code.push_str(&cue_constants(meta));
+        if options.duration_ms {
+            code.push_str(&format!(
+                "inline constexpr std::uint64_t DURATION_MS = {};\n",
+                duration_ms(meta)
+            ));
+        }
+        if let Some((hz, _)) = meta.pitch {
+            code.push_str(&format!("inline constexpr double PITCH_HZ = {:.1};\n", hz));
+        }
+        if let Some(frame) = meta.onset {
+            code.push_str(&format!(
+                "inline constexpr std::size_t ONSET = {};\n",
+                frame
+            ));
+        }
+        code.push('\n');
+    }
+
+    let qualifier = if options.mutable {
+        "inline"
+    } else {
+        "inline constexpr"
+    };
+    let array_type = if meta.frame_channels > 1 {
+        format!(
+            "std::array<std::array<{}, {}>, {}>",
+            meta.c_type,
+            meta.frame_channels,
+            meta.frame_count()
+        )
+    } else {
+        format!("std::array<{}, {}>", meta.c_type, meta.sample_count)
+    };
+    // the outer braces initialize the std::array aggregate, the inner its C array member
+    code.push_str(&format!(
+        "{} {} samples{} = {{{{",
+        qualifier,
+        array_type,
+        attributes(options)
+    ));
+
+    code
+}
+
+/// `CUE_COUNT` and a `CUE_<i>` constant per cue point, aliased by any label as the C defines are
+fn cue_constants(meta: &WavMetadata) -> String {
+    if meta.cue_points.is_empty() {
+        return String::new();
+    }
+    let mut code = format!(
+        "inline constexpr std::size_t CUE_COUNT = {};\n",
+        meta.cue_points.len()
+    );
+    for (index, (offset, label)) in meta.cue_points.iter().enumerate() {
+        code.push_str(&format!(
+            "inline constexpr std::size_t CUE_{} = {};\n",
+            index, offset
+        ));
+        if let Some(label) = label.as_deref().filter(|label| !label.is_empty()) {
+            code.push_str(&format!(
+                "inline constexpr std::size_t CUE_{} = CUE_{};\n",
+                sanitize_c_identifier(label).to_uppercase(),
+                index
+            ));
+        }
+    }
+    code
+}
+
+/// Alignment attribute following the array declarator, as for C
+fn attributes(options: &WavToCOptions) -> String {
+    options
+        .align
+        .map(|align| format!(" __attribute__((aligned({})))", align))
+        .unwrap_or_default()
+}
+
+/// Close of the `samples` array and namespace opened by [`preamble`]
+pub(crate) fn close(meta: &WavMetadata) -> String {
+    format!("\n}}}};\n\n}} // namespace {}", meta.name)
+}
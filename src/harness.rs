@@ -0,0 +1,53 @@
+//! Generated C test harness that checks the array contents on the target.
+use crate::{WavMetadata, WavToCError};
+use std::path::Path;
+
+/// Write a C file with a `main` that includes `include_name`, prints the CRC-32 of the array
+/// and returns non-zero if it does not match `crc`
+pub(crate) fn write_harness(
+    harness_path: &Path,
+    include_name: &str,
+    meta: &WavMetadata,
+    crc: u32,
+) -> Result<(), WavToCError> {
+    let upper_name = meta.name.to_uppercase();
+    let harness = format!(
+        "/*\n\
+        * Test harness generated by {pkg} v{version} for {include}\n\
+        *\n\
+        * Build and run from the same directory: cc -o harness {harness} && ./harness\n\
+        */\n\n\
+        #include <stdint.h>\n\
+        #include <stddef.h>\n\
+        #include <stdio.h>\n\n\
+        #include \"{include}\"\n\n\
+        #define {upper}_EXPECTED_CRC32 0x{crc:08x}u\n\
+        /* from the array rather than _SAMPLE_NO which may be omitted with --no-define */\n\
+        #define {upper}_HARNESS_LEN (sizeof({name}) / sizeof({name}[0]))\n\n\
+        int main(void) {{\n\
+        \tuint32_t crc = 0xffffffffu;\n\
+        \tfor (size_t i = 0; i < {upper}_HARNESS_LEN; i++) {{\n\
+        \t\tuint64_t value = (uint64_t){name}[i];\n\
+        \t\tfor (size_t b = 0; b < sizeof({name}[0]); b++) {{\n\
+        \t\t\tcrc ^= (value >> (8 * b)) & 0xffu;\n\
+        \t\t\tfor (int k = 0; k < 8; k++) {{\n\
+        \t\t\t\tcrc = (crc >> 1) ^ (0xedb88320u & (0u - (crc & 1u)));\n\
+        \t\t\t}}\n\
+        \t\t}}\n\
+        \t}}\n\
+        \tcrc = ~crc;\n\n\
+        \tprintf(\"%u samples, crc32 0x%08x\\n\", (unsigned){upper}_HARNESS_LEN, (unsigned)crc);\n\
+        \treturn crc == {upper}_EXPECTED_CRC32 ? 0 : 1;\n\
+        }}\n",
+        pkg = env!("CARGO_PKG_NAME"),
+        version = env!("CARGO_PKG_VERSION"),
+        include = include_name,
+        harness = harness_path.file_name().unwrap().to_string_lossy(),
+        upper = upper_name,
+        name = meta.name,
+        crc = crc,
+    );
+
+    std::fs::write(harness_path, harness)?;
+    Ok(())
+}
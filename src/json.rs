@@ -0,0 +1,66 @@
+//! JSON output of the metadata and samples for tooling and test harnesses.
+use crate::{SampleEmitter, WavMetadata};
+use std::io::Write;
+
+/// Streams a JSON object with the file information and emitted samples to a writer
+///
+/// `channels` is the number of interleaved channels in `samples`, 1 unless stereo is kept as
+/// frames.
+pub struct JsonEmitter<W: Write> {
+    writer: W,
+    error: Option<std::io::Error>,
+    index: usize,
+}
+
+impl<W: Write> JsonEmitter<W> {
+    pub fn new(writer: W) -> Self {
+        JsonEmitter {
+            writer,
+            error: None,
+            index: 0,
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        if self.error.is_none() {
+            if let Err(e) = self.writer.write_all(text.as_bytes()) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// Flush the writer and return it, or the first error that occurred while writing
+    pub fn finish(mut self) -> std::io::Result<W> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> SampleEmitter for JsonEmitter<W> {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.index = 0;
+        // the name is a C identifier so needs no escaping
+        let head = format!(
+            "{{\n  \"name\": \"{}\",\n  \"sample_rate\": {},\n  \"channels\": {},\n  \"bits\": {},\n  \"samples\": [",
+            meta.name, meta.sample_rate, meta.frame_channels, meta.bits_per_sample
+        );
+        self.write(&head);
+    }
+
+    fn sample(&mut self, value: i64) {
+        let token = if self.index == 0 {
+            value.to_string()
+        } else {
+            format!(", {}", value)
+        };
+        self.index += 1;
+        self.write(&token);
+    }
+
+    fn end(&mut self) {
+        self.write("]\n}");
+    }
+}
@@ -0,0 +1,3123 @@
+//! Convert a .wav file to a C array for use in embedded systems.
+//!
+//! The conversion decodes the WAV file and drives a [`SampleEmitter`] with the
+//! resulting samples. [`CEmitter`] generates the C source used by the `wav2c`
+//! binary but consumers can implement their own emitter to write to other
+//! sinks or formats.
+use clap::ValueEnum;
+use log::{debug, info, warn};
+use std::cell::Cell;
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter, Cursor, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
+mod adpcm;
+mod checksum;
+mod compile;
+mod cpp;
+mod harness;
+mod json;
+mod pack;
+mod pitch;
+mod post_process;
+mod preview;
+mod progress;
+mod riff;
+mod split;
+mod stats;
+mod template;
+mod transform;
+mod verify;
+#[cfg(feature = "waveform-png")]
+mod waveform_png;
+
+pub use checksum::Crc32Emitter;
+pub use json::JsonEmitter;
+pub use split::part_path;
+pub use stats::StatsEmitter;
+pub use transform::fit_to_type;
+
+/// Maximum number of samples to process to prevent massive arrays
+pub const MAX_SAMPLES: usize = 220_000;
+/// Samples per line in the output C array for formatting
+const SAMPLES_PER_LINE: usize = 8;
+/// Fraction of the input samples at full scale above which the input is likely clipped
+const INPUT_CLIP_FRACTION: f64 = 0.001;
+
+/// Error type for the application
+///
+/// Each variant has its own process exit code, noted on the variant, through the
+/// [`ExitCode`](std::process::ExitCode) conversion.
+pub enum WavToCError {
+    /// std:io error, exit code 4
+    IoError(std::io::Error),
+    /// Hound wav decoding error, exit code 5
+    HoundError(hound::Error),
+    /// Incompatible input file, exit code 2
+    InvalidInput(String),
+    /// Output file already exists, exit code 3
+    OutputExists(PathBuf),
+    /// Emitted samples do not match the reference WAV, exit code 6
+    VerifyFailed(String),
+    /// Post-process command could not be run or failed, exit code 7
+    PostProcessFailed(String),
+}
+
+/// Format for the output array values
+#[derive(Debug, Default, Clone, ValueEnum)]
+pub enum ArrayFormat {
+    #[default]
+    /// Signed integers in base 10
+    Base10,
+    /// Signed integers in hexadecimal
+    Base16,
+}
+
+impl fmt::Display for WavToCError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WavToCError::IoError(e) => write!(f, "IO error: {}", e),
+            WavToCError::HoundError(e) => write!(f, "Hound error: {}", e),
+            WavToCError::InvalidInput(e) => write!(f, "Invalid input: {}", e),
+            WavToCError::OutputExists(p) => {
+                write!(f, "Output file already exists: {}", p.display())
+            }
+            WavToCError::VerifyFailed(e) => write!(f, "Verification failed: {}", e),
+            WavToCError::PostProcessFailed(e) => write!(f, "Post-process failed: {}", e),
+        }
+    }
+}
+
+impl fmt::Debug for WavToCError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self)
+    }
+}
+
+impl Error for WavToCError {}
+
+impl From<&WavToCError> for std::process::ExitCode {
+    fn from(err: &WavToCError) -> Self {
+        std::process::ExitCode::from(match err {
+            WavToCError::InvalidInput(_) => 2,
+            WavToCError::OutputExists(_) => 3,
+            WavToCError::IoError(_) => 4,
+            WavToCError::HoundError(_) => 5,
+            WavToCError::VerifyFailed(_) => 6,
+            WavToCError::PostProcessFailed(_) => 7,
+        })
+    }
+}
+
+impl From<std::io::Error> for WavToCError {
+    fn from(err: std::io::Error) -> Self {
+        WavToCError::IoError(err)
+    }
+}
+
+impl From<hound::Error> for WavToCError {
+    fn from(err: hound::Error) -> Self {
+        WavToCError::HoundError(err)
+    }
+}
+
+/// Byte order of samples serialized to a byte array
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ByteOrder {
+    /// Little endian, least significant byte first
+    Le,
+    /// Big endian, most significant byte first
+    Be,
+}
+
+/// Position in the input, either in seconds or as a frame offset
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ClipPosition {
+    /// Seconds from the start, rounded to the nearest frame
+    Seconds(f64),
+    /// Frame offset from the start
+    Sample(usize),
+}
+
+impl ClipPosition {
+    /// Frame offset at `sample_rate`, `None` for a negative or non-finite time
+    pub fn frame(&self, sample_rate: u32) -> Option<usize> {
+        match *self {
+            ClipPosition::Seconds(secs) if secs.is_finite() && secs >= 0.0 => {
+                Some((secs * sample_rate as f64).round() as usize)
+            }
+            ClipPosition::Seconds(_) => None,
+            ClipPosition::Sample(frame) => Some(frame),
+        }
+    }
+}
+
+/// Standard line or part of a line of the comment
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum CommentField {
+    /// `Generated by wav2c`
+    Tool,
+    /// Version of the tool
+    Version,
+    /// `from <file>` source WAV file name
+    Source,
+    /// Sample rate, channels and bits per sample of the source
+    Spec,
+    /// Tags of the `LIST` `INFO` chunk, such as title and artist
+    Info,
+    /// Repository URL
+    Repo,
+}
+
+/// How the sample count and other lengths are defined
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LengthStyle {
+    /// `const size_t NAME_SAMPLE_NO = N;` variable
+    #[default]
+    Const,
+    /// `#define NAME_SAMPLE_NO N` preprocessor macro
+    Macro,
+    /// `#define NAME_SAMPLE_NO (sizeof(name) / sizeof(name[0]))` macro derived from the array,
+    /// a plain number in the header where the array is incomplete
+    Sizeof,
+}
+
+/// Language or format of the output
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Lang {
+    /// C source with the array definition
+    #[default]
+    C,
+    /// JSON object with the file information and samples
+    Json,
+    /// C++17 `constexpr std::array` named `samples` in a namespace of the array name
+    Cpp,
+    /// Brace-enclosed initializer body only, to `#include` inside a declaration
+    Inc,
+}
+
+/// How stereo and multichannel frames are merged to mono
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum MonoMix {
+    /// Mean of all channels
+    #[default]
+    Average,
+    /// Left (first) channel only
+    Left,
+    /// Right (second) channel only
+    Right,
+    /// Sum of all channels, saturating at the sample type bounds
+    SumClip,
+}
+
+/// Options for the conversion
+#[derive(Debug, Default, Clone)]
+pub struct WavToCOptions<'a> {
+    /// Error if the decoded sample count exceeds this
+    pub max_samples: Option<usize>,
+    /// Keep the first `max_samples` samples with a warning rather than erroring
+    pub truncate: bool,
+    /// Error if the array size in bytes, after all transforms, exceeds this
+    pub max_bytes: Option<usize>,
+    /// Start of the range of the input to convert
+    pub start: Option<ClipPosition>,
+    /// End of the range of the input to convert, exclusive
+    pub end: Option<ClipPosition>,
+    /// Do not include a comment with the file information
+    pub no_comment: bool,
+    /// Separate the prefix, defines, array and suffix with `/* ===== NAME ===== */` banners
+    pub banners: bool,
+    /// Omit the enclosing braces of [`Lang::Inc`] output, leaving only the values
+    pub no_braces: bool,
+    /// Array name built from `{stem}`, `{rate}`, `{channels}` and `{bits}` placeholders, the
+    /// stem being the array name passed to the conversion
+    pub name_template: Option<&'a str>,
+    /// Standard fields of the comment to include, all if `None`
+    pub comment_fields: Option<Vec<CommentField>>,
+    /// Number format for the output array
+    pub format: ArrayFormat,
+    /// Language or format of the output
+    pub lang: Lang,
+    /// Template with `{{field}}` placeholders written instead of the `lang` output
+    pub template: Option<&'a str>,
+    /// String to prepend to the output before the array
+    pub prefix: Option<&'a str>,
+    /// Write a header file with extern declarations alongside the output
+    pub header: bool,
+    /// Define `<NAME>_BANK_ENTRY` as a `{ .name, .rate, .len, .data }` designated initializer
+    /// after the array, for building a table of sound bank entries
+    pub bank_entry: bool,
+    /// Write everything to one include guarded header with `static` definitions, so it can be
+    /// included from many translation units of a header-only library
+    pub single_header: bool,
+    /// Wrap the header declarations in `extern "C"` when included from C++
+    pub extern_c: bool,
+    /// Place the array in AVR PROGMEM and define a `<NAME>_GET(i)` accessor
+    pub progmem_accessor: bool,
+    /// Align the array to this many bytes, a power of two, with a GCC/Clang attribute
+    pub align: Option<usize>,
+    /// Error if the array name is not a valid C identifier rather than sanitizing it
+    pub no_sanitize: bool,
+    /// Resample to this rate in Hz by linear interpolation, after the clip range
+    pub resample: Option<u32>,
+    /// Skip the low-pass filter before downsampling, keeping any aliasing
+    pub no_antialias: bool,
+    /// High-pass filter the samples at this cutoff in Hz to remove rumble, before the gain
+    pub highpass_hz: Option<f64>,
+    /// Scale samples by this factor, saturating at the sample type bounds
+    pub gain: Option<f64>,
+    /// Soft limit the samples after the gain to this ceiling in dBFS, zero or negative
+    pub limit_db: Option<f64>,
+    /// Hard clamp the emitted samples to plus or minus this value, after every other transform
+    pub clamp_amplitude: Option<u32>,
+    /// Requantize the samples to this many bits, at most the source depth, with rounding
+    pub bit_depth: Option<u16>,
+    /// Left shift the samples up to this many bits, more than the source depth, for DACs that
+    /// want wider samples
+    pub upscale: Option<u16>,
+    /// Fill the bits vacated by `upscale` by repeating the sample bits rather than with zeros
+    pub upscale_replicate: bool,
+    /// Add TPDF dither when reducing the bit depth
+    pub dither: bool,
+    /// Seed of the dither noise, the output is the same for the same seed
+    pub dither_seed: u64,
+    /// Low source bits spanned by the dither noise, by default those dropped by `bit_depth`
+    pub dither_depth: Option<u16>,
+    /// Subtract the mean of the samples to remove a constant DC bias
+    pub remove_dc: bool,
+    /// Keep stereo input as an array of `{l, r}` frame structs rather than merging to mono
+    pub stereo_frames: bool,
+    /// Scale the side (L-R) of stereo frames by this factor before the downmix
+    pub stereo_width: Option<f64>,
+    /// Convert stereo to mid (L+R)/2 and side (L-R)/2, emitted as `<name>_mid` and
+    /// `<name>_side` arrays
+    pub stereo_to_ms: bool,
+    /// How stereo and multichannel frames are merged to mono when not kept as frames
+    pub mono_mix: MonoMix,
+    /// Emit the frames in reverse order
+    pub reverse: bool,
+    /// Linear fade in from silence over this many milliseconds
+    pub fade_in_ms: Option<u32>,
+    /// Linear fade out to silence over this many milliseconds
+    pub fade_out_ms: Option<u32>,
+    /// Rotate the frames so the array starts at the maximum amplitude sample
+    pub rotate_to_peak: bool,
+    /// Tile the clip this many times, after the other buffered transforms
+    pub repeat: Option<usize>,
+    /// Wrap array lines by rendered byte width rather than sample count
+    pub line_bytes: Option<usize>,
+    /// Pad a short final array line with spaces to the width of the longest line
+    pub last_line_pad: bool,
+    /// Omit the comma after the last array element
+    pub no_trailing_comma: bool,
+    /// Right align each value to at least this many characters so columns line up
+    pub pad_width: Option<usize>,
+    /// Indentation of the array lines, a tab if `None`
+    pub indent: Option<&'a str>,
+    /// Write a C test harness with a `main` that checks the array CRC-32 alongside the output
+    pub test_harness: bool,
+    /// Define `<NAME>_VERSION` so firmware can reject stale assets
+    pub asset_version: Option<u32>,
+    /// Define `<NAME>_CRC32` of the samples in the header, known once they are all emitted
+    pub checksum: bool,
+    /// Emit a `<name>_parity` array with a byte per block of this many samples, the XOR of
+    /// the little endian bytes of the samples, for detecting bit flips at runtime
+    pub parity: Option<usize>,
+    /// C type of the array elements instead of the type chosen for the bit depth
+    pub c_type: Option<&'a str>,
+    /// Omit the sample count and metadata defines
+    pub no_define: bool,
+    /// Define `<NAME>_DURATION_MS` from the sample count and rate
+    pub duration_ms: bool,
+    /// Estimate the fundamental frequency for the comment and a `<NAME>_PITCH_HZ` define
+    pub detect_pitch: bool,
+    /// Define `<NAME>_ONSET` as the first frame with a sample above this level in dBFS
+    pub mark_onset: Option<f64>,
+    /// Define the sample count as a const variable or a macro
+    pub length_style: LengthStyle,
+    /// Drop the `const` qualifier so the array is writable at runtime
+    pub mutable: bool,
+    /// Command line recorded in the comment
+    pub command: Option<&'a str>,
+    /// String to append to the output after the array
+    pub suffix: Option<&'a str>,
+    /// Serialize each sample to bytes in this order and emit a `uint8_t` array
+    pub bytes: Option<ByteOrder>,
+    /// Emit the samples as offset binary in the unsigned type of the same width, the most
+    /// negative sample being zero, for R-2R DACs
+    pub offset_binary: bool,
+    /// Reverse the byte order of each value of the typed array, for peripherals reading
+    /// samples of the other endianness from memory
+    pub byte_swap: bool,
+    /// Only the comma separated values, without comment, defines, declaration or braces, for
+    /// pasting into an existing initializer
+    pub raw_bytes_only: bool,
+    /// Additional lines at the end of the comment
+    pub comment_extra: Vec<&'a str>,
+    /// Zero pad the samples to the next power of two and define `<NAME>_FFT_SIZE`
+    pub fft_ready: bool,
+    /// Zero pad the frames to a multiple of this and define `<NAME>_BLOCK_SIZE`/`_BLOCK_COUNT`
+    pub block_size: Option<usize>,
+    /// Print peak, RMS, DC offset and clipping statistics to stderr
+    pub stats: bool,
+    /// Draw a progress bar on stderr while converting large inputs
+    pub progress: bool,
+    /// Plot the emitted waveform on stderr in this many columns
+    pub preview: Option<usize>,
+    /// Render the emitted waveform to a PNG at this path
+    #[cfg(feature = "waveform-png")]
+    pub waveform_png: Option<&'a Path>,
+    /// Convert without writing any output, for the stats, preview and verification
+    pub dry_run: bool,
+    /// Skip the warning for an input with many samples at full scale
+    pub no_clip_check: bool,
+    /// Log the time spent decoding, transforming, formatting and writing at debug level
+    pub profile: bool,
+    /// Shell command the output and header are piped through before being written
+    pub post_process: Option<&'a str>,
+    /// Fail unless the emitted samples match this WAV decoded without transforms
+    pub verify_against: Option<&'a Path>,
+    /// Largest difference from the reference samples allowed by `verify_against`
+    pub verify_tolerance: u64,
+    /// Encode the samples as IMA ADPCM and emit a packed `uint8_t` array
+    pub adpcm: bool,
+    /// Pack the low this many bits of each sample tightly into a `uint8_t` array, least
+    /// significant bit first, for depths such as 12 that are not whole bytes
+    pub pack_bits: Option<u32>,
+    /// Write the array as parts of this many samples in `<stem>_part<i>.c` files with a
+    /// master header declaring them, rather than one output file
+    pub split_files: Option<usize>,
+    /// Compile the output with `$CC`, or `$CXX` for C++, before it is written and fail if it
+    /// does not build
+    pub verify_compile: bool,
+}
+
+/// Information about the decoded audio passed to [`SampleEmitter::begin`]
+#[derive(Debug, Clone)]
+pub struct WavMetadata {
+    /// Sanitized array name
+    pub name: String,
+    /// File name of the source WAV
+    pub source: String,
+    /// Sample rate in Hz
+    pub sample_rate: u32,
+    /// Channels in the source file
+    pub channels: u16,
+    /// Bits per sample of the decoded samples, 16 for 64-bit float input
+    pub bits_per_sample: u16,
+    /// Number of samples that will be emitted
+    pub sample_count: usize,
+    /// Interleaved samples per emitted frame, 2 when stereo is kept as frames, otherwise 1
+    pub frame_channels: u16,
+    /// C type of the array elements
+    pub c_type: String,
+    /// C type of the sample count
+    pub size_type: String,
+    /// Start and end sample offsets of the first loop in the `smpl` chunk
+    pub loop_points: Option<(u32, u32)>,
+    /// Sample offsets of the `cue ` points in order, with any `labl` name
+    pub cue_points: Vec<(u32, Option<String>)>,
+    /// Detected fundamental frequency in Hz, 0 if unknown, and confidence from 0 to 1
+    pub pitch: Option<(f64, f64)>,
+    /// First frame with a sample above the onset threshold, the frame count if none is
+    pub onset: Option<usize>,
+    /// Names and text of the `LIST` `INFO` tags, such as `("Title", ...)`
+    pub info_tags: Vec<(String, String)>,
+}
+
+impl WavMetadata {
+    /// Number of frames that will be emitted
+    pub fn frame_count(&self) -> usize {
+        self.sample_count / self.frame_channels.max(1) as usize
+    }
+
+    /// Bytes per sample of the array type
+    pub fn sample_width(&self) -> usize {
+        match self.bits_per_sample {
+            0..=8 => 1,
+            9..=16 => 2,
+            _ => 4,
+        }
+    }
+
+    /// Bytes of each element of the array type, the sample width if `c_type` is of unknown width
+    pub fn element_width(&self) -> usize {
+        c_type_bits(&self.c_type).map_or(self.sample_width(), |bits| bits as usize / 8)
+    }
+
+    /// Human readable description of the source file spec
+    pub fn file_spec(&self) -> String {
+        format!(
+            "Sample rate: {} Hz, Channels: {}, Bits per sample: {}",
+            self.sample_rate, self.channels, self.bits_per_sample
+        )
+    }
+}
+
+/// Outcome of a successful conversion
+#[derive(Debug, Clone)]
+pub struct Conversion {
+    /// Metadata passed to the emitter
+    pub meta: WavMetadata,
+    /// Non-fatal notices such as channels merged to mono or clipped gain, in the order they
+    /// were logged with `warn!`
+    pub warnings: Vec<String>,
+}
+
+/// Warnings collected for the [`Conversion`], logged as they are pushed unless `quiet`
+struct Warnings {
+    list: Vec<String>,
+    quiet: bool,
+}
+
+/// Log `warning` and collect it for the [`Conversion`]
+fn push_warning(warnings: &mut Warnings, warning: String) {
+    if !warnings.quiet {
+        warn!("{}", warning);
+    }
+    warnings.list.push(warning);
+}
+
+/// Receives the decoded samples during a conversion
+///
+/// `begin` is called once before any samples, `sample` for each sample in order and `end`
+/// once all samples have been emitted.
+pub trait SampleEmitter {
+    /// Start of the conversion with the decoded file information
+    fn begin(&mut self, meta: &WavMetadata);
+    /// Next sample value
+    fn sample(&mut self, value: i64);
+    /// All samples have been emitted
+    fn end(&mut self);
+}
+
+impl<E: SampleEmitter + ?Sized> SampleEmitter for &mut E {
+    fn begin(&mut self, meta: &WavMetadata) {
+        (**self).begin(meta);
+    }
+
+    fn sample(&mut self, value: i64) {
+        (**self).sample(value);
+    }
+
+    fn end(&mut self) {
+        (**self).end();
+    }
+}
+
+/// Drive the emitter only if there is one
+impl<E: SampleEmitter> SampleEmitter for Option<E> {
+    fn begin(&mut self, meta: &WavMetadata) {
+        if let Some(emitter) = self {
+            emitter.begin(meta);
+        }
+    }
+
+    fn sample(&mut self, value: i64) {
+        if let Some(emitter) = self {
+            emitter.sample(value);
+        }
+    }
+
+    fn end(&mut self) {
+        if let Some(emitter) = self {
+            emitter.end();
+        }
+    }
+}
+
+/// Drive two emitters from the same conversion
+impl<A: SampleEmitter, B: SampleEmitter> SampleEmitter for (A, B) {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.0.begin(meta);
+        self.1.begin(meta);
+    }
+
+    fn sample(&mut self, value: i64) {
+        self.0.sample(value);
+        self.1.sample(value);
+    }
+
+    fn end(&mut self) {
+        self.0.end();
+        self.1.end();
+    }
+}
+
+/// Emits the samples as C source with a sample count and array definition
+///
+/// Output is written to `writer` a line at a time. Since [`SampleEmitter`] methods cannot
+/// fail, the first write error is held and returned by [`CEmitter::finish`].
+pub struct CEmitter<'a, W: Write> {
+    options: &'a WavToCOptions<'a>,
+    writer: W,
+    error: Option<std::io::Error>,
+    bits_per_sample: u16,
+    sample_width: usize,
+    index: usize,
+    line_len: usize,
+    /// Width of the longest completed line
+    max_line_len: usize,
+    code: String,
+    encoder: adpcm::ImaEncoder,
+    /// ADPCM code waiting for the high nibble of its byte
+    nibble: Option<u8>,
+    frame_channels: u16,
+    /// Left sample of a stereo frame waiting for the right
+    left: Option<i64>,
+    /// Code closing the array definition
+    close: String,
+    /// Side samples of `--stereo-to-ms` waiting for the mid array to close
+    side: Vec<i64>,
+    /// Code opening the side array definition
+    side_open: String,
+    /// Parity byte of each block of samples started
+    parity: Vec<u8>,
+    /// Samples added to the parity
+    parity_samples: usize,
+    /// Bytes of each sample in the parity, those of the array element
+    parity_width: usize,
+    /// Code opening the parity array definition
+    parity_open: String,
+    packer: pack::BitPacker,
+}
+
+impl<'a, W: Write> CEmitter<'a, W> {
+    pub fn new(options: &'a WavToCOptions<'a>, writer: W) -> Self {
+        CEmitter {
+            options,
+            writer,
+            error: None,
+            bits_per_sample: 0,
+            sample_width: 0,
+            index: 0,
+            line_len: 0,
+            max_line_len: 0,
+            code: String::new(),
+            encoder: adpcm::ImaEncoder::default(),
+            nibble: None,
+            frame_channels: 1,
+            left: None,
+            close: String::new(),
+            side: Vec::new(),
+            side_open: String::new(),
+            parity: Vec::new(),
+            parity_samples: 0,
+            parity_width: 0,
+            parity_open: String::new(),
+            packer: pack::BitPacker::default(),
+        }
+    }
+
+    /// Array element token for `value` including the leading space and trailing comma
+    fn format_sample(&self, value: i64) -> String {
+        format!(" {},", self.format_value(value))
+    }
+
+    /// `value` in the array number format, right aligned to the pad width
+    fn format_value(&self, value: i64) -> String {
+        let value = match self.options.offset_binary {
+            true => transform::offset_binary(value, self.bits_per_sample),
+            false => value,
+        };
+        let value = match self.options.byte_swap {
+            true => transform::byte_swap(value, self.sample_width),
+            false => value,
+        };
+        let value = format_number(value, &self.options.format, self.bits_per_sample);
+        format!("{:>1$}", value, self.options.pad_width.unwrap_or(0))
+    }
+
+    /// Array element token for a byte of a `uint8_t` array
+    fn format_byte(&self, byte: u8) -> String {
+        let byte = match self.options.format {
+            ArrayFormat::Base10 => byte.to_string(),
+            ArrayFormat::Base16 => format!("0x{:02x}", byte),
+        };
+        format!(" {:>1$},", byte, self.options.pad_width.unwrap_or(0))
+    }
+
+    /// Append an array element token, wrapping the line as required
+    fn push_token(&mut self, token: &str) {
+        // always at least one token per line even if it exceeds the byte limit
+        let wrap = match self.options.line_bytes {
+            Some(limit) => self.index == 0 || self.line_len + token.len() > limit,
+            None => self.index.is_multiple_of(SAMPLES_PER_LINE),
+        };
+        if wrap {
+            self.flush_code();
+            if self.index > 0 {
+                self.max_line_len = self.max_line_len.max(self.line_len);
+            }
+            // raw values have no opening brace to follow
+            if self.index > 0 || !self.bare() {
+                self.code.push('\n');
+            }
+            let indent = self.options.indent.unwrap_or("\t");
+            self.code.push_str(indent);
+            self.line_len = indent.len();
+        }
+        self.index += 1;
+
+        self.line_len += token.len();
+        self.code.push_str(token);
+    }
+
+    /// End the last line of array values, which is still buffered
+    fn finish_line(&mut self) {
+        // every token ends with a comma
+        if self.options.no_trailing_comma && self.code.ends_with(',') {
+            self.code.pop();
+            self.line_len -= 1;
+        }
+
+        // keeps fixed width values, such as base16, a rectangular block
+        if self.options.last_line_pad && self.line_len < self.max_line_len {
+            let padding = self.max_line_len - self.line_len;
+            self.code.push_str(&" ".repeat(padding));
+            self.line_len = self.max_line_len;
+        }
+    }
+
+    /// Append an array following the samples, opened by `open`, of the element `tokens`
+    fn push_companion(&mut self, open: &str, tokens: Vec<String>) {
+        self.code.push_str(open);
+        self.index = 0;
+        self.line_len = 0;
+        self.max_line_len = 0;
+        for token in tokens {
+            self.push_token(&token);
+        }
+        self.finish_line();
+        self.code.push_str("\n};");
+    }
+
+    /// Whether only the values are written, without an opening brace
+    fn bare(&self) -> bool {
+        self.options.raw_bytes_only || (self.options.lang == Lang::Inc && self.options.no_braces)
+    }
+
+    /// Append a `--banners` separator line before the section `title`
+    fn push_banner(&mut self, title: &str) {
+        if self.options.banners {
+            self.code
+                .push_str(&format!("/* ===== {} ===== */\n", title));
+        }
+    }
+
+    /// Write out the buffered code
+    fn flush_code(&mut self) {
+        if self.error.is_none() {
+            if let Err(e) = self.writer.write_all(self.code.as_bytes()) {
+                self.error = Some(e);
+            }
+        }
+        self.code.clear();
+    }
+
+    /// Flush the writer and return it, or the first error that occurred while writing
+    pub fn finish(mut self) -> std::io::Result<W> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> SampleEmitter for CEmitter<'_, W> {
+    fn begin(&mut self, meta: &WavMetadata) {
+        // each of the mid and side arrays is a mono array of the frames
+        let mid_side_meta;
+        let meta = if self.options.stereo_to_ms {
+            mid_side_meta = mid_side(meta);
+            &mid_side_meta
+        } else {
+            meta
+        };
+        self.side.clear();
+        self.side_open.clear();
+        self.parity.clear();
+        self.parity_samples = 0;
+        // a byte array holds the samples at their width whatever the type
+        self.parity_width = match self.options.bytes {
+            Some(_) => meta.sample_width(),
+            None => meta.element_width(),
+        };
+        self.parity_open.clear();
+        self.packer = pack::BitPacker::new(self.options.pack_bits.unwrap_or(8));
+        self.bits_per_sample = meta.bits_per_sample;
+        self.sample_width = meta.sample_width();
+        self.index = 0;
+        self.line_len = 0;
+        self.max_line_len = 0;
+        self.encoder = adpcm::ImaEncoder::default();
+        self.nibble = None;
+        self.frame_channels = meta.frame_channels;
+        self.left = None;
+
+        if self.options.raw_bytes_only {
+            self.code.clear();
+            self.close.clear();
+            return;
+        }
+
+        // the declaration is the user's, only the initializer is written
+        if self.options.lang == Lang::Inc {
+            let braces = !self.options.no_braces;
+            self.code = if braces { "{" } else { "" }.to_string();
+            self.close = if braces { "\n}" } else { "" }.to_string();
+            return;
+        }
+
+        self.code = if self.options.single_header {
+            format!(
+                "#ifndef _{0}_H_\n#define _{0}_H_\n\n#include <stddef.h>\n#include <stdint.h>\n\n",
+                meta.name.to_uppercase()
+            )
+        } else {
+            String::new()
+        };
+        if !self.options.no_comment {
+            self.code.push_str(&comment_block(meta, self.options));
+        }
+
+        if let Some(prefix) = self.options.prefix {
+            self.push_banner("PREFIX");
+            self.code.push_str(prefix);
+            self.code.push_str("\n\n");
+        }
+
+        if self.options.lang == Lang::Cpp {
+            self.push_banner("ARRAY");
+            self.code.push_str(&cpp::preamble(meta, self.options));
+            self.close = cpp::close(meta);
+            self.flush_code();
+            return;
+        }
+        self.close = "\n};".to_string();
+
+        if !self.options.no_define {
+            self.push_banner("DEFINES");
+            self.code.push_str(&length_definition(
+                &meta.name,
+                "SAMPLE_NO",
+                meta.sample_count,
+                meta,
+                self.options,
+            ));
+
+            if self.options.bytes.is_some() || self.options.pack_bits.is_some() {
+                self.code.push_str(&length_definition(
+                    &meta.name,
+                    "BYTE_NO",
+                    array_element(meta, self.options).1,
+                    meta,
+                    self.options,
+                ));
+            }
+
+            if meta.frame_channels > 1 {
+                self.code.push_str(&length_definition(
+                    &meta.name,
+                    "FRAME_NO",
+                    meta.frame_count(),
+                    meta,
+                    self.options,
+                ));
+            }
+
+            self.code.push_str(&sample_rate_define(&meta.name, meta));
+            self.code.push('\n');
+
+            if let Some(version) = self.options.asset_version {
+                self.code.push_str(&version_define(&meta.name, version));
+                self.code.push('\n');
+            }
+
+            if let Some(defines) = loop_defines(meta) {
+                self.code.push_str(&defines);
+                self.code.push('\n');
+            }
+
+            if let Some(defines) = cue_defines(meta) {
+                self.code.push_str(&defines);
+                self.code.push('\n');
+            }
+
+            if self.options.duration_ms {
+                self.code.push_str(&duration_define(&meta.name, meta));
+                self.code.push('\n');
+            }
+
+            if let Some(define) = pitch_define(&meta.name, meta) {
+                self.code.push_str(&define);
+                self.code.push('\n');
+            }
+
+            if let Some(define) = onset_define(&meta.name, meta) {
+                self.code.push_str(&define);
+                self.code.push('\n');
+            }
+
+            if self.options.adpcm {
+                self.code.push_str(&adpcm_defines(&meta.name));
+                self.code.push('\n');
+            }
+
+            if let Some(bits) = self.options.pack_bits {
+                self.code.push_str(&pack_define(&meta.name, bits));
+                self.code.push('\n');
+            }
+
+            if self.options.fft_ready {
+                self.code.push_str(&fft_size_define(meta));
+                self.code.push('\n');
+            }
+
+            if let Some(block_size) = self.options.block_size {
+                self.code
+                    .push_str(&block_defines(&meta.name, meta, block_size));
+                self.code.push('\n');
+            }
+
+            if let Some(block) = self.options.parity {
+                self.code.push_str(&parity_defines(&meta.name, meta, block));
+                self.code.push('\n');
+            }
+        }
+
+        self.push_banner("ARRAY");
+        if self.options.adpcm && !self.options.no_comment {
+            self.code.push_str("/*\n");
+            for line in adpcm::DECODE_COMMENT {
+                let line = line.replace("<NAME>", &meta.name.to_uppercase());
+                self.code.push_str(&format!("* {}\n", line));
+            }
+            self.code.push_str("*/\n");
+        }
+
+        if self.options.pack_bits.is_some() && !self.options.no_comment {
+            self.code.push_str("/*\n");
+            for line in pack::UNPACK_COMMENT {
+                let line = line.replace("<NAME>", &meta.name.to_uppercase());
+                self.code.push_str(&format!("* {}\n", line));
+            }
+            self.code.push_str("*/\n");
+        }
+
+        if meta.frame_channels > 1 {
+            self.code.push_str(&frame_typedef(&meta.name, meta));
+            self.code.push('\n');
+        }
+
+        let (array_type, array_len, array_bits) = array_element(meta, self.options);
+
+        // AVR reads from flash need pgm_read_* so provide an accessor for portable user code
+        if self.options.progmem_accessor {
+            self.code
+                .push_str(&progmem_accessor(&meta.name, &array_type, array_bits));
+            self.code.push('\n');
+        }
+
+        let array_open = |name: &str| {
+            format!(
+                "{}{}{} {}[{}]{} = {{",
+                static_storage(self.options),
+                array_qualifier(self.options),
+                array_type,
+                name,
+                array_len,
+                array_attributes(self.options)
+            )
+        };
+        if self.options.stereo_to_ms {
+            self.code
+                .push_str(&array_open(&format!("{}_mid", meta.name)));
+            self.side_open = format!("\n\n{}", array_open(&format!("{}_side", meta.name)));
+        } else {
+            self.code.push_str(&array_open(&meta.name));
+        }
+        if let Some(block) = self.options.parity {
+            self.parity_open = format!(
+                "\n\n{}{}uint8_t {}_parity[{}]{} = {{",
+                static_storage(self.options),
+                array_qualifier(self.options),
+                meta.name,
+                meta.sample_count.div_ceil(block),
+                array_attributes(self.options)
+            );
+        }
+        if self.options.bank_entry {
+            self.close.push_str("\n\n");
+            self.close
+                .push_str(bank_entry_define(&meta.name, meta, self.options).trim_end());
+        }
+        self.flush_code();
+    }
+
+    fn sample(&mut self, value: i64) {
+        // frames alternate mid then side, the side held until the mid array is closed
+        if self.options.stereo_to_ms && self.side.len() < self.index {
+            self.side.push(value);
+            return;
+        }
+
+        if let Some(block) = self.options.parity {
+            if self.parity_samples.is_multiple_of(block) {
+                self.parity.push(0);
+            }
+            if let Some(parity) = self.parity.last_mut() {
+                for byte in &(value as u64).to_le_bytes()[..self.parity_width] {
+                    *parity ^= byte;
+                }
+            }
+            self.parity_samples += 1;
+        }
+
+        if self.options.pack_bits.is_some() {
+            for byte in self.packer.push(value) {
+                let token = self.format_byte(byte);
+                self.push_token(&token);
+            }
+            return;
+        }
+
+        if self.options.adpcm {
+            let code = self
+                .encoder
+                .encode(adpcm::to_i16(value, self.bits_per_sample));
+            match self.nibble.take() {
+                Some(low) => {
+                    let token = self.format_byte(low | (code << 4));
+                    self.push_token(&token);
+                }
+                None => self.nibble = Some(code),
+            }
+            return;
+        }
+
+        if self.frame_channels > 1 {
+            match self.left.take() {
+                Some(left) => {
+                    let token = format!(
+                        " {{{}, {}}},",
+                        self.format_value(left),
+                        self.format_value(value)
+                    );
+                    self.push_token(&token);
+                }
+                None => self.left = Some(value),
+            }
+            return;
+        }
+
+        let Some(order) = self.options.bytes else {
+            let token = self.format_sample(value);
+            self.push_token(&token);
+            return;
+        };
+
+        let mut bytes = (value as u32).to_le_bytes();
+        let bytes = &mut bytes[..self.sample_width];
+        if order == ByteOrder::Be {
+            bytes.reverse();
+        }
+        for byte in bytes.iter() {
+            let token = self.format_byte(*byte);
+            self.push_token(&token);
+        }
+    }
+
+    fn end(&mut self) {
+        // odd sample count leaves the high nibble of the last byte zero
+        if let Some(low) = self.nibble.take().or_else(|| self.packer.flush()) {
+            let token = self.format_byte(low);
+            self.push_token(&token);
+        }
+
+        self.finish_line();
+        let close = std::mem::take(&mut self.close);
+        self.code.push_str(&close);
+
+        if self.options.stereo_to_ms {
+            let open = std::mem::take(&mut self.side_open);
+            let tokens: Vec<_> = std::mem::take(&mut self.side)
+                .into_iter()
+                .map(|value| self.format_sample(value))
+                .collect();
+            self.push_companion(&open, tokens);
+        }
+        if self.options.parity.is_some() {
+            let open = std::mem::take(&mut self.parity_open);
+            let tokens: Vec<_> = std::mem::take(&mut self.parity)
+                .into_iter()
+                .map(|byte| self.format_byte(byte))
+                .collect();
+            self.push_companion(&open, tokens);
+        }
+
+        if let Some(suffix) = self.options.suffix {
+            self.code.push_str("\n\n");
+            self.push_banner("SUFFIX");
+            self.code.push_str(suffix);
+        }
+        if self.options.single_header && !self.options.raw_bytes_only {
+            self.code.push_str("\n\n#endif");
+        }
+        self.flush_code();
+    }
+}
+
+/// Layout of each of the `--stereo-to-ms` arrays of stereo `meta`: mono, a sample per frame
+fn mid_side(meta: &WavMetadata) -> WavMetadata {
+    WavMetadata {
+        sample_count: meta.frame_count(),
+        frame_channels: 1,
+        ..meta.clone()
+    }
+}
+
+/// Array name from `template` with `{stem}`, `{rate}`, `{channels}` and `{bits}` replaced by
+/// `stem`, the sample rate, source channels and emitted bits per sample
+fn expand_name_template(
+    template: &str,
+    stem: &str,
+    (rate, channels, bits): (u32, u16, u16),
+) -> Result<String, WavToCError> {
+    let mut name = String::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        name.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('}') else {
+            return Err(WavToCError::InvalidInput(
+                "Name template has an unclosed '{'.".to_string(),
+            ));
+        };
+        match &rest[start + 1..start + end] {
+            "stem" => name.push_str(stem),
+            "rate" => name.push_str(&rate.to_string()),
+            "channels" => name.push_str(&channels.to_string()),
+            "bits" => name.push_str(&bits.to_string()),
+            placeholder => {
+                return Err(WavToCError::InvalidInput(format!(
+                    "Unknown name template placeholder '{}'.",
+                    placeholder
+                )));
+            }
+        }
+        rest = &rest[start + end + 1..];
+    }
+    name.push_str(rest);
+    Ok(name)
+}
+
+/// Whether `name` is a valid C identifier: a letter or underscore followed by alphanumerics or underscores
+pub fn is_c_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+        }
+        _ => false,
+    }
+}
+
+/// `name` made a valid C identifier
+///
+/// Spaces become underscores and other invalid characters are removed. A leading digit is kept
+/// by prefixing an underscore.
+pub fn sanitize_c_identifier(name: &str) -> String {
+    let mut safe_name: String = name
+        .trim()
+        .replace(' ', "_")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric() || *c == '_')
+        .collect();
+    if !safe_name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_') {
+        safe_name.insert(0, '_');
+    }
+    safe_name
+}
+
+/// Width in bits of common C integer types, `None` if unknown
+fn c_type_bits(c_type: &str) -> Option<u16> {
+    match c_type.trim() {
+        "int8_t" | "uint8_t" | "char" | "signed char" | "unsigned char" | "q7_t" => Some(8),
+        "int16_t" | "uint16_t" | "short" | "unsigned short" | "q15_t" => Some(16),
+        "int32_t" | "uint32_t" | "int" | "unsigned int" | "long" | "unsigned long" | "q31_t" => {
+            Some(32)
+        }
+        "int64_t" | "uint64_t" | "long long" | "unsigned long long" | "q63_t" => Some(64),
+        _ => None,
+    }
+}
+
+/// Comment block describing the source file and tool, a `* ` line per entry
+fn comment_block(meta: &WavMetadata, options: &WavToCOptions) -> String {
+    let field = |field| {
+        options
+            .comment_fields
+            .as_ref()
+            .is_none_or(|fields| fields.contains(&field))
+    };
+
+    let mut generator = Vec::new();
+    if field(CommentField::Tool) {
+        generator.push(env!("CARGO_PKG_NAME").to_string());
+    }
+    if field(CommentField::Version) {
+        generator.push(format!("v{}", env!("CARGO_PKG_VERSION")));
+    }
+    if field(CommentField::Source) {
+        generator.push(format!("from {}", meta.source));
+    }
+
+    let mut lines = Vec::new();
+    match generator.first() {
+        Some(first) if first.starts_with("from ") => {
+            lines.push(format!("Generated {}", generator.join(" ")))
+        }
+        Some(_) => lines.push(format!("Generated by {}", generator.join(" "))),
+        None => (),
+    }
+    if field(CommentField::Spec) {
+        lines.push(meta.file_spec());
+    }
+    if field(CommentField::Info) {
+        lines.extend(
+            meta.info_tags
+                .iter()
+                .map(|(name, text)| format!("{}: {}", name, text)),
+        );
+    }
+    match meta.pitch {
+        Some((hz, confidence)) if hz > 0.0 => {
+            lines.push(format!("Pitch: {:.1} Hz, confidence {:.2}", hz, confidence))
+        }
+        Some(_) => lines.push("Pitch: unknown".to_string()),
+        None => (),
+    }
+    if let Some(hz) = options.highpass_hz {
+        lines.push(format!(
+            "High-pass: 2nd order Butterworth biquad at {} Hz",
+            hz
+        ));
+    }
+    if let Some(command) = options.command {
+        lines.push(format!("Command: {}", command));
+    }
+    if field(CommentField::Repo) {
+        lines.push(String::new());
+        lines.push(env!("CARGO_PKG_REPOSITORY").to_string());
+    }
+    lines.extend(options.comment_extra.iter().map(|line| line.to_string()));
+
+    let mut comment = String::from("/*\n");
+    for line in lines {
+        // must not close the comment early
+        let line = line.replace("*/", "*\\/");
+        if line.is_empty() {
+            comment.push_str("*\n");
+        } else {
+            comment.push_str(&format!("* {}\n", line));
+        }
+    }
+    comment.push_str("*/\n\n");
+
+    comment
+}
+
+/// `value` of a `bits_per_sample` sample in the array number `format`
+pub(crate) fn format_number(value: i64, format: &ArrayFormat, bits_per_sample: u16) -> String {
+    match format {
+        ArrayFormat::Base10 => value.to_string(),
+        // by bit depth since the C type name can be overridden
+        // the two's complement bits of the element width so negative samples are non-negative
+        // literals, `-5` in an int16_t being 0xfffb rather than -0x5 or 0xff..fffb
+        ArrayFormat::Base16 => match bits_per_sample {
+            0..=8 => format!("0x{:02x}", value as i8 as u8),
+            9..=16 => format!("0x{:04x}", value as i16 as u16),
+            _ => format!("0x{:08x}", value as i32 as u32),
+        },
+    }
+}
+
+/// Element type, length and element bits of the emitted array
+///
+/// With [`WavToCOptions::bytes`] the array holds the bytes of each sample, with
+/// [`WavToCOptions::adpcm`] two 4-bit codes per byte and with [`WavToCOptions::pack_bits`]
+/// the packed bits.
+fn array_element(meta: &WavMetadata, options: &WavToCOptions) -> (String, usize, u16) {
+    if options.adpcm {
+        ("uint8_t".to_string(), meta.sample_count.div_ceil(2), 8)
+    } else if let Some(bits) = options.pack_bits {
+        (
+            "uint8_t".to_string(),
+            (meta.sample_count * bits as usize).div_ceil(8),
+            8,
+        )
+    } else if meta.frame_channels > 1 {
+        (
+            frame_type(&meta.name),
+            meta.frame_count(),
+            meta.bits_per_sample,
+        )
+    } else if options.bytes.is_some() {
+        (
+            "uint8_t".to_string(),
+            meta.sample_count * meta.sample_width(),
+            8,
+        )
+    } else {
+        (meta.c_type.clone(), meta.sample_count, meta.bits_per_sample)
+    }
+}
+
+/// Qualifier of the array definition and declaration, `const` unless mutable
+fn array_qualifier(options: &WavToCOptions) -> &'static str {
+    if options.mutable {
+        ""
+    } else {
+        "const "
+    }
+}
+
+/// `static ` for definitions of a single header, which each translation unit gets a copy of
+fn static_storage(options: &WavToCOptions) -> &'static str {
+    if options.single_header {
+        "static "
+    } else {
+        ""
+    }
+}
+
+/// `<NAME>_<suffix>` length definition in the [`LengthStyle`] of `options`
+fn length_definition(
+    array_name: &str,
+    suffix: &str,
+    value: usize,
+    meta: &WavMetadata,
+    options: &WavToCOptions,
+) -> String {
+    match options.length_style {
+        LengthStyle::Const => format!(
+            "{}const {} {}_{} = {};\n\n",
+            static_storage(options),
+            meta.size_type,
+            array_name.to_uppercase(),
+            suffix,
+            value
+        ),
+        LengthStyle::Macro => format!(
+            "#define {}_{} {}\n\n",
+            array_name.to_uppercase(),
+            suffix,
+            value
+        ),
+        LengthStyle::Sizeof => format!(
+            "#define {}_{} (sizeof({2}) / sizeof({2}[0]))\n\n",
+            array_name.to_uppercase(),
+            suffix,
+            array_name
+        ),
+    }
+}
+
+/// Header declaration of a [`length_definition`], macros are repeated in full
+fn length_declaration(
+    array_name: &str,
+    suffix: &str,
+    value: usize,
+    meta: &WavMetadata,
+    options: &WavToCOptions,
+) -> String {
+    match options.length_style {
+        LengthStyle::Const => format!(
+            "extern const {} {}_{};\n",
+            meta.size_type,
+            array_name.to_uppercase(),
+            suffix
+        ),
+        LengthStyle::Macro | LengthStyle::Sizeof => format!(
+            "#define {}_{} {}\n",
+            array_name.to_uppercase(),
+            suffix,
+            value
+        ),
+    }
+}
+
+/// Placement and alignment attributes following the array declarator
+fn array_attributes(options: &WavToCOptions) -> String {
+    let mut attributes = String::new();
+    if options.progmem_accessor {
+        attributes.push_str(" PROGMEM");
+    }
+    if let Some(align) = options.align {
+        attributes.push_str(&format!(" __attribute__((aligned({})))", align));
+    }
+    attributes
+}
+
+/// `<NAME>_LOOP_START` and `<NAME>_LOOP_END` defines if the source has loop points
+fn loop_defines(meta: &WavMetadata) -> Option<String> {
+    meta.loop_points.map(|(start, end)| {
+        format!(
+            "#define {name}_LOOP_START {}\n#define {name}_LOOP_END {}\n",
+            start,
+            end,
+            name = meta.name.to_uppercase()
+        )
+    })
+}
+
+/// `<NAME>_CUE_COUNT` and a `<NAME>_CUE_<N>` define for each cue point, with a
+/// `<NAME>_CUE_<LABEL>` alias of any labelled points
+fn cue_defines(meta: &WavMetadata) -> Option<String> {
+    if meta.cue_points.is_empty() {
+        return None;
+    }
+    let name = meta.name.to_uppercase();
+    let mut defines = format!("#define {}_CUE_COUNT {}\n", name, meta.cue_points.len());
+    for (index, (offset, label)) in meta.cue_points.iter().enumerate() {
+        defines.push_str(&format!("#define {}_CUE_{} {}\n", name, index, offset));
+        if let Some(label) = label.as_deref().filter(|label| !label.is_empty()) {
+            defines.push_str(&format!(
+                "#define {}_CUE_{} {}_CUE_{}\n",
+                name,
+                sanitize_c_identifier(label).to_uppercase(),
+                name,
+                index
+            ));
+        }
+    }
+    Some(defines)
+}
+
+/// Name of the stereo frame struct type
+fn frame_type(array_name: &str) -> String {
+    format!("{}_frame_t", array_name)
+}
+
+/// Stereo frame struct typedef, guarded so the source and header can both be included
+fn frame_typedef(array_name: &str, meta: &WavMetadata) -> String {
+    format!(
+        "#ifndef {guard}\n#define {guard}\ntypedef struct {{ {} l, r; }} {};\n#endif\n",
+        meta.c_type,
+        frame_type(array_name),
+        guard = frame_type(array_name).to_uppercase()
+    )
+}
+
+/// `<NAME>_SAMPLE_RATE` define of the playback rate of the emitted samples in Hz
+fn sample_rate_define(array_name: &str, meta: &WavMetadata) -> String {
+    format!(
+        "#define {}_SAMPLE_RATE {}\n",
+        array_name.to_uppercase(),
+        meta.sample_rate
+    )
+}
+
+/// `<NAME>_BANK_ENTRY` designated initializer of a sound bank table entry for the array, its
+/// length being in elements of the array type
+fn bank_entry_define(array_name: &str, meta: &WavMetadata, options: &WavToCOptions) -> String {
+    let (_, array_len, _) = array_element(meta, options);
+    format!(
+        "#define {}_BANK_ENTRY {{ .name = \"{}\", .rate = {}, .len = {}, .data = {} }}\n",
+        array_name.to_uppercase(),
+        array_name,
+        meta.sample_rate,
+        array_len,
+        array_name
+    )
+}
+
+/// `<NAME>_DURATION_MS` define of the emitted length rounded to the nearest millisecond
+fn duration_define(array_name: &str, meta: &WavMetadata) -> String {
+    format!(
+        "#define {}_DURATION_MS {}\n",
+        array_name.to_uppercase(),
+        duration_ms(meta)
+    )
+}
+
+/// Playback duration of the frames in milliseconds, rounded to the nearest
+fn duration_ms(meta: &WavMetadata) -> u64 {
+    let rate = meta.sample_rate as u64;
+    (meta.frame_count() as u64 * 1000 + rate / 2) / rate
+}
+
+/// `<NAME>_VERSION` define of the asset version
+fn version_define(array_name: &str, version: u32) -> String {
+    format!(
+        "#define {}_VERSION {}\n",
+        array_name.to_uppercase(),
+        version
+    )
+}
+
+/// Comment describing the asset version and CRC-32, and the `<NAME>_CRC32` define
+fn checksum_define(array_name: &str, version: Option<u32>, crc32: u32) -> String {
+    let version = version
+        .map(|version| format!("version {}, ", version))
+        .unwrap_or_default();
+    format!(
+        "/* Asset {}CRC-32 of the samples as little endian bytes of the array type */\n\
+        #define {}_CRC32 0x{:08x}u\n",
+        version,
+        array_name.to_uppercase(),
+        crc32
+    )
+}
+
+/// `<NAME>_PITCH_HZ` define of the detected fundamental, 0 if unknown
+fn pitch_define(array_name: &str, meta: &WavMetadata) -> Option<String> {
+    meta.pitch
+        .map(|(hz, _)| format!("#define {}_PITCH_HZ {:.1}\n", array_name.to_uppercase(), hz))
+}
+
+/// `<NAME>_ONSET` define of the first non-silent frame
+fn onset_define(array_name: &str, meta: &WavMetadata) -> Option<String> {
+    meta.onset
+        .map(|frame| format!("#define {}_ONSET {}\n", array_name.to_uppercase(), frame))
+}
+
+/// `<NAME>_PREDICTOR` and `<NAME>_STEP_INDEX` initial ADPCM decoder state defines
+fn adpcm_defines(array_name: &str) -> String {
+    format!(
+        "#define {name}_PREDICTOR {}\n#define {name}_STEP_INDEX {}\n",
+        adpcm::INITIAL_PREDICTOR,
+        adpcm::INITIAL_STEP_INDEX,
+        name = array_name.to_uppercase()
+    )
+}
+
+/// `<NAME>_PACK_BITS` define of the bits per packed sample
+fn pack_define(array_name: &str, bits: u32) -> String {
+    format!("#define {}_PACK_BITS {}\n", array_name.to_uppercase(), bits)
+}
+
+/// `<NAME>_FFT_SIZE` define of the zero padded sample count
+fn fft_size_define(meta: &WavMetadata) -> String {
+    format!(
+        "#define {}_FFT_SIZE {}\n",
+        meta.name.to_uppercase(),
+        meta.frame_count()
+    )
+}
+
+/// `<NAME>_BLOCK_SIZE` and `<NAME>_BLOCK_COUNT` defines of the padded frames
+fn block_defines(array_name: &str, meta: &WavMetadata, block_size: usize) -> String {
+    format!(
+        "#define {name}_BLOCK_SIZE {}\n#define {name}_BLOCK_COUNT {}\n",
+        block_size,
+        meta.frame_count() / block_size,
+        name = array_name.to_uppercase()
+    )
+}
+
+/// Comment describing the `--parity` bytes with the `<NAME>_PARITY_BLOCK` and
+/// `<NAME>_PARITY_NO` defines
+fn parity_defines(array_name: &str, meta: &WavMetadata, block: usize) -> String {
+    format!(
+        "/* Byte i of {name}_parity is the XOR of the little endian bytes of samples\n\
+        * i * {upper}_PARITY_BLOCK up to the next block, the last block may be short */\n\
+        #define {upper}_PARITY_BLOCK {}\n#define {upper}_PARITY_NO {}\n",
+        block,
+        meta.sample_count.div_ceil(block),
+        name = array_name,
+        upper = array_name.to_uppercase()
+    )
+}
+
+/// `<NAME>_GET(i)` macro reading element `i` with the `pgm_read_*` call for the sample width
+fn progmem_accessor(array_name: &str, array_type: &str, bits_per_sample: u16) -> String {
+    let read = match bits_per_sample {
+        0..=8 => "pgm_read_byte",
+        9..=16 => "pgm_read_word",
+        _ => "pgm_read_dword",
+    };
+
+    format!(
+        "#define {}_GET(i) (({}){}(&{}[i]))\n",
+        array_name.to_uppercase(),
+        array_type,
+        read,
+        array_name
+    )
+}
+
+fn write_header(
+    output_path: &Path,
+    array_name: &str,
+    meta: &WavMetadata,
+    options: &WavToCOptions,
+    crc32: Option<u32>,
+) -> Result<(), WavToCError> {
+    let mid_side_meta;
+    let meta = if options.stereo_to_ms {
+        mid_side_meta = mid_side(meta);
+        &mid_side_meta
+    } else {
+        meta
+    };
+    let mut header = format!(
+        "#ifndef _{}_H_\n#define _{}_H_\n\n",
+        array_name.to_uppercase(),
+        array_name.to_uppercase(),
+    );
+
+    if options.extern_c {
+        header.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+    }
+
+    if !options.no_define {
+        header.push_str(&length_declaration(
+            array_name,
+            "SAMPLE_NO",
+            meta.sample_count,
+            meta,
+            options,
+        ));
+        if options.bytes.is_some() || options.pack_bits.is_some() {
+            header.push_str(&length_declaration(
+                array_name,
+                "BYTE_NO",
+                array_element(meta, options).1,
+                meta,
+                options,
+            ));
+        }
+        if meta.frame_channels > 1 {
+            header.push_str(&length_declaration(
+                array_name,
+                "FRAME_NO",
+                meta.frame_count(),
+                meta,
+                options,
+            ));
+        }
+    }
+
+    if meta.frame_channels > 1 {
+        header.push('\n');
+        header.push_str(&frame_typedef(array_name, meta));
+        header.push('\n');
+    }
+
+    let (array_type, _, array_bits) = array_element(meta, options);
+    let array_names = match options.stereo_to_ms {
+        true => vec![
+            format!("{}_mid", array_name),
+            format!("{}_side", array_name),
+        ],
+        false => vec![array_name.to_string()],
+    };
+    for name in array_names {
+        header.push_str(&format!(
+            "extern {}{} {}[]{};\n",
+            array_qualifier(options),
+            array_type,
+            name,
+            array_attributes(options),
+        ));
+    }
+    if options.parity.is_some() {
+        header.push_str(&format!(
+            "extern {}uint8_t {}_parity[]{};\n",
+            array_qualifier(options),
+            array_name,
+            array_attributes(options),
+        ));
+    }
+
+    if !options.no_define {
+        header.push('\n');
+        header.push_str(&sample_rate_define(array_name, meta));
+    }
+
+    if let Some(version) = options.asset_version.filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&version_define(array_name, version));
+    }
+
+    if let Some(crc32) = crc32.filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&checksum_define(array_name, options.asset_version, crc32));
+    }
+
+    if let Some(defines) = loop_defines(meta).filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&defines);
+    }
+
+    if let Some(defines) = cue_defines(meta).filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&defines);
+    }
+
+    if options.duration_ms && !options.no_define {
+        header.push('\n');
+        header.push_str(&duration_define(array_name, meta));
+    }
+
+    if let Some(define) = pitch_define(array_name, meta).filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&define);
+    }
+
+    if let Some(define) = onset_define(array_name, meta).filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&define);
+    }
+
+    if options.adpcm && !options.no_define {
+        header.push('\n');
+        header.push_str(&adpcm_defines(array_name));
+    }
+
+    if let Some(bits) = options.pack_bits.filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&pack_define(array_name, bits));
+    }
+
+    if options.fft_ready && !options.no_define {
+        header.push('\n');
+        header.push_str(&fft_size_define(meta));
+    }
+
+    if let Some(block_size) = options.block_size.filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&block_defines(array_name, meta, block_size));
+    }
+
+    if let Some(block) = options.parity.filter(|_| !options.no_define) {
+        header.push('\n');
+        header.push_str(&parity_defines(array_name, meta, block));
+    }
+
+    if options.progmem_accessor {
+        header.push('\n');
+        header.push_str(&progmem_accessor(array_name, &array_type, array_bits));
+    }
+
+    if options.bank_entry {
+        header.push('\n');
+        header.push_str(&bank_entry_define(array_name, meta, options));
+    }
+
+    if options.extern_c {
+        header.push_str("\n#ifdef __cplusplus\n}\n#endif\n");
+    }
+
+    header.push_str("\n#endif");
+
+    let header = match options.post_process {
+        Some(command) => post_process::run(command, header.as_bytes())?,
+        None => header.into_bytes(),
+    };
+    std::fs::write(output_path, header)?;
+    Ok(())
+}
+
+/// Seekable input the WAV is decoded from
+trait WavSource: Read + Seek {}
+
+impl<T: Read + Seek> WavSource for T {}
+
+/// Whether `wav_path` is `-`, meaning read the WAV from stdin
+pub fn is_stdin(wav_path: &Path) -> bool {
+    wav_path == Path::new("-")
+}
+
+/// Open `wav_path`, or stdin for `-`, with the file name for messages
+fn open_source(
+    wav_path: &Path,
+) -> Result<(Box<dyn WavSource>, std::borrow::Cow<'_, str>), WavToCError> {
+    if is_stdin(wav_path) {
+        // stdin cannot seek so buffer it all for the chunk scan and decoder
+        let mut bytes = Vec::new();
+        std::io::stdin().lock().read_to_end(&mut bytes)?;
+        return Ok((Box::new(Cursor::new(bytes)), "stdin".into()));
+    }
+    if !wav_path.exists() {
+        return Err(WavToCError::InvalidInput(
+            "Input file does not exist.".to_string(),
+        ));
+    }
+    Ok((
+        Box::new(BufReader::new(File::open(wav_path)?)),
+        wav_path.file_name().unwrap().to_string_lossy(),
+    ))
+}
+
+/// Human readable details of the WAV header of `wav_path`, for inspecting a file before
+/// converting it
+pub fn wav_info(wav_path: &Path) -> Result<String, WavToCError> {
+    let (mut file, wave_file) = open_source(wav_path)?;
+    let chunks = riff::read_chunks(&mut file)?;
+    let (Some(fmt), Some((_, len))) = (chunks.fmt(), chunks.data()) else {
+        return Err(WavToCError::InvalidInput(
+            "Input is not a WAV file with fmt and data chunks.".to_string(),
+        ));
+    };
+
+    let frame_bytes = fmt.channels as u64 * fmt.bits_per_sample.div_ceil(8) as u64;
+    let frames = len.checked_div(frame_bytes).unwrap_or(0);
+    let duration = frames as f64 / fmt.sample_rate.max(1) as f64;
+    let mut info = format!(
+        "File: {}\n\
+        Sample rate: {} Hz\n\
+        Channels: {}\n\
+        Bits per sample: {}\n\
+        Sample format: {}\n\
+        Duration: {:.3} s\n\
+        Samples: {} ({} frames)\n",
+        wave_file,
+        fmt.sample_rate,
+        fmt.channels,
+        fmt.bits_per_sample,
+        if fmt.is_float() { "float" } else { "int" },
+        duration,
+        frames * fmt.channels as u64,
+        frames
+    );
+    if let Some(bits) = fmt.valid_bits.filter(|&bits| bits != fmt.bits_per_sample) {
+        info.push_str(&format!("Valid bits per sample: {}\n", bits));
+    }
+    if let Some((start, end)) = chunks.loop_points() {
+        info.push_str(&format!("Loop: {} to {}\n", start, end));
+    }
+    let cue_points = chunks.cue_points();
+    if !cue_points.is_empty() {
+        info.push_str(&format!("Cue points: {}\n", cue_points.len()));
+    }
+    for (name, text) in chunks.info_tags() {
+        info.push_str(&format!("{}: {}\n", name, text));
+    }
+
+    Ok(info)
+}
+
+/// Decode `wav_path` and drive `emitter` with the samples
+///
+/// A `wav_path` of `-` reads the WAV from stdin. Returns the metadata passed to the emitter and
+/// any warnings.
+pub fn convert<E: SampleEmitter + ?Sized>(
+    wav_path: &Path,
+    array_name: &str,
+    options: &WavToCOptions,
+    emitter: &mut E,
+) -> Result<Conversion, WavToCError> {
+    convert_logged(wav_path, array_name, options, emitter, true)
+}
+
+/// [`convert`], logging the warnings as they are found only if `log_warnings`
+fn convert_logged<E: SampleEmitter + ?Sized>(
+    wav_path: &Path,
+    array_name: &str,
+    options: &WavToCOptions,
+    emitter: &mut E,
+    log_warnings: bool,
+) -> Result<Conversion, WavToCError> {
+    let started = Instant::now();
+    let mut warnings = Warnings {
+        list: Vec::new(),
+        quiet: !log_warnings,
+    };
+    let (mut file, wave_file) = open_source(wav_path)?;
+    let chunks = riff::read_chunks(&mut file)?;
+
+    info!("Processing file: {}", wave_file);
+
+    // TODO: generic types so not all hound::Samples cast to i32
+    type Decoded = Box<dyn Iterator<Item = Result<i32, hound::Error>>>;
+    let (spec, frames, mut decoded): (hound::WavSpec, usize, Decoded) =
+        match (chunks.fmt(), chunks.data()) {
+            // hound does not support 64-bit float so read the data chunk directly
+            (Some(fmt), Some((offset, len))) if fmt.is_float() && fmt.bits_per_sample == 64 => {
+                info!(
+                    "Sample rate: {} Hz, Channels: {}, Bits per sample: 64 (float)",
+                    fmt.sample_rate, fmt.channels
+                );
+                info!("Converting 64-bit float samples to 16-bit int.");
+                if fmt.channels == 0 {
+                    return Err(WavToCError::InvalidInput(
+                        "No channels in 64-bit float input.".to_string(),
+                    ));
+                }
+
+                file.seek(SeekFrom::Start(offset))?;
+                let spec = hound::WavSpec {
+                    channels: fmt.channels,
+                    sample_rate: fmt.sample_rate,
+                    bits_per_sample: 16,
+                    sample_format: hound::SampleFormat::Int,
+                };
+                let frames = (len / 8 / fmt.channels as u64) as usize;
+                let samples = riff::f64_samples(file, len).map(|s| {
+                    s.map(transform::float_to_i16)
+                        .map_err(hound::Error::IoError)
+                });
+
+                (spec, frames, Box::new(samples))
+            }
+            // hound decodes the valid bits from the bottom of the container, or not at all
+            (Some(fmt), Some((offset, len)))
+                if !fmt.is_float()
+                    && fmt
+                        .valid_bits
+                        .is_some_and(|bits| bits < fmt.bits_per_sample)
+                    && fmt.bits_per_sample <= 32 =>
+            {
+                let valid_bits = fmt.valid_bits.unwrap_or(fmt.bits_per_sample);
+                info!(
+                    "Sample rate: {} Hz, Channels: {}, Bits per sample: {} (valid {})",
+                    fmt.sample_rate, fmt.channels, fmt.bits_per_sample, valid_bits
+                );
+                if fmt.channels == 0 {
+                    return Err(WavToCError::InvalidInput(
+                        "Input has no channels.".to_string(),
+                    ));
+                }
+                push_warning(
+                    &mut warnings,
+                    format!(
+                        "{} bit container holds {} valid bits, converting as {} bit samples.",
+                        fmt.bits_per_sample, valid_bits, valid_bits
+                    ),
+                );
+
+                file.seek(SeekFrom::Start(offset))?;
+                let spec = hound::WavSpec {
+                    channels: fmt.channels,
+                    sample_rate: fmt.sample_rate,
+                    bits_per_sample: valid_bits,
+                    sample_format: hound::SampleFormat::Int,
+                };
+                let frame_bytes = fmt.bits_per_sample.div_ceil(8) as u64 * fmt.channels as u64;
+                let frames = (len / frame_bytes) as usize;
+                let samples = riff::int_samples(file, len, fmt.bits_per_sample, valid_bits)
+                    .map(|s| s.map_err(hound::Error::IoError));
+
+                (spec, frames, Box::new(samples))
+            }
+            (Some(fmt), _) if fmt.is_float() && fmt.bits_per_sample != 32 => {
+                return Err(WavToCError::InvalidInput(format!(
+                    "Unsupported {}-bit float input, only 64-bit float is supported.",
+                    fmt.bits_per_sample
+                )));
+            }
+            _ => {
+                let reader = hound::WavReader::new(file)?;
+                let spec = reader.spec();
+                info!(
+                    "Sample rate: {} Hz, Channels: {}, Bits per sample: {}",
+                    spec.sample_rate, spec.channels, spec.bits_per_sample
+                );
+
+                if spec.sample_format != hound::SampleFormat::Int {
+                    return Err(WavToCError::InvalidInput(
+                        "Only int PCM or 64-bit float audio is currently supported.".to_string(),
+                    ));
+                }
+
+                let frames = reader.duration() as usize;
+                (spec, frames, Box::new(reader.into_samples::<i32>()))
+            }
+        };
+
+    // the count compressed and float files must have, a mismatch being a truncated or corrupt file
+    if let Some(fact_frames) = chunks.fact_frames() {
+        if fact_frames as usize != frames {
+            push_warning(
+                &mut warnings,
+                format!(
+                    "The fact chunk counts {} frames but the data holds {}, the file may be truncated or corrupt.",
+                    fact_frames, frames
+                ),
+            );
+        }
+    }
+
+    if let Some(bits) = options
+        .dither_depth
+        .filter(|&bits| bits == 0 || bits > spec.bits_per_sample)
+    {
+        return Err(WavToCError::InvalidInput(format!(
+            "Dither depth must be from 1 to the source depth of {} bits, got {}",
+            spec.bits_per_sample, bits
+        )));
+    }
+
+    // depth of the emitted samples, transforms before the requantization work at the source depth
+    let bits_per_sample = match options.bit_depth {
+        Some(bits) if bits == 0 || bits > spec.bits_per_sample => {
+            return Err(WavToCError::InvalidInput(format!(
+                "Bit depth must be from 1 to the source depth of {} bits, got {}",
+                spec.bits_per_sample, bits
+            )));
+        }
+        Some(bits) => {
+            info!(
+                "Requantizing {} bit samples to {} bits.",
+                spec.bits_per_sample, bits
+            );
+            bits
+        }
+        None => match options.upscale {
+            Some(bits) if bits <= spec.bits_per_sample || bits > 32 => {
+                return Err(WavToCError::InvalidInput(format!(
+                    "Upscale depth must be above the source depth of {} bits and at most 32, got {}",
+                    spec.bits_per_sample, bits
+                )));
+            }
+            Some(bits) => {
+                info!(
+                    "Upscaling {} bit samples to {} bits.",
+                    spec.bits_per_sample, bits
+                );
+                bits
+            }
+            None => spec.bits_per_sample,
+        },
+    };
+
+    if let Some(bits) = options
+        .pack_bits
+        .filter(|&bits| !(bits_per_sample as u32..=32).contains(&bits))
+    {
+        return Err(WavToCError::InvalidInput(format!(
+            "Pack bits must be from the sample depth of {} bits to 32, got {}; use --bit-depth to requantize first",
+            bits_per_sample, bits
+        )));
+    }
+
+    let mut c_type = match bits_per_sample {
+        0..=8 if options.offset_binary => "uint8_t".to_string(),
+        9..=16 if options.offset_binary => "uint16_t".to_string(),
+        17..=32 if options.offset_binary => "uint32_t".to_string(),
+        0..=8 => std::env::var("WAV2C_I8_TYPE").unwrap_or_else(|_| "int8_t".to_string()),
+        9..=16 => std::env::var("WAV2C_I16_TYPE").unwrap_or_else(|_| "int16_t".to_string()),
+        17..=32 => std::env::var("WAV2C_I32_TYPE").unwrap_or_else(|_| "int32_t".to_string()),
+        _ => {
+            return Err(WavToCError::InvalidInput(
+                "Unsupported bits per sample.".to_string(),
+            ))
+        }
+    };
+
+    if let Some(user_type) = options.c_type {
+        match c_type_bits(user_type) {
+            Some(bits) if bits < bits_per_sample => push_warning(
+                &mut warnings,
+                format!(
+                    "{} ({} bits) cannot hold {} bit samples.",
+                    user_type, bits, bits_per_sample
+                ),
+            ),
+            Some(_) => (),
+            None => info!(
+                "Unknown width of {}, ensure it can hold {} bit samples.",
+                user_type, bits_per_sample
+            ),
+        }
+        c_type = user_type.to_string();
+    }
+
+    if options.single_header && options.lang != Lang::C {
+        return Err(WavToCError::InvalidInput(
+            "A single header is only written for C output.".to_string(),
+        ));
+    }
+
+    if options.bank_entry && options.lang != Lang::C {
+        return Err(WavToCError::InvalidInput(
+            "A bank entry is only defined for C output.".to_string(),
+        ));
+    }
+
+    if options.no_braces && options.lang != Lang::Inc {
+        return Err(WavToCError::InvalidInput(
+            "Braces can only be omitted from inc output.".to_string(),
+        ));
+    }
+
+    if options.lang == Lang::Inc && (options.prefix.is_some() || options.suffix.is_some()) {
+        return Err(WavToCError::InvalidInput(
+            "Inc output is only the initializer, without a prefix or suffix.".to_string(),
+        ));
+    }
+
+    // the array length is the sample count only for a plain sample array
+    if options.length_style == LengthStyle::Sizeof
+        && (options.lang != Lang::C
+            || options.adpcm
+            || options.pack_bits.is_some()
+            || options.bytes.is_some()
+            || options.stereo_frames
+            || options.stereo_to_ms)
+    {
+        return Err(WavToCError::InvalidInput(
+            "Sizeof lengths are only for C arrays of one sample per element.".to_string(),
+        ));
+    }
+
+    if options.pack_bits.is_some() && options.lang != Lang::C {
+        return Err(WavToCError::InvalidInput(
+            "Packed samples are only written for C output.".to_string(),
+        ));
+    }
+
+    if options.parity == Some(0) {
+        return Err(WavToCError::InvalidInput(
+            "Parity block must be at least 1 sample.".to_string(),
+        ));
+    }
+
+    if options.parity.is_some() && options.lang != Lang::C {
+        return Err(WavToCError::InvalidInput(
+            "Parity bytes are only written for C output.".to_string(),
+        ));
+    }
+
+    if (options.checksum || options.test_harness) && (options.bytes.is_some() || options.adpcm) {
+        return Err(WavToCError::InvalidInput(
+            "The CRC-32 is of the samples so cannot check a byte or ADPCM array.".to_string(),
+        ));
+    }
+
+    if options.stereo_to_ms && options.lang != Lang::C {
+        return Err(WavToCError::InvalidInput(
+            "Mid/side arrays are only written for C output.".to_string(),
+        ));
+    }
+
+    if options.banners && matches!(options.lang, Lang::Json | Lang::Inc) {
+        return Err(WavToCError::InvalidInput(
+            "Banners are only written to C and C++ output.".to_string(),
+        ));
+    }
+
+    if options.byte_swap && options.lang == Lang::Json {
+        return Err(WavToCError::InvalidInput(
+            "Byte swapping is only for C and C++ arrays.".to_string(),
+        ));
+    }
+
+    if options.offset_binary && options.lang == Lang::Json {
+        return Err(WavToCError::InvalidInput(
+            "Offset binary is only for C and C++ arrays.".to_string(),
+        ));
+    }
+
+    // hex of negative values would narrow in the std::array initializer
+    if options.lang == Lang::Cpp && matches!(options.format, ArrayFormat::Base16) {
+        return Err(WavToCError::InvalidInput(
+            "C++ output only supports base 10 values.".to_string(),
+        ));
+    }
+
+    if let Some(align) = options.align.filter(|align| !align.is_power_of_two()) {
+        return Err(WavToCError::InvalidInput(format!(
+            "Alignment must be a power of two, got {}",
+            align
+        )));
+    }
+
+    if let Some(gain) = options.gain.filter(|gain| !gain.is_finite()) {
+        return Err(WavToCError::InvalidInput(format!(
+            "Gain must be a finite number, got {}",
+            gain
+        )));
+    }
+
+    if let Some(db) = options
+        .limit_db
+        .filter(|db| !(db.is_finite() && *db <= 0.0))
+    {
+        return Err(WavToCError::InvalidInput(format!(
+            "Limit must be zero or negative dBFS, got {}",
+            db
+        )));
+    }
+
+    if let Some(indent) = options.indent {
+        if !indent.chars().all(|c| c == ' ' || c == '\t') {
+            return Err(WavToCError::InvalidInput(format!(
+                "Indent must only be spaces or tabs, got {:?}",
+                indent
+            )));
+        }
+    }
+
+    if options.truncate && (options.fft_ready || options.block_size.is_some()) {
+        return Err(WavToCError::InvalidInput(
+            "Truncating cannot be combined with zero padding.".to_string(),
+        ));
+    }
+
+    if options.repeat == Some(0) {
+        return Err(WavToCError::InvalidInput(
+            "Repeat count must be at least 1.".to_string(),
+        ));
+    }
+
+    if options.resample == Some(0) {
+        return Err(WavToCError::InvalidInput(
+            "Resample rate must be at least 1 Hz.".to_string(),
+        ));
+    }
+
+    if options.block_size == Some(0) {
+        return Err(WavToCError::InvalidInput(
+            "Block size must be at least one frame.".to_string(),
+        ));
+    }
+
+    if options.stereo_frames && (options.bytes.is_some() || options.adpcm) {
+        return Err(WavToCError::InvalidInput(
+            "Stereo frames cannot be emitted as bytes or ADPCM.".to_string(),
+        ));
+    }
+
+    // clipped and total input samples read, for the clipping warning after the conversion
+    let full_scale = Rc::new(Cell::new((0usize, 0usize)));
+    if !options.no_clip_check {
+        let max = transform::full_scale(spec.bits_per_sample).1;
+        let counts = Rc::clone(&full_scale);
+        // a single sample at full scale is a peak, a run of them in a channel a clipped top
+        let channels = spec.channels.max(1) as usize;
+        let mut previous = vec![false; channels];
+        decoded = Box::new(decoded.inspect(move |sample| {
+            if let Ok(value) = sample {
+                let (clipped, total) = counts.get();
+                let channel = &mut previous[total % channels];
+                let at_full_scale = (*value as i64).abs() >= max;
+                let clipped = clipped + (at_full_scale && *channel) as usize;
+                *channel = at_full_scale;
+                counts.set((clipped, total + 1));
+            }
+        }));
+    }
+
+    let channels = spec.channels as usize;
+    if options.stereo_to_ms && channels != 2 {
+        return Err(WavToCError::InvalidInput(format!(
+            "Input has {} channels, mid/side needs stereo.",
+            channels
+        )));
+    }
+    // channels per emitted frame, input is merged to mono unless stereo kept as frames
+    let frame_channels = match channels {
+        1 => {
+            if options.stereo_frames {
+                info!("Input is mono, emitting a flat array rather than stereo frames.");
+            }
+            1
+        }
+        2 if options.stereo_frames || options.stereo_to_ms => 2,
+        2 => {
+            push_warning(
+                &mut warnings,
+                format!(
+                    "Merging stereo channels into mono by {:?}.",
+                    options.mono_mix
+                ),
+            );
+            1
+        }
+        0 => {
+            return Err(WavToCError::InvalidInput(
+                "Input has no channels.".to_string(),
+            ));
+        }
+        _ if options.stereo_frames => {
+            return Err(WavToCError::InvalidInput(format!(
+                "Input has {} channels, only stereo can be kept as frames.",
+                channels
+            )));
+        }
+
+        _ => {
+            push_warning(
+                &mut warnings,
+                format!(
+                    "Merging {} channels into mono by {:?}.",
+                    channels, options.mono_mix
+                ),
+            );
+            1
+        }
+    };
+
+    let nyquist = options.resample.unwrap_or(spec.sample_rate) as f64 / 2.0;
+    if let Some(hz) = options
+        .highpass_hz
+        .filter(|hz| !(hz.is_finite() && *hz > 0.0 && *hz < nyquist))
+    {
+        return Err(WavToCError::InvalidInput(format!(
+            "High-pass cutoff must be above 0 and below the Nyquist frequency of {} Hz, got {}",
+            nyquist, hz
+        )));
+    }
+
+    let stereo_width = match options.stereo_width {
+        Some(width) if !(width.is_finite() && width >= 0.0) => {
+            return Err(WavToCError::InvalidInput(format!(
+                "Stereo width must be zero or positive, got {}",
+                width
+            )));
+        }
+        Some(_) if channels != 2 => {
+            info!("Ignoring stereo width for {} channel input.", channels);
+            None
+        }
+        width => width,
+    };
+    let mono_mix = options.mono_mix;
+    let stereo_to_ms = options.stereo_to_ms;
+
+    // mix each frame to mono or interleave the kept channels, an incomplete final frame is
+    // dropped
+    let mut pending = None;
+    let interleaved = std::iter::from_fn(move || {
+        if let Some(sample) = pending.take() {
+            return Some(Ok(sample));
+        }
+        // left and right are the first two channels in the WAV channel order, the average and
+        // sum mixes take all channels
+        let mut frame = [0i64; 2];
+        let mut sum = 0;
+        for channel in 0..channels {
+            let value = match decoded.next()? {
+                Ok(value) => value as i64,
+                Err(e) => return Some(Err(e)),
+            };
+            if let Some(sample) = frame.get_mut(channel) {
+                *sample = value;
+            }
+            sum += value;
+        }
+        if let Some(width) = stereo_width {
+            frame = transform::stereo_width(frame, width);
+            sum = frame[0] + frame[1];
+        }
+        if stereo_to_ms {
+            frame = transform::mid_side(frame);
+        }
+        if frame_channels == 2 {
+            pending = Some(frame[1]);
+            Some(Ok(frame[0]))
+        } else if channels == 1 {
+            Some(Ok(frame[0]))
+        } else {
+            Some(Ok(match mono_mix {
+                MonoMix::Average => sum / channels as i64,
+                MonoMix::Left => frame[0],
+                MonoMix::Right => frame[1],
+                // saturated with the other transforms by the sample processor
+                MonoMix::SumClip => sum,
+            }))
+        }
+    });
+
+    // slice before any transforms so they only see the requested range
+    let source_frames = frames;
+    let position = |position: Option<ClipPosition>, default: usize| {
+        position.map_or(Some(default), |p| p.frame(spec.sample_rate))
+    };
+    let (start, end) = match (position(options.start, 0), position(options.end, frames)) {
+        // without a requested range an empty input stays empty
+        _ if options.start.is_none() && options.end.is_none() => (0, frames),
+        (Some(start), Some(end)) if start < end && end <= frames => (start, end),
+        _ => {
+            return Err(WavToCError::InvalidInput(format!(
+                "Clip range {:?} to {:?} is not within the {} frames of the input",
+                options.start, options.end, frames
+            )));
+        }
+    };
+    let frames = end - start;
+    let interleaved = interleaved
+        .skip(start * frame_channels)
+        .take(frames * frame_channels);
+
+    // everything after the clip range sees the resampled rate
+    let source_rate = spec.sample_rate;
+    let resample = options.resample.filter(|&rate| rate != source_rate);
+    let spec = hound::WavSpec {
+        sample_rate: resample.unwrap_or(source_rate),
+        ..spec
+    };
+
+    let mut pitch = None;
+    let mut onset = None;
+    // frames reversed, before any repeat, to mirror the loop and cue points about
+    let mut reversed_frames = None;
+    // transforms needing every sample buffer them, otherwise samples stream to the emitter
+    let (sample_count, samples): (usize, Box<dyn Iterator<Item = Result<i64, hound::Error>>>) =
+        if options.reverse
+            || options.rotate_to_peak
+            || options.remove_dc
+            || options.detect_pitch
+            || options.mark_onset.is_some()
+            || options.repeat.is_some()
+            || resample.is_some()
+        {
+            let mut samples = interleaved.collect::<Result<Vec<_>, _>>()?;
+            if let Some(rate) = resample {
+                samples = transform::resample(
+                    &samples,
+                    frame_channels,
+                    source_rate,
+                    rate,
+                    !options.no_antialias,
+                );
+                info!("Resampled from {} Hz to {} Hz.", source_rate, rate);
+            }
+            if options.remove_dc {
+                let offsets = transform::remove_dc(&mut samples, frame_channels);
+                info!("Removed DC offset of {:?}.", offsets);
+            }
+            if options.reverse {
+                transform::reverse_frames(&mut samples, frame_channels);
+                reversed_frames = Some(samples.len() / frame_channels);
+            }
+            if options.rotate_to_peak {
+                transform::rotate_to_peak(&mut samples, frame_channels);
+                if chunks.loop_points().is_some() || !chunks.cue_points().is_empty() {
+                    push_warning(
+                        &mut warnings,
+                        "Loop and cue points are not adjusted for --rotate-to-peak.".to_string(),
+                    );
+                }
+            }
+            if options.detect_pitch {
+                let mono: Vec<i64> = samples
+                    .chunks_exact(frame_channels)
+                    .map(|frame| frame.iter().sum::<i64>() / frame_channels as i64)
+                    .collect();
+                pitch = Some(pitch::detect_pitch(&mono, spec.sample_rate));
+                info!("Detected pitch {:?}.", pitch);
+            }
+            if let Some(db) = options.mark_onset {
+                let threshold =
+                    transform::full_scale(spec.bits_per_sample).1 as f64 * 10f64.powf(db / 20.0);
+                let frame = transform::onset(&samples, frame_channels, threshold);
+                if frame.is_none() {
+                    push_warning(
+                        &mut warnings,
+                        format!(
+                            "No sample above the onset threshold of {} dBFS, onset is the end.",
+                            db
+                        ),
+                    );
+                }
+                onset = Some(frame.unwrap_or(samples.len() / frame_channels));
+                info!("Onset at frame {:?}.", onset);
+            }
+            if let Some(count) = options.repeat {
+                info!(
+                    "Repeating {} frames {} times.",
+                    samples.len() / frame_channels,
+                    count
+                );
+                samples = samples.repeat(count);
+            }
+            (samples.len(), Box::new(samples.into_iter().map(Ok)))
+        } else {
+            (frames * frame_channels, Box::new(interleaved))
+        };
+
+    // audio length in frames before padding so fades end with the clip
+    let clip_len = sample_count / frame_channels;
+    let (sample_count, samples) = if options.fft_ready {
+        let fft_size = clip_len.next_power_of_two();
+        info!("Zero padding {} frames to FFT size {}.", clip_len, fft_size);
+        let padding = std::iter::repeat_n(0, (fft_size - clip_len) * frame_channels).map(Ok);
+        (
+            fft_size * frame_channels,
+            Box::new(samples.chain(padding)) as Box<dyn Iterator<Item = _>>,
+        )
+    } else {
+        (sample_count, samples)
+    };
+
+    let (sample_count, samples) = match options.block_size {
+        Some(block_size) if sample_count % (block_size * frame_channels) != 0 => {
+            let frames = sample_count / frame_channels;
+            let padded = frames.next_multiple_of(block_size);
+            info!(
+                "Zero padding {} frames to {} blocks of {}.",
+                frames,
+                padded / block_size,
+                block_size
+            );
+            let padding = std::iter::repeat_n(0, (padded - frames) * frame_channels).map(Ok);
+            (
+                padded * frame_channels,
+                Box::new(samples.chain(padding)) as Box<dyn Iterator<Item = _>>,
+            )
+        }
+        _ => (sample_count, samples),
+    };
+
+    let (sample_count, samples, truncated) = match options.max_samples {
+        Some(max_samples) if sample_count > max_samples && options.truncate => {
+            // whole frames so the channels stay interleaved
+            let kept = max_samples - max_samples % frame_channels;
+            push_warning(
+                &mut warnings,
+                format!(
+                    "Too many samples ({}), truncating to the first {}.",
+                    sample_count, kept
+                ),
+            );
+            let samples = Box::new(samples.take(kept)) as Box<dyn Iterator<Item = _>>;
+            (kept, samples, true)
+        }
+        Some(max_samples) if sample_count > max_samples => {
+            return Err(WavToCError::InvalidInput(format!(
+                "Too many samples ({}), maximum is {}",
+                sample_count, max_samples
+            )));
+        }
+        _ => (sample_count, samples, false),
+    };
+    // fades end with the truncated array
+    let clip_len = clip_len.min(sample_count / frame_channels);
+    if let Some(max_bytes) = options.max_bytes {
+        // a user type of known width may be wider than the samples need
+        let sample_bytes = options
+            .c_type
+            .and_then(c_type_bits)
+            .filter(|_| options.bytes.is_none())
+            .map_or(bits_per_sample.div_ceil(8).next_power_of_two(), |bits| {
+                bits / 8
+            });
+        let bytes = if options.adpcm {
+            sample_count.div_ceil(2)
+        } else if let Some(bits) = options.pack_bits {
+            (sample_count * bits as usize).div_ceil(8)
+        } else {
+            sample_count * sample_bytes as usize
+        };
+        if bytes > max_bytes {
+            return Err(WavToCError::InvalidInput(format!(
+                "Array is too large ({} bytes), maximum is {}",
+                bytes, max_bytes
+            )));
+        }
+    }
+    let array_name = match options.name_template {
+        Some(template) => &expand_name_template(
+            template,
+            array_name,
+            (spec.sample_rate, spec.channels, bits_per_sample),
+        )?,
+        None => array_name,
+    };
+    let safe_array_name = if options.no_sanitize {
+        if !is_c_identifier(array_name) {
+            return Err(WavToCError::InvalidInput(format!(
+                "Array name '{}' is not a valid C identifier.",
+                array_name
+            )));
+        }
+        array_name.to_string()
+    } else {
+        let safe_name = sanitize_c_identifier(array_name);
+        if safe_name != array_name {
+            push_warning(
+                &mut warnings,
+                format!(
+                    "Array name '{}' is not a valid C identifier, using '{}'.",
+                    array_name, safe_name
+                ),
+            );
+        }
+        safe_name
+    };
+
+    // from env WAV2C_SIZE_TYPE or default to size_t
+    let size_type = std::env::var("WAV2C_SIZE_TYPE").unwrap_or_else(|_| "size_t".to_string());
+
+    let mut meta = WavMetadata {
+        name: safe_array_name,
+        source: wave_file.into_owned(),
+        sample_rate: spec.sample_rate,
+        channels: spec.channels,
+        bits_per_sample,
+        sample_count,
+        frame_channels: frame_channels as u16,
+        c_type,
+        size_type,
+        loop_points: chunks.loop_points().and_then(|(loop_start, loop_end)| {
+            if frames == source_frames {
+                return Some((loop_start, loop_end));
+            }
+            // the loop end is inclusive so it must be a sample of the clip
+            if loop_start as usize >= start && (loop_end as usize) < end {
+                Some((loop_start - start as u32, loop_end - start as u32))
+            } else {
+                push_warning(
+                    &mut warnings,
+                    "Loop points are outside the clip range, dropping them.".to_string(),
+                );
+                None
+            }
+        }),
+        cue_points: {
+            let cue_points = chunks.cue_points();
+            let count = cue_points.len();
+            let cue_points: Vec<_> = cue_points
+                .into_iter()
+                .filter(|(offset, _)| (start..end).contains(&(*offset as usize)))
+                .map(|(offset, label)| (offset - start as u32, label))
+                .collect();
+            if cue_points.len() < count {
+                info!(
+                    "Dropping {} cue points outside the clip range.",
+                    count - cue_points.len()
+                );
+            }
+            cue_points
+        },
+        pitch,
+        onset,
+        info_tags: chunks.info_tags(),
+    };
+    if resample.is_some() {
+        let scale =
+            |frame: u32| (frame as u64 * spec.sample_rate as u64 / source_rate as u64) as u32;
+        meta.loop_points = meta
+            .loop_points
+            .map(|(loop_start, loop_end)| (scale(loop_start), scale(loop_end)));
+        for (offset, _) in &mut meta.cue_points {
+            *offset = scale(*offset);
+        }
+    }
+    if let Some(frames) = reversed_frames.map(|frames| frames as u32) {
+        meta.loop_points = match meta.loop_points {
+            // the loop end is inclusive, so like the cues each point moves to its mirrored sample
+            Some((loop_start, loop_end)) if loop_end < frames => {
+                Some((frames - 1 - loop_end, frames - 1 - loop_start))
+            }
+            Some(_) => {
+                push_warning(
+                    &mut warnings,
+                    "Loop points are past the reversed end, dropping them.".to_string(),
+                );
+                None
+            }
+            None => None,
+        };
+        // each cue moves to its sample in the reversed frames, kept in ascending order
+        meta.cue_points = std::mem::take(&mut meta.cue_points)
+            .into_iter()
+            .rev()
+            .filter(|(offset, _)| *offset < frames)
+            .map(|(offset, label)| (frames - 1 - offset, label))
+            .collect();
+    }
+    // labelled cues are aliased by name, which must not redefine the count or another alias
+    let mut aliases = vec!["COUNT".to_string()];
+    for (_, label) in &mut meta.cue_points {
+        let Some(name) = label.as_deref().filter(|label| !label.is_empty()) else {
+            continue;
+        };
+        let alias = sanitize_c_identifier(name).to_uppercase();
+        if aliases.contains(&alias) {
+            push_warning(
+                &mut warnings,
+                format!(
+                    "Cue label '{}' collides with another cue define, dropping its alias.",
+                    name
+                ),
+            );
+            *label = None;
+        } else {
+            aliases.push(alias);
+        }
+    }
+    if truncated {
+        let kept_frames = meta.frame_count();
+        if meta
+            .loop_points
+            .is_some_and(|(_, loop_end)| loop_end as usize > kept_frames)
+        {
+            push_warning(
+                &mut warnings,
+                "Loop points are past the truncated end, dropping them.".to_string(),
+            );
+            meta.loop_points = None;
+        }
+        meta.cue_points
+            .retain(|(offset, _)| (*offset as usize) < kept_frames);
+        meta.onset = meta.onset.map(|onset| onset.min(kept_frames));
+    }
+
+    let fade_samples =
+        |ms: Option<u32>| (ms.unwrap_or(0) as u64 * spec.sample_rate as u64 / 1000) as usize;
+    let mut processor = transform::SampleProcessor::new(options.gain, spec.bits_per_sample)
+        .fade(
+            fade_samples(options.fade_in_ms),
+            fade_samples(options.fade_out_ms),
+            clip_len,
+            frame_channels,
+        )
+        .requantize(spec.bits_per_sample, bits_per_sample);
+    if options.upscale_replicate {
+        processor = processor.replicate_lsb();
+    }
+    if let Some(hz) = options.highpass_hz {
+        processor = processor.highpass(hz, spec.sample_rate);
+    }
+    if let Some(db) = options.limit_db {
+        processor = processor.limit(db, spec.bits_per_sample);
+    }
+    if let Some(value) = options.clamp_amplitude {
+        processor = processor.clamp_amplitude(value);
+    }
+    if options.dither {
+        processor = processor.dither(options.dither_seed, options.dither_depth);
+    }
+
+    if options.profile {
+        debug!("Profile: setup {:.3?}", started.elapsed());
+    }
+    emitter.begin(&meta);
+    if options.profile {
+        // timed separately so the plain loop pays nothing for the clock reads
+        let (mut decode, mut transform, mut format) =
+            (Duration::ZERO, Duration::ZERO, Duration::ZERO);
+        let mut samples = samples;
+        loop {
+            let start = Instant::now();
+            let Some(sample) = samples.next() else {
+                decode += start.elapsed();
+                break;
+            };
+            let sample = sample?;
+            let decoded = Instant::now();
+            let value = processor.process(sample);
+            let transformed = Instant::now();
+            emitter.sample(value);
+            decode += decoded - start;
+            transform += transformed - decoded;
+            format += transformed.elapsed();
+        }
+        debug!(
+            "Profile: decode {:.3?}, transform {:.3?}, format {:.3?}",
+            decode, transform, format
+        );
+    } else {
+        for sample in samples {
+            emitter.sample(processor.process(sample?));
+        }
+    }
+    emitter.end();
+    for warning in processor.report() {
+        push_warning(&mut warnings, warning);
+    }
+    let (clipped, total) = full_scale.get();
+    if clipped as f64 > total as f64 * INPUT_CLIP_FRACTION {
+        push_warning(
+            &mut warnings,
+            format!(
+                "{} of {} input samples are in runs at full scale, the input may be clipped.",
+                clipped, total
+            ),
+        );
+    }
+
+    Ok(Conversion {
+        meta,
+        warnings: warnings.list,
+    })
+}
+
+/// Log the time since `writing` started flushing the output if profiling
+///
+/// Output the buffer fills while converting is written in the format stage.
+fn log_write_time(options: &WavToCOptions, writing: Instant) {
+    if options.profile {
+        debug!("Profile: write {:.3?}", writing.elapsed());
+    }
+}
+
+/// Convert `wav_path` to C source written to `writer`
+///
+/// Samples are streamed from the file to `writer` unless a transform needs them all.
+pub fn wav_to_c<W: Write>(
+    wav_path: &Path,
+    array_name: &str,
+    options: &WavToCOptions,
+    writer: W,
+) -> Result<(Conversion, W), WavToCError> {
+    let mut emitter = CEmitter::new(options, writer);
+    let conversion = convert(wav_path, array_name, options, &mut emitter)?;
+    let writer = emitter.finish()?;
+
+    Ok((conversion, writer))
+}
+
+/// Emitter for the output language of [`wav_to_c_array`]
+enum OutputEmitter<'a, W: Write> {
+    C(CEmitter<'a, W>),
+    Json(JsonEmitter<W>),
+    Template(template::TemplateEmitter<'a, W>),
+}
+
+impl<'a, W: Write> OutputEmitter<'a, W> {
+    /// Template output if there is one, otherwise `options.lang`
+    fn new(
+        options: &'a WavToCOptions<'a>,
+        template: Option<template::Template>,
+        writer: W,
+    ) -> Self {
+        if let Some(template) = template {
+            return OutputEmitter::Template(template::TemplateEmitter::new(
+                options, template, writer,
+            ));
+        }
+        match options.lang {
+            Lang::C | Lang::Cpp | Lang::Inc => OutputEmitter::C(CEmitter::new(options, writer)),
+            Lang::Json => OutputEmitter::Json(JsonEmitter::new(writer)),
+        }
+    }
+
+    fn finish(self) -> std::io::Result<W> {
+        match self {
+            OutputEmitter::C(emitter) => emitter.finish(),
+            OutputEmitter::Json(emitter) => emitter.finish(),
+            OutputEmitter::Template(emitter) => emitter.finish(),
+        }
+    }
+}
+
+impl<W: Write> SampleEmitter for OutputEmitter<'_, W> {
+    fn begin(&mut self, meta: &WavMetadata) {
+        match self {
+            OutputEmitter::C(emitter) => emitter.begin(meta),
+            OutputEmitter::Json(emitter) => emitter.begin(meta),
+            OutputEmitter::Template(emitter) => emitter.begin(meta),
+        }
+    }
+
+    fn sample(&mut self, value: i64) {
+        match self {
+            OutputEmitter::C(emitter) => emitter.sample(value),
+            OutputEmitter::Json(emitter) => emitter.sample(value),
+            OutputEmitter::Template(emitter) => emitter.sample(value),
+        }
+    }
+
+    fn end(&mut self) {
+        match self {
+            OutputEmitter::C(emitter) => emitter.end(),
+            OutputEmitter::Json(emitter) => emitter.end(),
+            OutputEmitter::Template(emitter) => emitter.end(),
+        }
+    }
+}
+
+/// Emitters observing the samples alongside the output of [`wav_to_c_array`]
+///
+/// Built once and chained after the emitter of whichever output is written.
+struct Observers {
+    checksum: Crc32Emitter,
+    stats: StatsEmitter,
+    verifier: Option<verify::VerifyEmitter>,
+    progress: Option<progress::ProgressEmitter>,
+    preview: Option<preview::PreviewEmitter>,
+    waveform: Option<preview::PreviewEmitter>,
+}
+
+impl Observers {
+    fn chain(&mut self) -> impl SampleEmitter + '_ {
+        (
+            &mut self.checksum,
+            (
+                &mut self.stats,
+                (
+                    &mut self.verifier,
+                    (&mut self.progress, (&mut self.preview, &mut self.waveform)),
+                ),
+            ),
+        )
+    }
+
+    /// Mismatch with the `--verify-against` reference, if any
+    fn verify_error(&self) -> Option<WavToCError> {
+        self.verifier
+            .as_ref()
+            .and_then(|verifier| verifier.mismatch())
+            .map(WavToCError::VerifyFailed)
+    }
+}
+
+impl SampleEmitter for Observers {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.chain().begin(meta);
+    }
+
+    fn sample(&mut self, value: i64) {
+        self.chain().sample(value);
+    }
+
+    fn end(&mut self) {
+        self.chain().end();
+    }
+}
+
+/// Path of the test harness written alongside `output_path`: `<stem>_harness.c`
+pub fn harness_path(output_path: &Path) -> PathBuf {
+    let mut name = output_path.file_stem().unwrap_or_default().to_os_string();
+    name.push("_harness.c");
+    output_path.with_file_name(name)
+}
+
+/// Convert `wav_path` to a C array written to `output_path` or stdout
+pub fn wav_to_c_array(
+    wav_path: &Path,
+    array_name: &str,
+    output_path: Option<&Path>,
+    options: WavToCOptions,
+) -> Result<Conversion, WavToCError> {
+    // parsed before any output is created so a bad template writes nothing
+    let template = options
+        .template
+        .map(template::Template::parse)
+        .transpose()?;
+    if options.verify_compile {
+        if output_path.is_none() {
+            return Err(WavToCError::InvalidInput(
+                "Compile verification requires an output path.".to_string(),
+            ));
+        }
+        if template.is_some() || matches!(options.lang, Lang::Json | Lang::Inc) {
+            return Err(WavToCError::InvalidInput(
+                "Compile verification only supports C and C++ output.".to_string(),
+            ));
+        }
+        if options.dry_run {
+            return Err(WavToCError::InvalidInput(
+                "Compile verification needs the output a dry run does not write.".to_string(),
+            ));
+        }
+    }
+    let compile_check = |source_path: &Path| {
+        if options.verify_compile {
+            compile::check(source_path, options.lang)?;
+            info!("Output compiles: {}", source_path.display());
+        }
+        Ok(())
+    };
+    let verifier = match options.verify_against {
+        Some(reference_path) => {
+            // only the layout must match the conversion, the reference is otherwise as decoded
+            let reference_options = WavToCOptions {
+                stereo_frames: options.stereo_frames,
+                mono_mix: options.mono_mix,
+                ..Default::default()
+            };
+            // the conversion itself warns of the same merge or clipping
+            let mut reference = verify::SampleCollector::default();
+            convert_logged(
+                reference_path,
+                array_name,
+                &reference_options,
+                &mut reference,
+                false,
+            )?;
+            Some(verify::VerifyEmitter::new(
+                reference.0,
+                options.verify_tolerance,
+            ))
+        }
+        None => None,
+    };
+    #[cfg(feature = "waveform-png")]
+    let waveform = options
+        .waveform_png
+        .map(|_| preview::PreviewEmitter::new(waveform_png::WIDTH));
+    #[cfg(not(feature = "waveform-png"))]
+    let waveform = None;
+    let mut observers = Observers {
+        checksum: Crc32Emitter::default(),
+        stats: StatsEmitter::default(),
+        verifier,
+        progress: options.progress.then(progress::ProgressEmitter::default),
+        preview: options.preview.map(preview::PreviewEmitter::new),
+        waveform,
+    };
+
+    let conversion = if options.dry_run {
+        let conversion = convert(wav_path, array_name, &options, &mut observers)?;
+        if let Some(e) = observers.verify_error() {
+            return Err(e);
+        }
+        info!("Dry run, no output written");
+        conversion
+    } else if let Some(part_size) = options.split_files {
+        let Some(output_path) = output_path else {
+            return Err(WavToCError::InvalidInput(
+                "Splitting into files requires an output path.".to_string(),
+            ));
+        };
+        if part_size == 0 {
+            return Err(WavToCError::InvalidInput(
+                "Split size must be at least 1 sample.".to_string(),
+            ));
+        }
+        if template.is_some()
+            || options.lang != Lang::C
+            || options.bytes.is_some()
+            || options.adpcm
+            || options.stereo_frames
+        {
+            return Err(WavToCError::InvalidInput(
+                "Splitting into files only supports plain C sample arrays.".to_string(),
+            ));
+        }
+        // the master header has the defines so the parts only hold the arrays
+        let part_options = WavToCOptions {
+            no_define: true,
+            ..options.clone()
+        };
+        let mut emitter = split::SplitEmitter::new(&part_options, output_path, part_size);
+        let result = convert(
+            wav_path,
+            array_name,
+            &options,
+            &mut (&mut emitter, &mut observers),
+        )
+        .and_then(|conversion| {
+            if conversion.meta.sample_count == 0 {
+                return Err(WavToCError::InvalidInput(
+                    "No samples to split into files.".to_string(),
+                ));
+            }
+            observers.verify_error().map_or(Ok(conversion), Err)
+        });
+        let (conversion, parts) = emitter.finish(result, compile_check)?;
+        for (part_path, _) in &parts {
+            info!("Part written to: {}", part_path.display());
+        }
+        let header_path = output_path.with_extension("h");
+        let part_crcs: Vec<u32> = parts.iter().map(|(_, crc32)| *crc32).collect();
+        split::write_header(
+            &header_path,
+            &conversion.meta,
+            &part_crcs,
+            part_size,
+            &options,
+            options.checksum.then(|| observers.checksum.crc32()),
+        )?;
+        info!("Header written to: {}", header_path.display());
+        conversion
+    } else if let Some(output_path) = output_path {
+        // stream to a temporary file so a failed conversion leaves any existing output intact
+        let mut temp_name = output_path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = output_path.with_file_name(temp_name);
+        let mut emitter = OutputEmitter::new(
+            &options,
+            template,
+            post_process::PostProcessWriter::new(
+                options.post_process,
+                BufWriter::new(File::create(&temp_path)?),
+            ),
+        );
+        let conversion = match convert(
+            wav_path,
+            array_name,
+            &options,
+            &mut (&mut emitter, &mut observers),
+        )
+        .and_then(|conversion| {
+            let writing = Instant::now();
+            emitter.finish()?.finish()?;
+            log_write_time(&options, writing);
+            observers.verify_error().map_or(Ok(conversion), Err)
+        })
+        .and_then(|conversion| compile_check(&temp_path).map(|_| conversion))
+        {
+            Ok(conversion) => conversion,
+            Err(e) => {
+                let _ = std::fs::remove_file(&temp_path);
+                return Err(e);
+            }
+        };
+        std::fs::rename(&temp_path, output_path)?;
+        info!("Output written to: {}", output_path.display());
+        let meta = &conversion.meta;
+        if options.header {
+            let header_path = output_path.with_extension("h");
+            let crc32 = options.checksum.then(|| observers.checksum.crc32());
+            write_header(&header_path, &meta.name, meta, &options, crc32)?;
+            info!("Header written to: {}", header_path.display());
+        }
+        if options.test_harness {
+            let harness_path = harness_path(output_path);
+            let include_name = output_path.file_name().unwrap().to_string_lossy();
+            harness::write_harness(
+                &harness_path,
+                &include_name,
+                meta,
+                observers.checksum.crc32(),
+            )?;
+            info!("Test harness written to: {}", harness_path.display());
+        }
+        conversion
+    } else {
+        let mut emitter = OutputEmitter::new(
+            &options,
+            template,
+            post_process::PostProcessWriter::new(
+                options.post_process,
+                BufWriter::new(std::io::stdout().lock()),
+            ),
+        );
+        let conversion = convert(
+            wav_path,
+            array_name,
+            &options,
+            &mut (&mut emitter, &mut observers),
+        )?;
+        let writing = Instant::now();
+        let mut stdout = emitter.finish()?.finish()?;
+        writeln!(stdout)?;
+        stdout.flush()?;
+        log_write_time(&options, writing);
+        if let Some(e) = observers.verify_error() {
+            return Err(e);
+        }
+        conversion
+    };
+
+    if let Some(preview) = &observers.preview {
+        eprintln!("{}", preview);
+    }
+    #[cfg(feature = "waveform-png")]
+    if let (Some(path), Some(waveform)) = (options.waveform_png, &observers.waveform) {
+        if options.dry_run {
+            info!("Dry run, no waveform written");
+        } else {
+            waveform_png::write(path, waveform)?;
+            info!("Waveform written to: {}", path.display());
+        }
+    }
+    if options.stats {
+        eprintln!("{}", observers.stats);
+    }
+
+    Ok(conversion)
+}
@@ -1,92 +1,91 @@
 //! Convert a .wav file to a C array for use in embedded systems.
-use clap::{Parser, ValueEnum};
-use log::{info, warn, LevelFilter};
-use std::error::Error;
-use std::fmt;
-use std::path::{Path, PathBuf};
-
-/// Maximum number of samples to process to prevent massive arrays
-const MAX_SAMPLES: usize = 220_000;
-/// Samples per line in the output C array for formatting
-const SAMPLES_PER_LINE: usize = 8;
-
-/// Error type for the application
-enum WavToCError {
-    /// std:io error
-    IoError(std::io::Error),
-    /// Hound wav decoding error
-    HoundError(hound::Error),
-    /// Incompatible input file
-    InvalidInput(String),
-    /// Output file already exists
-    OutputExists(PathBuf),
-}
-
-/// Format for the output array values
-#[derive(Debug, Default, Clone, ValueEnum)]
-enum ArrayFormat {
-    #[default]
-    /// Signed integers in base 10
-    Base10,
-    /// Signed integers in hexadecimal
-    Base16,
-}
-
-impl fmt::Display for WavToCError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match self {
-            WavToCError::IoError(e) => write!(f, "IO error: {}", e),
-            WavToCError::HoundError(e) => write!(f, "Hound error: {}", e),
-            WavToCError::InvalidInput(e) => write!(f, "Invalid input: {}", e),
-            WavToCError::OutputExists(p) => {
-                write!(f, "Output file already exists: {}", p.display())
-            }
-        }
-    }
-}
-
-impl fmt::Debug for WavToCError {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{}", self)
-    }
-}
-
-impl Error for WavToCError {}
-
-impl From<std::io::Error> for WavToCError {
-    fn from(err: std::io::Error) -> Self {
-        WavToCError::IoError(err)
-    }
-}
-
-impl From<hound::Error> for WavToCError {
-    fn from(err: hound::Error) -> Self {
-        WavToCError::HoundError(err)
-    }
-}
+use clap::{CommandFactory, Parser, ValueEnum};
+#[cfg(feature = "cli")]
+use log::LevelFilter;
+use std::io::IsTerminal;
+use std::path::PathBuf;
+use std::process::ExitCode;
+use wav2c::{
+    harness_path, is_stdin, part_path, wav_info, wav_to_c_array, ArrayFormat, ByteOrder,
+    ClipPosition, CommentField, Lang, LengthStyle, MonoMix, WavToCError, WavToCOptions,
+    MAX_SAMPLES,
+};
 
 #[derive(Parser, Debug)]
 #[command(author, version, about, long_about = None)]
+#[command(group(clap::ArgGroup::new("destination").args(["output", "output_dir"])))]
+#[command(group(clap::ArgGroup::new("header_file").args(["header", "split_files"]).multiple(true)))]
 struct Args {
-    /// Path to the input .wav file
+    /// Path to the input .wav file, or `-` to read from stdin
     ///
     /// Use ffmpeg or other to convert other formats to .wav. For example:
     /// `ffmpeg -i input.m4a -ar 22050 -ac 1 -sample_fmt s16 output.wav`; mono
     /// 16-bit 22.05kHz audio.
-    input: PathBuf,
+    #[arg(required_unless_present = "list_formats")]
+    input: Option<PathBuf>,
 
     /// Name of the array (optional, defaults to the input file name without extension)
     #[arg(short, long)]
     array_name: Option<String>,
 
+    /// Build the array name from `{stem}`, `{rate}`, `{channels}` and `{bits}`
+    ///
+    /// For example `{stem}_{rate}` names the array `bell_22050` for many
+    /// rates of one sound. It takes the place of `--array-name`, which if given
+    /// is the `{stem}` rather than the output or input file name. `{bits}` is
+    /// the depth of the emitted samples. The name is sanitized as any other.
+    #[arg(long, value_name = "STR")]
+    name_template: Option<String>,
+
     /// Path to the output file (optional, defaults to stdout)
     #[arg(short, long)]
     output: Option<PathBuf>,
 
+    /// Directory to write `<stem>.c` to, named after the input and created if needed
+    ///
+    /// The extension is `.hpp` for `--lang cpp`, `.json` for `--lang json`
+    /// and `.inc` for `--lang inc`.
+    /// Reading from stdin names the file `audio`.
+    #[arg(long, value_name = "DIR")]
+    output_dir: Option<PathBuf>,
+
+    /// Error if the array name is not a valid C identifier instead of sanitizing it
+    #[arg(long)]
+    no_sanitize: bool,
+
+    /// C type of the array elements, e.g. `short` or a project typedef like `q15_t`
+    ///
+    /// Overrides the type chosen for the bit depth and the WAV2C_I*_TYPE
+    /// environment variables. Warns if a known type is too narrow for the samples.
+    /// The `--checksum` and `--emit-test-harness` CRC-32 and `--parity` bytes
+    /// are of elements of its width, or of the sample width if it is unknown.
+    #[arg(long)]
+    ctype: Option<String>,
+
     /// Number format for the output array
     #[arg(short = 'F', long, value_enum, default_value_t = ArrayFormat::Base10)]
     format: ArrayFormat,
 
+    /// Language or format of the output
+    ///
+    /// `json` writes an object with `name`, `sample_rate`, `channels`, `bits`
+    /// and the `samples` after any transforms, `channels` being the number
+    /// interleaved in `samples`. `cpp` writes a C++17 `inline constexpr
+    /// std::array` named `samples`, in a namespace of the array name, that can
+    /// be included from many sources, the defines of C being constants such as
+    /// `SAMPLE_NO` and `CUE_0` in the namespace. `inc` writes only the `{ ... }`
+    /// initializer, for `#include` inside a declaration of your own.
+    #[arg(long, value_enum, default_value_t = Lang::C, conflicts_with_all = ["header", "emit_test_harness", "bytes", "adpcm", "wrap_progmem_accessor"])]
+    lang: Lang,
+
+    /// Write a template file with `{{field}}` placeholders instead of C
+    ///
+    /// Placeholders are `{{name}}`, `{{type}}`, `{{count}}`, `{{sample_rate}}`,
+    /// `{{channels}}`, `{{bits}}` and `{{samples}}`, the samples comma
+    /// separated in the `--format`. Write `\{{` for a literal `{{`.
+    #[arg(long, value_name = "PATH", conflicts_with_all = ["lang", "header", "emit_test_harness", "bytes", "adpcm"])]
+    template: Option<PathBuf>,
+
     /// Max samples to sanity check the array size
     ///
     /// 220,000 samples of 16 bit 44.1kHz audio is about 5 seconds/440 kB. For
@@ -95,10 +94,512 @@ struct Args {
     #[arg(short, long, default_value_t = MAX_SAMPLES)]
     max_samples: usize,
 
+    /// Keep the first `--max-samples` samples with a warning rather than failing
+    ///
+    /// Whole frames are kept, so for stereo the count is rounded down to even.
+    /// Loop and cue points past the new end are dropped.
+    #[arg(long, conflicts_with_all = ["fft_ready", "block_size"])]
+    truncate: bool,
+
+    /// Max size of the array in bytes, after all transforms, to fit a flash budget
+    ///
+    /// Independent of `--max-samples`, the size depends on the type of the array.
+    #[arg(long, value_name = "N")]
+    max_bytes: Option<usize>,
+
+    /// Start of the range to convert in seconds
+    #[arg(long, value_name = "SECS", conflicts_with = "start_sample")]
+    start: Option<f64>,
+
+    /// End of the range to convert in seconds, exclusive
+    #[arg(long, value_name = "SECS", conflicts_with = "end_sample")]
+    end: Option<f64>,
+
+    /// Start of the range to convert as a frame offset
+    #[arg(long, value_name = "SAMPLE")]
+    start_sample: Option<usize>,
+
+    /// End of the range to convert as a frame offset, exclusive
+    #[arg(long, value_name = "SAMPLE")]
+    end_sample: Option<usize>,
+
+    /// Resample the clip to a new rate in Hz, e.g. 8000, by linear interpolation
+    ///
+    /// Downsampling first low-passes each channel below the new Nyquist
+    /// frequency so higher content does not alias into the band. Loop and cue
+    /// points are scaled to the new rate.
+    #[arg(long, value_name = "HZ")]
+    resample: Option<u32>,
+
+    /// Skip the anti-alias low-pass before downsampling with `--resample`
+    #[arg(long, requires = "resample")]
+    no_antialias: bool,
+
+    /// Scale samples by a factor, e.g. 0.5 to halve or 2.0 to double
+    ///
+    /// Samples saturate at the bounds of the array type rather than wrapping.
+    #[arg(long)]
+    gain: Option<f64>,
+
+    /// High-pass filter the samples at HZ to remove rumble, e.g. 30
+    ///
+    /// A 2nd order Butterworth biquad applied to each channel before `--gain`,
+    /// which also removes any DC offset once it settles.
+    #[arg(long, value_name = "HZ")]
+    highpass: Option<f64>,
+
+    /// Soft limit the samples after `--gain` to a ceiling in dBFS, e.g. -1
+    ///
+    /// Samples up to half the ceiling are unchanged and louder samples are
+    /// compressed along a tanh curve towards it rather than clipping.
+    #[arg(long, value_name = "DB", allow_negative_numbers = true)]
+    limit: Option<f64>,
+
+    /// Hard clamp the samples to plus or minus VALUE for a safe DAC range
+    ///
+    /// In units of the emitted samples, after every other transform, with a
+    /// warning counting the samples clamped. Unlike `--limit` the samples
+    /// below VALUE are untouched.
+    #[arg(long, value_name = "VALUE")]
+    clamp_amplitude: Option<u32>,
+
+    /// Requantize the samples to N bits, e.g. 8 for an 8-bit DAC
+    ///
+    /// Samples are rounded to the nearest step after any other transforms and
+    /// the array type follows the new depth. N must not exceed the source depth.
+    #[arg(long, value_name = "N")]
+    bit_depth: Option<u16>,
+
+    /// Left shift the samples up to N bits, e.g. 16 for a DAC wanting 16-bit data from 8-bit audio
+    ///
+    /// The array type follows the new depth. N must be above the source depth
+    /// and at most 32. The low bits are zero unless `--replicate-lsb`.
+    #[arg(long, value_name = "N", conflicts_with = "bit_depth")]
+    upscale: Option<u16>,
+
+    /// Fill the bits vacated by `--upscale` by repeating the sample bits
+    ///
+    /// The maximum then comes closer to the full scale of the new depth, 127
+    /// becoming 32639 (0x7f7f) rather than 32512 from 8 to 16 bits, while zero
+    /// stays zero.
+    #[arg(long, requires = "upscale")]
+    replicate_lsb: bool,
+
+    /// Add triangular (TPDF) dither of +-1 LSB when reducing the bit depth
+    ///
+    /// The noise is pseudo-random from `--dither-seed`, so the output is the
+    /// same each run for a given seed.
+    #[arg(long, requires = "bit_depth")]
+    dither: bool,
+
+    /// Seed of the dither noise
+    #[arg(long, default_value_t = 0, requires = "dither")]
+    dither_seed: u64,
+
+    /// Span the dither noise over N low bits of the source samples
+    ///
+    /// Defaults to the bits dropped by `--bit-depth`, +-1 LSB of the reduced
+    /// depth, which is usually right. More bits over-dither, masking more of
+    /// the quantization error with louder noise, and fewer under-dither. N is
+    /// from 1 to the source depth.
+    #[arg(long, value_name = "N", requires = "dither")]
+    dither_depth: Option<u16>,
+
+    /// Keep stereo input as an array of `{l, r}` frame structs
+    ///
+    /// Emits a `<name>_frame_t` typedef and a `<NAME>_FRAME_NO` frame count
+    /// for engines indexing frames rather than interleaved samples. Mono
+    /// input is emitted as a flat array.
+    #[arg(
+        long,
+        conflicts_with_all = ["bytes", "adpcm", "emit_test_harness", "wrap_progmem_accessor"]
+    )]
+    group_stereo_as_struct_array: bool,
+
+    /// Remove a constant DC bias by subtracting the mean of the samples
+    #[arg(long)]
+    remove_dc: bool,
+
+    /// Scale the stereo side (L-R) by this factor before the downmix
+    ///
+    /// 0 collapses to the mid, 1 is unchanged and greater than 1 widens. Left
+    /// and right are rebuilt from the adjusted mid/side and clipped to the
+    /// sample type.
+    #[arg(long, value_name = "FACTOR")]
+    stereo_width: Option<f64>,
+
+    /// Convert stereo to mid (L+R)/2 and side (L-R)/2 arrays, `<name>_mid` and `<name>_side`
+    ///
+    /// Each array holds a sample per frame, `<NAME>_SAMPLE_NO` being the
+    /// length of either, for mid/side effects rather than L/R or mono. The
+    /// input must be stereo.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "group_stereo_as_struct_array",
+            "lang",
+            "template",
+            "bytes",
+            "adpcm",
+            "raw_bytes_only",
+            "split_files",
+            "emit_test_harness",
+            "wrap_progmem_accessor",
+            "bank_entry",
+            "checksum",
+            "verify_against",
+        ]
+    )]
+    stereo_to_ms: bool,
+
+    /// How stereo or multichannel input is merged to mono
+    ///
+    /// `sum-clip` adds the channels and saturates at the sample type, for
+    /// sources where each channel is recorded at half level.
+    #[arg(long, value_enum, value_name = "MODE", default_value_t = MonoMix::Average)]
+    mono_mix: MonoMix,
+
+    /// Align the array to N bytes with `__attribute__((aligned(N)))`
+    ///
+    /// For DMA transfers requiring aligned buffers. N must be a power of two.
+    /// Combines with `--wrap-progmem-accessor` placement.
+    #[arg(long, value_name = "N")]
+    align: Option<usize>,
+
+    /// Emit the samples in reverse order for reversed playback
+    ///
+    /// Any loop range and cue points are mirrored to the same samples in the
+    /// reversed array.
+    #[arg(long)]
+    reverse: bool,
+
+    /// Tile the clip N times, for sustained test tones or loops baked into flash
+    ///
+    /// The sample count and `--max-samples` are of the repeated array, and
+    /// the fades are of its start and end.
+    #[arg(long, value_name = "N")]
+    repeat: Option<usize>,
+
+    /// Fade in from silence over this many milliseconds to avoid a click on start
+    #[arg(long, value_name = "MS")]
+    fade_in: Option<u32>,
+
+    /// Fade out to silence over this many milliseconds to avoid a click on stop
+    #[arg(long, value_name = "MS")]
+    fade_out: Option<u32>,
+
+    /// Circularly rotate the samples so the array starts at the peak amplitude
+    ///
+    /// Only makes sense for looped content such as single cycle wavetables,
+    /// since the length is preserved but the start moves.
+    #[arg(long, visible_alias = "peak-align")]
+    rotate_to_peak: bool,
+
+    /// Pack as many values as fit in this many bytes per array line
+    ///
+    /// Accounts for the rendered width of each value, including the
+    /// indentation, rather than wrapping at a fixed number of samples.
+    #[arg(long)]
+    sample_limit_per_line_bytes: Option<usize>,
+
+    /// Pad a short final array line with spaces to the width of the others
+    ///
+    /// With fixed width values, such as `--format base16`, the array stays a
+    /// rectangular block for tools parsing a fixed layout.
+    #[arg(long)]
+    last_line_pad: bool,
+
+    /// Omit the comma after the last array element
+    ///
+    /// For strict or old compilers and linters that reject a trailing comma in
+    /// an initializer.
+    #[arg(long)]
+    no_trailing_comma: bool,
+
+    /// Right align each value to at least N characters so the columns line up
+    ///
+    /// Base 16 values are already zero padded to the width of the type.
+    #[arg(long, value_name = "N")]
+    pad_width: Option<usize>,
+
+    /// Indentation of the array lines, spaces or tabs, default a tab
+    #[arg(
+        long,
+        value_name = "STR",
+        default_value = "\t",
+        hide_default_value = true
+    )]
+    indent: String,
+
+    /// Emit a `uint8_t` array of each sample's bytes in this order
+    ///
+    /// For DMA fed peripherals wanting raw bytes. A `<NAME>_BYTE_NO` count is
+    /// added alongside `<NAME>_SAMPLE_NO`.
+    #[arg(long, value_enum, conflicts_with = "emit_test_harness")]
+    bytes: Option<ByteOrder>,
+
+    /// Emit offset binary samples in the unsigned type for R-2R DACs
+    ///
+    /// The most negative sample is zero and silence is the midpoint, such as
+    /// 0x8000 in a `uint16_t` array of 16-bit samples. Samples of a depth
+    /// below the type, such as `--bit-depth 12`, are offset by their own
+    /// midpoint.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "bytes",
+            "raw_bytes_only",
+            "adpcm",
+            "template",
+            "emit_test_harness",
+            "checksum",
+            "byte_swap",
+        ]
+    )]
+    offset_binary: bool,
+
+    /// Swap the byte order of each value in the typed array
+    ///
+    /// For peripherals expecting samples of the other endianness in memory:
+    /// 16-bit values swap their two bytes and 32-bit values reverse all four.
+    /// Unlike `--bytes` the array keeps the sample type.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "bytes",
+            "raw_bytes_only",
+            "adpcm",
+            "template",
+            "emit_test_harness",
+            "checksum",
+        ]
+    )]
+    byte_swap: bool,
+
+    /// Write only the comma separated bytes of the samples, for pasting into an initializer
+    ///
+    /// A C fallback for `.incbin`: no comment, defines, declaration or braces.
+    /// The bytes are little endian unless `--bytes` sets the order.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "lang",
+            "template",
+            "header",
+            "emit_test_harness",
+            "split_files",
+            "adpcm",
+            "group_stereo_as_struct_array",
+            "wrap_progmem_accessor",
+            "comment_fields",
+            "prefix",
+            "prefix_file",
+            "suffix",
+            "suffix_file",
+            "verify",
+        ]
+    )]
+    raw_bytes_only: bool,
+
+    /// Zero pad the samples up to the next power of two for on-device FFTs
+    ///
+    /// For CMSIS-DSP `arm_rfft` and similar. `<NAME>_FFT_SIZE` is defined as
+    /// the padded length, which `<NAME>_SAMPLE_NO` also includes.
+    #[arg(long)]
+    fft_ready: bool,
+
+    /// Zero pad the frames to a multiple of N for block based audio callbacks
+    ///
+    /// `<NAME>_BLOCK_SIZE` and `<NAME>_BLOCK_COUNT` are defined, the last
+    /// block being padded with silence. The array itself is not split.
+    #[arg(long, value_name = "N")]
+    block_size: Option<usize>,
+
+    /// Print waveform statistics to stderr: peak, RMS, DC offset and clipping
+    ///
+    /// Statistics are of the emitted samples, so after any gain or fades.
+    #[arg(long)]
+    stats: bool,
+
+    /// Plot the emitted waveform as ASCII art on stderr
+    ///
+    /// The samples are decimated to the terminal width from `$COLUMNS`,
+    /// default 80, each column spanning the range of its samples.
+    #[arg(long)]
+    preview: bool,
+
+    /// Render the emitted waveform to a PNG at PATH as a visual reference
+    ///
+    /// The samples are decimated to at most 800 columns, each spanning the range of
+    /// its samples as with `--preview`. Nothing is written on a dry run.
+    #[cfg(feature = "waveform-png")]
+    #[arg(long, value_name = "PATH")]
+    waveform_png: Option<PathBuf>,
+
+    /// Convert without writing any output
+    ///
+    /// For checking an input with `--preview`, `--stats` or
+    /// `--verify-against`. The output path is still used for the array name.
+    #[arg(long, conflicts_with = "verify")]
+    dry_run: bool,
+
+    /// Time the decode, transform, format and write stages
+    ///
+    /// The times are logged at debug level, so shown with `-vv`.
+    #[arg(long)]
+    profile: bool,
+
+    /// Fail unless the emitted samples match this WAV decoded without transforms
+    ///
+    /// A regression gate for asset pipelines. The output file is not written
+    /// if verification fails.
+    #[arg(long, value_name = "REFERENCE")]
+    verify_against: Option<PathBuf>,
+
+    /// Compile the output with `$CC` (default `cc`) before writing it, failing if it does not build
+    ///
+    /// C++ output from `--lang cpp` uses `$CXX` (default `c++`). Output for
+    /// another toolchain, such as with PROGMEM, needs the variable set to its
+    /// compiler. Nothing is written if compilation fails.
+    #[arg(
+        long,
+        visible_alias = "verify-compile",
+        requires = "destination",
+        conflicts_with = "template"
+    )]
+    verify: bool,
+
+    /// Pipe the output and header through a shell command before writing
+    ///
+    /// The command receives the C on stdin and its stdout is written instead,
+    /// e.g. `clang-format`. Nothing is written if the command fails.
+    #[arg(long, value_name = "CMD")]
+    post_process: Option<String>,
+
+    /// Largest difference from the reference samples allowed by `--verify-against`
+    #[arg(long, default_value_t = 0, requires = "verify_against")]
+    verify_tolerance: u64,
+
+    /// Encode as IMA ADPCM and emit a packed `uint8_t` array, two samples per byte
+    ///
+    /// Quarters the size of 16-bit samples for speech on small targets.
+    /// `<NAME>_PREDICTOR` and `<NAME>_STEP_INDEX` defines hold the initial
+    /// decoder state and the comment describes the decode.
+    #[arg(long, conflicts_with_all = ["bytes", "ctype", "emit_test_harness"])]
+    adpcm: bool,
+
+    /// Pack the low N bits of each sample tightly into a `uint8_t` array
+    ///
+    /// For ADC depths such as 12 bits stored in 16-bit containers, two
+    /// 12-bit samples taking three bytes. Samples are packed least significant
+    /// bit first and the comment describes the unpacking. `<NAME>_PACK_BITS`
+    /// and `<NAME>_BYTE_NO` are defined. N is at least the sample depth, so
+    /// requantize deeper input with `--bit-depth`.
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = [
+            "lang",
+            "template",
+            "bytes",
+            "adpcm",
+            "ctype",
+            "raw_bytes_only",
+            "group_stereo_as_struct_array",
+            "stereo_to_ms",
+            "offset_binary",
+            "byte_swap",
+            "parity",
+            "checksum",
+            "emit_test_harness",
+            "split_files",
+        ]
+    )]
+    pack_bits: Option<u32>,
+
+    /// Split the array into parts of N samples, each in a `<stem>_part<i>.c`
+    ///
+    /// For compilers that cannot handle one huge initializer. The header is
+    /// always written, declaring the parts with `<NAME>_PART_SIZE` and
+    /// `<NAME>_PART_COUNT` defines and a `<name>_concat(dest)` helper that
+    /// copies them in order. `<NAME>_SAMPLE_NO` remains the total.
+    #[arg(
+        long,
+        value_name = "N",
+        requires = "destination",
+        conflicts_with_all = [
+            "lang",
+            "template",
+            "bytes",
+            "adpcm",
+            "group_stereo_as_struct_array",
+            "wrap_progmem_accessor",
+            "emit_test_harness",
+        ]
+    )]
+    split_files: Option<usize>,
+
     /// Do not include a comment with the file information
     #[arg(short, long)]
     no_comment: bool,
 
+    /// Standard fields of the comment to include, comma separated, default all
+    ///
+    /// Lines from `--comment-extra`, `--record-command`, `--detect-pitch` and
+    /// `--highpass` are not affected.
+    #[arg(
+        long,
+        value_enum,
+        value_delimiter = ',',
+        value_name = "LIST",
+        conflicts_with = "no_comment"
+    )]
+    comment_fields: Option<Vec<CommentField>>,
+
+    /// Do not include the sample count and metadata defines
+    #[arg(long)]
+    no_define: bool,
+
+    /// Define `<NAME>_DURATION_MS` with the length in milliseconds, rounded
+    #[arg(long)]
+    emit_length_in_ms: bool,
+
+    /// Estimate the fundamental frequency by autocorrelation
+    ///
+    /// Recorded with a confidence in the comment and as `<NAME>_PITCH_HZ`,
+    /// which is 0 for input that is not tonal enough to tell.
+    #[arg(long)]
+    detect_pitch: bool,
+
+    /// Define `<NAME>_ONSET` as the first frame louder than DBFS, default -40
+    ///
+    /// The array is not modified, so firmware can skip leading silence or
+    /// align sounds. The onset is the frame count if nothing is louder.
+    #[arg(
+        long,
+        value_name = "DBFS",
+        num_args = 0..=1,
+        default_missing_value = "-40",
+        allow_negative_numbers = true
+    )]
+    mark_onset: Option<f64>,
+
+    /// Define the sample count as a `const` variable or a `#define` macro
+    ///
+    /// `sizeof` defines it as `(sizeof(name) / sizeof(name[0]))` so it cannot
+    /// drift from the data, repeated as a number in the header where the array
+    /// is incomplete.
+    #[arg(long, value_enum, default_value_t = LengthStyle::Const)]
+    length_style: LengthStyle,
+
+    /// Add a line to the end of the comment, e.g. author or SPDX license (repeatable)
+    #[arg(long)]
+    comment_extra: Vec<String>,
+
+    /// Record the command line used in the comment so the file can be regenerated
+    #[arg(long)]
+    record_command: bool,
+
     /// File to read and write to the output file before the array
     #[arg(short = 'P', long, conflicts_with = "prefix")]
     prefix_file: Option<PathBuf>,
@@ -107,10 +608,122 @@ struct Args {
     #[arg(short, long, conflicts_with = "prefix_file")]
     prefix: Option<String>,
 
+    /// File to read and write to the output file after the array
+    #[arg(long, conflicts_with = "suffix")]
+    suffix_file: Option<PathBuf>,
+
+    /// String to append to the output file after the array
+    #[arg(long, conflicts_with = "suffix_file")]
+    suffix: Option<String>,
+
     /// Include header file in output with extern declarations
-    #[arg(short = 'H', long, requires = "output")]
+    #[arg(short = 'H', long, requires = "destination")]
     header: bool,
 
+    /// Define `<NAME>_BANK_ENTRY` as a designated initializer of a sound bank entry
+    ///
+    /// `{ .name = "<name>", .rate = <rate>, .len = <len>, .data = <name> }`
+    /// after the array, and in the header, for a central table of a struct
+    /// with those fields: `const struct sound bank[] = { A_BANK_ENTRY, ... };`.
+    /// `.len` is in elements of the array.
+    #[arg(long, conflicts_with_all = ["lang", "template", "raw_bytes_only", "split_files"])]
+    bank_entry: bool,
+
+    /// Separate the output sections with `/* ===== NAME ===== */` banner comments
+    ///
+    /// Banners go before the `--prefix`, the defines, the array and the
+    /// `--suffix`, for easier reading of dense output.
+    #[arg(long, conflicts_with_all = ["template", "raw_bytes_only"])]
+    banners: bool,
+
+    /// Leave the braces off `--lang inc` output so only the values are written
+    #[arg(long)]
+    no_braces: bool,
+
+    /// Write everything to one include guarded `.h` for header-only libraries
+    ///
+    /// The array and lengths are `static const` so the header can be included
+    /// from many translation units without duplicate symbols, each getting
+    /// its own copy. `--output-dir` names the file `<stem>.h`.
+    #[arg(
+        long,
+        conflicts_with_all = [
+            "lang",
+            "template",
+            "header",
+            "emit_test_harness",
+            "split_files",
+            "raw_bytes_only",
+        ]
+    )]
+    single_header: bool,
+
+    /// Wrap the header declarations in `extern "C"` for inclusion from C++
+    #[arg(long, requires = "header")]
+    extern_c: bool,
+
+    /// Drop the `const` qualifier so the array can be written at runtime
+    ///
+    /// A const array is placed in `.rodata`, which usually stays in flash. A
+    /// mutable array is placed in `.data` so it occupies RAM as well as flash
+    /// for the initial values copied at startup. Combines with `--align`.
+    #[arg(long, conflicts_with = "wrap_progmem_accessor")]
+    mutable: bool,
+
+    /// Place the array in AVR PROGMEM and define a `<NAME>_GET(i)` accessor macro
+    ///
+    /// The macro expands to the `pgm_read_*` call matching the sample width so
+    /// user code can read samples with it on both PROGMEM and RAM targets.
+    /// Requires `avr/pgmspace.h` to be included, for example with `--prefix`.
+    #[arg(long)]
+    wrap_progmem_accessor: bool,
+
+    /// Write a `<stem>_harness.c` test program alongside the output
+    ///
+    /// The harness includes the output, iterates the array and prints its
+    /// CRC-32, exiting non-zero if it does not match the converted data.
+    #[arg(long, requires = "destination")]
+    emit_test_harness: bool,
+
+    /// Define `<NAME>_VERSION` as N so firmware can reject stale assets
+    #[arg(long, value_name = "N")]
+    asset_version: Option<u32>,
+
+    /// Define `<NAME>_CRC32` of the samples in the header
+    ///
+    /// The CRC-32 is of the samples as little endian bytes of the array type,
+    /// as checked by `--emit-test-harness`. A comment describes it with any
+    /// `--asset-version`. With `--split-files` the header also has a
+    /// `<name>_chunk_crc[]` table of the CRC-32 of each part. Byte and ADPCM
+    /// arrays are not samples so cannot be checked.
+    #[arg(
+        long,
+        requires = "header_file",
+        conflicts_with_all = ["bytes", "adpcm"]
+    )]
+    checksum: bool,
+
+    /// Emit a `<name>_parity` array with a parity byte per N samples
+    ///
+    /// Each byte is the XOR of the little endian bytes of its block of samples
+    /// in the array type, for detecting bit flips on target by recomputing it.
+    /// `<NAME>_PARITY_BLOCK` and `<NAME>_PARITY_NO` define the block size and
+    /// byte count.
+    #[arg(
+        long,
+        value_name = "N",
+        conflicts_with_all = [
+            "lang",
+            "template",
+            "adpcm",
+            "offset_binary",
+            "raw_bytes_only",
+            "split_files",
+            "stereo_to_ms",
+        ]
+    )]
+    parity: Option<usize>,
+
     /// Overwrite if the output file exists
     #[arg(short, long)]
     force: bool,
@@ -118,10 +731,70 @@ struct Args {
     /// Enable verbose output (can be repeated for more verbosity)
     #[arg(short, long, action = clap::ArgAction::Count)]
     verbose: u8,
+
+    /// Only log errors, and do not show a progress bar or check the input for clipping
+    ///
+    /// Info and warnings such as channels merged to mono are not logged even
+    /// if `RUST_LOG` enables them. The bar is drawn on stderr for large inputs
+    /// when it is a terminal. The clipping check warns when many input samples
+    /// are at full scale. Conflicts with the stderr output of `--stats` and
+    /// `--preview`.
+    #[arg(short, long, conflicts_with_all = ["verbose", "stats", "preview"])]
+    quiet: bool,
+
+    /// Print the WAV header details and exit without converting
+    #[arg(long)]
+    info: bool,
+
+    /// Print the supported `--lang` values, `--format` bases and compression modes and exit
+    #[arg(long)]
+    list_formats: bool,
+}
+
+/// One `  name  description` line per possible value of `T`, with the names aligned
+fn value_lines<T: ValueEnum>() -> String {
+    let values: Vec<_> = T::value_variants()
+        .iter()
+        .filter_map(ValueEnum::to_possible_value)
+        .collect();
+    let width = values.iter().map(|v| v.get_name().len()).max().unwrap_or(0);
+    values
+        .iter()
+        .map(|value| {
+            let help = value
+                .get_help()
+                .map(ToString::to_string)
+                .unwrap_or_default();
+            format!("  {:width$}  {}\n", value.get_name(), help, width = width)
+        })
+        .collect()
+}
+
+/// Listing of the output languages, number formats and compression modes for `--list-formats`
+fn list_formats() -> String {
+    let command = Args::command();
+    let compression: String = ["adpcm"]
+        .into_iter()
+        .filter_map(|id| command.get_arguments().find(|arg| arg.get_id() == id))
+        .map(|arg| {
+            let help = arg.get_help().map(ToString::to_string).unwrap_or_default();
+            format!("  --{}  {}\n", arg.get_long().unwrap_or_default(), help)
+        })
+        .collect();
+    format!(
+        "Languages (--lang):\n{}\nNumber formats (--format):\n{}\nCompression:\n{}",
+        value_lines::<Lang>(),
+        value_lines::<ArrayFormat>(),
+        compression
+    )
 }
 
-fn setup_logging(verbose: u8) {
+#[cfg(feature = "cli")]
+fn setup_logging(verbose: u8, quiet: bool) {
     match verbose {
+        _ if quiet => env_logger::Builder::new()
+            .filter_level(LevelFilter::Error)
+            .init(),
         0 => env_logger::Builder::new().parse_default_env().init(),
         1 => env_logger::Builder::new()
             .filter_level(LevelFilter::Info)
@@ -135,204 +808,111 @@ fn setup_logging(verbose: u8) {
     };
 }
 
-#[derive(Debug, Default)]
-struct WavToCOptions<'a> {
-    max_samples: Option<usize>,
-    no_comment: bool,
-    format: ArrayFormat,
-    prefix: Option<&'a str>,
-    header: bool,
-}
-
-fn write_header(
-    output_path: &Path,
-    array_name: &str,
-    array_type: &str,
-    size_type: &str,
-) -> Result<(), WavToCError> {
-    let header = format!(
-        "#ifndef _{}_H_\n#define _{}_H_\n\nextern const {} {}_SAMPLE_NO;\n\
-        extern const {} {}[];\n\n#endif",
-        array_name.to_uppercase(),
-        array_name.to_uppercase(),
-        size_type,
-        array_name.to_uppercase(),
-        array_type,
-        array_name,
-    );
-
-    std::fs::write(output_path, header)?;
-    Ok(())
+/// Command line of this invocation with the program name in place of its path
+///
+/// Arguments containing whitespace or quotes are single quoted for the shell.
+fn command_line() -> String {
+    std::env::args()
+        .enumerate()
+        .map(|(i, arg)| {
+            if i == 0 {
+                env!("CARGO_PKG_NAME").to_string()
+            } else if arg.is_empty()
+                || arg.contains(|c: char| c.is_whitespace() || c == '\'' || c == '"')
+            {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            } else {
+                arg
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
 }
 
-fn wav_to_c_array(
-    wav_path: &Path,
-    array_name: &str,
-    output_path: Option<&Path>,
-    options: WavToCOptions,
-) -> Result<(), WavToCError> {
-    if !wav_path.exists() {
-        return Err(WavToCError::InvalidInput(
-            "Input file does not exist.".to_string(),
-        ));
-    }
-
-    let mut reader = hound::WavReader::open(wav_path)?;
-    let spec = reader.spec();
-    let file_spec = format!(
-        "Sample rate: {} Hz, Channels: {}, Bits per sample: {}",
-        spec.sample_rate, spec.channels, spec.bits_per_sample
-    );
-
-    let wave_file = wav_path.file_name().unwrap().to_string_lossy();
-    info!("Processing file: {}", wave_file);
-    info!("{}", file_spec);
-
-    if spec.sample_format != hound::SampleFormat::Int {
-        return Err(WavToCError::InvalidInput(
-            "Only int PCM audio is currently supported.".to_string(),
-        ));
+fn main() -> ExitCode {
+    match run() {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) => {
+            eprintln!("Error: {:?}", e);
+            ExitCode::from(&e)
+        }
     }
+}
 
-    let c_type = match spec.bits_per_sample {
-        0..=8 => std::env::var("WAV2C_I8_TYPE").unwrap_or_else(|_| "int8_t".to_string()),
-        9..=16 => std::env::var("WAV2C_I16_TYPE").unwrap_or_else(|_| "int16_t".to_string()),
-        17..=32 => std::env::var("WAV2C_I32_TYPE").unwrap_or_else(|_| "int32_t".to_string()),
-        _ => {
-            return Err(WavToCError::InvalidInput(
-                "Unsupported bits per sample.".to_string(),
-            ))
-        }
-    };
+fn run() -> Result<(), WavToCError> {
+    let mut args = Args::parse();
 
-    // TODO: generic types so not all hound::Samples cast to i32
-    let samples = match spec.channels {
-        1 => reader.samples::<i32>().collect::<Result<Vec<_>, _>>()?,
-        2 => {
-            warn!("Merging stereo channels into mono.");
-            reader
-                .samples::<i32>()
-                .collect::<Result<Vec<_>, _>>()?
-                .chunks(2)
-                .map(|pair| {
-                    let left = pair[0] as i64;
-                    let right = pair[1] as i64;
-                    ((left + right) / 2) as i32
-                })
-                .collect()
-        }
-        _ => {
-            return Err(WavToCError::InvalidInput(
-                "Only mono or stereo audio is supported.".to_string(),
-            ));
-        }
-    };
+    #[cfg(feature = "cli")]
+    setup_logging(args.verbose, args.quiet);
 
-    if let Some(max_samples) = options.max_samples {
-        if samples.len() > max_samples {
-            return Err(WavToCError::InvalidInput(format!(
-                "Too many samples ({}), maximum is {}",
-                samples.len(),
-                max_samples
-            )));
-        }
+    if args.list_formats {
+        print!("{}", list_formats());
+        return Ok(());
     }
+    // clap requires the input unless listing formats
+    let input = args.input.take().unwrap_or_default();
 
-    // strip spaces and numeric from the array name
-    let safe_array_name = array_name
-        .trim()
-        .replace(" ", "_")
-        .replace(|c: char| !c.is_ascii_alphabetic() && c != '_', "");
-    let mut c_code = if !options.no_comment {
-        format!(
-            "/*\n\
-            * Generated by {} v{} from {}\n\
-            * {}\n\
-            *\n\
-            * {}\n\
-            */\n\n",
-            env!("CARGO_PKG_NAME"),
-            env!("CARGO_PKG_VERSION"),
-            wave_file,
-            file_spec,
-            env!("CARGO_PKG_REPOSITORY")
-        )
-    } else {
-        String::new()
-    };
-
-    if let Some(prefix) = options.prefix {
-        c_code.push_str(prefix);
-        c_code.push_str("\n\n");
+    if args.info {
+        print!("{}", wav_info(&input)?);
+        return Ok(());
     }
 
-    // from env WAV2C_SIZE_TYPE or default to size_t
-    let size_type = std::env::var("WAV2C_SIZE_TYPE").unwrap_or_else(|_| "size_t".to_string());
-    c_code.push_str(&format!(
-        "const {} {}_SAMPLE_NO = {};\n\n\
-        const {} {}[{}] = {{",
-        size_type,
-        safe_array_name.to_uppercase(),
-        samples.len(),
-        c_type,
-        safe_array_name,
-        samples.len()
-    ));
-
-    for (i, sample) in samples.into_iter().enumerate() {
-        if i % SAMPLES_PER_LINE == 0 {
-            c_code.push_str("\n\t");
-        }
-        match options.format {
-            ArrayFormat::Base10 => c_code.push_str(&format!(" {},", sample)),
-            ArrayFormat::Base16 => match c_type.as_str() {
-                // cast to signed type for correct hex representation - - i32 would be 0xffffff..
-                "int8_t" => c_code.push_str(&format!(" 0x{:02x},", sample as i8)),
-                "int16_t" => c_code.push_str(&format!(" 0x{:04x},", sample as i16)),
-                "int32_t" => c_code.push_str(&format!(" 0x{:08x},", { sample })),
-                _ => unreachable!(),
-            },
+    if let Some(output_dir) = &args.output_dir {
+        if !args.dry_run {
+            std::fs::create_dir_all(output_dir)?;
         }
+        let stem = Some(&input)
+            .filter(|input| !is_stdin(input))
+            .and_then(|input| input.file_stem())
+            .unwrap_or("audio".as_ref());
+        let extension = match args.lang {
+            Lang::C if args.single_header => "h",
+            Lang::C => "c",
+            Lang::Cpp => "hpp",
+            Lang::Json => "json",
+            Lang::Inc => "inc",
+        };
+        // pushed rather than set so dots in the stem are kept
+        let mut file_name = stem.to_os_string();
+        file_name.push(".");
+        file_name.push(extension);
+        args.output = Some(output_dir.join(file_name));
     }
 
-    c_code.push_str("\n};");
-
-    if let Some(output_path) = output_path {
-        std::fs::write(output_path, c_code)?;
-        info!("Output written to: {}", output_path.display());
-        if options.header {
-            let header_path = output_path.with_extension("h");
-            write_header(&header_path, array_name, &c_type, &size_type)?;
-            info!("Header written to: {}", header_path.display());
+    if let Some(output_path) = args.output.as_ref().filter(|_| !args.dry_run) {
+        // split output is written as parts in place of the output itself
+        let written_path = match args.split_files {
+            Some(_) => part_path(output_path, 0),
+            None => output_path.to_path_buf(),
+        };
+        if written_path.exists() && !args.force {
+            return Err(WavToCError::OutputExists(written_path));
+        }
+        let header_path = output_path.with_extension("h");
+        if (args.header || args.split_files.is_some()) && header_path.exists() && !args.force {
+            return Err(WavToCError::OutputExists(header_path));
+        }
+        let harness_path = harness_path(output_path);
+        if args.emit_test_harness && harness_path.exists() && !args.force {
+            return Err(WavToCError::OutputExists(harness_path));
         }
-    } else {
-        println!("{}", c_code);
     }
-
-    Ok(())
-}
-
-fn main() -> Result<(), WavToCError> {
-    let args = Args::parse();
-
-    setup_logging(args.verbose);
-
-    if let Some(output_path) = &args.output {
-        if output_path.exists() && !args.force {
-            return Err(WavToCError::OutputExists(output_path.to_path_buf()));
+    #[cfg(feature = "waveform-png")]
+    if let Some(waveform_path) = &args.waveform_png {
+        if !args.dry_run && waveform_path.exists() && !args.force {
+            return Err(WavToCError::OutputExists(waveform_path.clone()));
         }
     }
 
-    // use the input file name as the array name if not provided
-    // converted to lowercase ascii
+    // use the output or input file name as the array name if not provided
+    // converted to lowercase ascii, stdin has no name so falls back to 'audio'
     let array_name = args.array_name.unwrap_or_else(|| {
         args.output
             .as_ref()
-            .unwrap_or(&args.input)
-            .file_stem()
-            .unwrap()
-            .to_string_lossy()
-            .into_owned()
+            .or(Some(&input).filter(|input| !is_stdin(input)))
+            .and_then(|path| path.file_stem())
+            .map(|stem| stem.to_string_lossy().into_owned())
+            .unwrap_or_else(|| "audio".to_string())
             .to_ascii_lowercase()
     });
 
@@ -342,14 +922,119 @@ fn main() -> Result<(), WavToCError> {
         args.prefix
     };
 
+    let suffix = if let Some(suffix_file) = &args.suffix_file {
+        Some(std::fs::read_to_string(suffix_file)?)
+    } else {
+        args.suffix
+    };
+
+    let template = args
+        .template
+        .as_ref()
+        .map(std::fs::read_to_string)
+        .transpose()?;
+
+    let command = args.record_command.then(command_line);
+
     let options = WavToCOptions {
         max_samples: Some(args.max_samples),
+        truncate: args.truncate,
+        max_bytes: args.max_bytes,
+        start: args
+            .start
+            .map(ClipPosition::Seconds)
+            .or(args.start_sample.map(ClipPosition::Sample)),
+        end: args
+            .end
+            .map(ClipPosition::Seconds)
+            .or(args.end_sample.map(ClipPosition::Sample)),
         no_comment: args.no_comment,
+        name_template: args.name_template.as_deref(),
+        comment_fields: args.comment_fields,
         format: args.format,
+        lang: args.lang,
+        template: template.as_deref(),
         prefix: prefix.as_deref(),
         header: args.header,
+        single_header: args.single_header,
+        bank_entry: args.bank_entry,
+        banners: args.banners,
+        no_braces: args.no_braces,
+        extern_c: args.extern_c,
+        progmem_accessor: args.wrap_progmem_accessor,
+        align: args.align,
+        no_sanitize: args.no_sanitize,
+        gain: args.gain,
+        limit_db: args.limit,
+        resample: args.resample,
+        no_antialias: args.no_antialias,
+        highpass_hz: args.highpass,
+        clamp_amplitude: args.clamp_amplitude,
+        bit_depth: args.bit_depth,
+        upscale: args.upscale,
+        upscale_replicate: args.replicate_lsb,
+        dither: args.dither,
+        dither_seed: args.dither_seed,
+        dither_depth: args.dither_depth,
+        stereo_frames: args.group_stereo_as_struct_array,
+        remove_dc: args.remove_dc,
+        stereo_width: args.stereo_width,
+        stereo_to_ms: args.stereo_to_ms,
+        parity: args.parity,
+        mono_mix: args.mono_mix,
+        reverse: args.reverse,
+        repeat: args.repeat,
+        fade_in_ms: args.fade_in,
+        fade_out_ms: args.fade_out,
+        rotate_to_peak: args.rotate_to_peak,
+        line_bytes: args.sample_limit_per_line_bytes,
+        last_line_pad: args.last_line_pad,
+        no_trailing_comma: args.no_trailing_comma,
+        pad_width: args.pad_width,
+        indent: Some(&args.indent),
+        test_harness: args.emit_test_harness,
+        asset_version: args.asset_version,
+        checksum: args.checksum,
+        c_type: args.ctype.as_deref(),
+        no_define: args.no_define,
+        duration_ms: args.emit_length_in_ms,
+        detect_pitch: args.detect_pitch,
+        mark_onset: args.mark_onset,
+        length_style: args.length_style,
+        mutable: args.mutable,
+        command: command.as_deref(),
+        suffix: suffix.as_deref(),
+        bytes: args.bytes.or(args.raw_bytes_only.then_some(ByteOrder::Le)),
+        byte_swap: args.byte_swap,
+        offset_binary: args.offset_binary,
+        raw_bytes_only: args.raw_bytes_only,
+        comment_extra: args.comment_extra.iter().map(String::as_str).collect(),
+        fft_ready: args.fft_ready,
+        block_size: args.block_size,
+        stats: args.stats,
+        preview: args.preview.then(|| {
+            std::env::var("COLUMNS")
+                .ok()
+                .and_then(|columns| columns.parse::<usize>().ok())
+                .filter(|&columns| columns > 1)
+                // less the axis drawn at the start of each line
+                .map_or(79, |columns| columns - 1)
+        }),
+        #[cfg(feature = "waveform-png")]
+        waveform_png: args.waveform_png.as_deref(),
+        dry_run: args.dry_run,
+        profile: args.profile,
+        no_clip_check: args.quiet,
+        progress: !args.quiet && std::io::stderr().is_terminal(),
+        verify_against: args.verify_against.as_deref(),
+        verify_tolerance: args.verify_tolerance,
+        post_process: args.post_process.as_deref(),
+        adpcm: args.adpcm,
+        pack_bits: args.pack_bits,
+        split_files: args.split_files,
+        verify_compile: args.verify,
     };
 
-    wav_to_c_array(&args.input, &array_name, args.output.as_deref(), options)?;
+    wav_to_c_array(&input, &array_name, args.output.as_deref(), options)?;
     Ok(())
 }
@@ -0,0 +1,52 @@
+//! Tight packing of samples narrower than their container into a byte stream.
+
+/// Comment describing how to unpack the array, one entry per line
+pub(crate) const UNPACK_COMMENT: &[&str] = &[
+    "<NAME>_PACK_BITS bit two's complement samples packed least significant bit first,",
+    "sample i starting at bit i * <NAME>_PACK_BITS of the bytes. Unpack sample i with:",
+    "  bit = i * <NAME>_PACK_BITS",
+    "  raw = little endian bytes from bit / 8, >> (bit % 8), masked to <NAME>_PACK_BITS bits",
+    "  sample = raw sign extended from bit <NAME>_PACK_BITS - 1",
+    "12-bit samples are two per three bytes: s0 = b0 | (b1 & 0xf) << 8, s1 = b1 >> 4 | b2 << 4.",
+];
+
+/// Packs the low `bits` of each sample into bytes, least significant bit first
+#[derive(Debug, Default)]
+pub(crate) struct BitPacker {
+    bits: u32,
+    /// Bits not yet making a whole byte, in the low `pending` bits
+    accumulator: u64,
+    pending: u32,
+}
+
+impl BitPacker {
+    pub(crate) fn new(bits: u32) -> Self {
+        BitPacker {
+            bits,
+            ..Default::default()
+        }
+    }
+
+    /// Add `value`, returning the bytes it completed
+    pub(crate) fn push(&mut self, value: i64) -> Vec<u8> {
+        let mask = (1u64 << self.bits) - 1;
+        self.accumulator |= (value as u64 & mask) << self.pending;
+        self.pending += self.bits;
+
+        let mut bytes = Vec::new();
+        while self.pending >= 8 {
+            bytes.push(self.accumulator as u8);
+            self.accumulator >>= 8;
+            self.pending -= 8;
+        }
+        bytes
+    }
+
+    /// Final partial byte, its unused high bits zero
+    pub(crate) fn flush(&mut self) -> Option<u8> {
+        let byte = (self.pending > 0).then_some(self.accumulator as u8);
+        self.accumulator = 0;
+        self.pending = 0;
+        byte
+    }
+}
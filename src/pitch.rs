@@ -0,0 +1,75 @@
+//! Fundamental frequency estimation by autocorrelation.
+
+/// Lowest fundamental searched for in Hz
+const MIN_PITCH_HZ: f64 = 50.0;
+/// Highest fundamental searched for in Hz
+const MAX_PITCH_HZ: f64 = 2000.0;
+/// Samples correlated for each lag, bounding the cost on long files
+const WINDOW: usize = 16384;
+/// Normalized correlation below which the input is considered non-tonal
+const MIN_CONFIDENCE: f64 = 0.5;
+/// Fraction of the best correlation the shortest period must reach to be chosen over it
+const OCTAVE_THRESHOLD: f64 = 0.9;
+
+/// Estimate the fundamental frequency of `samples` at `sample_rate`
+///
+/// Returns the frequency in Hz and a confidence from 0 to 1, the normalized autocorrelation at
+/// the detected period. The frequency is 0 if the input is not tonal enough to tell.
+pub(crate) fn detect_pitch(samples: &[i64], sample_rate: u32) -> (f64, f64) {
+    let rate = sample_rate as f64;
+    let min_lag = ((rate / MAX_PITCH_HZ).floor() as usize).max(1);
+    let max_lag = (rate / MIN_PITCH_HZ).ceil() as usize;
+    if samples.len() < max_lag * 2 {
+        return (0.0, 0.0);
+    }
+
+    // remove any DC so it does not correlate at every lag
+    let window = &samples[..samples.len().min(WINDOW + max_lag)];
+    let mean = window.iter().map(|&s| s as f64).sum::<f64>() / window.len() as f64;
+    let x: Vec<f64> = window.iter().map(|&s| s as f64 - mean).collect();
+    let len = x.len() - max_lag;
+
+    let correlation: Vec<f64> = (0..=max_lag + 1)
+        .map(|lag| {
+            if lag < min_lag || lag > max_lag {
+                return 0.0;
+            }
+            let (mut xy, mut xx, mut yy) = (0.0, 0.0, 0.0);
+            for i in 0..len {
+                xy += x[i] * x[i + lag];
+                xx += x[i] * x[i];
+                yy += x[i + lag] * x[i + lag];
+            }
+            if xx == 0.0 || yy == 0.0 {
+                0.0
+            } else {
+                xy / (xx * yy).sqrt()
+            }
+        })
+        .collect();
+
+    let best = correlation.iter().cloned().fold(0.0, f64::max);
+    if best < MIN_CONFIDENCE {
+        return (0.0, best.max(0.0));
+    }
+
+    // shortest period close to the best so multiples of the period are not chosen
+    let Some(lag) = (min_lag..=max_lag).find(|&lag| {
+        correlation[lag] >= best * OCTAVE_THRESHOLD
+            && correlation[lag] >= correlation[lag - 1]
+            && correlation[lag] >= correlation[lag + 1]
+    }) else {
+        return (0.0, best);
+    };
+
+    // parabolic interpolation of the peak for a fractional period
+    let (a, b, c) = (correlation[lag - 1], correlation[lag], correlation[lag + 1]);
+    let denominator = a - 2.0 * b + c;
+    let offset = if denominator == 0.0 {
+        0.0
+    } else {
+        (0.5 * (a - c) / denominator).clamp(-0.5, 0.5)
+    };
+
+    (rate / (lag as f64 + offset), b.clamp(0.0, 1.0))
+}
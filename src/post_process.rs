@@ -0,0 +1,84 @@
+//! Piping of the generated output through an external command such as a formatter.
+use crate::WavToCError;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Run `command` with the shell, `input` on its stdin, returning its stdout
+pub(crate) fn run(command: &str, input: &[u8]) -> Result<Vec<u8>, WavToCError> {
+    let (shell, flag) = if cfg!(windows) {
+        ("cmd", "/C")
+    } else {
+        ("sh", "-c")
+    };
+    let mut child = Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+
+    // feed stdin from another thread so a command writing as it reads cannot fill the pipe
+    let mut stdin = child.stdin.take().expect("stdin is piped");
+    let output = std::thread::scope(|scope| {
+        let writer = scope.spawn(move || stdin.write_all(input));
+        let output = child.wait_with_output();
+        // a command exiting without reading all its input is fine, its status decides
+        let _ = writer.join();
+        output
+    })?;
+
+    if !output.status.success() {
+        return Err(WavToCError::PostProcessFailed(format!(
+            "'{}' exited with {}",
+            command, output.status
+        )));
+    }
+
+    Ok(output.stdout)
+}
+
+/// Writer buffering everything written when there is a command to run it through on `finish`
+pub(crate) struct PostProcessWriter<'a, W: Write> {
+    command: Option<&'a str>,
+    buffer: Vec<u8>,
+    inner: W,
+}
+
+impl<'a, W: Write> PostProcessWriter<'a, W> {
+    pub(crate) fn new(command: Option<&'a str>, inner: W) -> Self {
+        PostProcessWriter {
+            command,
+            buffer: Vec::new(),
+            inner,
+        }
+    }
+
+    /// Run the buffered output through the command to the inner writer
+    pub(crate) fn finish(mut self) -> Result<W, WavToCError> {
+        if let Some(command) = self.command {
+            let output = run(command, &self.buffer)?;
+            self.inner.write_all(&output)?;
+        }
+        self.inner.flush()?;
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for PostProcessWriter<'_, W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        if self.command.is_some() {
+            self.buffer.extend_from_slice(buf);
+            Ok(buf.len())
+        } else {
+            self.inner.write(buf)
+        }
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        if self.command.is_some() {
+            Ok(())
+        } else {
+            self.inner.flush()
+        }
+    }
+}
@@ -0,0 +1,91 @@
+//! ASCII plot of the emitted waveform for a quick visual check of a conversion.
+use crate::transform::sample_bounds;
+use crate::{SampleEmitter, WavMetadata};
+use std::fmt;
+use std::ops::RangeInclusive;
+
+/// Rows of the plot, odd so there is a centre line at zero
+const PREVIEW_HEIGHT: usize = 11;
+
+/// Collects the minimum and maximum of the samples in each column of a plot `width` characters
+/// wide, displayed as the plot
+#[derive(Debug)]
+pub(crate) struct PreviewEmitter {
+    width: usize,
+    bounds: (i64, i64),
+    /// Samples per column
+    bucket: usize,
+    count: usize,
+    columns: Vec<(i64, i64)>,
+}
+
+impl PreviewEmitter {
+    pub(crate) fn new(width: usize) -> Self {
+        PreviewEmitter {
+            width: width.max(1),
+            bounds: (-1, 1),
+            bucket: 1,
+            count: 0,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Row of `value` in a plot `height` rows high, 0 at the positive full scale
+    fn row(&self, value: i64, height: usize) -> usize {
+        let (min, max) = self.bounds;
+        let scale = (max - value) as f64 / (max - min) as f64;
+        ((scale * (height - 1) as f64).round() as usize).min(height - 1)
+    }
+
+    /// Rows spanned by each column in a plot `height` rows high
+    pub(crate) fn column_rows(&self, height: usize) -> Vec<RangeInclusive<usize>> {
+        self.columns
+            .iter()
+            .map(|&(min, max)| self.row(max, height)..=self.row(min, height))
+            .collect()
+    }
+}
+
+impl SampleEmitter for PreviewEmitter {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.bounds = sample_bounds(meta.bits_per_sample);
+        self.bucket = meta.sample_count.div_ceil(self.width).max(1);
+        self.count = 0;
+        self.columns = Vec::with_capacity(self.width);
+    }
+
+    fn sample(&mut self, value: i64) {
+        if self.count.is_multiple_of(self.bucket) {
+            self.columns.push((value, value));
+        } else if let Some((min, max)) = self.columns.last_mut() {
+            *min = (*min).min(value);
+            *max = (*max).max(value);
+        }
+        self.count += 1;
+    }
+
+    fn end(&mut self) {}
+}
+
+impl fmt::Display for PreviewEmitter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ranges = self.column_rows(PREVIEW_HEIGHT);
+        for row in 0..PREVIEW_HEIGHT {
+            let line: String = ranges
+                .iter()
+                .map(|range| match range.contains(&row) {
+                    true => '#',
+                    false if row == PREVIEW_HEIGHT / 2 => '-',
+                    false => ' ',
+                })
+                .collect();
+            writeln!(f, "|{}", line.trim_end())?;
+        }
+        write!(
+            f,
+            "{} samples, {} per column",
+            self.count,
+            self.bucket.min(self.count)
+        )
+    }
+}
@@ -0,0 +1,62 @@
+//! Progress of long conversions drawn on stderr.
+use crate::{SampleEmitter, WavMetadata};
+use std::io::Write;
+
+/// Conversions with fewer samples than this finish too quickly to need progress
+pub(crate) const PROGRESS_THRESHOLD: usize = 50_000;
+/// Width of the bar in characters
+const BAR_WIDTH: usize = 40;
+
+/// Draws a progress bar on stderr as samples are emitted, redrawn each percent
+#[derive(Debug, Default)]
+pub(crate) struct ProgressEmitter {
+    total: usize,
+    count: usize,
+    percent: Option<usize>,
+}
+
+impl ProgressEmitter {
+    fn draw(&mut self) {
+        let percent = self.count * 100 / self.total;
+        if self.percent == Some(percent) {
+            return;
+        }
+        self.percent = Some(percent);
+        let filled = BAR_WIDTH * percent / 100;
+        let mut stderr = std::io::stderr().lock();
+        let _ = write!(
+            stderr,
+            "\r[{}{}] {:3}%",
+            "#".repeat(filled),
+            "-".repeat(BAR_WIDTH - filled),
+            percent
+        );
+        let _ = stderr.flush();
+    }
+}
+
+impl SampleEmitter for ProgressEmitter {
+    fn begin(&mut self, meta: &WavMetadata) {
+        *self = ProgressEmitter {
+            total: meta.sample_count,
+            ..Default::default()
+        };
+        if self.total >= PROGRESS_THRESHOLD {
+            self.draw();
+        }
+    }
+
+    fn sample(&mut self, _value: i64) {
+        self.count += 1;
+        if self.total >= PROGRESS_THRESHOLD {
+            self.draw();
+        }
+    }
+
+    fn end(&mut self) {
+        if self.percent.is_some() {
+            // clear the bar so it does not mix with any following output
+            let _ = write!(std::io::stderr(), "\r{}\r", " ".repeat(BAR_WIDTH + 7));
+        }
+    }
+}
@@ -0,0 +1,309 @@
+//! Minimal RIFF chunk parsing for WAV metadata that hound does not expose.
+use std::io::{self, Read, Seek, SeekFrom};
+
+/// `WAVE_FORMAT_IEEE_FLOAT` format tag
+const FORMAT_IEEE_FLOAT: u16 = 3;
+/// `WAVE_FORMAT_EXTENSIBLE` format tag, the real format is in the sub format GUID
+const FORMAT_EXTENSIBLE: u16 = 0xfffe;
+
+/// Names of the common `LIST` `INFO` tags, others are named by their id
+const INFO_NAMES: [(&[u8; 4], &str); 12] = [
+    (b"INAM", "Title"),
+    (b"IART", "Artist"),
+    (b"IPRD", "Album"),
+    (b"ITRK", "Track"),
+    (b"IGNR", "Genre"),
+    (b"ICRD", "Date"),
+    (b"ICMT", "Comment"),
+    (b"ICOP", "Copyright"),
+    (b"IENG", "Engineer"),
+    (b"IKEY", "Keywords"),
+    (b"ISRC", "Source"),
+    (b"ISFT", "Software"),
+];
+
+/// Chunks of a RIFF WAVE file other than the sample data
+#[derive(Debug, Default)]
+pub(crate) struct Chunks {
+    chunks: Vec<([u8; 4], Vec<u8>)>,
+    /// Offset and size of the `data` chunk contents
+    data: Option<(u64, u64)>,
+}
+
+/// Fields of the `fmt ` chunk
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Fmt {
+    pub(crate) format_tag: u16,
+    pub(crate) channels: u16,
+    pub(crate) sample_rate: u32,
+    pub(crate) bits_per_sample: u16,
+    /// Format code from the sub format GUID of `WAVE_FORMAT_EXTENSIBLE`
+    pub(crate) sub_format: Option<u16>,
+    /// `wValidBitsPerSample` of `WAVE_FORMAT_EXTENSIBLE`, the precision of the samples within
+    /// the `bits_per_sample` container
+    pub(crate) valid_bits: Option<u16>,
+}
+
+impl Fmt {
+    /// IEEE float samples, either directly or through the extensible sub format
+    pub(crate) fn is_float(&self) -> bool {
+        self.format_tag == FORMAT_IEEE_FLOAT || self.sub_format == Some(FORMAT_IEEE_FLOAT)
+    }
+}
+
+/// Read the chunks following the `RIFF....WAVE` header, skipping over the `data` chunk
+///
+/// Stops at the first truncated chunk rather than erroring since metadata is optional. The
+/// reader is left at the start of the stream.
+pub(crate) fn read_chunks<R: Read + Seek>(reader: &mut R) -> io::Result<Chunks> {
+    let mut chunks = Vec::new();
+    let mut data_chunk = None;
+    let mut header = [0u8; 12];
+
+    reader.seek(SeekFrom::Start(0))?;
+    if reader.read_exact(&mut header).is_ok()
+        && &header[0..4] == b"RIFF"
+        && &header[8..12] == b"WAVE"
+    {
+        let mut chunk_header = [0u8; 8];
+        while reader.read_exact(&mut chunk_header).is_ok() {
+            let id = [
+                chunk_header[0],
+                chunk_header[1],
+                chunk_header[2],
+                chunk_header[3],
+            ];
+            let size = read_u32(&chunk_header, 4).unwrap() as u64;
+            // chunks are padded to an even size
+            let padding = size & 1;
+
+            if &id == b"data" {
+                data_chunk = Some((reader.stream_position()?, size));
+                reader.seek(SeekFrom::Current((size + padding) as i64))?;
+                continue;
+            }
+
+            let mut data = Vec::new();
+            reader.by_ref().take(size).read_to_end(&mut data)?;
+            if (data.len() as u64) < size {
+                break;
+            }
+            reader.seek(SeekFrom::Current(padding as i64))?;
+            chunks.push((id, data));
+        }
+    }
+
+    reader.seek(SeekFrom::Start(0))?;
+    Ok(Chunks {
+        chunks,
+        data: data_chunk,
+    })
+}
+
+/// Id and data of the sub chunks of a `LIST` chunk following its four byte type
+fn sub_chunks(list: &[u8]) -> impl Iterator<Item = (&[u8], &[u8])> {
+    let mut offset = 4;
+    std::iter::from_fn(move || {
+        let id = list.get(offset..offset + 4)?;
+        let size = read_u32(list, offset + 4)? as usize;
+        let start = offset + 8;
+        let data = list.get(start..start + size)?;
+        // sub chunks are padded to an even size too
+        offset = start + size + (size & 1);
+        Some((id, data))
+    })
+}
+
+/// Text of a null terminated string chunk
+fn chunk_text(data: &[u8]) -> String {
+    let text = data.split(|&b| b == 0).next().unwrap_or_default();
+    String::from_utf8_lossy(text).into_owned()
+}
+
+/// Little endian u16 at `offset`
+pub(crate) fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    bytes
+        .get(offset..offset + 2)
+        .map(|b| u16::from_le_bytes([b[0], b[1]]))
+}
+
+/// Little endian u32 at `offset`
+pub(crate) fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    bytes
+        .get(offset..offset + 4)
+        .map(|b| u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+}
+
+impl Chunks {
+    /// Data of the first chunk with `id`
+    pub(crate) fn find(&self, id: &[u8; 4]) -> Option<&[u8]> {
+        self.chunks
+            .iter()
+            .find(|(chunk_id, _)| chunk_id == id)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    /// Data of every chunk with `id` in file order
+    pub(crate) fn find_all<'a>(&'a self, id: &'a [u8; 4]) -> impl Iterator<Item = &'a [u8]> {
+        self.chunks
+            .iter()
+            .filter(move |(chunk_id, _)| chunk_id == id)
+            .map(|(_, data)| data.as_slice())
+    }
+
+    /// Offset and size of the `data` chunk contents
+    pub(crate) fn data(&self) -> Option<(u64, u64)> {
+        self.data
+    }
+
+    /// Parsed `fmt ` chunk
+    pub(crate) fn fmt(&self) -> Option<Fmt> {
+        let fmt = self.find(b"fmt ")?;
+        let format_tag = read_u16(fmt, 0)?;
+        let (sub_format, valid_bits) = if format_tag == FORMAT_EXTENSIBLE {
+            // first two bytes of the GUID following cbSize, valid bits and channel mask
+            (
+                read_u16(fmt, 24),
+                read_u16(fmt, 18).filter(|&bits| bits > 0),
+            )
+        } else {
+            (None, None)
+        };
+
+        Some(Fmt {
+            format_tag,
+            channels: read_u16(fmt, 2)?,
+            sample_rate: read_u32(fmt, 4)?,
+            bits_per_sample: read_u16(fmt, 14)?,
+            sub_format,
+            valid_bits,
+        })
+    }
+
+    /// Frames per channel in the `fact` chunk
+    pub(crate) fn fact_frames(&self) -> Option<u32> {
+        read_u32(self.find(b"fact")?, 0)
+    }
+
+    /// Start and end sample offsets of the first loop in the `smpl` chunk
+    pub(crate) fn loop_points(&self) -> Option<(u32, u32)> {
+        let smpl = self.find(b"smpl")?;
+        let loop_count = read_u32(smpl, 28)?;
+        if loop_count == 0 {
+            return None;
+        }
+        // first loop follows the 36 byte header: cue id, type, start, end, fraction, play count
+        let start = read_u32(smpl, 36 + 8)?;
+        let end = read_u32(smpl, 36 + 12)?;
+
+        Some((start, end))
+    }
+
+    /// Sample offsets of the `cue ` points in order, with any `labl` names from `LIST` `adtl`
+    pub(crate) fn cue_points(&self) -> Vec<(u32, Option<String>)> {
+        let Some(cue) = self.find(b"cue ") else {
+            return Vec::new();
+        };
+        let count = read_u32(cue, 0).unwrap_or(0) as usize;
+        // id, position, data chunk id, chunk start, block start, sample offset
+        let mut points: Vec<(u32, u32)> = (0..count)
+            .map_while(|i| {
+                let point = 4 + i * 24;
+                Some((read_u32(cue, point)?, read_u32(cue, point + 20)?))
+            })
+            .collect();
+        points.sort_by_key(|&(_, offset)| offset);
+
+        let labels = self.labels();
+        points
+            .into_iter()
+            .map(|(id, offset)| {
+                let label = labels
+                    .iter()
+                    .find(|(label_id, _)| *label_id == id)
+                    .map(|(_, label)| label.clone());
+                (offset, label)
+            })
+            .collect()
+    }
+
+    /// Cue point ids and names of the `labl` sub chunks of `LIST` `adtl` chunks
+    fn labels(&self) -> Vec<(u32, String)> {
+        self.find_all(b"LIST")
+            .filter(|list| list.get(0..4) == Some(b"adtl"))
+            .flat_map(sub_chunks)
+            .filter(|(id, _)| id == b"labl")
+            .filter_map(|(_, data)| Some((read_u32(data, 0)?, chunk_text(&data[4..]))))
+            .collect()
+    }
+
+    /// Names and text of the non-empty tags of `LIST` `INFO` chunks in file order, such as
+    /// `("Title", ...)`
+    pub(crate) fn info_tags(&self) -> Vec<(String, String)> {
+        self.find_all(b"LIST")
+            .filter(|list| list.get(0..4) == Some(b"INFO"))
+            .flat_map(sub_chunks)
+            .filter_map(|(id, data)| {
+                // one line per tag in the comment
+                let text = chunk_text(data)
+                    .split_whitespace()
+                    .collect::<Vec<_>>()
+                    .join(" ");
+                if text.is_empty() {
+                    return None;
+                }
+                let name = INFO_NAMES
+                    .iter()
+                    .find(|(tag, _)| tag.as_slice() == id)
+                    .map_or_else(
+                        || String::from_utf8_lossy(id).into_owned(),
+                        |(_, name)| name.to_string(),
+                    );
+                Some((name, text))
+            })
+            .collect()
+    }
+}
+
+/// Little endian int samples of `container_bits` holding `valid_bits` from `reader` until `len`
+/// bytes are read
+///
+/// The valid bits are the most significant of the container, as `WAVE_FORMAT_EXTENSIBLE`
+/// specifies, so the padding below them is shifted out. 8-bit containers are unsigned.
+pub(crate) fn int_samples<R: Read>(
+    reader: R,
+    len: u64,
+    container_bits: u16,
+    valid_bits: u16,
+) -> impl Iterator<Item = io::Result<i32>> {
+    let mut reader = reader.take(len);
+    let width = container_bits.div_ceil(8) as usize;
+    std::iter::from_fn(move || {
+        let mut bytes = [0u8; 4];
+        // left align the little endian bytes in the i32 so the sign is its top bit
+        match reader.read_exact(&mut bytes[4 - width..]) {
+            Ok(()) => {
+                let mut value = u32::from_le_bytes(bytes);
+                if width == 1 {
+                    value ^= 0x8000_0000;
+                }
+                Some(Ok(value as i32 >> (32 - valid_bits)))
+            }
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => None,
+            Err(e) => Some(Err(e)),
+        }
+    })
+}
+
+/// Little endian `f64` samples from `reader` until `len` bytes are read
+pub(crate) fn f64_samples<R: Read>(reader: R, len: u64) -> impl Iterator<Item = io::Result<f64>> {
+    let mut reader = reader.take(len);
+    std::iter::from_fn(move || {
+        let mut bytes = [0u8; 8];
+        match reader.read_exact(&mut bytes) {
+            Ok(()) => Some(Ok(f64::from_le_bytes(bytes))),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => None,
+            Err(e) => Some(Err(e)),
+        }
+    })
+}
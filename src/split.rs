@@ -0,0 +1,279 @@
+//! Splitting of a large array into part files for compilers that choke on huge initializers.
+use crate::{
+    array_attributes, array_element, array_qualifier, block_defines, checksum_define, cue_defines,
+    duration_define, fft_size_define, loop_defines, onset_define, pitch_define, post_process,
+    sample_rate_define, version_define, CEmitter, Crc32Emitter, SampleEmitter, WavMetadata,
+    WavToCError, WavToCOptions,
+};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+type PartEmitter<'a> = CEmitter<'a, post_process::PostProcessWriter<'a, BufWriter<File>>>;
+
+/// Path of part `index` of the split `output_path`: `<stem>_part<index>.c`
+pub fn part_path(output_path: &Path, index: usize) -> PathBuf {
+    let stem = output_path
+        .file_stem()
+        .unwrap_or_default()
+        .to_string_lossy();
+    output_path.with_file_name(format!("{}_part{}.c", stem, index))
+}
+
+/// Emits the samples as consecutive `<name>_part<i>` arrays of `part_size` samples, each in
+/// its own file next to the output
+///
+/// Parts are written to temporary files that [`SplitEmitter::finish`] renames once the whole
+/// conversion succeeded.
+pub(crate) struct SplitEmitter<'a> {
+    options: &'a WavToCOptions<'a>,
+    output_path: &'a Path,
+    part_size: usize,
+    meta: Option<WavMetadata>,
+    part: Option<PartEmitter<'a>>,
+    /// Samples emitted to the current part
+    part_len: usize,
+    /// Temporary and final path of each part started
+    paths: Vec<(PathBuf, PathBuf)>,
+    /// CRC-32 of the current part and those of each part finished
+    crc: Crc32Emitter,
+    crcs: Vec<u32>,
+    error: Option<WavToCError>,
+}
+
+impl<'a> SplitEmitter<'a> {
+    /// `options` are used for each part, which should not repeat the defines of the header
+    pub(crate) fn new(
+        options: &'a WavToCOptions<'a>,
+        output_path: &'a Path,
+        part_size: usize,
+    ) -> Self {
+        SplitEmitter {
+            options,
+            output_path,
+            part_size,
+            meta: None,
+            part: None,
+            part_len: 0,
+            paths: Vec::new(),
+            crc: Crc32Emitter::default(),
+            crcs: Vec::new(),
+            error: None,
+        }
+    }
+
+    fn start_part(&mut self) -> Result<(), WavToCError> {
+        let Some(meta) = &self.meta else {
+            return Ok(());
+        };
+        let index = self.paths.len();
+        let path = part_path(self.output_path, index);
+        let mut temp_name = path.file_name().unwrap_or_default().to_os_string();
+        temp_name.push(".tmp");
+        let temp_path = path.with_file_name(temp_name);
+        let file = File::create(&temp_path)?;
+        self.paths.push((temp_path, path));
+
+        // the loop and cue points are of the whole array so are only in the header
+        let part_meta = WavMetadata {
+            name: format!("{}_part{}", meta.name, index),
+            sample_count: self
+                .part_size
+                .min(meta.sample_count - index * self.part_size),
+            loop_points: None,
+            cue_points: Vec::new(),
+            ..meta.clone()
+        };
+        let mut part = CEmitter::new(
+            self.options,
+            post_process::PostProcessWriter::new(self.options.post_process, BufWriter::new(file)),
+        );
+        part.begin(&part_meta);
+        self.crc.begin(&part_meta);
+        self.part = Some(part);
+        self.part_len = 0;
+        Ok(())
+    }
+
+    fn finish_part(&mut self) -> Result<(), WavToCError> {
+        if let Some(mut part) = self.part.take() {
+            part.end();
+            part.finish()?.finish()?;
+            self.crcs.push(self.crc.crc32());
+        }
+        Ok(())
+    }
+
+    /// Rename the parts into place if `result`, writing them and `check` of each succeeded,
+    /// returning it with the part paths and the CRC-32 of their samples, otherwise remove them
+    /// and return the first error
+    pub(crate) fn finish<T>(
+        mut self,
+        result: Result<T, WavToCError>,
+        check: impl Fn(&Path) -> Result<(), WavToCError>,
+    ) -> Result<(T, Vec<(PathBuf, u32)>), WavToCError> {
+        let finished = self.finish_part();
+        let result = match self.error.take() {
+            Some(e) => Err(e),
+            None => finished.and(result),
+        }
+        .and_then(|value| {
+            self.paths
+                .iter()
+                .try_for_each(|(temp_path, _)| check(temp_path))
+                .map(|_| value)
+        });
+        match result {
+            Ok(value) => {
+                let mut parts = Vec::new();
+                for ((temp_path, path), crc32) in self.paths.into_iter().zip(self.crcs) {
+                    std::fs::rename(&temp_path, &path)?;
+                    parts.push((path, crc32));
+                }
+                Ok((value, parts))
+            }
+            Err(e) => {
+                for (temp_path, _) in &self.paths {
+                    let _ = std::fs::remove_file(temp_path);
+                }
+                Err(e)
+            }
+        }
+    }
+}
+
+impl SampleEmitter for SplitEmitter<'_> {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.meta = Some(meta.clone());
+        self.part = None;
+        self.part_len = 0;
+    }
+
+    fn sample(&mut self, value: i64) {
+        if self.error.is_some() {
+            return;
+        }
+        if self.part.is_none() || self.part_len == self.part_size {
+            if let Err(e) = self.finish_part().and_then(|_| self.start_part()) {
+                self.error = Some(e);
+                return;
+            }
+        }
+        if let Some(part) = &mut self.part {
+            part.sample(value);
+            self.crc.sample(value);
+            self.part_len += 1;
+        }
+    }
+
+    fn end(&mut self) {}
+}
+
+/// Write the master header declaring the parts of `meta` with the CRC-32 `part_crcs` of each,
+/// the defines of the whole array and a `<name>_concat` helper copying the parts in order to a
+/// buffer
+///
+/// The lengths are macros regardless of the length style so the helper can use them. With
+/// `crc32` of the whole array the header also has a `<name>_chunk_crc` table of the parts.
+pub(crate) fn write_header(
+    header_path: &Path,
+    meta: &WavMetadata,
+    part_crcs: &[u32],
+    part_size: usize,
+    options: &WavToCOptions,
+    crc32: Option<u32>,
+) -> Result<(), WavToCError> {
+    let part_count = part_crcs.len();
+    let name = &meta.name;
+    let upper_name = name.to_uppercase();
+    let mut header = format!("#ifndef _{0}_H_\n#define _{0}_H_\n\n", upper_name);
+
+    if options.extern_c {
+        header.push_str("#ifdef __cplusplus\nextern \"C\" {\n#endif\n\n");
+    }
+
+    header.push_str(&format!(
+        "#define {0}_SAMPLE_NO {1}\n#define {0}_PART_SIZE {2}\n#define {0}_PART_COUNT {3}\n\n",
+        upper_name, meta.sample_count, part_size, part_count
+    ));
+    header.push_str(&sample_rate_define(name, meta));
+    for define in [
+        options
+            .asset_version
+            .map(|version| version_define(name, version)),
+        crc32.map(|crc32| checksum_define(name, options.asset_version, crc32)),
+        loop_defines(meta),
+        cue_defines(meta),
+        options.duration_ms.then(|| duration_define(name, meta)),
+        pitch_define(name, meta),
+        onset_define(name, meta),
+        options.fft_ready.then(|| fft_size_define(meta)),
+        options
+            .block_size
+            .map(|block_size| block_defines(name, meta, block_size)),
+    ]
+    .into_iter()
+    .flatten()
+    {
+        header.push('\n');
+        header.push_str(&define);
+    }
+    header.push('\n');
+
+    let (array_type, _, _) = array_element(meta, options);
+    let parts: Vec<String> = (0..part_count)
+        .map(|index| format!("{}_part{}", name, index))
+        .collect();
+    for part in &parts {
+        header.push_str(&format!(
+            "extern {}{} {}[]{};\n",
+            array_qualifier(options),
+            array_type,
+            part,
+            array_attributes(options),
+        ));
+    }
+
+    if crc32.is_some() {
+        let crcs: Vec<String> = part_crcs
+            .iter()
+            .map(|crc32| format!("0x{:08x}u", crc32))
+            .collect();
+        header.push_str(&format!(
+            "\n/* CRC-32 of the samples of each part as little endian bytes of the array type */\n\
+            static const uint32_t {}_chunk_crc[{}_PART_COUNT] = {{{}}};\n",
+            name,
+            upper_name,
+            crcs.join(", "),
+        ));
+    }
+
+    header.push_str(&format!(
+        "\n/* Copy the parts in order to `dest`, which must hold {upper}_SAMPLE_NO samples */\n\
+        static inline void {name}_concat({ty} *dest)\n\
+        {{\n\
+        \tconst {ty} *const parts[{upper}_PART_COUNT] = {{{parts}}};\n\
+        \tsize_t i;\n\n\
+        \tfor (i = 0; i < {upper}_SAMPLE_NO; i++) {{\n\
+        \t\tdest[i] = parts[i / {upper}_PART_SIZE][i % {upper}_PART_SIZE];\n\
+        \t}}\n\
+        }}\n",
+        upper = upper_name,
+        name = name,
+        ty = array_type,
+        parts = parts.join(", "),
+    ));
+
+    if options.extern_c {
+        header.push_str("\n#ifdef __cplusplus\n}\n#endif\n");
+    }
+
+    header.push_str("\n#endif");
+
+    let header = match options.post_process {
+        Some(command) => post_process::run(command, header.as_bytes())?,
+        None => header.into_bytes(),
+    };
+    std::fs::write(header_path, header)?;
+    Ok(())
+}
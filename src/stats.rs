@@ -0,0 +1,89 @@
+//! Waveform statistics of the emitted samples for sanity checking a conversion.
+use crate::transform::full_scale;
+use crate::{SampleEmitter, WavMetadata};
+use std::fmt;
+
+/// Collects peak, RMS, DC offset and full scale clipping of the samples
+#[derive(Debug, Default)]
+pub struct StatsEmitter {
+    bounds: (i64, i64),
+    count: usize,
+    peak: u64,
+    // exact sums, squares of 32-bit samples overflow i64 after a few and lose precision in f64
+    sum: i128,
+    sum_squares: i128,
+    clipped: usize,
+}
+
+impl StatsEmitter {
+    /// Largest absolute sample value
+    pub fn peak(&self) -> u64 {
+        self.peak
+    }
+
+    /// Root mean square of the samples
+    pub fn rms(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        (self.sum_squares as f64 / self.count as f64).sqrt()
+    }
+
+    /// Mean of the samples
+    pub fn dc_offset(&self) -> f64 {
+        if self.count == 0 {
+            return 0.0;
+        }
+        self.sum as f64 / self.count as f64
+    }
+
+    /// Number of samples at the minimum or maximum of the sample depth
+    pub fn clipped(&self) -> usize {
+        self.clipped
+    }
+
+    /// `value` relative to the positive full scale in dB
+    fn dbfs(&self, value: f64) -> f64 {
+        20.0 * (value / self.bounds.1 as f64).log10()
+    }
+}
+
+impl SampleEmitter for StatsEmitter {
+    fn begin(&mut self, meta: &WavMetadata) {
+        *self = StatsEmitter {
+            bounds: full_scale(meta.bits_per_sample),
+            ..Default::default()
+        };
+    }
+
+    fn sample(&mut self, value: i64) {
+        self.count += 1;
+        self.peak = self.peak.max(value.unsigned_abs());
+        self.sum += value as i128;
+        self.sum_squares += value as i128 * value as i128;
+        if value <= self.bounds.0 || value >= self.bounds.1 {
+            self.clipped += 1;
+        }
+    }
+
+    fn end(&mut self) {}
+}
+
+impl fmt::Display for StatsEmitter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Peak: {} ({:.2} dBFS)",
+            self.peak,
+            self.dbfs(self.peak as f64)
+        )?;
+        writeln!(
+            f,
+            "RMS: {:.2} ({:.2} dBFS)",
+            self.rms(),
+            self.dbfs(self.rms())
+        )?;
+        writeln!(f, "DC offset: {:.2}", self.dc_offset())?;
+        write!(f, "Clipped: {} samples at full scale", self.clipped)
+    }
+}
@@ -0,0 +1,183 @@
+//! Custom output from a template with `{{field}}` placeholders.
+use crate::{format_number, SampleEmitter, WavMetadata, WavToCError, WavToCOptions};
+use std::io::Write;
+
+/// Value substituted for a placeholder
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Name,
+    Type,
+    Count,
+    SampleRate,
+    Channels,
+    Bits,
+    Samples,
+}
+
+impl Field {
+    fn parse(name: &str) -> Option<Self> {
+        Some(match name {
+            "name" => Field::Name,
+            "type" => Field::Type,
+            "count" => Field::Count,
+            "sample_rate" => Field::SampleRate,
+            "channels" => Field::Channels,
+            "bits" => Field::Bits,
+            "samples" => Field::Samples,
+            _ => return None,
+        })
+    }
+}
+
+/// Literal text or a placeholder of a template
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Field(Field),
+}
+
+/// Parsed template
+///
+/// Placeholders are `{{name}}`, `{{type}}`, `{{count}}`, `{{sample_rate}}`, `{{channels}}`,
+/// `{{bits}}` and `{{samples}}`, with optional spaces inside the braces. `\{{` is a literal
+/// `{{`. `{{samples}}` may appear at most once since the samples are streamed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct Template(Vec<Segment>);
+
+impl Template {
+    pub(crate) fn parse(template: &str) -> Result<Self, WavToCError> {
+        let mut segments = Vec::new();
+        let mut text = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find("{{") {
+            if rest[..start].ends_with('\\') {
+                text.push_str(&rest[..start - 1]);
+                text.push_str("{{");
+                rest = &rest[start + 2..];
+                continue;
+            }
+            text.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find("}}") else {
+                return Err(WavToCError::InvalidInput(
+                    "Template has an unclosed '{{'.".to_string(),
+                ));
+            };
+            let name = rest[start + 2..start + end].trim();
+            let field = Field::parse(name).ok_or_else(|| {
+                WavToCError::InvalidInput(format!("Unknown template placeholder '{}'.", name))
+            })?;
+            if !text.is_empty() {
+                segments.push(Segment::Text(std::mem::take(&mut text)));
+            }
+            segments.push(Segment::Field(field));
+            rest = &rest[start + end + 2..];
+        }
+        text.push_str(rest);
+        if !text.is_empty() {
+            segments.push(Segment::Text(text));
+        }
+
+        let samples = segments
+            .iter()
+            .filter(|s| **s == Segment::Field(Field::Samples))
+            .count();
+        if samples > 1 {
+            return Err(WavToCError::InvalidInput(
+                "Template can only contain '{{samples}}' once.".to_string(),
+            ));
+        }
+
+        Ok(Template(segments))
+    }
+}
+
+/// Streams a [`Template`] to a writer, the samples comma separated in the array number format
+pub(crate) struct TemplateEmitter<'a, W: Write> {
+    options: &'a WavToCOptions<'a>,
+    template: Template,
+    writer: W,
+    error: Option<std::io::Error>,
+    meta: Option<WavMetadata>,
+    index: usize,
+}
+
+impl<'a, W: Write> TemplateEmitter<'a, W> {
+    pub(crate) fn new(options: &'a WavToCOptions<'a>, template: Template, writer: W) -> Self {
+        TemplateEmitter {
+            options,
+            template,
+            writer,
+            error: None,
+            meta: None,
+            index: 0,
+        }
+    }
+
+    fn write(&mut self, text: &str) {
+        if self.error.is_none() {
+            if let Err(e) = self.writer.write_all(text.as_bytes()) {
+                self.error = Some(e);
+            }
+        }
+    }
+
+    /// Text of the segments from `start` up to the samples placeholder or the end
+    fn render(&self, start: usize) -> String {
+        let Some(meta) = &self.meta else {
+            return String::new();
+        };
+        let mut text = String::new();
+        for segment in &self.template.0[start..] {
+            match segment {
+                Segment::Text(literal) => text.push_str(literal),
+                Segment::Field(Field::Samples) => break,
+                Segment::Field(Field::Name) => text.push_str(&meta.name),
+                Segment::Field(Field::Type) => text.push_str(&meta.c_type),
+                Segment::Field(Field::Count) => text.push_str(&meta.sample_count.to_string()),
+                Segment::Field(Field::SampleRate) => text.push_str(&meta.sample_rate.to_string()),
+                Segment::Field(Field::Channels) => text.push_str(&meta.frame_channels.to_string()),
+                Segment::Field(Field::Bits) => text.push_str(&meta.bits_per_sample.to_string()),
+            }
+        }
+        text
+    }
+
+    /// Flush the writer and return it, or the first error that occurred while writing
+    pub(crate) fn finish(mut self) -> std::io::Result<W> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        self.writer.flush()?;
+        Ok(self.writer)
+    }
+}
+
+impl<W: Write> SampleEmitter for TemplateEmitter<'_, W> {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.meta = Some(meta.clone());
+        self.index = 0;
+        let text = self.render(0);
+        self.write(&text);
+    }
+
+    fn sample(&mut self, value: i64) {
+        let bits = self.meta.as_ref().map_or(0, |meta| meta.bits_per_sample);
+        let value = format_number(value, &self.options.format, bits);
+        let token = if self.index == 0 {
+            value
+        } else {
+            format!(", {}", value)
+        };
+        self.index += 1;
+        self.write(&token);
+    }
+
+    fn end(&mut self) {
+        // without samples everything was written at the start
+        let samples = Segment::Field(Field::Samples);
+        if let Some(index) = self.template.0.iter().position(|s| *s == samples) {
+            let text = self.render(index + 1);
+            self.write(&text);
+        }
+    }
+}
@@ -0,0 +1,492 @@
+//! Sample transforms applied between decoding and emitting.
+use log::info;
+
+/// SplitMix64 PRNG, small and good enough for dither noise
+#[derive(Debug)]
+pub(crate) struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Minimum and maximum value of the C type used for `bits_per_sample`
+pub(crate) fn sample_bounds(bits_per_sample: u16) -> (i64, i64) {
+    match bits_per_sample {
+        0..=8 => (i8::MIN as i64, i8::MAX as i64),
+        9..=16 => (i16::MIN as i64, i16::MAX as i64),
+        _ => (i32::MIN as i64, i32::MAX as i64),
+    }
+}
+
+/// Minimum and maximum of two's complement samples of `bits_per_sample`, their full scale
+///
+/// Narrower than [`sample_bounds`] for depths such as 12 or 24 bits held in a wider type.
+pub(crate) fn full_scale(bits_per_sample: u16) -> (i64, i64) {
+    let max = (1i64 << (bits_per_sample.clamp(1, 32) - 1)) - 1;
+    (-max - 1, max)
+}
+
+/// `value` saturated to the range of the C type holding samples of `bits_per_sample`, and
+/// whether it was out of range
+///
+/// Transforms work on wider values so any result outside the type, such as a sum of channels,
+/// is saturated here once rather than wrapping when cast to the type.
+pub fn fit_to_type(value: i64, bits_per_sample: u16) -> (i64, bool) {
+    let (min, max) = sample_bounds(bits_per_sample);
+    let fitted = value.clamp(min, max);
+    (fitted, fitted != value)
+}
+
+/// Signed `value` of `bits_per_sample` as offset binary, the most negative value being zero
+pub(crate) fn offset_binary(value: i64, bits_per_sample: u16) -> i64 {
+    value + (1i64 << (bits_per_sample.max(1) - 1))
+}
+
+/// `value` with the byte order of its `width` byte array element reversed, read back as the
+/// signed element
+pub(crate) fn byte_swap(value: i64, width: usize) -> i64 {
+    match width {
+        0..=1 => value,
+        2 => (value as i16).swap_bytes() as i64,
+        _ => (value as i32).swap_bytes() as i64,
+    }
+}
+
+/// Scale a float sample in -1.0..=1.0 to 16-bit int, saturating out of range values
+pub(crate) fn float_to_i16(sample: f64) -> i32 {
+    (sample * i16::MAX as f64)
+        .round()
+        .clamp(i16::MIN as f64, i16::MAX as f64) as i32
+}
+
+/// Scale the side component of a stereo frame by `width`, rebuilding left and right from mid/side
+///
+/// 0 collapses both channels to the mid, 1 leaves them unchanged and above 1 widens. Results
+/// may be outside the sample type when widening.
+pub(crate) fn stereo_width(frame: [i64; 2], width: f64) -> [i64; 2] {
+    let [left, right] = frame;
+    let mid = (left + right) as f64 / 2.0;
+    let side = (left - right) as f64 / 2.0 * width;
+
+    [mid + side, mid - side].map(|v| v.round() as i64)
+}
+
+/// Mid (L+R)/2 and side (L-R)/2 of a stereo frame, rounded
+pub(crate) fn mid_side(frame: [i64; 2]) -> [i64; 2] {
+    let [left, right] = frame;
+
+    [(left + right) as f64 / 2.0, (left - right) as f64 / 2.0].map(|v| v.round() as i64)
+}
+
+/// Subtract the rounded mean of each channel from interleaved `samples` with `channels` per
+/// frame, returning the means removed
+pub(crate) fn remove_dc(samples: &mut [i64], channels: usize) -> Vec<i64> {
+    let frames = samples.len() / channels;
+    if frames == 0 {
+        return vec![0; channels];
+    }
+
+    let mut sums = vec![0i128; channels];
+    for frame in samples.chunks_exact(channels) {
+        for (sum, &sample) in sums.iter_mut().zip(frame) {
+            *sum += sample as i128;
+        }
+    }
+    let offsets: Vec<i64> = sums
+        .iter()
+        .map(|&sum| (sum as f64 / frames as f64).round() as i64)
+        .collect();
+
+    for frame in samples.chunks_exact_mut(channels) {
+        for (sample, offset) in frame.iter_mut().zip(&offsets) {
+            *sample -= offset;
+        }
+    }
+
+    offsets
+}
+
+/// Interleaved `samples` resampled from `from_rate` to `to_rate` by linear interpolation
+/// between frames
+///
+/// Downsampling with `antialias` first low-passes each channel with two cascaded
+/// [`Biquad::lowpass`] sections at 90% of the new Nyquist frequency, 24 dB per octave, so
+/// content above it does not alias into the band.
+pub(crate) fn resample(
+    samples: &[i64],
+    channels: usize,
+    from_rate: u32,
+    to_rate: u32,
+    antialias: bool,
+) -> Vec<i64> {
+    let frames = samples.len() / channels;
+    let mut filtered: Vec<f64> = samples.iter().map(|&s| s as f64).collect();
+    if antialias && to_rate < from_rate {
+        let cutoff = 0.45 * to_rate as f64;
+        for channel in 0..channels {
+            for _ in 0..2 {
+                let mut lowpass = Biquad::lowpass(cutoff, from_rate);
+                for sample in filtered.iter_mut().skip(channel).step_by(channels) {
+                    *sample = lowpass.filter(*sample);
+                }
+            }
+        }
+    }
+
+    let out_frames = (frames as u64 * to_rate as u64 / from_rate as u64) as usize;
+    let mut resampled = Vec::with_capacity(out_frames * channels);
+    for frame in 0..out_frames {
+        let position = frame as f64 * from_rate as f64 / to_rate as f64;
+        let before = (position as usize).min(frames - 1);
+        let after = (before + 1).min(frames - 1);
+        let fraction = position - before as f64;
+        for channel in 0..channels {
+            let a = filtered[before * channels + channel];
+            let b = filtered[after * channels + channel];
+            resampled.push((a + (b - a) * fraction).round() as i64);
+        }
+    }
+    resampled
+}
+
+/// Index of the first frame of interleaved `samples` with a sample magnitude above `threshold`
+pub(crate) fn onset(samples: &[i64], channels: usize, threshold: f64) -> Option<usize> {
+    samples
+        .chunks_exact(channels)
+        .position(|frame| frame.iter().any(|&s| s.unsigned_abs() as f64 > threshold))
+}
+
+/// Reverse the order of the frames of interleaved `samples`, keeping the channel order
+pub(crate) fn reverse_frames(samples: &mut [i64], channels: usize) {
+    samples.reverse();
+    for frame in samples.chunks_exact_mut(channels) {
+        frame.reverse();
+    }
+}
+
+/// Circularly rotate interleaved `samples` so the frame with the first maximum amplitude sample
+/// is at index 0
+pub(crate) fn rotate_to_peak(samples: &mut [i64], channels: usize) {
+    // max_by_key returns the last of equal elements so search the reversed order
+    if let Some((peak, _)) = samples
+        .iter()
+        .enumerate()
+        .rev()
+        .max_by_key(|(_, sample)| sample.unsigned_abs())
+    {
+        let frame = peak / channels;
+        info!("Rotating samples to start at peak frame {}.", frame);
+        samples.rotate_left(frame * channels);
+    }
+}
+
+/// Second order Butterworth biquad from the RBJ audio EQ cookbook, in direct form I
+///
+/// Either rolls off 12 dB per octave beyond the cutoff. The high-pass has a double zero at DC,
+/// so any offset is removed once the filter settles.
+#[derive(Debug, Clone)]
+pub(crate) struct Biquad {
+    /// b0, b1, b2, a1 and a2 normalized by a0
+    coefficients: [f64; 5],
+    /// x[n-1], x[n-2], y[n-1] and y[n-2]
+    state: [f64; 4],
+}
+
+impl Biquad {
+    pub(crate) fn highpass(cutoff_hz: f64, sample_rate: u32) -> Self {
+        Self::butterworth(cutoff_hz, sample_rate, |cos| (1.0 + cos) / 2.0, -1.0)
+    }
+
+    pub(crate) fn lowpass(cutoff_hz: f64, sample_rate: u32) -> Self {
+        Self::butterworth(cutoff_hz, sample_rate, |cos| (1.0 - cos) / 2.0, 1.0)
+    }
+
+    /// Filter with b0 = b2 = `b0(cos(w0))` and b1 = `sign` * 2 * b0
+    fn butterworth(cutoff_hz: f64, sample_rate: u32, b0: fn(f64) -> f64, sign: f64) -> Self {
+        let w0 = 2.0 * std::f64::consts::PI * cutoff_hz / sample_rate as f64;
+        // sin(w0) / 2Q with the Butterworth Q of 1/sqrt(2)
+        let alpha = w0.sin() * std::f64::consts::FRAC_1_SQRT_2;
+        let cos = w0.cos();
+        let a0 = 1.0 + alpha;
+        let b0 = b0(cos) / a0;
+        Biquad {
+            coefficients: [b0, sign * 2.0 * b0, b0, -2.0 * cos / a0, (1.0 - alpha) / a0],
+            state: [0.0; 4],
+        }
+    }
+
+    pub(crate) fn filter(&mut self, x: f64) -> f64 {
+        let [b0, b1, b2, a1, a2] = self.coefficients;
+        let [x1, x2, y1, y2] = self.state;
+        let y = b0 * x + b1 * x1 + b2 * x2 - a1 * y1 - a2 * y2;
+        self.state = [x, x1, y, y1];
+        y
+    }
+}
+
+/// Transforms applied to each sample independently so they can run while streaming
+#[derive(Debug)]
+pub(crate) struct SampleProcessor {
+    gain: Option<f64>,
+    /// Source depth of the samples, saturated at its type by [`fit_to_type`]
+    bits_per_sample: u16,
+    gain_clipped: usize,
+    /// Samples out of the type bounds other than by the gain
+    clipped: usize,
+    /// Fade in and out lengths in frames
+    fade: (usize, usize),
+    len: usize,
+    channels: usize,
+    index: usize,
+    /// Right shift and bounds of the requantized samples
+    requantize: Option<(u32, (i64, i64))>,
+    /// Left shift of the upscaled samples, their source depth and whether to fill the low bits
+    upscale: Option<(u32, u16, bool)>,
+    /// Dither noise and the low bits it spans, if not those dropped by the requantization
+    dither: Option<(SplitMix64, Option<u16>)>,
+    /// Ceiling of the soft limiter in sample units
+    limit: Option<f64>,
+    limited: usize,
+    /// Largest magnitude of the emitted samples
+    clamp: Option<i64>,
+    clamped: usize,
+    /// High-pass filter of each channel, empty if not filtering
+    highpass: Vec<Biquad>,
+}
+
+/// `sample` of `from_bits` left shifted by `shift`, the vacated bits repeating the bits of the
+/// sample from the most significant if `replicate`
+///
+/// Replication works on the two's complement bits so zero and -1 are unchanged.
+fn upscale(sample: i64, shift: u32, from_bits: u16, replicate: bool) -> i64 {
+    let shifted = sample << shift;
+    if !replicate {
+        return shifted;
+    }
+    let from_bits = from_bits as u32;
+    let bits = sample & ((1 << from_bits) - 1);
+    let mut low = 0;
+    let mut remaining = shift;
+    while remaining > 0 {
+        let take = remaining.min(from_bits);
+        low |= (bits >> (from_bits - take)) << (remaining - take);
+        remaining -= take;
+    }
+    shifted | low
+}
+
+/// Soft limit `value` below `ceiling`: linear up to half the ceiling then a tanh curve
+/// approaching it, continuous in slope at the knee
+pub(crate) fn soft_limit(value: f64, ceiling: f64) -> f64 {
+    let knee = ceiling / 2.0;
+    let magnitude = value.abs();
+    if magnitude <= knee {
+        return value;
+    }
+    let range = ceiling - knee;
+    (knee + range * ((magnitude - knee) / range).tanh()).copysign(value)
+}
+
+impl SampleProcessor {
+    pub(crate) fn new(gain: Option<f64>, bits_per_sample: u16) -> Self {
+        SampleProcessor {
+            gain,
+            bits_per_sample,
+            gain_clipped: 0,
+            clipped: 0,
+            fade: (0, 0),
+            len: 0,
+            channels: 1,
+            index: 0,
+            requantize: None,
+            upscale: None,
+            dither: None,
+            limit: None,
+            limited: 0,
+            clamp: None,
+            clamped: 0,
+            highpass: Vec::new(),
+        }
+    }
+
+    /// High-pass filter each channel at `cutoff_hz` before the gain, after [`Self::fade`] has
+    /// set the channels
+    pub(crate) fn highpass(mut self, cutoff_hz: f64, sample_rate: u32) -> Self {
+        self.highpass = vec![Biquad::highpass(cutoff_hz, sample_rate); self.channels];
+        self
+    }
+
+    /// Hard clamp the emitted samples to +-`value`, after every other stage so it is in units of
+    /// the emitted depth
+    pub(crate) fn clamp_amplitude(mut self, value: u32) -> Self {
+        self.clamp = Some(value as i64);
+        self
+    }
+
+    /// Soft limit the samples after the gain to a ceiling of `db` relative to the full scale of
+    /// the source `bits_per_sample`
+    pub(crate) fn limit(mut self, db: f64, bits_per_sample: u16) -> Self {
+        self.limit = Some(full_scale(bits_per_sample).1 as f64 * 10f64.powf(db / 20.0));
+        self
+    }
+
+    /// Linear fade in and out over the given number of frames of a clip `len` frames long, with
+    /// `channels` interleaved samples per frame
+    ///
+    /// Fades longer than the clip are clamped to it.
+    pub(crate) fn fade(
+        mut self,
+        fade_in: usize,
+        fade_out: usize,
+        len: usize,
+        channels: usize,
+    ) -> Self {
+        self.fade = (fade_in.min(len), fade_out.min(len));
+        self.len = len;
+        self.channels = channels;
+        self
+    }
+
+    /// Reduce samples from `from_bits` to `to_bits`, rounding to the nearest step, or left
+    /// shift them up if `to_bits` is greater
+    pub(crate) fn requantize(mut self, from_bits: u16, to_bits: u16) -> Self {
+        if to_bits < from_bits {
+            let max = (1i64 << (to_bits - 1)) - 1;
+            self.requantize = Some(((from_bits - to_bits) as u32, (-max - 1, max)));
+        } else if to_bits > from_bits {
+            self.upscale = Some(((to_bits - from_bits) as u32, from_bits, false));
+        }
+        self
+    }
+
+    /// Fill the low bits of upscaled samples by repeating their bits rather than with zeros, so
+    /// the maximum comes closer to the full scale of the new depth
+    pub(crate) fn replicate_lsb(mut self) -> Self {
+        if let Some((_, _, replicate)) = self.upscale.as_mut() {
+            *replicate = true;
+        }
+        self
+    }
+
+    /// Add TPDF dither of +-1 LSB of the requantized depth before rounding, from a PRNG seeded
+    /// with `seed` so the output is reproducible
+    ///
+    /// With `bits` the noise spans that many low bits of the source samples instead, more
+    /// over-dithering and fewer under-dithering.
+    pub(crate) fn dither(mut self, seed: u64, bits: Option<u16>) -> Self {
+        self.dither = Some((SplitMix64(seed), bits));
+        self
+    }
+
+    pub(crate) fn process(&mut self, mut sample: i64) -> i64 {
+        if !self.highpass.is_empty() {
+            let channel = self.index % self.channels;
+            sample = self.highpass[channel].filter(sample as f64).round() as i64;
+        }
+
+        if self.gain.is_some() || self.limit.is_some() {
+            let mut scaled = sample as f64 * self.gain.unwrap_or(1.0);
+            if let Some(ceiling) = self.limit {
+                let limited = soft_limit(scaled, ceiling);
+                if limited != scaled {
+                    self.limited += 1;
+                }
+                scaled = limited;
+            }
+            // saturate at the type bounds rather than wrapping, the cast saturating at i64
+            let (scaled, clipped) = fit_to_type(scaled.round() as i64, self.bits_per_sample);
+            if clipped {
+                self.gain_clipped += 1;
+            }
+            sample = scaled;
+        }
+
+        // ramps reach zero at the first and last frame
+        let (fade_in, fade_out) = self.fade;
+        let frame = self.index / self.channels;
+        if frame < fade_in {
+            sample = sample * frame as i64 / fade_in as i64;
+        }
+        let remaining = self.len.saturating_sub(frame + 1);
+        if remaining < fade_out {
+            sample = sample * remaining as i64 / fade_out as i64;
+        }
+        self.index += 1;
+
+        let (sample, clipped) = fit_to_type(sample, self.bits_per_sample);
+        if clipped {
+            self.clipped += 1;
+        }
+        let sample = match self.requantize {
+            // rounding can carry the maximum over the reduced range
+            Some((shift, bounds)) => {
+                // difference of two uniform values is triangular over +-1 LSB of the new depth
+                let noise = match self.dither.as_mut() {
+                    Some((rng, bits)) => {
+                        let step = 1u64 << bits.map_or(shift, u32::from);
+                        (rng.next() % step) as i64 - (rng.next() % step) as i64
+                    }
+                    None => 0,
+                };
+                ((sample + noise + (1 << (shift - 1))) >> shift).clamp(bounds.0, bounds.1)
+            }
+            None => match self.upscale {
+                Some((shift, from_bits, replicate)) => {
+                    fit_to_type(
+                        upscale(sample, shift, from_bits, replicate),
+                        from_bits + shift as u16,
+                    )
+                    .0
+                }
+                None => sample,
+            },
+        };
+
+        match self.clamp {
+            Some(max) => {
+                if sample.abs() > max {
+                    self.clamped += 1;
+                }
+                sample.clamp(-max, max)
+            }
+            None => sample,
+        }
+    }
+
+    /// Log a summary once all samples are processed, returning warnings for any clipping
+    pub(crate) fn report(&self) -> Vec<String> {
+        let mut warnings = Vec::new();
+        if self.limit.is_some() {
+            info!("Soft limited {} samples.", self.limited);
+        }
+        if let Some(gain) = self.gain {
+            if self.gain_clipped > 0 {
+                warnings.push(format!(
+                    "Gain of {} clipped {} samples.",
+                    gain, self.gain_clipped
+                ));
+            } else {
+                info!("Applied gain of {}.", gain);
+            }
+        }
+        if self.clipped > 0 {
+            warnings.push(format!(
+                "Clipped {} samples outside the sample type range.",
+                self.clipped
+            ));
+        }
+        if let Some(max) = self.clamp.filter(|_| self.clamped > 0) {
+            warnings.push(format!(
+                "Clamped {} samples to an amplitude of {}.",
+                self.clamped, max
+            ));
+        }
+        warnings
+    }
+}
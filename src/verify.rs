@@ -0,0 +1,79 @@
+//! Comparison of the emitted samples against a reference WAV.
+use crate::{SampleEmitter, WavMetadata};
+
+/// Collects the samples of a conversion
+#[derive(Debug, Default)]
+pub(crate) struct SampleCollector(pub(crate) Vec<i64>);
+
+impl SampleEmitter for SampleCollector {
+    fn begin(&mut self, meta: &WavMetadata) {
+        self.0 = Vec::with_capacity(meta.sample_count);
+    }
+
+    fn sample(&mut self, value: i64) {
+        self.0.push(value);
+    }
+
+    fn end(&mut self) {}
+}
+
+/// Compares each emitted sample with the reference samples as it is emitted
+#[derive(Debug)]
+pub(crate) struct VerifyEmitter {
+    reference: Vec<i64>,
+    tolerance: u64,
+    index: usize,
+    mismatches: usize,
+    first_mismatch: Option<(usize, i64, i64)>,
+}
+
+impl VerifyEmitter {
+    pub(crate) fn new(reference: Vec<i64>, tolerance: u64) -> Self {
+        VerifyEmitter {
+            reference,
+            tolerance,
+            index: 0,
+            mismatches: 0,
+            first_mismatch: None,
+        }
+    }
+
+    /// Description of how the samples differ from the reference, `None` if they match
+    pub(crate) fn mismatch(&self) -> Option<String> {
+        if self.index != self.reference.len() {
+            return Some(format!(
+                "{} samples emitted but the reference has {}",
+                self.index,
+                self.reference.len()
+            ));
+        }
+
+        self.first_mismatch.map(|(index, value, expected)| {
+            format!(
+                "{} samples differ by more than {}, first at index {}: {} but expected {}",
+                self.mismatches, self.tolerance, index, value, expected
+            )
+        })
+    }
+}
+
+impl SampleEmitter for VerifyEmitter {
+    fn begin(&mut self, _meta: &WavMetadata) {
+        self.index = 0;
+        self.mismatches = 0;
+        self.first_mismatch = None;
+    }
+
+    fn sample(&mut self, value: i64) {
+        if let Some(&expected) = self.reference.get(self.index) {
+            if value.abs_diff(expected) > self.tolerance {
+                self.mismatches += 1;
+                self.first_mismatch
+                    .get_or_insert((self.index, value, expected));
+            }
+        }
+        self.index += 1;
+    }
+
+    fn end(&mut self) {}
+}
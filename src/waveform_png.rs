@@ -0,0 +1,89 @@
+//! PNG render of the emitted waveform as a visual reference for generated assets.
+//!
+//! The image is 8-bit grayscale in uncompressed deflate blocks, so it needs no dependencies.
+use crate::checksum::crc32_update;
+use crate::preview::PreviewEmitter;
+use std::path::Path;
+
+/// Most columns of the image, the samples being decimated to fit
+pub(crate) const WIDTH: usize = 800;
+/// Rows of the image, odd so there is a centre line at zero
+const HEIGHT: usize = 201;
+
+const BACKGROUND: u8 = 0xff;
+const CENTRE_LINE: u8 = 0xc0;
+const WAVEFORM: u8 = 0x30;
+
+/// Largest block of uncompressed deflate data
+const STORED_BLOCK: usize = 0xffff;
+
+/// Append the PNG chunk `kind` of `data` with its length and CRC-32
+fn push_chunk(png: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    png.extend_from_slice(kind);
+    png.extend_from_slice(data);
+    let crc = kind
+        .iter()
+        .chain(data)
+        .fold(0xffff_ffff, |crc, byte| crc32_update(crc, *byte));
+    png.extend_from_slice(&(!crc).to_be_bytes());
+}
+
+/// zlib stream of non-empty `data` in stored, uncompressed, deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate with a 32K window and no preset dictionary
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(STORED_BLOCK).peekable();
+    while let Some(block) = blocks.next() {
+        let last = blocks.peek().is_none();
+        stream.push(last as u8);
+        let len = block.len() as u16;
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+
+    let (a, b) = data.iter().fold((1u32, 0u32), |(a, b), byte| {
+        let a = (a + *byte as u32) % 65521;
+        (a, (b + a) % 65521)
+    });
+    stream.extend_from_slice(&((b << 16) | a).to_be_bytes());
+    stream
+}
+
+/// PNG image of the columns of `waveform`, one pixel column each
+pub(crate) fn render(waveform: &PreviewEmitter) -> Vec<u8> {
+    let columns = waveform.column_rows(HEIGHT);
+    let width = columns.len().max(1);
+
+    // each row is preceded by its filter type, none
+    let mut pixels = Vec::with_capacity((width + 1) * HEIGHT);
+    for row in 0..HEIGHT {
+        pixels.push(0);
+        let background = match row == HEIGHT / 2 {
+            true => CENTRE_LINE,
+            false => BACKGROUND,
+        };
+        pixels.extend((0..width).map(|column| match columns.get(column) {
+            Some(rows) if rows.contains(&row) => WAVEFORM,
+            _ => background,
+        }));
+    }
+
+    let mut header = Vec::with_capacity(13);
+    header.extend_from_slice(&(width as u32).to_be_bytes());
+    header.extend_from_slice(&(HEIGHT as u32).to_be_bytes());
+    // 8-bit grayscale, deflate, adaptive filtering and no interlace
+    header.extend_from_slice(&[8, 0, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    push_chunk(&mut png, b"IHDR", &header);
+    push_chunk(&mut png, b"IDAT", &zlib_stored(&pixels));
+    push_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Write the [`render`] of `waveform` to `path`
+pub(crate) fn write(path: &Path, waveform: &PreviewEmitter) -> std::io::Result<()> {
+    std::fs::write(path, render(waveform))
+}
@@ -0,0 +1,131 @@
+const size_t MONO_16BIT_1000_MUTABLE_ALIGN_SAMPLE_NO = 1000;
+
+#define MONO_16BIT_1000_MUTABLE_ALIGN_SAMPLE_RATE 10000
+
+int16_t mono_16bit_1000_mutable_align[1000] __attribute__((aligned(4))) = {
+	 0, 8943, 17208, 24166, 29288, 32186, 32640, 30615,
+	 26264, 19920, 12062, 3288, -5734, -14322, -21823, -27666,
+	 -31407, -32764, -31632, -28098, -22430, -15059, -6543, 2468,
+	 11292, 19259, 25764, 30312, 32557, 32330, 29648, 24714,
+	 17903, 9733, 823, -8148, -16502, -23602, -28910, -32022,
+	 -32702, -30898, -26748, -20567, -12824, -4106, 4922, 13577,
+	 21202, 27216, 31163, 32743, 31837, 28513, 23023, 15785,
+	 7348, -1646, -10516, -18587, -25247, -29989, -32454, -32454,
+	 -29989, -25247, -18587, -10516, -1646, 7348, 15785, 23023,
+	 28513, 31837, 32743, 31163, 27216, 21202, 13577, 4922,
+	 -4106, -12824, -20567, -26749, -30898, -32702, -32022, -28910,
+	 -23602, -16502, -8148, 823, 9733, 17903, 24714, 29648,
+	 32330, 32557, 30312, 25764, 19259, 11292, 2468, -6543,
+	 -15059, -22430, -28098, -31632, -32764, -31407, -27666, -21823,
+	 -14322, -5735, 3288, 12062, 19920, 26264, 30615, 32640,
+	 32186, 29288, 24166, 17208, 8943, 0, -8943, -17208,
+	 -24166, -29288, -32186, -32640, -30615, -26264, -19920, -12062,
+	 -3288, 5735, 14322, 21823, 27666, 31407, 32764, 31632,
+	 28098, 22430, 15059, 6543, -2468, -11292, -19260, -25764,
+	 -30311, -32557, -32330, -29648, -24714, -17903, -9733, -823,
+	 8148, 16502, 23602, 28910, 32022, 32702, 30898, 26748,
+	 20567, 12824, 4106, -4922, -13577, -21202, -27216, -31163,
+	 -32743, -31837, -28513, -23023, -15785, -7348, 1646, 10516,
+	 18587, 25247, 29989, 32454, 32454, 29989, 25247, 18587,
+	 10516, 1646, -7348, -15785, -23023, -28513, -31837, -32743,
+	 -31163, -27216, -21202, -13577, -4922, 4106, 12824, 20567,
+	 26748, 30898, 32702, 32022, 28910, 23602, 16502, 8148,
+	 -823, -9733, -17903, -24714, -29648, -32330, -32557, -30312,
+	 -25764, -19259, -11293, -2468, 6543, 15059, 22430, 28098,
+	 31632, 32764, 31407, 27666, 21823, 14322, 5735, -3288,
+	 -12062, -19920, -26264, -30615, -32640, -32186, -29288, -24166,
+	 -17208, -8943, 0, 8943, 17208, 24166, 29288, 32186,
+	 32640, 30615, 26264, 19920, 12062, 3288, -5735, -14323,
+	 -21823, -27666, -31407, -32764, -31632, -28098, -22430, -15059,
+	 -6543, 2468, 11292, 19259, 25764, 30311, 32557, 32330,
+	 29648, 24714, 17903, 9733, 823, -8148, -16502, -23602,
+	 -28909, -32022, -32702, -30898, -26749, -20567, -12824, -4106,
+	 4922, 13577, 21202, 27216, 31163, 32743, 31837, 28513,
+	 23023, 15785, 7348, -1646, -10516, -18587, -25247, -29989,
+	 -32454, -32454, -29989, -25247, -18587, -10516, -1646, 7348,
+	 15785, 23023, 28513, 31837, 32743, 31163, 27216, 21202,
+	 13577, 4922, -4106, -12824, -20567, -26749, -30898, -32702,
+	 -32022, -28909, -23602, -16502, -8148, 823, 9733, 17903,
+	 24714, 29648, 32330, 32557, 30311, 25764, 19259, 11292,
+	 2468, -6544, -15058, -22430, -28098, -31632, -32764, -31407,
+	 -27666, -21823, -14322, -5735, 3288, 12062, 19920, 26264,
+	 30615, 32640, 32186, 29288, 24166, 17208, 8943, 0,
+	 -8943, -17208, -24166, -29288, -32186, -32640, -30615, -26264,
+	 -19920, -12062, -3288, 5734, 14323, 21823, 27666, 31407,
+	 32764, 31632, 28098, 22430, 15058, 6543, -2468, -11292,
+	 -19259, -25764, -30312, -32557, -32330, -29648, -24714, -17903,
+	 -9732, -823, 8148, 16502, 23602, 28910, 32022, 32702,
+	 30898, 26748, 20567, 12824, 4106, -4922, -13578, -21202,
+	 -27216, -31163, -32743, -31837, -28513, -23023, -15785, -7348,
+	 1646, 10516, 18587, 25247, 29989, 32454, 32454, 29989,
+	 25247, 18587, 10516, 1646, -7348, -15785, -23023, -28513,
+	 -31837, -32743, -31163, -27216, -21202, -13577, -4922, 4106,
+	 12824, 20567, 26749, 30898, 32702, 32022, 28910, 23602,
+	 16502, 8148, -823, -9733, -17903, -24714, -29648, -32330,
+	 -32557, -30311, -25764, -19259, -11292, -2467, 6543, 15058,
+	 22430, 28098, 31632, 32764, 31407, 27666, 21823, 14323,
+	 5735, -3288, -12062, -19920, -26265, -30615, -32640, -32186,
+	 -29288, -24166, -17208, -8943, 0, 8943, 17208, 24166,
+	 29288, 32186, 32640, 30615, 26264, 19919, 12062, 3288,
+	 -5735, -14323, -21823, -27665, -31407, -32764, -31632, -28098,
+	 -22430, -15059, -6544, 2468, 11293, 19260, 25764, 30312,
+	 32557, 32330, 29648, 24714, 17903, 9733, 823, -8149,
+	 -16502, -23602, -28910, -32022, -32702, -30898, -26749, -20567,
+	 -12824, -4106, 4922, 13577, 21202, 27216, 31163, 32743,
+	 31837, 28513, 23023, 15785, 7348, -1646, -10516, -18587,
+	 -25247, -29989, -32454, -32454, -29989, -25247, -18587, -10516,
+	 -1646, 7348, 15785, 23023, 28513, 31837, 32743, 31163,
+	 27216, 21201, 13577, 4922, -4106, -12824, -20567, -26748,
+	 -30898, -32702, -32022, -28909, -23602, -16502, -8149, 823,
+	 9733, 17903, 24714, 29648, 32330, 32557, 30311, 25764,
+	 19259, 11292, 2468, -6543, -15059, -22430, -28098, -31632,
+	 -32764, -31407, -27665, -21823, -14322, -5734, 3288, 12062,
+	 19920, 26264, 30615, 32640, 32186, 29288, 24166, 17208,
+	 8943, 0, -8943, -17208, -24166, -29288, -32186, -32640,
+	 -30615, -26265, -19919, -12062, -3288, 5734, 14322, 21823,
+	 27666, 31407, 32764, 31632, 28098, 22430, 15058, 6543,
+	 -2468, -11292, -19259, -25764, -30312, -32557, -32330, -29648,
+	 -24714, -17903, -9733, -823, 8148, 16502, 23602, 28910,
+	 32022, 32702, 30898, 26748, 20567, 12824, 4106, -4922,
+	 -13577, -21202, -27216, -31163, -32743, -31837, -28513, -23023,
+	 -15785, -7348, 1646, 10516, 18587, 25247, 29989, 32454,
+	 32454, 29989, 25247, 18587, 10516, 1646, -7349, -15785,
+	 -23023, -28513, -31837, -32743, -31163, -27216, -21202, -13577,
+	 -4922, 4107, 12824, 20567, 26748, 30899, 32702, 32022,
+	 28910, 23602, 16502, 8148, -823, -9733, -17903, -24714,
+	 -29648, -32330, -32557, -30311, -25764, -19260, -11293, -2467,
+	 6544, 15058, 22430, 28098, 31632, 32764, 31407, 27666,
+	 21823, 14322, 5734, -3288, -12062, -19920, -26265, -30615,
+	 -32640, -32186, -29288, -24166, -17208, -8943, 0, 8944,
+	 17208, 24166, 29288, 32186, 32640, 30615, 26265, 19920,
+	 12062, 3288, -5735, -14323, -21822, -27666, -31407, -32764,
+	 -31632, -28098, -22430, -15059, -6543, 2468, 11292, 19260,
+	 25764, 30312, 32557, 32330, 29648, 24714, 17903, 9732,
+	 823, -8148, -16501, -23602, -28910, -32022, -32702, -30899,
+	 -26748, -20567, -12823, -4106, 4922, 13577, 21202, 27216,
+	 31163, 32743, 31837, 28513, 23023, 15785, 7348, -1646,
+	 -10516, -18588, -25247, -29989, -32454, -32454, -29989, -25247,
+	 -18587, -10516, -1646, 7348, 15785, 23024, 28513, 31837,
+	 32743, 31163, 27215, 21201, 13578, 4922, -4106, -12824,
+	 -20567, -26748, -30899, -32702, -32022, -28909, -23602, -16502,
+	 -8148, 823, 9733, 17903, 24714, 29648, 32330, 32557,
+	 30312, 25764, 19260, 11292, 2468, -6544, -15059, -22430,
+	 -28098, -31632, -32764, -31407, -27666, -21823, -14322, -5734,
+	 3288, 12062, 19920, 26264, 30615, 32640, 32186, 29288,
+	 24166, 17207, 8943, 0, -8943, -17208, -24166, -29288,
+	 -32186, -32640, -30615, -26264, -19920, -12061, -3288, 5734,
+	 14323, 21823, 27666, 31407, 32764, 31632, 28098, 22430,
+	 15059, 6543, -2468, -11292, -19260, -25764, -30311, -32557,
+	 -32330, -29648, -24714, -17904, -9733, -823, 8149, 16502,
+	 23602, 28910, 32022, 32702, 30898, 26748, 20567, 12824,
+	 4106, -4922, -13578, -21202, -27215, -31163, -32743, -31837,
+	 -28513, -23023, -15785, -7348, 1647, 10516, 18587, 25247,
+	 29989, 32454, 32454, 29989, 25247, 18588, 10516, 1646,
+	 -7348, -15785, -23023, -28513, -31837, -32743, -31163, -27216,
+	 -21202, -13577, -4921, 4106, 12823, 20568, 26748, 30899,
+	 32702, 32022, 28909, 23602, 16501, 8148, -822, -9733,
+	 -17903, -24714, -29648, -32330, -32557, -30312, -25764, -19260,
+	 -11293, -2468, 6543, 15059, 22430, 28098, 31632, 32764,
+	 31407, 27665, 21822, 14322, 5734, -3288, -12062, -19920,
+	 -26264, -30615, -32640, -32186, -29288, -24166, -17208, -8944,
+};
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef _MONO_16BIT_1000_MUTABLE_ALIGN_H_
+#define _MONO_16BIT_1000_MUTABLE_ALIGN_H_
+
+extern const size_t MONO_16BIT_1000_MUTABLE_ALIGN_SAMPLE_NO;
+extern int16_t mono_16bit_1000_mutable_align[] __attribute__((aligned(4)));
+
+#define MONO_16BIT_1000_MUTABLE_ALIGN_SAMPLE_RATE 10000
+
+#endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t MONO_BIT_SAMPLE_NO = 22050;
+const size_t MONO_32BIT_SAMPLE_NO = 22050;
 
-const int32_t mono_bit[22050] = {
+#define MONO_32BIT_SAMPLE_RATE 22050
+
+const int32_t mono_32bit[22050] = {
 	 0, 268543968, 532872000, 788834304, 1032412544, 1259782656, 1467375104, 1651930880,
 	 1810552576, 1940749952, 2040479104, 2108174208, 2142772480, 2143730816, 2111034368, 2045196160,
 	 1947249792, 1818733312, 1661663872, 1478507392, 1272140032, 1045800448, 803042624, 547678144,
@@ -4,4 +4,6 @@
 extern const size_t MONO_32BIT_SAMPLE_NO;
 extern const int32_t mono_32bit[];
 
+#define MONO_32BIT_SAMPLE_RATE 22050
+
 #endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t MONO_BIT_BASE_SAMPLE_NO = 22050;
+const size_t MONO_32BIT_BASE16_SAMPLE_NO = 22050;
 
-const int32_t mono_bit_base[22050] = {
+#define MONO_32BIT_BASE16_SAMPLE_RATE 22050
+
+const int32_t mono_32bit_base16[22050] = {
 	 0x00000000, 0x1001a7e0, 0x1fc2fb40, 0x2f04a800, 0x3d895d80, 0x4b16c200, 0x57765e00, 0x62767700,
 	 0x6bead700, 0x73ad7e80, 0x799f3d80, 0x7da82f80, 0x7fb81d00, 0x7fc6bc80, 0x7dd3d400, 0x79e73780,
 	 0x7410ac80, 0x6c67ab00, 0x630afa80, 0x58203b80, 0x4bd35100, 0x3e55a600, 0x2fdd7540, 0x20a4e7c0,
@@ -0,0 +1,2763 @@
+const size_t MONO_32BIT_PAD_WIDTH_SAMPLE_NO = 22050;
+
+#define MONO_32BIT_PAD_WIDTH_SAMPLE_RATE 22050
+
+const int32_t mono_32bit_pad_width[22050] = {
+	           0,   268543968,   532872000,   788834304,  1032412544,  1259782656,  1467375104,  1651930880,
+	  1810552576,  1940749952,  2040479104,  2108174208,  2142772480,  2143730816,  2111034368,  2045196160,
+	  1947249792,  1818733312,  1661663872,  1478507392,  1272140032,  1045800448,   803042624,   547678144,
+	   283714976,    15298243,  -253359184,  -518039040,  -774585600, -1018972224, -1247361280, -1456168192,
+	 -1642114304, -1802280064, -1934151424, -2035658624, -2105207040, -2141705344, -2144580480, -2113787392,
+	 -2049809408, -1953650688, -1826821504, -1671312512, -1489564672, -1284432384, -1059135552,  -817211072,
+	  -562456128,  -298872960,   -30595710,   238161856,   503179456,   760297536,  1005480448,  1234877952,
+	  1444887168,  1632213760,  1793916288,  1927455616,  2030734464,  2102132864,  2140529536,  2145321216,
+	  2116433024,  2054318592,  1959952768,  1834816384,  1680876800,  1500547712,  1296659584,  1072416384,
+	   831337152,   577206528,   314014208,    45890088,  -222952448,  -488292832,  -745971328,  -991938496,
+	 -1222329472, -1433532544, -1622231680, -1785461504, -1920660992, -2025707648, -2098952192, -2139245184,
+	 -2145953152, -2118971648, -2058723456, -1966155136, -1842719744, -1690355072, -1511453184, -1308820992,
+	 -1085642880,  -845420992,  -591928640,  -329137536,   -61185208,   207729696,   473385408,   731607296,
+	   978343552,  1209720576,  1422105856,  1612166656,  1776916096,  1913768448,  2020578688,  2095665152,
+	  2137852032,  2146476160,  2121402496,  2063023744,  1972257536,  1850528512,  1699746304,  1522280448,
+	  1320917632,  1098816000,   859463808,   606616768,   344248192,    76475176,  -192500480,  -458451968,
+	  -717206144,  -964698880, -1197050240, -1410608640, -1602018432, -1768280576, -1906781568, -2015345792,
+	 -2092271232, -2136350464, -2146890368, -2123725568, -2067219456, -1978260736, -1858243328, -1709052544,
+	 -1533033472, -1332947200, -1111931648,  -873459264,  -621278080,  -359339360,   -91759216,   177257408,
+	   443495264,   702766656,   951005312,  1184320896,  1399036672,  1591788928,  1759556480,  1899695232,
+	  2010010752,  2088772096,  2134740352,  2147195392,  2125940608,  2071310848,  1984162048,  1865862912,
+	  1718273280,  1543708544,  1344905984,  1124989056,   887414144,   635903872,   374410272,   107042696,
+	  -162009424,  -428514048,  -688291456,  -937267072, -1171528064, -1387393664, -1581481344, -1750740608,
+	 -1892512384, -2004574976, -2085165952, -2133022336, -2147391616, -2128048384, -2075296000, -1989964160,
+	 -1873389824, -1727404416, -1554302464, -1356796416, -1137996288,  -901323968,  -650497472,  -389470240,
+	  -122320736,   146753216,   413511104,   673785280,   923481344,  1158675840,  1375683456,  1571090816,
+	  1741836032,  1885235328,  1999036032,  2081454976,  2131195648,  2147478784,  2130047616,  2079176832,
+	  1995663744,  1880819712,  1736452736,  1564817536,  1368618112,  1150938880,   915184384,   665065792,
+	   404502400,   137588480,  -131481392,  -398491168,  -659240960,  -909645056, -1145768192, -1363900288,
+	 -1560623360, -1732845440, -1877860864, -1993397248, -2077638400, -2129261312, -2147457024, -2131939712,
+	 -2082952192, -2001262080, -1888158080, -1745408000, -1575258880, -1380376576, -1163823104,  -929001984,
+	  -679592640,  -419514016,  -152853344,   116211064,   383451008,   644667072,   895766272,  1132798976,
+	  1352051072,  1550073728,  1723762048,  1870392960,  1987655680,  2073714304,  2127218944,  2147326208,
+	  2133722624,  2086619904,  2006760320,  1895396608,  1754272384,  1585611776,  1392058752,  1176651776,
+	   942772480,   694084992,   434508352,   168110432,  -100926664,  -368387392,  -630056640,  -881834624,
+	 -1119775744, -1340129920, -1539445632, -1714598400, -1862828160, -1981813248, -2069688192, -2125068032,
+	 -2147086464, -2135396864, -2090185600, -2012156672, -1902540928, -1763057280, -1595889920, -1403673344,
+	 -1189420544,  -956487808,  -708545984,  -449480672,  -183350832,    85649416,   353305056,   615414144,
+	   867869376,  1106692096,  1328140928,  1528744960,  1705337984,  1855168768,  1975873536,  2065552640,
+	  2122809216,  2146737920,  2136964480,  2093641344,  2017448192,  1909588736,  1771743232,  1606086912,
+	  1415216768,  1202122240,   970161920,   722971008,   464430144,   198590080,   -70359640,  -338204800,
+	  -600748352,  -853852672, -1093552384, -1316090880, -1517961088, -1696000896, -1847419392, -1969826944,
+	 -2061316736, -2120444032, -2146279936, -2138422016, -2096992768, -2022642816, -1916535808, -1780343936,
+	 -1616202496, -1426682112, -1214769792,  -983786752,  -737351616,  -479348064,  -213819264,    55066288,
+	   323095456,   586044160,   839792576,  1080364160,  1303961216,  1507100160,  1686572800,  1839567872,
+	  1963687040,  2056973952,  2117968640,  2145713664,  2139771776,  2100237696,  2027729408,  1923389440,
+	  1788854272,  1626230656,  1438081280,  1227355648,   997361664,   751702528,   494249664,   229037584,
+	   -39770144,  -307961632,  -571310272,  -825689856, -1067114112, -1291784832, -1496162688, -1677059072,
+	 -1831631488, -1957444096, -2052529152, -2115387008, -2145038080, -2141012992, -2103374464, -2032715648,
+	 -1930148992, -1797273856, -1636186880, -1449407488, -1239872512, -1010878784,  -766022976,  -509126176,
+	  -244244288,    24480170,   292820288,   556547328,   811545280,  1053809920,  1279523200,  1485155328,
+	  1667465344,  1823597824,  1951101824,  2047977856,  2112699392,  2144254080,  2142145408,  2106407680,
+	  2037598848,  1936799872,  1805597696,  1646054784,  1460660096,  1252339712,  1024351808,   780281536,
+	   523976832,   259438592,    -9180764,  -277647872,  -541764096,  -797367040, -1040452160, -1267203328,
+	 -1474066560, -1657781888, -1815476096, -1944660480, -2043320064, -2109903232, -2143360896, -2143168640,
+	 -2109329280, -2042378624, -1943363072, -1813834496, -1655833984, -1471832576, -1264736768, -1037772800,
+	  -794523456,  -538792960,  -274611584,    -6127300,   262469440,   526945440,   783140800,  1027048832,
+	  1254818944,  1462897024,  1648014208,  1807257728,  1938123904,  2038566144,  2106999936,  2142358400,
+	  2144083712,  2112148480,  2047052160,  1949824128,  1821979136,  1665534336,  1482936320,  1277063040,
+	  1051134016,   808717376,   553589696,   289778784,    21410478,  -247285856,  -512092096,  -768874816,
+	 -1013579008, -1242377728, -1451671168, -1638162944, -1798943232, -1931485568, -2033701120, -2103991296,
+	 -2141247616, -2144889856, -2114858880, -2051624320, -1956182784, -1830027008, -1675150080, -1493964928,
+	 -1289331072, -1064449024,  -822862720,  -568366208,  -304931296,   -36717144,   232081568,   497236608,
+	   754577408,  1000064896,  1229866752,  1440353664,  1628233856,  1790550912,  1924749184,  2028735488,
+	  2100874240,  2140029568,  2145587200,  2117463296,  2056094720,  1962445696,  1837986304,  1684670720,
+	  1504917632,  1301540224,  1077717120,   836973888,   583090240,   320052096,    52005564,  -216857360,
+	  -482332032,  -740234112,  -986514560, -1217293312, -1428975232, -1618211456, -1782054272, -1917918848,
+	 -2023672192, -2097650688, -2138701568, -2146175616, -2119956224, -2060453760, -1968608896, -1845852288,
+	 -1694126080, -1515793920, -1313663744, -1090923392,  -851042560,  -597800448,  -335189088,   -67299536,
+	   201630288,   467410912,   725853184,   972899648,  1204658048,  1417518080,  1608112256,  1773471488,
+	  1910987392,  2018500992,  2094320512,  2137263360,  2146654976,  2122344320,  2064712960,  1974665728,
+	  1853624704,  1703485440,  1526593280,  1325733632,  1104060160,   865068032,   612480256,   350292832,
+	    82590096,  -186409296,  -452482080,  -711435456,  -959220736, -1191961728, -1405988992, -1597942400,
+	 -1764798848, -1903959040, -2013221504, -2090884096, -2135719936, -2147025152, -2124624640, -2068867328,
+	 -1980635264, -1861302912, -1712748288, -1537315072, -1337736320, -1117155072,  -879049536,  -627129024,
+	  -365362656,   -97876456,   171162544,   437514304,   696981632,   945522368,  1179204864,  1394388608,
+	  1587680512,  1756036608,  1896834048,  2007845504,  2087341568,  2134066560,  2147286784,  2126797184,
+	  2072920960,  1986497792,  1868886656,  1721934208,  1547947520,  1349670912,  1130207232,   892986496,
+	   641745984,   380430112,   113141488,  -155907088,  -422508224,  -682492352,  -931761408, -1166401920,
+	 -1382729984, -1577338112, -1747185152, -1889612672, -2002373376, -2083697024, -2132304768, -2147439488,
+	 -2128861696, -2076860800, -1992253312, -1876375552, -1731032832, -1558512896, -1361537152, -1143174016,
+	  -906878080,  -656330368,  -395478240,  -128433504,   140643728,   407512864,   667968512,   917953152,
+	  1153526016,  1370976000,  1566915584,  1738245120,  1882295424,  1996793856,  2079942912,  2130434816,
+	  2147483136,  2130818176,  2080699520,  1997913984,  1883761408,  1740043520,  1569010304,  1373334272,
+	  1156096896,   920708864,   670865792,   410506304,   143702640,  -125373224,  -392480736,  -653426304,
+	  -904098304, -1140591616, -1359165056, -1556413568, -1729226496, -1874890624, -1991113088, -2076078976,
+	 -2128456704, -2147417728, -2132664576, -2084432512, -2003473280, -1891067264, -1748965888, -1579405696,
+	 -1385049088, -1168961024,  -934507776,  -685398400,  -425513536,  -158948128,   110096360,   377428704,
+	   638835456,   890197568,  1127599360,  1347285120,  1545832448,  1720110464,  1867382784,  1985331200,
+	  2072113664,  2126370432,  2147243392,  2134404736,  2088059904,  2008930816,  1898269312,  1757799424,
+	  1589732224,  1396706176,  1181765760,   948273920,   699880576,   440499136,   174201920,   -94830280,
+	  -362357504,  -624196416,  -876251648, -1114549760, -1335349504, -1535184512, -1710907136, -1859772032,
+	 -1979448448, -2068047744, -2124178816, -2146960000, -2136036480, -2091581184, -2014286464, -1905367552,
+	 -1766543744, -1599977856, -1408292480, -1194510592,  -961962560,  -714311808,  -455462400,  -189446848,
+	    79526640,   347267904,   609557056,   862261248,  1101443712,  1323333504,  1524435584,  1701607040,
+	  1852083200,  1973465344,  2063876992,  2121876992,  2146567680,  2137559936,  2094996352,  2019534208,
+	  1912376576,  1775198464,  1610142464,  1419819520,  1207181184,   975617024,   728737664,   470386560,
+	   204682176,   -64251716,  -332160672,  -594855360,  -848242112, -1088281600, -1311250304, -1513620736,
+	 -1692230400, -1844292096, -1967382016, -2059592448, -2119464832, -2146066944, -2138974848, -2098305024,
+	 -2024685184, -1919288576, -1783753984, -1620225152, -1431262080, -1219804160,  -989222016,  -743095744,
+	  -485318848,  -219923392,    48957156,   317036576,   580170752,   834164928,  1075064320,  1299100416,
+	  1502729088,  1682788224,  1836407424,  1961198720,  2055207808,  2116947584,  2145456896,  2140281216,
+	  2101504000,  2029733248,  1926103168,  1792228096,  1630225792,  1442607872,  1232365184,  1002776768,
+	   757431488,   500210464,   235104576,   -33660112,  -301896416,  -565409344,  -820030208, -1061806784,
+	 -1286884736, -1491761152, -1673240448, -1828429568, -1954922752, -2050718976, -2114322944, -2144737792,
+	 -2141478912, -2104599680, -2034683648, -1932827136, -1800611200, -1640143488, -1453904768, -1244863616,
+	 -1016280640,  -771728768,  -515076704,  -250306432,    18361360,   286757152,   550635008,   805869056,
+	  1048481088,  1274603648,  1480729344,  1663597440,  1820358912,  1948540928,  2046125952,  2111596928,
+	  2143910016,  2142567936,  2107588608,  2039525504,  1939431680,  1808903040,  1649978112,  1465139840,
+	  1257312256,  1029718528,   785986880,   529916800,   265495568,    -3061673,  -271587104,  -535832768,
+	  -791697408, -1035102208, -1262257920, -1469610624, -1653880192, -1812204672, -1942060160, -2041429120,
+	 -2108754816, -2142973312, -2143547136, -2110470528, -2044263680, -1945952128, -1817102976, -1659718528,
+	 -1476288384, -1269683584, -1043118592,  -800205120,  -544714176,  -280687488,   -12254551,   256403280,
+	   521003328,   777455168,  1021670720,  1249848064,  1458417408,  1644078976,  1803949696,  1935480832,
+	  2036628736,  2105808768,  2141926656,  2144418688,  2113245312,  2048893184,  1952373760,  1825210624,
+	  1669385088,  1487362176,  1281964288,  1056465728,   814382720,   559499776,   295848896,    27521004,
+	  -241206448,  -506147424,  -763158208, -1008172992, -1237388288, -1447149952, -1634194432, -1795603072,
+	 -1928803200, -2031730176, -2102755712, -2140772352, -2145181440, -2115912832, -2053423872, -1958702976,
+	 -1833217152, -1678967040, -1498360320, -1294206336, -1069759168,  -828503872,  -574256960,  -310995264,
+	   -42818828,   225997360,   491281792,   748837760,   994638464,  1224852224,  1435809152,  1624237568,
+	  1787156352,  1922027648,  2026723328,  2099596032,  2139512192,  2145835264,  2118472960,  2057850112,
+	  1964925824,  1841139200,  1688463616,  1509282560,  1306395648,  1083012608,   842598208,   588984960,
+	   326125856,    58114480,  -210776800,  -476375296,  -734479296,  -981082624, -1212254080, -1424395520,
+	 -1614187648, -1778627840, -1915161984, -2021613568, -2096329728, -2138140800, -2146380160, -2120923008,
+	 -2062172032, -1971049088, -1848967936, -1697874688, -1520116480, -1318505600, -1096196736,  -856649664,
+	  -603683136,  -341223744,   -73423552,   195529232,   461444608,   720083584,   967447936,  1199594368,
+	  1412921856,  1604055680,  1770009088,  1908191744,  2016401152,  2092960768,  2136659328,  2146816512,
+	  2123268096,  2066389248,  1977059456,  1856702720,  1707199360,  1530873472,  1330548608,  1109311232,
+	   870657664,   618366272,   356304320,    88712520,  -180304368,  -446490496,  -705635840,  -953764096,
+	 -1186860160, -1401376640, -1593842432, -1761309824, -1901124736, -2011080704, -2089485696, -2135070848,
+	 -2147143040, -2125505280, -2070505984, -1982982528, -1864351360, -1716437504, -1541575680, -1342511232,
+	 -1122369536,  -884636416,  -632955392,  -371399136,  -103980624,   165070368,   431497664,   691214208,
+	   940017152,  1174092928,  1389735552,  1583537152,  1752521344,  1893953536,  2005669632,  2085896960,
+	  2133375872,  2147360896,  2127636992,  2074508800,  1988811136,  1871889152,  1725578752,  1552199552,
+	  1354406016,  1135398784,   898540416,   647575040,   386442816,   119243464,  -149795328,  -416515040,
+	  -676695552,  -926251968, -1161266304, -1378023680, -1573173632, -1743624960, -1886701568, -2000157056,
+	 -2082202240, -2131568896, -2147470080, -2129656064, -2078406656, -1994526336, -1879348096, -1734651904,
+	 -1562721920, -1366257408, -1148342528,  -912428544,  -662161792,  -401499136,  -134532960,   134545360,
+	   401479136,   662173632,   912410176,  1148353024,  1366266880,  1562730496,  1734659328,  1879338240,
+	  1994530944,  2078409728,  2129657728,  2147470080,  2131571328,  2082207232,  2000152576,  1886695680,
+	  1743617792,  1573187584,  1378014080,  1161255808,   926240704,   676683712,   416535008,   149782928,
+	  -119255864,  -386455040,  -647586880,  -898521920, -1135409280, -1354415616, -1552208128, -1725586176,
+	 -1871895296, -1988815744, -2074503552, -2127638656, -2147361024, -2133374464, -2085894016, -2005676928,
+	 -1893947648, -1752514176, -1583550976, -1389726080, -1174082560,  -940005952,  -691202432,  -431485504,
+	  -165057984,   103993040,   371411360,   632967296,   884647744,  1122380032,  1342520960,  1541584384,
+	  1716425344,  1864357632,  1982987264,  2070500608,  2125507072,  2147143296,  2135069568,  2089482880,
+	  2011087872,  1901118976,  1761302656,  1593834112,  1401367296,  1186849792,   953752960,   705655040,
+	   446478336,   180291984,   -88724936,  -356316576,  -618346816,  -870669056, -1109321856, -1330558336,
+	 -1530882176, -1707197056, -1856708992, -1977070720, -2066392704, -2123267456, -2146816000, -2136659712,
+	 -2092957952, -2016396928, -1908178560, -1770020608, -1604058368, -1412912512, -1199584000,  -967422208,
+	  -720071872,  -461448480,  -195549488,    73419600,   341236000,   603695040,   856676096,  1096179200,
+	  1318502400,  1520125312,  1697882240,  1848982528,  1971041024,  2062171008,  2120924928,  2146380544,
+	  2138138112,  2096334208,  2021614848,  1915156352,  1778630016,  1614179328,  1424410752,  1212230272,
+	   981071552,   734483008,   476363168,   210797040,   -58143276,  -326105760,  -588981184,  -842609600,
+	 -1082980864, -1306405504, -1509268096, -1688481408, -1841145600, -1964924288, -2057853696, -2118469632,
+	 -2145836416, -2139511168, -2099596928, -2026719232, -1922036736, -1787149440, -1624240128, -1435812096,
+	 -1224842112,  -994641984,  -748826112,  -491285632,  -225968704,    42831252,   310991360,   574268928,
+	   828500288,  1069784192,  1294216192,  1498357504,  1678974720,  1833215104,  1958707968,  2053422720,
+	  2115917824,  2145182080,  2140772608,  2102753280,  2031731456,  1928790528,  1795596288,  1634196992,
+	  1447140864,  1237391488,  1008162048,   763161920,   506151264,   241194096,   -27549810,  -295861184,
+	  -559495936,  -814379072, -1056476544, -1281987456, -1487371136, -1669382656, -1825208576, -1952378880,
+	 -2048897024, -2113247616, -2144420224, -2141928064, -2105809536, -2036629888, -1935475328, -1803933952,
+	 -1644092032, -1458420224, -1249851392, -1021659840,  -777428352,  -520991264,  -256407216,    12234208,
+	   280683552,   544726208,   800216640,  1043143808,  1269667200,  1476285568,  1659726336,  1817109504,
+	  1945964288,  2044257408,  2110469888,  2143547904,  2142972544,  2108752512,  2041435520,  1942047744,
+	  1812197888,  1653882752,  1469601664,  1262274432,  1035076992,   791685824,   535836608,   271574784,
+	     3049248,  -265524160,  -529897088,  -785983232, -1029729472, -1257309056, -1465148928, -1649965056,
+	 -1808918528, -1939437056, -2039524224, -2107591040, -2142566528, -2143908352, -2111594624, -2046127232,
+	 -1948535680, -1820369664, -1663589504, -1480732288, -1274580480, -1048470272,  -805872704,  -550623040,
+	  -286761056,   -18332552,   250318768,   515072864,   771740352,  1016262720,  1244887168,  1453913856,
+	  1640140928,  1800617984,  1932825472,  2034687616,  2104598912,  2141481088,  2144737280,  2114323712,
+	  2050715264,  1954924416,  1828414464,  1673232640,  1491763968,  1286874752,  1061810240,   820018752,
+	   565413184,   301900320,    33647688,  -235133216,  -500222560,  -757427776, -1002773312, -1232375296,
+	 -1442629120, -1630233856, -1792225792, -1926101376, -2029737344, -2101506560, -2140282240, -2145455616,
+	 -2116945536, -2055208960, -1961200384, -1836401024, -1682770304, -1502720256, -1299103616, -1075067776,
+	  -834153472,  -580142976,  -317024288,   -48961116,   219903168,   485314976,   743107392,   989233088,
+	  1219827840,  1431246976,  1620222592,  1783760768,  1919294208,  2024694784,  2098300800,  2138974592,
+	  2146066560,  2119465472,  2059588864,  1967390080,  1844277376,  1692242816,  1513623552,  1311240448,
+	  1088299136,   848215616,   594874880,   332164576,    64239300,  -204694544,  -470414688,  -728718528,
+	  -975613568, -1207191424, -1419816576, -1610150656, -1775186944, -1912389632, -2019538432, -2094995456,
+	 -2137561216, -2146568320, -2121872512, -2063873536, -1973466880, -1852076800, -1701629440, -1524426752,
+	 -1323336576, -1101419008,  -862264896,  -609545152,  -347255648,   -79546976,   189475552,   455458560,
+	   714323520,   961973696,  1194493696,  1408314240,  1599975296,  1766541568,  1905373184,  2014290688,
+	  2091584000,  2136036224,  2146959360,  2124177024,  2068044416,  1979443584,  1859782144,  1710889728,
+	  1535175808,  1335339904,  1114539136,   876270208,   624184512,   362361408,    94817864,  -174214304,
+	  -440511296,  -699892352,  -948255680, -1181762432, -1396715648, -1589740544, -1757806592, -1898259840,
+	 -2008929408, -2088059008, -2134406144, -2147243264, -2126368768, -2072110464, -1985332608, -1867376640,
+	 -1720103040, -1545823872, -1347275392, -1127602688,  -890201216,  -638823552,  -377416480,  -110083952,
+	   158944192,   425509632,   685394624,   934518976,  1168971392,  1385071104,  1579403008,  1748963584,
+	  1891065344,  2003477760,  2084435584,  2132664064,  2147417728,  2128457216,  2076088320,  1991108352,
+	  1874876544,  1729209344,  1556416256,  1359168128,  1140553344,   904087040,   653398912,   392484640,
+	   125377176,  -143731376,  -410518496,  -670893184,  -920705344, -1156093568, -1373356416, -1569007616,
+	 -1740050688, -1883759488, -1997912576, -2080706688, -2130817664, -2147483136, -2130437376, -2079943808,
+	 -1996783232, -1882297344, -1738247424, -1566929536, -1370991744, -1153501824,  -917927168,  -667972288,
+	  -407500672,  -140664032,   128445904,   395506560,   656326592,   906874560,  1143156864,  1361546752,
+	  1558532736,  1731030400,  1876373632,  1992245760,  2076864000,  2128863360,  2147439488,  2132305280,
+	  2083702016,  2002368896,  1889606784,  1747196928,  1577340800,  1382695424,  1166391552,   931750272,
+	   682511680,   422528192,   155878352,  -113186624,  -380442336,  -641757824,  -892968000, -1130217728,
+	 -1349693440, -1547956224, -1721941632, -1868876672, -1986502400, -2072924160, -2126796672, -2147287040,
+	 -2134068864, -2087338624, -2007841024, -1896835840, -1756038784, -1587694208, -1394379136, -1179194496,
+	  -945525952,  -696985344,  -437534208,  -171150160,    97888872,   365358752,   627125248,   879045952,
+	  1117193728,  1337746048,  1537323776,  1712745856,  1861300864,  1980646400,  2068870656,  2124626432,
+	  2147025152,  2135720448,  2090877568,  2013222912,  1903953280,  1764810368,  1597945088,  1405967232,
+	  1191965056,   959224256,   711454656,   452485952,   186380592,   -82586136,  -350288928,  -612460800,
+	  -865079424, -1104084864, -1325756288, -1526590464, -1703483008, -1853647488, -1974676992, -2064720896,
+	 -2122343808, -2146654976, -2137260672, -2094317824, -2018491136, -1910989184, -1773473792, -1608093184,
+	 -1417508736, -1204647808,  -972903232,  -725856960,  -467382784,  -201634224,    67311952,   335168992,
+	   597796608,   851068992,  1090919936,  1313673600,  1515779456,  1694113664,  1845867008,  1968620416,
+	  2060457344,  2119958272,  2146174848,  2138699008,  2097644544,  2023668096,  1917913216,  1782065536,
+	  1618203264,  1428953728,  1217296640,   986503552,   740253184,   482319904,   216844992,   -52001608,
+	  -320064384,  -583070656,  -836985344, -1077727872, -1301524096, -1504914816, -1684658048, -1837992704,
+	 -1962450688, -2056088832, -2117459968, -2145587072, -2140025856, -2100871680, -2028731392, -1924754560,
+	 -1790548608, -1628209792, -1440350592, -1229856512, -1000082880,  -754550464,  -497200640,  -232077360,
+	    36721376,   304911168,   568378240,   822889344,  1064452736,  1289334528,  1493950336,  1675157888,
+	  1830041984,  1956181120,  2051625600,  2114853888,  2144890496,  2141246720,  2103992064,  2033702400,
+	  1931483776,  1798923008,  1638154880,  1451650048,  1242380928,  1013582528,   768840256,   512087968,
+	   247265376,   -21406520,  -289774880,  -553625472,  -808721344, -1051152000, -1277053312, -1482933504,
+	 -1665552512, -1821981312, -1949825920, -2047048448, -2112146304, -2144085248, -2142358784, -2106999040,
+	 -2038569984, -1938129152, -1807242112, -1648016768, -1462899840, -1254815616, -1027059520,  -783121600,
+	  -526917504,  -262473376,     6123341,   274591424,   538812928,   794542592,  1037769344,  1264733568,
+	  1471865472,  1655841792,  1813845504,  1943357952,  2042377344,  2109337728,  2143169408,  2143359744,
+	  2109905536,  2043323776,  1944648192,  1815469440,  1657773952,  1474075392,  1267213056,  1040427008,
+	   797363136,   541752064,   277635552,     9192914,  -259459056,  -524004768,  -780293120, -1024362752,
+	 -1252323200, -1460675200, -1646068096, -1805600000, -1936805248, -2037592448, -2106410112, -2142146944,
+	 -2144253952, -2112698624, -2047984000, -1951096576, -1823586944, -1667467776, -1485152256, -1279546112,
+	 -1053799104,  -811533760,  -556559104,  -292816096,   -24508702,   244248496,   509138240,   766034560,
+	  1010875264,  1239875968,  1449434752,  1636189696,  1797280640,  1930140032,  2032714368,  2103381888,
+	  2141013248,  2145037568,  2115390464,  2052530304,  1957432192,  1831629184,  1677056384,  1496177280,
+	  1291787904,  1067089152,   825693568,   571306176,   307981760,    39757720,  -229058096,  -494245824,
+	  -751706496,  -997336448, -1227365888, -1438096640, -1626249472, -1788852096, -1923391360, -2027744256,
+	 -2100240384, -2139773568, -2145713792, -2117969280, -2056963328, -1963681920, -1839561472, -1686580224,
+	 -1507102976, -1303938304, -1080353536,  -839781184,  -586055872,  -323099360,   -55037488,   213823472,
+	   479360192,   737340224,   983776000,  1214793600,  1426685312,  1616205312,  1780332544,  1916530304,
+	  2022649728,  2096999040,  2138422400,  2146279808,  2120445952,  2061310976,  1969818752,  1847417216,
+	  1695998336,  1517975552,  1316074624,  1093534592,   853856320,   600744256,   338232960,    70347224,
+	  -198610624,  -464418304,  -722967232,  -970136448, -1202132608, -1415226112, -1606078848, -1771740928,
+	 -1909601920, -2017452416, -2093644160, -2136962560, -2146738048, -2122804992, -2065544832, -1975868544,
+	 -1855162368, -1705350272, -1528724736, -1328118272, -1106688512,  -867858048,  -615433664,  -353284736,
+	   -85620632,   183355040,   449484800,   708526784,   956506304,  1189437824,  1403670400,  1595892736,
+	  1763040896,  1902546688,  2012163968,  2090182784,  2135397376,  2147086976,  2125066240,  2069682688,
+	  1981817984,  1862830080,  1714595840,  1539419776,  1340120320,  1119758080,   881845696,   630060416,
+	   368350944,   100922440,  -168122816,  -434496480,  -694081216,  -942798400, -1176655232, -1392068224,
+	 -1585603584, -1754270080, -1895410176, -2006761856, -2086622848, -2133720448, -2147326336, -2127214976,
+	 -2073715328, -1987654144, -1870402816, -1723774080, -1550059520, -1352054144, -1132798848,  -895758720,
+	  -644686528,  -383430752,  -116186392,   152849392,   419518176,   679631616,   929013248,  1163840512,
+	  1380370432,  1575256192,  1745429504,  1888161920,  2001269504,  2082949248,  2131938816,  2147456768,
+	  2129260288,  2077634304,  1993401728,  1877864704,  1732825984,  1560620416,  1363893888,  1145782016,
+	   909656064,   659217408,   398458848,   131477168,  -137600880,  -404486432,  -665085440,  -915210432,
+	 -1150939136, -1368627712, -1564806400, -1736462336, -1880833536, -1995663744, -2079177984, -2130045056,
+	 -2147478784, -2131193216, -2081456000, -1999036032, -1885247104, -1741828736, -1571079552, -1375689600,
+	 -1158679168,  -923507136,  -673777344,  -413498912,  -146761248,   122312696,   389434112,   650536640,
+	   901331584,  1138006912,  1356790144,  1554299776,  1727426304,  1873393920,  1989968768,  2075292928,
+	  2128051712,  2147391872,  2133021952,  2085163904,  2004580736,  1892502528,  1750723968,  1581481216,
+	  1387390464,  1171545216,   937252224,   688271936,   428517952,   162005200,  -107018288,  -374426528,
+	  -635923584,  -887406848, -1124989184, -1344909184, -1543737088, -1718283136, -1865875200, -1984160512,
+	 -2071310848, -2125946496, -2147196160, -2134738560, -2088773888, -2010023552, -1899693184, -1759530496,
+	 -1591780608, -1399045888, -1184351616,  -951008832,  -702731712,  -443483104,  -177273600,    91718432,
+	   359335456,   621309568,   873466880,  1111914240,  1332908800,  1533027712,  1709072512,  1858245504,
+	  1978251264,  2067223936,  2123733504,  2146890880,  2136350464,  2092277632,  2015341568,  1906756992,
+	  1768266496,  1602021120,  1410630144,  1197043328,   964658560,   717186688,   458457856,   192532976,
+	   -76479408,  -344290784,  -606632640,  -859452672, -1098782720, -1320917760, -1522309504, -1699755136,
+	 -1850521216, -1972241408, -2063023232, -2121407872, -2146476544, -2137853696, -2095660672, -2020557824,
+	 -1913754496, -1776912512, -1612180096, -1422093568, -1209674752,  -978317888,  -731605248,  -473409248,
+	  -207717328,    61236524,   329163968,   591924864,   845394752,  1085651840,  1308860160,  1511467776,
+	  1690351360,  1842702848,  1966157568,  2058736256,  2118974464,  2145952896,  2139248256,  2098951808,
+	  2025693952,  1920654464,  1785468288,  1622214144,  1433533952,  1222299008,   991927488,   745986560,
+	   488272736,   222956400,   -45925032,  -314020416,  -577186944,  -831354304, -1072409472, -1296684160,
+	 -1500549248, -1680861568, -1834824832, -1959947776, -2054326272, -2116433152, -2145320064, -2140528640,
+	 -2102122752, -2030727040, -1927456896, -1793933056, -1632208896, -1444852992, -1234861056, -1005486656,
+	  -760268672,  -503175328,  -238118976,    30611204,   298860928,   562482944,   817211264,  1059169536,
+	  1284442368,  1489554432,  1671327360,  1826819840,  1953665536,  2049811840,  2113784192,  2144581632,
+	  2141705856,  2105200768,  2035656832,  1934161152,  1802270976,  1642120704,  1456147328,  1247360384,
+	  1018994688,   774573568,   518052352,   253334640,   -15295820,  -283751168,  -547687168,  -803026624,
+	 -1045818944, -1272135168, -1478531328, -1661667456, -1818721920, -1947257216, -2045193216, -2111039744,
+	 -2143731072, -2142774144, -2108171520, -2040483200, -1940739072, -1810553344, -1651949056, -1467367680,
+	 -1259796224, -1032393344,  -788839040,  -532902976,  -268537408,      -20342,   268562080,   532863552,
+	   788801152,  1032415168,  1259816320,  1467385856,  1651923072,  1810566656,  1940749696,  2040490880,
+	  2108176256,  2142771456,  2143729536,  2111035136,  2045185664,  1947246720,  1818743552,  1661651712,
+	  1478513280,  1272115200,  1045797248,   803064384,   547663168,   283726528,    15270971,  -253359312,
+	  -518012864,  -774596736, -1018958848, -1247380608, -1456165632, -1642094592, -1802284544, -1934172032,
+	 -2035664768, -2105205632, -2141702912, -2144580224, -2113779712, -2049804544, -1953655296, -1826841216,
+	 -1671311872, -1489536512, -1284422400, -1059147904,  -817248896,  -562458944,  -298836320,   -30586358,
+	   238143680,   503135776,   760291904,  1005508608,  1234881408,  1444871424,  1632182528,  1793910656,
+	  1927467776,  2030735232,  2102127872,  2140530560,  2145318912,  2116428928,  2054319104,  1959964416,
+	  1834811904,  1680846080,  1500531456,  1296664448,  1072444736,   831331392,   577163008,   313995840,
+	    45900188,  -222915920,  -488296960,  -746009856,  -991949568, -1222319488, -1433503744, -1622230400,
+	 -1785481984, -1920665600, -2025702272, -2098943232, -2139244672, -2145951872, -2118970368, -2058729216,
+	 -1966147584, -1842690176, -1690336000, -1511450112, -1308840448, -1085630464,  -845371904,  -591900928,
+	  -329139424,   -61211684,   207742064,   473433472,   731628608,   978340032,  1209695232,  1422112128,
+	  1612196480,  1776926464,  1913765760,  2020566272,  2095666048,  2137856128,  2146475776,  2121404032,
+	  2063034624,  1972257536,  1850508672,  1699739904,  1522291968,  1320898176,  1098817664,   859429952,
+	   606608768,   344266240,    76454576,  -192492448,  -458482112,  -717210112,  -964680704, -1197064064,
+	 -1410599424, -1602037632, -1768280576, -1906768512, -2015350144, -2092283264, -2136353024, -2146890368,
+	 -2123729792, -2067217280, -1978241536, -1858232960, -1709057408, -1533056256, -1332940672, -1111892992,
+	  -873444224,  -621285760,  -359310944,   -91759080,   177298352,   443507424,   702755136,   951031104,
+	  1184317696,  1399064704,  1591797248,  1759544704,  1899704832,  2010009344,  2088779776,  2134741376,
+	  2147195776,  2125938304,  2071313024,  1984150912,  1865862784,  1718288000,  1543697024,  1344915456,
+	  1124968064,   887414080,   635931136,   374402048,   107058920,  -162029984,  -428510176,  -688326528,
+	  -937274624, -1171511040, -1387409408, -1581475968, -1750757376, -1892514304, -2004566144, -2085169920,
+	 -2133021056, -2147391360, -2128048384, -2075303296, -1989959424, -1873397760, -1727392128, -1554305152,
+	 -1356770944, -1137985792,  -901338752,  -650481728,  -389474144,  -122287888,   146753344,   413491136,
+	   673800960,   923470400,  1158700160,  1375683584,  1571118848,  1741843328,  1885227520,  1999045120,
+	  2081454080,  2131200256,  2147478784,  2130050176,  2079171712,  1995666688,  1880805760,  1736447744,
+	  1564834304,  1368608512,  1150945792,   915158336,   665061824,   404526400,   137576080,  -131469264,
+	  -398515456,  -659241088,  -909619200, -1145775232, -1363887744, -1560637440, -1732840576, -1877844992,
+	 -1993398784, -2077648896, -2129263488, -2147456896, -2131943680, -2082951168, -2001248640, -1888150144,
+	 -1745415040, -1575283840, -1380376448, -1163792000,  -928990848,  -679608064,  -419558080,  -152857280,
+	   116243920,   383455200,   644647680,   895721728,  1132792192,  1352073472,  1550076672,  1723749888,
+	  1870366720,  1987651072,  2073721856,  2127218432,  2147325824,  2133721344,  2086609280,  2006752896,
+	  1895398400,  1754293632,  1585608960,  1392024320,  1176634496,   942776064,   694119744,   434504224,
+	   168065376,  -100947264,  -368375424,  -630021504,  -881838464, -1119807232, -1340139648, -1539437056,
+	 -1714571392, -1862826112, -1981827456, -2069689344, -2125065088, -2147085568, -2135398272, -2090177152,
+	 -2012155264, -1902550400, -1763045504, -1595854208, -1403651584, -1189417088,  -956513344,  -708534208,
+	  -449428480,  -183330288,    85645464,   353276896,   615426048,   867910784,  1106709760,  1328137856,
+	  1528719232,  1705345536,  1855191424,  1975878272,  2065551616,  2122803712,  2146737792,  2136960128,
+	  2093638656,  2017455104,  1909605504,  1771745408,  1606062336,  1415207424,  1202139136,   970143552,
+	   722913024,   464394016,   198585872,   -70339304,  -338225152,  -600799616,  -853879104, -1093556096,
+	 -1316068352, -1517969920, -1696033664, -1847429888, -1969828608, -2061308800, -2120444800, -2146281728,
+	 -2138420096, -2096993664, -2022652416, -1916533888, -1780318592, -1616188928, -1426691200, -1214746112,
+	  -983783040,  -737316864,  -479335968,  -213831360,    55095088,   323091552,   586079744,   839804032,
+	  1080346624,  1303984128,  1507097344,  1686595584,  1839574272,  1963678720,  2056979968,  2117968000,
+	  2145714816,  2139771520,  2100241920,  2027725312,  1923394816,  1788838272,  1626233216,  1438102528,
+	  1227345408,   997372480,   751683264,   494253536,   229000800,   -39782564,  -307941504,  -571330112,
+	  -825686272, -1067139136, -1291781632, -1496148096, -1677071872, -1831625088, -1957455872, -2052528000,
+	 -2115383424, -2145038720, -2141013888, -2103370240, -2032716928, -1930129152, -1797267072, -1636194816,
+	 -1449392256, -1239882368, -1010853376,  -766011328,  -509145952,  -244223808,    24468020,   292840704,
+	   556551424,   811587136,  1053820736,  1279513472,  1485170176,  1667462784,  1823617408,  1951106944,
+	  2047971712,  2112703104,  2144253568,  2142142848,  2106405248,  2037605248,  1936794496,  1805604224,
+	  1646031104,  1460657024,  1252356352,  1024340864,   780300480,   523948896,   259434384,    -9152229,
+	  -277660192,  -541744384,  -797386240, -1040448768, -1267180288, -1474069632, -1657810560, -1815482624,
+	 -1944658688, -2043311232, -2109904000, -2143363328, -2143167872, -2109333120, -2042389888, -1943361280,
+	 -1813814656, -1655826048, -1471847424, -1264766464, -1037776320,  -794489088,  -538788864,  -274631776,
+	    -6098491,   262465520,   526973376,   783144768,  1027023808,  1254835712,  1462894080,  1648032640,
+	  1807255552,  1938111616,  2038567552,  2107010176,  2142360448,  2144083968,  2112153600,  2047050880,
+	  1949801728,  1821968128,  1665536768,  1482962944,  1277059712,  1051101760,   808698304,   553601472,
+	   289815200,    21414438,  -247322608,  -512112096,  -768863424, -1013546624, -1242374528, -1451692416,
+	 -1638171008, -1798936576, -1931465984, -2033699840, -2103997056, -2141248640, -2144890880, -2114855296,
+	 -2051608576, -1956170880, -1830029056, -1675162752, -1493955968, -1289288448, -1064431104,  -822866368,
+	  -568385856,  -304919008,   -36663768,   232102064,   497224800,   754543040,  1000075904,  1229903744,
+	  1440369024,  1628225920,  1790526080,  1924751104,  2028750208,  2100876928,  2140027904,  2145588736,
+	  2117461248,  2056081664,  1962440576,  1837996800,  1684663040,  1504873600,  1301504256,  1077706368,
+	   836992640,   583078272,   320007424,    51976764,  -216869728,  -482312192,  -740245760,  -986554688,
+	 -1217316992, -1428972288, -1618198144, -1782061056, -1917939072, -2023676416, -2097649792, -2138698240,
+	 -2146175104, -2119949056, -2060450304, -1968610432, -1845871104, -1694118528, -1515761920, -1313654016,
+	 -1090926720,  -851016128,  -597804224,  -335144448,   -67287120,   201626336,   467439008,   725849472,
+	   972925376,  1204668416,  1417502848,  1608131328,  1773469312,  1911000576,  2018499584,  2094316032,
+	  2137266304,  2146655104,  2122339968,  2064713984,  1974680192,  1853618432,  1703487744,  1526572928,
+	  1325736832,  1104091648,   865056640,   612499776,   350264416,    82594048,  -186438000,  -452478208,
+	  -711478080,  -959246528, -1191958400, -1406010880, -1597939712, -1764824576, -1903964800, -2013220096,
+	 -2090890752, -2135719552, -2147024640, -2124622848, -2068872832, -1980624128, -1861304832, -1712730880,
+	 -1537306368, -1337752192, -1117144448,  -879053184,  -627101504,  -365366560,   -97896776,   171174928,
+	   437494368,   697008896,   945518848,  1179242624,  1394398080,  1587666816,  1756053120,  1896832128,
+	  2007861504,  2087344512,  2134064256,  2147286656,  2126797696,  2072909184,  1986493056,  1868896640,
+	  1721926784,  1547961728,  1349648512,  1130196608,   893004992,   641734144,   380450112,   113129080,
+	  -155903136,  -422488288,  -682504192,  -931743104, -1166412416, -1382714368, -1577313152, -1747192320,
+	 -1889634176, -2002377856, -2083692160, -2132300416, -2147439488, -2128855808, -2076857600, -1992260864,
+	 -1876393472, -1731035136, -1558493056, -1361527552, -1143191296,  -906852032,  -656334144,  -395449920,
+	  -128421104,   140623424,   407525056,   667964736,   917979264,  1153522688,  1370960384,  1566924032,
+	  1738242688,  1882309248,  1996792448,  2079933696,  2130436352,  2147483136,  2130814592,  2080700544,
+	  1997927424,  1883763328,  1740017024,  1568990720,  1373337344,  1156127872,   920712448,   670838464,
+	   410494112,   143706592,  -125336560,  -392476864,  -653453760,  -904109632, -1140574464, -1359136640,
+	 -1556410880, -1729243520, -1874888704, -1991105408, -2076069504, -2128456192, -2147417984, -2132665088,
+	 -2084437504, -2003468800, -1891038080, -1748949120, -1579408384, -1385077120, -1168950528,  -934467072,
+	  -685371072,  -425517408,  -158984768,   110108768,   377473184,   638847296,   890193984,  1127568128,
+	  1347294720,  1545863936,  1720117888,  1867372800,  1985317120,  2072116992,  2126376832,  2147243520,
+	  2134407040,  2088068480,  2008932224,  1898248192,  1757792256,  1589745792,  1396696832,  1181714432,
+	   948233408,   699868864,   440519072,   174189536,   -94875424,  -362385888,  -624208320,  -876233088,
+	 -1114560384, -1335384960, -1535193088, -1710904704, -1859761792, -1979453312, -2068059904, -2124180608,
+	 -2146960000, -2136040320, -2091578368, -2014270720, -1905361792, -1766546048, -1600002432, -1408295424,
+	 -1194472960,  -961951488,  -714331008,  -455434272,  -189450800,    79571808,   347280160,   609537536,
+	   862287616,  1101440256,  1323356160,  1524444288,  1701604608,  1852089472,  1973463680,  2063880448,
+	  2121876352,  2146567168,  2137558784,  2094997248,  2019529984,  1912378368,  1775209856,  1610134144,
+	  1419822464,  1207170944,   975620608,   728756800,   470390432,   204637184,   -64264136,  -332156768,
+	  -594898816,  -848238464, -1088320640, -1311260160, -1513617920, -1692258176, -1844290048, -1967400064,
+	 -2059595904, -2119464192, -2146067456, -2138975232, -2098295552, -2024686464, -1919297664, -1783746944,
+	 -1620227840, -1431228416, -1219807360,  -989240064,  -743084096,  -485322688,  -219878448,    48953200,
+	   317016480,   580166912,   834146176,  1075089280,  1299097344,  1502761344,  1682785792,  1836396928,
+	  1961210496,  2055206656,  2116955264,  2145456768,  2140282880,  2101501440,  2029739904,  1926090368,
+	  1792230272,  1630238976,  1442610816,  1232381824,  1002751296,   757435200,   500230240,   235108512,
+	   -33639772,  -301924928,  -565405504,  -820072000, -1061746368, -1286868480, -1491781888, -1673227648,
+	 -1828444672, -1954921088, -2050732416, -2114339456, -2144736896, -2141476736, -2104600576, -2034669184,
+	 -1932786048, -1800622336, -1640124928, -1453919744, -1244840192, -1016211968,  -771747776,  -515048736,
+	  -250326640,    18390168,   286818176,   550615360,   805895744,  1048463360,  1274626944,  1480774016,
+	  1663584512,  1820365440,  1948532352,  2046134784,  2111605120,  2143907840,  2142567040,  2107592576,
+	  2039516416,  1939412224,  1808922752,  1649970048,  1465154688,  1257288832,  1029678912,   786021056,
+	   529904768,   265532016,    -3074098,  -271631936,  -535797216,  -791678464, -1035070016, -1262268032,
+	 -1469643648, -1653856768, -1812193664, -1942044416, -2041432960, -2108763392, -2142969856, -2143548416,
+	 -2110477312, -2044259840, -1945939840, -1817096320, -1659689728, -1476315136, -1269673600, -1043093440,
+	  -800193600,  -544670464,  -280723904,   -12242126,   256431888,   521015392,   777482048,  1021624064,
+	  1249844864,  1458438528,  1644087040,  1803965312,  1935457792,  2036627456,  2105814400,  2141927552,
+	  2144417152,  2113254784,  2048899328,  1952368512,  1825212672,  1669366912,  1487400448,  1281993728,
+	  1056454912,   814386432,   559471936,   295901472,    27557728,  -241218784,  -506143584,  -763185152,
+	 -1008126144, -1237358208, -1447159168, -1634191744, -1795609856, -1928830208, -2031718272, -2102758272,
+	 -2140771968, -2145180928, -2115902336, -2053434624, -1958697728, -1833236224, -1678959232, -1498316288,
+	 -1294248704, -1069762624,  -828537792,  -574244992,  -310934336,   -42806404,   226058592,   491246016,
+	   748849408,   994693056,  1224862464,  1435854976,  1624213504,  1787163264,  1922047744,  2026727424,
+	  2099608960,  2139507584,  2145835392,  2118465536,  2057846656,  1964900992,  1841166592,  1688466048,
+	  1509250432,  1306385792,  1082959360,   842647040,   588988800,   326081184,    58118436,  -210821776,
+	  -476323488,  -734475584,  -981093696, -1212250752, -1424429312, -1614152576, -1778625664, -1915167616,
+	 -2021612160, -2096339584, -2138134400, -2146380800, -2120921088, -2062173184, -1971031168, -1849003264,
+	 -1697887104, -1520107776, -1318508672, -1096157824,  -856713408,  -603702656,  -341211456,   -73427512,
+	   195574240,   461376736,   720064384,   967502912,  1199577472,  1412931200,  1604053120,  1770034688,
+	  1908220032,  2016394240,  2092963584,  2136658944,  2146815360,  2123258880,  2066394880,  1977060992,
+	  1856712960,  1707181952,  1530841728,  1330564608,  1109300608,   870676288,   618323008,   356259744,
+	    88732848,  -180316752,  -446470592,  -705678464,  -953804608, -1186843136, -1401361280, -1593828736,
+	 -1761316864, -1901145728, -2011073536, -2089480960, -2135068672, -2147142912, -2125498880, -2070511360,
+	 -1982990336, -1864361472, -1716430080, -1541544320, -1342552704, -1122386816,  -884654976,  -632974848,
+	  -371354624,  -104033672,   165050080,   431477760,   691194944,   940057792,  1174048384,  1389719936,
+	  1583567744,  1752509568,  1893974784,  2005650688,  2085907712,  2133384832,  2147361152,  2127630848,
+	  2074505600,  1988794112,  1871851008,  1725590912,  1552168320,  1354421760,  1135360384,   898469632,
+	   647594432,   386430592,   119263776,  -149840400,  -416591520,  -676676224,  -926263168, -1161249152,
+	 -1378058240, -1573226752, -1743613184, -1886707456, -2000149632, -2082213248, -2131578368, -2147470080,
+	 -2129654528, -2078411776, -1994521728, -1879310336, -1734663936, -1562713472, -1366273024, -1148332032,
+	  -912358016,  -662181120,  -401486912,  -134553264,   134557760,   401555744,   662123072,   912421376,
+	  1148335872,  1366276480,  1562761472,  1734627968,  1879344256,  1994523392,  2078412928,  2129663488,
+	  2147469952,  2131569920,  2082212224,  2000147968,  1886674048,  1743610496,  1573134464,  1378054784,
+	  1161245312,   926199936,   676671936,   416458528,   149835904,  -119268272,  -386499488,  -647598784,
+	  -898592768, -1135364224, -1354425216, -1552216704, -1725593600, -1871917440, -1988795776, -2074506752,
+	 -2127640320, -2147361152, -2133369344, -2085906688, -2005672576, -1893941760, -1752507008, -1583520384,
+	 -1389766528, -1174099584,  -939994816,  -691190656,  -431473344,  -165110928,   103972720,   371423616,
+	   632979136,   884659072,  1122334848,  1342505088,  1541593088,  1716432768,  1864363776,  1982966912,
+	  2070495232,  2125508864,  2147142912,  2135068160,  2089495040,  2011095040,  1901113216,  1761314304,
+	  1593825792,  1401407488,  1186893952,   953741824,   705674240,   446466176,   180312256,   -88737352,
+	  -356393440,  -618327360,  -870680384, -1109304448, -1330568064, -1530936832, -1707184640, -1856715264,
+	 -1977062784, -2066396032, -2123279104, -2146815488, -2136658432, -2092947840, -2016392576, -1908157824,
+	 -1770032128, -1604050048, -1412878464, -1199573760,  -967381824,  -720121920,  -461436320,  -195504480,
+	    73432016,   341280608,   603644032,   856657408,  1096218112,  1318512256,  1520157184,  1697849728,
+	  1848972160,  1971058944,  2062174464,  2120932096,  2146378880,  2138140032,  2096324352,  2021610624,
+	  1915135872,  1778659840,  1614192768,  1424376960,  1212247040,   981031360,   734532928,   476383008,
+	   210752064,   -58122940,  -326150432,  -588930112,  -842590912, -1083019904, -1306389376, -1509300224,
+	 -1688468864, -1841168896, -1964955776, -2057847936, -2118477056, -2145835648, -2139507200, -2099580544,
+	 -2026725888, -1922016512, -1787160704, -1624210560, -1435754240, -1224858752,  -994630976,  -748845184,
+	  -491241632,  -225923760,    42810912,   311003648,   574249280,   828541952,  1069823360,  1294199936,
+	  1498366464,  1678962048,  1833238656,  1958726528,  2053416704,  2115914368,  2145181056,  2140771712,
+	  2102744064,  2031737984,  1928799360,  1795607424,  1634188928,  1447107456,  1237408128,  1008180032,
+	   763180928,   506139200,   241149184,   -27496704,  -295841024,  -559476288,  -814390592, -1056515904,
+	 -1281944832, -1487356416, -1669369856, -1825215104, -1952397696, -2048881024, -2113243904, -2144417408,
+	 -2141927168, -2105800704, -2036626048, -1935455744, -1803962752, -1644084096, -1458387072, -1249841280,
+	 -1021620096,  -777477824,  -521011008,  -256362336,    12246633,   280728352,   544674816,   800197760,
+	  1043154688,  1269677184,  1476318336,  1659692672,  1817098624,  1945969536,  2044261248,  2110478208,
+	  2143552640,  2142973824,  2108750208,  2041431552,  1942042496,  1812156032,  1653895680,  1469592576,
+	  1262264320,  1035066112,   791613376,   535856320,   271562464,     3069591,  -265536480,  -529972640,
+	  -785964288, -1029740352, -1257292544, -1465158016, -1650014976, -1808889856, -1939442304, -2039517824,
+	 -2107593472, -2142571776, -2143911424, -2111592448, -2046133376, -1948530432, -1820328320, -1663623168,
+	 -1480723328, -1274623232, -1048459392,  -805830848,  -550611008,  -286683808,   -18385660,   250331104,
+	   515116736,   771752000,  1016331392,  1244843904,  1453923072,  1640170112,  1800624768,  1932845056,
+	  2034670720,  2104601472,  2141481984,  2144736640,  2114315776,  2050731008,  1954919296,  1828407936,
+	  1673224832,  1491731584,  1286917376,  1061799424,   820007296,   565401152,   301855584,    33700792,
+	  -235112992,  -500234624,  -757439424, -1002813248, -1232331904, -1442614144, -1630241920, -1792232704,
+	 -1926121344, -2029720064, -2101502336, -2140283264, -2145456512, -2116943488, -2055224448, -1961208576,
+	 -1836394496, -1682782976, -1502711296, -1299145984, -1075085440,  -834142016,  -580162560,  -317012000,
+	   -49014212,   219882928,   485327072,   743088320,   989244096,  1219811072,  1431280640,  1620273792,
+	  1783749504,  1919299712,  2024688000,  2098310400,  2138981504,  2146067328,  2119463552,  2059594624,
+	  1967371904,  1844254208,  1692255360,  1513614720,  1311256576,  1088260224,   848174144,   594894464,
+	   332152320,    64259632,  -204706912,  -470458784,  -728699392,  -975624576, -1207174656, -1419825920,
+	 -1610180480, -1775175552, -1912380416, -2019531520, -2094998144, -2137565568, -2146568832, -2121875712,
+	 -2063879168, -1973461888, -1852054016, -1701641856, -1524441088, -1323300992, -1101436416,  -862223488,
+	  -609596096,  -347275712,   -79501808,   189455280,   455502720,   714273408,   961955520,  1194531200,
+	  1408298880,  1600005376,  1766511360,  1905363840,  2014295040,  2091579392,  2136040832,  2146959872,
+	  2124170368,  2068023424,  1979451520,  1859759488,  1710902016,  1535144192,  1335278848,  1114556544,
+	   876228992,   624203968,   362316864,    94739976,  -174194016,  -440523456,  -699873088,  -948296256,
+	 -1181827584, -1396700160, -1589748864, -1757794816, -1898280960, -2008956928, -2088054144, -2134407552,
+	 -2147243520, -2126367104, -2072098560, -1985340416, -1867370496, -1720115200, -1545815296, -1347240192,
+	 -1127619968,  -890189888,  -638843008,  -377404224,  -110038824,   158923904,   425521824,   685375360,
+	   934530176,  1169009280,  1385030528,  1579411456,  1748951680,  1891071232,  2003494016,  2084422784,
+	  2132665600,  2147417984,  2128455552,  2076068352,  1991128320,  1874886528,  1729240832,  1556407680,
+	  1359133184,  1140570624,   904046080,   653449472,   392472416,   125332064,  -143711088,  -410562848,
+	  -670842752,  -920716544, -1156131584, -1373340800, -1569038464, -1740019584, -1883765376, -1997929088,
+	 -2080701568, -2130823296, -2147483136, -2130435840, -2079932672, -1996790784, -1882275584, -1738278528,
+	 -1566920960, -1370956928, -1153518976,  -917915904,  -668022720,  -407520640,  -140618928,   128425600,
+	   395518752,   656276032,   906856064,  1143195136,  1361531008,  1558541312,  1730999040,  1876363776,
+	  1992262528,  2076858752,  2128865024,  2147439104,  2132307712,  2083691008,  2002376192,  1889600896,
+	  1747151616,  1577354624,  1382710912,  1166408576,   931739072,   682437760,   422548128,   155898640,
+	  -113133584,  -380454560,  -641800960,  -893009088, -1130256256, -1349652096, -1547964800, -1721968640,
+	 -1868898944, -1986519680, -2072910336, -2126798336, -2147287680, -2134063744, -2087328000, -2007859840,
+	 -1896829952, -1756012800, -1587663744, -1394344832, -1179238912,  -945514816,  -696942592,  -437489952,
+	  -171105104,    97835816,   365371008,   627168512,   879057280,  1117204352,  1337704448,  1537309568,
+	  1712773120,  1861307136,  1980651136,  2068856448,  2124623488,  2147026048,  2135719168,  2090874752,
+	  2013241344,  1903962624,  1764784640,  1597936768,  1405957888,  1192009216,   959242496,   711412032,
+	   452473792,   186368224,   -82533064,  -350268864,  -612504128,  -865060800, -1104095616, -1325688832,
+	 -1526576128, -1703490560, -1853620608, -1974681856, -2064715264, -2122350592, -2146657152, -2137265792,
+	 -2094315008, -2018498048, -1910968576, -1773429760, -1608128384, -1417499392, -1204664704,  -972862912,
+	  -725783552,  -467434624,  -201621856,    67291624,   335213632,   597871488,   851020224,  1090930688,
+	  1313657472,  1515811456,  1694161536,  1845839872,  1968612224,  2060451584,  2119960320,  2146177536,
+	  2138703744,  2097648896,  2023674880,  1917907584,  1782022016,  1618238208,  1428968832,  1217313280,
+	   986492480,   740180032,   482371680,   216865232,   -51981272,  -320076672,  -583145664,  -836936448,
+	 -1077710208, -1301507840, -1504923648, -1684706432, -1837965184, -1962442368, -2056083072, -2117462016,
+	 -2145588864, -2140032896, -2100875904, -2028748800, -1924749056, -1790523648, -1628222976, -1440317056,
+	 -1229900160, -1000071872,  -754538816,  -497220416,  -232032432,    36799328,   304923456,   568390208,
+	   822870528,  1064491968,  1289396864,  1493959168,  1675165568,  1830031360,  1956199808,  2051648640,
+	  2114856064,  2144891136,  2141248256,  2103982976,  2033677312,  1931492608,  1798934144,  1638168064,
+	  1451640832,  1242317440,  1013600448,   768859264,   512107744,   247253024,   -21484476,  -289754720,
+	  -553605824,  -808702464, -1051162816, -1277116032, -1482918784, -1665539584, -1821970560, -1949831040,
+	 -2047072000, -2112142592, -2144084224, -2142360064, -2106996736, -2038545536, -1938137984, -1807253120,
+	 -1648029824, -1462890752, -1254778880, -1027077376,  -783079552,  -526968992,  -262461040,     6168534,
+	   274636256,   538856704,   794493248,  1037780224,  1264770176,  1471850752,  1655870592,  1813817088,
+	  1943363200,  2042391296,  2109333888,  2143172352,  2143362944,  2109903104,  2043309952,  1944656896,
+	  1815445248,  1657807744,  1474066304,  1267176576,  1040444800,   797321152,   541803456,   277655712,
+	     9147722,  -259438864,  -524016832,  -780243648, -1024344832, -1252359936, -1460660352, -1646076032,
+	 -1805571200, -1936796416, -2037606656, -2106406144, -2142147712, -2144256896, -2112702336, -2047970432,
+	 -1951105024, -1823580416, -1667501312, -1485166848, -1279509888, -1053816832,  -811522304,  -556610368,
+	  -292836256,   -24463512,   244228288,   509150304,   765954368,  1010915136,  1239939584,  1449395584,
+	  1636197760,  1797269504,  1930159872,  2032739584,  2103371136,  2141014272,  2145038464,  2115382656,
+	  2052507392,  1957454080,  1831622784,  1677069056,  1496144896,  1291725696,  1067135232,   825682112,
+	   571262592,   307937056,    39712536,  -229005280,  -494257920,  -751748864,  -997376448, -1227402880,
+	 -1438057216, -1626236160, -1788877056, -1923396864, -2027748352, -2100229248, -2139771776, -2145712000,
+	 -2117967232, -2056959744, -1963703424, -1839571968, -1686552320, -1507094144, -1303928448, -1080399360,
+	  -839799872,  -586012416,  -323087072,   -55025068,   213770624,   479340352,   737382656,   983787008,
+	  1214803840,  1426645632,  1616191872,  1780357760,  1916535936,  2022653952,  2096994560,  2138426624,
+	  2146277248,  2120444032,  2061307520,  1969826816,  1847394176,  1695950464,  1517966720,  1316064768,
+	  1093552128,   853814848,   600669440,   338220704,    70269304,  -198525104,  -464462400,  -722978944,
+	  -970147584, -1202197248, -1415161472, -1606108928, -1771747968, -1909577600, -2017479168, -2093625088,
+	 -2136967040, -2146737664, -2122812928, -2065532416, -1975902208, -1855156224, -1705342720, -1528762112,
+	 -1328082816, -1106762112,  -867846656,  -615421760,  -353337120,   -85575472,   183269488,   449496960,
+	   708538496,   956458752,  1189475456,  1403605376,  1595901056,  1763048064,  1902522112,  2012179712,
+	  2090163072,  2135398656,  2147086720,  2125073920,  2069670656,  1981851008,  1862823808,  1714608128,
+	  1539479680,  1340084992,  1119747584,   881834368,   629954560,   368435552,   100877296,  -168135200,
+	  -434508640,  -694185984,  -942721216, -1176693120, -1392077696, -1585612032, -1754333952, -1895369728,
+	 -2006777856, -2086625792, -2133721856, -2147325056, -2127226752, -2073703680, -1987649408, -1870396800,
+	 -1723708032, -1550118912, -1352018944, -1132788352,  -895777216,  -644580864,  -383515232,  -116173984,
+	   152861776,   419498208,   679674496,   928935808,  1163850880,  1380379904,  1575242368,  1745455872,
+	  1888121088,  2001273984,  2082952320,  2131936384,  2147456512,  2129271424,  2077631104,  1993397120,
+	  1877874560,  1732799232,  1560679424,  1363884288,  1145771392,   909674496,   659174400,   398575424,
+	   131464760,  -137580576,  -404466464,  -665128384,  -915221696, -1150949504, -1368712960, -1564792448,
+	 -1736488960, -1880839552, -1995668352, -2079205632, -2130042496, -2147478912, -2131191680, -2081452928,
+	 -1998995584, -1885256832, -1741802240, -1571071104, -1375680128, -1158585984,  -923525504,  -673734464,
+	  -413486720,  -146748848,   122423248,   389414112,   650548480,   901342848,  1137989632,  1356876032,
+	  1554263040,  1727433728,  1873399936,  1989961088,  2075321344,  2128040192,  2147392000,  2133020544,
+	  2085168768,  2004541056,  1892543104,  1750716800,  1581472896,  1387405952,  1171452416,   937329536,
+	   688260160,   428505760,   162025488,  -107128880,  -374341952,  -635935424,  -887418176, -1124971904,
+	 -1344969984, -1543654656, -1718290688, -1865865088, -1984152704, -2071331456, -2125948288, -2147195776,
+	 -2134740864, -2088778624, -2009984640, -1899672064, -1759542144, -1591794176, -1399061376, -1184259200,
+	  -950968320,  -702750912,  -443503008,  -177293872,    91829056,   359380000,   621290112,   873448320,
+	  1111896832,  1332995584,  1533059456,  1709060096,  1858235264,  1978243328,  2067236224,  2123730560,
+	  2146890496,  2136352640,  2092282240,  2015325952,  1906766336,  1768278016,  1602034560,  1410645504,
+	  1197005824,   964676736,   717205888,   458477728,   192553232,   -76524568,  -344270688,  -606613120,
+	  -859554176, -1098765184, -1320953344, -1522295168, -1699742720, -1850577408, -1972233344, -2063035776,
+	 -2121404800, -2146475904, -2137843328, -2095679360, -2020564736, -1913763712, -1776924032, -1612106880,
+	 -1422059648, -1209691520,  -978336000,  -731624384,  -473301248,  -207672352,    61216188,   329143872,
+	   591905280,   845496576,  1085690880,  1308844032,  1511453312,  1690338816,  1842759808,  1966175744,
+	  2058730496,  2118971136,  2145952128,  2139238528,  2098942208,  2025700736,  1920663552,  1785479552,
+	  1622184448,  1433500288,  1222315776,   991945536,   746005632,   488228736,   222911440,   -45904696,
+	  -314000320,  -577167360,  -831395968, -1072448640, -1296668032, -1500534784, -1680848896, -1834848384,
+	 -1959966336, -2054320384, -2116429696, -2145319168, -2140524928, -2102126976, -2030733696, -1927465856,
+	 -1793944320, -1632179584, -1444868096, -1234877696, -1005504640,  -760226432,  -503131392,  -238139200,
+	    30590864,   298840768,   562526528,   817253056,  1059151808,  1284426112,  1489539712,  1671355776,
+	  1826843648,  1953657088,  2049805824,  2113780608,  2144583936,  2141702528,  2105204736,  2035663360,
+	  1934169984,  1802246528,  1642091648,  1456162304,  1247376896,  1019012544,   774531392,   518008480,
+	   253354848,   -15275478,  -283666016,  -547730880,  -803068544, -1045801152, -1272118784, -1478468992,
+	 -1661696128, -1818745984, -1947248640, -2045186944, -2111023872, -2143733760, -2142771200, -2108175360,
+	 -2040489472, -1940775808, -1810529024, -1651920128, -1467382528, -1259812736, -1032468672,  -788796992,
+	  -532859200,  -268557600,      -40685,   268476864,   532907360,   788843200,  1032397312,  1259852928,
+	  1467323136,  1651951872,  1810555776,  1940740992,  2040504960,  2108184832,  2142774400,  2143730816,
+	  2111038848,  2045171840,  1947227648,  1818719488,  1661664640,  1478528000,  1272078720,  1045757760,
+	   803022464,   547682816,   283746688,    15225779,  -253404192,  -518056704,  -774577728, -1018940928,
+	 -1247417344, -1456198912, -1642123648, -1802273536, -1934134656, -2035679232, -2105214592, -2141706240,
+	 -2144581376, -2113794944, -2049790976, -1953636480, -1826817536, -1671324544, -1489598336, -1284386176,
+	 -1059108608,  -817207104,  -562478592,  -298921344,   -30541168,   238188592,   503179712,   760272896,
+	  1005432704,  1234918400,  1444904832,  1632211840,  1793899520,  1927429888,  2030749824,  2102137088,
+	  2140529024,  2145316992,  2116443392,  2054305920,  1959945984,  1834822528,  1680817920,  1500592896,
+	  1296628352,  1072405568,   831350144,   577119488,   314080800,    45855004,  -222960880,  -488277152,
+	  -746052288,  -991873408, -1222356608, -1433537280, -1622217088, -1785507200, -1920627200, -2025717248,
+	 -2098952832, -2139242880, -2145950208, -2118984320, -2058716288, -1966155776, -1842734208, -1690308096,
+	 -1511511168, -1308804608, -1085648000,  -845450880,  -591857536,  -329224288,   -61166508,   207721808,
+	   473349728,   731671104,   978263552,  1209732608,  1422096896,  1612139776,  1776951936,  1913726720,
+	  2020581504,  2095661696,  2137847936,  2146474368,  2121417472,  2063021952,  1972265600,  1850552192,
+	  1699712256,  1522260096,  1320914176,  1098722560,   859508608,   606565440,   344221632,    76474904,
+	  -192602736,  -458398208,  -717252736,  -964721088, -1197047168, -1410683008, -1601980416, -1768306176,
+	 -1906789248, -2015343104, -2092293504, -2136344320, -2146889216, -2123723136, -2067222784, -1978224000,
+	 -1858276096, -1709030016, -1533024640, -1332956672, -1111854336,  -873522688,  -621242496,  -359331008,
+	   -91779408,   177343392,   443423392,   702797888,   951012864,  1184300672,  1399099008,  1591739648,
+	  1759570688,  1899695232,  2010002176,  2088790144,  2134732032,  2147195008,  2125941248,  2071318400,
+	  1984133632,  1865905408,  1718260864,  1543711232,  1344931328,  1124929536,   887492288,   635888000,
+	   374422080,   107079240,  -162075040,  -428554464,  -688307264,  -937374208, -1171494016, -1387443968,
+	 -1581506432, -1750745472, -1892566656, -2004558848, -2085180672, -2133026304, -2147391488, -2128033536,
+	 -2075308544, -1989942400, -1873375616, -1727404160, -1554228736, -1356837504, -1137947520,  -901297728,
+	  -650501120,  -389365248,  -122373624,   146798432,   413535488,   673781632,   923570368,  1158627840,
+	  1375718272,  1571104896,  1741831424,  1885280640,  1999013760,  2081465088,  2131197696,  2147478784,
+	  2130036096,  2079193216,  1995649920,  1880815616,  1736459776,  1564758528,  1368674688,  1150907648,
+	   915176704,   665081152,   404417632,   137661792,  -131514368,  -398495456,  -659221760,  -909719488,
+	 -1145702656, -1363922688, -1560623488, -1732828544, -1877898624, -1993415552, -2077643648, -2129260800,
+	 -2147456768, -2131930368, -2082940288, -2001255936, -1888159872, -1745426944, -1575208576, -1380341888,
+	 -1163809152,  -929009152,  -679627328,  -419449472,  -152812208,   116223608,   383435168,   644628288,
+	   895822400,  1132830464,  1352057600,  1550062592,  1723737728,  1870421248,  1987668224,  2073716608,
+	  2127215616,  2147325568,  2133716224,  2086614016,  2006760192,  1895346432,  1754305280,  1585578496,
+	  1392039808,  1176651520,   942676608,   694139008,   434459968,   168085664,  -100926936,  -368484512,
+	  -630002048,  -881879680, -1119789952, -1340123776, -1539514368, -1714559104, -1862848512, -1981819648,
+	 -2069683968, -2125081088, -2147085184, -2135393408, -2090181760, -2012162304, -1902498944, -1763019648,
+	 -1595867776, -1403666944, -1189433984,  -956414208,  -708491584,  -449448352,  -183350560,    85625136,
+	   353386144,   615469376,   867892160,  1106692352,  1328121856,  1528796928,  1705372928,  1855181184,
+	  1975870336,  2065545984,  2122820480,  2146738944,  2136962048,  2093643136,  2017462144,  1909554816,
+	  1771719936,  1606075904,  1415222656,  1202156032,   970103232,   722932160,   464413888,   198606128,
+	   -70318976,  -338269792,  -600780096,  -853860416, -1093538560, -1316052352, -1518001920, -1696021120,
+	 -1847419520, -1969820544, -2061303040, -2120451840, -2146281088, -2138422016, -2096998016, -2022659328,
+	 -1916513536, -1780329984, -1616202240, -1426706432, -1214816896,  -983742848,  -737336000,  -479355808,
+	  -213851600,    55140264,   323136224,   586060224,   839785344,  1080329088,  1304019968,  1507129600,
+	  1686583040,  1839563776,  1963670528,  2056992896,  2117975424,  2145714048,  2139773184,  2100246272,
+	  2027710336,  1923374720,  1788849536,  1626246528,  1438117632,  1227308416,   997332480,   751702272,
+	   494273312,   229086192,   -39827752,  -307986240,  -571310528,  -825667456, -1067064640, -1291817728,
+	 -1496180608, -1677059200, -1831614464, -1957420544, -2052541312, -2115391232, -2145037696, -2141015552,
+	 -2103361152, -2032702336, -1930138112, -1797278208, -1636208000, -1449358976, -1239845504, -1010871296,
+	  -766030336,  -509165696,  -244178912,    24513210,   292820576,   556531776,   811507584,  1053860160,
+	  1279549824,  1485155456,  1667449984,  1823641216,  1951125760,  2047985408,  2112699520,  2144252416,
+	  2142139648,  2106396416,  2037591040,  1936803328,  1805615360,  1646002048,  1460623872,  1252319616,
+	  1024358784,   780319488,   523905088,   259389520,    -9197422,  -277640000,  -541724736,  -797428160,
+	 -1040488256, -1267216768, -1474054784, -1657755904, -1815506816, -1944677888, -2043325184, -2109900160,
+	 -2143357952, -2143165056, -2109324544, -2042375936, -1943369984, -1813860608, -1655797248, -1471814528,
+	 -1264729984, -1037794112,  -794568896,  -538745152,  -274586944,    -6118834,   262445328,   526890112,
+	   783186816,  1027063488,  1254819200,  1462879232,  1647977600,  1807280000,  1938131072,  2038561152,
+	  2106993664,  2142354560,  2144081408,  2112145408,  2047057024,  1949782784,  1822013568,  1665508224,
+	  1482930304,  1277075968,  1051062336,   808777856,   553557760,   289770400,    21434778,  -247367504,
+	  -512028704,  -768905664, -1013586496, -1242357888, -1451725696, -1638115456, -1798961280, -1931485696,
+	 -2033693312, -2104006144, -2141242112, -2144888704, -2114858880, -2051633920, -1956152320, -1830073984,
+	 -1675134592, -1493970560, -1289357056, -1064391872,  -822945728,  -568342272,  -304939136,   -36749632,
+	   232146992,   497141248,   754585344,  1000057856,  1229833344,  1440402560,  1628169984,  1790551040,
+	  1924742016,  2028722048,  2100886272,  2140020736,  2145586816,  2117464704,  2056106496,  1962422272,
+	  1838041216,  1684675584,  1504934912,  1301572608,  1077667328,   836950976,   583097856,   319962720,
+	    52062620,  -216914688,  -482356224,  -740226688,  -986594880, -1217246336, -1429005952, -1618227840,
+	 -1782049792, -1917959424, -2023647616, -2097659520, -2138702336, -2146175872, -2119941888, -2060474496,
+	 -1968592384, -1845847936, -1694130944, -1515729792, -1313721856, -1090887808,  -851034752,  -597823744,
+	  -335099808,   -67372952,   201671344,   467419168,   725830336,   972965632,  1204597248,  1417536768,
+	  1608117888,  1773457792,  1911021184,  2018470272,  2094326016,  2137264256,  2146655744,  2122333056,
+	  2064737664,  1974662400,  1853628672,  1703500160,  1526541184,  1325804416,  1104052992,   865075264,
+	   612519296,   350219808,    82679864,  -186417728,  -452458336,  -711397056,  -959286912, -1191996032,
+	 -1405995392, -1598013696, -1764775680, -1903985664, -2013235840, -2090886016, -2135731200, -2147026432,
+	 -2124616320, -2068860672, -1980631936, -1861249664, -1712782720, -1537274880, -1337716864, -1117161856,
+	  -878952192,  -627183616,  -365322016,   -97851632,   171154640,   437602784,   696927616,   945559424,
+	  1179225600,  1394382592,  1587741440,  1756003712,  1896853248,  2007854208,  2087339648,  2134076672,
+	  2147287808,  2126791424,  2072914432,  1986500736,  1868842112,  1721978112,  1547930368,  1349664384,
+	  1130213888,   892904320,   641816064,   380405632,   113149400,  -155882848,  -422596864,  -682422720,
+	  -931783872, -1166395264, -1382698880, -1577388288, -1747142400, -1889624448, -2002370560, -2083687168,
+	 -2132313600, -2147439232, -2128858496, -2076862848, -1992268544, -1876339584, -1731008384, -1558507136,
+	 -1361441920, -1143208576,  -906811072,  -656291072,  -395469920,  -128310568,   140603136,   407569440,
+	   668007680,   917960832,  1153616128,  1370944768,  1566955008,  1738269312,  1882299520,  1996833152,
+	  2079928704,  2130442112,  2147483136,  2130817152,  2080673024,  1997934848,  1883741568,  1740028928,
+	  1569004544,  1373252224,  1156145024,   920671616,   670857792,   410514080,   143596112,  -125316256,
+	  -392521280,  -653434432,  -904091136, -1140668288, -1359120896, -1556441984, -1729231488, -1874878720,
+	 -1991146880, -2076064384, -2128462208, -2147417856, -2132667392, -2084410880, -2003452544, -1891047680,
+	 -1748960896, -1579422208, -1384992512, -1168912640,  -934485440,  -685390336,  -425537344,  -158874336,
+	   110153904,   377453152,   638827840,   890175488,  1127662336,  1347329920,  1545849728,  1720105728,
+	  1867362688,  1985359360,  2072128896,  2126374016,  2147243264,  2134409216,  2088042624,  2008916224,
+	  1898257664,  1757803904,  1589759488,  1396662528,  1181731328,   948251648,   699888064,   440538976,
+	   174144480,   -94855104,  -362365824,  -624188864,  -876214528, -1114599040, -1335369088, -1535178880,
+	 -1710892416, -1859751680, -1979470720, -2068054400, -2124177664, -2146959488, -2136042496, -2091568128,
+	 -2014277760, -1905371136, -1766557568, -1600016000, -1408261376, -1194489984,  -961969664,  -714350208,
+	  -455390112,  -189405776,    79551480,   347260096,   609518080,   862329024,  1101479040,  1323340160,
+	  1524429952,  1701592192,  1852112256,  1973481472,  2063874816,  2121873280,  2146566528,  2137554432,
+	  2094987264,  2019536896,  1912387584,  1775221376,  1610104320,  1419788544,  1207187712,   975638720,
+	   728775936,   470346336,   204657440,   -64243804,  -332136672,  -594942208,  -848280000, -1088303104,
+	 -1311244032, -1513603456, -1692285952, -1844313216, -1967391872, -2059590144, -2119460992, -2146069120,
+	 -2138971264, -2098299776, -2024693248, -1919306880, -1783721856, -1620198144, -1431243648, -1219824128,
+	  -989258176,  -743041664,  -485278656,  -219898672,    48932864,   316996352,   580210432,   834187840,
+	  1075071744,  1299081088,  1502793728,  1682813824,  1836420352,  1961202176,  2055200768,  2116962816,
+	  2145458688,  2140279168,  2101505664,  2029746560,  1926070400,  1792205312,  1630209536,  1442625792,
+	  1232398464,  1002711360,   757392896,   500186304,   235128736,   -33619432,  -301969664,  -565449152,
+	  -820053248, -1061785664, -1286852224, -1491814400, -1673256064, -1828434048, -1954912640, -2050706944,
+	 -2114335872, -2144739200, -2141478272, -2104604544, -2034696704, -1932794880, -1800597632, -1640138112,
+	 -1453934720, -1244910208, -1016229888,  -771705600,  -515068512,  -250346832,    18304292,   286798016,
+	   550659072,   805876928,  1048445632,  1274557824,  1480759296,  1663613056,  1820354688,  1948523776,
+	  2046108672,  2111601408,  2143910528,  2142568448,  2107571328,  2039543296,  1939421056,  1808898432,
+	  1649983104,  1465073664,  1257358464,  1029696768,   785979008,   529924480,   265422128,    -2988220,
+	  -271611744,  -535840992,  -791659584, -1035167040, -1262198528, -1469628800, -1653885568, -1812182784,
+	 -1942091648, -2041406336, -2108759552, -2142972800, -2143549696, -2110456832, -2044286208, -1945948416,
+	 -1817107072, -1659744256, -1476234752, -1269742848, -1043111232,  -800212480,  -544753536,  -280614112,
+	   -12196934,   256411680,   520995648,   777401984,  1021721472,  1249881728,  1458423552,  1644073856,
+	  1803918592,  1935505664,  2036641792,  2105810432,  2141926144,  2144421760,  2113235072,  2048885760,
+	  1952376960,  1825223424,  1669420928,  1487320576,  1281957504,  1056472640,   814283968,   559554880,
+	   295791808,    27512538,  -241198576,  -506251200,  -763104896, -1008223872, -1237395200, -1447144192,
+	 -1634263680, -1795562752, -1928821248, -2031732864, -2102754176, -2140780800, -2145184896, -2115905792,
+	 -2053421312, -1958706176, -1833178624, -1679012736, -1498330880, -1294212608, -1069780288,  -828435648,
+	  -574327744,  -310954464,   -42826744,   225973184,   491353824,   748768896,   994675008,  1224845696,
+	  1435791104,  1624285952,  1787115648,  1922038656,  2026720640,  2099591040,  2139517184,  2145838720,
+	  2118468992,  2057852416,  1964935680,  1841109632,  1688519168,  1509264896,  1306401920,  1083033600,
+	   842545152,   589071360,   326101312,    58138772,  -210736320,  -476431456,  -734518080,  -981075584,
+	 -1212342144, -1424365056, -1614225536, -1778650880, -1915158400, -2021649536, -2096320896, -2138144640,
+	 -2146379392, -2120924288, -2062142208, -1971065216, -1848946944, -1697859456, -1520122112, -1318421248,
+	 -1096231680,  -856611840,  -603659264,  -341231552,   -73316848,   195488720,   461484864,   720107008,
+	   967426240,  1199669376,  1412866560,  1604083072,  1770023168,  1908180608,  2016432256,  2092944256,
+	  2136663424,  2146815872,  2123271680,  2066364672,  1977094528,  1856690176,  1707194240,  1530902016,
+	  1330477696,  1109374080,   870634944,   618342464,   356344448,    88622216,  -180231168,  -446514816,
+	  -705659264,  -953727680, -1186935424, -1401296128, -1593859072, -1761305344, -1901105792, -2011112448,
+	 -2089491456, -2135073536, -2147140864, -2125511168, -2070481920, -1982973056, -1864339072, -1716363520,
+	 -1541604096, -1342466304, -1122348288,  -884613824,  -632869056,  -371439200,  -103923072,   165095152,
+	   431522016,   691299776,   939980544,  1174141184,  1389754496,  1583553920,  1752573568,  1893934336,
+	  2005690240,  2085902976,  2133374976,  2147359872,  2127642496,  2074493952,  1988801792,  1871893120,
+	  1725524992,  1552227712,  1354386688,  1135377664,   898547648,   647488896,   386515072,   119218648,
+	  -149820112,  -416507296,  -676781312,  -926185664, -1161287168, -1378042624, -1573168256, -1743677824,
+	 -1886666496, -2000166144, -2082208384, -2131568000, -2147469696, -2129665536, -2078400384, -1994529280,
+	 -1879351808, -1734598656, -1562682496, -1366238208, -1148349312,  -912435712,  -662075840,  -401442528,
+	  -134508160,   134537456,   401471360,   662228416,   912462336,  1148374016,  1366260864,  1562702592,
+	  1734693248,  1879366016,  1994540160,  2078407808,  2129652480,  2147470336,  2131564416,  2082201088,
+	  2000155392,  1886715008,  1743584128,  1573148288,  1378020224,  1161262464,   926277440,   676629056,
+	   416478496,   149790832,  -119247960,  -386415040,  -647641856,  -898574272, -1135402624, -1354409472,
+	 -1552157440, -1725620480, -1871907456, -1988812800, -2074501504, -2127628672, -2147361664, -2133371648,
+	 -2085895936, -2005679744, -1893982336, -1752480896, -1583534080, -1389732096, -1174116608,  -940072000,
+	  -691147904,  -431493248,  -165065872,   103952400,   371468128,   633022336,   884640576,  1122373376,
+	  1342591488,  1541624448,  1716459904,  1864353664,  1982984320,  2070524544,  2125515392,  2147143680,
+	  2135070336,  2089484672,  2011056128,  1901092224,  1761288448,  1593839360,  1401373312,  1186801792,
+	   953701376,   705631552,   446486080,   180332528,   -88782504,  -356373408,  -618370624,  -870661824,
+	 -1109287040, -1330603520, -1530922624, -1707212032, -1856705024, -1977054848, -2066408320, -2123276160,
+	 -2146816640, -2136660480, -2092967040, -2016377088, -1908167168, -1770006528, -1604063616, -1412943104,
+	 -1199536256,  -967400000,  -720079296,  -461456192,  -195590000,    73477184,   341260544,   603687424,
+	   856638784,  1096144256,  1318547968,  1520142848,  1697877376,  1848961920,  1971076864,  2062187008,
+	  2120928896,  2146380288,  2138141952,  2096314624,  2021595392,  1915145088,  1778634496,  1614206208,
+	  1424343168,  1212209792,   981049472,   734490496,   476402848,   210707104,   -58168120,  -326130336,
+	  -588973568,  -842572160, -1083058944, -1306425216, -1509285760, -1688456320, -1841124736, -1964947456,
+	 -2057860864, -2118473728, -2145834752, -2139514624, -2099584768, -2026710912, -1922025600, -1787171968,
+	 -1624266752, -1435769344, -1224821632,  -994649024,  -748864256,  -491325248,  -225944000,    42856096,
+	   310983520,   574229696,   828462720,  1069805760,  1294236032,  1498351872,  1678949376,  1833193856,
+	  1958718208,  2053429888,  2115910912,  2145180160,  2140778496,  2102748288,  2031723392,  1928808320,
+	  1795546752,  1634244608,  1447122432,  1237371136,  1008197952,   763077440,   506222656,   241169408,
+	   -27541894,  -295820896,  -559583168,  -814311104, -1056498176, -1281981056, -1487341824, -1669439488,
+	 -1825169792, -1952389248, -2048894592, -2113240320, -2144423296, -2141923968, -2105804672, -2036632448,
+	 -1935492992, -1803902720, -1644055040, -1458402048, -1249857792, -1021695616,  -777374656,  -520967168,
+	  -256382544,    12226290,   280643232,   544781952,   800239744,  1043136896,  1269660800,  1476256000,
+	  1659762944,  1817122816,  1945960832,  2044254976,  2110462336,  2143551488,  2142970880,  2108754048,
+	  2041437952,  1942079104,  1812167040,  1653866880,  1469607424,  1262280832,  1035141312,   791632320,
+	   535812544,   271582624,     2958862,  -265451264,  -529952928,  -786006336, -1029722496, -1257382272,
+	 -1465095168, -1650001920, -1808914176, -1939433600, -2039552512, -2107576960, -2142570496, -2143908864,
+	 -2111596160, -2046099712, -1948566528, -1820339072, -1663594496, -1480738048, -1274534144, -1048534400,
+	  -805849664,  -550630656,  -286768928,   -18274936,   250245808,   515096992,   771732992,  1016255744,
+	  1244934016,  1453859840,  1640157056,  1800613632,  1932807680,  2034706048,  2104584320,  2141480448,
+	  2144737664,  2114330752,  2050698240,  1954954752,  1828418560,  1673237632,  1491793280,  1286828672,
+	  1061874048,   820026112,   565420800,   301940608,    33590080,  -235027632,  -500214848,  -757543040,
+	 -1002737344, -1232422528, -1442647552, -1630228608, -1792293632, -1926083456, -2029756160, -2101511680,
+	 -2140281600, -2145451776, -2116957952, -2055192320, -1961190272, -1836405120, -1682714112, -1502772736,
+	 -1299057792, -1075046272,  -834160768,  -580055936,  -317096960,   -48903512,   219927888,   485307264,
+	   743192192,   989167872,  1219848320,  1431265536,  1620217344,  1783811200,  1919261184,  2024702976,
+	  2098306048,  2138973824,  2146063232,  2119477376,  2059581824,  1967380096,  1844298112,  1692187264,
+	  1513675648,  1311220736,  1088277760,   848252992,   594788032,   332237152,    64214460,  -204686656,
+	  -470374976,  -728803584,  -975548096, -1207212032, -1419810560, -1610123648, -1775237888, -1912401024,
+	 -2019546880, -2095022464, -2137557248, -2146565632, -2121868800, -2063866752, -1973418240, -1852097408,
+	 -1701574272, -1524409344, -1323317120, -1101341312,  -862302144,  -609489920,  -347231104,   -79522136,
+	   189565584,   455418784,   714377856,   961995904,  1194514304,  1408382464,  1599948160,  1766574208,
+	  1905384704,  2014288000,  2091604480,  2136032000,  2146958848,  2124173312,  2068046464,  1979408512,
+	  1859802496,  1710874752,  1535158400,  1335346048,  1114461952,   876307392,   624160768,   362336896,
+	    94825776,  -174304384,  -440439424,  -699915840,  -948278016, -1181755904, -1396784384, -1589691136,
+	 -1757820800, -1898271488, -2008926592, -2088080128, -2134398080, -2147242880, -2126369920, -2072121088,
+	 -1985298176, -1867348224, -1720088192, -1545829376, -1347307136, -1127525760,  -890148736,  -638799872,
+	  -377424256,  -110124584,   159034320,   425566112,   685418176,   934511872,  1168937344,  1385115136,
+	  1579442048,  1748977920,  1891061632,  2003463040,  2084449408,  2132670848,  2147417600,  2128458240,
+	  2076090368,  1991086848,  1874864512,  1729214080,  1556421760,  1359199616,  1140532352,   904064512,
+	   653406464,   392492416,   125417800,  -143756176,  -410542880,  -670885696,  -920698176, -1156059264,
+	 -1373375488, -1569024640, -1740046080, -1883755648, -1997897600, -2080712832, -2130820736, -2147483136,
+	 -2130438400, -2079953920, -1996774144, -1882285312, -1738252032, -1566845312, -1371022976, -1153480832,
+	  -917934336,  -667979776,  -407411936,  -140573840,   128470712,   395498784,   656319040,   906956480,
+	  1143233408,  1361565952,  1558527232,  1731025792,  1876417664,  1992279424,  2076870272,  2128862336,
+	  2147439360,  2132294528,  2083680128,  2002359936,  1889610496,  1747201536,  1577279488,  1382676352,
+	  1166370688,   931757376,   682519168,   422439552,   155853568,  -113178712,  -380434528,  -641719040,
+	  -893050240, -1130238848, -1349687168, -1547950720, -1721917312, -1868921216, -1986511872, -2072922112,
+	 -2126795520, -2147286400, -2134058752, -2087332864, -2007843840, -1896839552, -1756062208, -1587633408,
+	 -1394360320, -1179201152,  -945533056,  -697023872,  -437445728,  -171125376,    97880960,   365350944,
+	   627086336,   879098560,  1117186944,  1337739776,  1537295360,  1712800384,  1861329664,  1980643328,
+	  2068868480,  2124620544,  2147026944,  2135714432,  2090879360,  2013225600,  1903972096,  1764758912,
+	  1597906560,  1405973248,  1191971584,   959260672,   711369408,   452429632,   186388480,   -82578224,
+	  -350248768,  -612547392,  -865102144, -1104078080, -1325724288, -1526561792, -1703518080, -1853643520,
+	 -1974673920, -2064709632, -2122337536, -2146656512, -2137261440, -2094319488, -2018504960, -1911007744,
+	 -1773441280, -1608098432, -1417514752, -1204681472,  -972939456,  -725802688,  -467390528,  -201642112,
+	    67271288,   335128800,   597851968,   851061760,  1090913152,  1313641472,  1515843456,  1694148992,
+	  1845795968,  1968656512,  2060445824,  2119946496,  2146176896,  2138699648,  2097653248,  2023681664,
+	  1917887232,  1782033408,  1618208512,  1428886272,  1217222144,   986568768,   740076096,   482327616,
+	   216885472,   -51960932,  -320121376,  -583126080,  -836978048, -1077806080, -1301595904, -1504862336,
+	 -1684775040, -1837988608, -1962434176, -2056077184, -2117469568, -2145588096, -2140029184, -2100852992,
+	 -2028712448, -1924787200, -1790462464, -1628236288, -1440380672, -1229916800, -1000031936,  -754557888,
+	  -497240192,  -231987504,    36778988,   304838432,   568496960,   822851776,  1064417408,  1289275776,
+	  1493991680,  1675152896,  1830020736,  1956218496,  2051642624,  2114841216,  2144896512,  2141249792,
+	  2104000256,  2033725952,  1931472896,  1798945280,  1638181248,  1451607552,  1242333952,  1013560576,
+	   768755840,   512000192,   247338336,   -21333070,  -289799488,  -553586176,  -808683648, -1051202240,
+	 -1277099648, -1482951552, -1665609472, -1822029184, -1949795072, -2047105536, -2112150784, -2144083072,
+	 -2142361472, -2106987904, -2038551936, -1938118400, -1807193344, -1647958784, -1462953600, -1254689024,
+	 -1027037696,  -783159488,  -526988736,  -262416192,     6148191,   274616064,   538900416,   794596160,
+	  1037705024,  1264859648,  1471835904,  1655815936,  1813806080,  1943382400,  2042385024,  2109330048,
+	  2143175168,  2143356160,  2109919104,  2043275776,  1944665472,  1815491072,  1657820672,  1474033536,
+	  1267193088,  1040462592,   797279232,   541696320,   277740864,     9036994,  -259418672,  -523933536,
+	  -780224704, -1024384576, -1252343424, -1460645376, -1646105088, -1805631232, -1936816000, -2037641600,
+	 -2106427648, -2142141696, -2144257920, -2112694272, -2047976576, -1951113600, -1823556480, -1667431424,
+	 -1485134208, -1279420928, -1053720320,  -811601792,  -556630016,  -292791488,   -24483854,   244208080,
+	   509194208,   766057792,  1010897216,  1239976448,  1449477376,  1636142080,  1797222528,  1930150912,
+	  2032711808,  2103367040,  2141017728,  2145036288,  2115386240,  2052494080,  1957408512,  1831667584,
+	  1677122688,  1496159488,  1291794304,  1067152896,   825640384,   571282240,   307957184,    39667348,
+	  -229115376,  -494174336,  -751606976,  -997358464, -1227332480, -1438042112, -1626265600, -1788865792,
+	 -1923387776, -2027763200, -2100252288, -2139775616, -2145718144, -2117948928, -2056984448, -1963711616,
+	 -1839548544, -1686564864, -1507108608, -1303892480, -1080303744,  -839758272,  -586158080,  -322977632,
+	   -55110920,   213750384,   479384416,   737363520,   983768960,  1214841088,  1426728448,  1616221568,
+	  1780273152,  1916585856,  2022625152,  2096976128,  2138424704,  2146280064,  2120447232,  2061294848,
+	  1969808768,  1847404544,  1695922688,  1517888384,  1316132736,  1093626112,   853833536,   600751872,
+	   338240800,    70289632,  -198635360,  -464442560,  -723083200,  -970246336, -1202071680, -1415146240,
+	 -1606095360, -1771736448, -1909568256, -2017472128, -2093649664, -2136964992, -2146734720, -2122796288,
+	 -2065573888, -1975910144, -1855166464, -1705355136, -1528776320, -1328098816, -1106667264,  -867865280,
+	  -615315648,  -353227904,   -85595800,   183249216,   449605248,   708519296,   956440512,  1189458432,
+	  1403689216,  1595887360,  1763111296,  1902573440,  2012172544,  2090158464,  2135410432,  2147087104,
+	  2125076864,  2069676160,  1981808384,  1862833920,  1714541440,  1539402496,  1340100864,  1119876736,
+	   881733440,   630099328,   368455584,   100897616,  -168114928,  -434488704,  -694166784,  -942820736,
+	 -1176676096, -1391962368, -1585686656, -1754246656, -1895360256, -2006770688, -2086621056, -2133719552,
+	 -2147325312, -2127211648, -2073708928, -1987706752, -1870342400, -1723798400, -1550132992, -1352034816,
+	 -1132805632,  -895795712,  -644600320,  -383406272,  -116194296,   152841488,   419606816,   679655168,
+	   928917440,  1163943936,  1380364288,  1575228544,  1745443968,  1888173824,  2001266688,  2082947328,
+	  2131949696,  2147456640,  2129274112,  2077603072,  1993404672,  1877884416,  1732811264,  1560603264,
+	  1363899904,  1145788672,   909574208,   659193792,   398595424,   131354240,  -137560288,  -404446464,
+	  -665109056,  -915203264, -1150932352, -1368596352, -1564868352, -1736477056, -1880766464, -1995709184,
+	 -2079167744, -2130039808, -2147478784, -2131194112, -2081457920, -1999003008, -1885203712, -1741814144,
+	 -1571174272, -1375595136, -1158713472,  -923543872,  -673753728,  -413506688,  -146769152,   122402936,
+	   389523008,   650529088,   901205440,  1138083584,  1356758656,  1554249088,  1727499520,  1873390080,
+	  1989953536,  2075316096,  2128055040,  2147391744,  2133022848,  2085142400,  2004548352,  1892552704,
+	  1750652672,  1581486592,  1387421568,  1171469440,   937229888,   688279424,   428525696,   161915072,
+	  -107108560,  -374321920,  -636041216,  -887399616, -1124954496, -1344954112, -1543731584, -1718278400,
+	 -1865854976, -1984195072, -2071326080, -2125926784, -2147197568, -2134743040, -2088783360, -2009991808,
+	 -1899681536, -1759553792, -1591807872, -1398977280, -1184276224,  -951104064,  -702646272,  -443522912,
+	  -177314144,    91808736,   359359936,   621270592,   873429760,  1111991552,  1332979712,  1532953344,
+	  1709127168,  1858225024,  1978235392,  2067230720,  2123727488,  2146889984,  2136354688,  2092257280,
+	  2015332992,  1906775680,  1768215168,  1601960832,  1410660864,  1196913920,   964694912,   717225088,
+	   458497600,   192442944,   -76504240,  -344250624,  -606719360,  -859535552, -1098747776, -1321040640,
+	 -1522280704, -1699730304, -1850500608, -1972277120, -2063030144, -2121401600, -2146479232, -2137845248,
+	 -2095683840, -2020527232, -1913772928, -1776935424, -1612206976, -1422074880, -1209708416,  -978354112,
+	  -731520256,  -473321088,  -207823056,    61326872,   329123776,   591885696,   845357376,  1085673344,
+	  1308827904,  1511438848,  1690407040,  1842749312,  1966114816,  2058761984,  2118967808,  2145951360,
+	  2139240320,  2098946560,  2025707520,  1920672640,  1785417984,  1622197888,  1433613056,  1222224768,
+	   991847360,   746024768,   488120896,   222931680,   -45884356,  -313980192,  -577273984,  -831377216,
+	 -1072430976, -1296756224, -1500613888, -1680836224, -1834905856, -1959958016, -2054314496, -2116426240,
+	 -2145324160, -2140526592, -2102131072, -2030697728, -1927416960, -1793955456, -1632107648, -1444883072,
+	 -1234894336, -1005522624,  -760245440,  -503151168,  -238159424,    30701582,   298950432,   562380416,
+	   817355456,  1059134144,  1284409728,  1489525120,  1671342976,  1826832896,  1953648640,  2049838848,
+	  2113800064,  2144576000,  2141694464,  2105208704,  2035669888,  1934178816,  1802257536,  1642104704,
+	  1456177280,  1247286784,  1018915072,   774672640,   517901024,   253375040,   -15255135,  -283645856,
+	  -547711232,  -803049664, -1045783424, -1272208000, -1478549376, -1661683200, -1818804864, -1947295232,
+	 -2045180800, -2111020160, -2143732480, -2142772480, -2108179328, -2040454912, -1940728448, -1810540032,
+	 -1651849344, -1467301632, -1259829120, -1032486528,  -788815936,  -532878912,  -268577792,       70043,
+	   268586720,   532887648,   788946176,  1032494400,  1259730304,  1467308288,  1651938944,  1810544768,
+	  1940732288,  2040498688,  2108180992,  2142773120,  2143724288,  2111018496,  2045218048,  1947291520,
+	  1818730368,  1661677568,  1478542848,  1272095104,  1045775552,   803041344,   547575744,   283636928,
+	    15377190,  -253253840,  -518036960,  -774558784, -1018923008, -1247400832, -1456183936, -1642110592,
+	 -1802333696, -1934182784, -2035672704, -2105184640, -2141714304, -2144582400, -2113798528, -2049797120,
+	 -1953644928, -1826828160, -1671255040, -1489518592, -1284402560, -1059012288,  -817104704,  -562498176,
+	  -298941504,   -30561510,   238168368,   503159936,   760376448,  1005530560,  1234901760,  1444986752,
+	  1632283776,  1793888384,  1927420928,  2030743296,  2102132992,  2140527360,  2145317888,  2116424704,
+	  2054311808,  1959900672,  1834765056,  1680912128,  1500607488,  1296644608,  1072423168,   831368896,
+	   577139072,   313971264,    45875344,  -223071008,  -488384960,  -745910272,  -991855360, -1222339840,
+	 -1433522176, -1622203776, -1785495808, -1920676736, -2025710464, -2098976128, -2139252608, -2145955968,
+	 -2118987648, -2058722176, -1966163968, -1842744704, -1690320640, -1511432448, -1308820736, -1085552384,
+	  -845349120,  -591877056,  -329244384,   -61055824,   207701568,   473329888,   731651968,   978362176,
+	  1209715840,  1422179840,  1612212864,  1776940416,  1913717504,  2020619008,  2095657216,  2137846016,
+	  2146475008,  2121400192,  2063027712,  1972221696,  1850496000,  1699724800,  1522366848,  1320826880,
+	  1098852608,   859527296,   606584960,   344241728,    76495232,  -192582480,  -458506400,  -717233536,
+	  -964585856, -1197139072, -1410568832, -1601966848, -1768294656, -1906779904, -2015336064, -2092288896,
+	 -2136355584, -2146889728, -2123745664, -2067192704, -1978282880, -1858286208, -1709042304, -1533038848,
+	 -1332972544, -1111871744,  -873421504,  -621261952,  -359351072,   -91668776,   177323120,   443403488,
+	   702902528,   950994624,  1184283776,  1399083648,  1591813888,  1759559040,  1899685760,  2010041088,
+	  2088785536,  2134729728,  2147193216,  2125944064,  2071323776,  1984141440,  1865850496,  1718273024,
+	  1543725312,  1344844928,  1124946816,   887510784,   635782208,   374442112,   107099560,  -162054752,
+	  -428534528,  -688288000,  -937355904, -1171586816, -1387428352, -1581404032, -1750809600, -1892495104,
+	 -2004551552, -2085175808, -2133023872, -2147391744, -2128036224, -2075280128, -1989950080, -1873449728,
+	 -1727338496, -1554333312, -1356853248, -1137964800,  -901316224,  -650520512,  -389385248,  -122263080,
+	   146778144,   413386912,   673886784,   923433664,  1158610688,  1375702656,  1571091072,  1741819520,
+	  1885270912,  1999054208,  2081460096,  2131195264,  2147478528,  2130038784,  2079198336,  1995609088,
+	  1880825344,  1736471680,  1564772352,  1368589312,  1150924800,   915195136,   664975872,   404437632,
+	   137682096,  -131624888,  -398475488,  -659202368,  -909701056, -1145796224, -1363906944, -1560609536,
+	 -1732893952, -1877888768, -1993359232, -2077671680, -2129258240, -2147456768, -2131932800, -2082945152,
+	 -2001263360, -1888169472, -1745362432, -1575222400, -1380457856, -1163716096,  -929027520,  -679646656,
+	  -419469408,  -152832496,   116203296,   383415168,   644733888,   895803904,  1132701824,  1352143616,
+	  1550048512,  1723725568,  1870411136,  1987660544,  2073711232,  2127212800,  2147326976,  2133718528,
+	  2086618880,  2006720768,  1895355904,  1754317056,  1585503744,  1392055296,  1176668544,   942812608,
+	   694034176,   434479872,   168105936,  -101037544,  -368464480,  -629982656,  -881980672, -1119772544,
+	 -1340107904, -1539408768, -1714625792, -1862838400, -1981811840, -2069713408, -2125078144, -2147084800,
+	 -2135381760, -2090186496, -2012169472, -1902569216, -1763031296, -1595881344, -1403682304, -1189341824,
+	  -956432448,  -708634496,  -449340096,  -183370832,    85604808,   353366080,   615449856,   867873536,
+	  1106674944,  1328208896,  1528782720,  1705280896,  1855236992,  1975862400,  2065540480,  2122817408,
+	  2146738432,  2136964096,  2093647616,  2017424128,  1909564160,  1771805440,  1606002432,  1415238016,
+	  1202172928,   970121344,   722951296,   464433760,   198626384,   -70429640,  -338249696,  -600760512,
+	  -853962048, -1093633792, -1316036224, -1518080256, -1696008704, -1847409152, -1969812352, -2061334144,
+	 -2120448640, -2146280448, -2138411904, -2096974208, -2022666112, -1916463616, -1780341376, -1616215680,
+	 -1426721664, -1214725504,  -983760960,  -737355072,  -479247872,  -213741424,    54988900,   323245696,
+	   586040640,   839766592,  1080311424,  1304003840,  1507115008,  1686570496,  1839620864,  1963715328,
+	  2056949376,  2117993728,  2145713152,  2139774848,  2100250496,  2027717120,  1923383808,  1788860800,
+	  1626174208,  1438035456,  1227432576,   997234368,   751721344,   494293120,   229106416,   -39807412,
+	  -307966080,  -571290880,  -825769664, -1067160704, -1291801472, -1496259968, -1677128320, -1831603840,
+	 -1957412224, -2052535296, -2115387776, -2145036800, -2141006848, -2103365248, -2032708992, -1930089600,
+	 -1797217536, -1636221184, -1449470720, -1239862144, -1010889280,  -766049344,  -509058144,  -244199120,
+	    24492868,   292930272,   556638720,   811488768,  1053728192,  1279533440,  1485140736,  1667437184,
+	  1823630464,  1951117312,  2047979264,  2112719360,  2144258432,  2142150272,  2106425984,  2037597440,
+	  1936812032,  1805626368,  1646015104,  1460638848,  1252336128,  1024261440,   780216320,   524051904,
+	   259279616,    -9177079,  -277619840,  -541705024,  -797409280, -1040470464, -1267200384, -1474135296,
+	 -1657826304, -1815425920, -1944724864, -2043318912, -2109896448, -2143356672, -2143166336, -2109328384,
+	 -2042382208, -1943322752, -1813801344, -1655810176, -1471733888, -1264640512, -1037811904,  -794587776,
+	  -538764800,  -274607136,    -6139177,   262555232,   526997472,   783167872,  1027160768,  1254909056,
+	  1462864256,  1647964544,  1807268992,  1938122368,  2038554752,  2107015040,  2142362112,  2144082560,
+	  2112125440,  2047023488,  1949846272,  1822024448,  1665521152,  1482945024,  1277092352,  1051080064,
+	   808675264,   553577408,   289660704,    21324056,  -247217088,  -512008960,  -768886656, -1013568512,
+	 -1242341376, -1451710720, -1638187008, -1798950144, -1931534080, -2033728896, -2103975808, -2141240576,
+	 -2144889600, -2114862336, -2051639936, -1956160640, -1830016000, -1675147264, -1493891072, -1289268608,
+	 -1064409536,  -822964480,  -568235520,  -304959264,   -36769972,   232126768,   497248960,   754566336,
+	  1000155904,  1229924224,  1440387456,  1628156672,  1790612224,  1924733056,  2028715392,  2100882048,
+	  2140029952,  2145587712,  2117446272,  2056074496,  1962430464,  1838051712,  1684606976,  1504949376,
+	  1301588736,  1077684864,   836969728,   583117440,   319982848,    51951920,  -216894448,  -482208672,
+	  -740330624,  -986460352, -1217229568, -1428990848, -1618214400, -1782038400, -1917950336, -2023684736,
+	 -2097655168, -2138688640, -2146172032, -2119966080, -2060480256, -1968600448, -1845858432, -1694143488,
+	 -1515744256, -1313634304, -1090905344,  -851173824,  -597717440,  -335249344,   -67393288,   201781568,
+	   467399296,   725811200,   972947520,  1204689024,  1417521536,  1608191232,  1773520256,  1911011840,
+	  2018463360,  2094350464,  2137262336,  2146656256,  2122336128,  2064707200,  1974670336,  1853572736,
+	  1703432704,  1526555520,  1325820416,  1103958016,   865093888,   612538752,   350239872,    82569216,
+	  -186397472,  -452566560,  -711501568,  -959268736, -1191870080, -1406079104, -1597912576, -1764764032,
+	 -1903976192, -2013228800, -2090881408, -2135729024, -2147024128, -2124619264, -2068901248, -1980589184,
+	 -1861325184, -1712795008, -1537289088, -1337732736, -1117179264,  -878970752,  -627077760,  -365342080,
+	   -98002888,   171265024,   437454560,   696908416,   945541184,  1179208704,  1394367104,  1587727744,
+	  1756067456,  1896843776,  2007847040,  2087365760,  2134074368,  2147288064,  2126776192,  2072919808,
+	  1986508416,  1868852224,  1721911936,  1547944448,  1349680256,  1130119808,   892922816,   641835520,
+	   380296672,   113169712,  -155862560,  -422576928,  -682527744,  -931765504, -1166378240, -1382783488,
+	 -1577374464, -1747130624, -1889677056, -2002363136, -2083682304, -2132311168, -2147439360, -2128861056,
+	 -2076867968, -1992227200, -1876349440, -1731097984, -1558430848, -1361559040, -1143225728,  -906829504,
+	  -656310464,  -395489920,  -128461712,   140713616,   407549472,   667863808,   918060928,  1153488384,
+	  1370929024,  1566941056,  1738257280,  1882289664,  1996777472,  2079956224,  2130439552,  2147483008,
+	  2130803328,  2080678144,  1997942400,  1883688448,  1740040832,  1569018496,  1373368576,  1156051712,
+	   920690048,   670877120,   410405376,   143616400,  -125295944,  -392630144,  -653415040,  -904072704,
+	 -1140539904, -1359206656, -1556427904, -1729219456, -1874932736, -1991139328, -2076059136, -2128476928,
+	 -2147417600, -2132669824, -2084415744, -2003459840, -1891057408, -1748972672, -1579347200, -1385008128,
+	 -1169039744,  -934385728,  -685409664,  -425557280,  -158894624,   110133592,   377433120,   638808448,
+	   890276224,  1127645056,  1347212032,  1545926656,  1720093568,  1867352704,  1985351552,  2072123520,
+	  2126371200,  2147243008,  2134397056,  2088047360,  2008969728,  1898205952,  1757815680,  1589773184,
+	  1396677888,  1181748352,   948269888,   699907328,   440430592,   174164768,   -94834784,  -362474976,
+	  -624294784,  -876195904, -1114693632, -1335353088, -1535164672, -1710880128, -1859806976, -1979462912,
+	 -2068048896, -2124193920, -2146961920, -2136044544, -2091543040, -2014284800, -1905380480, -1766569088,
+	 -1599942144, -1408276736, -1194506880,  -961870656,  -714245760,  -455538080,  -189295472,    79531144,
+	   347240000,   609498560,   862310400,  1101461632,  1323324160,  1524508032,  1701659648,  1852035712,
+	  1973525248,  2063869184,  2121870080,  2146565888,  2137556480,  2094991744,  2019543808,  1912337280,
+	  1775159040,  1610204416,  1419705472,  1207204608,   975656832,   728795072,   470366208,   204677680,
+	   -64223468,  -332246048,  -594922688,  -848261312, -1088398592, -1311331712, -1513589120, -1692192768,
+	 -1844302848, -1967383680, -2059584384, -2119478784, -2146068352, -2138973056, -2098276224, -2024656256,
+	 -1919315968, -1783806080, -1620211456, -1431258752, -1219840896,  -989159872,  -743060736,  -485298496,
+	  -219788528,    49043564,   316976224,   580064640,   834169088,  1075054080,  1299064960,  1502779136,
+	  1682801152,  1836409728,  1961247232,  2055232896,  2116937344,  2145463552,  2140280832,  2101509888,
+	  2029753216,  1926079488,  1792216576,  1630222848,  1442543872,  1232307840,  1002845248,   757289280,
+	   500206080,   235148960,   -33599092,  -301949536,  -565429504,  -820034432, -1061881920, -1286940800,
+	 -1491705472, -1673325440, -1828423296, -1954904320, -2050700800, -2114332416, -2144738176, -2141479808,
+	 -2104582528, -2034661248, -1932803712, -1800537344, -1640066560, -1453949696, -1244926720, -1016247808,
+	  -771724544,  -515088256,  -250236864,    18415016,   286777856,   550766080,   805979520,  1048427840,
+	  1274541440,  1480744576,  1663600256,  1820343936,  1948570368,  2046142336,  2111597824,  2143916928,
+	  2142561024,  2107600384,  2039549696,  1939429760,  1808909312,  1649996160,  1465088640,  1257268736,
+	  1029714624,   785875968,   529817184,   265572384,    -2967877,  -271591584,  -535821280,  -791640704,
+	 -1035149248, -1262288128, -1469613952, -1653956224, -1812242176, -1942027008, -2041400064, -2108755712,
+	 -2142971520, -2143550848, -2110460672, -2044252288, -1945957120, -1817048064, -1659673984, -1476249472,
+	 -1269759232, -1043014400,  -800231360,  -544773184,  -280634272,   -12217276,   256391488,   521103072,
+	   777505216,  1021703552,  1249758592,  1458504832,  1644060800,  1803907584,  1935496832,  2036635264,
+	  2105806464,  2141934080,  2144415872,  2113238784,  2048931200,  1952330880,  1825234176,  1669433728,
+	  1487335296,  1281973760,  1056490368,   814302784,   559447936,   295811968,    27663942,  -241308608,
+	  -506104032,  -763085888, -1008205952, -1237378560, -1447129088, -1634250496, -1795623552, -1928812288,
+	 -2031768704, -2102776704, -2140768768, -2145185792, -2115909248, -2053427328, -1958714496, -1833189248,
+	 -1678943744, -1498345344, -1294124288, -1069684288,  -828575360,  -574347328,  -310974592,   -42847084,
+	   225952960,   491334016,   748872704,   994657024,  1224936704,  1435873536,  1624272640,  1787104256,
+	  1922088064,  2026713984,  2099586688,  2139515392,  2145834496,  2118472320,  2057820800,  1964891008,
+	  1841120128,  1688531712,  1509186048,  1306418048,  1083051136,   842563904,   588964864,   326121408,
+	    58028084,  -210846512,  -476411616,  -734375744,  -981174080, -1212217216, -1424349824, -1614212224,
+	 -1778639488, -1915149184, -2021642752, -2096344960, -2138142720, -2146384256, -2120906880, -2062184576,
+	 -1971073280, -1848957312, -1697871872, -1520136448, -1318437376, -1096136448,  -856630528,  -603804544,
+	  -341122240,   -73468176,   195468448,   461464992,   720087808,   967408064,  1199652480,  1412949888,
+	  1604069632,  1770011648,  1908231424,  2016425216,  2092939776,  2136674560,  2146816384,  2123274752,
+	  2066370176,  1977051392,  1856700416,  1707206656,  1530824320,  1330493568,  1109391488,   870533760,
+	   618361984,   356364512,    88642544,  -180341504,  -446494912,  -705640064,  -953826880, -1186918528,
+	 -1401280768, -1593933312, -1761293696, -1901096320, -2011105280, -2089486720, -2135071360, -2147143552,
+	 -2125495296, -2070487424, -1983031168, -1864284160, -1716454528, -1541618176, -1342482176, -1122365696,
+	  -884632320,  -633013696,  -371330144,  -103943392,   164944176,   431630496,   691156416,   939962304,
+	  1174124160,  1389738880,  1583540224,  1752486144,  1893986560,  2005683072,  2085866880,  2133387648,
+	  2147361536,  2127645184,  2074499200,  1988809472,  1871903104,  1725537152,  1552151168,  1354402432,
+	  1135394944,   898447040,   647508288,   386535104,   119108088,  -149799824,  -416487328,  -676761984,
+	  -926285568, -1161270016, -1378027136, -1573243648, -1743665920, -1886656768, -2000206464, -2082203392,
+	 -2131565440, -2147469824, -2129651328, -2078405504, -1994536832, -1879298304, -1734610688, -1562786304,
+	 -1366152704, -1148366464,  -912454144,  -662095168,  -401462496,  -134528464,   134517152,   401580128,
+	   662209088,   912325248,  1148467584,  1366245120,  1562688640,  1734681216,  1879356288,  1994532608,
+	  2078402688,  2129666688,  2147470208,  2131582720,  2082174080,  2000162816,  1886724736,  1743596032,
+	  1573162112,  1378035712,  1161279616,   926177536,   676648384,   416498432,   149680368,  -119358520,
+	  -386395008,  -647747392,  -898555776, -1135385344, -1354393728, -1552233856, -1725608320, -1871897472,
+	 -1988854528, -2074530176, -2127625856, -2147362816, -2133373952, -2085900800, -2005687040, -1893930112,
+	 -1752492672, -1583547904, -1389647616, -1174023808,  -940090304,  -691043072,  -431513184,  -165086160,
+	   103932080,   371448096,   633002880,   884622016,  1122467712,  1342575616,  1541519104,  1716526464,
+	  1864343552,  1982976512,  2070484352,  2125512448,  2147143424,  2135072512,  2089459072,  2011063296,
+	  1901162624,  1761225088,  1593853056,  1401388672,  1186927872,   953719552,   705650752,   446505984,
+	   180222192,   -88762184,  -356224064,  -618476672,  -870643200, -1109269632, -1330484736, -1530908288,
+	 -1707199744, -1856694784, -1977098112, -2066402816, -2123273088, -2146819328, -2136649344, -2092971648,
+	 -2016429184, -1908176512, -1770018048, -1604077184, -1412859776, -1199553152,  -967418176,  -719974976,
+	  -461348064,  -195610272,    73587848,   341240448,   603667904,   856620160,  1096239488,  1318531840,
+	  1520128512,  1697945216,  1849018240,  1971016832,  2062217984,  2120925696,  2146379648,  2138143872,
+	  2096318976,  2021602304,  1915154304,  1778572416,  1614133248,  1424456448,  1212118400,   981067584,
+	   734509568,   476422688,   210727344,   -58147784,  -326110208,  -589080000,  -842674048, -1082928128,
+	 -1306513152, -1509271296, -1688443776, -1841114240, -1964939264, -2057854976, -2118470400, -2145839104,
+	 -2139505024, -2099589120, -2026674304, -1921976192, -1787183232, -1624280064, -1435784448, -1224838400,
+	  -994667072,  -748760448,  -491217440,  -225964224,    42966804,   311093088,   574210112,   828443968,
+	  1069788096,  1294219776,  1498337280,  1679018368,  1833251584,  1958709888,  2053462400,  2115929728,
+	  2145179136,  2140780032,  2102752384,  2031729920,  1928817280,  1795557888,  1634172800,  1447137536,
+	  1237280640,  1008100224,   763218944,   506242432,   241189616,   -27521552,  -295800736,  -559563584,
+	  -814413568, -1056480448, -1282069888, -1487421696, -1669344256, -1825159168, -1952380672, -2048888448,
+	 -2113236736, -2144422272, -2141925376, -2105808640, -2036597376, -1935444992, -1803984896, -1644152448,
+	 -1458320768, -1249874304, -1021713536,  -777393600,  -520986912,  -256402736,    12337018,   280752992,
+	   544762240,   800099200,  1043233664,  1269644416,  1476241280,  1659750016,  1817111936,  1945952256,
+	  2044288896,  2110482816,  2143550208,  2142980736,  2108733056,  2041444224,  1942087808,  1812177920,
+	  1653879808,  1469622272,  1262191232,  1035044288,   791651200,   535705312,   271472800,     3110277,
+	  -265431088,  -529933216,  -785987392, -1029704640, -1257365760, -1465176192, -1649988864, -1808973952,
+	 -1939481216, -2039505152, -2107572992, -2142569088, -2143910016, -2111599872, -2046105984, -1948519936,
+	 -1820349952, -1663524480, -1480657792, -1274656000, -1048552128,  -805868544,  -550650368,  -286789088,
+	   -18295278,   250355792,   515077248,   771836288,  1016353280,  1244917504,  1453844864,  1640228480,
+	  1800602624,  1932798848,  2034699520,  2104606336,  2141479040,  2144732032,  2114311424,  2050704256,
+	  1954963200,  1828360576,  1673250304,  1491807872,  1286844928,  1061777856,   820044864,   565313984,
+	   301830976,    33610420,  -235007408,  -500322528,  -757401344, -1002719360, -1232405888, -1442632576,
+	 -1630215424, -1792282496, -1926132352, -2029749504, -2101480576, -2140290688, -2145458304, -2116961280,
+	 -2055198208, -1961198464, -1836415616, -1682726784, -1502693632, -1299073920, -1075177344,  -834058752,
+	  -580201728,  -317117056,   -48923852,   219907648,   485287456,   743173120,   989266176,  1219831552,
+	  1431152640,  1620290048,  1783799808,  1919252096,  2024739968,  2098301696,  2138972032,  2146064000,
+	  2119459456,  2059587584,  1967388288,  1844241408,  1692199808,  1513690112,  1311133056,  1088295296,
+	   848271744,   594807616,   332127744,    64234792,  -204666400,  -470483040,  -728784448,  -975529984,
+	 -1207303552, -1419795328, -1610110208, -1775226368, -1912391680, -2019539968, -2094989184, -2137567872,
+	 -2146566272, -2121892096, -2063836032, -1973478016, -1852107776, -1701586688, -1524423680, -1323333120,
+	 -1101358848,  -862200704,  -609509440,  -347380544,   -79411488,   189414752,   455398912,   714358656,
+	   961977728,  1194497408,  1408367104,  1600022016,  1766562688,  1905314816,  2014326400,  2091570176,
+	  2136029824,  2146959360,  2124176384,  2068051968,  1979416448,  1859747072,  1710887040,  1535172608,
+	  1335259264,  1114479360,   876325952,   624054784,   362356960,    94846096,  -174284112,  -440547808,
+	  -699896576,  -948259712, -1181848320, -1396768896, -1589677440, -1757884416, -1898261888, -2008919424,
+	 -2088075264, -2134410240, -2147243136, -2126372736, -2072092032, -1985305984, -1867422976, -1720021888,
+	 -1545843456, -1347323008, -1127543040,  -890167296,  -638819264,  -377444288,  -110014000,   159014032,
+	   425417696,   685523136,   934493504,  1168920192,  1385099648,  1579428352,  1748966144,  1891052032,
+	  2003502976,  2084444544,  2132653056,  2147416704,  2128460928,  2076095616,  1991094400,  1874874368,
+	  1729226112,  1556435712,  1359113856,  1140549504,   904082944,   653300992,   392383552,   125438104,
+	  -143866656,  -410522912,  -670866368,  -920679808, -1156152576, -1373359872, -1569010688, -1740110976,
+	 -1883808768, -1997890176, -2080740224, -2130818304, -2147483136, -2130440960, -2079926400, -1996781696,
+	 -1882295168, -1738187008, -1566859136, -1371038720, -1153387392,  -917952704,  -667999104,  -407560576,
+	  -140463344,   128319568,   395349952,   656424448,   906938048,  1143216128,  1361550208,  1558513280,
+	  1731013760,  1876343936,  1992320768,  2076831744,  2128842368,  2147440128,  2132296960,  2083684992,
+	  2002367232,  1889620224,  1747213440,  1577382144,  1382591616,  1166497792,   931893824,   682414208,
+	   422459520,   155873856,  -113158400,  -380414528,  -641699584,  -893150912, -1130333056, -1349773312,
+	 -1547845760, -1721983488, -1868911104, -1986504192, -2072916864, -2126792704, -2147286144, -2134046336,
+	 -2087306752, -2007804544, -1896910464, -1755998464, -1587647104, -1394375680, -1179218176,  -945551360,
+	  -697043072,  -437337312,  -171015008,    97991576,   365201728,   627192256,   879080000,  1117169536,
+	  1337723904,  1537281152,  1712709120,  1861384832,  1980686080,  2068898176,  2124598528,  2147026560,
+	  2135716480,  2090883968,  2013232640,  1903981440,  1764845184,  1597832576,  1405889536,  1191879552,
+	   959396160,   711388544,   452449504,   186408752,   -82557896,  -350228704,  -612402304,  -865203456,
+	 -1104173056, -1325811456, -1526455296, -1703505664, -1853633152, -1974665984, -2064704128, -2122334464,
+	 -2146652288, -2137250560, -2094295040, -2018467200, -1910957184, -1773452672, -1608111872, -1417529984,
+	 -1204481280,  -972957632,  -725945216,  -467282432,  -201531872,    67381968,   335238176,   597832448,
+	   851043072,  1090895616,  1313832704,  1515736192,  1694055936,  1845919616,  1968648320,  2060477056,
+	  2119964288,  2146176128,  2138701568,  2097657600,  2023600768,  1917955328,  1782117888,  1618135680,
+	  1428901376,  1217238912,   986470400,   740218240,   482347456,   216905712,   -52202664,  -319971648,
+	  -582980352,  -837080000, -1077788416, -1301579776, -1504941312, -1684681216, -1837978112, -1962425856,
+	 -2056146944, -2117444352, -2145581696, -2140019968, -2100857216, -2028719104, -1924738048, -1790546048,
+	 -1628249600, -1440395776, -1229718528,  -999933888,  -754699648,  -497132480,  -232007728,    36758648,
+	   304948064,   568350976,   822832960,  1064399680,  1289469184,  1494071168,  1675058176,  1830078720,
+	  1956210048,  2051636608,  2114860416,  2144889088,  2141251328,  2104004352,  2033648256,  1931424512,
+	  1799027968,  1638109568,  1451622528,  1242350592,  1013578496,   768897216,   512147232,   247358544,
+	   -21574858,  -289909216,  -553439872,  -808786240, -1051184512, -1277083264, -1482936832, -1665513984,
+	 -1821949056, -1949786624, -2047099392, -2112170752, -2144074496, -2142353920, -2106991872, -2038558208,
+	 -1938127232, -1807275136, -1648055936, -1462968576, -1254705536, -1026940480,  -783300480,  -526881376,
+	  -262436384,     6127848,   274595904,   538753856,   794455488,  1037687232,  1264843136,  1471916544,
+	  1655886464,  1813865472,  1943373824,  2042378752,  2109375488,  2143165568,  2143365504,  2109922944,
+	  2043282048,  1944618496,  1815431936,  1657750272,  1474048256,  1267209472,  1040251072,   797419840,
+	   541842816,   277761024,     9057337,  -259528592,  -524040928,  -780327872, -1024366656, -1252326912,
+	 -1460822656, -1646007808, -1805549184, -1936750592, -2037635200, -2106423680, -2142149504, -2144251904,
+	 -2112697856, -2047982592, -1951012480, -1823636480, -1667526912, -1485243648, -1279437312, -1053738048,
+	  -811499264,  -556523072,  -292811648,   -24504196,   244448304,   509047136,   765916352,  1010763648,
+	  1239959936,  1449462272,  1636213760,  1797283072,  1930142080,  2032705280,  2103415808,  2141026304,
+	  2145043584,  2115412224,  2052500096,  1957416832,  1831609728,  1677053568,  1496174080,  1291810560,
+	  1066943040,   825538112,   571428160,   308107008,    39687688,  -229095152,  -494282080,  -751710720,
+	  -997340416, -1227315712, -1438221696, -1626337920, -1788782080, -1923437056, -2027756544, -2100248064,
+	 -2139773952, -2145713664, -2117974016, -2056990336, -1963613824, -1839491456, -1686658560, -1507029760,
+	 -1303908608, -1080321280,  -839777024,  -586051520,  -323127296,   -55131256,   213990992,   479492352,
+	   737221312,   983867392,  1214824320,  1426713216,  1616208256,  1780335104,  1916517632,  2022618240,
+	  2097028224,  2138434816,  2146285184,  2120429696,  2061300480,  1969816960,  1847414912,  1696015616,
+	  1517995520,  1316148736,  1093417984,   853731904,   600645568,   338131456,    70309968,  -198615104,
+	  -464422688,  -722940672,  -970111232, -1202054912, -1415328000, -1606168832, -1771799040, -1909618944,
+	 -2017465216, -2093645184, -2136962944, -2146738816, -2122819072, -2065579392, -1975815424, -1855110656,
+	 -1705287808, -1528698624, -1328114816, -1106684672,  -867883904,  -615460736,  -353377248,   -85747096,
+	   183490128,   449585344,   708623808,   956539648,  1189441536,  1403673728,  1595873792,  1763024768,
+	  1902503168,  2012119680,  2090213888,  2135408256,  2147085056,  2125060864,  2069681536,  1981816192,
+	  1862844032,  1714632576,  1539508096,  1340219136,  1119670400,   881751936,   629993472,   368346496,
+	   100917936,  -168094640,  -434468800,  -694271552,  -942684672, -1176549376, -1392146432, -1585672960,
+	 -1754310528, -1895412224, -2006763392, -2086616192, -2133717248, -2147323904, -2127232384, -2073748224,
+	 -1987615104, -1870352384, -1723732352, -1550056448, -1352050560, -1132822912,  -895814208,  -644494656,
+	  -383555264,  -116345488,   152951936,   419586848,   679635904,   929017280,  1163816704,  1380348800,
+	  1575392768,  1745508480,  1888101632,  2001211648,  2082974336,  2131947264,  2147456768,  2129259648,
+	  2077641472,  1993412224,  1877767040,  1732745856,  1560707328,  1364016896,  1145694976,   909592640,
+	   659213120,   398486592,   131505376,  -137539984,  -404683936,  -665214336,  -915066304, -1150804608,
+	 -1368681600, -1564854400, -1736465024, -1880819968, -1995653248, -2079162624, -2130070656, -2147479040,
+	 -2131180544, -2081495168, -1999010432, -1885213568, -1741826048, -1571098752, -1375711360, -1158730624,
+	  -923325568,  -673648640,  -413398016,  -146920208,   122382624,   389503008,   650509696,   901305920,
+	  1137955200,  1356742912,  1554415872,  1727487488,  1873444096,  1989896576,  2075310848,  2128052352,
+	  2147391616,  2133025152,  2085178496,  2004602624,  1892438528,  1750664448,  1581411712,  1387537152,
+	  1171486464,   937248192,   688298688,   428545632,   162066048,  -106957336,  -374560032,  -636021760,
+	  -887500480, -1124825600, -1344938368, -1543717504, -1718266240, -1865844992, -1984137088, -2071286144,
+	 -2125960960, -2147197312, -2134731008, -2088757632, -2009998976, -1899691136, -1759565440, -1591645568,
+	 -1399092224, -1184402560,  -950887296,  -702665472,  -443414592,  -177203792,    91788408,   359339904,
+	   621251136,   873650624,  1111862016,  1332860928,  1533122688,  1709114880,  1858280576,  1978278528,
+	  2067225216,  2123724544,  2146889472,  2136330112,  2092291456,  2015385216,  1906724864,  1768226688,
+	  1601974400,  1410577280,  1197039616,   964713088,   717244224,   458261376,   192593760,   -76352920,
+	  -344359904,  -606699840,  -859516864, -1098842880, -1320921344, -1522266368, -1699717760, -1850623232,
+	 -1972217216, -2062988160, -2121418752, -2146478592, -2137847168, -2095659648, -2020578560, -1913782144,
+	 -1776946816, -1612047232, -1421991936, -1209833472,  -978255552,  -731539392,  -473340928,  -207712848,
+	    61175520,   329103680,   591866176,   845579648,  1085768832,  1308707840,  1511517568,  1690394496,
+	  1842738816,  1966159360,  2058718848,  2118964480,  2145950592,  2139230592,  2098923136,  2025757696,
+	  1920623104,  1785429248,  1622211200,  1433530624,  1222349184,   991981632,   746043840,   488140736,
+	   222821552,   -45732976,  -314089728,  -577254400,  -831358464, -1072413376, -1296635520, -1500505600,
+	 -1680823552, -1834895360, -1960003200, -2054270336, -2116444928, -2145323264, -2140528256, -2102135296,
+	 -2030746880, -1927483776, -1793966592, -1632120832, -1444801152, -1235018240, -1005424768,  -760264448,
+	  -503170976,  -238179632,    30550182,   298800480,   562360768,   817336640,  1059230464,  1284498432,
+	  1489604864,  1671330304,  1826822272,  1953749120,  2049793664,  2113773440,  2144574976,  2141695872,
+	  2105186944,  2035634560,  1934130688,  1802268544,  1642117888,  1455999616,  1247410048,  1019048384,
+	   774691584,   517920768,   253265088,   -15365861,  -283755616,  -547691520,  -803030848, -1045994560,
+	 -1272086016, -1478439552, -1661587328, -1818793984, -1947286656, -2045214592, -2111040512, -2143731328,
+	 -2142773888, -2108133248, -2040502144, -1940793216, -1810621440, -1651862400, -1467316480, -1259739520,
+	 -1032389440,  -788834816,  -532898624,  -268337872,      -81371,   268436512,   532740960,   788927296,
+	  1032476544,  1259820032,  1467389056,  1651925888,  1810533888,  1940835712,  2040533120,  2108152192,
+	  2142763008,  2143725440,  2111022336,  2045184256,  1947244800,  1818741120,  1661690368,  1478367488,
+	  1272005888,  1045907776,   802938624,   547595392,   283657088,    15266464,  -253363792,  -518017216,
+	  -774539776, -1019135872, -1247490944, -1456072576, -1642181888, -1802322688, -1934173952, -2035666304,
+	 -2105206528, -2141703168, -2144583424, -2113755776, -2049764096, -1953707776, -1826769920, -1671267840,
+	 -1489533312, -1284418816, -1059144000,  -817244672,  -562517824,  -298702048,   -30450792,   238017888,
+	   503267584,   760357440,  1005512576,  1234885120,  1444874752,  1632185472,  1793877120,  1927527552,
+	  2030779264,  2102102016,  2140536192,  2145318784,  2116428160,  2054317824,  1959962624,  1834843648,
+	  1680924800,  1500434560,  1296556288,  1072327232,   831266752,   577158656,   313991392,    45895684,
+	  -222920416,  -488237504,  -745891200,  -992069824, -1222430976, -1433604608, -1622276352, -1785484544,
+	 -1920667648, -2025703680, -2098944128, -2139239296, -2145956736, -2118948352, -2058690560, -1966119424,
+	 -1842687872, -1690333184, -1511446912, -1308836864, -1085683072,  -845488256,  -592022592,  -329005440,
+	   -61076160,   207811776,   473437888,   731632832,   978344064,  1209699072,  1422066432,  1612112896,
+	  1776855424,  1913827200,  2020612224,  2095681408,  2137856512,  2146475648,  2121403392,  2063033344,
+	  1972281600,  1850572800,  1699817344,  1522196352,  1320843008,  1098757504,   859425792,   606604480,
+	   344261792,    76515560,  -192692752,  -458358464,  -717090816,  -964801856, -1197122176, -1410652288,
+	 -1602040576, -1768283136, -1906770560, -2015329024, -2092313856, -2136340224, -2146893312, -2123709696,
+	 -2067198336, -1978239872, -1858230784, -1709054720, -1533053056, -1332782976, -1111777024,  -873559808,
+	  -621406912,  -359241888,   -91689104,   177302848,   443511840,   702759424,   950976384,  1184485376,
+	  1399167616,  1591712256,  1759472128,  1899737472,  2010033920,  2088780800,  2134741760,  2147195648,
+	  2125947008,  2071259904,  1984099072,  1865925504,  1718363904,  1543648384,  1344860800,  1124964224,
+	   887409920,   635926848,   374462144,   106858056,  -162165168,  -428386176,  -688144576,  -937337600,
+	 -1171569792, -1387412864, -1581478912, -1750721920, -1892485504, -2004638336, -2085202304, -2133036672,
+	 -2147393152, -2128039040, -2075285376, -1989957760, -1873395584, -1727428352, -1554347264, -1356665856,
+	 -1137870848,  -901215680,  -650664768,  -389405248,  -122283384,   146757856,   413495552,   673742976,
+	   923415296,  1158814208,  1375787648,  1571166592,  1741730944,  1885261056,  1999046784,  2081455104,
+	  2131192704,  2147478912,  2130057984,  2079137792,  1995616640,  1880771968,  1736560768,  1564786304,
+	  1368605056,  1150941952,   915213568,   665119808,   404586336,   137440784,  -131604584,  -398584288,
+	  -659058240,  -909682688, -1145779072, -1363891200, -1560595584, -1732804608, -1877815296, -1993449216,
+	 -2077666560, -2129272576, -2147457280, -2131935232, -2082950144, -2001270784, -1888054272, -1745450624,
+	 -1575325312, -1380272640, -1163733248,  -928927680,  -679541632,  -419489376,  -152852784,   116182984,
+	   383653056,   644589504,   895666304,  1132907264,  1352127872,  1550125184,  1723791616,  1870401152,
+	  1987652736,  2073705984,  2127245952,  2147325184,  2133735552,  2086592640,  2006728064,  1895365504,
+	  1754253184,  1585605888,  1392070784,  1176685568,   942595392,   694177472,   434628160,   167995552,
+	  -101017224,  -368444416,  -630088512,  -881842624, -1119755264, -1340092032, -1539577344, -1714534656,
+	 -1862763136, -1981854464, -2069708032, -2125075200, -2147086976, -2135397760, -2090191104, -2012176512,
+	 -1902457088, -1763117696, -1595982720, -1403598592, -1189358720,  -956450624,  -708529984,  -449488128,
+	  -183391088,    85584480,   353475296,   615555968,   867735040,  1106769792,  1328192896,  1528768384,
+	  1705348224,  1855160704,  1975854464,  2065534848,  2122834176,  2146741376,  2136979072,  2093623040,
+	  2017431168,  1909573504,  1771742848,  1606102912,  1415253248,  1202189696,   970022592,   722847040,
+	   464581568,   198516128,   -70409312,  -338229600,  -600740992,  -853823104, -1093503488, -1316020096,
+	 -1518065792, -1696076544, -1847331840, -1969856512, -2061328384, -2120445440, -2146279680, -2138425728,
+	 -2097006848, -2022672896, -1916472704, -1780279424, -1616315392, -1426638848, -1214742272,  -983779008,
+	  -737374208,  -479395456,  -213892080,    54968564,   323225568,   586147136,   839868480,  1080407168,
+	  1303987712,  1507100544,  1686720128,  1839542784,  1963654016,  2056943488,  2117990400,  2145717632,
+	  2139765504,  2100227328,  2027723776,  1923392768,  1788727040,  1626273024,  1438147840,  1227449344,
+	   997252416,   751617600,   494185376,   228996320,   -39787072,  -307945952,  -571523968,  -825629888,
+	 -1067029312, -1291680512, -1496245376, -1677115648, -1831661696, -1957457664, -2052529408, -2115384320,
+	 -2145048320, -2141018624, -2103395840, -2032757760, -1930098432, -1797228672, -1636149376, -1449388928,
+	 -1239878784, -1010907200,  -765823488,  -509205216,  -244349552,    24603590,   292910112,   556619072,
+	   811591296,  1053824704,  1279517056,  1485126144,  1667589504,  1823550464,  1951054080,  2048012544,
+	  2112715648,  2144257408,  2142142464,  2106404352,  2037603840,  1936820864,  1805495424,  1645943936,
+	  1460749824,  1252246144,  1024279296,   780235264,   523944544,   259429920,    -9156736,  -277599648,
+	  -541939008,  -797512128, -1040338048, -1267289728, -1474120576, -1657813376, -1815485056, -1944660608,
+	 -2043312640, -2109892608, -2143371648, -2143159296, -2109356800, -2042348032, -1943331456, -1813812224,
+	 -1655823104, -1471844096, -1264762880, -1037829696,  -794363136,  -538657664,  -274757312,    -6028448,
+	   262535040,   526977728,   783148928,  1027027776,  1254786176,  1462849408,  1648119552,  1807328768,
+	  1938057088,  2038589568,  2107011072,  2142360704,  2144083712,  2112152832,  2047069312,  1949854720,
+	  1821896448,  1665451136,  1482864896,  1277003392,  1051097856,   808694144,   553597120,   289810720,
+	    21475462,  -247196880,  -512243776,  -768990016, -1013666176, -1242431616, -1451695744, -1638173952,
+	 -1798939136, -1931467904, -2033680256, -2103971712, -2141258880, -2144884224, -2114843136, -2051607296,
+	 -1956169088, -1830026624, -1675159936, -1493999872, -1289389696, -1064541056,  -822741184,  -568255104,
+	  -304849664,   -36659260,   232106544,   497229184,   754547264,  1000021888,  1229800064,  1440275072,
+	  1628314368,  1790600960,  1924782080,  2028751744,  2100877824,  2140028288,  2145588480,  2117471488,
+	  2056118272,  1962492032,  1837926656,  1684619520,  1504870400,  1301500672,  1077702528,   836988480,
+	   583137024,   319873344,    52103292,  -216743808,  -482444320,  -740311488,  -986558720, -1217320704,
+	 -1428975616, -1618201088, -1782173312, -1918000128, -2023634048, -2097622656, -2138710528, -2146172672,
+	 -2119948416, -2060449024, -1968608640, -1845868800, -1693994880, -1515665792, -1313754112, -1091035776,
+	  -850951808,  -597736960,  -335139968,   -67282616,   201630832,   467379456,   726038784,   973046208,
+	  1204563584,  1417407744,  1608177792,  1773508736,  1911002624,  2018501120,  2094317056,  2137260288,
+	  2146649600,  2122319232,  2064748800,  1974729856,  1853582976,  1703445120,  1526569856,  1325733248,
+	  1104087808,   865112512,   612307008,   350130624,    82720520,  -186246624,  -452546688,  -711482368,
+	  -959250560, -1191962240, -1405964672, -1597899008, -1764901760, -1904027392, -2013267328, -2090846848,
+	 -2135726976, -2147024512, -2124622208, -2068871552, -1980647680, -1861335296, -1712649088, -1537211776,
+	 -1337646080, -1117308544,  -878989312,  -627097216,  -365362112,   -97892272,   171114096,   437434624,
+	   697137088,   945640576,  1179301248,  1394252032,  1587714048,  1756055680,  1896834176,  2007839872,
+	  2087330176,  2134057472,  2147284864,  2126779008,  2072890880,  1986566016,  1868862208,  1721924096,
+	  1547958528,  1349696000,  1130248576,   893060544,   641604736,   380316672,   113059136,  -155711552,
+	  -422556960,  -682508416,  -931747200, -1166361088, -1382667648, -1577271808, -1747271168, -1889667456,
+	 -2002403200, -2083645568, -2132308736, -2147439488, -2128863744, -2076806528, -1992283648, -1876423168,
+	 -1730954880, -1558444928, -1361473408, -1143132032,  -906847936,  -656329856,  -395509920,  -128220344,
+	   140562528,   407400800,   668093632,   918042560,  1153581824,  1371014272,  1566927232,  1738245376,
+	  1882279936,  1996866432,  2079918592,  2130420480,  2147483136,  2130805888,  2080683136,  1997901696,
+	  1883761152,  1740052736,  1569032320,  1373182720,  1156179328,   920826816,   670771904,   410425344,
+	   143636704,  -125406488,  -392481280,  -653395648,  -904054272, -1140744832, -1359089408, -1556323584,
+	 -1729285120, -1874922880, -1991131648, -2076087424, -2128456704, -2147417472, -2132672256, -2084389120,
+	 -2003514368, -1891129088, -1748908416, -1579361024, -1385023616, -1168946816,  -934522048,  -685428928,
+	  -425577216,  -158784192,   110244168,   377284064,   638914176,   890257728,  1127627648,  1347298304,
+	  1545821568,  1720081408,  1867342592,  1985393792,  2072152576,  2126349952,  2147244672,  2134399232,
+	  2088052096,  2008930560,  1898276736,  1757827328,  1589786880,  1396593792,  1181655936,   948405760,
+	   699802624,   440450496,   174185040,   -94814456,  -362325728,  -624149888,  -876177344, -1114676352,
+	 -1335439872, -1535058816, -1710947072, -1859796864, -1979454976, -2068043520, -2124171648, -2146958592,
+	 -2136046720, -2091547648, -2014246400, -1905450368, -1766506240, -1599955712, -1408292096, -1194523776,
+	  -962006016,  -714388544,  -455557952,  -189315744,    79641800,   347349280,   609604736,   862291776,
+	  1101444096,  1323514624,  1524401280,  1701567360,  1852025344,  1973517184,  2063899776,  2121887232,
+	  2146569216,  2137558400,  2094996224,  2019461504,  1912406144,  1775244288,  1610217984,  1419720832,
+	  1207112960,   975558208,   728690944,   470386048,   204697936,   -64465164,  -332096480,  -594777152,
+	  -848363008, -1088380928, -1311315584, -1513667584, -1692260992, -1844292352, -1967375616, -2059652864,
+	 -2119454336, -2146062848, -2138963200, -2098280576, -2024663040, -1919266304, -1783744512, -1620224768,
+	 -1431273984, -1219641856,  -989294272,  -743202816,  -485190624,  -219808768,    49023224,   317085728,
+	   580171264,   834150336,  1075036544,  1299257472,  1502670976,  1682707072,  1836467200,  1961239040,
+	  2055227008,  2116956032,  2145456896,  2140282496,  2101513984,  2029674240,  1926030464,  1792299904,
+	  1630150656,  1442558848,  1232324480,  1002747328,   757430976,   500225856,   235169184,   -33840864,
+	  -302059168,  -565283392,  -820136768, -1061864256, -1286924544, -1491785088, -1673230464, -1828412672,
+	 -1954895872, -2050772608, -2114351744, -2144730496, -2141471616, -2104586624, -2034667776, -1932812672,
+	 -1800619776, -1640164352, -1453964672, -1244729728, -1016150272,  -771865856,  -514980736,  -250257072,
+	    18394674,   286757696,   550619712,   805839168,  1048410112,  1274736000,  1480824704,  1663504512,
+	  1820402688,  1948561792,  2046136064,  2111594112,  2143908096,  2142571264,  2107604224,  2039473920,
+	  1939382144,  1808849664,  1649925248,  1465103488,  1257285248,  1029732480,   786016896,   529963904,
+	   265592576,    -3209677,  -271701408,  -535928512,  -791743616, -1035131392, -1262271616, -1469599104,
+	 -1653859584, -1812160896, -1942018432, -2041475072, -2108776704, -2142978688, -2143544192, -2110464384,
+	 -2044258560, -1945965696, -1817128832, -1659770112, -1476359424, -1269564288, -1043032192,  -800128640,
+	  -544666112,  -280654432,   -12237619,   256371296,   520956160,   777364096,  1021570368,  1249955200,
+	  1458489984,  1644132096,  1803967744,  1935488128,  2036628864,  2105853824,  2141923200,  2144423936,
+	  2113265664,  2048858752,  1952339328,  1825175808,  1669364096,  1487349888,  1281990144,  1056279808,
+	   814442880,   559594112,   295961920,    27422160,  -241288384,  -506211648,  -763189376, -1008187968,
+	 -1237361920, -1447307776, -1634322304, -1795540480, -1928745728, -2031762176, -2102772608, -2140777600,
+	 -2145180672, -2115912832, -2053433216, -1958615296, -1833131520, -1679038208, -1498453888, -1294140544,
+	 -1069701888,  -828473152,  -574240640,  -310994720,   -42867424,   226193424,   491441824,   748730816,
+	   994522816,  1224919936,  1435858304,  1624259328,  1787165696,  1922020608,  2026707200,  2099637504,
+	  2139524864,  2145840384,  2118497024,  2057826560,  1964899200,  1841130496,  1688463360,  1509293824,
+	  1306434176,  1082842368,   842462016,   589110464,   326271072,    58048420,  -210826256,  -476391808,
+	  -734479808,  -981039360, -1212200448, -1424530816, -1614285184, -1778701568, -1915080704, -2021635840,
+	 -2096340608, -2138140928, -2146380672, -2120930688, -2062190208, -1970977280, -1848900992, -1697804032,
+	 -1520243456, -1318453376, -1096153984,  -856649152,  -603698304,  -341271712,   -73488504,   195709248,
+	   461573152,   720192128,   967272896,  1199635584,  1412934656,  1604056064,  1770000128,  1908162048,
+	  2016373248,  2092993920,  2136672512,  2146813568,  2123297408,  2066375808,  1977059328,  1856710656,
+	  1707218944,  1530930560,  1330612480,  1109184512,   870552320,   618255936,   356513824,    88662864,
+	  -180321232,  -446475008,  -705620864,  -953691200, -1186792320, -1401463936, -1593919616, -1761356928,
+	 -1901025920, -2011098112, -2089481984, -2135069184, -2147143936, -2125516928, -2070527616, -1982938240,
+	 -1864294272, -1716387968, -1541541120, -1342498048, -1122382976,  -884650880,  -632782656,  -371479296,
+	  -104094632,   165185264,   431610560,   691261248,   940061824,  1174107136,  1389723392,  1583526528,
+	  1752625792,  1893915136,  2005628928,  2085924480,  2133385344,  2147360384,  2127630208,  2074504448,
+	  1988817024,  1871912960,  1725471232,  1552255744,  1354519936,  1135300992,   898465536,   647527680,
+	   386426176,   119259272,  -149779520,  -416467360,  -676867072,  -926148992, -1161142656, -1378112000,
+	 -1573229824, -1743654016, -1886709632, -2000151296, -2082198400, -2131563008, -2147469440, -2129670784,
+	 -2078443648, -1994495744, -1879308160, -1734622592, -1562710400, -1366269568, -1148383616,  -912472576,
+	  -661989824,  -401353728,  -134679584,   134627664,   401560160,   662189696,   912425472,  1148339712,
+	  1366229376,  1562674688,  1734746496,  1879409792,  1994476544,  2078430592,  2129664128,  2147470208,
+	  2131569280,  2082211072,  2000170240,  1886734464,  1743531392,  1573086720,  1378151936,  1161186432,
+	   926195904,   676667648,   416518400,   149831408,  -119207336,  -386375008,  -647728000,  -898656384,
+	 -1135256832, -1354479616, -1552219904, -1725596288, -1871887488, -1988797440, -2074491008, -2127623168,
+	 -2147362560, -2133361280, -2085936768, -2005647488, -1893939712, -1752504448, -1583561600, -1389763072,
+	 -1174150656,  -940108608,  -691062336,  -431404704,  -165237120,   104042680,   371428064,   632983424,
+	   884603456,  1122338688,  1342457472,  1541504896,  1716514304,  1864398464,  1983019008,  2070513792,
+	  2125509504,  2147143040,  2135046528,  2089494016,  2011116416,  1901050112,  1761236736,  1593778816,
+	  1401304704,  1186835584,   953737792,   705669952,   446269472,   180373072,   -88610896,  -356462528,
+	  -618457152,  -870744448, -1109364352, -1330571648, -1530894080, -1707187456, -1856816256, -1977038976,
+	 -2066361600, -2123289600, -2146818816, -2136651392, -2092946816, -2016391040, -1908185856, -1770029568,
+	 -1603916416, -1412973824, -1199678720,  -967319296,  -719994176,  -461367936,  -195500000,    73436520,
+	   341220352,   603648384,   856841856,  1096109312,  1318412416,  1520206720,  1697932672,  1849007872,
+	  1971060736,  2062175744,  2120922496,  2146379008,  2138121344,  2096294912,  2021653376,  1915104256,
+	  1778583808,  1614146560,  1424373504,  1212243328,   981085632,   734528704,   476186912,   210617152,
+	   -57996424,  -326219648,  -589060480,  -842655296, -1083023744, -1306392960, -1509256832, -1688431232,
+	 -1841238656, -1964983936, -2057811712, -2118488576, -2145838336, -2139506816, -2099593344, -2026724352,
+	 -1922043776, -1787194496, -1624121856, -1435702144, -1224962688,  -994568896,  -748779520,  -491237248,
+	  -225984464,    42815420,   310943264,   574190528,   828667008,  1069884096,  1294098944,  1498416640,
+	  1679005696,  1833240960,  1958701440,  2053417984,  2115903872,  2145178240,  2140760960,  2102729856,
+	  2031778944,  1928768640,  1795569024,  1634185984,  1447152512,  1237404416,  1008233920,   763237952,
+	   506007424,   241079584,   -27632272,  -295910400,  -559543936,  -814394752, -1056462784, -1281948416,
+	 -1487312384, -1669331456, -1825286528, -1952426880, -2048921728, -2113256448, -2144421120, -2141926912,
+	 -2105761280, -2036645376, -1935510656, -1803995904, -1643996800, -1458335744, -1249784320, -1021616128,
+	  -777412544,  -521006624,  -256162672,    12185605,   280602880,   544615808,   800323584,  1043215872,
+	  1269733760,  1476321664,  1659737088,  1817101056,  1946054528,  2044242560,  2110454784,  2143540992,
+	  2142964992,  2108736896,  2041409920,  1942040576,  1812188800,  1653892736,  1469445888,  1262313728,
+	  1035176960,   791791936,   535725024,   271492992,     2999548,  -265540960,  -529913504,  -785968512,
+	 -1029916864, -1257455616, -1465065472, -1649891968, -1808962944, -1939472384, -2039539712, -2107594240,
+	 -2142567680, -2143911168, -2111555840, -2046072320, -1948583680, -1820430208, -1663537408, -1480672512,
+	 -1274566912, -1048455488,  -805887424,  -550670016,  -286549440,   -18184552,   250205408,   514930240,
+	   771817344,  1016335360,  1244900864,  1453926272,  1640130688,  1800591488,  1932904192,  2034734976,
+	  2104576256,  2141467648,  2144733056,  2114315008,  2050710272,  1954917376,  1828439936,  1673263104,
+	  1491634048,  1286756352,  1061909440,   820184832,   565333632,   301851136,    33630760,  -235117472,
+	  -500175296,  -757382272, -1002933120, -1232496512, -1442714496, -1630116864, -1792271232, -1926123392,
+	 -2029742848, -2101503360, -2140278272, -2145459200, -2116920704, -2055166080, -1961153408, -1836494080,
+	 -1682739456, -1502708096, -1299090176, -1075081472,  -834198272,  -580221312,  -316877920,   -48813152,
+	   220017792,   485139936,   743154048,   989248064,  1219814784,  1431235200,  1620190720,  1783715456,
+	  1919360512,  2024733184,  2098325248,  2138958592,  2146064768,  2119462784,  2059593344,  1967396480,
+	  1844318976,  1692292992,  1513518592,  1311149184,  1088199808,   848169984,   594827136,   332147840,
+	    64255128,  -204646160,  -470335296,  -728641984,  -975745408, -1207286784, -1419878400, -1610183552,
+	 -1775214976, -1912382464, -2019533056, -2094984704, -2137553408, -2146570624, -2121854848, -2063841664,
+	 -1973434368, -1852051712, -1701599104, -1524438016, -1323349120, -1101263744,  -862339392,  -609654592,
+	  -347141920,   -79431816,   189525056,   455507104,   714339520,   961959488,  1194480512,  1408450688,
+	  1599921024,  1766476544,  1905426304,  2014319232,  2091595264,  2136041344,  2146959744,  2124179328,
+	  2068057472,  1979373568,  1859822848,  1710978560,  1535095168,  1335275264,  1114496640,   876224832,
+	   624199680,   362376992,    94866424,  -174394480,  -440399584,  -699753472,  -948359104, -1181831296,
+	 -1396753408, -1589751936, -1757797504, -1898252416, -2008912256, -2088101120, -2134393600, -2147245440,
+	 -2126357248, -2072097408, -1985313664, -1867368320, -1720112512, -1545857664, -1347338752, -1127448832,
+	  -890066496,  -638963840,  -377335264,  -110034320,   158993744,   425526240,   685379648,   934475200,
+	  1168903168,  1385184256,  1579503360,  1748878208,  1891104512,  2003495552,  2084439680,  2132666112,
+	  2147417856,  2128463616,  2076100736,  1991052928,  1874820352,  1729315840,  1556359424,  1359129600,
+	  1140566784,   904101440,   653445184,   392532416,   125458416,  -143846352,  -410631616,  -670722496,
+	  -920779840, -1156135424, -1373344256, -1568996864, -1740022272, -1883736064, -1997882624, -2080735104,
+	 -2130832000, -2147483264, -2130427008, -2079931520, -1996789120, -1882304896, -1738275968, -1566962688,
+	 -1371054336, -1153404544,  -917852608,  -668143040,  -407451872,  -140614432,   128430096,   395458784,
+	   656280320,   906800768,  1143309952,  1361635840,  1558589440,  1731079296,  1876397824,  1992264320,
+	  2076859904,  2128891392,  2147439104,  2132315008,  2083658240,  2002327296,  1889567616,  1747149056,
+	  1577307008,  1382707456,  1166404736,   931557888,   682557760,   422607968,   155763424,  -113268976,
+	  -380523488,  -641805248,  -893013184, -1130204288, -1349655552, -1548104192, -1721892992, -1868836608,
+	 -1986546176, -2072945792, -2126808064, -2147287680, -2134063360, -2087342336, -2007858304, -1896735744,
+	 -1756085632, -1587748992, -1394291584, -1179125632,  -945451904,  -696938368,  -437485568,  -171165936,
+	    97840312,   365569184,   627047424,   878941824,  1117264128,  1337810560,  1537358464,  1712775936,
+	  1861309312,  1980627584,  2068857600,  2124652800,  2147028864,  2135732352,  2090858752,  2013194112,
+	  1903930240,  1764782080,  1597933696,  1406003968,  1192005504,   959062528,   711284096,   452597536,
+	   186298448,   -82668544,  -350337952,  -612508416,  -865064896, -1104043264, -1325692288, -1526717568,
+	 -1703573120, -1853556736, -1974709504, -2064734592, -2122351360, -2146655360, -2137265408, -2094328576,
+	 -2018518912, -1910906752, -1773390336, -1608212224, -1417446784, -1204606720,  -972858880,  -725841024,
+	  -467430240,  -201682608,    67230624,   335347520,   597938752,   850904064,  1090990976,  1313712896,
+	  1515814656,  1694124032,  1845842176,  1968587904,  2060434304,  2119981952,  2146180096,  2138715264,
+	  2097633792,  2023651456,  1917905536,  1782056064,  1618235264,  1429014400,  1217363584,   986372096,
+	   740114240,   482239552,   216795552,   -52051292,  -320081120,  -583339264,  -836940544, -1077657472,
+	 -1301459328, -1505020288, -1684749824, -1838035328, -1962470912, -2056103296, -2117462784, -2145597312,
+	 -2140032512, -2100888576, -2028768768, -1924688896, -1790484992, -1628177408, -1440313728, -1229842688,
+	 -1000067904,  -754350528,  -497279776,  -232158256,    36607252,   305057664,   568457728,   822935232,
+	  1064495872,  1289348096,  1493962496,  1675291520,  1829999488,  1956147584,  2051591808,  2114879616,
+	  2144894592,  2141242880,  2103982080,  2033696896,  1931490688,  1798824320,  1638207488,  1451734144,
+	  1242474112,  1013480896,   768793856,   512039712,   247248544,   -21423450,  -289759200,  -553800128,
+	  -808888768, -1051052480, -1276961536, -1483016832, -1665583872, -1822007680, -1949832960, -2047053568,
+	 -2112143360, -2144095488, -2142346240, -2107021184, -2038605824, -1938079488, -1807215360, -1647984896,
+	 -1462887552, -1254828416, -1027073472,  -782953280,  -526774048,  -262586656,     5976434,   274705728,
+	   538861056,   794558336,  1037784192,  1264720768,  1471806208,  1655956992,  1813924736,  1943309312,
+	  2042331904,  2109347072,  2143172608,  2143358720,  2109902336,  2043328640,  1944682752,  1815372800,
+	  1657679872,  1474158336,  1267331712,  1040383552,   797316992,   541735680,   277651232,     9208750,
+	  -259378288,  -524148320,  -780431040, -1024233600, -1252203904, -1460711680, -1646078976, -1805609216,
+	 -1936798336, -2037587456, -2106394240, -2142157312, -2144245760, -2112678016, -2048028160, -1951075840,
+	 -1823577984, -1667457152, -1485163648, -1279558912, -1053870016,  -811396736,  -556416128,  -292701952,
+	   -24393474,   244297872,   509154688,   766019776,  1010861312,  1239836288,  1449350528,  1636285568,
+	  1797343744,  1930190592,  2032740992,  2103385344,  2141014656,  2145038336,  2115393280,  2052544640,
+	  1957479168,  1831552000,  1676984320,  1496094720,  1291722112,  1067074496,   825677952,   571321472,
+	   307997440,    39839076,  -228944608,  -494389856,  -751814464,  -997438464, -1227406592, -1438109184,
+	 -1626239104, -1788843264, -1923369728, -2027706624, -2100216576, -2139783296, -2145709184, -2117955712,
+	 -2056958464, -1963675008, -1839569536, -1686590080, -1506950912, -1304028928, -1080452096,  -839675136,
+	  -585945024,  -323017856,   -55020560,   213840320,   479344768,   737325312,   983965824,  1214699392,
+	  1426600064,  1616281088,  1780396928,  1916567552,  2022655488,  2096995584,  2138420992,  2146281472,
+	  2120412160,  2061342976,  1969877248,  1847358464,  1695947648,  1517917184,  1316061312,  1093548288,
+	   853870848,   600790976,   338022080,    70461296,  -198464336,  -464530784,  -723044928,  -970210048,
+	 -1202146688, -1415214208, -1606068352, -1771713536, -1909669504, -2017413376, -2093611392, -2136973952,
+	 -2146735872, -2122802432, -2065549184, -1975874816, -1855186944, -1705379840, -1528620800, -1328233728,
+	 -1106814464,  -867782592,  -615354624,  -353268032,   -85636456,   183339264,   449437280,   708480896,
+	   956638784,  1189533696,  1403559168,  1595947904,  1763088000,  1902554496,  2012158336,  2090179200,
+	  2135392256,  2147087872,  2125044992,  2069651968,  1981874560,  1862788992,  1714565888,  1539430784,
+	  1340132608,  1119799552,   881890048,   630138176,   368237408,   100807328,  -167943696,  -434577216,
+	  -694128256,  -942784192, -1176642048, -1392031232, -1585570816, -1754374400, -1895464320, -2006802816,
+	 -2086580352, -2133729664, -2147325696, -2127217152, -2073719552, -1987672448, -1870426752, -1723666304,
+	 -1549979776, -1351964544, -1132951552,  -895713536,  -644639104,  -383446304,  -116234920,   152800912,
+	   419438368,   679740928,   929117120,  1163909760,  1380433536,  1575289984,  1745420288,  1888154368,
+	  2001346944,  2082937472,  2131928960,  2147456256,  2129245312,  2077613440,  1993371008,  1877840512,
+	  1732835328,  1560631296,  1363728896,  1145823104,   909729792,   659107776,   398377792,   131394848,
+	  -137650480,  -404535232,  -665070400,  -915166464, -1151119360, -1368564992, -1564750720, -1736530176,
+	 -1880873344, -1995694208, -2079190400, -2130051328, -2147478784, -2131199104, -2081403392, -1999065728,
+	 -1885286016, -1741761280, -1571023360, -1375626368, -1158637312,  -923462272,  -673792384,  -413546592,
+	  -146548208,   122231456,   389354080,   650615232,   901406400,  1138049024,  1356828672,  1554311424,
+	  1727397504,  1873370112,  1990036736,  2075339392,  2128032000,  2147392640,  2133012352,  2085152128,
+	  2004562944,  1892510080,  1750752128,  1581514112,  1387252480,  1171393664,   937384448,   688193856,
+	   428437120,   161955632,  -107067920,  -374410944,  -635877184,  -887362560, -1125143168, -1345024640,
+	 -1543612160, -1718332672, -1865899776, -1984179456, -2071315328, -2125939584, -2147194880, -2134747520,
+	 -2088731904, -2009959936, -1899761664, -1759501952, -1591747200, -1399008128, -1184310144,  -951023040,
+	  -702808576,  -443562720,  -177093440,    91899040,   359190624,   621357120,   873512320,  1111956736,
+	  1332947712,  1533016704,  1709023232,  1858204672,  1978321536,  2067255168,  2123702016,  2146892032,
+	  2136345472,  2092266496,  2015347072,  1906794496,  1768312704,  1602075264,  1410493824,  1196947712,
+	   964614144,   717139840,   458409280,   192483472,   -76725560,  -344210464,  -606554560,  -859378112,
+	 -1098937984, -1321008640, -1522344448, -1699785472, -1850546432, -1972261120, -2063091712, -2121395328,
+	 -2146473984, -2137861504, -2095635456, -2020541056, -1913731840, -1776884608, -1612147200, -1422105344,
+	 -1209525376,  -978390336,  -731681728,  -473488608,  -207602640,    61286204,   329213088,   591972608,
+	   845440448,  1085638144,  1309003392,  1511410048,  1690301184,  1842661120,  1966203904,  2058750336,
+	  2118982528,  2145954688,  2139243904,  2098955136,  2025633920,  1920690944,  1785513472,  1622310400,
+	  1433448192,  1222258176,   991883456,   745939968,   488288160,   222972144,   -46105764,  -314199264,
+	  -577108544,  -831218816, -1072509312, -1296723840, -1500584832, -1680892416, -1834816640, -1959941376,
+	 -2054379008, -2116463744, -2145316480, -2140540416, -2102112640, -2030710912, -1927435008, -1793905792,
+	 -1632219264, -1444913280, -1234713216, -1005326912,  -760406080,  -503318144,  -238069584,    30660900,
+	   298910144,   562467648,   817196608,  1059098752,  1284587264,  1489684608,  1671235200,  1826742656,
+	  1953686144,  2049826688,  2113792896,  2144580736,  2141707008,  2105216768,  2035599360,  1934082560,
+	  1802350976,  1642215424,  1456110848,  1247319936,  1018950912,   774588352,   518067712,   253415440,
+	   -15476587,  -283865376,  -547545152,  -802890368, -1045862336, -1272175232, -1478519808, -1661657472,
+	 -1818713472, -1947222784, -2045248256, -2111060864, -2143737856, -2142766464, -2108162048, -2040467712,
+	 -1940745856, -1810561920, -1651959168, -1467427072, -1259649792, -1032292288,  -788731840,  -532791360,
+	  -268488096,       29357,   268546368,   532848224,   788786432,  1032343808,  1259909632,  1467469952,
+	  1651996672,  1810593408,  1940770944,  2040486016,  2108173312,  2142770432,  2143734400,  2111050112,
+	  2045150464,  1947198080,  1818682240,  1661620224,  1478477312,  1272127872,  1045811072,   803079040,
+	   547741824,   283807168,    15155737,  -253473744,  -518124672,  -774643072, -1019002624, -1247367680,
+	 -1456153984, -1642084352, -1802240256, -1934108160, -2035701504, -2105228416, -2141711360, -2144577664,
+	 -2113782528, -2049809152, -1953661824, -1826711808, -1671362944, -1489642368, -1284330112, -1059047680,
+	  -817142272,  -562410944,  -298852000,   -30602192,   238127936,   503375232,   760215808,  1005378816,
+	  1234975744,  1444956672,  1632257408,  1793938048,  1927460864,  2030729984,  2102124672,  2140545152,
+	  2145325568,  2116453760,  2054285568,  1959917312,  1834786176,  1680855936,  1500542848,  1296676992,
+	  1072458432,   831164672,   577304512,   314141184,    45784980,  -223030544,  -488345344,  -745995008,
+	  -991935488, -1222306432, -1433491840, -1622348800, -1785400448, -1920599936, -2025740544, -2098967552,
+	 -2139249024, -2145952512, -2118972928, -2058733696, -1966180352, -1842630912, -1690426624, -1511554560,
+	 -1308749056, -1085587584,  -845386496,  -591916160,  -329155072,   -61227512,   207661072,   473545888,
+	   731736960,   978209216,  1209790464,  1422149376,  1612185984,  1776917632,  1913758464,  2020560896,
+	  2095705600,  2137867008,  2146472192,  2121426944,  2063002624,  1972237824,  1850516608,  1699749632,
+	  1522303104,  1320962304,  1098662400,   859324352,   606498240,   344411264,    76404904,  -192541952,
+	  -458466656,  -717195200,  -964666560, -1196996480, -1410735744, -1602114304, -1768345984, -1906700928,
+	 -2015367296, -2092279680, -2136351488, -2146890752, -2123732224, -2067239296, -1978196736, -1858175232,
+	 -1708987648, -1533159168, -1332901760, -1111906560,  -873458688,  -621300928,  -359391168,   -91840376,
+	   177413200,   443620192,   702864064,   951075712,  1184359168,  1399052672,  1591786624,  1759685888,
+	  1899666816,  2009980672,  2088806528,  2134753792,  2147193856,  2125931264,  2071299840,  1984157056,
+	  1865870720,  1718140160,  1543753600,  1344978816,  1124869888,   887309120,   635821056,   374353120,
+	   107009280,  -162014192,  -428494656,  -688497728,  -937201408, -1171442944, -1387497344, -1581553920,
+	 -1750786048, -1892537856, -2004584064, -2085166080, -2133019136, -2147389696, -2128059264, -2075324288,
+	 -1989916160, -1873341440, -1727362560, -1554270976, -1356783232, -1137999232,  -901353152,  -650309440,
+	  -389554144,  -122434552,   146868320,   413604224,   673848128,   923515264,  1158686720,  1375671424,
+	  1571063296,  1741949056,  1885188608,  1998991360,  2081482368,  2131206400,  2147478656,  2130043904,
+	  2079175680,  1995672448,  1880845056,  1736341376,  1564710528,  1368721664,  1150848512,   915113344,
+	   665014528,   404477568,   137591888,  -131453456,  -398435488,  -659413120,  -909782976, -1145651072,
+	 -1363976704, -1560671616, -1732870016, -1877869056, -1993392896, -2077628288, -2129252864, -2147457792,
+	 -2131921920, -2082987008, -2001230592, -1888126464, -1745386112, -1575250048, -1380388608, -1163860480,
+	  -929064192,  -679436544,  -419380768,  -153003824,   116293552,   383504096,   644695104,   895766912,
+	  1132778624,  1352010240,  1550020352,  1723857664,  1870455552,  1987595520,  2073734784,  2127225216,
+	  2147326464,  2133723136,  2086628480,  2006781952,  1895436672,  1754189312,  1585531264,  1391986432,
+	  1176592896,   942731392,   694072704,   434263008,   168146496,  -100865976,  -368295264,  -630194304,
+	  -881943552, -1119849728, -1340178560, -1539471744, -1714601216, -1862948608, -1981796224, -2069667712,
+	 -2125053440, -2147089024, -2135385984, -2090165632, -2012137856, -1902527360, -1763054464, -1595733248,
+	 -1403713152, -1189484800,  -956586240,  -708425472,  -449379872,  -183280768,    85695120,   353325920,
+	   615410880,   868076096,  1106640128,  1328073856,  1528662016,  1705415552,  1855216512,  1975897728,
+	  2065565184,  2122811264,  2146737408,  2136942208,  2093656704,  2017483008,  1909642752,  1771680384,
+	  1606029440,  1415170048,  1202097920,   970157632,   722989632,   464217536,   198666896,   -70257976,
+	  -338080064,  -600847296,  -853924736, -1093598848, -1316107648, -1517958656, -1695983744, -1847522048,
+	 -1969900544, -2061285888, -2120421504, -2146283392, -2138415616, -2096982912, -2022635776, -1916541056,
+	 -1780364160, -1616069888, -1426556160, -1214867200,  -983913600,  -737270208,  -479287520,  -213781904,
+	    55079256,   323075872,   586001472,   839970432,  1080502912,  1303867392,  1506992640,  1686626432,
+	  1839599872,  1963698816,  2056975360,  2117965312,  2145711488,  2139756160,  2100204288,  2027773568,
+	  1923460096,  1788810880,  1626200704,  1438065664,  1227358464,   997386496,   751759424,   494077600,
+	   228886224,   -39635684,  -308055552,  -571378048,  -825732096, -1067125440, -1291768960, -1496136832,
+	 -1677021056, -1831719424, -1957503232, -2052561920, -2115403392, -2145041152, -2141010048, -2103373440,
+	 -2032722048, -1930164864, -1797311616, -1636077696, -1449307264, -1239788288, -1010809536,  -765964928,
+	  -509097664,  -244239536,    24452184,   292760096,   556472832,   811693824,  1053921152,  1279606016,
+	  1485206016,  1667494144,  1823608960,  1951100288,  2047966976,  2112688512,  2144249088,  2142134656,
+	  2106382848,  2037568896,  1936772992,  1805577344,  1646041216,  1460668672,  1252369152,  1024412416,
+	   780376320,   523837152,   259320000,    -9267464,  -277709472,  -541792512,  -797371520, -1040434880,
+	 -1267167488, -1474010496, -1657717120, -1815544192, -1944707584, -2043346688, -2109913216, -2143362304,
+	 -2143168896, -2109336064, -2042394752, -1943395840, -1813893248, -1655752704, -1471763456, -1264673408,
+	 -1037732800,  -794503808,  -538804224,  -274647488,    -5917720,   262384752,   526830944,   783252032,
+	  1027124992,  1254876032,  1462930432,  1648022528,  1807247104,  1938104832,  2038624384,  2106981888,
+	  2142350336,  2144077440,  2112132736,  2047035776,  1949808384,  1821976576,  1665546752,  1482974464,
+	  1276914304,  1051229888,   808834432,   553490112,   289701024,    21364740,  -247306880,  -512096736,
+	  -768848640, -1013532672, -1242521984, -1451584128, -1638076032, -1798999552, -1931516288, -2033715840,
+	 -2103993856, -2141247360, -2144891648, -2114869504, -2051574528, -1956231552, -1830105856, -1675090688,
+	 -1493920256, -1289301120, -1064444864,  -822881024,  -568401152,  -304740064,   -36548548,   232216624,
+	   497081888,   754650944,  1000119872,  1229890816,  1440357248,  1628215680,  1790517376,  1924831232,
+	  2028787968,  2100900736,  2140015616,  2145583872,  2117453056,  2056086272,  1962446976,  1838004992,
+	  1684713472,  1504791424,  1301412608,  1077606656,   837127936,   583030464,   320023072,    51992596,
+	  -216853968,  -482296768,  -740169408,  -986657088, -1217411968, -1429058304, -1618101504, -1782088832,
+	 -1917932032, -2023671040, -2097646464, -2138696832, -2146178048, -2119930624, -2060417792, -1968564352,
+	 -1845946112, -1694087936, -1515773056, -1313666432, -1090940416,  -851090816,  -597882368,  -335030624,
+	   -67171936,   201741072,   467231680,   725896256,   972911232,  1204655232,  1417687808,  1608077440,
+	  1773423360,  1911053056,  2018538880,  2094341504,  2137271040,  2146653824,  2122342400,  2064718336,
+	  1974583296,  1853659520,  1703537280,  1526491904,  1325646080,  1103992832,   865011136,   612452160,
+	   350280032,    82609872,  -186618080,  -452398656,  -711339520,  -959349632, -1192054272, -1406048384,
+	 -1597972992, -1764815488, -1903957376, -2013214592, -2090931968, -2135711104, -2147027584, -2124606080,
+	 -2068841856, -1980604800, -1861280128, -1712740480, -1537317504, -1337764608, -1116990080,  -879127424,
+	  -627241984,  -365252992,   -97781664,   171224464,   437543040,   696993856,   945504640,  1179174656,
+	  1394535552,  1587612032,  1755968512,  1896886144,  2007879040,  2087356160,  2134069888,  2147286912,
+	  2126799872,  2072930432,  1986424320,  1868807680,  1722014592,  1547881856,  1349609856,  1130154368,
+	   892959808,   641749248,   380465696,   113210344,  -156083440,  -422665536,  -682364864,  -931846976,
+	 -1166454144, -1382752384, -1577346944, -1747183104, -1889595520, -2002348416, -2083735808, -2132321920,
+	 -2147440384, -2128849280, -2076845056, -1992242304, -1876369280, -1731044480, -1558549120, -1361590528,
+	 -1143038336,  -906747520,  -656473984,  -395401056,  -128371488,   140673024,   407509504,   667949696,
+	   917905664,  1153454080,  1371099520,  1567002880,  1738156416,  1882333184,  1996810752,  2079946112,
+	  2130434432,  2147483136,  2130824704,  2080720640,  1997861120,  1883707904,  1739987840,  1568956672,
+	  1373299072,  1156086016,   920489920,   670915776,   410573984,   143787776,  -125517024,  -392590144,
+	  -653501120,  -904154688, -1140616576, -1359175168, -1556580480, -1729195264, -1874849024, -1991074944,
+	 -2076115840, -2128471424, -2147418368, -2132659200, -2084425472, -2003474432, -1890952448, -1748996352,
+	 -1579463552, -1385139328, -1168853888,  -934422336,  -685323968,  -425468704,  -158935200,   110092960,
+	   377651136,   638769600,   890119936,  1127498880,  1347384448,  1545898368,  1720147584,  1867397248,
+	  1985336064,  2072112768,  2126402176,  2147242368,  2134416000,  2088087424,  2008891520,  1898224896,
+	  1757763712,  1589712384,  1396708864,  1181782272,   948071168,   699945792,   440598688,   174335952,
+	   -94925080,  -362434880,  -624255872,  -876278464, -1114546944, -1335321216, -1535319552, -1711014016,
+	 -1859721088, -1979396224, -2068073344, -2124187904, -2146961024, -2136035200, -2091581952, -2014298880,
+	 -1905278336, -1766443264, -1600056704, -1408406400, -1194431744,  -961907008,  -714284096,  -455449728,
+	  -189466560,    79490488,   347458560,   609710912,   862153088,  1101539200,  1323395328,  1524479360,
+	  1701634944,  1852081408,  1973457408,  2063857920,  2121904256,  2146572416,  2137572864,  2094971904,
+	  2019513088,  1912355712,  1775181952,  1610144640,  1419834368,  1207238272,   975459520,   728586752,
+	   470533792,   204587712,   -64313816,  -332205856,  -594883584,  -848223936, -1088250496, -1311195648,
+	 -1513746176, -1692329088, -1844349056, -1967419904, -2059609984, -2119472256, -2146066944, -2138976640,
+	 -2098312832, -2024713600, -1919216640, -1783682816, -1620152192, -1431191424, -1219766528,  -989195968,
+	  -743098944,  -485338112,  -219959392,    48871848,   317195264,   580277888,   834252352,  1075132288,
+	  1299136896,  1502750080,  1682775936,  1836388736,  1961177344,  2055183104,  2116974592,  2145461760,
+	  2140273536,  2101491200,  2029723648,  1926097408,  1792238976,  1630249216,  1442671104,  1232448512,
+	  1002649408,   757327360,   500118176,   235059120,   -33689468,  -301909248,  -565390272,  -819996800,
+	 -1061732608, -1286803328, -1491864832, -1673299968, -1828470784, -1954941696, -2050727680, -2114325248,
+	 -2144736000, -2141482880, -2104616704, -2034716160, -1932764416, -1800559488, -1640092800, -1453883136,
+	 -1244853120, -1016283648,  -771762560,  -514873248,  -250407456,    18243264,   286867424,   550726784,
+	   805941824,  1048506752,  1274614144,  1480715008,  1663574528,  1820461312,  1948498176,  2046090112,
+	  2111614208,  2143914496,  2142563712,  2107582976,  2039521408,  1939447168,  1808931328,  1649854464,
+	  1465214208,  1257408000,  1029635264,   785913856,   529856608,   265482704,    -3058263,  -271551232,
+	  -535781888,  -791846528, -1034998720, -1262149120, -1469679872, -1653930240, -1812220288, -1942065664,
+	 -2041428096, -2108748032, -2142985856, -2143537536, -2110492416, -2044304896, -1945918848, -1817069824,
+	 -1659699840, -1476279040, -1269686400, -1043164544,  -800025856,  -544558976,  -280544672,   -12389032,
+	   256481232,   521063584,   777467264,  1021667776,  1249832064,  1458378752,  1644203264,  1804027776,
+	  1935536000,  2036580864,  2105824128,  2141931136,  2144418048,  2113245952,  2048904064,  1952402432,
+	  1825117440,  1669294464,  1487270016,  1282111616,  1056411648,   814340416,   559487232,   295852256,
+	    27573562,  -241137936,  -506319264,  -763292864, -1008285760, -1237238144, -1447195904, -1634224000,
+	 -1795601152, -1928794496, -2031713152, -2102741760, -2140786304, -2145175552, -2115893888, -2053477632,
+	 -1958677376, -1833210368, -1678969088, -1498374528, -1294261376, -1069833216,  -828371008,  -574133952,
+	  -310885152,   -43018808,   226042848,   491294400,   748834560,   994620928,  1224795648,  1435745792,
+	  1624331776,  1787227136,  1922070016,  2026743808,  2099605632,  2139511936,  2145836032,  2118435968,
+	  2057869824,  1964960256,  1841073536,  1688394880,  1509214976,  1306346368,  1082973056,   842601344,
+	   589004032,   325902528,    58199780,  -210675584,  -476499776,  -734583872,  -981137856, -1212291840,
+	 -1424417536, -1614185344, -1778616704, -1915249408, -2021584768, -2096307712, -2138151168, -2146377088,
+	 -2120913280, -2062159360, -1971037440, -1848977920, -1697896832, -1519980032, -1318572928, -1096284160,
+	  -856547648,  -603592064,  -341162400,   -73377840,   195558464,   461425280,   720049472,   967605760,
+	  1199510016,  1412820608,  1604129664,  1770062848,  1908212736,  2016411264,  2092960000,  2136657280,
+	  2146817408,  2123241600,  2066345600,  1977118336,  1856654976,  1707151744,  1530852864,  1330525568,
+	  1109314176,   870690752,   618400960,   356146112,    88552232,  -180170352,  -446583328,  -705725440,
+	  -953790400, -1186884608, -1401349248, -1593818240, -1761270400, -1901199360, -2011137024, -2089447040,
+	 -2135081088, -2147142016, -2125501184, -2070498176, -1982996480, -1864369408, -1716478976, -1541464064,
+	 -1342411648, -1122512128,  -884549952,  -632927360,  -371370208,  -103984032,   165034304,   431462240,
+	   691117888,   940161408,  1174199808,  1389607936,  1583601280,  1752538368,  1893967360,  2005668480,
+	  2085888384,  2133368064,  2147361920,  2127615232,  2074475904,  1988874240,  1871858816,  1725561344,
+	  1552179328,  1354434048,  1135429504,   898603072,   647672064,   386317248,   119148712,  -149889984,
+	  -416576000,  -676723392,  -926248832, -1161456384, -1377995904, -1573126784, -1743565568, -1886762496,
+	 -2000191616, -2082225408, -2131576448, -2147469952, -2129656576, -2078349824, -1994551936, -1879381376,
+	 -1734711936, -1562634368, -1366184192, -1148290048,  -912372352,  -662133888,  -401502464,  -134307440,
+	   134476544,   401411424,   662045696,   912525696,  1148433280,  1366314880,  1562750592,  1734657280,
+	  1879336576,  1994614656,  2078392448,  2129678336,  2147470592,  2131555840,  2082184064,  2000129920,
+	  1886806784,  1743772800,  1573011456,  1377865856,  1161313792,   926332480,   676811392,   416666944,
+	   149720960,  -119317896,  -386483936,  -647583680,  -898518848, -1135350784, -1354362112, -1552296320,
+	 -1725662208, -1871941760, -1988839296, -2074519680, -2127638144, -2147363712, -2133348608, -2085848064,
+	 -2005607936, -1894011008, -1752591872, -1583663872, -1389478784, -1174057984,  -940009024,  -691205696,
+	  -431553056,  -165126720,   103891440,   371537120,   633089280,   884704384,  1122433024,  1342543872,
+	  1541581952,  1716580736,  1864453504,  1983061504,  2070543104,  2125487872,  2147140352,  2135090944,
+	  2089408000,  2011077632,  1901120512,  1761323392,  1593880320,  1401419520,  1186961792,   953638592,
+	   705565376,   446417568,   180262736,   -88721528,  -356313216,  -618563200,  -870845632, -1109459200,
+	 -1330658560, -1530787840, -1707095552, -1856608512, -1977184512, -2066463104, -2123266944, -2146815104,
+	 -2136666624, -2092980736, -2016443136, -1908135040, -1769966848, -1604017024, -1412890368, -1199586944,
+	  -967454464,  -719889856,  -461259776,  -195389728,    73547184,   341329696,   603503104,   856462656,
+	  1096429824,  1318706688,  1520099712,  1697840000,  1848930816,  1971000704,  2062133376,  2120939904,
+	  2146382592,  2138135424,  2096327808,  2021616000,  1915172736,  1778521728,  1614073600,  1424290688,
+	  1212151936,   980987136,   734670976,   476590144,   210506944,   -58369160,  -326069984,  -588914880,
+	  -842516032, -1082893056, -1306272768, -1509335552, -1688499584, -1841160704, -1964922880, -2057843328,
+	 -2118463744, -2145842688, -2139497344, -2099570048, -2026687744, -1921994368, -1787133184, -1624392320,
+	 -1435619712, -1224656384,  -994470784,  -748921472,  -491384640,  -226135024,    42664032,   311052832,
+	   574297216,   828527360,  1069752832,  1294187392,  1498308096,  1679074816,  1833298688,  1958746880,
+	  2053450496,  2115922816,  2145183360,  2140793600,  2102707456,  2031658240,  1928719872,  1795652096,
+	  1634284160,  1447264384,  1237099648,  1008136128,   763134464,   506154592,   241230048,   -27480870,
+	  -295760448,  -559650816,  -814497216, -1056559168, -1282037248, -1487392256, -1669401088, -1825068544,
+	 -1952472960, -2048954880, -2113276032, -2144413056, -2141937792, -2105842304, -2036527104, -1935462656,
+	 -1803935872, -1644094336, -1458446848, -1249907456, -1021749312,  -777309312,  -520899200,  -256312992,
+	    12296332,   280712672,   544722880,   800183104,  1043312640,  1269822976,  1476402048,  1659807360,
+	  1817020416,  1945879680,  2044356736,  2110523648,  2143547776,  2142974848,  2108765568,  2041456896,
+	  1942105216,  1812129408,  1653822208,  1469556352,  1262224128,  1035079936,   791689024,   535871648,
+	   271383136,     2888819,  -265650832,  -530020800,  -785827584, -1029553920, -1257545344, -1465337984,
+	 -1649962880, -1808881280, -1939407360, -2039492352, -2107565184, -2142575232, -2143904768, -2111583360,
+	 -2046118272, -1948537088, -1820371456, -1663633152, -1480592384, -1274477824, -1048358848,  -805784768,
+	  -550816384,  -286959296,   -18073828,   250575744,   515037760,   771676032,  1016201984,  1244777600,
+	  1453814912,  1640202240,  1800651776,  1932838144,  2034686592,  2104598272,  2141475968,  2144740736,
+	  2114295552,  2050677376,  1954871552,  1828381824,  1673193728,  1491931520,  1286667648,  1061585344,
+	   819840192,   565479680,   302001024,    33782156,  -234966976,  -500282976,  -757485888, -1002799232,
+	 -1232372608, -1442602368, -1630188928, -1792187904, -1926172416, -2029779072, -2101526144, -2140287360,
+	 -2145454336, -2116990208, -2055133952, -1961108224, -1836300800, -1682833536, -1502816256, -1299210752,
+	 -1075212544,  -834096256,  -580114752,  -317027680,   -48964524,   219867168,   485247808,   743257920,
+	   989346368,  1219905920,  1431317760,  1620263424,  1783777152,  1919175040,  2024770048,  2098348800,
+	  2138991744,  2146060672,  2119487104,  2059636224,  1967457152,  1844128000,  1692224768,  1513625984,
+	  1311269120,  1088330368,   848309120,   594720768,   332038464,    64144448,  -204756384,  -470443328,
+	  -728746176,  -975610496, -1207378304, -1419961472, -1610256768, -1775277312, -1912313600, -2019481472,
+	 -2094951424, -2137589120, -2146567424, -2121878144, -2063883520, -1973494016, -1852128256, -1701531520,
+	 -1524359936, -1323261952, -1101393792,  -862237952,  -609548416,  -347291328,   -79321160,   189635344,
+	   455615328,   714443904,   961824128,  1194354688,  1408138496,  1600169728,  1766539520,  1905356544,
+	  2014266752,  2091560960,  2136025728,  2146957312,  2124163072,  2068027648,  1979432192,  1859767424,
+	  1710911616,  1535201024,  1335188480,  1114402048,   876123776,   624093760,   362267872,    95017688,
+	  -173982272,  -440764544,  -700105984,  -948223232, -1181704960, -1396638464, -1589650048, -1757860992,
+	 -1898304128, -2008951296, -2088065792, -2134405760, -2147243776, -2126378368, -2072068352, -1985271424,
+	 -1867313664, -1720046208, -1545780736, -1347456640, -1127800832,  -889965696,  -638607808,  -377484352,
+	  -110185536,   158842752,   425377824,   685484544,   934574912,  1168996096,  1385068544,  1579400704,
+	  1748942464,  1891032704,  2003535488,  2084466304,  2132679040,  2147417088,  2128449024,  2076139520,
+	  1991207808,  1874766336,  1729094784,  1556463744,  1359246848,  1140695040,   904238784,   653339712,
+	   392423552,   125347872,  -143695280,  -410482976,  -670827712,  -920643008, -1156228736, -1373429376,
+	 -1569072384, -1740087168, -1883789312, -1997923328, -2080762496, -2130845824, -2147483008, -2130413056,
+	 -2079969152, -1996844800, -1882377856, -1738056960, -1566887040, -1370969088, -1153532288,  -917989504,
+	  -668037824,  -407600544,  -140503936,   128540632,   395567616,   656385728,   906901120,  1143181696,
+	  1361721472,  1558665600,  1731144832,  1876451712,  1992207744,  2076821376,  2128836992,  2147441536,
+	  2132301824,  2083694848,  2002381952,  1889639552,  1747237120,  1577409792,  1382622720,  1166311808,
+	   931694272,   682452800,   422499392,   155914448,  -113379552,  -380632480,  -641910912,  -893113920,
+	 -1130075520, -1349537792, -1547817600, -1722115840, -1868891136, -1986488704, -2072906240, -2126787072,
+	 -2147285632, -2134080256, -2087316352, -2007819008, -1896806656, -1756021888, -1587674496, -1394406656,
+	 -1179033088,  -945352512,  -696833600,  -437377152,  -171055568,    97689056,   365161664,   627404032,
+	   879282048,  1117134848,  1337692032,  1537252736,  1712684544,  1861233792,  1980670336,  2068887296,
+	  2124630784,  2147025792,  2135720832,  2090893312,  2013155584,  1903879040,  1764718976,  1597859712,
+	  1405920256,  1192131456,   959432512,   711179648,   452233024,   186449280,   -82517240,  -350188576,
+	  -612363328,  -864926336, -1104138240, -1325779456, -1526611072, -1703480832, -1853612672, -1974649984,
+	 -2064764928, -2122368256, -2146658432, -2137254528, -2094304000, -2018481024, -1911095424, -1773327872,
+	 -1607965184, -1417363584, -1204732032,  -972993856,  -725983488,  -467066272,  -201572368,    67341296,
+	   335197984,   597793344,   851005696,  1090860544,  1313800576,  1515893120,  1694192000,  1845898752,
+	  1968632064,  2060465536,  2119915904,  2146183936,  2138681472,  2097610112,  2023702144,  1917973632,
+	  1782140544,  1617990144,  1428931712,  1217272448,   986506560,   740256384,   482387104,   216946192,
+	   -52161988,  -320190624,  -583193536,  -837042560, -1077753216, -1301547392, -1504725248, -1684818560,
+	 -1838092672, -1962515840, -2056059520, -2117437568, -2145580032, -2140001536, -2100865664, -2028732416,
+	 -1924756096, -1790568576, -1628276096, -1440425984, -1229751936,  -999969920,  -754492288,  -497172064,
+	  -232048176,    36717968,   304907776,   568564544,   823037504,  1064592064,  1289436544,  1493853696,
+	  1675032704,  1830194560,  1956301440,  2051624576,  2114853376,  2144887040,  2141254400,  2104012416,
+	  2033661440,  1931442304,  1798907008,  1638135936,  1451652480,  1242383744,  1013614400,   768690432,
+	   511932192,   247138560,   -21534174,  -289609152,  -553400512,  -808991360, -1051377600, -1277050624,
+	 -1482907392, -1665488256, -1821927424, -1949769472, -2047087104, -2112163456, -2144086912, -2142356736,
+	 -2106999808, -2038571008, -1938144768, -1807155456, -1647913856, -1462806400, -1254738560, -1027206400,
+	  -783338368,  -526666688,  -262216576,     6087162,   274555552,   538714496,   794417664,  1037651648,
+	  1264810368,  1471886848,  1655860608,  1813843584,  1943356416,  2042366208,  2109318656,  2143179648,
+	  2143351808,  2109881728,  2043294592,  1944635776,  1815593728,  1657609472,  1473887232,  1267030656,
+	  1040515968,   797457600,   541882176,   277801376,     9098022,  -259488192,  -524001472,  -780289984,
+	 -1024330944, -1252293888, -1460792832, -1646150016, -1805669120, -1936846208, -2037622400, -2106415744,
+	 -2142128128, -2144239744, -2112658176, -2047916032, -1951139072, -1823657984, -1667552512, -1485272960,
+	 -1279469952, -1053773504,  -811536960,  -556562368,  -292851936,   -24544880,   244407888,   509262272,
+	   766123200,  1010959040,  1239926656,  1449432320,  1636017664,  1797404288,  1930239104,  2032776704,
+	  2103354752,  2141002880,  2145045504,  2115419264,  2052434944,  1957433600,  1831630976,  1677078912,
+	  1496203264,  1291843072,  1066978368,   825575680,   571214720,   307887840,    39728368,  -229054704,
+	  -494242496,  -751918144,  -997536576, -1227497472, -1438191488, -1626140160, -1788759552, -1923302272,
+	 -2027829376, -2100239616, -2139770496, -2145715328, -2117980672, -2057001984, -1963630208, -1839512448,
+	 -1686521472, -1507058688, -1303940992, -1080356480,  -839814464,  -585838464,  -322908384,   -54909868,
+	   213950512,   479197152,   737183104,   983598208,  1215006848,  1426682752,  1616181376,  1780312320,
+	  1916499200,  2022604544,  2097019392,  2138431104,  2146277760,  2120436224,  2061312000,  1969833088,
+	  1847435648,  1695879808,  1517838848,  1315973760,  1093452928,   853769216,   600936320,   338430496,
+	    70088624,  -198835616,  -464382976,  -722902336,  -970074944, -1202021120, -1415297408, -1606141824,
+	 -1771776128, -1909600256, -2017451264, -2093636096, -2136958976, -2146732928, -2122785664, -2065518848,
+	 -1975831424, -1855131136, -1705471872, -1528911232, -1327940736, -1106494848,  -867921088,  -615499712,
+	  -353417376,   -85787752,   183449600,   449545568,   708585408,   956503232,  1189407616,  1403643008,
+	  1595846528,  1763151232,  1902605952,  2012197120,  2090204544,  2135404032,  2147090816,  2125028992,
+	  2069622400,  1981730944,  1862864384,  1714657024,  1539536384,  1340251008,  1119705088,   881789056,
+	   630032320,   368386592,   100958576,  -168054080,  -434428960,  -694233024,  -942883648, -1176734720,
+	 -1392115456, -1585645568, -1754287104, -1895516416, -2006842240, -2086668544, -2133742208, -2147327616,
+	 -2127237888, -2073758848, -1987531264, -1870372352, -1723756544, -1550084608, -1352082176, -1132857472,
+	  -895851200,  -644533504,  -383337376,  -116124360,   152911360,   419546944,   679597312,   929216960,
+	  1164002816,  1380518400,  1575365120,  1745332096,  1888082304,  2001196928,  2083028224,  2131942272,
+	  2147457024,  2129264896,  2077651712,  1993427328,  1877913984,  1732769920,  1560555264,  1363845888,
+	  1145729408,   909629440,   659251840,   398268992,   131284328,  -137760976,  -404644000,  -664926400,
+	  -915029504, -1150770176, -1368852224, -1564826496, -1736441088, -1880800256, -1995638272, -2079152512,
+	 -2130032128, -2147479040, -2131185536, -2081440640, -1999025280, -1885233024, -1741849856, -1570947840,
+	 -1375541248, -1158544128,  -923362304,  -673687232,  -413695168,  -146960800,   122603720,   389720768,
+	   650470912,   901268992,  1137920640,  1356711296,  1554206976,  1727463296,  1873424256,  1989979776,
+	  2075300480,  2128046848,  2147391232,  2132999552,  2085125632,  2004523264,  1892457728,  1750688000,
+	  1581616640,  1387568128,  1171300864,   937048960,   688337280,   428585504,   162106624,  -106916696,
+	  -374519968,  -635982912,  -887463424, -1125014144, -1344906624, -1543689216, -1718241792, -1865954560,
+	 -1984221824, -2071344640, -2125955200, -2147196672, -2134763904, -2088828032, -2009921024, -1899587840,
+	 -1759438464, -1591848832, -1399123072, -1184436480,  -950688704,  -702703936,  -443454400,  -177244336,
+	    91747760,   359299776,   621212160,   873613440,  1112051456,  1333034624,  1533094272,  1709090176,
+	  1858260224,  1978160640,  2067285120,  2123757312,  2146894720,  2136360832,  2092300544,  2015399296,
+	  1906622848,  1768249856,  1602001536,  1410608000,  1197073408,   964749376,   717282560,   458301120,
+	   192373184,   -76574240,  -344319744,  -606660800,  -859479616, -1098582656, -1321095936, -1522422656,
+	 -1699853184, -1850469632, -1972201216, -2062976768, -2121453184, -2146477440, -2137851008, -2095668608,
+	 -2020592256, -1913800576, -1776969728, -1612074112, -1422022400, -1209650560,  -978291776,  -731577664,
+	  -473380608,  -207753344,    61396888,   329322496,   592079040,   845542272,  1085507584,  1308675584,
+	  1511674880,  1690531072,  1842717952,  1966142976,  2058707328,  2118957952,  2145949056,  2139234176,
+	  2098931712,  2025684224,  1920641408,  1785451904,  1622237824,  1433560960,  1222167168,   991785216,
+	   745836160,   488180352,   223122736,   -45692300,  -314308800,  -577467712,  -831320896, -1072378112,
+	 -1296603136, -1500476544, -1680798208, -1834874240, -1959986560, -2054334848, -2116438144, -2145321344,
+	 -2140531584, -2102143616, -2030674944, -1927386112, -1793844864, -1632147328, -1445025280, -1235051520,
+	 -1005229056,  -760057344,  -503210528,  -238220064,    30509500,   298760192,   562321536,   817299008,
+	  1059195072,  1284465920,  1489575552,  1671304704,  1826800896,  1953732224,  2049859712,  2113812480,
+	  2144586496,  2141698944,  2105194880,  2035731072,  1934034432,  1802148224,  1641975168,  1456222080,
+	  1247443200,  1019084224,   774729536,   517960256,   253305488,   -15325176,  -283715296,  -547652224,
+	  -802993088, -1045959040, -1272264448, -1478600192, -1661727616, -1818772352, -1947269504, -2045122176,
+	 -2111081088, -2143744384, -2142759168, -2108190976, -2040514816, -1940810624, -1810643328, -1651888384,
+	 -1467346176, -1259772416, -1032425088,  -788872704,  -532938016,  -268378240,      140086,   268656224,
+	   532955488,   788889472,  1032440896,  1259574784,  1467550720,  1652067456,  1810652928,  1940706176,
+	  2040438784,  2108144384,  2142760320,  2143727872,  2111029760,  2045196672,  1947261952,  1818762752,
+	  1661716224,  1478396928,  1272038656,  1045714368,   802976384,   547634752,   283697440,    15307148,
+	  -253583696,  -518232128,  -774746368, -1019100032, -1247244416, -1456042752, -1641986688, -1802443008,
+	 -1934156288, -2035653248, -2105198464, -2141700224, -2144585600, -2113763072, -2049776256, -1953615872,
+	 -1826791424, -1671293440, -1489562624, -1284451456, -1058951360,  -817039872,  -562304128,  -298742336,
+	   -30753592,   237977456,   502973184,   760564544,  1005476672,  1234851840,  1444844672,  1632158976,
+	  1793854848,  1927509632,  2030766080,  2102147328,  2140532992,  2145320576,  2116435072,  2054329600,
+	  1959872128,  1834728576,  1680787072,  1500463616,  1296588800,  1072589632,   831545984,   576945344,
+	   313772320,    45936360,  -222879936,  -488197888,  -745853056,  -992033728, -1222397440, -1433574400,
+	 -1622249600, -1785461888, -1920649472, -2025690240, -2098990976, -2139258752, -2145948288, -2118955008,
+	 -2058702208, -1966241152, -1842574080, -1690196608, -1511289600, -1308869120, -1085718144,  -845525696,
+	  -592061696,  -329045632,   -61116828,   207771280,   473398208,   731594624,   978307840,  1209665408,
+	  1422232320,  1612259200,  1776979840,  1913808768,  2020598400,  2095615232,  2137877504,  2146468736,
+	  2121368960,  2063044608,  1972297728,  1850593536,  1699842176,  1522225024,  1320875008,  1098792448,
+	   859463104,   606643520,   344301952,    76556224,  -192652240,  -458574816,  -717299584,  -964765504,
+	 -1197088384, -1410621568, -1602188032, -1768408832, -1906872448, -2015405568, -2092245632, -2136336000,
+	 -2146894208, -2123676800, -2067209344, -1978255616, -1858251136, -1709079296, -1533081600, -1333020416,
+	 -1111811840,  -873357504,  -621194944,  -359282016,   -91729752,   177262304,   443728512,   702968704,
+	   951174976,  1184451456,  1398937856,  1591684992,  1759448832,  1899840640,  2010019584,  2088771328,
+	  2134737408,  2147196288,  2125952768,  2071339776,  1984114688,  1865815808,  1718231040,  1543676672,
+	  1344892544,  1124998784,   887208256,   635715328,   374244096,   106898688,  -161863200,  -428346304,
+	  -688105984,  -937536832, -1171535744, -1387381888, -1581451392, -1750698368, -1892466176, -2004529664,
+	 -2085192576, -2133032064, -2147391104, -2128044416, -2075295744, -1989972992, -1873287296, -1727296768,
+	 -1554194560, -1356697344, -1137905408,  -901490560,  -650703552,  -389187456,  -122062288,   146717264,
+	   413455648,   673704384,   923378560,  1158559360,  1375756416,  1571138816,  1741860480,  1885241600,
+	  1999031936,  2081445120,  2131219968,  2147478400,  2130029824,  2079148032,  1995631616,  1880918144,
+	  1736584704,  1564634624,  1368434432,  1150976256,   915250368,   665158528,   404626272,   137481392,
+	  -131563976,  -398544288,  -659269056,  -909645824, -1145744640, -1363859840, -1560747648, -1732935424,
+	 -1877922816, -1993434112, -2077656320, -2129233152, -2147455744, -2131908608, -2082896256, -2001285504,
+	 -1888198528, -1745474304, -1575352960, -1380102912, -1163767424,  -928964352,  -679580224,  -419529280,
+	  -152893376,   116142360,   383613024,   644800704,   895867584,  1132872704,  1352096256,  1550097024,
+	  1723611008,  1870509952,  1987736576,  2073763584,  2127204480,  2147324672,  2133740160,  2086540288,
+	  2006742528,  1895384704,  1754276608,  1585633408,  1392101760,  1176719616,   942631936,   693967936,
+	   434411296,   168036112,  -100976584,  -368404352,  -629798976,  -882044544, -1119944192, -1340265088,
+	 -1539366144, -1714510080, -1862742912, -1981939712, -2069697152, -2125069312, -2147086080, -2135402112,
+	 -2090200448, -2012190720, -1902475904, -1762991232, -1595834496, -1403629312, -1189392640,  -956487104,
+	  -708568384,  -449271584,  -183170448,    85805760,   353435168,   615265856,   867697792,  1106959616,
+	  1328366848,  1528739840,  1705323520,  1855140224,  1975838464,  2065523712,  2122828032,  2146740352,
+	  2136957184,  2093632128,  2017445120,  1909592064,  1771765888,  1605955840,  1415086720,  1202006272,
+	   970058880,   723132224,   464621312,   198295616,   -70630648,  -338189440,  -600701952,  -853785792,
+	 -1093468544, -1315987968, -1518036992, -1696051584, -1847444864, -1969840256, -2061316992, -2120439040,
+	 -2146278400, -2138405376, -2096959104, -2022598528, -1916491136, -1780448768, -1616342144, -1426473344,
+	 -1214559744,  -983815168,  -737412416,  -479435104,  -213932560,    54927892,   323185344,   586108032,
+	   839831040,  1080371968,  1303955328,  1507071616,  1686694912,  1839656960,  1963743616,  2057007104,
+	  2117983616,  2145715968,  2139791104,  2100181120,  2027650816,  1923294336,  1788894592,  1626299648,
+	  1438178048,  1227482624,   997288448,   751655744,   494224960,   229036768,   -39746392,  -307905696,
+	  -571484800,  -825834368, -1067221504, -1291857408, -1496216192, -1677090176, -1831503488, -1957548800,
+	 -2052594432, -2115422336, -2145033856, -2141021824, -2103404032, -2032770944, -1930116352, -1797250944,
+	 -1636175744, -1449419008, -1239911936, -1010943104,  -765861504,  -508990080,  -244129536,    24562906,
+	   292869792,   556579776,   811310912,  1054017664,  1279694976,  1485286016,  1667398656,  1823529088,
+	  1951037056,  2047921408,  2112708352,  2144255104,  2142145408,  2106412288,  2037616640,  1936838400,
+	  1805517440,  1645970048,  1460587392,  1252279168,  1024315072,   780273152,   523984000,   259210080,
+	    -9378192,  -277819264,  -541899648,  -797230912, -1040302400, -1267045248, -1474281600, -1657787520,
+	 -1815463296, -1944643456, -2043300096, -2109885056, -2143369216, -2143161856, -2109315328, -2042360576,
+	 -1943348736, -1813833984, -1655849088, -1471682816, -1264583936, -1037635840,  -794400960,  -538950784,
+	  -274797664,    -6331277,   262754832,   526938304,   783111040,  1026992064,  1254753152,  1462819584,
+	  1648093568,  1807306880,  1938152448,  2038576768,  2107003264,  2142357888,  2144085888,  2112112768,
+	  2047002368,  1949761920,  1821917952,  1665642368,  1483083904,  1276825344,  1050904704,   808488960,
+	   553636416,   289851040,    21516146,  -247156464,  -512204256,  -768952064, -1013630272, -1242398464,
+	 -1451665792, -1638147584, -1798916864, -1931564672, -2033751424, -2104016000, -2141255808, -2144886144,
+	 -2114895744, -2051541760, -1956077696, -1829910784, -1675185408, -1494029056, -1289422208, -1064576384,
+	  -822778752,  -568294336,  -304889920,   -36699940,   232066096,   497189600,   754509184,  1000217856,
+	  1229981568,  1440439296,  1628287872,  1790578560,  1924647808,  2028824320,  2100923648,  2140046720,
+	  2145590272,  2117478272,  2056130048,  1962508544,  1837947776,  1684644736,  1504899456,  1301533056,
+	  1077737600,   837025920,   583176192,   319913568,    51881900,  -216964128,  -482404672,  -740273344,
+	  -986522560, -1217503232, -1429140864, -1618346624, -1782150656, -1917863808, -2023620352, -2097613952,
+	 -2138730496, -2146174080, -2119954816, -2060460544, -1968624896, -1845889536, -1694180992, -1515694592,
+	 -1313578880, -1090845056,  -850989120,  -597776064,  -335180160,   -67061264,   201851312,   467595616,
+	   726000448,   972776256,  1204529920,  1417377152,  1608324608,  1773485824,  1910984064,  2018487168,
+	  2094308096,  2137256320,  2146657920,  2122325504,  2064688000,  1974642816,  1853603584,  1703469952,
+	  1526598400,  1325559040,  1103897856,   864909824,   612346048,   350429408,    82761176,  -186206096,
+	  -452763168,  -711443968,  -959214144, -1191928320, -1405933952, -1597871744, -1764729216, -1904008576,
+	 -2013253120, -2090897408, -2135722624, -2147025408, -2124628096, -2068812160, -1980562048, -1861224832,
+	 -1712673664, -1537240192, -1337883008, -1117343232,  -878787200,  -626885376,  -365402208,   -97932920,
+	   171073536,   437394784,   697098624,   945604032,  1179267200,  1394420352,  1587686656,  1756032256,
+	  1896815104,  2007918336,  2087382144,  2134082176,  2147285376,  2126784640,  2072969984,  1986581376,
+	  1868753024,  1721791744,  1547986688,  1349727744,  1130283136,   893097536,   641643584,   380356736,
+	   113099768,  -155932416,  -422517088,  -682469888,  -931710528, -1166547072, -1382837120, -1577422080,
+	 -1747247488, -1889648128, -2002293760, -2083635840, -2132335104, -2147438080, -2128869120, -2076883456,
+	 -1992298880, -1876442880, -1730978944, -1558472832, -1361504896, -1143166464,  -906884800,  -656368576,
+	  -395549888,  -128260960,   140783520,   407618240,   668054912,   918005760,  1153547520,  1370781184,
+	  1567078656,  1738375424,  1882386560,  1996754944,  2079908480,  2130415360,  2147483392,  2130811008,
+	  2080693248,  1997916672,  1883780608,  1740076544,  1569060096,  1373213952,  1155992704,   920626752,
+	   670810560,   410465280,   143677296,  -125104176,  -392699008,  -653606592,  -904255104, -1140488192,
+	 -1359057920, -1556295552, -1729416320, -1874903040, -1991116416, -2076077056, -2128451328, -2147417216,
+	 -2132676992, -2084398848, -2003434624, -1891024128, -1748932096, -1579388544, -1385054720, -1169200768,
+	  -934322624,  -685219072,  -425360160,  -158824768,   109941744,   377244032,   639125568,   890459264,
+	  1127593088,  1347266560,  1545793280,  1720056960,  1867322496,  1985378304,  2072141952,  2126380928,
+	  2147244032,  2134403712,  2088061568,  2008945024,  1898173184,  1757700096,  1589638016,  1396624768,
+	  1181908736,   948442240,   699593216,   440233760,   174225584,   -94773808,  -362285632,  -624110976,
+	  -876140224, -1114641536, -1335408000, -1535213696, -1710922496, -1859776512, -1979439232, -2068103168,
+	 -2124204160, -2146963456, -2136023808, -2091556864, -2014351488, -1905469056, -1766380288, -1599808000,
+	 -1408322816, -1194557568,  -962042368,  -714426944,  -455597696,  -189356272,    79601144,   347309120,
+	   609565696,   862254464,  1101409280,  1323482496,  1524557312,  1701702400,  1852137472,  1973501184,
+	  2063888512,  2121840512,  2146575616,  2137537152,  2094947584,  2019564544,  1912424576,  1775267072,
+	  1610244864,  1419751296,  1207146624,   975594432,   728729216,   470425728,   204738432,   -64424496,
+	  -332315264,  -594989952,  -848325632, -1088345856, -1311283328, -1513452800, -1692397312, -1844405760,
+	 -1967464320, -2059567104, -2119447808, -2146061440, -2138990080, -2098289280, -2024676608, -1919284480,
+	 -1783767168, -1620251520, -1431304320, -1219675392,  -989097728,  -742995072,  -485230240,  -219849232,
+	    48982548,   316786240,   580384448,   834354368,  1075228160,  1299016320,  1502641920,  1682681856,
+	  1836310144,  1961222400,  2055215232,  2116949120,  2145455104,  2140285824,  2101522432,  2029687552,
+	  1926048512,  1792177920,  1630177152,  1442589056,  1232357760,  1002783296,   757223744,   500010496,
+	   234949056,   -33800180,  -301759328,  -565244160,  -819856896, -1062056704, -1286892032, -1491755904,
+	 -1673204992, -1828391296, -1954878976, -2050760576, -2114344576, -2144741632, -2141474560, -2104594688,
+	 -2034680832, -1932830336, -1800499072, -1640021376, -1453801600, -1244762880, -1016417024,  -771903808,
+	  -514765760,  -250037120,    18353990,   286717376,   550580416,   805801472,  1048374592,  1274703232,
+	  1480795264,  1663644544,  1820381056,  1948544640,  2046123776,  2111586688,  2143920896,  2142556288,
+	  2107561728,  2039486720,  1939512192,  1809012864,  1649783552,  1464941568,  1257318144,  1029768128,
+	   786054784,   530003360,   265632944,    -3168992,  -271661056,  -535889120,  -791705792, -1035095744,
+	 -1262238720, -1469569408, -1654000896, -1812279808, -1942112896, -2041462400, -2108769024, -2142958976,
+	 -2143530752, -2110423424, -2044190592, -1945982848, -1817150464, -1659795968, -1476388992, -1269597056,
+	 -1043067776,  -800166336,  -544705472,  -280694784,   -12278304,   256330896,   521171008,   777570496,
+	  1021765184,  1249922048,  1458460032,  1643937280,  1804087808,  1935584000,  2036699136,  2105794560,
+	  2141920256,  2144426112,  2113272832,  2048870912,  1952356224,  1825197184,  1669389696,  1487379328,
+	  1282022784,  1056543488,   814237952,   559380352,   295742592,    27462842,  -241247952,  -506172096,
+	  -763396352, -1008383488, -1237542912, -1447277696, -1634125824, -1795518208, -1928727808, -2031833856,
+	 -2102764288, -2140774400, -2145182592, -2115919744, -2053445120, -1958739456, -1833152768, -1678900096,
+	 -1498295168, -1294172928, -1069737216,  -828510720,  -574027200,  -310775584,   -42646008,   226152960,
+	   491147008,   748692672,   994486720,  1225101824,  1435828096,  1624232704,  1787143168,  1922002432,
+	  2026693760,  2099573888,  2139521408,  2145831680,  2118460800,  2057838208,  1964915584,  1841151488,
+	  1688326528,  1509136256,  1306258432,  1082877440,   842740608,   589149632,   326311296,    57827040,
+	  -210785776,  -476352128,  -734441600,  -981003200, -1212166784, -1424500352, -1614258304, -1778678784,
+	 -1915180928, -2021622144, -2096331776, -2138137088, -2146373504, -2120895872, -2062128384, -1970993536,
+	 -1848921600, -1697989504, -1520272128, -1318278656, -1095963520,  -856686464,  -603737344,  -341311904,
+	   -73529168,   195668736,   461533408,   720153792,   967470592,  1199601792,  1412903936,  1604029056,
+	  1770125440,  1908263552,  2016449408,  2092984832,  2136668416,  2146821120,  2123303552,  2066315520,
+	  1976972800,  1856731136,  1707243648,  1530959104,  1330644352,  1109219328,   870589568,   618294912,
+	   356295424,    88703520,  -180280704,  -446435200,  -705830016,  -953889600, -1186976896, -1401433216,
+	 -1593892352, -1761183744, -1901006976, -2011175808, -2089533184, -2135064832, -2147144704, -2125522816,
+	 -2070538368, -1982953984, -1864314368, -1716412416, -1541569408, -1342529792, -1122417664,  -884687936,
+	  -632821504,  -371261152,  -103873432,   165144704,   431570720,   691222720,   939789568,  1174292480,
+	  1389892224,  1583676032,  1752450816,  1893895936,  2005614336,  2085977088,  2133380736,  2147360768,
+	  2127635712,  2074514944,  1988832384,  1871932928,  1725495424,  1552102784,  1354348160,  1135335424,
+	   898502464,   647566464,   386724064,   119038160,  -150000448,  -416684640,  -676579712,  -926112256,
+	 -1161108480, -1378281856, -1573202176, -1743630208, -1886690176, -2000136448, -2082188416, -2131558016,
+	 -2147469568, -2129642368, -2078387968, -1994510848, -1879327872, -1734646656, -1562918016, -1366098688,
+	 -1148196480,  -912272064,  -662277888,  -401651232,  -134720192,   134848688,   401777696,   662151040,
+	   912388672,  1148305280,  1366198016,  1562646784,  1734722560,  1879390080,  1994558592,  2078420352,
+	  2129658880,  2147469952,  2131542400,  2082156928,  2000089600,  1886628736,  1743555072,  1573292928,
+	  1378183040,  1161000192,   925996096,   676706304,   416558304,   149872000,  -119166712,  -386334976,
+	  -647689216,  -898619392, -1135444736, -1354448000, -1552191744, -1725572096, -1871996032, -1988881024,
+	 -2074548224, -2127653248, -2147362176, -2133395968, -2085946496, -2005568384, -1893835264, -1752527872,
+	 -1583589120, -1389794048, -1174184704,  -940145152,  -691100800,  -431444576,  -165016320,   104002040,
+	   371387968,   632944576,   884805248,  1122527488,  1342630272,  1541659136,  1716489856,  1864378368,
+	  1982902656,  2070572544,  2125541120,  2147146880,  2135079168,  2089503488,  2011130624,  1901191040,
+	  1761260032,  1593806080,  1401335552,  1186869504,   953774272,   705708416,   446309248,   180152400,
+	   -88832168,  -356422400,  -618418176,  -870707264, -1109105152, -1330745472, -1531049344, -1707321728,
+	 -1856664064, -1977023104, -2066350464, -2123244288, -2146817792, -2136655488, -2092955904, -2016405120,
+	 -1908204544, -1770052608, -1603943424, -1412807040, -1199495040,  -967355648,  -720032512,  -461407648,
+	  -195801568,    73657848,   341439008,   603860928,   856564160,  1096074240,  1318380288,  1519992704,
+	  1697907840,  1848987136,  1971044608,  2062164352,  2120916096,  2146377728,  2138125056,  2096303744,
+	  2021578624,  1915122688,  1778606592,  1614173440,  1424403968,  1212060544,   980888640,   734320576,
+	   476226560,   210918512,   -57955752,  -325920352,  -589273408,  -842617920, -1082988672, -1306360576,
+	 -1509227904, -1688406016, -1841217792, -1964967552, -2057875072, -2118481920, -2145836800, -2139510272,
+	 -2099601920, -2026651136, -1921944960, -1787071744, -1624148480, -1435927296, -1224996096,  -994372608,
+	  -748571968,  -491276864,  -226024912,    42774740,   310903008,   574151296,   828629504,  1069848832,
+	  1294275712,  1498387456,  1678980352,  1833219840,  1958684800,  2053482880,  2115941760,  2145188480,
+	  2140764160,  2102791424,  2031792128,  1928671232,  1795447552,  1634212352,  1447182592,  1237437696,
+	  1008269824,   763276032,   506046976,   241120016,   -27591590,  -295870112,  -559504640,  -814357120,
+	 -1056427328, -1282126080, -1487472128, -1669470720, -1825265024, -1952409856, -2048830976, -2113295744,
+	 -2144433024, -2141910912, -2105820672, -2036658304, -1935528192, -1804018048, -1644023040, -1458365568,
+	 -1249817344, -1021651904,  -777450496,  -521046112,  -256463328,    12407060,   280822432,   544830016,
+	   800285824,  1043180352,  1269489536,  1476482432,  1659877632,  1817219072,  1945926528,  2044230144,
+	  2110447232,  2143538560,  2142967680,  2108744704,  2041422464,  1942057984,  1812210688,  1653918720,
+	  1469666688,  1262134528,  1034982912,   791586112,   535764416,   271533344,     3302378,  -265760720,
+	  -530128128,  -786174592, -1029881152, -1257210112, -1465035648, -1649865984, -1809082240, -1939454976,
+	 -2039527040, -2107586432, -2142564992, -2143913472, -2111610880, -2046084608, -1948490496, -1820312704,
+	 -1663563136, -1480701952, -1274599680, -1048262208,  -805682112,  -550455936,  -286589760,   -18487372,
+	   250164992,   514890752,   772024000,  1016299520,  1244867712,  1453896448,  1640104448,  1800569344,
+	  1932772224,  2034721920,  2104620288,  2141484160,  2144735104,  2114322048,  2050722304,  1954825728,
+	  1828323840,  1673124352,  1491663232,  1286998784,  1061944832,   820222400,   565119936,   301891392,
+	    33671440,  -235077040,  -500135712,  -757344192, -1002897152, -1232463232, -1442684416, -1630260992,
+	 -1792248832, -1926105344, -2029729536, -2101548928, -2140296448, -2145449600, -2116927488, -2055177856,
+	 -1961276800, -1836515200, -1682601856, -1502549888, -1299122560, -1075116800,  -834235776,  -580260480,
+	  -316918144,   -48853824,   219977312,   485355680,   743115840,   989211968,  1219781376,  1431400320,
+	  1620336000,  1783838848,  1919342336,  2024719616,  2098260864,  2138955008,  2146056704,  2119427072,
+	  2059604864,  1967412736,  1844339840,  1692318080,  1513547392,  1311181440,  1088234880,   848207360,
+	   594866240,   332188064,    64295796,  -204866608,  -470551360,  -728850304,  -975709120, -1207253120,
+	 -1419651200, -1609983104, -1775339520, -1912483200, -2019519232, -2094975872, -2137549440, -2146571904,
+	 -2121861120, -2063852928, -1973450368, -1852072320, -1701623936, -1524466560, -1323381120, -1101298688,
+	  -862136576,  -609442240,  -347182080,   -79472472,   189484528,   455211168,   714548352,   962157504,
+	  1194664576,  1408222080,  1599893888,  1766453504,  1905528448,  2014305152,  2091586048,  2136037120,
+	  2146960640,  2124185344,  2068068480,  1979389312,  1859712128,  1710844672,  1535123584,  1335307136,
+	  1114531456,   876501312,   623987776,   362158720,    94645176,  -174092640,  -440359776,  -699715008,
+	  -948557760, -1181797376, -1396722560, -1589724544, -1757774080, -1898233344, -2008897792, -2088091648,
+	 -2134417920, -2147242112, -2126362880, -2072108032, -1985329152, -1867258880, -1719979904, -1545703936,
+	 -1347166336, -1127706624,  -890342080,  -639002688,  -377117280,  -110074952,   158953184,   425486368,
+	   685341056,   934438592,  1168868992,  1385153152,  1579475712,  1749006720,  1891085184,  2003480960,
+	  2084429824,  2132692096,  2147416192,  2128434304,  2076044160,  1991068160,  1874968064,  1729340032,
+	  1556206848,  1358958208,  1140601216,   904138304,   653483968,   392572416,   125499032,  -143805760,
+	  -410591680,  -670932864,  -920743040, -1156101120, -1373312896, -1569148032, -1740152064, -1883842432,
+	 -1997963904, -2080725120, -2130794368, -2147483264, -2130399104, -2079876352, -1996804096, -1882324480,
+	 -1738299776, -1566990592, -1371085696, -1153438848,  -917889344,  -667932544,  -407491808,  -140655040,
+	   128389488,   395676448,   656491136,   907001536,  1143275520,  1361604480,  1558561536,  1730899968,
+	  1876505472,  1992346880,  2076916224,  2128851584,  2147438848,  2132319744,  2083731456,  2002342016,
+	  1889586944,  1747172736,  1577334656,  1382738688,  1166438912,   931594496,   682347776,   422390848,
+	   155804000,  -113228344,  -380483456,  -641516288,  -893214592, -1130392576, -1349827840, -1547894272,
+	 -1721868672, -1868816512, -1986431232, -2072935168, -2126802432, -2147287168, -2134067840, -2087351936,
+	 -2007872640, -1896754816, -1755958144, -1587599872, -1394322432, -1179159552,  -945488448,  -697224768,
+	  -437268736,  -170945184,    98061544,   365270752,   627008512,   878904704,  1117005440,  1337778688,
+	  1537330048,  1712751360,  1861289088,  1980611840,  2068846720,  2124646912,  2147027968,  2135709184,
+	  2090867968,  2013208320,  1903949056,  1764805248,  1597785728,  1405836544,  1191821312,   959098944,
+	   711569856,   452637312,   186077824,   -82889840,  -350297824,  -612469440,  -865027648, -1104008320,
+	 -1325660288, -1526689024, -1703548288, -1853668608, -1974693504, -2064723328, -2122345088, -2146654336,
+	 -2137243776, -2094279552, -2018443264, -1910925312, -1773561088, -1608239232, -1417280384, -1204423296,
+	  -972895168,  -725879296,  -467469920,  -201723120,    67189960,   335307360,   597899712,   851107392,
+	  1090955904,  1313680768,  1515785856,  1694099072,  1845955328,  1968676352,  2060496768,  2119975424,
+	  2146169472,  2138718848,  2097586432,  2023577344,  1917923840,  1782078848,  1618262016,  1429044864,
+	  1217397120,   986408192,   740152448,   482279200,   216836032,   -52010620,  -320040896,  -583047808,
+	  -837144512, -1077849088, -1301635456, -1504991360, -1684724608, -1837878784, -1962560768, -2056167168,
+	 -2117499648, -2145584640, -2140035968, -2100897024, -2028782080, -1924706944, -1790507392, -1628203904,
+	 -1440343808, -1229875968, -1000103936,  -754634048,  -497064352,  -231938096,    36828680,   305017376,
+	   568418496,   822655552,  1064688192,  1289525120,  1494121472,  1675101952,  1829978112,  1956130816,
+	  2051579904,  2114872576,  2144892544,  2141246080,  2103990272,  2033710080,  1931508480,  1798989696,
+	  1638064384,  1451570944,  1242293376,  1013516800,   768831808,   512333792,   247028560,   -21644898,
+	  -289978624,  -553507520,  -808608256, -1051016960, -1276928768, -1483177088, -1665558144, -1821986048,
+	 -1949815936, -2047041280, -2112136064, -2144078464, -2142349056, -2106978304, -2038536192, -1938097024,
+	 -1807237248, -1648011008, -1462725376, -1254648704, -1026878976,  -782991168,  -527067616,  -262627040,
+	     5935748,   274925344,   538821632,   794520576,  1037748544,  1264688000,  1471776640,  1655931008,
+	  1813902848,  1943403520,  2042400384,  2109339392,  2143170048,  2143361152,  2109861120,  2043260544,
+	  1944588800,  1815394560,  1657872384,  1474187904,  1267364608,  1040189760,   797354752,   541775040,
+	   277691584,     9249436,  -259337888,  -524108864,  -780393088, -1024428224, -1252383872, -1460681856,
+	 -1646052736, -1805587200, -1936894080, -2037657344, -2106437376, -2142154496, -2144248064, -2112732288,
+	 -2048040448, -1950983296, -1823460992, -1667482752, -1485193088, -1279591552, -1053905472,  -811434432,
+	  -556455424,  -292742240,   -24434158,   244257456,   509115168,   765981760,  1011056704,  1240017024,
+	  1449513984,  1636259200,  1797321472,  1930057856,  2032643328,  2103429888,  2141031808,  2145040256,
+	  2115400192,  2052556672,  1957495808,  1831573248,  1677009792,  1496123904,  1291754624,  1067109760,
+	   825715456,   571360640,   307778272,    39617656,  -229164800,  -494350272,  -751776320,  -997170304,
+	 -1227158144, -1438273664, -1626383744, -1788820864, -1923351552, -2027693312, -2100208000, -2139779840,
+	 -2145710720, -2117962368, -2056970240, -1963691520, -1839590528, -1686615296, -1506979840, -1303853056,
+	 -1080260736,  -839712576,  -585984128,  -323058080,   -55323292,   214060688,   479560608,   737533312,
+	   983696640,  1214665856,  1426569600,  1616426880,  1780374272,  1916549248,  2022641792,  2096986752,
+	  2138417280,  2146282880,  2120418688,  2061280896,  1969789056,  1847379200,  1695972608,  1517945984,
+	  1315886208,  1093357696,   853667648,   600578304,   338321152,    70501960,  -198423824,  -464747008,
+	  -723006592,  -970173760, -1202112896, -1415183616, -1606041344, -1771690496, -1909650944, -2017489152,
+	 -2093660672, -2136969856, -2146736896, -2122808576, -2065488512, -1975788032, -1855075328, -1705245184,
+	 -1528833536, -1328265728, -1106849280,  -867580032,  -615393664,  -353308160,   -85677112,   183298736,
+	   449397504,   708442496,   956602368,  1189499904,  1403726848,  1595920640,  1763064832,  1902535680,
+	  2012235776,  2090230016,  2135415680,  2147083648,  2125050752,  2069732736,  1981890176,  1862678784,
+	  1714432512,  1539459200,  1340164480,  1119834368,   881927104,   630177088,   368277504,   100847968,
+	  -168164464,  -434537376,  -694089728,  -942747648, -1176827264, -1392199808, -1585720192, -1754350976,
+	 -1895445120, -2006695040, -2086570752, -2133754752, -2147323008, -2127222784, -2073730048, -1987687936,
+	 -1870446720, -1723846912, -1550007936, -1351996160, -1132763392,  -895750528,  -644677888,  -383486336,
+	  -116013792,   153021808,   419655552,   679702336,   929080448,  1163655296,  1380201600,  1575440384,
+	  1745549312,  1888259968,  2001237120,  2082927616,  2131924096,  2147457664,  2129250560,  2077623680,
+	  1993386240,  1877860352,  1732859392,  1560659200,  1363760384,  1145635712,   909529152,   659146496,
+	   398417792,   131435464,  -137348272,  -404752736,  -665280960,  -915366848, -1150863744, -1368533632,
+	 -1564722816, -1736352000, -1880853760, -1995679104, -2079180160, -2130046208, -2147478656, -2131204096,
+	 -2081413376, -1998984832, -1885179904, -1741785088, -1571051008, -1375657600, -1158892288,  -923262336,
+	  -673582080,  -413329280,  -146850336,   122190832,   389314080,   650826240,   901369472,  1138014592,
+	  1356797184,  1554283392,  1727373312,  1873350272,  1990021376,  2075328896,  2128061696,  2147392256,
+	  2133017088,  2085161856,  2004577536,  1892405376,  1750623872,  1581364352,  1387283584,  1171647488,
+	   937420992,   687984064,   428220128,   161996208,  -107027288,  -374370880,  -635838272,  -887325504,
+	 -1125108480, -1344992896, -1543766144, -1718308224, -1865879552, -1984163968, -2071304576, -2125970944,
+	 -2147198464, -2134723328, -2088741376, -2010066560, -1899780608, -1759374976, -1591598592, -1399039104,
+	 -1184344064,  -951059520,  -702847040,  -443602528,  -177133984,    91858392,   359408960,   621318208,
+	   873475136,  1111921920,  1332915840,  1533171712,  1709157248,  1858315648,  1978305792,  2067173120,
+	  2123696000,  2146897280,  2136322944,  2092275584,  2015361024,  1906813184,  1768335744,  1602102400,
+	  1410524544,  1196981504,   964650496,   717178240,   458449024,   192524000,   -76422920,  -344429056,
+	  -606766976,  -859581056, -1098903040, -1320976512, -1522130944, -1699920768, -1850658816, -1972348672,
+	 -2063007616, -2121388928, -2146472704, -2137865344, -2095644416, -2020554752, -1913750400, -1776907520,
+	 -1612174080, -1422135808, -1209775616,  -978193216,  -731473536,  -473272608,  -207643136,    61245536,
+	   328913824,   592185472,   845644032,  1085829248,  1308763392,  1511381120,  1690276096,  1842640256,
+	  1966187520,  2058738816,  2118975872,  2145953152,  2139247488,  2098963712,  2025734528,  1920591872,
+	  1785390336,  1622165248,  1433478528,  1222291584,   992152000,   745732288,   488072512,   222751888,
+	   -45803004,  -313899680,  -577069376,  -831181312, -1072474048, -1296691456, -1500555776, -1680867200,
+	 -1834795520, -1959924736, -2054367104, -2116456832, -2145326336, -2140522624, -2102120960, -2030724096,
+	 -1927452928, -1793784064, -1632075264, -1444749440, -1234746496, -1005594496,  -760444096,  -503357696,
+	  -237849488,    30620218,   298869856,   562428352,   817158976,  1059063360,  1284554624,  1489655296,
+	  1671374208,  1826859008,  1953669248,  2049814528,  2113785728,  2144592256,  2141690752,  2105172992,
+	  2035612288,  1934214144,  1802372992,  1642241664,  1455948032,  1247353088,  1018986752,   774626240,
+	   518107200,   253455840,   -15435903,  -283825056,  -547759232,  -803095808, -1045826816, -1272142464,
+	 -1478490368, -1661797760, -1818831232, -1947316224, -2045235968, -2111053312, -2143719936, -2142786560,
+	 -2108119936, -2040398592, -1940763264, -1810583680, -1651985152, -1467456768, -1259682688, -1032328000,
+	  -788769664,  -532830752,  -268528480,      -11328,   268505984,   533062752,   788992448,  1032537984,
+	  1259876736,  1467440256,  1651803136,  1810430592,  1940865792,  2040555008,  2108165504,  2142767744,
+	  2143736832,  2111057536,  2045162880,  1947215232,  1818703872,  1661646080,  1478506752,  1272160640,
+	  1045846592,   802873664,   547527680,   283587680,    15196422,  -253433344,  -517830784,  -774360640,
+	 -1019197504, -1247548032, -1456124160, -1642058112, -1802218240, -1934090496, -2035688576, -2105220352,
+	 -2141708288, -2144579840, -2113789696, -2049821312, -1953678720, -1826733184, -1671223808, -1489482880,
+	 -1284362752, -1059083072,  -817179904,  -562703232,  -298632672,   -30380756,   238348032,   503080832,
+	   760177792,  1005342848,  1235156864,  1444926592,  1632230912,  1793915648,  1927442944,  2030716800,
+	  2102116352,  2140541824,  2145315584,  2116416256,  2054297344,  1959933952,  1834807296,  1680718080,
+	  1500384384,  1296500480,  1072266560,   831443904,   577343680,   314181440,    45563572,  -222990080,
+	  -488305728,  -745956864,  -991899392, -1222273024, -1433461632, -1622322176, -1785523456, -1920699008,
+	 -2025726976, -2098958976, -2139245440, -2145944192, -2118936960, -2058670720, -1966091264, -1842786432,
+	 -1690451712, -1511583360, -1308573440, -1085622656,  -845423872,  -591955264,  -329195264,   -61268184,
+	   207620576,   473506208,   731698688,   978406400,  1209756928,  1422118912,  1612159104,  1777041920,
+	  1913858944,  2020635904,  2095696640,  2137863168,  2146481408,  2121433216,  2062941056,  1972150144,
+	  1850537344,  1699774464,  1522331776,  1320994432,  1098922496,   859361600,   606537280,   344192672,
+	    76445560,  -192501424,  -458426912,  -717403968,  -964864448, -1197180288, -1410705024, -1602087296,
+	 -1768174080, -1906682112, -2015443712, -2092329600, -2136347264, -2146891648, -2123738240, -2067250304,
+	 -1978314624, -1858195584, -1709012224, -1533004032, -1332933632, -1111941376,  -873495872,  -621088960,
+	  -359172832,   -91619120,   177372656,   443580384,   702577920,   950804160,  1184543872,  1399220736,
+	  1591759360,  1759512320,  1899647872,  2009966336,  2088736128,  2134749440,  2147194496,  2125937024,
+	  2071310592,  1984172544,  1865890816,  1718164608,  1543599616,  1344806144,  1124904448,   887346176,
+	   635859968,   374651328,   106788096,  -162235008,  -428711680,  -688210880,  -937164800, -1171408768,
+	 -1387266304, -1581526400, -1750762496, -1892518528, -2004569472, -2085156352, -2133014400, -2147390080,
+	 -2128029568, -2075267328, -1989931392, -1873361280, -1727386752, -1554479872, -1356611584, -1137811456,
+	  -901152128,  -650598016,  -389594176,  -122475176,   147089264,   413564288,   673809472,   923478528,
+	  1158652544,  1375640192,  1571035648,  1741925248,  1885294592,  1999072384,  2081472384,  2131201408,
+	  2147478656,  2130049024,  2079120256,  1995590656,  1880738176,  1736365312,  1564917888,  1368753024,
+	  1150661504,   914913024,   665053248,   404517536,   137632496,  -131412848,  -398395520,  -659374400,
+	  -909746112, -1145838336, -1363945344, -1560643712, -1732845952, -1877849344, -1993475200, -2077684224,
+	 -2129281664, -2147457664, -2131958400, -2082996864, -2001150208, -1888020992, -1745409792, -1575277696,
+	 -1380419712, -1163894656,  -929100864,  -679475136,  -419420672,  -152782928,   116252928,   383464064,
+	   644656320,   895729920,  1132966784,  1352182272,  1550173696,  1723833344,  1870306816,  1987580032,
+	  2073792256,  2127255552,  2147325952,  2133727744,  2086638080,  2006796416,  1895455872,  1754212736,
+	  1585558656,  1392017408,  1176626944,   942768000,   694111232,   434559584,   167925712,  -101087192,
+	  -368513440,  -630155456,  -881906496, -1119591296, -1340351616, -1539626112, -1714734592, -1862797952,
+	 -1981780480, -2069656832, -2125047552, -2147088256, -2135390336, -2090174976, -2012152064, -1902546176,
+	 -1763077760, -1595935872, -1403545600, -1189300480,  -956387968,  -708463872,  -449419648,  -183582496,
+	    85916400,   353544384,   615623104,   867799104,  1106605184,  1328041856,  1528633472,  1705390848,
+	  1855196032,  1975881856,  2065554048,  2122805120,  2146736256,  2136946176,  2093607424,  2017407104,
+	  1909541376,  1771703296,  1606056448,  1415397760,  1201914496,   969960064,   722781120,   464513184,
+	   198707408,   -70217312,  -338039904,  -600808256,  -853887360, -1093563776, -1316075520, -1517929984,
+	 -1695958784, -1847501312, -1969884416, -2061348096, -2120456576, -2146282112, -2138419328, -2096991744,
+	 -2022561280, -1916441088, -1780240256, -1616096640, -1426782464, -1214900736,  -983949760,  -737062208,
+	  -479327168,  -213822384,    55038584,   323035680,   585962368,   839932992,  1080467712,  1304043264,
+	  1507150464,  1686601216,  1839578880,  1963682304,  2057038976,  2118001920,  2145720448,  2139759616,
+	  2100267392,  2027787008,  1923478272,  1788688256,  1626227328,  1438095872,  1227391872,   997422528,
+	   751797568,   494117184,   228926672,   -39857104,  -308015296,  -571338816,  -825694592, -1067090112,
+	 -1291945856, -1496295680, -1677159424, -1831698176, -1957378688, -2052472832, -2115351168, -2145051648,
+	 -2140992896, -2103381632, -2032735232, -1930182656, -1797333888, -1636104064, -1449337344, -1239821568,
+	 -1010845440,  -766002944,  -509137184,  -244279968,    24673628,   292979488,   556686720,   811656128,
+	  1053885696,  1279362816,  1484987264,  1667633664,  1823725952,  1951083264,  2047954688,  2112681216,
+	  2144246784,  2142137600,  2106390784,  2037581696,  1936790528,  1805599360,  1646067328,  1460698496,
+	  1252189312,  1024217728,   780169984,   523876608,   259360384,    -8964637,  -277409184,  -542006784,
+	  -797577152, -1040399296, -1267134592, -1473980800, -1657691264, -1815522432, -1944690432, -2043334272,
+	 -2109905664, -2143359744, -2143171456, -2109343744, -2042326400, -1943301632, -1813774720, -1655778560,
+	 -1471793152, -1264706304, -1037538880,  -794298048,  -538589824,  -274427840,    -6220549,   262344368,
+	   526791520,   783458240,  1027089280,  1254843008,  1462900736,  1647996416,  1807225088,  1938087296,
+	  2038611584,  2107024640,  2142365568,  2144079744,  2112140160,  2047048192,  1949715584,  1821859328,
+	  1665406976,  1482814208,  1277157760,  1051265344,   808872064,   553276096,   289741312,    21405424,
+	  -247266464,  -512057216,  -768810688, -1013496768, -1242488832, -1451747328, -1638219136, -1798977280,
+	 -1931498496, -2033702784, -2104038272, -2141264256, -2144880768, -2114830976, -2051664000, -1956248320,
+	 -1830127232, -1674952064, -1493949440, -1289333632, -1064480192,  -822918592,  -568440384,  -305039808,
+	   -36589228,   232176176,   497297312,   754612864,  1000083840,  1229857408,  1440521472,  1628360064,
+	  1790639616,  1924813184,  2028774656,  2100838016,  2140012288,  2145574528,  2117416064,  2056098048,
+	  1962463488,  1838025984,  1684738688,  1505007488,  1301444992,  1077641856,   836923968,   583069568,
+	   320063296,    52033268,  -217074288,  -482512576,  -740377280,  -986620928, -1217378432, -1428832256,
+	 -1618074752, -1782212352, -1918031616, -2023657472, -2097637760, -2138693120, -2146179456, -2119979008,
+	 -2060429312, -1968580608, -1845832960, -1694112896, -1515801856, -1313698688, -1090749696,  -850887488,
+	  -597669696,  -335070784,   -67212608,   201439584,   467191968,   726104704,   973108672,  1204621568,
+	  1417460352,  1608050432,  1773400448,  1910914944,  2018525056,  2094332544,  2137267072,  2146654848,
+	  2122348544,  2064729600,  1974599296,  1853547648,  1703402496,  1526520576,  1325678208,  1104027776,
+	   865288320,   612239872,   350061536,    82388576,  -186316400,  -452358880,  -711301120,  -959547712,
+	 -1192020480, -1406017664, -1597945728, -1764792320, -1903938560, -2013200384, -2090922624, -2135734272,
+	 -2147023104, -2124611968, -2068852736, -1980620544, -1861431168, -1712606848, -1537162880, -1337591296,
+	 -1117248768,  -879164544,  -627280896,  -365034752,   -97822304,   171183904,   437503200,   696955392,
+	   945468096,  1179140608,  1394504576,  1587761152,  1756096000,  1896867072,  2007864704,  2087346560,
+	  2134036096,  2147283840,  2126769280,  2072872576,  1986439808,  1868956800,  1722038912,  1547728256,
+	  1349437696,  1130188928,   892996800,   641788096,   380505760,   113250968,  -156042864,  -422625632,
+	  -682574848,  -931810304, -1166419968, -1382721280, -1577319296, -1747311872, -1889700608, -2002428544,
+	 -2083725952, -2132285952, -2147440640, -2128820096, -2076788608, -1992257536, -1876388992, -1731068544,
+	 -1558577024, -1361621888, -1143072768,  -906784448,  -656263104,  -395441056,  -128412104,   140632416,
+	   407469568,   668160192,   918105856,  1153640832,  1371068160,  1566795776,  1738132608,  1882439808,
+	  1996892160,  2079936000,  2130429312,  2147483008,  2130829824,  2080730624,  1997876096,  1883727488,
+	  1740011648,  1568984448,  1373330304,  1156120192,   920763520,   670705408,   410356608,   143566816,
+	  -125476408,  -392550144,  -653212672,  -904355584, -1140804096, -1359346560, -1556371840, -1729171200,
+	 -1874829184, -1991059712, -2076105344, -2128466048, -2147418112, -2132663936, -2084435328, -2003489152,
+	 -1890971648, -1748867840, -1579313536, -1384970112, -1168888064,  -934459008,  -685610944,  -425251616,
+	  -158714352,   110314120,   377353024,   638730752,   890082880,  1127464192,  1347352832,  1545870080,
+	  1720123264,  1867377152,  1985320576,  2072102144,  2126396416,  2147245696,  2134391552,  2088035712,
+	  2008905856,  1898243968,  1757937664,  1589563520,  1396540544,  1181597440,   948342912,   699984256,
+	   440638528,   174376512,   -94884432,  -362394784,  -624216960,  -876241280, -1114512128, -1335289344,
+	 -1535291136, -1710989440, -1859831808, -1979482112, -2068062336, -2124182016, -2146960128, -2136012416,
+	 -2091531776, -2014222208, -1905297152, -1766615424, -1600083840, -1408437120, -1194247680,  -961943424,
+	  -714322496,  -455489504,  -189507088,    79449832,   347418400,   609671872,   862355904,  1101504256,
+	  1323363328,  1524450688,  1701610112,  1852193536,  1973544832,  2063919104,  2121897984,  2146563584,
+	  2137576832,  2095038336,  2019437696,  1912374272,  1775204864,  1610171520,  1419864960,  1207271808,
+	   975495808,   728625024,   470317696,   204628208,   -64273148,  -332165664,  -594844480,  -848427392,
+	 -1088441344, -1311371008, -1513717248, -1692142720, -1844193920, -1967298560, -2059672704, -2119465728,
+	 -2146065408, -2138980224, -2098321408, -2024727168, -1919234816, -1783705472, -1620178816, -1431221760,
+	 -1219799936,  -989232064,  -743137088,  -485122400,  -219739088,    49093248,   317155008,   580238720,
+	   833973312,  1074870144,  1299313280,  1502908288,  1682750592,  1836367616,  1961160704,  2055171328,
+	  2116967808,  2145459968,  2140276864,  2101499648,  2029736960,  1926115456,  1792261376,  1630105088,
+	  1442507008,  1232267136,  1002685376,   757365440,   500412672,   234838992,   -33910896,  -302128512,
+	  -565350976,  -819959232, -1061697280, -1286770816, -1491835520, -1673274368, -1828449408, -1954924800,
+	 -2050715648, -2114318080, -2144733952, -2141466368, -2104572672, -2034645376, -1932782080, -1800581632,
+	 -1640119168, -1453720064, -1244672640, -1016088576,  -771555904,  -515167232,  -250447856,    18202580,
+	   287086912,   550687424,   805904128,  1048471232,  1274581376,  1480685568,  1663548800,  1820439808,
+	  1948591232,  2046157312,  2111606784,  2143912192,  2142566528,  2107540480,  2039452032,  1939352064,
+	  1808811904,  1650048256,  1465243904,  1257440896,  1029440960,   785951680,   529896032,   265523072,
+	    -3017577,  -271510848,  -535742496,  -791808704, -1035192768, -1262328320, -1469650176, -1653904256,
+	 -1812198528, -1942160128, -2041496832, -2108789888, -2142983168, -2143555840, -2110499968, -2044317312,
+	 -1945825152, -1817091456, -1659725696, -1476308608, -1269719168, -1043200128,  -800306880,  -544598336,
+	  -280325120,   -12167577,   256180560,   521024128,   777185024,  1022093120,  1250012160,  1458348928,
+	  1644177152,  1803863424,  1935518464,  2036567936,  2105816192,  2141947008,  2144420224,  2113206528,
+	  2048916224,  1952310144,  1825277056,  1669320064,  1487110272,  1281933952,  1056675264,   814378048,
+	   559779584,   295373248,    27352122,  -241097504,  -506279712,  -763009792, -1008249792, -1237204864,
+	 -1447165824, -1634367744, -1795578880, -1928891776, -2031699968, -2102786816, -2140762368, -2145177472,
+	 -2115856000, -2053412736, -1958801536, -1833231616, -1679157888, -1498028032, -1294084608, -1069868480,
+	  -828408576,  -574425728,  -310925408,   -43059484,   226002384,   491509984,   748796416,   994817216,
+	  1224762240,  1435910400,  1624476544,  1787204480,  1922168704,  2026730368,  2099652224,  2139508352,
+	  2145847936,  2118399616,  2057806592,  1964976640,  1841094528,  1688582016,  1509243904,  1306586624,
+	  1083008256,   842397632,   589043136,   325942720,    58240448,  -210895968,  -476715712,  -734545664,
+	  -981334848, -1212258304, -1424583168, -1614158464, -1778446976, -1915349504, -2021659392, -2096298880,
+	 -2138147456, -2146386816, -2120919680, -2062243840, -1971053568, -1848865280, -1697921664, -1520008832,
+	 -1318605056, -1096093696,  -856344576,  -603631104,  -340943744,   -73418504,   195779008,   461385536,
+	   719764160,   967803456,  1199693696,  1412790016,  1604102656,  1769891328,  1908194176,  2016307072,
+	  2092950912,  2136679552,  2146818432,  2123247616,  2066428032,  1977031936,  1856543744,  1707176448,
+	  1530697600,  1330557440,  1109124480,   870727936,   618690944,   355927712,    88592880,  -180652256,
+	  -446543520,  -705439424,  -953753984, -1186632192, -1401318400, -1593966592, -1761247104, -1901180416,
+	 -2011030784, -2089498240, -2135104768, -2147142656, -2125469568, -2070508928, -1982911360, -1864389504,
+	 -1716660992, -1541309824, -1342443392, -1122099840,  -884587072,  -633216704,  -371410304,  -104286504,
+	   164993728,   431679168,   691079360,   940124800,  1173946240,  1389776768,  1583750784,  1752514816,
+	  1894071680,  2005653888,  2085941120,  2133363328,  2147365120,  2127585152,  2074486400,  1988691712,
+	  1871878656,  1725741568,  1552207360,  1354669056,  1135464064,   898401920,   647710848,   386357280,
+	   119451080,  -149849392,  -416793248,  -676684800,  -926448640, -1161201536, -1378165760, -1573099008,
+	 -1743694848, -1886868224, -2000176768, -2082279680, -2131571456, -2147470976, -2129661824, -2078360064,
+	 -1994372608, -1879274240, -1734735872, -1562662272, -1366417792, -1148324480,  -912171840,  -662172544,
+	  -401284928,  -134609680,   134697568,   401371456,   662256320,   912726208,  1148398848,  1366485760,
+	  1562722688,  1734478720,  1879316864,  1994599680,  2078514048,  2129673088,  2147469440,  2131560832,
+	  2082258048,  2000144768,  1886575872,  1743643520,  1573039104,  1378098176,  1161127552,   926369216,
+	   676601216,   416192512,   149761536,  -119539016,  -386443904,  -647294912,  -898481920, -1135538816,
+	 -1354737408, -1552268288, -1725481856, -1871921920, -1988724992, -2074509184, -2127668224, -2147360512,
+	 -2133353216, -2085920128, -2005622400, -1894030208, -1752463872, -1583337216, -1389709696, -1173872512,
+	  -940045632,  -690995968,  -431592896,  -164905920,   104374472,   371497024,   632799872,   884667328,
+	  1122174848,  1342512128,  1541736192,  1716398848,  1864433280,  1982945280,  2070532352,  2125482112,
+	  2147144192,  2135039104,  2089477888,  2010999936,  1901139456,  1761196672,  1593907584,  1401251712,
+	  1186558720,   953675072,   705851392,   446457376,   180564496,   -88680880,  -356531584,  -618273216,
+	  -870808448, -1109199872, -1330626560, -1530759296, -1707229952, -1856851456, -1977066368, -2066451968,
+	 -2123260928, -2146820608, -2136670720, -2092931200, -2016276736, -1908153728, -1769841408, -1604044160,
+	 -1413118464, -1199620608,  -967256768,  -720175168,  -461299520,  -195691296,    73506520,   341030720,
+	   603715584,   856906112,  1096169472,  1318674560,  1520071040,  1697975552,  1848910080,  1971088512,
+	  2062268416,  2120933504,  2146389632,  2138139136,  2096393472,  2021629696,  1915072512,  1778691456,
+	  1614100352,  1424517376,  1212185600,   981256512,   734462912,   476118592,   210808320,   -58328488,
+	  -326029792,  -589127808,  -842478656, -1083084288, -1306656512, -1509306624, -1688636416, -1841139840,
+	 -1964800640, -2057831808, -2118499968, -2145830784, -2139500800, -2099633664, -2026701184, -1922129408,
+	 -1787155712, -1624076032, -1435844992, -1224689920,  -994739136,  -748713920,  -491424256,  -225914800,
+	    43147540,   311012576,   574510592,   828489792,  1069490240,  1294154880,  1498466816,  1679212800,
+	  1833277440,  1958622720,  2053438592,  2115871104,  2145181440,  2140755456,  2102768896,  2031671424,
+	  1928853120,  1795530624,  1634310528,  1447100800,  1237132928,  1008172032,   762927488,   506194144,
+	   241530960,   -27440188,  -295979776,  -559864640,  -814459584, -1056295488, -1282004608, -1487173888,
+	 -1669375488, -1825323392, -1952346880, -2048942592, -2113222272, -2144424960, -2141940736, -2105798912,
+	 -2036540032, -1935480320, -1803815680, -1644120448, -1458669056, -1249940480, -1021554496,  -777102912,
+	  -520938688,  -256613664,    12255647,   280412416,   544683520,   800388608,  1043048000,  1269790208,
+	  1476182144,  1659781504,  1816998656,  1945973248,  2044344320,  2110467712,  2143561088,  2142977536,
+	  2108723712,  2041469440,  1942010624,  1812010624,  1653848064,  1469777152,  1262257024,  1035345280,
+	   791239488,   535657184,   271683520,     2929505,  -265350336,  -529981376,  -785789696, -1029748288,
+	 -1257512320, -1465116672, -1650104576, -1808859392, -1939502464, -2039643776, -2107607680, -2142590208,
+	 -2143907072, -2111638528, -2046130688, -1948664320, -1820114816, -1663493120, -1480811648, -1274510464,
+	 -1048623104,  -805822464,  -550855680,  -286739840,   -18114512,   250274976,   515252736,   771638080,
+	  1016397056,  1245171584,  1453977856,  1640345216,  1800629632,  1932934656,  2034673536,  2104537984,
+	  2141512064,  2144729600,  2114348544,  2050689408,  1954996864,  1828403200,  1673383552,  1491772160,
+	  1286700288,  1061848576,   819877824,   565518912,   301781792,    33298612,  -235187104,  -500498336,
+	  -757447808, -1002995072, -1232339200, -1442377984, -1630503680, -1792309888, -1926038400, -2029765760,
+	 -2101463808, -2140284032, -2145467520, -2116952960, -2055145728, -1961231616, -1836321920, -1682858752,
+	 -1502658048, -1298825600, -1075020928,  -833892160,  -580153920,  -316808640,   -49005200,   219565936,
+	   485718912,   743219712,   989077568,  1219872512,  1431091968,  1620236672,  1783608576,  1919274368,
+	  2024756480,  2098284416,  2138988160,  2146071680,  2119451520,  2059499264,  1967368320,  1844148864,
+	  1692249856,  1513468928,  1311301376,  1088591488,   847864768,   594759872,   331819648,    64185116,
+	  -204454928,  -470403648,  -728461312,  -975574272, -1207344640, -1419734272, -1610229888, -1775106816,
+	 -1912414336, -2019645952, -2095000192, -2137585280, -2146568576, -2121844096, -2063894784, -1973613440,
+	 -1851883520, -1701556352, -1524204032, -1323293952, -1101653760,  -862275264,  -609838784,  -347331488,
+	   -79361824,   189333696,   455575552,   714158336,   962022144,  1194756608,  1408305664,  1600142592,
+	  1766516480,  1905458688,  2014252672,  2091492224,  2136075520,  2146958208,  2124130432,  2068038656,
+	  1979549696,  1859787776,  1710777728,  1535229568,  1335220352,  1114660864,   876160896,   624383488,
+	   362307968,    94534560,  -174203008,  -440724704,  -699819648,  -948421952, -1181670912, -1396806656,
+	 -1589975168, -1757837696, -1898407680, -2008936960, -2087995008, -2134401280, -2147240448, -2126310784,
+	 -2072078976, -1985386880, -1867333760, -1720227456, -1545809024, -1347080064, -1127612416,  -890002752,
+	  -638896960,  -377266336,  -110226168,   159063600,   425851840,   685446016,   934774272,  1168961920,
+	  1384837120,  1579373184,  1749070976,  1891261824,  2003520768,  2084393472,  2132674304,  2147419392,
+	  2128454400,  2076015872,  1991124864,  1874786176,  1729274368,  1556311168,  1359278336,  1140507392,
+	   903800128,   653378496,   392205824,   125388488,  -143393136,  -410443040,  -671038080,  -921079936,
+	 -1156194432, -1373196544, -1569044608, -1739909632, -1883769728, -1998004480, -2080687616, -2130840704,
+	 -2147483136, -2130418176, -2079979264, -1996763392, -1882145024, -1738234752, -1566735616, -1371000448,
+	 -1153345536,  -918026240,  -667827328,  -407125696,  -140544544,   128238344,   395527616,   656097408,
+	   906864256,  1143369216,  1361487360,  1558637696,  1730965504,  1876431872,  1992192512,  2076877696,
+	  2128900608,  2147439616,  2132275456,  2083704704,  2002301952,  1889658880,  1747108352,  1577081600,
+	  1382653952,  1166566016,   931730944,   682739904,   422539296,   155693568,  -113077144,  -380592416,
+	  -641621952,  -893076928, -1130040960, -1349710080, -1548152704, -1721934848, -1869000192, -1986473216,
+	 -2072963968, -2126781440, -2147288576, -2134026240, -2087325952, -2007740416, -1896825728, -1756196224,
+	 -1587701888, -1394238208, -1179286144,  -945389056,  -697120064,  -437416992,  -171357424,    97910288,
+	   365638208,   627114432,   879244928,  1117100032,  1337865344,  1537224320,  1712818176,  1861475072,
+	  1980654592,  2068946688,  2124624896,  2147019392,  2135725056,  2090842752,  2013261056,  1903897856,
+	  1764891520,  1597886976,  1406149248,  1191947264,   958999872,   711465344,   452272800,   186489824,
+	   -82738536,  -350148416,  -612575552,  -865368960, -1104103296, -1325953664, -1526582528, -1703296512,
+	 -1853592064, -1974737024, -2064681728, -2122361984, -2146650112, -2137258496, -2094371072, -2018494976,
+	 -1910874752, -1773498624, -1607992064, -1417591168, -1204548736,  -973030144,  -725775104,  -467105984,
+	  -201612880,    67562648,   335157792,   597502464,   850968320,  1091051264,  1313975680,  1515864320,
+	  1694005888,  1845878016,  1968511104,  2060454144,  2119993088,  2146173312,  2138685184,  2097675008,
+	  2023628032,  1917991936,  1782017024,  1618016896,  1428962176,  1217089920,   986542720,   740540672,
+	   482426752,   216725872,   -52383380,  -320150400,  -582902080,  -837005056, -1077491328, -1301515008,
+	 -1505070336, -1684630784, -1838071552, -1962392832, -2056123392, -2117430784, -2145589248, -2140004864,
+	 -2100874112, -2028659840, -1924774144, -1790735744, -1628302592, -1440261760, -1229570304, -1000005952,
+	  -754775808,  -497211648,  -232349232,    36677288,   305126976,   568272512,   822999936,  1064329024,
+	  1289404032,  1493824512,  1675171328,  1830173312,  1956176512,  2051689984,  2114846336,  2144897920,
+	  2141217664,  2103968128,  2033590272,  1931460096,  1799072384,  1638162304,  1451875584,  1241989248,
+	  1013419136,   768973184,   511971680,   247439376,   -21493490,  -289568832,  -553614528,  -808953664,
+	 -1051113536, -1277228544, -1482877952, -1665628032, -1822183424, -1949862400, -2047153920, -2112156032,
+	 -2144069888, -2142359552, -2107058304, -2038418944, -1938049408, -1807319040, -1647939968, -1463028096,
+	 -1254771584, -1027242176,  -783132160,  -526706144,  -262517136,     6308620,   274515200,   538928832,
+	   794866944,  1037845504,  1264989312,  1471857280,  1655667712,  1813821824,  1943227520,  2042515584,
+	  2109360256,  2143160448,  2143354368,  2109938048,  2043307136,  1944764160,  1815475456,  1657635328,
+	  1474107392,  1267063552,  1040551552,   797251968,   541414208,   277581792,     8876566,  -259447808,
+	  -524216224,  -780252032, -1024064768, -1252686720, -1460763008, -1645955584, -1805647104, -1936715392,
+	 -2037609472, -2106356864, -2142143744, -2144241920, -2112712448, -2047928192, -1951156096, -1823540992,
+	 -1667247744, -1485113088, -1279292032, -1053808960,  -811331904,  -556601664,  -293151936,   -24061308,
+	   244367456,   508968064,   766085184,  1010691840,  1239893504,  1449208832,  1636161152,  1797382016,
+	  1930106368,  2032763648,  2103346560,  2141020032,  2145022464,  2115381120,  2052446976,  1957450368,
+	  1831515392,  1677104384,  1496420480,  1291456640,  1067013696,   825371264,   571253952,   308187552,
+	    39769048,  -228753600,  -494202912,  -751880064,  -997268352, -1227464064, -1437966592, -1626284800,
+	 -1789027072, -1923400832, -2027816064, -2100231168, -2139789184, -2145716864, -2118030720, -2056862976,
+	 -1963646720, -1839398144, -1686546688, -1507274496, -1303973376, -1080164992,  -839851904,  -585877632,
+	  -323207744,   -54950540,   213649184,   479413024,   737637312,   983795072,  1214973312,  1426652416,
+	  1616327296,  1780289536,  1916362624,  2022767104,  2097010688,  2138451456,  2146279168,  2120484096,
+	  2061323392,  1969744896,  1847456384,  1695904768,  1518053120,  1316005888,  1093713664,   853806592,
+	   600472000,   338211808,    70129288,  -198534080,  -464599168,  -722864000,  -970272512, -1202421888,
+	 -1415266816, -1606288896, -1771753088, -1909461760, -2017437312, -2093685376, -2137006720, -2146733952,
+	 -2122831360, -2065529984, -1975950080, -1855151616, -1705177984, -1528755712, -1327972736, -1106754432,
+	  -867718528,  -615538688,  -353198944,   -85304528,   183409056,   449762112,   708547008,   956232064,
+	  1189373824,  1403810560,  1596170112,  1763128064,  1902465408,  2012182784,  2090135040,  2135399680,
+	  2147081472,  2125072640,  2069633280,  1981847552,  1862754176,  1714681600,  1539382016,  1339873024,
+	  1119739776,   881587136,   630071232,   368684928,   100999216,  -168274864,  -434902528,  -694194560,
+	  -942611584, -1176700672, -1391884928, -1585618048, -1754414720, -1895373952, -2006827776, -2086596992,
+	 -2133737600, -2147328128, -2127207552, -2073633152, -1987645952, -1870263552, -1723780864, -1549931264,
+	 -1352113792, -1132669312,  -895411648,  -644572288,  -383635328,  -116164984,   152609296,   419507040,
+	   679807360,   928943936,  1163968640,  1380286464,  1575337472,  1745308416,  1888187776,  2001372288,
+	  2082954496,  2131968896,  2147457152,  2129236224,  2077661952,  1993345024,  1877679360,  1732793984,
+	  1560763264,  1363877248,  1145985536,   909666304,   659041088,   398566560,   131324936,  -137458768,
+	  -404604032,  -664887744,  -915229824, -1151178496, -1368618880, -1564978176, -1736417152, -1880907136,
+	 -1995623296, -2079207936, -2130093568, -2147478912, -2131158272, -2081450752, -1999135872, -1885252480,
+	 -1741720320, -1571154304, -1375572480, -1158799104,  -923399040,  -673974784,  -413477856,  -146478336,
+	   122301384,   389680768,   650432128,   901470016,  1137886080,  1356882944,  1554540672,  1727439104,
+	  1873532544,  1989964544,  2075222528,  2128041472,  2147393280,  2133034624,  2085135360,  2004631808,
+	  1892476928,  1750863360,  1581466752,  1387198976,  1171554688,   937085568,   688375808,   428368480,
+	   162147184,  -107137880,  -374738048,  -635944064,  -887665088, -1124979584, -1344670464, -1543660928,
+	 -1718374656, -1865804672, -1984206336, -2071264640, -2125949568, -2147191680, -2134739840, -2088715648,
+	 -2010027648, -1899606784, -1759612160, -1591700224, -1399153920, -1184251776,  -950725184,  -702742400,
+	  -443237696,  -177284880,    91445208,   359259680,   621424192,   873815744,  1112016640,  1332797184,
+	  1533065728,  1708906880,  1858239744,  1978348800,  2067203200,  2123751296,  2146887552,  2136338304,
+	  2092309760,  2015322880,  1906641664,  1768272896,  1601854080,  1410638720,  1197324800,   964785728,
+	   717073856,   458084736,   192413712,   -76271600,  -344279584,  -606370304,  -859442304, -1098998144,
+	 -1320857216, -1522393856, -1699668096, -1850582016, -1972185088, -2063038336, -2121446912, -2146476160,
+	 -2137830016, -2095677440, -2020694784, -1913819008, -1776845312, -1611927808, -1422052864, -1209900672,
+	  -978328000,  -731862336,  -473420288,  -207532912,    61094180,   329282304,   591787968,   845504832,
+	  1085472384,  1308851200,  1511645952,  1690344320,  1842831744,  1966126720,  2058770304,  2119036416,
+	  2145957376,  2139214720,  2098940416,  2025784704,  1920659584,  1785620224,  1621920896,  1433395968,
+	  1222416128,   991821312,   746120128,   488219968,   223163200,   -45913708,  -314268544,  -577176000,
+	  -831525120, -1072342848, -1296779648, -1500822400, -1680936064, -1834989184, -1959969920, -2054246656,
+	 -2116431232, -2145307776, -2140492544, -2102098304, -2030773376, -1927404032, -1794011392, -1632173696,
+	 -1445055360, -1234870400, -1005265024,  -760340544,  -502995232,  -238260512,    30730936,   299239072,
+	   562535232,   817503808,  1059159680,  1284223232,  1489546240,  1671114496,  1827054976,  1953715328,
+	  2049769472,  2113805312,  2144570752,  2141701888,  2105254656,  2035660544,  1934052224,  1802312832,
+	  1642001408,  1456252032,  1247262848,  1018658496,   774523008,   517745312,   253345888,   -15546629,
+	  -283674976,  -547359360,  -803441600, -1045923520, -1272020480, -1478570624, -1661535744, -1818750720,
+	 -1947141888, -2045189760, -2111073664, -2143726464, -2142761856, -2108198656, -2040445824, -1940603648,
+	 -1810524160, -1651746816, -1467375872, -1259593088, -1032460736,  -789154304,  -532469536,  -268418608,
+	     -162743,   268615840,   532662144,   788851584,  1032175360,  1259754112,  1467521024,  1651873920,
+	  1810631040,  1940688768,  2040507776,  2108236544,  2142775040,  2143714688,  2111037184,  2045129088,
+	  1947279104,  1818923776,  1661409792,  1478426496,  1271860224,  1045749888,   803257216,   547674112,
+	   283997600,    15347833,  -253543296,  -517938272,  -774708416, -1018833472, -1247424768, -1456398080,
+	 -1642129408, -1802420864, -1934138624, -2035723776, -2105190400, -2141677952, -2144560384, -2113770240,
+	 -2049710080, -1953632768, -1826950528, -1671318912, -1489403008, -1284484096, -1058986752,  -817319936,
+	  -562343360,  -299042208,   -30532156,   238458080,   503188480,   760526464,  1005440704,  1235032960,
+	  1444814592,  1631962240,  1794120576,  1927491712,  2030838016,  2102139008,  2140508544,  2145322368,
+	  2116397440,  2054341504,  1959888768,  1834886016,  1680812288,  1500680192,  1296621184,  1072170624,
+	   831341824,   576984512,   314071872,    45714952,  -222839472,  -488413568,  -746306560,  -991997632,
+	 -1222579584, -1433544064, -1622051200, -1785439360, -1920748544, -2025850752, -2098982400, -2139232256,
+	 -2145949824, -2119004160, -2058713728, -1966046720, -1842729600, -1690221696, -1511504768, -1308693504,
+	 -1085753216,  -845322112,  -591596864,  -329085856,   -60895460,   207730784,   473102816,   731556352,
+	   978504960,  1210064896,  1422201856,  1612059136,  1776956928,  1913671424,  2020584576,  2095720832,
+	  2137848832,  2146470016,  2121416064,  2062983168,  1972313856,  1850481152,  1699546624,  1522253696,
+	  1320700416,  1098827392,   859740608,   606682496,   344083360,    76072928,  -192611712,  -458278976,
+	  -717261248,  -964494976, -1197054592, -1410788608, -1601986432, -1768385664, -1906733056, -2015391488,
+	 -2092236416, -2136358528, -2146882944, -2123721856, -2067149312, -1978271488, -1858140160, -1709104000,
+	 -1532926464, -1332641280, -1111846528,  -873634176,  -621233856,  -359580576,   -91770400,   177483008,
+	   443432224,   702930240,   950903424,  1184417536,  1398907008,  1591833600,  1759726080,  1899699456,
+	  2010097536,  2088761856,  2134761472,  2147197056,  2125921408,  2071212160,  1984130176,  1865965824,
+	  1718255488,  1543887104,  1344924288,  1124810240,   887484032,   635754176,   374542272,   106939328,
+	  -161822624,  -428563296,  -688564096,  -937264384, -1171721344, -1387350784, -1581601280, -1750674816,
+	 -1892570880, -2004703104, -2085182848, -2132996864, -2147391488, -2128085120, -2075306240, -1989889792,
+	 -1873435392, -1727320960, -1554403456, -1356728960, -1138162176,  -901289536,  -650242688,  -389485280,
+	  -122102904,   146676672,   413672960,   673665728,   923578496,  1158966400,  1375725184,  1571289856,
+	  1741836672,  1885096576,  1999016960,  2081499648,  2131182720,  2147478528,  2130068352,  2079158144,
+	  1995743488,  1880811264,  1736300160,  1564842112,  1368465792,  1151010688,   915049984,   665197184,
+	   404408800,   137260384,  -131523368,  -398761920,  -659230336,  -909371456, -1145710208, -1364030848,
+	 -1560539648, -1732911360, -1877775872, -1993419008, -2077579648, -2129261952, -2147458176, -2131945088,
+	 -2082906112, -2001300224, -1888093056, -1745497984, -1575202432, -1380134144, -1163801600,  -928764672,
+	  -679618816,  -419826272,  -152933952,   116363488,   383830944,   644761920,   895592320,  1132838144,
+	  1351860992,  1550068864,  1723899392,  1870361216,  1987721216,  2073684864,  2127234816,  2147324160,
+	  2133715200,  2086549888,  2006756992,  1895280512,  1754300160,  1585837568,  1392132736,  1176534272,
+	   942432960,   694006400,   434707872,   168076672,  -100674088,  -368364256,  -630261312,  -881768384,
+	 -1119909504, -1340028416, -1539520640, -1714485632, -1862853120, -1981924096, -2069686400, -2125101312,
+	 -2147085312, -2135434112, -2090089472, -2012113408, -1902373248, -1763014528, -1596037120, -1403660160,
+	 -1189644800,  -956523520,  -708359360,  -449567712,  -183210976,    85503176,   353395008,   615226880,
+	   867900416,  1106924800,  1328128896,  1528895360,  1705298816,  1855251712,  1976027904,  2065584384,
+	  2122861440,  2146739200,  2136987136,  2093641088,  2017548800,  1909370752,  1771640704,  1606156928,
+	  1415117312,  1202257152,   970095168,   723170496,   464405088,   198336128,   -70327984,  -338408128,
+	  -600662912,  -853988992, -1093884672, -1316162944, -1518193664, -1696026624, -1847290368, -1969824128,
+	 -2061232000, -2120515456, -2146285824, -2138433280, -2096967808, -2022700288, -1916509440, -1780471552,
+	 -1616196352, -1426503808, -1214809472,  -983618304,  -737450624,  -479219232,  -213451360,    55149276,
+	   323404288,   586068864,   839552320,  1080336896,  1303714688,  1507416064,  1686669824,  1839500800,
+	  1963727232,  2056920192,  2117976960,  2145703680,  2139772416,  2100189696,  2027750528,  1923312384,
+	  1788917120,  1626155008,  1437818880,  1227300992,   997092288,   751693824,   494009440,   229077232,
+	   -39443616,  -308384288,  -571445568,  -825554816, -1067186176, -1291615488, -1496187008, -1676900992,
+	 -1831619200, -1957532032, -2052505472, -2115415424, -2145031936, -2141004672, -2103306496, -2032699520,
+	 -1930019200, -1797273216, -1636032384, -1449448960, -1240159232, -1010516416,  -765899456,  -509284288,
+	  -244169952,    24260096,   292829504,   556287296,   811515968,  1053982208,  1279451776,  1485256704,
+	  1667373056,  1823645952,  1951239040,  2047988096,  2112748032,  2144252928,  2142129792,  2106420224,
+	  2037712256,  1936629504,  1805539456,  1645827840,  1460617216,  1252525184,  1024350848,   780066816,
+	   524023456,   259250464,    -9075365,  -277778912,  -541606592,  -797436544, -1040725504, -1267224064,
+	 -1474252032, -1657761664, -1815581568, -1944626176, -2043206912, -2109975040, -2143366656, -2143147776,
+	 -2109322880, -2042454144, -1943366144, -1813715456, -1655874944, -1471712512, -1264828672, -1037671424,
+	  -794682240,  -538736384,  -274318016,    -6109820,   262714448,   526898848,   783317248,  1026956288,
+	  1254507392,  1463173632,  1648067456,  1807426432,  1938135040,  2038481536,  2106995328,  2142373248,
+	  2144088192,  2112120192,  2047093888,  1949779072,  1822078208,  1665502592,  1482734080,  1277068800,
+	  1050940160,   808769536,   553422400,   289891360,    21294700,  -247636848,  -512164768,  -769158848,
+	 -1013594432, -1242151424, -1451635840, -1638290816, -1799180928, -1931546880, -2033654144, -2104007936,
+	 -2141232768, -2144888192, -2114811776, -2051631232, -1956094464, -1830069248, -1675046912, -1494058240,
+	 -1289245056, -1064156352,  -822816320,  -568080768,  -304930208,   -37002724,   232025648,   497405056,
+	   754961920,  1000181824,  1229733376,  1440409216,  1628090368,  1790556032,  1924862336,  2028724992,
+	  2100915200,  2140021504,  2145580928,  2117485056,  2056066048,  1962312064,  1837968768,  1684507392,
+	  1504928512,  1301773952,  1077772800,   836822016,   582710720,   319953824,    52184640,  -216923648,
+	  -482109568,  -740235136,  -986719296, -1217253760, -1429110528, -1618147584, -1782127872, -1917845504,
+	 -2023694464, -2097717504, -2138703104, -2146166400, -2119961344, -2060398080, -1968641152, -1845776384,
+	 -1693883776, -1515723520, -1313818496, -1090880128,  -851267200,  -597815104,  -334961408,   -67363944,
+	   201810800,   467300032,   725962176,   972739968,  1204713216,  1417740416,  1608123904,  1773610752,
+	  1910965504,  2018562816,  2094299008,  2137277952,  2146644480,  2122331648,  2064771200,  1974658816,
+	  1853756416,  1703494656,  1526442624,  1325797248,  1103932800,   865187008,   612385024,   350469568,
+	    82539880,  -186687872,  -452467136,  -711652928,  -959177728, -1192112512, -1405903232, -1598019712,
+	 -1765004800, -1903989760, -2013147776, -2090888064, -2135691008, -2147026176, -2124595968, -2068893440,
+	 -1980577792, -1861375872, -1712698240, -1537451648, -1337709824, -1116930304,  -879063552,  -626924288,
+	  -365442304,   -97711688,   171032976,   437611616,   697308096,   945567552,  1179452288,  1394389504,
+	  1587482624,  1756008832,  1896918912,  2007810944,  2087372672,  2134048384,  2147285888,  2126826496,
+	  2072912128,  1986397696,  1868902272,  1721816064,  1548014976,  1349555456,  1130317696,   892896128,
+	   641432256,   380396768,   112878616,  -155891840,  -422220160,  -682431296,  -931910080, -1166292736,
+	 -1382806016, -1577216512, -1747223936, -1889504128, -2002373760, -2083752832, -2132299136, -2147438336,
+	 -2128874496, -2076827136, -1992313984, -1876335232, -1730847872, -1558500864, -1361333632, -1143200896,
+	  -907159296,  -656407296,  -395332224,  -128039896,   140742912,   407320896,   668016256,   917731968,
+	  1153513216,  1371153408,  1566871552,  1738351488,  1882240768,  1996836480,  2079898240,  2130443264,
+	  2147483264,  2130816000,  2080638464,  1997931648,  1883926016,  1739793152,  1568908928,  1373043712,
+	  1156026880,   920900352,   670849216,   410762528,   143717888,  -125586952,  -392401280,  -653567872,
+	  -903980416, -1140675840, -1359026432, -1556448128, -1729392256, -1874883200, -1991199360, -2076066688,
+	 -2128411136, -2147420928, -2132651008, -2084345600, -2003449216, -1891167616, -1748955648, -1579593728,
+	 -1385085824, -1168795136,  -934595328,  -685257600,  -425656992,  -158865344,   109901104,   377462048,
+	   639086720,   890183680,  1127781504,  1347234944,  1545947008,  1720346496,  1867431808,  1985462656,
+	  2072131200,  2126338560,  2147243392,  2134437120,  2087948544,  2008866688,  1898314752,  1757723520,
+	  1589841536,  1396655616,  1181942656,   948243584,   699631680,   440530144,   174004864,   -94733168,
+	  -362503904,  -624573696,  -876342400, -1114830848, -1335376128, -1535001856, -1710897920, -1859625088,
+	 -1979626752, -2068092160, -2124159744, -2146962560, -2136055040, -2091566080, -2014365568, -1905366912,
+	 -1766403328, -1600009984, -1408155648, -1194591360,  -961844416,  -713970816,  -455381280,  -189135680,
+	    79560488,   347010272,   609526720,   861977152,  1101824384,  1323450496,  1524344064,  1701677568,
+	  1851984128,  1973485056,  2063804800,  2121874688,  2146574464,  2137566208,  2094956416,  2019578368,
+	  1912323840,  1774994944,  1610098304,  1419585152,  1207180288,   975397120,   728767488,   470721216,
+	   204257024,   -64383828,  -332016064,  -594950848,  -848047424, -1088310784, -1311043584, -1513609856,
+	 -1692372224, -1844250624, -1967448064, -2059555584, -2119483520, -2146078976, -2138970496, -2098242048,
+	 -2024690176, -1919185152, -1783789824, -1620450304, -1430943744, -1219708800,  -989366464,  -743033216,
+	  -485525248,  -219889712,    49203948,   317005248,   580345280,   834075328,  1075192960,  1298983936,
+	  1502800128,  1682982272,  1836424960,  1961312640,  2055203456,  2116986368,  2145453440,  2140310656,
+	  2101422848,  2029700864,  1926182400,  1792200320,  1630374272,  1442619136,  1232176384,  1002819264,
+	   757261824,   500304992,   234989488,   -33497390,  -301978592,  -565710720,  -820061568, -1062021376,
+	 -1286859392, -1491915136, -1673179520, -1828232448, -1955079168, -2050748416, -2114383360, -2144739584,
+	 -2141497216, -2104602752, -2034609920, -1932848128, -1800521344, -1640216832, -1453831552, -1245009664,
+	 -1016221952,  -771452544,  -515059744,  -250077520,    18313306,   286936832,   550541056,   805520768,
+	  1048796608,  1274670464,  1480955648,  1663618816,  1820220416,  1948527616,  2046190976,  2111579264,
+	  2143918592,  2142576768,  2107569536,  2039581568,  1939417088,  1808752256,  1649977344,  1464971264,
+	  1257351168,  1029573824,   786092608,   529788736,   265153056,    -3128306,  -271880736,  -535849696,
+	  -791424256, -1035060096, -1262417920, -1469922048, -1653974912, -1812117248, -1942095488, -2041368448,
+	 -2108761344, -2142990336, -2143549056, -2110430976, -2044283392, -1945889280, -1817172224, -1659655424,
+	 -1476037760, -1269629824, -1042874176,  -800204096,  -544998400,  -280735136,   -12056850,   256811040,
+	   521131552,   777288192,  1021729408,  1249675776,  1458430208,  1644248320,  1803923584,  1935566336,
+	  2036603008,  2105837952,  2141917312,  2144414336,  2113186816,  2048883072,  1952264064,  1825218688,
+	  1669580160,  1487408640,  1281845120,  1056122432,   814275584,   559672704,   295782880,    27765648,
+	  -241207536,  -506387328,  -763113280, -1008347584, -1237295360, -1447247616, -1634099456, -1795639680,
+	 -1928940416, -2031735808, -2102809216, -2140771072, -2145172224, -2115926656, -2053380352, -1958541184,
+	 -1833173888, -1679088896, -1498324352, -1294414592, -1069772480,  -828306368,  -574319040,  -310815872,
+	   -42948780,   226112496,   491107392,   748900224,   994915328,  1224853120,  1435992832,  1624206080,
+	  1787265920,  1921984256,  2026766976,  2099675392,  2139517952,  2145843584,  2118467456,  2057924736,
+	  1964931968,  1841037440,  1688513536,  1509165184,  1306498816,  1082912512,   842777984,   588936640,
+	   325833280,    58129760,  -211006160,  -476312448,  -734649664,  -980966976, -1212349696, -1424666112,
+	 -1614231552, -1778509056, -1915162496, -2021519872, -2096322944, -2138157696, -2146383232, -2120902272,
+	 -2062212864, -1971009664, -1849075712, -1697853952, -1519930496, -1318517632, -1095998464,  -856723776,
+	  -603524800,  -341352064,   -73307840,   195889264,   461493664,   720362432,   967434240,  1199350656,
+	  1412873344,  1604176256,  1769954048,  1908244864,  2016345216,  2092975616,  2136638080,  2146815616,
+	  2123231104,  2066397952,  1976988672,  1856751616,  1707109248,  1530987648,  1330470528,  1109029632,
+	   870626752,   618082816,   356335552,    89006088,  -180240160,  -446651840,  -705544000,  -953853184,
+	 -1186724480, -1401402368, -1593689472, -1761310464, -1901232000, -2011069568, -2089523712, -2135060352,
+	 -2147140736, -2125528576, -2070479616, -1982868864, -1864334592, -1716279296, -1541597824, -1342766208,
+	 -1122452352,  -884486144,  -632609920,  -371301248,  -104175912,   165104128,   431274048,   691184192,
+	   940224384,  1174038912,  1389861248,  1583471488,  1752578816,  1893876736,  2005693440,  2085967360,
+	  2133376000,  2147358464,  2127641216,  2074593280,  1988649984,  1871824384,  1725363584,  1552130816,
+	  1354583168,  1135369984,   898777536,   647605248,   386248352,   119340520,  -149959856,  -416387552,
+	  -676789888,  -926075520, -1161294720, -1378250624, -1573174400, -1743759488, -1886670720, -2000026240,
+	 -2082306688, -2131585024, -2147468672, -2129647488, -2078464128, -1994525952, -1879474304, -1734670592,
+	 -1562586368, -1366332288, -1148230912,  -912546240,  -662067264,  -401691200,  -134499168,   134808080,
+	   401480224,   662361664,   912351808,  1148492416,  1366571136,  1562798720,  1734853120,  1879370496,
+	  1994446336,  2078410112,  2129619968,  2147471616,  2131547392,  2082231040,  2000104448,  1886773376,
+	  1743578880,  1573320576,  1378013312,  1161034368,   926269312,   676496128,   416598240,   149651088,
+	  -119649568,  -386552832,  -647900352,  -898582464, -1135187712, -1354416512, -1551982464, -1725859840,
+	 -1871976064, -1988766720, -2074537728, -2127612160, -2147361664, -2133400576, -2085893760, -2005582848,
+	 -1893977984, -1752399872, -1583616512, -1389625216, -1173779712,  -939946048,  -690891136,  -431484416,
+	  -165318256,   103961400,   371089696,   633406656,   884768192,  1122269312,  1342598528,  1541448320,
+	  1716465408,  1864227968,  1982987776,  2070561664,  2125497856,  2147146240,  2135083520,  2089452288,
+	  2010961024,  1901088000,  1761133312,  1593833344,  1401167744,  1186903424,   954045568,   705251648,
+	   446349056,   180454160,   -88791512,  -356123776,  -618379264,  -870909696, -1109294720, -1330713472,
+	 -1530836992, -1707297024, -1856643712, -1977109504, -2066482176, -2123277440, -2146823296, -2136659584,
+	 -2092906368, -2016419072, -1908343424, -1769778688, -1603970432, -1413035136, -1199528832,  -967625984,
+	  -720070848,  -461191360,  -195581024,    73617184,   341140032,   603821888,   856526848,  1096264704,
+	  1318761984,  1520149248,  1698043392,  1848966400,  1971132544,  2062152960,  2120868608,  2146393088,
+	  2138128896,  2096369536,  2021592448,  1915259648,  1778629376,  1614027392,  1424434432,  1212094208,
+	   981158016,   734358848,   476521856,   210698128,   -58439176,  -326139232,  -589234304,  -842580480,
+	 -1083179904, -1306328320, -1509012352, -1688704768, -1841196800, -1965056896, -2057863424, -2118432256,
+	 -2145835136, -2139491200, -2099610496, -2026664576, -1922080000, -1787094272, -1624346624, -1435762560,
+	 -1224598912,  -994641024,  -748610112,  -491316480,  -225804688,    42734064,   310603360,   574617280,
+	   828591936,  1070040832,  1294243200,  1498170496,  1678955008,  1833335168,  1958668160,  2053470976,
+	  2115890048,  2145186560,  2140788096,  2102746368,  2031635584,  1928804352,  1795469824,  1634238720,
+	  1447019008,  1237470976,  1008537152,   762823936,   506086528,   240899952,   -27550908,  -295570176,
+	  -559465344,  -814562048, -1056848384, -1282093440, -1487253760, -1669445120, -1825105536, -1952392960,
+	 -2048975744, -2113241984, -2144430848, -2141932800, -2105777280, -2036671104, -1935432320, -1803755648,
+	 -1644049152, -1458203008, -1249850496, -1021918272,  -777488384,  -520831264,  -255983184,    12366374,
+	   280522208,   544790656,   800004800,  1043144768,  1269879552,  1476262528,  1659851776,  1817057664,
+	  1946020096,  2044217600,  2110488192,  2143567872,  2142970368,  2108702720,  2041435136,  1942187136,
+	  1812232448,  1653777536,  1469314048,  1262167424,  1035248256,   791623936,   536057664,   271573696,
+	     2818776,  -265460208,  -530088704,  -785892736, -1029845440, -1257177088, -1465197568, -1650175488,
+	 -1808919040, -1939550080, -2039514240, -2107628928, -2142562176, -2143900672, -2111522816, -2046097024,
+	 -1948617856, -1820334336, -1663754624, -1480731520, -1274421376, -1048526528,  -805719808,  -550748672,
+	  -286630080,   -18528056,   250384944,   515360224,   771741376,  1016494656,  1244834560,  1454059392,
+	  1640078208,  1800690048,  1932982912,  2034708992,  2104560128,  2141481216,  2144750464,  2114329216,
+	  2050656640,  1954951040,  1828345088,  1673314176,  1491692544,  1287031296,  1061752320,   819775488,
+	   565412096,   301672160,    33712120,  -235297168,  -500096160,  -757551424, -1003092992, -1232429952,
+	 -1442460032, -1630234496, -1792082048, -1926087424, -2029801856, -2101486592, -2140293120, -2145462656,
+	 -2116934400, -2055265664, -1961186560, -1836264448, -1682790016, -1502578944, -1299154944, -1074925056,
+	  -834273216,  -580047296,  -316699136,   -48894500,   220197616,   485316032,   742831744,   989175872,
+	  1219963648,  1431174528,  1620309376,  1783670144,  1919324032,  2024618624,  2098307968,  2138997888,
+	  2146067712,  2119433600,  2059616384,  1967323904,  1844360704,  1692181632,  1513390336,  1311213568,
+	  1088044032,   848244736,   595157248,   332228256,    64074436,  -204565152,  -470511680,  -728565440,
+	  -975672896, -1207002624, -1419817344, -1610303104, -1775169152, -1912464640, -2019505280, -2095024512,
+	 -2137545600, -2146565376, -2121826944, -2063864192, -1973363072, -1852092928, -1701808640, -1524125952,
+	 -1323206784, -1101558656,  -862173824,  -609732608,  -347222208,   -79775096,   189444000,   455683776,
+	   714262784,   962121152,  1194412928,  1408389248,  1599866752,  1766579328,  1905509760,  2014291072,
+	  2091636224,  2136032896,  2146967296,  2124114176,  2068008704,  1979303424,  1859732480,  1711027712,
+	  1535152128,  1335544320,  1114566272,   876059776,   624277568,   362198816,    94947712,  -174313376,
+	  -440319968,  -699924352,  -948521280, -1181763456, -1396890752, -1589697152, -1757600128, -1898459520,
+	 -2008976128, -2088143360, -2134413440, -2147246592, -2126368640, -2072187648, -1985344768, -1867278976,
+	 -1720161152, -1545732096, -1347402112, -1127518080,  -890379136,  -638791232,  -377157312,  -110115584,
+	   159174032,   425446496,   685550912,   934873984,  1169054848,  1385322368,  1579448192,  1748831104,
+	  1891065856,  2003371904,  2084546176,  2132687232,  2147418496,  2128439680,  2076121600,  1991083392,
+	  1874987904,  1729208704,  1556234880,  1359192704,  1140413568,   904175232,   653273024,   392096960,
+	   125277952,  -144026720,  -410551744,  -670645184,  -920706304, -1155845888, -1373684736, -1569120256,
+	 -1739974528, -1883822976, -1997852800, -2080715008, -2130789248, -2147483136, -2130404224, -2079951744,
+	 -1996722560, -1882344064, -1738169728, -1566659840, -1370915200, -1153252096,  -917926144,  -668220352,
+	  -407531776,  -140957216,   128872216,   395636448,   656202816,   906964608,  1143019136,  1361572992,
+	  1558713856,  1731031040,  1876485760,  1992233856,  2076905856,  2128846208,  2147440256,  2132262400,
+	  2083677952,  2002261888,  1889606272,  1747043968,  1577362304,  1382970368,  1166032896,   931631168,
+	   682634880,   422430720,   156106032,  -113187712,  -380701408,  -641727616,  -893177600, -1130135168,
+	 -1349796224, -1547866112, -1722001024, -1869054720, -1986515328, -2072992896, -2126796800, -2147290112,
+	 -2134072320, -2087423104, -2007701120, -1896773888, -1756132480, -1587627264, -1394552832, -1179193600,
+	  -945289600,  -697015296,  -437308576,  -171247056,    98020896,   365230656,   627220352,   879345920,
+	  1117194624,  1337951872,  1537301632,  1712884864,  1861268736,  1980494720,  2068976384,  2124640896,
+	  2147021696,  2135713408,  2090937088,  2013222528,  1903846656,  1764828416,  1597812992,  1406065536,
+	  1191855104,   959369856,   711360896,   452164544,   186379504,   -82849184,  -350257664,  -612681664,
+	  -864990400, -1103748608, -1326040704, -1526660352, -1703683072, -1853648000, -1974574464, -2064712192,
+	 -2122378880, -2146653184, -2137247744, -2094346496, -2018457216, -1911063424, -1773436160, -1607918720,
+	 -1417507968, -1204456960,  -972931456,  -725670848,  -467509632,  -202024608,    67673320,   335267168,
+	   598112384,   851070016,  1090695040,  1313648512,  1515942656,  1694073984,  1845934592,  1968555392,
+	  2060485248,  2119927040,  2146177152,  2138675200,  2097651328,  2023590912,  1917942144,  1781955200,
+	  1618288768,  1429270784,  1216998656,   986444352,   739944576,   482318848,   217137312,   -51969944,
+	  -320259872,  -583513216,  -837107072, -1077587072, -1301603072, -1504775296, -1684699392, -1838128896,
+	 -1962437760, -2056155392, -2117449216, -2145593984, -2140039296, -2100851072, -2028623488, -1924724992,
+	 -1790385152, -1628230400, -1440568448, -1229909376,  -999907904,  -754181248,  -497103936,  -232239152,
+	    36788000,   304717632,   568379264,   823102208,  1064425216,  1289492608,  1493904000,  1675240576,
+	  1829956864,  1956222208,  2051722752,  2114865536,  2144903424,  2141249152,  2104050944,  2033723136,
+	  1931411584,  1798725504,  1638090624,  1451794048,  1242326656,  1013783744,   768869824,   511864160,
+	   247329376,   -21604214,  -289678560,  -553721472,  -808570560, -1051210112, -1277317632, -1482958080,
+	 -1665697920, -1821964544, -1949908736, -2047028992, -2112176000, -2144105600, -2142351872, -2107036928,
+	 -2038548992, -1938227456, -1807259264, -1647868928, -1462947072, -1254681728, -1027144896,  -783029056,
+	  -527107040,  -262407248,     6419348,   274625024,   539036032,   794482752,  1037942464,  1264655104,
+	  1471937920,  1656072064,  1813881088,  1943274752,  2042387840,  2109282560,  2143167488,  2143347456,
+	  2109917440,  2043273088,  1944717184,  1815416320,  1657898240,  1474026880,  1266974080,  1040454720,
+	   797149120,   541814400,   277472000,     9290121,  -259557728,  -524323616,  -780355200, -1024162112,
+	 -1252350720, -1460459776, -1646026624, -1805707008, -1936763264, -2037644544, -2106378368, -2142151552,
+	 -2144264704, -2112692608, -2047894912, -1951109760, -1823482496, -1667508352, -1485033088, -1279624192,
+	 -1053712512,  -811229376,  -556494720,  -292522848,   -24474840,   243956592,   509075648,   766188672,
+	  1010789504,  1239983872,  1449290496,  1636232832,  1797155712,  1930154880,  2032799360,  2103368960,
+	  2141028608,  2145042176,  2115362048,  2052568576,  1957404800,  1831457536,  1677035136,  1495964928,
+	  1291787136,  1067372544,   825269056,   571147200,   308077984,    39658336,  -228863696,  -494310656,
+	  -751492672,  -997366464, -1227554944, -1438048768, -1626357120, -1788798336, -1923450112, -2027679872,
+	 -2100254208, -2139798656, -2145712384, -2117925760, -2056981888, -1963814144, -1839341056, -1686478208,
+	 -1507195648, -1303885312, -1080522496,  -839750016,  -586275456,  -323098272,   -54839848,   213759360,
+	   479520960,   737248896,   983893440,  1214632320,  1426735104,  1616400128,  1780351488,  1916649088,
+	  2022628096,  2096921472,  2138461568,  2146275456,  2120383616,  2061292288,  1969909632,  1847399936,
+	  1696158336,  1517974784,  1315918464,  1093618304,   853704960,   600869056,   338102464,    70542624,
+	  -198644336,  -464707296,  -722968320,  -970371328, -1202079232, -1414955776, -1606362368, -1771815680,
+	 -1909752192, -2017475328, -2093593344, -2136965888, -2146744832, -2122814720, -2065499648, -1975906688,
+	 -1855095808, -1705429248, -1528678016, -1328297728, -1106659456,  -867617280,  -615432640,  -353089696,
+	   -85717760,   182997008,   449870368,   708651520,   956800640,  1189465984,  1403497600,  1595893504,
+	  1762891904,  1902759936,  2012221568,  2090160512,  2135411456,  2147089536,  2125056640,  2069743616,
+	  1981804928,  1862699008,  1714614912,  1539304832,  1340196224,  1119645312,   881486144,   629965376,
+	   368059328,   100888608,  -167862576,  -434497536,  -693803200,  -943182080, -1176793216, -1391969280,
+	 -1585692800, -1754176256, -1895426048, -2006680576, -2086623104, -2133750144, -2147326720, -2127192448,
+	 -2073740672, -1987604096, -1870209152, -1723714816, -1549854592, -1352027776, -1133020672,  -895787520,
+	  -644966784,  -383010528,  -116054416,   152719744,   419615648,   679415104,   929043776,  1164061696,
+	  1380371200,  1575412736,  1745372928,  1888240512,  2001222400,  2082981504,  2131982208,  2147456640,
+	  2129221760,  2077633920,  1993303808,  1877880064,  1733038208,  1560327040,  1363791744,  1145891840,
+	   909566016,   659434688,   398457760,   131214416,  -137569280,  -404712768,  -664993024,  -915330048,
+	 -1150829312, -1368704256, -1565054080, -1736482304, -1880960640, -1995664128, -2079235584, -2130040960,
+	 -2147478016, -2131144704, -2081423488, -1999095424, -1885199488, -1741962240, -1571078784, -1375487488,
+	 -1158705920,  -923299072,  -673869632,  -413369216,  -146890928,   122411936,   389789664,   650537664,
+	   901570496,  1137980032,  1356968832,  1554255232,  1727193344,  1873586688,  1990006144,  2075251072,
+	  2128056320,  2147389440,  2133021824,  2085108864,  2004592128,  1892424576,  1750799232,  1581391872,
+	  1387514752,  1171461888,   936985920,   688270912,   428260000,   162036784,  -107248472,  -374330816,
+	  -635549056,  -887765888, -1125073792, -1345165568, -1543737856, -1718126592, -1865859456, -1984248576,
+	 -2071293824, -2125965184, -2147193472, -2134727808, -2088811776, -2009988608, -1899555072, -1759548672,
+	 -1591625856, -1399069952, -1184159360,  -951096000,  -703133184,  -443129344,  -177174528,    92079648,
+	   359368832,   621028288,   873437952,  1112111360,  1332883968,  1533143296,  1708973952,  1858295296,
+	  1978187904,  2067233152,  2123767680,  2146890240,  2136327040,  2092284800,  2015284608,  1906831872,
+	  1768507520,  1601780224,  1410555136,  1196797568,   964686848,   717463616,   458488768,   192303424,
+	   -76382264,  -344388896,  -606476480,  -859543808, -1098642944, -1320944512, -1522472064, -1699735808,
+	 -1850638208, -1972228864, -2063069056, -2121382656, -2146479488, -2137819520, -2095653248, -2020479744,
+	 -1913768832, -1777077504, -1612200960, -1421969920, -1209376000,  -978229440,  -731758272,  -473312288,
+	  -207944544,    61204864,   329391712,   591894400,   845606656,  1085568000,  1308938880,  1511352192,
+	  1690412672,  1842888576,  1966171264,  2058801792,  2118969216,  2145941760,  2139250944,  2098916992,
+	  2025573888,  1920610048,  1785558656,  1622191872,  1433703936,  1222325120,   991723072,   746016256,
+	   488112128,   223053072,   -46024412,  -313859424,  -577282688,  -831627200, -1072438784, -1296867968,
+	 -1500526592, -1681004928, -1834774400, -1960015232, -2054431616, -2116449920, -2145312768, -2140525952,
+	 -2102182912, -2030737408, -1927355264, -1793950464, -1632101760, -1444973440, -1234779776, -1005630464,
+	  -760236992,  -502887584,  -238150464,    30841654,   298829536,   562642112,   817121344,  1059256000,
+	  1284732032,  1489625984,  1671184000,  1826837632,  1953543552,  2049802496,  2113824768,  2144576512,
+	  2141693696,  2105232896,  2035625216,  1934231936,  1802252672,  1641929984,  1456170624,  1247172736,
+	  1019022528,   774419712,   518146688,   253235936,   -15657355,  -283784736,  -547466432,  -803058048,
+	 -1045562304, -1272109696, -1478650880, -1661605888, -1818809600, -1947188480, -2045223552, -2110997760,
+	 -2143732992, -2142754560, -2108177536, -2040411264, -1940780672, -1810464640, -1652011136, -1467295104,
+	 -1259503360, -1032363648,  -788563712,  -532870176,  -268828928,      472273,   268725696,   532769408,
+	   788954560,  1032272448,  1259843712,  1467219072,  1651944704,  1810690688,  1940736128,  2040542336,
+	  2108157824,  2142782336,  2143739136,  2111016832,  2045095296,  1947232384,  1818586112,  1661671808,
+	  1478726400,  1271771008,  1045653184,   803154496,   547567040,   283887840,    15237107,  -253132624,
+	  -518045728,  -774811712, -1018930944, -1247514880, -1456094208, -1642200832, -1802196096, -1934186624,
+	 -2035759104, -2105212288, -2141724544, -2144581888, -2113843200, -2049677056, -1953586816, -1826892288,
+	 -1671249408, -1489700992, -1284395264, -1059346496,  -817217536,  -562236480,  -298932576,   -30421438,
+	   238047072,   503296128,   760139712,  1005538560,  1235123584,  1444896512,  1632374912,  1793893248,
+	  1927309312,  2030873984,  2102161664,  2140559616,  2145317504,  2116467584,  2054309248,  1960057728,
+	  1834828416,  1680743424,  1500601088,  1296532992,  1072528960,   831239744,   577382848,   313962336,
+	    45604248,  -222949600,  -488521376,  -745918720,  -991630848, -1222670592, -1433626496, -1622467328,
+	 -1785500800, -1920563456, -2025713536, -2098894976, -2139241856, -2145945728, -2118986112, -2058682240,
+	 -1966212992, -1842672768, -1690476800, -1511426048, -1308605696, -1085657728,  -845220288,  -591994368,
+	  -329494528,   -60784776,   207840992,   473722208,   731660480,   978136832,  1209723264,  1421891968,
+	  1612132224,  1777019136,  1913721600,  2020622080,  2095630592,  2137859328,  2146482688,  2121398784,
+	  2062952320,  1972270080,  1850424960,  1699799296,  1522175616,  1320613120,  1098732288,   859158656,
+	   606576320,   344491584,    76486224,  -192199824,  -458899328,  -717365568,  -964593856, -1197146496,
+	 -1410476800, -1602060160, -1768448512, -1906784000, -2015429760, -2092261376, -2136369792, -2146892672,
+	 -2123705344, -2067119232, -1978228352, -1858084608, -1709036928, -1533216128, -1332965504, -1112200320,
+	  -873054016,  -621127872,  -359471392,   -91659768,   177070864,   443540576,   703034880,   951002752,
+	  1184509952,  1398990976,  1591907968,  1759489024,  1899751168,  2010136576,  2088787584,  2134773504,
+	  2147195136,  2125905792,  2071321344,  1984288384,  1865651328,  1718189056,  1543810176,  1344837888,
+	  1125162496,   887383232,   635648384,   374433248,   106828736,  -161933040,  -428671808,  -688172352,
+	  -937364032, -1171814144, -1387435264, -1581676160, -1750738944, -1892623232, -2004554880, -2085083904,
+	 -2133070464, -2147390336, -2128070272, -2075277824, -1990045184, -1873381248, -1727255168, -1554327040,
+	 -1356643072, -1138068224,  -901189056,  -650636800,  -389376384,  -121992360,   146787136,   413781600,
+	   673770880,   923678464,  1158618240,  1375407616,  1571365248,  1741901440,  1885149568,  1999057408,
+	  2081397888,  2131196416,  2147478272,  2130054272,  2079130496,  1995702528,  1880757760,  1736543488,
+	  1564766208,  1368380416,  1150917120,   914949824,   665091904,   404300032,   137673088,  -131110584,
+	  -398870720,  -659335680,  -909946688, -1145803904, -1363711360, -1560615680, -1732976768, -1877829632,
+	 -1993460096, -2077607680, -2129276416, -2147456128, -2131931776, -2082879232, -2001260032, -1888040320,
+	 -1745433472, -1575127168, -1380450944, -1164149120,  -928664832,  -679513728,  -419203488,  -152823504,
+	   115950536,   383424032,   644867520,   895692992,  1132932224,  1351947008,  1550145536,  1723652736,
+	  1870415616,  1987763072,  2073713664,  2127250048,  2147325440,  2133702656,  2086647680,  2006717568,
+	  1895228416,  1754236288,  1585409280,  1392048384,  1176880256,   942804544,   693901632,   434599424,
+	   167966288,  -100784696,  -368473344,  -629865920,  -881869376, -1120003968, -1340114944, -1539597824,
+	 -1714552320, -1862908160, -1981764864, -2069715840, -2125117184, -2147087488, -2135366912, -2090184320,
+	 -2012257920, -1902564992, -1762951296, -1595612160, -1403576320, -1189552640,  -956424384,  -708749760,
+	  -449459424,  -183100656,    85613816,   353504256,   615332928,   868001664,  1106570368,  1328215936,
+	  1528973056,  1705366016,  1855307520,  1975865856,  2065471232,  2122798976,  2146742144,  2136924288,
+	  2093616512,  2017510912,  1909560064,  1771874432,  1606083456,  1415033984,  1202165376,   969996352,
+	   723066240,   464296992,   198747920,   -70438656,  -338517472,  -600769216,  -854090560, -1093528832,
+	 -1315836160, -1517901184, -1696094592, -1847614080, -1969868160, -2061263104, -2120450048, -2146271872,
+	 -2138423040, -2096944000, -2022663040, -1916459520, -1780409600, -1616123392, -1426812928, -1214718080,
+	  -983519872,  -737346624,  -479111296,  -213862864,    55259968,   322995456,   586175424,   840136768,
+	  1080432512,  1303802624,  1507121536,  1686413696,  1839557888,  1963772032,  2056951936,  2117995264,
+	  2145708160,  2139763072,  2100275968,  2027714048,  1923263104,  1788855808,  1626082688,  1438126080,
+	  1227210112,   997458560,   751590144,   493901664,   228967136,   -39554324,  -307975008,  -571046912,
+	  -826140992, -1067282304, -1291703936, -1496266496, -1676970240, -1831676928, -1957361920, -2052537984,
+	 -2115434496, -2145037184, -2140995968, -2103389824, -2032663808, -1930200448, -1797212672, -1635960576,
+	 -1449367296, -1239640704, -1010881280,  -766285824,  -508667360,  -244059936,    24370818,   292939200,
+	   556394240,   811618496,  1053621824,  1279540736,  1485336576,  1667442816,  1823704448,  1951066368,
+	  2048021376,  2112673920,  2144258944,  2142121984,  2106398720,  2037511808,  1936808192,  1805763328,
+	  1645756672,  1460536064,  1252435328,  1024253504,   780452160,   523916064,   259660992,    -9186093,
+	  -277888704,  -541713792,  -797539392, -1040363712, -1267313408, -1473951232, -1657832064, -1815640704,
+	 -1944673152, -2043402368, -2109898112, -2143341056, -2143140736, -2109302144, -2042419968, -1943318912,
+	 -1813936768, -1655804416, -1472013696, -1264739072, -1037574528,  -794579392,  -538629184,  -274728192,
+	    -5999091,   262304000,   527006208,   783420352,  1027053568,  1255022720,  1462870912,  1647802240,
+	  1807486208,  1938182656,  2038681088,  2107016704,  2142344704,  2144082048,  2112194816,  2047060480,
+	  1949732608,  1822019584,  1665432704,  1483033216,  1276979712,  1051300800,   808666944,   553315456,
+	   289781632,    21183976,  -247226048,  -511763136,  -769262208, -1013692032, -1242669440, -1451717376,
+	 -1638023424, -1798955136, -1931366144, -2033689728, -2104030080, -2141241216, -2144882816, -2114883584,
+	 -2051598592, -1956265088, -1830011264, -1674977536, -1493978752, -1289156480, -1064515584,  -823198336,
+	  -567974016,  -304820608,   -36367800,   232135728,   497002720,   754574720,   999815872,  1229824128,
+	  1440491264,  1628162560,  1790617216,  1924678912,  2028761344,  2100938240,  2140030720,  2145576320,
+	  2117466624,  2056034048,  1962480128,  1837911552,  1684438784,  1504849536,  1301268864,  1077677056,
+	   837202816,   583108736,   320362752,    51549808,  -217033808,  -482217472,  -740339072,  -986351936,
+	 -1217344896, -1429193216, -1618220416, -1782189696, -1917895424, -2023731584, -2097628928, -2138713216,
+	 -2146162560, -2119943680, -2060366848, -1968596864, -1845987712, -1694137984, -1516016384, -1313316096,
+	 -1090784640,  -851165504,  -597708736,  -335369920,   -67253272,   201921040,   467408096,   726066368,
+	   972838720,  1204804992,  1417429760,  1608197248,  1773673088,  1911015936,  2018600576,  2094323584,
+	  2137288704,  2146656000,  2122394752,  2064596608,  1974615296,  1853700480,  1703427200,  1526733568,
+	  1325710208,  1103837824,   865085632,   612278912,   350360320,    82429232,  -186275872,  -452575392,
+	  -711757376,  -959276800, -1192204672, -1405986816, -1598093696, -1764769152, -1903798528, -2013368704,
+	 -2090913408, -2135702528, -2147023872, -2124656128, -2068863744, -1980535040, -1861320704, -1712631424,
+	 -1537374336, -1337623168, -1117283456,  -878962496,  -626818368,  -365333184,   -97601080,   171143360,
+	   437720000,   696916928,   945196224,  1179544832,  1394473728,  1587557248,  1756072576,  1896725120,
+	  2007850240,  2087398656,  2134060800,  2147284480,  2126811136,  2072883200,  1986554880,  1868847744,
+	  1721749888,  1547938176,  1349469312,  1130223616,   892795392,   641826880,   380803776,   112768040,
+	  -156002272,  -422842752,  -682536256,  -931537472, -1166385792, -1382890752, -1577291648, -1747288320,
+	 -1889556736, -2002413824, -2083652736, -2132312320, -2147437568, -2128859904, -2076798976, -1992272768,
+	 -1876281344, -1731092608, -1558424704, -1361248000, -1143107200,  -906583680,  -656301888,  -395738720,
+	  -128452712,   140853408,   407429632,   668121472,   917832128,  1153606528,  1370835072,  1566947200,
+	  1738416512,  1882294016,  1996877184,  2079925888,  2130457088,  2147483008,  2130802176,  2080610944,
+	  1997890944,  1883621120,  1740035584,  1569191296,  1373361664,  1155933568,   920800320,   670744064,
+	   410653856,   143607408,  -125174096,  -392510144,  -653673344,  -904080896, -1140769664, -1359112064,
+	 -1556524416, -1729147008, -1874937216, -1991240832, -2076094976, -2128495488, -2147417728, -2132699520,
+	 -2084445056, -2003409408, -1890866688, -1748891392, -1579518720, -1385001216, -1169142016,  -934495616,
+	  -685152640,  -425548448,  -158754928,   110011688,   377571040,   638691904,   890284416,  1127875712,
+	  1347321088,  1546023808,  1720098944,  1867227648,  1985305088,  2072160256,  2126427392,  2147245056,
+	  2134424960,  2088045184,  2009012864,  1898263040,  1757659904,  1589767168,  1396571520,  1181850240,
+	   948144192,   700022720,   440421760,   173894496,   -94843784,  -362613056,  -624177984,  -875964800,
+	 -1114477312, -1335462784, -1535445888, -1710964864, -1859680384, -1979466368, -2067980672, -2124176000,
+	 -2146964992, -2136043648, -2091540992, -2014327168, -1905315840, -1766638592, -1599936128, -1408071936,
+	 -1194499328,  -961745408,  -714360832,  -455273088,  -189547616,    79671136,   347636928,   609632896,
+	   862078528,  1101469312,  1323124736,  1524422016,  1701745152,  1852040192,  1973528704,  2063835392,
+	  2121891712,  2146562304,  2137555584,  2094932096,  2019540736,  1912273536,  1775227776,  1610025088,
+	  1419895424,  1207088640,   975298496,   728663296,   470613184,   204668704,   -63970452,  -332643424,
+	  -595057280,  -848149184, -1088406272, -1311131264, -1513688448, -1692117632, -1844307456, -1967492480,
+	 -2059586944, -2119501312, -2146063872, -2138960640, -2098330112, -2024653312, -1919135488, -1783728128,
+	 -1620033536, -1431252096, -1220049152,  -988802816,  -742929344,  -485417376,  -219779568,    48790500,
+	   317114784,   579947136,   834177408,  1075288832,  1299072128,  1502879232,  1682725376,  1836482432,
+	  1961144192,  2055235584,  2117004928,  2145458176,  2140258688,  2101507968,  2029835904,  1925901568,
+	  1792139392,  1630302208,  1442537088,  1232515072,  1002721408,   757648768,   500197312,   234879424,
+	   -33608104,  -302088224,  -565311744,  -820163904, -1061661888, -1286948096, -1491994880, -1673248896,
+	 -1828565504, -1954908032, -2050625664, -2114402688, -2144745216, -2141488896, -2104580736, -2034742272,
+	 -1932799872, -1800746624, -1640145408, -1453750016, -1244919424, -1016124416,  -771838464,  -514952256,
+	  -250488272,    18424030,   287046592,   550648128,   806109376,  1048435712,  1274337664,  1481035776,
+	  1663688832,  1820557312,  1948574080,  2046065408,  2111599360,  2143894656,  2142569216,  2107548288,
+	  2039546880,  1939369600,  1808975104,  1649906432,  1465273600,  1257261440,  1029476608,   785989568,
+	   529681440,   265563440,    -2714747,  -271990560,  -535956928,  -792014592, -1035157120, -1262083328,
+	 -1469620480, -1653711104, -1812176640, -1942142848, -2041402880, -2108782208, -2142963584, -2143542400,
+	 -2110507392, -2044249472, -1945842432, -1817113216, -1659585152, -1476338176, -1269963392, -1042777408,
+	  -800101376,  -544384128,  -280625344,   -12470402,   256400432,   520730336,   777391424,  1021826752,
+	  1249765888,  1458511488,  1643982336,  1803983616,  1935614336,  2036638208,  2105859584,  2141925248,
+	  2144408448,  2113260416,  2048849920,  1952217856,  1825160320,  1669180800,  1487328768,  1282176896,
+	  1056482496,   814658304,   559059584,   295673216,    27654928,  -241317552,  -505985408,  -763216768,
+	 -1008445312, -1237385984, -1447329408, -1634171264, -1795700352, -1928758656, -2031771648, -2102831744,
+	 -2140779904, -2145167104, -2115907712, -2053501440, -1958710784, -1833389312, -1678692864, -1498244992,
+	 -1294326272, -1069676480,  -828687936,  -574212352,  -310706304,   -42838072,   226222608,   491215200,
+	   749003968,   994548800,  1224944128,  1436075136,  1624278528,  1787327360,  1922033664,  2026630272,
+	  2099588608,  2139482240,  2145818624,  2118449280,  2057893120,  1964887296,  1841250304,  1688445184,
+	  1509086464,  1306410880,  1082816896,   842676160,   588830144,   326242048,    58019072,  -211116352,
+	  -476420416,  -734753728,  -981065472, -1212441088, -1424356608, -1613958784, -1778864896, -1915212544,
+	 -2021557248, -2096346880, -2138119168, -2146379776, -2120884864, -2062182016, -1970965632, -1849019392,
+	 -1697786112, -1520222720, -1318430208, -1095903360,  -856622272,  -603418560,  -341242752,   -73197176,
+	   195477440,   461089760,   720466752,   967533120,  1199442560,  1412956800,  1603901312,  1770016768,
+	  1908295680,  2016383232,  2093000448,  2136649216,  2146812928,  2123293056,  2066367744,  1976945408,
+	  1856695936,  1707042048,  1530909952,  1330383616,  1109383808,   870525504,   617976768,   356226368,
+	    88371616,  -180350496,  -446247296,  -705648576,  -953952384, -1186816768, -1401486208, -1593763712,
+	 -1761373824, -1901039616, -2011108480, -2089549312, -2135072256, -2147138688, -2125512704, -2070450176,
+	 -1983027712, -1864279680, -1716212736, -1541520640, -1342270592, -1122358016,  -884862976,  -633005120,
+	  -371192160,  -104065312,   165214528,   431382528,   691289024,   939852544,  1174131712,  1389945600,
+	  1583546368,  1752642816,  1893928960,  2005733120,  2085869056,  2133388672,  2147357184,  2127626240,
+	  2074429056,  1988806016,  1872027136,  1725609728,  1552054400,  1354497152,  1135276032,   898676928,
+	   647499712,   386655168,   119229960,  -150070320,  -416496160,  -676894976,  -926175424, -1161387904,
+	 -1377933440, -1573249792, -1743824128, -1886723712, -2000257408, -2082205568, -2131534720, -2147470208,
+	 -2129633280, -2078304256, -1994484864, -1879420800, -1734605312, -1562870016, -1366246912, -1148137344,
+	  -912446016,  -661961920,  -401582432,  -134388656,   134395344,   401588992,   662467008,   912452032,
+	  1148585984,  1366252032,  1562514944,  1734609280,  1879424000,  1994681728,  2078437888,  2129634176,
+	  2147470208,  2131597568,  2082203904,  2000064128,  1886720512,  1743514240,  1573245184,  1377928320,
+	  1161382272,   926169408,   676391040,   416489600,   149540624,  -119236648,  -386146016,  -647506048,
+	  -898683008, -1135726720, -1354502400, -1552059008, -1725613696, -1871773440, -1988808576, -2074566400,
+	 -2127627136, -2147362944, -2133387904, -2085867520, -2005730688, -1893925888, -1752335872, -1583541760,
+	 -1389540864, -1174126080,  -939846528,  -691282688,  -431375968,  -164685120,   104072000,   371198784,
+	   633011520,   884391360,  1122810624,  1342684928,  1541525376,  1716531840,  1864283008,  1983030272,
+	  2070451968,  2125513728,  2147148160,  2135071616,  2089426688,  2011106048,  1901036544,  1761369984,
+	  1593759104,  1401083904,  1186811136,   953946368,   705642240,   446753568,   179821376,   -88902144,
+	  -356232960,  -618485248,  -870531648, -1109389568, -1330388864, -1530914688, -1707364224, -1856699264,
+	 -1977152768, -2066369536, -2123294080, -2146813056, -2136648448, -2092881536, -2016380928, -1908052096,
+	 -1770012928, -1604245376, -1412556800, -1199436928,  -967527168,  -719966528,  -461595264,  -195470768,
+	    73203872,   341249344,   603928128,   856628416,  1096359936,  1318435584,  1520227456,  1697790208,
+	  1849022720,  1971176448,  2062183936,  2120968192,  2146379904,  2138167424,  2096231680,  2021555072,
+	  1915209472,  1778567424,  1614300160,  1424351616,  1212435456,   981059520,   734254784,   476413888,
+	   210587936,   -58025768,  -326248672,  -588836608,  -842682304, -1083275520, -1306416256, -1509464192,
+	 -1688449280, -1840983936, -1965101568, -2057895040, -2118450432, -2145839488, -2139526912, -2099587200,
+	 -2026801408, -1922030720, -1787032960, -1624274176, -1435680256, -1224938624,  -994542912,  -748997696,
+	  -491208672,  -225694576,    42844768,   311231680,   574218816,   828210432,  1070136832,  1294331648,
+	  1498625408,  1679024000,  1833119744,  1958713472,  2053349888,  2115908864,  2145191680,  2140779392,
+	  2102723968,  2031769472,  1928755712,  1795696640,  1634166912,  1446937088,  1237380480,  1007976512,
+	   763210560,   506488416,   240789920,   -27661626,  -296199136,  -559572288,  -814179328, -1056488320,
+	 -1281761664, -1487333632, -1669514752, -1825163904, -1952439040, -2048851968, -2113261568, -2144436736,
+	 -2141924736, -2105755520, -2036636032, -1935384320, -1803980032, -1644315264, -1458121728, -1249760384,
+	 -1021359744,  -777385216,  -521232480,  -256393792,    11952822,   280632000,   544897728,   800107584,
+	  1043241536,  1269545984,  1476343040,  1659922048,  1817116672,  1946066944,  2044251520,  2110508672,
+	  2143542784,  2142963072,  2108681856,  2041400704,  1941916160,  1812173056,  1654041216,  1469615616,
+	  1262502016,  1034691840,   791520960,   535950432,   271463840,     3232335,  -265570096,  -530196000,
+	  -785995776, -1029942592, -1257266816, -1465278592, -1649910784, -1808978688, -1939597568, -2039548928,
+	 -2107650176, -2142569728, -2143924608, -2111598208, -2046222592, -1948350848, -1820275584, -1663684608,
+	 -1480651264, -1274754304, -1048429888,  -805617216,  -550641600,  -286520352,   -18417332,   250494928,
+	   514958752,   771844736,  1016592192,  1244924800,  1454140800,  1640149632,  1800464640,  1932802688,
+	  2034576640,  2104686336,  2141489408,  2144744832,  2114309760,  2050779392,  1954905216,  1828287104,
+	  1673244672,  1491612928,  1286942720,  1061656064,   820157696,   565305280,   301562528,    33601404,
+	  -235407216,  -500203840,  -757655040, -1002727296, -1232091264, -1442930432, -1630306560, -1792142976,
+	 -1926136320, -2029666816, -2101509376, -2140302080, -2145457920, -2116915712, -2055233536, -1961141376,
+	 -1836478848, -1682721152, -1502499840, -1299066752, -1074829184,  -834171200,  -579940672,  -317108160,
+	   -48783800,   220307760,   485423904,   742935616,   989274176,  1219623168,  1431257088,  1620381952,
+	  1783731840,  1919373696,  2024655488,  2098331520,  2138961152,  2146063744,  2119415808,  2059585152,
+	  1967279616,  1844304000,  1692113536,  1513683712,  1311125888,  1087948544,   848143040,   594547072,
+	   332118848,    64487808,  -204675376,  -470619712,  -728669632,  -975771520, -1207094272, -1419900416,
+	 -1610029440, -1775231488, -1912515072, -2019543040, -2095048832, -2137556224, -2146562176, -2121890688,
+	 -2063833600, -1973319296, -1852036864, -1701421184, -1524417280, -1323532416, -1101463552,  -862072384,
+	  -609626432,  -347112928,   -79664440,   189554288,   455279616,   714367168,   962220160,  1194504960,
+	  1408472832,  1599940608,  1766642304,  1905318912,  2014329472,  2091661312,  2136044288,  2146953344,
+	  2124174976,  2068120192,  1979463808,  1859677056,  1710960768,  1535074688,  1335457536,  1114471552,
+	   876437376,   624171584,   362089664,    94837096,  -174423728,  -440428320,  -700029056,  -948150208,
+	 -1181855872, -1396974848, -1589771648, -1757964928, -1898266112, -2008829952, -2088046848, -2134425600,
+	 -2147237120, -2126353152, -2072158592, -1985302528, -1867483264, -1720094976, -1545655296, -1347315968,
+	 -1127423872,  -890278336,  -638685504,  -377564448,  -110005000,   159284448,   425555008,   685655872,
+	   934501632,  1168707840,  1385006336,  1579523200,  1749199488,  1891118336,  2003411840,  2084446720,
+	  2132638720,  2147417728,  2128424960,  2076093312,  1991041920,  1874933888,  1729143040,  1556519808,
+	  1359106944,  1140319744,   904074816,   653167488,   392503552,   125690800,  -143614096,  -410660416,
+	  -671248448,  -920806336, -1155939328, -1373366784, -1568837888, -1740039424, -1883876096, -1997893504,
+	 -2080742400, -2130803072, -2147483008, -2130456320, -2079924224, -1996681856, -1882290816, -1738104704,
+	 -1566942720, -1370829952, -1153600896,  -917826048,  -667616832,  -407423040,  -140846720,   128459400,
+	   395229984,   656807424,   907065024,  1143112832,  1361658624,  1558429312,  1731096576,  1876284544,
+	  1992275200,  2076934016,  2128860800,  2147441024,  2132311424,  2083651072,  2002411392,  1889553664,
+	  1746979456,  1577287168,  1382885632,  1166380160,   932003776,   682032832,   422322176,   155995600,
+	  -113298288,  -380294400,  -641833280,  -892801472, -1130229248, -1349882368, -1547942784, -1722067200,
+	 -1868851072, -1986557312, -2072884864, -2126812160, -2147291648, -2134060032, -2087397120, -2007847808,
+	 -1896967680, -1755766912, -1587552768, -1394468608, -1179101056,  -945660928,  -696910592,  -437713472,
+	  -171136672,    98131512,   365339776,   627326208,   878968640,  1117289216,  1337628416,  1537378944,
+	  1712951680,  1861324032,  1980740224,  2068865536,  2124580736,  2147034880,  2135701888,  2090911872,
+	  2013183872,  1904037888,  1764765312,  1598089216,  1405981824,  1191762944,   959270784,   711256384,
+	   452568832,   186269200,   -82435928,  -350366912,  -612787840,  -865091776, -1104293248, -1325715456,
+	 -1526369536, -1703750528, -1853703936, -1974617984, -2064742656, -2122315776, -2146656256, -2137288064,
+	 -2094322048, -2018419328, -1911012864, -1773373696, -1608192768, -1417424768, -1204799360,  -972832704,
+	  -725566656,  -467401568,  -201392400,    67259968,   334858656,   598218752,   851171648,  1091242112,
+	  1313736192,  1515649792,  1694142080,  1845723264,  1968599552,  2060516480,  2119944704,  2146181120,
+	  2138712576,  2097627520,  2023553792,  1917892352,  1781893376,  1618215936,  1428796800,  1217339392,
+	   986811648,   739840640,   482210944,   216505536,   -52080640,  -319850944,  -583115200,  -836726208,
+	 -1077682816, -1301691136, -1504854272, -1684768000, -1837915008, -1962482816, -2056187392, -2117467648,
+	 -2145598592, -2140030080, -2100828160, -2028759168, -1924908416, -1790323968, -1628158208, -1440097408,
+	 -1229818624, -1000273920,  -754568448,  -497506240,  -232129072,    36898712,   304827232,   568486080,
+	   822720256,  1064521408,  1289581184,  1493983488,  1675309824,  1830014848,  1956267904,  2051600512,
+	  2114884736,  2144908800,  2141240704,  2103923712,  2033687552,  1931592448,  1798951424,  1638358016,
+	  1451326080,  1242236288,  1013686144,   768766464,   512265792,   247219392,   -21714938,  -289788288,
+	  -553828480,  -808673152, -1051306688, -1276985088, -1483038080, -1665767808, -1822023168, -1949955072,
+	 -2047062400, -2112101376, -2144082432, -2142380416, -2106914048, -2038514176, -1938179840, -1807199488,
+	 -1648134144, -1462866048, -1254591872, -1027047680,  -782925952,  -526999712,  -262297344,     6005791,
+	   274734816,   539143232,   794585600,  1038039360,  1264744576,  1471636736,  1655808768,  1813659776,
+	  1943544832,  2042422016,  2109303424,  2143174528,  2143373056,  2109896832,  2043239040,  1944670336,
+	  1815357184,  1657827840,  1473946368,  1267308032,  1040357824,   797046336,   541707264,   277362176,
+	     9179393,  -259667648,  -523922560,  -779969920, -1024720192, -1252440704, -1460541056, -1646097792,
+	 -1805483136, -1936811008, -2037679488, -2106400000, -2142159360, -2144258560, -2112672768, -2048019328,
+	 -1951063552, -1823424000, -1667438592, -1484953088, -1279535360, -1053616000,  -811612288,  -556387776,
+	  -292413152,   -24364118,   244066592,   509183232,   765802304,  1010887232,  1240074240,  1449372288,
+	  1636304512,  1797216256,  1930203392,  2032665856,  2103391232,  2141037184,  2145036928,  2115342976,
+	  2052536064,  1957359232,  1831673472,  1676966016,  1495885568,  1291698688,  1066821504,   825650816,
+	   571545856,   307968384,    39547628,  -228973792,  -494418432,  -751596416,  -997464512, -1227215616,
+	 -1438131072, -1626429440, -1788859520, -1923499264, -2027716352, -2100277376, -2139763584, -2145707904,
+	 -2117907456, -2056950016, -1963556992, -1839554432, -1686734208, -1507116672, -1303797376, -1080426752,
+	  -839648064,  -586168960,  -322988832,   -55253272,   213869536,   479628896,   737352896,   983991872,
+	  1214723584,  1426817920,  1616127872,  1780413440,  1916699008,  2022665344,  2097058432,  2138423680,
+	  2146289280,  2120449024,  2061261184,  1969865472,  1847343488,  1696090496,  1517896448,  1316245248,
+	  1093523072,   853603392,   600762752,   337993088,    70431960,  -198754592,  -464303520,  -723072576,
+	  -970470080, -1202171008, -1415433472, -1606087808, -1771581952, -1909563136, -2017513216, -2093734656,
+	 -2136976768, -2146742016, -2122797952, -2065612800, -1975863296, -1855040128, -1705362048, -1528600192,
+	 -1328210688, -1106564608,  -867995520,  -615326528,  -352980480,   -85607120,   183629696,   449465984,
+	   708261120,   956430400,  1189558144,  1403978112,  1595967488,  1762955136,  1902568192,  2012077056,
+	  2090185856,  2135423104,  2147087360,  2125040640,  2069714048,  1981762304,  1862904832,  1714548224,
+	  1539227520,  1340109696,  1119550848,   881863232,   630360768,   368466752,   100778000,  -168495632,
+	  -434605984,  -693907968,  -942810560, -1176447360, -1392053504, -1585767424, -1754240128, -1895478144,
+	 -2006720000, -2086649344, -2133703424, -2147325440, -2127177216, -2073711872, -1987562112, -1870412288,
+	 -1723648768, -1550140928, -1351941760, -1132481152,  -895686848,  -644861120,  -383417440,  -116467368,
+	   153353152,   419724256,   679520128,   929143616,  1163714176,  1380456064,  1575131648,  1745437440,
+	  1888293248,  2001262464,  2083008384,  2131932544,  2147456000,  2129275520,  2077606016,  1993262592,
+	  1877826304,  1732972800,  1560611072,  1364111232,  1145354752,   909465728,   659329344,   398348960,
+	   131627200,  -137679776,  -404306624,  -665098304,  -915430208, -1150922880, -1368789632, -1564770816,
+	 -1736547456, -1880761088, -1995705088, -2079263360, -2130055040, -2147478272, -2131195520, -2081525248,
+	 -1998863488, -1885146368, -1741897472, -1571003264, -1375805056, -1158612608,  -923672384,  -673764480,
+	  -413260544,  -146780448,   122522480,   389382976,   650643200,   901195136,  1138073984,  1357054592,
+	  1554331648,  1727570688,  1873384448,  1989850624,  2075414272,  2128071168,  2147390464,  2133009024,
+	  2085207680,  2004552448,  1892620032,  1750735104,  1581316992,  1387430144,  1171369088,   937358016,
+	   688166016,   428665248,   161926368,  -107359064,  -374439840,  -636155584,  -887389312, -1124721536,
+	 -1345251840, -1543814784, -1718193024, -1865914240, -1984090496, -2071323136, -2125906688, -2147195264,
+	 -2134715776, -2088786048, -2009949568, -1899747968, -1759485184, -1591551488, -1398985856, -1184066944,
+	  -950996736,  -702533120,  -443534016,  -177586672,    92190272,   359478016,   621636160,   873539136,
+	  1111757568,  1332970752,  1532853632,  1709040896,  1858350720,  1978231040,  2067263104,  2123706368,
+	  2146892800,  2136315776,  2092259840,  2015246336,  1906780928,  1768147200,  1602055680,  1410866944,
+	  1196705664,   964587904,   716865088,   458380608,   192715328,   -76492920,  -343980672,  -606582720,
+	  -859645248, -1098738048, -1321031808, -1522180352, -1699803392, -1850694400, -1972272640, -2063099776,
+	 -2121399808, -2146482944, -2137858688, -2095743488, -2020442240, -1913718528, -1776720896, -1612127872,
+	 -1422279808, -1209717760,  -978597568,  -731654144,  -473204288,  -207834336,    61315548,   328983040,
+	   592000832,   845708416,  1085663488,  1309026688,  1511430912,  1690480896,  1842676224,  1966215680,
+	  2058833280,  2118987264,  2145965696,  2139241344,  2099004416,  2025711232,  1920795008,  1785205760,
+	  1622119424,  1433621504,  1222233984,   992089920,   745912448,   488004288,   222942944,   -46135112,
+	  -313968992,  -577389312,  -831245888, -1072534720, -1296956160, -1500605824, -1681073920, -1834831872,
+	 -1959846144, -2054311168, -2116379904, -2145341184, -2140516992, -2102160256, -2030701312, -1927537536,
+	 -1793889664, -1632029824, -1444891520, -1234689152, -1005532608,  -760133440,  -503289632,  -238040416,
+	    30952372,   298939200,   562748992,   817223744,  1058896256,  1284400640,  1489328128,  1671582848,
+	  1826895872,  1953589504,  2049835520,  2113751808,  2144582272,  2141685632,  2105211008,  2035590016,
+	  1934183808,  1802192384,  1642196480,  1456089216,  1247082624,  1018925056,   774316416,   518039200,
+	   253125984,   -15243807,  -283894496,  -548080448,  -803160768, -1045659008, -1272198912, -1478350976,
+	 -1661676032, -1818868480, -1947235200, -2045257216, -2111018112, -2143739520, -2142781952, -2108156544,
+	 -2040376704, -1940733312, -1810405120, -1651940352, -1467214208, -1259838336, -1032266560,  -788460736,
+	  -532762912,  -268719072,
+};
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef _MONO_32BIT_PAD_WIDTH_H_
+#define _MONO_32BIT_PAD_WIDTH_H_
+
+extern const size_t MONO_32BIT_PAD_WIDTH_SAMPLE_NO;
+extern const int32_t mono_32bit_pad_width[];
+
+#define MONO_32BIT_PAD_WIDTH_SAMPLE_RATE 22050
+
+#endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t MONO_BIT_SAMPLE_NO = 44100;
+const size_t MONO_8BIT_SAMPLE_NO = 44100;
 
-const int8_t mono_bit[44100] = {
+#define MONO_8BIT_SAMPLE_RATE 44100
+
+const int8_t mono_8bit[44100] = {
 	 0, 7, 15, 23, 31, 39, 46, 53,
 	 61, 67, 74, 80, 86, 92, 97, 102,
 	 107, 111, 114, 117, 120, 122, 124, 125,
@@ -4,4 +4,6 @@
 extern const size_t MONO_8BIT_SAMPLE_NO;
 extern const int8_t mono_8bit[];
 
+#define MONO_8BIT_SAMPLE_RATE 44100
+
 #endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_BANNERS_H_
+#define _MONO_8BIT_BANNERS_H_
+
+extern const size_t MONO_8BIT_BANNERS_SAMPLE_NO;
+extern const int8_t mono_8bit_banners[];
+
+#define MONO_8BIT_BANNERS_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t MONO_BIT_BASE_SAMPLE_NO = 44100;
+const size_t MONO_8BIT_BASE16_SAMPLE_NO = 44100;
 
-const int8_t mono_bit_base[44100] = {
+#define MONO_8BIT_BASE16_SAMPLE_RATE 44100
+
+const int8_t mono_8bit_base16[44100] = {
 	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35,
 	 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
 	 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_INDENT_H_
+#define _MONO_8BIT_INDENT_H_
+
+extern const size_t MONO_8BIT_INDENT_SAMPLE_NO;
+extern const int8_t mono_8bit_indent[];
+
+#define MONO_8BIT_INDENT_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
@@ -0,0 +1,5519 @@
+const size_t MONO_8BIT_LAST_LINE_PAD_SAMPLE_NO = 44100;
+
+#define MONO_8BIT_LAST_LINE_PAD_SAMPLE_RATE 44100
+
+const int8_t mono_8bit_last_line_pad[44100] = {
+	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35,
+	 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
+	 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51,
+	 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18,
+	 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda,
+	 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5,
+	 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3,
+	 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8,
+	 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15,
+	 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38,
+	 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb,
+	 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf,
+	 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba,
+	 0xc0, 0xc7, 0xcf, 0xd6, 0xdd, 0xe5, 0xed, 0xf5,
+	 0xfd, 0x04, 0x0c, 0x14, 0x1b, 0x23, 0x2b, 0x32,
+	 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde,
+	 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0,
+	 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4,
+	 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x1a, 0x21, 0x29, 0x30, 0x38, 0x3f, 0x46, 0x4c,
+	 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b,
+	 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff,
+	 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2,
+	 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7,
+	 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f,
+	 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f,
+	 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1,
+	 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc2, 0xca, 0xd1,
+	 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e,
+	 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e,
+	 0x37, 0x30, 0x29, 0x21, 0x19, 0x11, 0x09, 0x01,
+	 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5,
+	 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97,
+	 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b,
+	 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59,
+	 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23,
+	 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe5,
+	 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad,
+	 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce,
+	 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b,
+	 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46,
+	 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d,
+	 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41,
+	 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05,
+	 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8,
+	 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea,
+	 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20, 0x28,
+	 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26,
+	 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8,
+	 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf,
+	 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a,
+	 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a,
+	 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca,
+	 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07,
+	 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43,
+	 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44,
+	 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09,
+	 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc,
+	 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae,
+	 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25,
+	 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a,
+	 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a,
+	 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98,
+	 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7,
+	 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03,
+	 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40,
+	 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d,
+	 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47,
+	 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf,
+	 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa8, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab,
+	 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3,
+	 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21,
+	 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58,
+	 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d,
+	 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef,
+	 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5,
+	 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4,
+	 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d,
+	 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b,
+	 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73,
+	 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a,
+	 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10,
+	 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2,
+	 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9,
+	 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0,
+	 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e,
+	 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63,
+	 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30,
+	 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3,
+	 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8,
+	 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f,
+	 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1,
+	 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd,
+	 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a,
+	 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d,
+	 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6,
+	 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2,
+	 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6,
+	 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc,
+	 0xe4, 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a,
+	 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53,
+	 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34,
+	 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff, 0xf7,
+	 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb,
+	 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa,
+	 0x01, 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36,
+	 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17,
+	 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9,
+	 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4,
+	 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9,
+	 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17,
+	 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50,
+	 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37,
+	 0x30, 0x28, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa,
+	 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe,
+	 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba,
+	 0xc1, 0xc8, 0xd0, 0xd7, 0xdf, 0xe6, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33,
+	 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53,
+	 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b,
+	 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd,
+	 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5,
+	 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13,
+	 0x1b, 0x23, 0x2a, 0x31, 0x39, 0x40, 0x46, 0x4d,
+	 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a,
+	 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe,
+	 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1,
+	 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2,
+	 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21, 0x28, 0x30,
+	 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62,
+	 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56,
+	 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e,
+	 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0,
+	 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f,
+	 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2,
+	 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f,
+	 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a,
+	 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d,
+	 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08, 0x00,
+	 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4,
+	 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5,
+	 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef,
+	 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c,
+	 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60,
+	 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58,
+	 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22,
+	 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4,
+	 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xcf,
+	 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c,
+	 0x14, 0x1b, 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47,
+	 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40,
+	 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7,
+	 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2,
+	 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb,
+	 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29,
+	 0x30, 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25,
+	 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7,
+	 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a,
+	 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb,
+	 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08,
+	 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44,
+	 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f,
+	 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44,
+	 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08,
+	 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb,
+	 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf,
+	 0xb5, 0xbc, 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8,
+	 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26,
+	 0x2d, 0x34, 0x3c, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d,
+	 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29,
+	 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb,
+	 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa8, 0xad, 0xb4, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41,
+	 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47,
+	 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe5, 0xdd, 0xd5, 0xce,
+	 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c,
+	 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac,
+	 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5, 0xdc, 0xe4,
+	 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22,
+	 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60,
+	 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c,
+	 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee,
+	 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4,
+	 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5,
+	 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01,
+	 0x09, 0x11, 0x19, 0x20, 0x28, 0x2f, 0x37, 0x3e,
+	 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1,
+	 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e,
+	 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x85, 0x88,
+	 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9,
+	 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1,
+	 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f,
+	 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56,
+	 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f,
+	 0x28, 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2,
+	 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2,
+	 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x33, 0x3b,
+	 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69,
+	 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x53, 0x4c,
+	 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5,
+	 0xcd, 0xc6, 0xbf, 0xb9, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7,
+	 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd,
+	 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b, 0x13, 0x1b,
+	 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53,
+	 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33,
+	 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba,
+	 0xb4, 0xad, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92,
+	 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe,
+	 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30, 0x37,
+	 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16,
+	 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8,
+	 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4,
+	 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda,
+	 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18,
+	 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a, 0x51,
+	 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76,
+	 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66,
+	 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36,
+	 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9,
+	 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb, 0xc4, 0xbd,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb,
+	 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7,
+	 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34,
+	 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52,
+	 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a,
+	 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc,
+	 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6,
+	 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6,
+	 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14,
+	 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e,
+	 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68,
+	 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39,
+	 0x32, 0x2b, 0x23, 0x1b, 0x14, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0,
+	 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8,
+	 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf3,
+	 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31,
+	 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d,
+	 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf,
+	 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8,
+	 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc4, 0xcc, 0xd3,
+	 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10,
+	 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e, 0x44, 0x4b,
+	 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c,
+	 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3,
+	 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95,
+	 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5,
+	 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0,
+	 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d,
+	 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61,
+	 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21,
+	 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3,
+	 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xd0,
+	 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48,
+	 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40,
+	 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6,
+	 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3,
+	 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe5, 0xec,
+	 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a,
+	 0x31, 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24,
+	 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6,
+	 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae,
+	 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc,
+	 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09,
+	 0x11, 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45,
+	 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43,
+	 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca,
+	 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0,
+	 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27,
+	 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c,
+	 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28,
+	 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea,
+	 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0,
+	 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99,
+	 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9,
+	 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06,
+	 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46,
+	 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a,
+	 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd,
+	 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad,
+	 0xb3, 0xba, 0xc0, 0xc7, 0xcf, 0xd6, 0xdd, 0xe5,
+	 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1b, 0x23,
+	 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b,
+	 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed,
+	 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3,
+	 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6,
+	 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02,
+	 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30, 0x38, 0x3f,
+	 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49,
+	 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e,
+	 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0,
+	 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa,
+	 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20,
+	 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57,
+	 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e,
+	 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1,
+	 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc2,
+	 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff,
+	 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c,
+	 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c,
+	 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19, 0x11,
+	 0x09, 0x01, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4,
+	 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8,
+	 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde,
+	 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c,
+	 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54,
+	 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64,
+	 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32,
+	 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xec, 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9,
+	 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf,
+	 0xc6, 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc,
+	 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38,
+	 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75,
+	 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e,
+	 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15,
+	 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7,
+	 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2,
+	 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb,
+	 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19,
+	 0x20, 0x28, 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51,
+	 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35,
+	 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc,
+	 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91,
+	 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91,
+	 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc,
+	 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8,
+	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35,
+	 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51,
+	 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18,
+	 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb,
+	 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3,
+	 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7,
+	 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15,
+	 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f,
+	 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38,
+	 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf,
+	 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f,
+	 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9,
+	 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5,
+	 0xfd, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32,
+	 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64,
+	 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde,
+	 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa7,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0,
+	 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4,
+	 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c,
+	 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b,
+	 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff,
+	 0xf7, 0xef, 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2,
+	 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6,
+	 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e,
+	 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61,
+	 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78,
+	 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57,
+	 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20,
+	 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2,
+	 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1,
+	 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e,
+	 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f,
+	 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02,
+	 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6,
+	 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97,
+	 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed,
+	 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b,
+	 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59,
+	 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23,
+	 0x1b, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5,
+	 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad,
+	 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd,
+	 0xd5, 0xdc, 0xe4, 0xec, 0xf3, 0xfb, 0x02, 0x0a,
+	 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46,
+	 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e,
+	 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42,
+	 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05,
+	 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9,
+	 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea,
+	 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20, 0x28,
+	 0x2f, 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26,
+	 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9,
+	 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca,
+	 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07,
+	 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43,
+	 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45,
+	 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19, 0x11, 0x09,
+	 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc,
+	 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae,
+	 0xb4, 0xba, 0xc1, 0xc8, 0xd0, 0xd7, 0xdf, 0xe6,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24,
+	 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a,
+	 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a,
+	 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7,
+	 0xce, 0xd5, 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03,
+	 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x31, 0x39, 0x40,
+	 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48,
+	 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d,
+	 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf,
+	 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3,
+	 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21,
+	 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58,
+	 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61,
+	 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d,
+	 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5,
+	 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95,
+	 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3,
+	 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d,
+	 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b,
+	 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b,
+	 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10,
+	 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3,
+	 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f,
+	 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8,
+	 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf,
+	 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d,
+	 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31,
+	 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3,
+	 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0,
+	 0xc7, 0xcf, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd,
+	 0x04, 0x0c, 0x14, 0x1b, 0x23, 0x2b, 0x32, 0x39,
+	 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6,
+	 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6,
+	 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc,
+	 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a,
+	 0x21, 0x29, 0x30, 0x38, 0x3f, 0x46, 0x4c, 0x52,
+	 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34,
+	 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7,
+	 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb,
+	 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd,
+	 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36,
+	 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76,
+	 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17,
+	 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda,
+	 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3,
+	 0xa9, 0xaf, 0xb5, 0xbc, 0xc2, 0xca, 0xd1, 0xd8,
+	 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16,
+	 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67,
+	 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37,
+	 0x30, 0x29, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb,
+	 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe,
+	 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92,
+	 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4, 0xba,
+	 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33,
+	 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53,
+	 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b,
+	 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xed, 0xe5, 0xdd,
+	 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7,
+	 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5,
+	 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13,
+	 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d,
+	 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69,
+	 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a,
+	 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe,
+	 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1,
+	 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2,
+	 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20, 0x28, 0x2f,
+	 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e,
+	 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0,
+	 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9,
+	 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f,
+	 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2,
+	 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f,
+	 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a,
+	 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e,
+	 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09, 0x01,
+	 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5,
+	 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4,
+	 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c,
+	 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60,
+	 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58,
+	 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22,
+	 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4,
+	 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c,
+	 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce,
+	 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b,
+	 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47,
+	 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41,
+	 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8,
+	 0xc1, 0xba, 0xb4, 0xad, 0xa8, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b,
+	 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1,
+	 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb,
+	 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29,
+	 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d,
+	 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25,
+	 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8,
+	 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a,
+	 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb,
+	 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08,
+	 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44,
+	 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f,
+	 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f,
+	 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44,
+	 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08,
+	 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb,
+	 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf,
+	 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7,
+	 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25,
+	 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e,
+	 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29,
+	 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb,
+	 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2,
+	 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04,
+	 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41,
+	 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71,
+	 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47,
+	 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce,
+	 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c,
+	 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac,
+	 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4,
+	 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22,
+	 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c,
+	 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef,
+	 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4,
+	 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc4,
+	 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01,
+	 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e,
+	 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2,
+	 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x88,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9,
+	 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0,
+	 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e,
+	 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56,
+	 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30,
+	 0x28, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2,
+	 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1,
+	 0xc8, 0xd0, 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a,
+	 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d,
+	 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5,
+	 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7,
+	 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd,
+	 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b,
+	 0x23, 0x2a, 0x32, 0x39, 0x40, 0x46, 0x4d, 0x53,
+	 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33,
+	 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6,
+	 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba,
+	 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa,
+	 0x01, 0x09, 0x11, 0x19, 0x21, 0x28, 0x30, 0x37,
+	 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50,
+	 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16,
+	 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8,
+	 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4,
+	 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9,
+	 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17,
+	 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50,
+	 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36,
+	 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9,
+	 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd,
+	 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb,
+	 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7,
+	 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34,
+	 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65,
+	 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52,
+	 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a,
+	 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc,
+	 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xcf, 0xd6,
+	 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14,
+	 0x1b, 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d,
+	 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a,
+	 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0,
+	 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8,
+	 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3,
+	 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30,
+	 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d,
+	 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf,
+	 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f,
+	 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3,
+	 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10,
+	 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b,
+	 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d,
+	 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00,
+	 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4,
+	 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96,
+	 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5,
+	 0xbc, 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0,
+	 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d,
+	 0x34, 0x3c, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60,
+	 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58,
+	 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21,
+	 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb, 0xe3,
+	 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa8, 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf,
+	 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48,
+	 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d,
+	 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40,
+	 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe5, 0xdd, 0xd5, 0xce, 0xc7,
+	 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98,
+	 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2,
+	 0xb9, 0xbf, 0xc6, 0xce, 0xd5, 0xdc, 0xe4, 0xec,
+	 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a,
+	 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a,
+	 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24,
+	 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6,
+	 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae,
+	 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc,
+	 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09,
+	 0x11, 0x19, 0x20, 0x28, 0x2f, 0x37, 0x3e, 0x45,
+	 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43,
+	 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca,
+	 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a,
+	 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a,
+	 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf,
+	 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8,
+	 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26,
+	 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c,
+	 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28,
+	 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea,
+	 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99,
+	 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9,
+	 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42,
+	 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e,
+	 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4c, 0x46,
+	 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd,
+	 0xc6, 0xbf, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad,
+	 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5,
+	 0xed, 0xf5, 0xfd, 0x03, 0x0b, 0x13, 0x1b, 0x23,
+	 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59,
+	 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b,
+	 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee,
+	 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4,
+	 0xad, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97,
+	 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5,
+	 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02,
+	 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e,
+	 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49,
+	 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e,
+	 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8, 0xd1,
+	 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa,
+	 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f,
+	 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57,
+	 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78,
+	 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62,
+	 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f,
+	 0x27, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1,
+	 0xe9, 0xe2, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2,
+	 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff,
+	 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b,
+	 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c,
+	 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12,
+	 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4,
+	 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0,
+	 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7,
+	 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde,
+	 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c,
+	 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54,
+	 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64,
+	 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32,
+	 0x2b, 0x23, 0x1b, 0x14, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9,
+	 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf,
+	 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38,
+	 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15,
+	 0x0d, 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7,
+	 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3,
+	 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda,
+	 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18,
+	 0x20, 0x28, 0x2f, 0x36, 0x3e, 0x44, 0x4b, 0x51,
+	 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35,
+	 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91,
+	 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc,
+	 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8,
+	 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35,
+	 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52,
+	 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19,
+	 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb,
+	 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xd0, 0xd7,
+	 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15,
+	 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e,
+	 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39,
+	 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0,
+	 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9,
+	 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe5, 0xec, 0xf4,
+	 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x31,
+	 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c,
+	 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde,
+	 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4,
+	 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11,
+	 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b,
+	 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c,
+	 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3,
+	 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6,
+	 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e,
+	 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61,
+	 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20,
+	 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2,
+	 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa,
+	 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e,
+	 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0,
+	 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e,
+	 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f,
+	 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02,
+	 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6,
+	 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3,
+	 0xba, 0xc0, 0xc7, 0xcf, 0xd6, 0xdd, 0xe5, 0xed,
+	 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1b, 0x23, 0x2b,
+	 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23,
+	 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5,
+	 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad,
+	 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b,
+	 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd,
+	 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a,
+	 0x12, 0x1a, 0x21, 0x29, 0x30, 0x38, 0x3f, 0x46,
+	 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42,
+	 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06,
+	 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9,
+	 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0,
+	 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20, 0x27,
+	 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27,
+	 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9,
+	 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc2, 0xca,
+	 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06,
+	 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45,
+	 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19, 0x11, 0x09,
+	 0x01, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc,
+	 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad,
+	 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24,
+	 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e,
+	 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a,
+	 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3,
+	 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6,
+	 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03,
+	 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f,
+	 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71,
+	 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48,
+	 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d,
+	 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0,
+	 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d,
+	 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2,
+	 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20,
+	 0x28, 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57,
+	 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e,
+	 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6,
+	 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d,
+	 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95,
+	 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3,
+	 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00,
+	 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c,
+	 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b,
+	 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11,
+	 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3,
+	 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8,
+	 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf,
+	 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d,
+	 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55,
+	 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31,
+	 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93,
+	 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0,
+	 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd,
+	 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39,
+	 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68,
+	 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6,
+	 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa8, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6,
+	 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb,
+	 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19,
+	 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52,
+	 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34,
+	 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7,
+	 0xef, 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc,
+	 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd,
+	 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36,
+	 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66,
+	 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76,
+	 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51,
+	 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18,
+	 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda,
+	 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3,
+	 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8,
+	 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16,
+	 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67,
+	 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38,
+	 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb,
+	 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf,
+	 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93,
+	 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba,
+	 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5,
+	 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x32,
+	 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53,
+	 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd,
+	 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7,
+	 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5,
+	 0xdc, 0xe4, 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c,
+	 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b,
+	 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff,
+	 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2,
+	 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2,
+	 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f,
+	 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f,
+	 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1,
+	 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1,
+	 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f,
+	 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49,
+	 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e,
+	 0x37, 0x30, 0x28, 0x21, 0x19, 0x11, 0x09, 0x01,
+	 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5,
+	 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xdf, 0xe6, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c,
+	 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60,
+	 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59,
+	 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22,
+	 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4,
+	 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce,
+	 0xd5, 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b,
+	 0x13, 0x1b, 0x23, 0x2a, 0x31, 0x39, 0x40, 0x46,
+	 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41,
+	 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05,
+	 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8,
+	 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb,
+	 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21, 0x28,
+	 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d,
+	 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b,
+	 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26,
+	 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8,
+	 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a,
+	 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb,
+	 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07,
+	 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43,
+	 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44,
+	 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08,
+	 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb,
+	 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae,
+	 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7,
+	 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25,
+	 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29,
+	 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb,
+	 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7,
+	 0xcf, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x04,
+	 0x0c, 0x14, 0x1b, 0x23, 0x2b, 0x32, 0x39, 0x40,
+	 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47,
+	 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf,
+	 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac,
+	 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3,
+	 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21,
+	 0x29, 0x30, 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d,
+	 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef,
+	 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5,
+	 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4,
+	 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d,
+	 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72,
+	 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10,
+	 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2,
+	 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9,
+	 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd8, 0xe0,
+	 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e,
+	 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62,
+	 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30,
+	 0x29, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3,
+	 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4, 0xba, 0xc1,
+	 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a,
+	 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d,
+	 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe5, 0xdd, 0xd5,
+	 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1,
+	 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6,
+	 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5, 0xdc,
+	 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a,
+	 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53,
+	 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65,
+	 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33,
+	 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6,
+	 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb,
+	 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa,
+	 0x01, 0x09, 0x11, 0x19, 0x20, 0x28, 0x2f, 0x37,
+	 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17,
+	 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9,
+	 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4,
+	 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x85,
+	 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4,
+	 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9,
+	 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17,
+	 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50,
+	 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37,
+	 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa,
+	 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe,
+	 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb,
+	 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x33,
+	 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65,
+	 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53,
+	 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a,
+	 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc,
+	 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1,
+	 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6,
+	 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b, 0x13,
+	 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d,
+	 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a,
+	 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1,
+	 0xba, 0xb4, 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f,
+	 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8,
+	 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3,
+	 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30,
+	 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63,
+	 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e,
+	 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0,
+	 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f,
+	 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2,
+	 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10,
+	 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a,
+	 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72,
+	 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b,
+	 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d,
+	 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08, 0x00,
+	 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb, 0xc4,
+	 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5,
+	 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef,
+	 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d,
+	 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60,
+	 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58,
+	 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21,
+	 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3,
+	 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab,
+	 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf,
+	 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c,
+	 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47,
+	 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d,
+	 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40,
+	 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x13, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7,
+	 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98,
+	 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2,
+	 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec,
+	 0xf4, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29,
+	 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25,
+	 0x1d, 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef, 0xe7,
+	 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae,
+	 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc4, 0xcc,
+	 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09,
+	 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e, 0x44,
+	 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f,
+	 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43,
+	 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca,
+	 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf,
+	 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8,
+	 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26,
+	 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b,
+	 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28,
+	 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea,
+	 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41,
+	 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46,
+	 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce,
+	 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac,
+	 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe5,
+	 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23,
+	 0x2a, 0x31, 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c,
+	 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee,
+	 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4,
+	 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5,
+	 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01,
+	 0x09, 0x11, 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e,
+	 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49,
+	 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1,
+	 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa,
+	 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f,
+	 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56,
+	 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f,
+	 0x28, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2,
+	 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7,
+	 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2,
+	 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff,
+	 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b,
+	 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a,
+	 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c,
+	 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12,
+	 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4,
+	 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7,
+	 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xce, 0xd6, 0xde,
+	 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1b,
+	 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54,
+	 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32,
+	 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba,
+	 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf,
+	 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30, 0x38,
+	 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16,
+	 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8,
+	 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5,
+	 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda,
+	 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18,
+	 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51,
+	 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36,
+	 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9,
+	 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc,
+	 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7,
+	 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34,
+	 0x3c, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x61, 0x65,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52,
+	 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19,
+	 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb,
+	 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa8, 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7,
+	 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x14,
+	 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e,
+	 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68,
+	 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39,
+	 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xec, 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0,
+	 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93,
+	 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9,
+	 0xbf, 0xc6, 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4,
+	 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31,
+	 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55,
+	 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d,
+	 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf,
+	 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8,
+	 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3,
+	 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11,
+	 0x19, 0x20, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b,
+	 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c,
+	 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3,
+	 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95,
+	 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e,
+	 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6,
+	 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0,
+	 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e,
+	 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61,
+	 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20,
+	 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2,
+	 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d,
+	 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0,
+	 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x15, 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48,
+	 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71,
+	 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f,
+	 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6,
+	 0xbf, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3,
+	 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed,
+	 0xf5, 0xfd, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a,
+	 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f,
+	 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24,
+	 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6,
+	 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad,
+	 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b,
+	 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd,
+	 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a,
+	 0x12, 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45,
+	 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42,
+	 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06,
+	 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8, 0xd1, 0xc9,
+	 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0,
+	 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27,
+	 0x2e, 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c,
+	 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c,
+	 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27,
+	 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9,
+	 0xe2, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9,
+	 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06,
+	 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45,
+	 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a,
+	 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd,
+	 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b,
+	 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad,
+	 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6,
+	 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24,
+	 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f,
+	 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b,
+	 0x23, 0x1b, 0x13, 0x0c, 0x04, 0xfd, 0xf5, 0xed,
+	 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3,
+	 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6,
+	 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf3, 0xfb, 0x02,
+	 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f,
+	 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71,
+	 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48,
+	 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d,
+	 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0,
+	 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e,
+	 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2,
+	 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20,
+	 0x28, 0x2f, 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57,
+	 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e,
+	 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e,
+	 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3,
+	 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00,
+	 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c,
+	 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b,
+	 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19, 0x11,
+	 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3,
+	 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8,
+	 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xd0, 0xd7, 0xdf,
+	 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d,
+	 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54,
+	 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31,
+	 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0,
+	 0xc7, 0xce, 0xd5, 0xdd, 0xe5, 0xec, 0xf4, 0xfc,
+	 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39,
+	 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15,
+	 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7,
+	 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb,
+	 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19,
+	 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52,
+	 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66,
+	 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35,
+	 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc,
+	 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91,
+	 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd,
+	 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35,
+	 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
+	 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51,
+	 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18,
+	 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda,
+	 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5,
+	 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3,
+	 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8,
+	 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15,
+	 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38,
+	 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb,
+	 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf,
+	 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba,
+	 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5,
+	 0xfd, 0x04, 0x0c, 0x14, 0x1b, 0x23, 0x2b, 0x32,
+	 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde,
+	 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0,
+	 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4,
+	 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x1a, 0x21, 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c,
+	 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b,
+	 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff,
+	 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2,
+	 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7,
+	 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f,
+	 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f,
+	 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1,
+	 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc2, 0xca, 0xd1,
+	 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e,
+	 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e,
+	 0x37, 0x30, 0x29, 0x21, 0x19, 0x11, 0x09, 0x01,
+	 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5,
+	 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97,
+	 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b,
+	 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59,
+	 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23,
+	 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe5,
+	 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad,
+	 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xcd,
+	 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b,
+	 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46,
+	 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d,
+	 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41,
+	 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05,
+	 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8,
+	 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea,
+	 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20, 0x28,
+	 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26,
+	 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8,
+	 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf,
+	 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a,
+	 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a,
+	 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca,
+	 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07,
+	 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43,
+	 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44,
+	 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09,
+	 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc,
+	 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae,
+	 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25,
+	 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a,
+	 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a,
+	 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98,
+	 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7,
+	 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03,
+	 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40,
+	 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d,
+	 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47,
+	 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf,
+	 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa7, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab,
+	 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3,
+	 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21,
+	 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58,
+	 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d,
+	 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef,
+	 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5,
+	 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4,
+	 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d,
+	 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b,
+	 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73,
+	 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a,
+	 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10,
+	 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2,
+	 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9,
+	 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0,
+	 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e,
+	 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63,
+	 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30,
+	 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3,
+	 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8,
+	 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f,
+	 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1,
+	 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd,
+	 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a,
+	 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d,
+	 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6,
+	 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2,
+	 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6,
+	 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc,
+	 0xe4, 0xec, 0xf4, 0xfb, 0x02, 0x0a, 0x12, 0x1a,
+	 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53,
+	 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34,
+	 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf7,
+	 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb,
+	 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa,
+	 0x01, 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36,
+	 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17,
+	 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9,
+	 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4,
+	 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9,
+	 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17,
+	 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50,
+	 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37,
+	 0x30, 0x28, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa,
+	 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe,
+	 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba,
+	 0xc1, 0xc8, 0xd0, 0xd7, 0xdf, 0xe6, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33,
+	 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53,
+	 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b,
+	 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd,
+	 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5,
+	 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13,
+	 0x1b, 0x23, 0x2a, 0x31, 0x39, 0x40, 0x46, 0x4d,
+	 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a,
+	 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe,
+	 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1,
+	 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2,
+	 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21, 0x28, 0x30,
+	 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62,
+	 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56,
+	 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e,
+	 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0,
+	 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f,
+	 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2,
+	 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f,
+	 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a,
+	 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d,
+	 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08, 0x00,
+	 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4,
+	 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5,
+	 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef,
+	 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c,
+	 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60,
+	 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58,
+	 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22,
+	 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4,
+	 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xcf,
+	 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c,
+	 0x14, 0x1c, 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47,
+	 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40,
+	 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7,
+	 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2,
+	 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb,
+	 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29,
+	 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25,
+	 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7,
+	 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a,
+	 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb,
+	 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08,
+	 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44,
+	 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f,
+	 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44,
+	 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08,
+	 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb,
+	 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf,
+	 0xb5, 0xbc, 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8,
+	 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26,
+	 0x2d, 0x34, 0x3c, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d,
+	 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29,
+	 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb,
+	 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa8, 0xad, 0xb4, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41,
+	 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47,
+	 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe5, 0xdd, 0xd5, 0xce,
+	 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c,
+	 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac,
+	 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5, 0xdc, 0xe4,
+	 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22,
+	 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60,
+	 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c,
+	 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee,
+	 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4,
+	 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5,
+	 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01,
+	 0x09, 0x11, 0x19, 0x20, 0x28, 0x2f, 0x37, 0x3e,
+	 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1,
+	 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e,
+	 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x85, 0x88,
+	 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9,
+	 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1,
+	 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f,
+	 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56,
+	 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f,
+	 0x28, 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2,
+	 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2,
+	 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x33, 0x3b,
+	 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69,
+	 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x53, 0x4c,
+	 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5,
+	 0xcd, 0xc6, 0xbf, 0xb9, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7,
+	 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd,
+	 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b, 0x13, 0x1b,
+	 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53,
+	 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33,
+	 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba,
+	 0xb4, 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92,
+	 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe,
+	 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30, 0x37,
+	 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16,
+	 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8,
+	 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4,
+	 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda,
+	 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18,
+	 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a, 0x51,
+	 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76,
+	 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66,
+	 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36,
+	 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9,
+	 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb, 0xc4, 0xbd,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb,
+	 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7,
+	 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34,
+	 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52,
+	 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a,
+	 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc,
+	 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6,
+	 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6,
+	 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14,
+	 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e,
+	 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68,
+	 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39,
+	 0x32, 0x2b, 0x23, 0x1b, 0x13, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0,
+	 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8,
+	 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf3,
+	 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31,
+	 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d,
+	 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf,
+	 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8,
+	 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbd, 0xc4, 0xcc, 0xd3,
+	 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10,
+	 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e, 0x44, 0x4b,
+	 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c,
+	 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3,
+	 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95,
+	 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5,
+	 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0,
+	 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d,
+	 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61,
+	 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21,
+	 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3,
+	 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xd0,
+	 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48,
+	 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40,
+	 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6,
+	 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3,
+	 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe4, 0xec,
+	 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a,
+	 0x31, 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24,
+	 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6,
+	 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae,
+	 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc,
+	 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09,
+	 0x11, 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45,
+	 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43,
+	 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca,
+	 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0,
+	 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27,
+	 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c,
+	 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28,
+	 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea,
+	 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0,
+	 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99,
+	 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9,
+	 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06,
+	 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46,
+	 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a,
+	 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd,
+	 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad,
+	 0xb3, 0xba, 0xc0, 0xc7, 0xcf, 0xd6, 0xdd, 0xe5,
+	 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x23,
+	 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b,
+	 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed,
+	 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3,
+	 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6,
+	 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02,
+	 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30, 0x38, 0x3f,
+	 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49,
+	 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e,
+	 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0,
+	 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa,
+	 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20,
+	 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57,
+	 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e,
+	 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1,
+	 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc2,
+	 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff,
+	 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c,
+	 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c,
+	 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19, 0x11,
+	 0x09, 0x01, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4,
+	 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8,
+	 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde,
+	 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c,
+	 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54,
+	 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64,
+	 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32,
+	 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xed, 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9,
+	 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf,
+	 0xc6, 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc,
+	 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38,
+	 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75,
+	 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e,
+	 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15,
+	 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7,
+	 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2,
+	 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb,
+	 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19,
+	 0x20, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x51,
+	 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35,
+	 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc,
+	 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91,
+	 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91,
+	 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc,
+	 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8,
+	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35,
+	 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51,
+	 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18,
+	 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb,
+	 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3,
+	 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7,
+	 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15,
+	 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f,
+	 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38,
+	 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf,
+	 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f,
+	 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9,
+	 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5,
+	 0xfd, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32,
+	 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64,
+	 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde,
+	 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa8,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0,
+	 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4,
+	 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c,
+	 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b,
+	 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff,
+	 0xf7, 0xef, 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2,
+	 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6,
+	 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e,
+	 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61,
+	 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78,
+	 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57,
+	 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20,
+	 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2,
+	 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1,
+	 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e,
+	 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f,
+	 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02,
+	 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6,
+	 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97,
+	 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed,
+	 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b,
+	 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59,
+	 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23,
+	 0x1b, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5,
+	 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad,
+	 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd,
+	 0xd5, 0xdc, 0xe4, 0xec, 0xf3, 0xfb, 0x02, 0x0a,
+	 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46,
+	 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e,
+	 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42,
+	 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05,
+	 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9,
+	 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea,
+	 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20, 0x28,
+	 0x2f, 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26,
+	 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9,
+	 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca,
+	 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07,
+	 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43,
+	 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45,
+	 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19, 0x11, 0x09,
+	 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc,
+	 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae,
+	 0xb4, 0xba, 0xc1, 0xc8, 0xd0, 0xd7, 0xdf, 0xe6,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24,
+	 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a,
+	 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a,
+	 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7,
+	 0xce, 0xd5, 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03,
+	 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40,
+	 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48,
+	 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d,
+	 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf,
+	 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3,
+	 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21,
+	 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58,
+	 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61,
+	 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d,
+	 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5,
+	 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95,
+	 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3,
+	 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d,
+	 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b,
+	 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b,
+	 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10,
+	 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3,
+	 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f,
+	 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8,
+	 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf,
+	 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d,
+	 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31,
+	 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3,
+	 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0,
+	 0xc7, 0xcf, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd,
+	 0x04, 0x0c, 0x14, 0x1c, 0x23, 0x2b, 0x32, 0x39,
+	 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6,
+	 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6,
+	 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc,
+	 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a,
+	 0x21, 0x29, 0x30, 0x38, 0x3f, 0x46, 0x4c, 0x52,
+	 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34,
+	 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7,
+	 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb,
+	 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd,
+	 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36,
+	 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76,
+	 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17,
+	 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xd9,
+	 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3,
+	 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd8,
+	 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16,
+	 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67,
+	 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37,
+	 0x30, 0x29, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb,
+	 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe,
+	 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92,
+	 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba,
+	 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33,
+	 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53,
+	 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b,
+	 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe5, 0xdd,
+	 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7,
+	 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5,
+	 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13,
+	 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d,
+	 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69,
+	 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a,
+	 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe,
+	 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1,
+	 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2,
+	 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20, 0x28, 0x2f,
+	 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e,
+	 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0,
+	 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9,
+	 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f,
+	 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2,
+	 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f,
+	 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a,
+	 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e,
+	 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09, 0x01,
+	 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5,
+	 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4,
+	 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c,
+	 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60,
+	 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58,
+	 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22,
+	 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4,
+	 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c,
+	 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce,
+	 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b,
+	 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47,
+	 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41,
+	 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8,
+	 0xc1, 0xba, 0xb4, 0xad, 0xa8, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b,
+	 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1,
+	 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb,
+	 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29,
+	 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d,
+	 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25,
+	 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8,
+	 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a,
+	 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb,
+	 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08,
+	 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44,
+	 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f,
+	 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f,
+	 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44,
+	 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08,
+	 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb,
+	 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf,
+	 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7,
+	 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25,
+	 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e,
+	 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29,
+	 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb,
+	 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2,
+	 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04,
+	 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41,
+	 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71,
+	 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47,
+	 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce,
+	 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c,
+	 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac,
+	 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4,
+	 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22,
+	 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c,
+	 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef,
+	 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4,
+	 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9b, 0x9f, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc4,
+	 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01,
+	 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e,
+	 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2,
+	 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x88,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9,
+	 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0,
+	 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e,
+	 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56,
+	 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30,
+	 0x28, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2,
+	 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1,
+	 0xc8, 0xcf, 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a,
+	 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d,
+	 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5,
+	 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7,
+	 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd,
+	 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b,
+	 0x23, 0x2a, 0x32, 0x39, 0x40, 0x46, 0x4d, 0x53,
+	 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33,
+	 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6,
+	 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba,
+	 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa,
+	 0x01, 0x09, 0x11, 0x19, 0x21, 0x28, 0x30, 0x37,
+	 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50,
+	 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16,
+	 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8,
+	 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4,
+	 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9,
+	 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17,
+	 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50,
+	 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36,
+	 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9,
+	 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd,
+	 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb,
+	 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7,
+	 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34,
+	 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65,
+	 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52,
+	 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a,
+	 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc,
+	 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xce, 0xd6,
+	 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14,
+	 0x1b, 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d,
+	 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a,
+	 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0,
+	 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8,
+	 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3,
+	 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30,
+	 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d,
+	 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf,
+	 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f,
+	 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3,
+	 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10,
+	 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b,
+	 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d,
+	 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00,
+	 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4,
+	 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96,
+	 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5,
+	 0xbc, 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0,
+	 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d,
+	 0x34, 0x3c, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x61,
+	 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58,
+	 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21,
+	 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb, 0xe3,
+	 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa8, 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf,
+	 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48,
+	 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d,
+	 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40,
+	 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe5, 0xdd, 0xd5, 0xce, 0xc7,
+	 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98,
+	 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2,
+	 0xb9, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec,
+	 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a,
+	 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a,
+	 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24,
+	 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6,
+	 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae,
+	 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc,
+	 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09,
+	 0x11, 0x19, 0x20, 0x28, 0x2f, 0x37, 0x3e, 0x45,
+	 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43,
+	 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca,
+	 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a,
+	 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a,
+	 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf,
+	 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8,
+	 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26,
+	 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c,
+	 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28,
+	 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea,
+	 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99,
+	 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9,
+	 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42,
+	 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e,
+	 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x53, 0x4c, 0x46,
+	 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd,
+	 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad,
+	 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5,
+	 0xed, 0xf5, 0xfd, 0x03, 0x0b, 0x13, 0x1b, 0x23,
+	 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59,
+	 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b,
+	 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee,
+	 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4,
+	 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97,
+	 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5,
+	 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02,
+	 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e,
+	 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49,
+	 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e,
+	 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8, 0xd1,
+	 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa,
+	 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f,
+	 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57,
+	 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78,
+	 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62,
+	 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f,
+	 0x27, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1,
+	 0xe9, 0xe2, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2,
+	 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff,
+	 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b,
+	 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c,
+	 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12,
+	 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4,
+	 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0,
+	 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7,
+	 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde,
+	 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c,
+	 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54,
+	 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64,
+	 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32,
+	 0x2b, 0x23, 0x1b, 0x13, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9,
+	 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf,
+	 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38,
+	 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15,
+	 0x0d, 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7,
+	 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3,
+	 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda,
+	 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18,
+	 0x20, 0x28, 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51,
+	 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35,
+	 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91,
+	 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc,
+	 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8,
+	 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35,
+	 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52,
+	 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19,
+	 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb,
+	 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xd0, 0xd7,
+	 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15,
+	 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e,
+	 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39,
+	 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0,
+	 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9,
+	 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe5, 0xec, 0xf4,
+	 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x31,
+	 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c,
+	 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde,
+	 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4,
+	 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11,
+	 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b,
+	 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c,
+	 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3,
+	 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6,
+	 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e,
+	 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61,
+	 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20,
+	 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2,
+	 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa,
+	 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x94, 0x99, 0x9e,
+	 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0,
+	 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e,
+	 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f,
+	 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02,
+	 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6,
+	 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3,
+	 0xba, 0xc0, 0xc7, 0xcf, 0xd6, 0xde, 0xe5, 0xed,
+	 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1b, 0x23, 0x2b,
+	 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23,
+	 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5,
+	 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad,
+	 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b,
+	 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd,
+	 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a,
+	 0x12, 0x1a, 0x21, 0x29, 0x31, 0x38, 0x3f, 0x46,
+	 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42,
+	 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06,
+	 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9,
+	 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0,
+	 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20, 0x27,
+	 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27,
+	 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9,
+	 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca,
+	 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06,
+	 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45,
+	 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19, 0x11, 0x09,
+	 0x01, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc,
+	 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad,
+	 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24,
+	 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e,
+	 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a,
+	 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3,
+	 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6,
+	 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03,
+	 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f,
+	 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71,
+	 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48,
+	 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d,
+	 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0,
+	 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d,
+	 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2,
+	 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20,
+	 0x28, 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57,
+	 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e,
+	 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6,
+	 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d,
+	 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95,
+	 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3,
+	 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00,
+	 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c,
+	 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b,
+	 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11,
+	 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3,
+	 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8,
+	 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf,
+	 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d,
+	 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55,
+	 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31,
+	 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93,
+	 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0,
+	 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd,
+	 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39,
+	 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68,
+	 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6,
+	 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa7, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6,
+	 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb,
+	 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19,
+	 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52,
+	 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34,
+	 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7,
+	 0xef, 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc,
+	 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd,
+	 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36,
+	 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66,
+	 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76,
+	 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51,
+	 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18,
+	 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda,
+	 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3,
+	 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8,
+	 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16,
+	 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67,
+	 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38,
+	 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb,
+	 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf,
+	 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93,
+	 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba,
+	 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5,
+	 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x33,
+	 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53,
+	 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd,
+	 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7,
+	 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5,
+	 0xdc, 0xe4, 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c,
+	 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b,
+	 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff,
+	 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2,
+	 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2,
+	 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f,
+	 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f,
+	 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1,
+	 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1,
+	 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f,
+	 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49,
+	 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e,
+	 0x37, 0x30, 0x28, 0x21, 0x19, 0x11, 0x09, 0x01,
+	 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5,
+	 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4,
+	 0xba, 0xc1, 0xc8, 0xd0, 0xd7, 0xdf, 0xe6, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c,
+	 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60,
+	 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59,
+	 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22,
+	 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4,
+	 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce,
+	 0xd5, 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b,
+	 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40, 0x46,
+	 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41,
+	 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05,
+	 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8,
+	 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb,
+	 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21, 0x28,
+	 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d,
+	 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b,
+	 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26,
+	 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8,
+	 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a,
+	 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb,
+	 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07,
+	 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43,
+	 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44,
+	 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08,
+	 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb,
+	 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae,
+	 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7,
+	 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25,
+	 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29,
+	 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb,
+	 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7,
+	 0xcf, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x04,
+	 0x0c, 0x14, 0x1c, 0x23, 0x2b, 0x32, 0x39, 0x40,
+	 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47,
+	 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf,
+	 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac,
+	 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3,
+	 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21,
+	 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d,
+	 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef,
+	 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5,
+	 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4,
+	 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d,
+	 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72,
+	 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10,
+	 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2,
+	 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9,
+	 0xaf, 0xb5, 0xbc, 0xc2, 0xca, 0xd1, 0xd8, 0xe0,
+	 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e,
+	 0x26, 0x2d, 0x34, 0x3c, 0x43, 0x49, 0x4f, 0x55,
+	 0x5b, 0x61, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62,
+	 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30,
+	 0x29, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3,
+	 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4, 0xba, 0xc1,
+	 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a,
+	 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d,
+	 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xed, 0xe5, 0xdd, 0xd5,
+	 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1,
+	 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6,
+	 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5, 0xdc,
+	 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a,
+	 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53,
+	 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65,
+	 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33,
+	 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6,
+	 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb,
+	 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa,
+	 0x01, 0x09, 0x11, 0x19, 0x20, 0x28, 0x30, 0x37,
+	 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17,
+	 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9,
+	 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4,
+	 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x85,
+	 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4,
+	 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9,
+	 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17,
+	 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50,
+	 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37,
+	 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa,
+	 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe,
+	 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb,
+	 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x33,
+	 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65,
+	 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53,
+	 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a,
+	 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc,
+	 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1,
+	 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6,
+	 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b, 0x13,
+	 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d,
+	 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a,
+	 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1,
+	 0xba, 0xb4, 0xad, 0xa8, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f,
+	 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8,
+	 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3,
+	 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30,
+	 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63,
+	 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e,
+	 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0,
+	 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f,
+	 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2,
+	 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10,
+	 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a,
+	 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72,
+	 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b,
+	 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d,
+	 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08, 0x00,
+	 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb, 0xc4,
+	 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5,
+	 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef,
+	 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d,
+	 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60,
+	 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58,
+	 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21,
+	 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3,
+	 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab,
+	 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf,
+	 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c,
+	 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47,
+	 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d,
+	 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40,
+	 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7,
+	 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98,
+	 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2,
+	 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec,
+	 0xf4, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29,
+	 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25,
+	 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf7, 0xef, 0xe7,
+	 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae,
+	 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbd, 0xc4, 0xcc,
+	 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09,
+	 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e, 0x44,
+	 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f,
+	 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43,
+	 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca,
+	 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf,
+	 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8,
+	 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26,
+	 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b,
+	 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28,
+	 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea,
+	 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8,
+	 0xd0, 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41,
+	 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46,
+	 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce,
+	 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac,
+	 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe5,
+	 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23,
+	 0x2a, 0x32, 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c,
+	 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee,
+	 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4,
+	 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5,
+	 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01,
+	 0x09, 0x11, 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e,
+	 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49,
+	 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1,
+	 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa,
+	 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f,
+	 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56,
+	 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f,
+	 0x28, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2,
+	 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7,
+	 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2,
+	 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff,
+	 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b,
+	 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a,
+	 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c,
+	 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12,
+	 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4,
+	 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7,
+	 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xce, 0xd6, 0xdd,
+	 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1b,
+	 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54,
+	 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32,
+	 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba,
+	 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf,
+	 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30, 0x38,
+	 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16,
+	 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8,
+	 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5,
+	 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda,
+	 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18,
+	 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51,
+	 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36,
+	 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9,
+	 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc,
+	 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7,
+	 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34,
+	 0x3c, 0x43, 0x49, 0x4f, 0x55, 0x5b, 0x61, 0x65,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52,
+	 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19,
+	 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb,
+	 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa8, 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7,
+	 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0c, 0x14,
+	 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e,
+	 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68,
+	 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39,
+	 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xed, 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0,
+	 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93,
+	 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9,
+	 0xbf, 0xc6, 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4,
+	 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31,
+	 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55,
+	 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d,
+	 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf,
+	 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8,
+	 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3,
+	 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11,
+	 0x19, 0x20, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b,
+	 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c,
+	 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3,
+	 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95,
+	 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e,
+	 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6,
+	 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0,
+	 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e,
+	 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61,
+	 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20,
+	 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2,
+	 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d,
+	 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0,
+	 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x15, 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48,
+	 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71,
+	 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f,
+	 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6,
+	 0xbf, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3,
+	 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed,
+	 0xf5, 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a,
+	 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f,
+	 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24,
+	 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6,
+	 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad,
+	 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b,
+	 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd,
+	 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a,
+	 0x12, 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45,
+	 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42,
+	 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06,
+	 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8, 0xd1, 0xc9,
+	 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0,
+	 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27,
+	 0x2f, 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c,
+	 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c,
+	 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27,
+	 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9,
+	 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9,
+	 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06,
+	 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45,
+	 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a,
+	 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd,
+	 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b,
+	 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad,
+	 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6,
+	 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24,
+	 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f,
+	 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b,
+	 0x23, 0x1b, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed,
+	 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3,
+	 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6,
+	 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfb, 0x02,
+	 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f,
+	 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71,
+	 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48,
+	 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d,
+	 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0,
+	 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e,
+	 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2,
+	 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20,
+	 0x28, 0x2f, 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57,
+	 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e,
+	 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e,
+	 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3,
+	 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00,
+	 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c,
+	 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b,
+	 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19, 0x11,
+	 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3,
+	 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8,
+	 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xdf,
+	 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d,
+	 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54,
+	 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31,
+	 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0,
+	 0xc7, 0xce, 0xd5, 0xdd, 0xe5, 0xec, 0xf4, 0xfc,
+	 0x03, 0x0b, 0x13, 0x1b, 0x22, 0x2a, 0x32, 0x39,
+	 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15,
+	 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7,
+	 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb,
+	 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19,
+	 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52,
+	 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66,
+	 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35,
+	 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc,
+	 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91,
+	 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd,
+	 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35,
+	 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
+	 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51,
+	 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18,
+	 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda,
+	 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5,
+	 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3,
+	 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8,
+	 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15,
+	 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38,
+	 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb,
+	 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf,
+	 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba,
+	 0xc0, 0xc7, 0xcf, 0xd6, 0xde, 0xe5, 0xed, 0xf5,
+	 0xfd, 0x04, 0x0c, 0x14, 0x1b, 0x23, 0x2b, 0x32,
+	 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde,
+	 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0,
+	 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4,
+	 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x1a, 0x21, 0x29, 0x30, 0x38, 0x3f, 0x46, 0x4c,
+	 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b,
+	 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff,
+	 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2,
+	 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7,
+	 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f,
+	 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f,
+	 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1,
+	 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc2, 0xca, 0xd1,
+	 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e,
+	 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x61, 0x65, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e,
+	 0x37, 0x30, 0x29, 0x21, 0x19, 0x11, 0x09, 0x01,
+	 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5,
+	 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97,
+	 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b,
+	 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59,
+	 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23,
+	 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe5,
+	 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad,
+	 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce,
+	 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b,
+	 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46,
+	 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d,
+	 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41,
+	 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05,
+	 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8,
+	 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea,
+	 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20, 0x28,
+	 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26,
+	 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8,
+	 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf,
+	 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a,
+	 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a,
+	 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca,
+	 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07,
+	 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43,
+	 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44,
+	 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09,
+	 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc,
+	 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae,
+	 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25,
+	 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a,
+	 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a,
+	 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb9, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98,
+	 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7,
+	 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03,
+	 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40,
+	 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d,
+	 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47,
+	 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf,
+	 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa8, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab,
+	 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3,
+	 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21,
+	 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58,
+	 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d,
+	 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef,
+	 0xe8, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5,
+	 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4,
+	 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d,
+	 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b,
+	 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73,
+	 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a,
+	 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10,
+	 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2,
+	 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9,
+	 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0,
+	 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e,
+	 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63,
+	 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30,
+	 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3,
+	 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8,
+	 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f,
+	 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1,
+	 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd,
+	 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a,
+	 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d,
+	 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6,
+	 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2,
+	 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6,
+	 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc,
+	 0xe4, 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a,
+	 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53,
+	 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34,
+	 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff, 0xf7,
+	 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb,
+	 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9b, 0x9f, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa,
+	 0x01, 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36,
+	 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17,
+	 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9,
+	 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4,
+	 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9,
+	 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17,
+	 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50,
+	 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67,
+	 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37,
+	 0x30, 0x28, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa,
+	 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe,
+	 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba,
+	 0xc1, 0xc8, 0xd0, 0xd7, 0xdf, 0xe6, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33,
+	 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53,
+	 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b,
+	 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd,
+	 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6,
+	 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5,
+	 0xdd, 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13,
+	 0x1b, 0x22, 0x2a, 0x32, 0x39, 0x40, 0x46, 0x4d,
+	 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69,
+	 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a,
+	 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe,
+	 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1,
+	 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2,
+	 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21, 0x28, 0x30,
+	 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62,
+	 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56,
+	 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e,
+	 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0,
+	 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9,
+	 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f,
+	 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2,
+	 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x07, 0x0f,
+	 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d, 0x43, 0x4a,
+	 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3d,
+	 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10, 0x08, 0x00,
+	 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3, 0xcb, 0xc4,
+	 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f, 0x9a, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8, 0xae, 0xb5,
+	 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf, 0xe7, 0xef,
+	 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d, 0x25, 0x2c,
+	 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60,
+	 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x58,
+	 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x29, 0x22,
+	 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe4,
+	 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0, 0xc7, 0xce,
+	 0xd6, 0xde, 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0c,
+	 0x14, 0x1b, 0x23, 0x2b, 0x32, 0x39, 0x40, 0x47,
+	 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x40,
+	 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6, 0xcf, 0xc7,
+	 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6, 0xac, 0xb2,
+	 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc, 0xe3, 0xeb,
+	 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x21, 0x29,
+	 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x52, 0x58, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25,
+	 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe7,
+	 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb, 0xb5, 0xae,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a,
+	 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd, 0xc4, 0xcb,
+	 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9, 0x00, 0x08,
+	 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36, 0x3d, 0x44,
+	 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67, 0x6b, 0x6f,
+	 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76, 0x72, 0x6f,
+	 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x44,
+	 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17, 0x10, 0x08,
+	 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb,
+	 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf,
+	 0xb5, 0xbc, 0xc2, 0xca, 0xd1, 0xd8, 0xe0, 0xe8,
+	 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x26,
+	 0x2d, 0x34, 0x3c, 0x43, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x62, 0x5d,
+	 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37, 0x30, 0x29,
+	 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb, 0xf3, 0xeb,
+	 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe, 0xb8, 0xb1,
+	 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92, 0x8f, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6, 0xfe, 0x05,
+	 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33, 0x3a, 0x41,
+	 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70,
+	 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53, 0x4d, 0x47,
+	 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b, 0x13, 0x0b,
+	 0x03, 0xfc, 0xf4, 0xec, 0xe5, 0xdd, 0xd5, 0xce,
+	 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa1, 0x9c,
+	 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac,
+	 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5, 0xdc, 0xe4,
+	 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1a, 0x22,
+	 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d, 0x53, 0x59,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69, 0x65, 0x60,
+	 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c,
+	 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee,
+	 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1, 0xbb, 0xb4,
+	 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5,
+	 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2, 0xfa, 0x01,
+	 0x09, 0x11, 0x19, 0x20, 0x28, 0x30, 0x37, 0x3e,
+	 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e, 0x17, 0x0f,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd9, 0xd1,
+	 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9, 0xa4, 0x9e,
+	 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x85, 0x88,
+	 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xa9,
+	 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd2, 0xd9, 0xe1,
+	 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1f,
+	 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a, 0x50, 0x56,
+	 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e, 0x37, 0x2f,
+	 0x28, 0x20, 0x18, 0x11, 0x09, 0x01, 0xfa, 0xf2,
+	 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4, 0xbb, 0xc2,
+	 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b,
+	 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60, 0x65, 0x69,
+	 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4c,
+	 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22, 0x1a, 0x12,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdc, 0xd5,
+	 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c, 0xa1, 0xa7,
+	 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd6, 0xdd,
+	 0xe5, 0xed, 0xf5, 0xfd, 0x04, 0x0b, 0x13, 0x1b,
+	 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x53,
+	 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41, 0x3a, 0x33,
+	 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5,
+	 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8, 0xc1, 0xba,
+	 0xb4, 0xad, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b, 0x8f, 0x92,
+	 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1, 0xb8, 0xbe,
+	 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb, 0xf3, 0xfb,
+	 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29, 0x30, 0x37,
+	 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d, 0x63, 0x67,
+	 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f,
+	 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1e, 0x16,
+	 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8, 0xe0, 0xd8,
+	 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa4,
+	 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb, 0xd2, 0xda,
+	 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18,
+	 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44, 0x4a, 0x51,
+	 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x76,
+	 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x66,
+	 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44, 0x3d, 0x36,
+	 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9,
+	 0xf1, 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd,
+	 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x92,
+	 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91,
+	 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbb,
+	 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7, 0xef, 0xf7,
+	 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25, 0x2d, 0x34,
+	 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76,
+	 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e, 0x58, 0x52,
+	 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29, 0x21, 0x1a,
+	 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdc,
+	 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xab, 0xa6,
+	 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b, 0x89, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2,
+	 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8, 0xcf, 0xd6,
+	 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14,
+	 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41, 0x47, 0x4e,
+	 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71, 0x6d, 0x68,
+	 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39,
+	 0x32, 0x2b, 0x23, 0x1b, 0x13, 0x0c, 0x04, 0xfd,
+	 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce, 0xc7, 0xc0,
+	 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c, 0x98, 0x93,
+	 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f,
+	 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb8,
+	 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4, 0xec, 0xf4,
+	 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22, 0x29, 0x31,
+	 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58, 0x5e, 0x63,
+	 0x68, 0x6c, 0x70, 0x73, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60, 0x5b, 0x55,
+	 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c, 0x25, 0x1d,
+	 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef, 0xe7, 0xdf,
+	 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4, 0xae, 0xa8,
+	 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0,
+	 0xa5, 0xab, 0xb1, 0xb7, 0xbd, 0xc4, 0xcc, 0xd3,
+	 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x10,
+	 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e, 0x44, 0x4b,
+	 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73,
+	 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6f, 0x6a,
+	 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c,
+	 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f, 0x07, 0x00,
+	 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2, 0xca, 0xc3,
+	 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x95,
+	 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x84, 0x85, 0x88, 0x8a, 0x8d,
+	 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9, 0xaf, 0xb5,
+	 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0, 0xe8, 0xf0,
+	 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e, 0x26, 0x2d,
+	 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56, 0x5b, 0x61,
+	 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79,
+	 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57,
+	 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30, 0x28, 0x21,
+	 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe3,
+	 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab,
+	 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x98, 0x9d,
+	 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1, 0xc8, 0xd0,
+	 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d,
+	 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a, 0x41, 0x48,
+	 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69, 0x6d, 0x71,
+	 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c,
+	 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d, 0x46, 0x40,
+	 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13, 0x0b, 0x03,
+	 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5, 0xce, 0xc6,
+	 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97,
+	 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7, 0xac, 0xb3,
+	 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd, 0xe5, 0xec,
+	 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a,
+	 0x32, 0x39, 0x40, 0x46, 0x4d, 0x53, 0x59, 0x5e,
+	 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a,
+	 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24,
+	 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6,
+	 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xae,
+	 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8d, 0x8a,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92, 0x96, 0x9b,
+	 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc,
+	 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa, 0x01, 0x09,
+	 0x11, 0x19, 0x21, 0x28, 0x30, 0x37, 0x3e, 0x45,
+	 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e,
+	 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50, 0x49, 0x43,
+	 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16, 0x0e, 0x07,
+	 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8, 0xd1, 0xca,
+	 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99,
+	 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0,
+	 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9, 0xe1, 0xe9,
+	 0xf1, 0xf9, 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x27,
+	 0x2e, 0x35, 0x3d, 0x43, 0x4a, 0x50, 0x56, 0x5c,
+	 0x61, 0x66, 0x6b, 0x6f, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b,
+	 0x78, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d,
+	 0x57, 0x51, 0x4b, 0x44, 0x3d, 0x36, 0x2f, 0x28,
+	 0x20, 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf2, 0xea,
+	 0xe2, 0xda, 0xd3, 0xcb, 0xc4, 0xbd, 0xb7, 0xb0,
+	 0xaa, 0xa5, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99,
+	 0x9e, 0xa3, 0xa8, 0xae, 0xb5, 0xbb, 0xc2, 0xc9,
+	 0xd0, 0xd8, 0xdf, 0xe7, 0xef, 0xf7, 0xff, 0x06,
+	 0x0e, 0x15, 0x1d, 0x25, 0x2c, 0x34, 0x3b, 0x42,
+	 0x49, 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e,
+	 0x71, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70,
+	 0x6c, 0x68, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x46,
+	 0x3f, 0x38, 0x31, 0x29, 0x22, 0x1a, 0x12, 0x0a,
+	 0x02, 0xfb, 0xf3, 0xeb, 0xe4, 0xdc, 0xd4, 0xcd,
+	 0xc6, 0xbf, 0xb8, 0xb2, 0xac, 0xa6, 0xa1, 0x9c,
+	 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89,
+	 0x8c, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad,
+	 0xb3, 0xba, 0xc0, 0xc7, 0xcf, 0xd6, 0xdd, 0xe5,
+	 0xed, 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x23,
+	 0x2b, 0x32, 0x39, 0x40, 0x47, 0x4d, 0x54, 0x59,
+	 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f,
+	 0x5a, 0x54, 0x4e, 0x47, 0x40, 0x3a, 0x32, 0x2b,
+	 0x23, 0x1c, 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xed,
+	 0xe5, 0xde, 0xd6, 0xcf, 0xc7, 0xc0, 0xba, 0xb3,
+	 0xad, 0xa7, 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c,
+	 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9b, 0xa0, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6,
+	 0xcd, 0xd4, 0xdc, 0xe3, 0xeb, 0xf3, 0xfb, 0x02,
+	 0x0a, 0x12, 0x1a, 0x21, 0x29, 0x30, 0x38, 0x3f,
+	 0x46, 0x4c, 0x52, 0x58, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6e, 0x6a, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49,
+	 0x42, 0x3b, 0x34, 0x2d, 0x25, 0x1d, 0x16, 0x0e,
+	 0x06, 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd8, 0xd0,
+	 0xc9, 0xc2, 0xbb, 0xb5, 0xae, 0xa9, 0xa3, 0x9e,
+	 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8e, 0x92, 0x96, 0x9a, 0x9f, 0xa5, 0xaa,
+	 0xb0, 0xb7, 0xbd, 0xc4, 0xcb, 0xd3, 0xda, 0xe2,
+	 0xe9, 0xf1, 0xf9, 0x00, 0x08, 0x10, 0x18, 0x20,
+	 0x27, 0x2f, 0x36, 0x3d, 0x44, 0x4b, 0x51, 0x57,
+	 0x5c, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x78,
+	 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x76, 0x72, 0x6f, 0x6b, 0x66, 0x61,
+	 0x5c, 0x56, 0x50, 0x4a, 0x44, 0x3d, 0x36, 0x2e,
+	 0x27, 0x1f, 0x17, 0x10, 0x08, 0x00, 0xf9, 0xf1,
+	 0xe9, 0xe1, 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6,
+	 0xb0, 0xaa, 0xa4, 0x9f, 0x9a, 0x96, 0x91, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x84, 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95,
+	 0x99, 0x9e, 0xa3, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3,
+	 0xca, 0xd1, 0xd8, 0xe0, 0xe8, 0xf0, 0xf7, 0xff,
+	 0x06, 0x0e, 0x16, 0x1e, 0x26, 0x2d, 0x34, 0x3c,
+	 0x43, 0x49, 0x4f, 0x55, 0x5b, 0x61, 0x65, 0x6a,
+	 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73,
+	 0x70, 0x6c, 0x67, 0x62, 0x5d, 0x58, 0x52, 0x4c,
+	 0x45, 0x3e, 0x37, 0x30, 0x29, 0x21, 0x19, 0x11,
+	 0x09, 0x02, 0xfb, 0xf3, 0xeb, 0xe3, 0xdb, 0xd4,
+	 0xcc, 0xc5, 0xbe, 0xb8, 0xb1, 0xab, 0xa5, 0xa0,
+	 0x9b, 0x97, 0x92, 0x8f, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8,
+	 0xad, 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xde,
+	 0xe6, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x14, 0x1c,
+	 0x24, 0x2b, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54,
+	 0x5a, 0x5f, 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6d, 0x68, 0x64,
+	 0x5e, 0x59, 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32,
+	 0x2a, 0x23, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4,
+	 0xec, 0xe5, 0xdd, 0xd5, 0xce, 0xc7, 0xc0, 0xb9,
+	 0xb3, 0xad, 0xa7, 0xa1, 0x9c, 0x98, 0x93, 0x8f,
+	 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93,
+	 0x97, 0x9c, 0xa1, 0xa6, 0xac, 0xb2, 0xb9, 0xbf,
+	 0xc6, 0xce, 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfc,
+	 0x03, 0x0b, 0x13, 0x1a, 0x22, 0x2a, 0x31, 0x38,
+	 0x3f, 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68,
+	 0x6c, 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x75,
+	 0x71, 0x6d, 0x69, 0x65, 0x60, 0x5a, 0x55, 0x4e,
+	 0x48, 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1d, 0x15,
+	 0x0d, 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xdf, 0xd7,
+	 0xd0, 0xc8, 0xc1, 0xbb, 0xb4, 0xae, 0xa8, 0xa2,
+	 0x9d, 0x99, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5,
+	 0xab, 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd3, 0xdb,
+	 0xe2, 0xea, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19,
+	 0x20, 0x28, 0x2f, 0x37, 0x3e, 0x45, 0x4b, 0x51,
+	 0x57, 0x5d, 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76,
+	 0x79, 0x7b, 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66,
+	 0x61, 0x5c, 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35,
+	 0x2e, 0x26, 0x1e, 0x17, 0x0f, 0x07, 0x00, 0xf8,
+	 0xf0, 0xe8, 0xe0, 0xd9, 0xd1, 0xca, 0xc3, 0xbc,
+	 0xb6, 0xaf, 0xa9, 0xa4, 0x9e, 0x9a, 0x95, 0x91,
+	 0x8d, 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x85, 0x88, 0x8a, 0x8e, 0x91,
+	 0x95, 0x9a, 0x9f, 0xa4, 0xa9, 0xaf, 0xb6, 0xbc,
+	 0xc3, 0xca, 0xd2, 0xd9, 0xe1, 0xe8, 0xf0, 0xf8,
+	 0x00, 0x07, 0x0f, 0x17, 0x1f, 0x26, 0x2e, 0x35,
+	 0x3c, 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66,
+	 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76,
+	 0x73, 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51,
+	 0x4b, 0x44, 0x3e, 0x37, 0x2f, 0x28, 0x20, 0x18,
+	 0x11, 0x09, 0x01, 0xfa, 0xf2, 0xea, 0xe2, 0xdb,
+	 0xd3, 0xcc, 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5,
+	 0xa0, 0x9b, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85,
+	 0x87, 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9d, 0xa3,
+	 0xa8, 0xae, 0xb4, 0xbb, 0xc2, 0xc9, 0xd0, 0xd7,
+	 0xdf, 0xe7, 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15,
+	 0x1d, 0x25, 0x2c, 0x33, 0x3b, 0x42, 0x48, 0x4f,
+	 0x55, 0x5a, 0x60, 0x65, 0x69, 0x6e, 0x71, 0x75,
+	 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68,
+	 0x63, 0x5e, 0x58, 0x53, 0x4c, 0x46, 0x3f, 0x38,
+	 0x31, 0x2a, 0x22, 0x1a, 0x12, 0x0b, 0x03, 0xfc,
+	 0xf4, 0xec, 0xe4, 0xdc, 0xd5, 0xcd, 0xc6, 0xbf,
+	 0xb9, 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93,
+	 0x8f, 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f,
+	 0x93, 0x98, 0x9c, 0xa1, 0xa7, 0xad, 0xb3, 0xb9,
+	 0xc0, 0xc7, 0xce, 0xd6, 0xdd, 0xe5, 0xed, 0xf5,
+	 0xfd, 0x03, 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x32,
+	 0x39, 0x40, 0x47, 0x4d, 0x53, 0x59, 0x5f, 0x64,
+	 0x68, 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77,
+	 0x74, 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54,
+	 0x4e, 0x47, 0x41, 0x3a, 0x33, 0x2b, 0x24, 0x1c,
+	 0x14, 0x0c, 0x04, 0xfd, 0xf5, 0xee, 0xe6, 0xde,
+	 0xd6, 0xcf, 0xc8, 0xc1, 0xba, 0xb4, 0xad, 0xa7,
+	 0xa2, 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8b, 0x8f, 0x92, 0x97, 0x9b, 0xa0,
+	 0xa6, 0xab, 0xb1, 0xb8, 0xbe, 0xc5, 0xcd, 0xd4,
+	 0xdb, 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12,
+	 0x19, 0x21, 0x29, 0x30, 0x37, 0x3e, 0x45, 0x4c,
+	 0x52, 0x58, 0x5d, 0x63, 0x67, 0x6c, 0x70, 0x73,
+	 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a,
+	 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b,
+	 0x34, 0x2d, 0x25, 0x1e, 0x16, 0x0e, 0x06, 0xff,
+	 0xf7, 0xef, 0xe7, 0xe0, 0xd8, 0xd1, 0xc9, 0xc2,
+	 0xbc, 0xb5, 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95,
+	 0x91, 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6,
+	 0xbd, 0xc4, 0xcb, 0xd2, 0xda, 0xe1, 0xe9, 0xf1,
+	 0xf9, 0x00, 0x08, 0x10, 0x18, 0x1f, 0x27, 0x2e,
+	 0x36, 0x3d, 0x44, 0x4a, 0x51, 0x57, 0x5c, 0x61,
+	 0x66, 0x6b, 0x6f, 0x72, 0x76, 0x78, 0x7b, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78,
+	 0x76, 0x73, 0x6f, 0x6b, 0x66, 0x62, 0x5c, 0x57,
+	 0x51, 0x4a, 0x44, 0x3d, 0x36, 0x2f, 0x27, 0x20,
+	 0x18, 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe2,
+	 0xda, 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa,
+	 0xa4, 0x9f, 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84,
+	 0x85, 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e,
+	 0xa3, 0xa9, 0xaf, 0xb5, 0xbb, 0xc2, 0xc9, 0xd1,
+	 0xd8, 0xe0, 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e,
+	 0x16, 0x1e, 0x25, 0x2d, 0x34, 0x3b, 0x42, 0x49,
+	 0x4f, 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c,
+	 0x67, 0x63, 0x5e, 0x58, 0x52, 0x4c, 0x45, 0x3f,
+	 0x38, 0x30, 0x29, 0x21, 0x1a, 0x12, 0x0a, 0x02,
+	 0xfb, 0xf3, 0xeb, 0xe3, 0xdc, 0xd4, 0xcd, 0xc6,
+	 0xbf, 0xb8, 0xb2, 0xab, 0xa6, 0xa0, 0x9b, 0x97,
+	 0x93, 0x8f, 0x8b, 0x89, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c,
+	 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3,
+	 0xba, 0xc1, 0xc8, 0xcf, 0xd6, 0xde, 0xe6, 0xed,
+	 0xf5, 0xfd, 0x04, 0x0c, 0x14, 0x1c, 0x24, 0x2b,
+	 0x32, 0x3a, 0x41, 0x47, 0x4e, 0x54, 0x5a, 0x5f,
+	 0x64, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x71, 0x6d, 0x68, 0x64, 0x5f, 0x59,
+	 0x53, 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2b, 0x23,
+	 0x1b, 0x13, 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5,
+	 0xdd, 0xd6, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad,
+	 0xa7, 0xa2, 0x9c, 0x98, 0x93, 0x90, 0x8c, 0x89,
+	 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c,
+	 0xa1, 0xa6, 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd,
+	 0xd5, 0xdc, 0xe4, 0xec, 0xf4, 0xfb, 0x02, 0x0a,
+	 0x12, 0x1a, 0x22, 0x29, 0x31, 0x38, 0x3f, 0x46,
+	 0x4c, 0x53, 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70,
+	 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x71, 0x6e,
+	 0x69, 0x65, 0x60, 0x5b, 0x55, 0x4f, 0x48, 0x42,
+	 0x3b, 0x34, 0x2c, 0x25, 0x1d, 0x15, 0x0d, 0x05,
+	 0xff, 0xf7, 0xef, 0xe7, 0xdf, 0xd7, 0xd0, 0xc9,
+	 0xc2, 0xbb, 0xb4, 0xae, 0xa8, 0xa3, 0x9e, 0x99,
+	 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b,
+	 0x8e, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1,
+	 0xb7, 0xbe, 0xc4, 0xcc, 0xd3, 0xda, 0xe2, 0xea,
+	 0xf2, 0xfa, 0x01, 0x09, 0x10, 0x18, 0x20, 0x28,
+	 0x2f, 0x36, 0x3e, 0x44, 0x4b, 0x51, 0x57, 0x5d,
+	 0x62, 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b,
+	 0x7c, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6f, 0x6a, 0x66, 0x61, 0x5c,
+	 0x56, 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26,
+	 0x1f, 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe9,
+	 0xe1, 0xd9, 0xd2, 0xca, 0xc3, 0xbc, 0xb6, 0xb0,
+	 0xaa, 0xa4, 0x9f, 0x9a, 0x95, 0x91, 0x8e, 0x8a,
+	 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x84, 0x85, 0x88, 0x8a, 0x8d, 0x91, 0x95, 0x99,
+	 0x9e, 0xa4, 0xa9, 0xaf, 0xb5, 0xbc, 0xc3, 0xca,
+	 0xd1, 0xd9, 0xe0, 0xe8, 0xf0, 0xf8, 0x00, 0x07,
+	 0x0f, 0x17, 0x1e, 0x26, 0x2d, 0x35, 0x3c, 0x43,
+	 0x49, 0x50, 0x56, 0x5b, 0x61, 0x66, 0x6a, 0x6e,
+	 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f,
+	 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x52, 0x4b, 0x45,
+	 0x3e, 0x37, 0x30, 0x28, 0x21, 0x19, 0x11, 0x09,
+	 0x01, 0xfa, 0xf2, 0xea, 0xe3, 0xdb, 0xd3, 0xcc,
+	 0xc5, 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a,
+	 0x8d, 0x90, 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xae,
+	 0xb4, 0xba, 0xc1, 0xc8, 0xcf, 0xd7, 0xdf, 0xe6,
+	 0xee, 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x24,
+	 0x2c, 0x33, 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a,
+	 0x60, 0x65, 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e,
+	 0x59, 0x53, 0x4d, 0x46, 0x40, 0x39, 0x31, 0x2a,
+	 0x22, 0x1b, 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec,
+	 0xe4, 0xdd, 0xd5, 0xce, 0xc6, 0xc0, 0xb9, 0xb2,
+	 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c,
+	 0x89, 0x86, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x97,
+	 0x9c, 0xa1, 0xa7, 0xac, 0xb3, 0xb9, 0xc0, 0xc7,
+	 0xce, 0xd5, 0xdd, 0xe4, 0xec, 0xf4, 0xfc, 0x03,
+	 0x0b, 0x13, 0x1b, 0x23, 0x2a, 0x31, 0x39, 0x40,
+	 0x46, 0x4d, 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c,
+	 0x70, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71,
+	 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x48,
+	 0x41, 0x3a, 0x33, 0x2c, 0x24, 0x1c, 0x15, 0x0d,
+	 0x05, 0xfe, 0xf6, 0xee, 0xe6, 0xde, 0xd7, 0xcf,
+	 0xc8, 0xc1, 0xba, 0xb4, 0xae, 0xa8, 0xa2, 0x9d,
+	 0x98, 0x94, 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x88,
+	 0x8b, 0x8f, 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab,
+	 0xb1, 0xb7, 0xbe, 0xc5, 0xcc, 0xd4, 0xdb, 0xe3,
+	 0xeb, 0xf2, 0xfa, 0x01, 0x09, 0x11, 0x19, 0x21,
+	 0x28, 0x30, 0x37, 0x3e, 0x45, 0x4b, 0x52, 0x58,
+	 0x5d, 0x62, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61,
+	 0x5b, 0x56, 0x50, 0x49, 0x43, 0x3c, 0x35, 0x2d,
+	 0x26, 0x1e, 0x16, 0x0e, 0x07, 0x00, 0xf8, 0xf0,
+	 0xe8, 0xe0, 0xd8, 0xd1, 0xca, 0xc3, 0xbc, 0xb5,
+	 0xaf, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d,
+	 0x8a, 0x88, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x91, 0x95,
+	 0x9a, 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc3,
+	 0xcb, 0xd2, 0xd9, 0xe1, 0xe9, 0xf1, 0xf9, 0x00,
+	 0x07, 0x0f, 0x17, 0x1f, 0x27, 0x2e, 0x35, 0x3d,
+	 0x43, 0x4a, 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6b,
+	 0x6f, 0x72, 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73,
+	 0x6f, 0x6b, 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b,
+	 0x44, 0x3d, 0x36, 0x2f, 0x28, 0x20, 0x18, 0x10,
+	 0x08, 0x00, 0xf9, 0xf2, 0xea, 0xe2, 0xda, 0xd3,
+	 0xcb, 0xc4, 0xbd, 0xb7, 0xb0, 0xaa, 0xa5, 0x9f,
+	 0x9a, 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x8a, 0x8d, 0x90, 0x94, 0x99, 0x9e, 0xa3, 0xa8,
+	 0xae, 0xb5, 0xbb, 0xc2, 0xc9, 0xd0, 0xd8, 0xdf,
+	 0xe7, 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x15, 0x1d,
+	 0x25, 0x2c, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55,
+	 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x71, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x68, 0x63,
+	 0x5e, 0x58, 0x52, 0x4c, 0x46, 0x3f, 0x38, 0x31,
+	 0x29, 0x22, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3,
+	 0xeb, 0xe4, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8,
+	 0xb2, 0xac, 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f,
+	 0x8c, 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc0,
+	 0xc7, 0xcf, 0xd6, 0xde, 0xe5, 0xed, 0xf5, 0xfd,
+	 0x04, 0x0c, 0x14, 0x1c, 0x23, 0x2b, 0x32, 0x39,
+	 0x40, 0x47, 0x4d, 0x54, 0x59, 0x5f, 0x64, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x7a, 0x77, 0x74,
+	 0x71, 0x6d, 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e,
+	 0x47, 0x40, 0x3a, 0x32, 0x2b, 0x23, 0x1c, 0x14,
+	 0x0c, 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xde, 0xd6,
+	 0xcf, 0xc7, 0xc0, 0xba, 0xb3, 0xad, 0xa7, 0xa2,
+	 0x9d, 0x98, 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9b, 0xa0, 0xa6,
+	 0xac, 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd4, 0xdc,
+	 0xe3, 0xeb, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a,
+	 0x21, 0x29, 0x30, 0x38, 0x3f, 0x46, 0x4c, 0x52,
+	 0x58, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65,
+	 0x60, 0x5b, 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34,
+	 0x2d, 0x25, 0x1d, 0x16, 0x0e, 0x06, 0xff, 0xf7,
+	 0xef, 0xe7, 0xdf, 0xd8, 0xd0, 0xc9, 0xc2, 0xbb,
+	 0xb5, 0xae, 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91,
+	 0x8d, 0x8a, 0x87, 0x85, 0x84, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92,
+	 0x96, 0x9a, 0x9f, 0xa5, 0xaa, 0xb0, 0xb7, 0xbd,
+	 0xc4, 0xcb, 0xd3, 0xda, 0xe2, 0xe9, 0xf1, 0xf9,
+	 0x00, 0x08, 0x10, 0x18, 0x20, 0x27, 0x2f, 0x36,
+	 0x3d, 0x44, 0x4b, 0x51, 0x57, 0x5c, 0x62, 0x67,
+	 0x6b, 0x6f, 0x73, 0x76, 0x78, 0x7b, 0x7c, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x76,
+	 0x72, 0x6f, 0x6b, 0x66, 0x61, 0x5c, 0x56, 0x50,
+	 0x4a, 0x44, 0x3d, 0x36, 0x2e, 0x27, 0x1f, 0x17,
+	 0x10, 0x08, 0x00, 0xf9, 0xf1, 0xe9, 0xe1, 0xda,
+	 0xd2, 0xcb, 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4,
+	 0x9f, 0x9a, 0x96, 0x91, 0x8e, 0x8b, 0x88, 0x86,
+	 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85,
+	 0x87, 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3,
+	 0xa9, 0xaf, 0xb5, 0xbc, 0xc2, 0xca, 0xd1, 0xd8,
+	 0xe0, 0xe8, 0xf0, 0xf7, 0xff, 0x06, 0x0e, 0x16,
+	 0x1e, 0x26, 0x2d, 0x34, 0x3c, 0x42, 0x49, 0x4f,
+	 0x55, 0x5b, 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75,
+	 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7b, 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67,
+	 0x62, 0x5d, 0x58, 0x52, 0x4c, 0x45, 0x3e, 0x37,
+	 0x30, 0x29, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfb,
+	 0xf3, 0xeb, 0xe3, 0xdb, 0xd4, 0xcc, 0xc5, 0xbe,
+	 0xb8, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x97, 0x92,
+	 0x8f, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90,
+	 0x94, 0x98, 0x9d, 0xa2, 0xa8, 0xad, 0xb4, 0xba,
+	 0xc1, 0xc8, 0xcf, 0xd7, 0xde, 0xe6, 0xee, 0xf6,
+	 0xfe, 0x05, 0x0d, 0x14, 0x1c, 0x24, 0x2b, 0x33,
+	 0x3a, 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x5f, 0x64,
+	 0x69, 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77,
+	 0x74, 0x70, 0x6d, 0x68, 0x64, 0x5e, 0x59, 0x53,
+	 0x4d, 0x47, 0x40, 0x39, 0x32, 0x2a, 0x23, 0x1b,
+	 0x13, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe5, 0xdd,
+	 0xd5, 0xce, 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7,
+	 0xa1, 0x9c, 0x98, 0x93, 0x8f, 0x8c, 0x89, 0x87,
+	 0x85, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x86, 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1,
+	 0xa6, 0xac, 0xb2, 0xb9, 0xbf, 0xc6, 0xce, 0xd5,
+	 0xdc, 0xe4, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13,
+	 0x1a, 0x22, 0x2a, 0x31, 0x38, 0x3f, 0x46, 0x4d,
+	 0x53, 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74,
+	 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e,
+	 0x7d, 0x7c, 0x7a, 0x77, 0x75, 0x71, 0x6d, 0x69,
+	 0x65, 0x60, 0x5a, 0x55, 0x4e, 0x48, 0x41, 0x3a,
+	 0x33, 0x2c, 0x24, 0x1d, 0x15, 0x0d, 0x05, 0xfe,
+	 0xf6, 0xee, 0xe6, 0xdf, 0xd7, 0xd0, 0xc8, 0xc1,
+	 0xbb, 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x99, 0x94,
+	 0x90, 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82,
+	 0x82, 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e,
+	 0x92, 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7,
+	 0xbe, 0xc5, 0xcc, 0xd3, 0xdb, 0xe2, 0xea, 0xf2,
+	 0xfa, 0x01, 0x09, 0x11, 0x19, 0x20, 0x28, 0x30,
+	 0x37, 0x3e, 0x45, 0x4b, 0x51, 0x57, 0x5d, 0x62,
+	 0x67, 0x6b, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c,
+	 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78,
+	 0x75, 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5c, 0x56,
+	 0x50, 0x4a, 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1e,
+	 0x17, 0x0f, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0,
+	 0xd9, 0xd1, 0xca, 0xc3, 0xbc, 0xb6, 0xaf, 0xa9,
+	 0xa4, 0x9e, 0x9a, 0x95, 0x91, 0x8d, 0x8a, 0x88,
+	 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84,
+	 0x85, 0x88, 0x8a, 0x8e, 0x91, 0x95, 0x9a, 0x9f,
+	 0xa4, 0xa9, 0xaf, 0xb6, 0xbc, 0xc3, 0xca, 0xd1,
+	 0xd9, 0xe1, 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f,
+	 0x17, 0x1f, 0x26, 0x2e, 0x35, 0x3c, 0x43, 0x4a,
+	 0x50, 0x56, 0x5c, 0x61, 0x66, 0x6a, 0x6e, 0x72,
+	 0x75, 0x78, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7c, 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b,
+	 0x67, 0x62, 0x5d, 0x57, 0x51, 0x4b, 0x44, 0x3e,
+	 0x37, 0x2f, 0x28, 0x20, 0x18, 0x11, 0x09, 0x01,
+	 0xfa, 0xf2, 0xea, 0xe2, 0xdb, 0xd3, 0xcc, 0xc5,
+	 0xbe, 0xb7, 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96,
+	 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d,
+	 0x90, 0x94, 0x99, 0x9d, 0xa3, 0xa8, 0xae, 0xb4,
+	 0xbb, 0xc2, 0xc9, 0xd0, 0xd7, 0xdf, 0xe7, 0xee,
+	 0xf6, 0xfe, 0x05, 0x0d, 0x15, 0x1d, 0x25, 0x2c,
+	 0x33, 0x3b, 0x42, 0x48, 0x4f, 0x55, 0x5a, 0x60,
+	 0x65, 0x69, 0x6e, 0x71, 0x75, 0x77, 0x7a, 0x7c,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79,
+	 0x77, 0x74, 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59,
+	 0x53, 0x4c, 0x46, 0x3f, 0x38, 0x31, 0x2a, 0x22,
+	 0x1a, 0x12, 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4,
+	 0xdc, 0xd5, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2, 0xac,
+	 0xa6, 0xa1, 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89,
+	 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x85, 0x87, 0x89, 0x8c, 0x8f, 0x93, 0x98, 0x9c,
+	 0xa1, 0xa7, 0xad, 0xb3, 0xb9, 0xc0, 0xc7, 0xce,
+	 0xd6, 0xdd, 0xe5, 0xed, 0xf5, 0xfd, 0x03, 0x0b,
+	 0x13, 0x1b, 0x23, 0x2a, 0x32, 0x39, 0x40, 0x47,
+	 0x4d, 0x53, 0x59, 0x5f, 0x64, 0x68, 0x6d, 0x71,
+	 0x74, 0x77, 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e,
+	 0x7e, 0x7d, 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d,
+	 0x69, 0x64, 0x5f, 0x5a, 0x54, 0x4e, 0x47, 0x41,
+	 0x3a, 0x33, 0x2b, 0x24, 0x1c, 0x14, 0x0c, 0x04,
+	 0xfd, 0xf5, 0xee, 0xe6, 0xde, 0xd6, 0xcf, 0xc8,
+	 0xc1, 0xba, 0xb4, 0xad, 0xa8, 0xa2, 0x9d, 0x98,
+	 0x94, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83, 0x82,
+	 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89, 0x8b,
+	 0x8f, 0x92, 0x97, 0x9b, 0xa0, 0xa6, 0xab, 0xb1,
+	 0xb8, 0xbe, 0xc5, 0xcd, 0xd4, 0xdb, 0xe3, 0xeb,
+	 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x19, 0x21, 0x29,
+	 0x30, 0x37, 0x3e, 0x45, 0x4c, 0x52, 0x58, 0x5d,
+	 0x63, 0x67, 0x6c, 0x70, 0x73, 0x76, 0x79, 0x7b,
+	 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a,
+	 0x78, 0x75, 0x72, 0x6e, 0x6a, 0x65, 0x60, 0x5b,
+	 0x55, 0x4f, 0x49, 0x42, 0x3b, 0x34, 0x2d, 0x25,
+	 0x1e, 0x16, 0x0e, 0x06, 0xff, 0xf7, 0xef, 0xe8,
+	 0xe0, 0xd8, 0xd1, 0xc9, 0xc2, 0xbc, 0xb5, 0xaf,
+	 0xa9, 0xa3, 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a,
+	 0x87, 0x85, 0x84, 0x82, 0x82, 0x82, 0x82, 0x83,
+	 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96, 0x9a,
+	 0x9f, 0xa4, 0xaa, 0xb0, 0xb6, 0xbd, 0xc4, 0xcb,
+	 0xd2, 0xda, 0xe1, 0xe9, 0xf1, 0xf9, 0x00, 0x08,
+	 0x10, 0x18, 0x1f, 0x27, 0x2e, 0x36, 0x3d, 0x44,
+	 0x4a, 0x51, 0x57, 0x5c, 0x61, 0x66, 0x6b, 0x6f,
+	 0x72, 0x76, 0x78, 0x7b, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7c, 0x7b, 0x78, 0x76, 0x73, 0x6f,
+	 0x6b, 0x66, 0x62, 0x5c, 0x57, 0x51, 0x4a, 0x44,
+	 0x3d, 0x36, 0x2f, 0x27, 0x20, 0x18, 0x10, 0x08,
+	 0x00, 0xf9, 0xf1, 0xe9, 0xe2, 0xda, 0xd2, 0xcb,
+	 0xc4, 0xbd, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f, 0x9a,
+	 0x96, 0x92, 0x8e, 0x8b, 0x88, 0x86, 0x84, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x87, 0x8a,
+	 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa3, 0xa9, 0xaf,
+	 0xb5, 0xbb, 0xc2, 0xc9, 0xd1, 0xd8, 0xe0, 0xe7,
+	 0xef, 0xf7, 0xff, 0x06, 0x0e, 0x16, 0x1e, 0x25,
+	 0x2d, 0x34, 0x3b, 0x42, 0x49, 0x4f, 0x55, 0x5b,
+	 0x60, 0x65, 0x6a, 0x6e, 0x72, 0x75, 0x78, 0x7a,
+	 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7b,
+	 0x79, 0x76, 0x73, 0x70, 0x6c, 0x67, 0x63, 0x5e,
+	 0x58, 0x52, 0x4c, 0x45, 0x3f, 0x38, 0x30, 0x29,
+	 0x21, 0x1a, 0x12, 0x0a, 0x02, 0xfb, 0xf3, 0xeb,
+	 0xe3, 0xdc, 0xd4, 0xcd, 0xc6, 0xbf, 0xb8, 0xb2,
+	 0xab, 0xa6, 0xa0, 0x9b, 0x97, 0x93, 0x8f, 0x8b,
+	 0x89, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x85, 0x87, 0x89, 0x8c, 0x90, 0x94, 0x98,
+	 0x9d, 0xa2, 0xa7, 0xad, 0xb3, 0xba, 0xc1, 0xc8,
+	 0xcf, 0xd6, 0xde, 0xe6, 0xed, 0xf5, 0xfd, 0x04,
+	 0x0c, 0x14, 0x1c, 0x24, 0x2b, 0x32, 0x3a, 0x41,
+	 0x47, 0x4e, 0x54, 0x5a, 0x5f, 0x64, 0x69, 0x6d,
+	 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74, 0x71,
+	 0x6d, 0x68, 0x64, 0x5f, 0x59, 0x53, 0x4d, 0x47,
+	 0x40, 0x39, 0x32, 0x2b, 0x23, 0x1b, 0x13, 0x0c,
+	 0x04, 0xfd, 0xf5, 0xed, 0xe5, 0xdd, 0xd6, 0xce,
+	 0xc7, 0xc0, 0xb9, 0xb3, 0xad, 0xa7, 0xa2, 0x9c,
+	 0x98, 0x93, 0x90, 0x8c, 0x89, 0x87, 0x85, 0x83,
+	 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86, 0x89,
+	 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa6, 0xac,
+	 0xb2, 0xb8, 0xbf, 0xc6, 0xcd, 0xd5, 0xdc, 0xe4,
+	 0xec, 0xf3, 0xfb, 0x02, 0x0a, 0x12, 0x1a, 0x22,
+	 0x29, 0x31, 0x38, 0x3f, 0x46, 0x4c, 0x53, 0x58,
+	 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x73, 0x76, 0x79,
+	 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d, 0x7c,
+	 0x7a, 0x78, 0x75, 0x71, 0x6e, 0x69, 0x65, 0x60,
+	 0x5b, 0x55, 0x4f, 0x48, 0x42, 0x3b, 0x34, 0x2c,
+	 0x25, 0x1d, 0x15, 0x0d, 0x05, 0xff, 0xf7, 0xef,
+	 0xe7, 0xdf, 0xd7, 0xd0, 0xc9, 0xc2, 0xbb, 0xb4,
+	 0xae, 0xa8, 0xa3, 0x9e, 0x99, 0x94, 0x90, 0x8d,
+	 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82, 0x82,
+	 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8e, 0x92, 0x96,
+	 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe, 0xc4,
+	 0xcc, 0xd3, 0xda, 0xe2, 0xea, 0xf2, 0xfa, 0x01,
+	 0x09, 0x10, 0x18, 0x20, 0x28, 0x2f, 0x36, 0x3e,
+	 0x44, 0x4b, 0x51, 0x57, 0x5d, 0x62, 0x67, 0x6b,
+	 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7c, 0x7e, 0x7e,
+	 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75, 0x72,
+	 0x6f, 0x6a, 0x66, 0x61, 0x5c, 0x56, 0x50, 0x4a,
+	 0x43, 0x3c, 0x35, 0x2e, 0x26, 0x1f, 0x17, 0x0f,
+	 0x07, 0x00, 0xf8, 0xf0, 0xe9, 0xe1, 0xd9, 0xd2,
+	 0xca, 0xc3, 0xbc, 0xb6, 0xb0, 0xaa, 0xa4, 0x9f,
+	 0x9a, 0x95, 0x91, 0x8e, 0x8a, 0x88, 0x86, 0x84,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x84, 0x85, 0x88,
+	 0x8a, 0x8d, 0x91, 0x95, 0x99, 0x9e, 0xa4, 0xa9,
+	 0xaf, 0xb5, 0xbc, 0xc3, 0xca, 0xd1, 0xd9, 0xe0,
+	 0xe8, 0xf0, 0xf8, 0x00, 0x07, 0x0f, 0x17, 0x1e,
+	 0x26, 0x2d, 0x35, 0x3c, 0x43, 0x49, 0x50, 0x56,
+	 0x5b, 0x61, 0x66, 0x6a, 0x6e, 0x72, 0x75, 0x78,
+	 0x7a, 0x7c, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7c,
+	 0x7b, 0x79, 0x76, 0x73, 0x6f, 0x6b, 0x67, 0x62,
+	 0x5d, 0x57, 0x52, 0x4b, 0x45, 0x3e, 0x37, 0x30,
+	 0x28, 0x21, 0x19, 0x11, 0x09, 0x01, 0xfa, 0xf2,
+	 0xea, 0xe3, 0xdb, 0xd3, 0xcc, 0xc5, 0xbe, 0xb7,
+	 0xb1, 0xab, 0xa5, 0xa0, 0x9b, 0x96, 0x92, 0x8e,
+	 0x8b, 0x88, 0x86, 0x84, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x85, 0x87, 0x8a, 0x8d, 0x90, 0x94,
+	 0x98, 0x9d, 0xa2, 0xa8, 0xae, 0xb4, 0xba, 0xc1,
+	 0xc8, 0xd0, 0xd7, 0xdf, 0xe6, 0xee, 0xf6, 0xfe,
+	 0x05, 0x0d, 0x15, 0x1d, 0x24, 0x2c, 0x33, 0x3a,
+	 0x41, 0x48, 0x4e, 0x54, 0x5a, 0x60, 0x65, 0x69,
+	 0x6d, 0x71, 0x74, 0x77, 0x7a, 0x7c, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7b, 0x79, 0x77, 0x74,
+	 0x70, 0x6c, 0x68, 0x63, 0x5e, 0x59, 0x53, 0x4d,
+	 0x46, 0x40, 0x39, 0x31, 0x2a, 0x22, 0x1b, 0x13,
+	 0x0b, 0x03, 0xfc, 0xf4, 0xec, 0xe4, 0xdd, 0xd5,
+	 0xce, 0xc6, 0xc0, 0xb9, 0xb2, 0xac, 0xa6, 0xa1,
+	 0x9c, 0x97, 0x93, 0x8f, 0x8c, 0x89, 0x86, 0x85,
+	 0x83, 0x82, 0x82, 0x82, 0x82, 0x83, 0x85, 0x87,
+	 0x89, 0x8c, 0x8f, 0x93, 0x97, 0x9c, 0xa1, 0xa7,
+	 0xac, 0xb3, 0xb9, 0xc0, 0xc7, 0xce, 0xd5, 0xdd,
+	 0xe5, 0xec, 0xf4, 0xfc, 0x03, 0x0b, 0x13, 0x1b,
+	 0x23, 0x2a, 0x32, 0x39, 0x40, 0x46, 0x4d, 0x53,
+	 0x59, 0x5e, 0x63, 0x68, 0x6c, 0x70, 0x74, 0x77,
+	 0x79, 0x7b, 0x7d, 0x7e, 0x7e, 0x7e, 0x7e, 0x7d,
+	 0x7c, 0x7a, 0x77, 0x74, 0x71, 0x6d, 0x69, 0x64,
+	 0x5f, 0x5a, 0x54, 0x4e, 0x48, 0x41, 0x3a, 0x33,
+	 0x2c, 0x24, 0x1c, 0x15, 0x0d, 0x05, 0xfe, 0xf6,
+	 0xee, 0xe6, 0xde, 0xd7, 0xcf, 0xc8, 0xc1, 0xba,
+	 0xb4, 0xae, 0xa8, 0xa2, 0x9d, 0x98, 0x94, 0x90,
+	 0x8d, 0x8a, 0x87, 0x85, 0x83, 0x82, 0x82, 0x82,
+	 0x82, 0x83, 0x84, 0x86, 0x88, 0x8b, 0x8f, 0x92,
+	 0x96, 0x9b, 0xa0, 0xa5, 0xab, 0xb1, 0xb7, 0xbe,
+	 0xc5, 0xcc, 0xd4, 0xdb, 0xe3, 0xeb, 0xf2, 0xfa,
+	 0x01, 0x09, 0x11, 0x19, 0x21, 0x28, 0x30, 0x37,
+	 0x3e, 0x45, 0x4b, 0x52, 0x58, 0x5d, 0x62, 0x67,
+	 0x6c, 0x6f, 0x73, 0x76, 0x79, 0x7b, 0x7d, 0x7e,
+	 0x7e, 0x7e, 0x7e, 0x7d, 0x7c, 0x7a, 0x78, 0x75,
+	 0x72, 0x6e, 0x6a, 0x66, 0x61, 0x5b, 0x56, 0x50,
+	 0x49, 0x43, 0x3c, 0x35, 0x2d, 0x26, 0x1e, 0x16,
+	 0x0e, 0x07, 0x00, 0xf8, 0xf0, 0xe8, 0xe0, 0xd8,
+	 0xd1, 0xca, 0xc3, 0xbc, 0xb5, 0xaf, 0xa9, 0xa3,
+	 0x9e, 0x99, 0x95, 0x91, 0x8d, 0x8a, 0x88, 0x85,
+	 0x84, 0x82, 0x82, 0x82, 0x82, 0x83, 0x84, 0x86,
+	 0x88, 0x8b, 0x8e, 0x91, 0x95, 0x9a, 0x9f, 0xa4,
+	 0xaa, 0xb0, 0xb6, 0xbd, 0xc3, 0xcb, 0xd2, 0xd9,
+	 0xe1, 0xe9, 0xf1, 0xf9,                        
+};
\ No newline at end of file
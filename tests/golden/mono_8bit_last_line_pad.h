@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_LAST_LINE_PAD_H_
+#define _MONO_8BIT_LAST_LINE_PAD_H_
+
+extern const size_t MONO_8BIT_LAST_LINE_PAD_SAMPLE_NO;
+extern const int8_t mono_8bit_last_line_pad[];
+
+#define MONO_8BIT_LAST_LINE_PAD_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
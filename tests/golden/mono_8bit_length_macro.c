@@ -0,0 +1,5519 @@
+#define MONO_8BIT_LENGTH_MACRO_SAMPLE_NO 44100
+
+#define MONO_8BIT_LENGTH_MACRO_SAMPLE_RATE 44100
+
+const int8_t mono_8bit_length_macro[44100] = {
+	 0, 7, 15, 23, 31, 39, 46, 53,
+	 61, 67, 74, 80, 86, 92, 97, 102,
+	 107, 111, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 120, 118,
+	 115, 111, 107, 103, 98, 93, 87, 81,
+	 75, 68, 61, 54, 47, 40, 32, 24,
+	 16, 8, 0, -7, -14, -22, -30, -38,
+	 -45, -53, -60, -67, -73, -80, -86, -91,
+	 -97, -102, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -103, -98, -93,
+	 -88, -82, -75, -69, -62, -55, -48, -40,
+	 -33, -25, -17, -9, -1, 6, 14, 21,
+	 29, 37, 44, 52, 59, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 113, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 104,
+	 99, 94, 88, 82, 76, 70, 63, 56,
+	 49, 41, 34, 26, 18, 10, 2, -5,
+	 -13, -21, -28, -36, -44, -51, -58, -65,
+	 -72, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -112,
+	 -108, -104, -99, -94, -89, -83, -77, -70,
+	 -64, -57, -49, -42, -35, -27, -19, -11,
+	 -3, 4, 12, 20, 27, 35, 43, 50,
+	 57, 64, 71, 77, 84, 89, 95, 100,
+	 105, 109, 113, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 123, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 71, 64, 58, 50, 43, 35, 28,
+	 20, 12, 4, -3, -11, -19, -27, -34,
+	 -42, -49, -57, -64, -70, -77, -83, -89,
+	 -94, -99, -104, -108, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -101, -96,
+	 -90, -84, -78, -72, -65, -58, -51, -44,
+	 -36, -29, -21, -13, -5, 2, 10, 18,
+	 26, 33, 41, 48, 56, 63, 70, 76,
+	 82, 88, 94, 99, 104, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 101, 96, 91, 85, 79, 73, 66, 59,
+	 52, 45, 37, 29, 22, 14, 6, -1,
+	 -9, -17, -25, -33, -40, -48, -55, -62,
+	 -69, -75, -82, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -121, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -102, -97, -91, -86, -80, -73,
+	 -67, -60, -53, -45, -38, -30, -23, -15,
+	 -7, 0, 8, 16, 24, 32, 39, 47,
+	 54, 61, 68, 75, 81, 87, 92, 98,
+	 103, 107, 111, 115, 118, 120, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 118, 114, 111, 107, 102, 97, 92, 86,
+	 80, 74, 68, 61, 54, 46, 39, 31,
+	 23, 16, 8, 0, -7, -15, -23, -31,
+	 -38, -46, -53, -60, -67, -74, -80, -86,
+	 -92, -97, -102, -106, -111, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -121, -118, -115, -111, -107, -103, -98,
+	 -93, -87, -81, -75, -68, -62, -54, -47,
+	 -40, -32, -24, -16, -9, -1, 6, 14,
+	 22, 30, 38, 45, 52, 60, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 103, 98, 93, 88, 82, 76, 69, 62,
+	 55, 48, 41, 33, 25, 17, 9, 1,
+	 -5, -13, -21, -29, -37, -44, -52, -59,
+	 -66, -72, -79, -85, -91, -96, -101, -105,
+	 -110, -113, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -104, -99, -94, -88, -83, -76,
+	 -70, -63, -56, -49, -41, -34, -26, -18,
+	 -10, -2, 5, 13, 20, 28, 36, 43,
+	 51, 58, 65, 72, 78, 84, 90, 95,
+	 100, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 109, 104, 100, 94, 89,
+	 83, 77, 71, 64, 57, 50, 42, 35,
+	 27, 19, 11, 3, -4, -12, -20, -27,
+	 -35, -43, -50, -57, -64, -71, -77, -83,
+	 -89, -95, -100, -104, -109, -113, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -100,
+	 -95, -90, -84, -78, -71, -65, -58, -50,
+	 -43, -36, -28, -20, -12, -4, 3, 11,
+	 19, 26, 34, 42, 49, 56, 63, 70,
+	 77, 83, 89, 94, 99, 104, 108, 112,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 117, 113, 109,
+	 105, 101, 96, 90, 85, 78, 72, 65,
+	 58, 51, 44, 36, 29, 21, 13, 5,
+	 -2, -10, -18, -26, -33, -41, -48, -56,
+	 -63, -69, -76, -82, -88, -94, -99, -103,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -59, -52, -45, -37, -30, -22,
+	 -14, -6, 1, 9, 17, 25, 32, 40,
+	 47, 55, 62, 69, 75, 81, 87, 93,
+	 98, 103, 107, 111, 115, 118, 121, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 102, 97, 92,
+	 86, 80, 74, 67, 60, 53, 46, 38,
+	 30, 23, 15, 7, 0, -8, -16, -24,
+	 -32, -39, -47, -54, -61, -68, -74, -81,
+	 -87, -92, -98, -102, -107, -111, -115, -118,
+	 -120, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -123, -120, -118, -114, -111, -107, -102,
+	 -97, -92, -87, -81, -74, -68, -61, -54,
+	 -46, -39, -31, -24, -16, -8, 0, 7,
+	 15, 23, 31, 38, 46, 53, 60, 67,
+	 74, 80, 86, 92, 97, 102, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 121, 118, 115, 111,
+	 107, 103, 98, 93, 87, 81, 75, 68,
+	 62, 55, 47, 40, 32, 24, 17, 9,
+	 1, -6, -14, -22, -30, -37, -45, -52,
+	 -59, -66, -73, -79, -85, -91, -96, -101,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -103, -99, -93, -88, -82,
+	 -76, -69, -62, -55, -48, -41, -33, -25,
+	 -18, -10, -2, 5, 13, 21, 29, 37,
+	 44, 51, 59, 66, 72, 79, 85, 90,
+	 96, 101, 105, 110, 113, 117, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 108, 104, 99, 94,
+	 88, 83, 76, 70, 63, 56, 49, 42,
+	 34, 26, 18, 11, 3, -4, -12, -20,
+	 -28, -36, -43, -51, -58, -65, -71, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -113, -109, -104,
+	 -100, -95, -89, -83, -77, -71, -64, -57,
+	 -50, -42, -35, -27, -19, -11, -3, 3,
+	 11, 19, 27, 35, 42, 50, 57, 64,
+	 71, 77, 83, 89, 95, 100, 104, 109,
+	 113, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 71,
+	 65, 58, 51, 43, 36, 28, 20, 12,
+	 4, -3, -11, -18, -26, -34, -42, -49,
+	 -56, -63, -70, -76, -83, -88, -94, -99,
+	 -104, -108, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -117, -113, -110, -105, -101, -96, -90, -85,
+	 -79, -72, -66, -59, -51, -44, -37, -29,
+	 -21, -13, -5, 2, 10, 18, 25, 33,
+	 41, 48, 55, 62, 69, 76, 82, 88,
+	 93, 99, 103, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 101, 96,
+	 91, 85, 79, 73, 66, 59, 52, 45,
+	 37, 30, 22, 14, 6, -1, -9, -17,
+	 -24, -32, -40, -47, -55, -62, -68, -75,
+	 -81, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -121, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -102, -97, -92, -86, -80, -74, -67, -60,
+	 -53, -46, -38, -31, -23, -15, -7, 0,
+	 8, 16, 24, 31, 39, 46, 54, 61,
+	 68, 74, 81, 87, 92, 97, 102, 107,
+	 111, 114, 118, 120, 123, 124, 125, 126,
+	 126, 126, 126, 124, 123, 120, 118, 115,
+	 111, 107, 102, 98, 92, 87, 81, 74,
+	 68, 61, 54, 47, 39, 32, 24, 16,
+	 8, 0, -7, -15, -23, -30, -38, -46,
+	 -53, -60, -67, -74, -80, -86, -92, -97,
+	 -102, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -121,
+	 -118, -115, -111, -107, -103, -98, -93, -87,
+	 -81, -75, -69, -62, -55, -47, -40, -32,
+	 -25, -17, -9, -1, 6, 14, 22, 30,
+	 37, 45, 52, 59, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 103, 99,
+	 94, 88, 82, 76, 69, 63, 56, 48,
+	 41, 33, 26, 18, 10, 2, -5, -13,
+	 -21, -29, -36, -44, -51, -58, -65, -72,
+	 -78, -85, -90, -96, -101, -105, -109, -113,
+	 -117, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -112, -108,
+	 -104, -99, -94, -89, -83, -77, -70, -63,
+	 -56, -49, -42, -34, -26, -19, -11, -3,
+	 4, 12, 20, 28, 36, 43, 50, 58,
+	 65, 71, 78, 84, 90, 95, 100, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 113, 109, 104, 100, 95, 89, 83, 77,
+	 71, 64, 57, 50, 43, 35, 27, 20,
+	 12, 4, -3, -11, -19, -27, -35, -42,
+	 -50, -57, -64, -71, -77, -83, -89, -94,
+	 -100, -104, -109, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -100, -95, -90,
+	 -84, -78, -72, -65, -58, -51, -43, -36,
+	 -28, -20, -13, -5, 2, 10, 18, 26,
+	 34, 41, 49, 56, 63, 70, 76, 83,
+	 88, 94, 99, 104, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 113, 110, 105, 101,
+	 96, 91, 85, 79, 72, 66, 59, 52,
+	 44, 37, 29, 21, 13, 5, -1, -9,
+	 -17, -25, -33, -41, -48, -55, -62, -69,
+	 -76, -82, -88, -93, -98, -103, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -60, -52, -45, -38, -30, -22, -14, -6,
+	 1, 9, 16, 24, 32, 40, 47, 54,
+	 62, 68, 75, 81, 87, 93, 98, 103,
+	 107, 111, 115, 118, 121, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 111, 106, 102, 97, 92, 86, 80,
+	 74, 67, 60, 53, 46, 38, 31, 23,
+	 15, 7, 0, -8, -16, -23, -31, -39,
+	 -46, -54, -61, -68, -74, -80, -86, -92,
+	 -97, -102, -107, -111, -114, -118, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -120, -118, -115, -111, -107, -103, -98, -92,
+	 -87, -81, -75, -68, -61, -54, -47, -39,
+	 -32, -24, -16, -8, 0, 7, 15, 23,
+	 30, 38, 45, 53, 60, 67, 73, 80,
+	 86, 91, 97, 102, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 121, 118, 115, 111, 107, 103,
+	 98, 93, 87, 82, 75, 69, 62, 55,
+	 48, 40, 33, 25, 17, 9, 1, -6,
+	 -14, -22, -29, -37, -45, -52, -59, -66,
+	 -73, -79, -85, -91, -96, -101, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -104, -99, -94, -88, -82, -76, -70,
+	 -63, -56, -48, -41, -33, -26, -18, -10,
+	 -2, 5, 13, 21, 29, 36, 44, 51,
+	 58, 65, 72, 78, 84, 90, 96, 101,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 108, 104, 99, 94, 89, 83,
+	 77, 70, 64, 57, 49, 42, 34, 27,
+	 19, 11, 3, -4, -12, -20, -28, -35,
+	 -43, -50, -58, -64, -71, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -123, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -113, -109, -105, -100, -95,
+	 -89, -84, -77, -71, -64, -57, -50, -43,
+	 -35, -27, -20, -12, -4, 3, 11, 19,
+	 27, 35, 42, 49, 57, 64, 70, 77,
+	 83, 89, 94, 99, 104, 108, 112, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 72, 65, 58,
+	 51, 44, 36, 28, 21, 13, 5, -2,
+	 -10, -18, -26, -34, -41, -49, -56, -63,
+	 -70, -76, -82, -88, -94, -99, -104, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -113,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -59, -52, -44, -37, -29, -21, -14,
+	 -6, 1, 9, 17, 25, 33, 40, 48,
+	 55, 62, 69, 75, 82, 88, 93, 98,
+	 103, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 102, 97, 91, 86,
+	 80, 73, 67, 60, 53, 45, 38, 30,
+	 22, 14, 7, 0, -8, -16, -24, -32,
+	 -40, -47, -54, -61, -68, -75, -81, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -120,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -111, -107, -102, -97,
+	 -92, -86, -80, -74, -67, -61, -53, -46,
+	 -39, -31, -23, -15, -7, 0, 7, 15,
+	 23, 31, 39, 46, 53, 61, 67, 74,
+	 80, 86, 92, 97, 102, 107, 111, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 120, 118, 115, 111, 107,
+	 103, 98, 93, 87, 81, 75, 68, 61,
+	 54, 47, 40, 32, 24, 16, 8, 0,
+	 -7, -14, -22, -30, -38, -45, -53, -60,
+	 -67, -73, -80, -86, -91, -97, -102, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -103, -98, -93, -88, -82, -75,
+	 -69, -62, -55, -48, -40, -33, -25, -17,
+	 -9, -1, 6, 14, 21, 29, 37, 44,
+	 52, 59, 66, 73, 79, 85, 91, 96,
+	 101, 106, 110, 113, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 104, 99, 94, 88,
+	 82, 76, 70, 63, 56, 49, 41, 34,
+	 26, 18, 10, 2, -5, -13, -21, -28,
+	 -36, -44, -51, -58, -65, -72, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -112, -108, -104, -99,
+	 -94, -89, -83, -77, -70, -64, -57, -49,
+	 -42, -35, -27, -19, -11, -3, 4, 12,
+	 20, 27, 35, 43, 50, 57, 64, 71,
+	 77, 84, 89, 95, 100, 105, 109, 113,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 123, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 71, 64,
+	 58, 50, 43, 35, 28, 20, 12, 4,
+	 -3, -11, -19, -27, -34, -42, -49, -57,
+	 -64, -70, -77, -83, -89, -94, -99, -104,
+	 -108, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -101, -96, -90, -84, -78,
+	 -72, -65, -58, -51, -44, -36, -29, -21,
+	 -13, -5, 2, 10, 18, 26, 33, 41,
+	 48, 56, 63, 70, 76, 82, 88, 94,
+	 99, 104, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 101, 96, 91,
+	 85, 79, 73, 66, 59, 52, 45, 37,
+	 29, 22, 14, 6, -1, -9, -17, -25,
+	 -33, -40, -48, -55, -62, -69, -75, -82,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -121, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -102,
+	 -97, -91, -86, -80, -73, -67, -60, -53,
+	 -45, -38, -30, -23, -15, -7, 0, 8,
+	 16, 24, 32, 39, 47, 54, 61, 68,
+	 75, 81, 87, 92, 98, 103, 107, 111,
+	 115, 118, 120, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 118, 114, 111,
+	 107, 102, 97, 92, 86, 80, 74, 68,
+	 61, 54, 46, 39, 31, 23, 16, 8,
+	 0, -7, -15, -23, -31, -38, -46, -53,
+	 -60, -67, -74, -80, -86, -92, -97, -102,
+	 -106, -111, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -121, -118,
+	 -115, -111, -107, -103, -98, -93, -87, -81,
+	 -75, -68, -62, -54, -47, -40, -32, -24,
+	 -16, -9, -1, 6, 14, 22, 30, 38,
+	 45, 52, 60, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 103, 98, 93,
+	 88, 82, 76, 69, 62, 55, 48, 41,
+	 33, 25, 17, 9, 1, -5, -13, -21,
+	 -29, -37, -44, -52, -59, -66, -72, -79,
+	 -85, -91, -96, -101, -105, -110, -113, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -104,
+	 -99, -94, -88, -83, -76, -70, -63, -56,
+	 -49, -41, -34, -26, -18, -10, -2, 5,
+	 13, 20, 28, 36, 43, 51, 58, 65,
+	 72, 78, 84, 90, 95, 100, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 109, 104, 100, 94, 89, 83, 77, 71,
+	 64, 57, 50, 42, 35, 27, 19, 11,
+	 3, -4, -12, -20, -27, -35, -43, -50,
+	 -57, -64, -71, -77, -83, -89, -95, -100,
+	 -104, -109, -113, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -100, -95, -90, -84,
+	 -78, -71, -65, -58, -50, -43, -36, -28,
+	 -20, -12, -4, 3, 11, 19, 26, 34,
+	 42, 49, 56, 63, 70, 77, 83, 89,
+	 94, 99, 104, 108, 112, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 117, 113, 109, 105, 101, 96,
+	 90, 85, 78, 72, 65, 58, 51, 44,
+	 36, 29, 21, 13, 5, -2, -10, -18,
+	 -26, -33, -41, -48, -56, -63, -69, -76,
+	 -82, -88, -94, -99, -103, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -59,
+	 -52, -45, -37, -30, -22, -14, -6, 1,
+	 9, 17, 25, 32, 40, 47, 55, 62,
+	 69, 75, 81, 87, 93, 98, 103, 107,
+	 111, 115, 118, 121, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 102, 97, 92, 86, 80, 74,
+	 67, 60, 53, 46, 38, 30, 23, 15,
+	 7, 0, -8, -16, -24, -32, -39, -47,
+	 -54, -61, -68, -74, -81, -87, -92, -98,
+	 -102, -107, -111, -115, -118, -120, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -123, -120,
+	 -118, -114, -111, -107, -102, -97, -92, -87,
+	 -81, -74, -68, -61, -54, -46, -39, -31,
+	 -24, -16, -8, 0, 7, 15, 23, 31,
+	 38, 46, 53, 60, 67, 74, 80, 86,
+	 92, 97, 102, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 121, 118, 115, 111, 107, 103, 98,
+	 93, 87, 81, 75, 68, 62, 55, 47,
+	 40, 32, 24, 17, 9, 1, -6, -14,
+	 -22, -30, -37, -45, -52, -59, -66, -73,
+	 -79, -85, -91, -96, -101, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -103, -99, -93, -88, -82, -76, -69, -62,
+	 -55, -48, -41, -33, -25, -18, -10, -2,
+	 5, 13, 21, 29, 37, 44, 51, 59,
+	 66, 72, 79, 85, 90, 96, 101, 105,
+	 110, 113, 117, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 108, 104, 99, 94, 88, 83, 76,
+	 70, 63, 56, 49, 42, 34, 26, 18,
+	 11, 3, -4, -12, -20, -28, -36, -43,
+	 -51, -58, -65, -71, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -113, -109, -104, -100, -95, -89,
+	 -83, -77, -71, -64, -57, -50, -42, -35,
+	 -27, -19, -11, -3, 3, 11, 19, 27,
+	 35, 42, 50, 57, 64, 71, 77, 83,
+	 89, 95, 100, 104, 109, 113, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 71, 65, 58, 51,
+	 43, 36, 28, 20, 12, 4, -3, -11,
+	 -18, -26, -34, -42, -49, -56, -63, -70,
+	 -76, -83, -88, -94, -99, -104, -108, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -117, -113, -110,
+	 -105, -101, -96, -90, -85, -79, -72, -66,
+	 -59, -51, -44, -37, -29, -21, -13, -5,
+	 2, 10, 18, 25, 33, 41, 48, 55,
+	 62, 69, 76, 82, 88, 93, 99, 103,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 101, 96, 91, 85, 79,
+	 73, 66, 59, 52, 45, 37, 30, 22,
+	 14, 6, -1, -9, -17, -24, -32, -40,
+	 -47, -55, -62, -68, -75, -81, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -121, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -102, -97, -92,
+	 -86, -80, -74, -67, -60, -53, -46, -38,
+	 -31, -23, -15, -7, 0, 8, 16, 24,
+	 31, 39, 46, 54, 61, 68, 74, 81,
+	 87, 92, 97, 102, 107, 111, 114, 118,
+	 120, 123, 124, 125, 126, 126, 126, 126,
+	 124, 123, 120, 118, 115, 111, 107, 102,
+	 98, 92, 87, 81, 74, 68, 61, 54,
+	 47, 39, 32, 24, 16, 8, 0, -7,
+	 -15, -23, -30, -38, -46, -53, -60, -67,
+	 -74, -80, -86, -92, -97, -102, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -121, -118, -115, -111,
+	 -107, -103, -98, -93, -87, -81, -75, -69,
+	 -62, -55, -47, -40, -32, -25, -17, -9,
+	 -1, 6, 14, 22, 30, 37, 45, 52,
+	 59, 66, 73, 79, 85, 91, 96, 101,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 103, 99, 94, 88, 82,
+	 76, 69, 63, 56, 48, 41, 33, 26,
+	 18, 10, 2, -5, -13, -21, -29, -36,
+	 -44, -51, -58, -65, -72, -78, -85, -90,
+	 -96, -101, -105, -109, -113, -117, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -112, -108, -104, -99, -94,
+	 -89, -83, -77, -70, -63, -56, -49, -42,
+	 -34, -26, -19, -11, -3, 4, 12, 20,
+	 28, 36, 43, 50, 58, 65, 71, 78,
+	 84, 90, 95, 100, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 113, 109, 104,
+	 100, 95, 89, 83, 77, 71, 64, 57,
+	 50, 43, 35, 27, 20, 12, 4, -3,
+	 -11, -19, -27, -35, -42, -50, -57, -64,
+	 -71, -77, -83, -89, -94, -100, -104, -109,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -100, -95, -90, -84, -78, -72,
+	 -65, -58, -51, -43, -36, -28, -20, -13,
+	 -5, 2, 10, 18, 26, 34, 41, 49,
+	 56, 63, 70, 76, 83, 88, 94, 99,
+	 104, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 113, 110, 105, 101, 96, 91, 85,
+	 79, 72, 66, 59, 52, 44, 37, 29,
+	 21, 13, 5, -1, -9, -17, -25, -33,
+	 -41, -48, -55, -62, -69, -76, -82, -88,
+	 -93, -98, -103, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -66, -60, -52, -45,
+	 -38, -30, -22, -14, -6, 1, 9, 16,
+	 24, 32, 40, 47, 54, 62, 68, 75,
+	 81, 87, 93, 98, 103, 107, 111, 115,
+	 118, 121, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 111, 106,
+	 102, 97, 92, 86, 80, 74, 67, 60,
+	 53, 46, 38, 31, 23, 15, 7, 0,
+	 -8, -16, -23, -31, -39, -46, -54, -61,
+	 -68, -74, -80, -86, -92, -97, -102, -107,
+	 -111, -114, -118, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -120, -118, -115,
+	 -111, -107, -103, -98, -92, -87, -81, -75,
+	 -68, -61, -54, -47, -39, -32, -24, -16,
+	 -8, 0, 7, 15, 23, 30, 38, 45,
+	 53, 60, 67, 73, 80, 86, 91, 97,
+	 102, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 121,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 82, 75, 69, 62, 55, 48, 40, 33,
+	 25, 17, 9, 1, -6, -14, -22, -29,
+	 -37, -45, -52, -59, -66, -73, -79, -85,
+	 -91, -96, -101, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -104, -99,
+	 -94, -88, -82, -76, -70, -63, -56, -48,
+	 -41, -33, -26, -18, -10, -2, 5, 13,
+	 21, 29, 36, 44, 51, 58, 65, 72,
+	 78, 84, 90, 96, 101, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 108,
+	 104, 99, 94, 89, 83, 77, 70, 64,
+	 57, 49, 42, 34, 27, 19, 11, 3,
+	 -4, -12, -20, -28, -35, -43, -50, -58,
+	 -64, -71, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -123, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -113, -109, -105, -100, -95, -89, -84, -77,
+	 -71, -64, -57, -50, -43, -35, -27, -20,
+	 -12, -4, 3, 11, 19, 27, 35, 42,
+	 49, 57, 64, 70, 77, 83, 89, 94,
+	 99, 104, 108, 112, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 72, 65, 58, 51, 44, 36,
+	 28, 21, 13, 5, -2, -10, -18, -26,
+	 -34, -41, -49, -56, -63, -70, -76, -82,
+	 -88, -94, -99, -104, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -113, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -66, -59, -52,
+	 -44, -37, -29, -21, -14, -6, 1, 9,
+	 17, 25, 33, 40, 48, 55, 62, 69,
+	 75, 82, 88, 93, 98, 103, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 102, 97, 91, 86, 80, 73, 67,
+	 60, 53, 45, 38, 30, 22, 14, 7,
+	 0, -8, -16, -24, -32, -40, -47, -54,
+	 -61, -68, -75, -81, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -120, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -111, -107, -102, -97, -92, -86, -80,
+	 -74, -67, -61, -53, -46, -39, -31, -23,
+	 -15, -7, 0, 7, 15, 23, 31, 39,
+	 46, 53, 61, 67, 74, 80, 86, 92,
+	 97, 102, 107, 111, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 120, 118, 115, 111, 107, 103, 98, 93,
+	 87, 81, 75, 68, 61, 54, 47, 40,
+	 32, 24, 16, 8, 0, -7, -14, -22,
+	 -30, -38, -45, -53, -60, -67, -73, -80,
+	 -86, -91, -97, -102, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -103,
+	 -98, -93, -88, -82, -75, -69, -62, -55,
+	 -48, -40, -33, -25, -17, -9, -1, 6,
+	 14, 21, 29, 37, 44, 52, 59, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 113, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 104, 99, 94, 88, 82, 76, 70,
+	 63, 56, 49, 41, 34, 26, 18, 10,
+	 2, -5, -13, -21, -28, -36, -44, -51,
+	 -58, -65, -72, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -112, -108, -104, -99, -94, -89, -83,
+	 -77, -70, -64, -57, -49, -42, -35, -27,
+	 -19, -11, -3, 4, 12, 20, 27, 35,
+	 43, 50, 57, 64, 71, 77, 84, 89,
+	 95, 100, 105, 109, 113, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 123,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 71, 64, 58, 50, 43,
+	 35, 28, 20, 12, 4, -3, -11, -19,
+	 -27, -34, -42, -49, -57, -64, -70, -77,
+	 -83, -89, -94, -99, -104, -108, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -101, -96, -90, -84, -78, -72, -65, -58,
+	 -51, -44, -36, -29, -21, -13, -5, 2,
+	 10, 18, 26, 33, 41, 48, 56, 63,
+	 70, 76, 82, 88, 94, 99, 104, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 101, 96, 91, 85, 79, 73,
+	 66, 59, 52, 45, 37, 29, 22, 14,
+	 6, -1, -9, -17, -25, -33, -40, -48,
+	 -55, -62, -69, -75, -82, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -121, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -102, -97, -91, -86,
+	 -80, -73, -67, -60, -53, -45, -38, -30,
+	 -23, -15, -7, 0, 8, 16, 24, 32,
+	 39, 47, 54, 61, 68, 75, 81, 87,
+	 92, 98, 103, 107, 111, 115, 118, 120,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 118, 114, 111, 107, 102, 97,
+	 92, 86, 80, 74, 68, 61, 54, 46,
+	 39, 31, 23, 16, 8, 0, -7, -15,
+	 -23, -31, -38, -46, -53, -60, -67, -74,
+	 -80, -86, -92, -97, -102, -106, -111, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -121, -118, -115, -111, -107,
+	 -103, -98, -93, -87, -81, -75, -68, -62,
+	 -54, -47, -40, -32, -24, -16, -9, -1,
+	 6, 14, 22, 30, 38, 45, 52, 60,
+	 66, 73, 79, 85, 91, 96, 101, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 103, 98, 93, 88, 82, 76,
+	 69, 62, 55, 48, 41, 33, 25, 17,
+	 9, 1, -5, -13, -21, -29, -37, -44,
+	 -52, -59, -66, -72, -79, -85, -91, -96,
+	 -101, -105, -110, -113, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -104, -99, -94, -88,
+	 -83, -76, -70, -63, -56, -49, -41, -34,
+	 -26, -18, -10, -2, 5, 13, 20, 28,
+	 36, 43, 51, 58, 65, 72, 78, 84,
+	 90, 95, 100, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 109, 104, 100,
+	 94, 89, 83, 77, 71, 64, 57, 50,
+	 42, 35, 27, 19, 11, 3, -4, -12,
+	 -20, -27, -35, -43, -50, -57, -64, -71,
+	 -77, -83, -89, -95, -100, -104, -109, -113,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -100, -95, -90, -84, -78, -71, -65,
+	 -58, -50, -43, -36, -28, -20, -12, -4,
+	 3, 11, 19, 26, 34, 42, 49, 56,
+	 63, 70, 77, 83, 89, 94, 99, 104,
+	 108, 112, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 117,
+	 113, 109, 105, 101, 96, 90, 85, 78,
+	 72, 65, 58, 51, 44, 36, 29, 21,
+	 13, 5, -2, -10, -18, -26, -33, -41,
+	 -48, -56, -63, -69, -76, -82, -88, -94,
+	 -99, -103, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -59, -52, -45, -37,
+	 -30, -22, -14, -6, 1, 9, 17, 25,
+	 32, 40, 47, 55, 62, 69, 75, 81,
+	 87, 93, 98, 103, 107, 111, 115, 118,
+	 121, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 102,
+	 97, 92, 86, 80, 74, 67, 60, 53,
+	 46, 38, 30, 23, 15, 7, 0, -8,
+	 -16, -24, -32, -39, -47, -54, -61, -68,
+	 -74, -81, -87, -92, -98, -102, -107, -111,
+	 -115, -118, -120, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -123, -120, -118, -114, -111,
+	 -107, -102, -97, -92, -87, -81, -74, -68,
+	 -61, -54, -46, -39, -31, -24, -16, -8,
+	 0, 7, 15, 23, 31, 38, 46, 53,
+	 60, 67, 74, 80, 86, 92, 97, 102,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 121, 118,
+	 115, 111, 107, 103, 98, 93, 87, 81,
+	 75, 68, 62, 55, 47, 40, 32, 24,
+	 17, 9, 1, -6, -14, -22, -30, -37,
+	 -45, -52, -59, -66, -73, -79, -85, -91,
+	 -96, -101, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -103, -99, -93,
+	 -88, -82, -76, -69, -62, -55, -48, -41,
+	 -33, -25, -18, -10, -2, 5, 13, 21,
+	 29, 37, 44, 51, 59, 66, 72, 79,
+	 85, 90, 96, 101, 105, 110, 113, 117,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 108, 104,
+	 99, 94, 88, 83, 76, 70, 63, 56,
+	 49, 42, 34, 26, 18, 11, 3, -4,
+	 -12, -20, -28, -36, -43, -51, -58, -65,
+	 -71, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -113,
+	 -109, -104, -100, -95, -89, -83, -77, -71,
+	 -64, -57, -50, -42, -35, -27, -19, -11,
+	 -3, 3, 11, 19, 27, 35, 42, 50,
+	 57, 64, 71, 77, 83, 89, 95, 100,
+	 104, 109, 113, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 71, 65, 58, 51, 43, 36, 28,
+	 20, 12, 4, -3, -11, -18, -26, -34,
+	 -42, -49, -56, -63, -70, -76, -83, -89,
+	 -94, -99, -104, -108, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -117, -113, -110, -105, -101, -96,
+	 -90, -85, -79, -72, -66, -59, -51, -44,
+	 -37, -29, -21, -13, -5, 2, 10, 18,
+	 25, 33, 41, 48, 55, 62, 69, 76,
+	 82, 88, 93, 99, 103, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 101, 96, 91, 85, 79, 73, 66, 59,
+	 52, 45, 37, 30, 22, 14, 6, -1,
+	 -9, -17, -24, -32, -40, -47, -55, -62,
+	 -68, -75, -81, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -121, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -102, -97, -92, -86, -80, -74,
+	 -67, -60, -53, -46, -38, -31, -23, -15,
+	 -7, 0, 8, 16, 24, 31, 39, 46,
+	 54, 61, 68, 74, 81, 87, 92, 97,
+	 102, 107, 111, 114, 118, 120, 123, 124,
+	 125, 126, 126, 126, 126, 124, 123, 120,
+	 118, 115, 111, 107, 102, 98, 92, 87,
+	 81, 74, 68, 61, 54, 47, 39, 32,
+	 24, 16, 8, 0, -7, -15, -23, -30,
+	 -38, -46, -53, -60, -67, -74, -80, -86,
+	 -92, -97, -102, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -121, -118, -115, -111, -107, -103, -98,
+	 -93, -87, -81, -75, -69, -62, -55, -47,
+	 -40, -32, -25, -17, -9, -1, 6, 14,
+	 22, 30, 37, 45, 52, 59, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 103, 99, 94, 88, 82, 76, 69, 63,
+	 56, 48, 41, 33, 26, 18, 10, 2,
+	 -5, -13, -21, -29, -36, -44, -51, -58,
+	 -65, -72, -78, -85, -90, -96, -101, -105,
+	 -109, -113, -117, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -112, -108, -104, -99, -94, -89, -83, -77,
+	 -70, -63, -56, -49, -42, -34, -26, -19,
+	 -11, -3, 4, 12, 20, 28, 36, 43,
+	 50, 58, 65, 71, 78, 84, 90, 95,
+	 100, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 113, 109, 104, 100, 95, 89,
+	 83, 77, 71, 64, 57, 50, 43, 35,
+	 27, 20, 12, 4, -3, -11, -19, -27,
+	 -35, -42, -50, -57, -64, -71, -77, -83,
+	 -89, -94, -100, -104, -109, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -100,
+	 -95, -90, -84, -78, -72, -65, -58, -51,
+	 -43, -36, -28, -20, -13, -5, 2, 10,
+	 18, 26, 34, 41, 49, 56, 63, 70,
+	 76, 83, 88, 94, 99, 104, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 113, 110,
+	 105, 101, 96, 91, 85, 79, 72, 66,
+	 59, 52, 44, 37, 29, 21, 13, 5,
+	 -1, -9, -17, -25, -33, -41, -48, -55,
+	 -62, -69, -76, -82, -88, -93, -98, -103,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -60, -52, -45, -38, -30, -22,
+	 -14, -6, 1, 9, 16, 24, 32, 40,
+	 47, 54, 62, 68, 75, 81, 87, 93,
+	 98, 103, 107, 111, 115, 118, 121, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 111, 106, 102, 97, 92,
+	 86, 80, 74, 67, 60, 53, 46, 38,
+	 31, 23, 15, 7, 0, -8, -16, -23,
+	 -31, -39, -46, -54, -61, -68, -74, -80,
+	 -86, -92, -97, -102, -107, -111, -114, -118,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -120, -118, -115, -111, -107, -103,
+	 -98, -92, -87, -81, -75, -68, -61, -54,
+	 -47, -39, -32, -24, -16, -8, 0, 7,
+	 15, 23, 30, 38, 45, 53, 60, 67,
+	 73, 80, 86, 91, 97, 102, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 121, 118, 115, 111,
+	 107, 103, 98, 93, 87, 82, 75, 69,
+	 62, 55, 48, 40, 33, 25, 17, 9,
+	 1, -6, -14, -22, -29, -37, -45, -52,
+	 -59, -66, -73, -79, -85, -91, -96, -101,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -104, -99, -94, -88, -82,
+	 -76, -70, -63, -56, -48, -41, -33, -26,
+	 -18, -10, -2, 5, 13, 21, 29, 36,
+	 44, 51, 58, 65, 72, 78, 84, 90,
+	 96, 101, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 108, 104, 99, 94,
+	 89, 83, 77, 70, 64, 57, 49, 42,
+	 34, 27, 19, 11, 3, -4, -12, -20,
+	 -28, -35, -43, -50, -58, -64, -71, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -123, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -113, -109, -105,
+	 -100, -95, -89, -84, -77, -71, -64, -57,
+	 -50, -43, -35, -27, -20, -12, -4, 3,
+	 11, 19, 27, 35, 42, 49, 57, 64,
+	 70, 77, 83, 89, 94, 99, 104, 108,
+	 112, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 72,
+	 65, 58, 51, 44, 36, 28, 21, 13,
+	 5, -2, -10, -18, -26, -34, -41, -49,
+	 -56, -63, -70, -76, -82, -88, -94, -99,
+	 -104, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -113, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -59, -52, -44, -37, -29,
+	 -21, -14, -6, 1, 9, 17, 25, 33,
+	 40, 48, 55, 62, 69, 75, 82, 88,
+	 93, 98, 103, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 102, 97,
+	 91, 86, 80, 73, 67, 60, 53, 45,
+	 38, 30, 22, 14, 7, 0, -8, -16,
+	 -24, -32, -40, -47, -54, -61, -68, -75,
+	 -81, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -120, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -111, -107,
+	 -102, -97, -92, -86, -80, -74, -67, -61,
+	 -53, -46, -39, -31, -23, -15, -7, 0,
+	 7, 15, 23, 31, 39, 46, 53, 61,
+	 67, 74, 80, 86, 92, 97, 102, 107,
+	 111, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 120, 118, 115,
+	 111, 107, 103, 98, 93, 87, 81, 75,
+	 68, 61, 54, 47, 40, 32, 24, 16,
+	 8, 0, -7, -14, -22, -30, -38, -45,
+	 -53, -60, -67, -73, -80, -86, -91, -97,
+	 -102, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -103, -98, -93, -88,
+	 -82, -75, -69, -62, -55, -48, -40, -33,
+	 -25, -17, -9, -1, 6, 14, 21, 29,
+	 37, 44, 52, 59, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 113, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 104, 99,
+	 94, 88, 82, 76, 70, 63, 56, 49,
+	 41, 34, 26, 18, 10, 2, -5, -13,
+	 -21, -28, -36, -44, -51, -58, -65, -72,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -112, -108,
+	 -104, -99, -94, -89, -83, -77, -70, -64,
+	 -57, -49, -42, -35, -27, -19, -11, -3,
+	 4, 12, 20, 27, 35, 43, 50, 57,
+	 64, 71, 77, 84, 89, 95, 100, 105,
+	 109, 113, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 123, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 71, 64, 58, 50, 43, 35, 28, 20,
+	 12, 4, -3, -11, -19, -27, -34, -42,
+	 -49, -57, -64, -70, -77, -83, -89, -94,
+	 -99, -104, -108, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -101, -96, -90,
+	 -84, -78, -72, -65, -58, -51, -44, -36,
+	 -29, -21, -13, -5, 2, 10, 18, 26,
+	 33, 41, 48, 56, 63, 70, 76, 82,
+	 88, 94, 99, 104, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 101,
+	 96, 91, 85, 79, 73, 66, 59, 52,
+	 45, 37, 29, 22, 14, 6, -1, -9,
+	 -17, -25, -33, -40, -48, -55, -62, -69,
+	 -75, -82, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -121, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -102, -97, -91, -86, -80, -73, -67,
+	 -60, -53, -45, -38, -30, -23, -15, -7,
+	 0, 8, 16, 24, 32, 39, 47, 54,
+	 61, 68, 75, 81, 87, 92, 98, 103,
+	 107, 111, 115, 118, 120, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 118,
+	 114, 111, 107, 102, 97, 92, 86, 80,
+	 74, 68, 61, 54, 46, 39, 31, 23,
+	 16, 8, 0, -7, -15, -23, -31, -38,
+	 -46, -53, -60, -67, -74, -80, -86, -92,
+	 -97, -102, -106, -111, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -121, -118, -115, -111, -107, -103, -98, -93,
+	 -87, -81, -75, -68, -62, -54, -47, -40,
+	 -32, -24, -16, -9, -1, 6, 14, 22,
+	 30, 38, 45, 52, 60, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 103,
+	 98, 93, 88, 82, 76, 69, 62, 55,
+	 48, 41, 33, 25, 17, 9, 1, -5,
+	 -13, -21, -29, -37, -44, -52, -59, -66,
+	 -72, -79, -85, -91, -96, -101, -105, -110,
+	 -113, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -104, -99, -94, -88, -83, -76, -70,
+	 -63, -56, -49, -41, -34, -26, -18, -10,
+	 -2, 5, 13, 20, 28, 36, 43, 51,
+	 58, 65, 72, 78, 84, 90, 95, 100,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 109, 104, 100, 94, 89, 83,
+	 77, 71, 64, 57, 50, 42, 35, 27,
+	 19, 11, 3, -4, -12, -19, -27, -35,
+	 -43, -50, -57, -64, -71, -77, -83, -89,
+	 -95, -100, -104, -109, -113, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -100, -95,
+	 -90, -84, -78, -71, -65, -58, -50, -43,
+	 -36, -28, -20, -12, -4, 3, 11, 19,
+	 26, 34, 42, 49, 56, 63, 70, 77,
+	 83, 89, 94, 99, 104, 108, 112, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 117, 113, 109, 105,
+	 101, 96, 90, 85, 78, 72, 65, 58,
+	 51, 44, 36, 29, 21, 13, 5, -2,
+	 -10, -18, -26, -33, -41, -48, -56, -63,
+	 -69, -76, -82, -88, -94, -99, -103, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -59, -52, -45, -37, -30, -22, -14,
+	 -6, 1, 9, 17, 25, 32, 40, 47,
+	 55, 62, 69, 75, 81, 87, 93, 98,
+	 103, 107, 111, 115, 118, 121, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 102, 97, 92, 86,
+	 80, 74, 67, 60, 53, 46, 38, 30,
+	 23, 15, 7, 0, -8, -16, -24, -32,
+	 -39, -47, -54, -61, -68, -74, -81, -87,
+	 -92, -98, -102, -107, -111, -115, -118, -120,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -123, -120, -118, -114, -111, -107, -102, -97,
+	 -92, -87, -81, -74, -68, -61, -54, -46,
+	 -39, -31, -24, -16, -8, 0, 7, 15,
+	 23, 31, 38, 46, 53, 60, 67, 74,
+	 80, 86, 92, 97, 102, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 121, 118, 115, 111, 107,
+	 103, 98, 93, 87, 81, 75, 68, 62,
+	 55, 47, 40, 32, 24, 17, 9, 1,
+	 -6, -14, -22, -30, -37, -45, -52, -59,
+	 -66, -73, -79, -85, -91, -96, -101, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -103, -99, -93, -88, -82, -76,
+	 -69, -62, -55, -48, -41, -33, -25, -18,
+	 -10, -2, 5, 13, 21, 29, 37, 44,
+	 51, 59, 66, 72, 79, 85, 90, 96,
+	 101, 105, 110, 113, 117, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 108, 104, 99, 94, 88,
+	 83, 76, 70, 63, 56, 49, 42, 34,
+	 26, 18, 11, 3, -4, -12, -20, -28,
+	 -36, -43, -51, -58, -65, -71, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -113, -109, -104, -100,
+	 -95, -89, -83, -77, -71, -64, -57, -50,
+	 -42, -35, -27, -19, -11, -3, 3, 11,
+	 19, 27, 35, 42, 50, 57, 64, 71,
+	 77, 83, 89, 95, 100, 104, 109, 113,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 71, 65,
+	 58, 51, 43, 36, 28, 20, 12, 4,
+	 -3, -11, -18, -26, -34, -42, -49, -56,
+	 -63, -70, -76, -83, -88, -94, -99, -104,
+	 -108, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -117,
+	 -113, -110, -105, -101, -96, -90, -85, -79,
+	 -72, -66, -59, -51, -44, -37, -29, -21,
+	 -13, -5, 2, 10, 18, 25, 33, 41,
+	 48, 55, 62, 69, 76, 82, 88, 93,
+	 99, 103, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 101, 96, 91,
+	 85, 79, 73, 66, 59, 52, 45, 37,
+	 30, 22, 14, 6, -1, -9, -17, -24,
+	 -32, -40, -47, -55, -62, -68, -75, -81,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -121, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -102,
+	 -97, -92, -86, -80, -74, -67, -60, -53,
+	 -46, -38, -31, -23, -15, -7, 0, 8,
+	 16, 24, 31, 39, 46, 54, 61, 68,
+	 74, 81, 87, 92, 97, 102, 107, 111,
+	 114, 118, 120, 123, 124, 125, 126, 126,
+	 126, 126, 124, 123, 120, 118, 115, 111,
+	 107, 102, 98, 92, 87, 81, 74, 68,
+	 61, 54, 47, 39, 32, 24, 16, 8,
+	 0, -7, -15, -23, -30, -38, -46, -53,
+	 -60, -67, -74, -80, -86, -92, -97, -102,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -121, -118,
+	 -115, -111, -107, -103, -98, -93, -87, -81,
+	 -75, -69, -62, -55, -47, -40, -32, -25,
+	 -17, -9, -1, 6, 14, 22, 30, 37,
+	 45, 52, 59, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 103, 99, 94,
+	 88, 82, 76, 69, 63, 56, 48, 41,
+	 33, 26, 18, 10, 2, -5, -13, -21,
+	 -29, -36, -44, -51, -58, -65, -72, -78,
+	 -85, -90, -96, -101, -105, -109, -113, -117,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -112, -108, -104,
+	 -99, -94, -89, -83, -77, -70, -63, -56,
+	 -49, -42, -34, -26, -19, -11, -3, 4,
+	 12, 20, 28, 36, 43, 50, 58, 65,
+	 71, 78, 84, 90, 95, 100, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 113,
+	 109, 104, 100, 95, 89, 83, 77, 71,
+	 64, 57, 50, 43, 35, 27, 20, 12,
+	 4, -3, -11, -19, -27, -35, -42, -50,
+	 -57, -64, -71, -77, -83, -89, -94, -100,
+	 -104, -109, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -100, -95, -90, -84,
+	 -78, -72, -65, -58, -51, -43, -36, -28,
+	 -20, -13, -5, 2, 10, 18, 26, 34,
+	 41, 49, 56, 63, 70, 76, 83, 88,
+	 94, 99, 104, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 113, 110, 105, 101, 96,
+	 91, 85, 79, 72, 66, 59, 52, 44,
+	 37, 29, 21, 13, 5, -1, -9, -17,
+	 -25, -33, -41, -48, -55, -62, -69, -76,
+	 -82, -88, -93, -98, -103, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -60,
+	 -52, -45, -38, -30, -22, -14, -6, 1,
+	 9, 16, 24, 32, 40, 47, 54, 62,
+	 68, 75, 81, 87, 93, 98, 103, 107,
+	 111, 115, 118, 121, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 111, 106, 102, 97, 92, 86, 80, 74,
+	 67, 60, 53, 46, 38, 31, 23, 15,
+	 7, 0, -8, -16, -23, -31, -39, -46,
+	 -54, -61, -68, -74, -80, -86, -92, -97,
+	 -102, -107, -111, -114, -118, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -120,
+	 -118, -115, -111, -107, -103, -98, -92, -87,
+	 -81, -75, -68, -61, -54, -47, -39, -32,
+	 -24, -16, -8, 0, 7, 15, 23, 30,
+	 38, 45, 53, 60, 67, 73, 80, 86,
+	 91, 97, 102, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 121, 118, 115, 111, 107, 103, 98,
+	 93, 87, 82, 75, 69, 62, 55, 48,
+	 40, 33, 25, 17, 9, 1, -6, -14,
+	 -22, -29, -37, -45, -52, -59, -66, -73,
+	 -79, -85, -91, -96, -101, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -104, -99, -94, -88, -82, -76, -70, -63,
+	 -56, -48, -41, -33, -26, -18, -10, -2,
+	 5, 13, 21, 29, 36, 44, 51, 58,
+	 65, 72, 78, 84, 90, 96, 101, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 108, 104, 99, 94, 89, 83, 77,
+	 70, 64, 57, 49, 42, 34, 27, 19,
+	 11, 3, -4, -12, -20, -28, -35, -43,
+	 -50, -58, -64, -71, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -123,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -113, -109, -105, -100, -95, -89,
+	 -84, -77, -71, -64, -57, -50, -43, -35,
+	 -27, -20, -12, -4, 3, 11, 19, 27,
+	 35, 42, 50, 57, 64, 70, 77, 83,
+	 89, 94, 99, 104, 108, 112, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 72, 65, 58, 51,
+	 44, 36, 28, 21, 13, 5, -2, -10,
+	 -18, -26, -34, -41, -49, -56, -63, -70,
+	 -76, -82, -88, -94, -99, -104, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -113, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -59, -52, -44, -37, -29, -21, -14, -6,
+	 1, 9, 17, 25, 33, 40, 48, 55,
+	 62, 69, 75, 82, 88, 93, 98, 103,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 102, 97, 91, 86, 80,
+	 73, 67, 60, 53, 45, 38, 30, 22,
+	 14, 7, 0, -8, -16, -24, -32, -40,
+	 -47, -54, -61, -68, -75, -81, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -120, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -111, -107, -102, -97, -92,
+	 -86, -80, -74, -67, -61, -53, -46, -39,
+	 -31, -23, -15, -7, 0, 7, 15, 23,
+	 31, 39, 46, 53, 61, 67, 74, 80,
+	 86, 92, 97, 102, 107, 111, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 120, 118, 115, 111, 107, 103,
+	 98, 93, 87, 81, 75, 68, 61, 54,
+	 47, 40, 32, 24, 16, 8, 0, -7,
+	 -14, -22, -30, -38, -45, -53, -60, -67,
+	 -73, -80, -86, -91, -97, -102, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -103, -98, -93, -88, -82, -75, -69,
+	 -62, -55, -48, -40, -33, -25, -17, -9,
+	 -1, 6, 14, 21, 29, 37, 44, 52,
+	 59, 66, 73, 79, 85, 91, 96, 101,
+	 106, 110, 113, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 104, 99, 94, 88, 82,
+	 76, 70, 63, 56, 49, 41, 34, 26,
+	 18, 10, 2, -5, -13, -21, -28, -36,
+	 -44, -51, -58, -65, -72, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -112, -108, -104, -99, -94,
+	 -89, -83, -77, -70, -64, -57, -49, -42,
+	 -35, -27, -19, -11, -3, 4, 12, 20,
+	 27, 35, 43, 50, 57, 64, 71, 77,
+	 84, 89, 95, 100, 105, 109, 113, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 123, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 71, 64, 58,
+	 50, 43, 35, 28, 20, 12, 4, -3,
+	 -11, -19, -27, -34, -42, -49, -57, -64,
+	 -70, -77, -83, -89, -94, -99, -104, -108,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -101, -96, -90, -84, -78, -72,
+	 -65, -58, -51, -44, -36, -29, -21, -13,
+	 -5, 2, 10, 18, 26, 33, 41, 48,
+	 56, 63, 70, 76, 82, 88, 94, 99,
+	 104, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 101, 96, 91, 85,
+	 79, 73, 66, 59, 52, 45, 37, 29,
+	 22, 14, 6, -1, -9, -17, -25, -33,
+	 -40, -48, -55, -62, -69, -75, -82, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -121,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -102, -97,
+	 -91, -86, -80, -73, -67, -60, -53, -45,
+	 -38, -30, -23, -15, -7, 0, 8, 16,
+	 24, 32, 39, 47, 54, 61, 68, 75,
+	 81, 87, 92, 98, 103, 107, 111, 115,
+	 118, 120, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 118, 114, 111, 107,
+	 102, 97, 92, 86, 80, 74, 68, 61,
+	 54, 46, 39, 31, 23, 16, 8, 0,
+	 -7, -15, -23, -31, -38, -46, -53, -60,
+	 -67, -74, -80, -86, -92, -97, -102, -106,
+	 -111, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -121, -118, -115,
+	 -111, -107, -103, -98, -93, -87, -81, -75,
+	 -68, -62, -54, -47, -40, -32, -24, -16,
+	 -9, -1, 6, 14, 22, 30, 38, 45,
+	 52, 60, 66, 73, 79, 85, 91, 96,
+	 101, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 103, 98, 93, 88,
+	 82, 76, 69, 62, 55, 48, 41, 33,
+	 25, 17, 9, 1, -5, -13, -21, -29,
+	 -37, -44, -52, -59, -66, -72, -79, -85,
+	 -91, -96, -101, -105, -110, -113, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -104, -99,
+	 -94, -88, -83, -76, -70, -63, -56, -49,
+	 -41, -34, -26, -18, -10, -2, 5, 13,
+	 20, 28, 36, 43, 51, 58, 65, 72,
+	 78, 84, 90, 95, 100, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 109,
+	 104, 100, 94, 89, 83, 77, 71, 64,
+	 57, 50, 42, 35, 27, 19, 11, 3,
+	 -4, -12, -20, -27, -35, -43, -50, -57,
+	 -64, -71, -77, -83, -89, -95, -100, -104,
+	 -109, -113, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -100, -95, -90, -84, -78,
+	 -71, -65, -58, -50, -43, -36, -28, -20,
+	 -12, -4, 3, 11, 19, 26, 34, 42,
+	 49, 56, 63, 70, 77, 83, 89, 94,
+	 99, 104, 108, 112, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 117, 113, 109, 105, 101, 96, 90,
+	 85, 78, 72, 65, 58, 51, 44, 36,
+	 29, 21, 13, 5, -2, -10, -18, -26,
+	 -33, -41, -48, -56, -63, -69, -76, -82,
+	 -88, -94, -99, -103, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -66, -59, -52,
+	 -45, -37, -30, -22, -14, -6, 1, 9,
+	 17, 25, 32, 40, 47, 55, 62, 69,
+	 75, 81, 87, 93, 98, 103, 107, 111,
+	 115, 118, 121, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 102, 97, 92, 86, 80, 74, 67,
+	 60, 53, 46, 38, 30, 23, 15, 7,
+	 0, -8, -16, -24, -32, -39, -47, -54,
+	 -61, -68, -74, -81, -87, -92, -98, -102,
+	 -107, -111, -115, -118, -120, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -123, -120, -118,
+	 -114, -111, -107, -102, -97, -92, -87, -81,
+	 -74, -68, -61, -54, -46, -39, -31, -24,
+	 -16, -8, 0, 7, 15, 23, 31, 38,
+	 46, 53, 60, 67, 74, 80, 86, 92,
+	 97, 102, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 121, 118, 115, 111, 107, 103, 98, 93,
+	 87, 81, 75, 68, 62, 55, 47, 40,
+	 32, 24, 17, 9, 1, -6, -14, -22,
+	 -30, -37, -45, -52, -59, -66, -73, -79,
+	 -85, -91, -96, -101, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -103,
+	 -99, -93, -88, -82, -76, -69, -62, -55,
+	 -48, -41, -33, -25, -18, -10, -2, 5,
+	 13, 21, 29, 37, 44, 51, 59, 66,
+	 72, 79, 85, 90, 96, 101, 105, 110,
+	 113, 117, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 108, 104, 99, 94, 89, 83, 76, 70,
+	 63, 56, 49, 42, 34, 26, 18, 11,
+	 3, -4, -12, -20, -28, -36, -43, -51,
+	 -58, -65, -71, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -113, -109, -104, -100, -95, -89, -83,
+	 -77, -71, -64, -57, -50, -42, -35, -27,
+	 -19, -11, -3, 3, 11, 19, 27, 35,
+	 42, 50, 57, 64, 71, 77, 83, 89,
+	 95, 100, 104, 109, 113, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 71, 65, 58, 51, 43,
+	 36, 28, 20, 12, 4, -3, -11, -18,
+	 -26, -34, -42, -49, -56, -63, -70, -76,
+	 -83, -88, -94, -99, -104, -108, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -117, -113, -110, -105,
+	 -101, -96, -90, -85, -79, -72, -66, -59,
+	 -51, -44, -37, -29, -21, -13, -5, 2,
+	 10, 18, 25, 33, 41, 48, 55, 62,
+	 69, 76, 82, 88, 93, 99, 103, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 101, 96, 91, 85, 79, 73,
+	 66, 59, 52, 45, 37, 30, 22, 14,
+	 6, -1, -9, -17, -24, -32, -40, -47,
+	 -55, -62, -68, -75, -81, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -121, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -102, -97, -92, -86,
+	 -80, -74, -67, -60, -53, -46, -38, -31,
+	 -23, -15, -7, 0, 8, 16, 24, 31,
+	 39, 46, 54, 61, 68, 74, 81, 87,
+	 92, 97, 102, 107, 111, 114, 118, 120,
+	 123, 124, 125, 126, 126, 126, 126, 124,
+	 123, 120, 118, 115, 111, 107, 102, 98,
+	 92, 87, 81, 74, 68, 61, 54, 47,
+	 39, 32, 24, 16, 8, 0, -7, -15,
+	 -23, -30, -38, -46, -53, -60, -67, -74,
+	 -80, -86, -92, -97, -102, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -121, -118, -115, -111, -107,
+	 -103, -98, -93, -87, -81, -75, -69, -62,
+	 -55, -47, -40, -32, -25, -17, -9, -1,
+	 6, 14, 22, 30, 37, 45, 52, 59,
+	 66, 73, 79, 85, 91, 96, 101, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 103, 99, 94, 88, 82, 76,
+	 69, 63, 56, 48, 41, 33, 26, 18,
+	 10, 2, -5, -13, -21, -29, -36, -44,
+	 -51, -58, -65, -72, -78, -85, -90, -96,
+	 -101, -105, -109, -113, -117, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -112, -108, -104, -99, -94, -89,
+	 -83, -77, -70, -63, -56, -49, -42, -34,
+	 -26, -19, -11, -3, 4, 12, 20, 28,
+	 36, 43, 50, 58, 65, 71, 78, 84,
+	 90, 95, 100, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 113, 109, 104, 100,
+	 95, 89, 83, 77, 71, 64, 57, 50,
+	 43, 35, 27, 20, 12, 4, -3, -11,
+	 -19, -27, -35, -42, -50, -57, -64, -71,
+	 -77, -83, -89, -94, -100, -104, -109, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -100, -95, -90, -84, -78, -72, -65,
+	 -58, -51, -43, -36, -28, -20, -13, -5,
+	 2, 10, 18, 26, 34, 41, 49, 56,
+	 63, 70, 76, 83, 88, 94, 99, 104,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 113, 110, 105, 101, 96, 91, 85, 79,
+	 72, 66, 59, 52, 44, 37, 29, 21,
+	 13, 5, -1, -9, -17, -25, -33, -41,
+	 -48, -55, -62, -69, -76, -82, -88, -93,
+	 -98, -103, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -60, -52, -45, -38,
+	 -30, -22, -14, -6, 1, 9, 16, 24,
+	 32, 40, 47, 54, 62, 68, 75, 81,
+	 87, 93, 98, 103, 107, 111, 115, 118,
+	 121, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 111, 106, 102,
+	 97, 92, 86, 80, 74, 67, 60, 53,
+	 46, 38, 31, 23, 15, 7, 0, -8,
+	 -16, -23, -31, -39, -46, -54, -61, -68,
+	 -74, -80, -86, -92, -97, -102, -107, -111,
+	 -114, -118, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -120, -118, -115, -111,
+	 -107, -103, -98, -92, -87, -81, -75, -68,
+	 -61, -54, -47, -39, -32, -24, -16, -8,
+	 0, 7, 15, 23, 30, 38, 45, 53,
+	 60, 67, 73, 80, 86, 91, 97, 102,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 121, 118,
+	 115, 111, 107, 103, 98, 93, 87, 82,
+	 75, 69, 62, 55, 48, 40, 33, 25,
+	 17, 9, 1, -6, -14, -22, -29, -37,
+	 -45, -52, -59, -66, -73, -79, -85, -91,
+	 -96, -101, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -104, -99, -94,
+	 -88, -82, -76, -70, -63, -56, -48, -41,
+	 -33, -26, -18, -10, -2, 5, 13, 21,
+	 29, 36, 44, 51, 58, 65, 72, 78,
+	 84, 90, 96, 101, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 108, 104,
+	 99, 94, 89, 83, 77, 70, 64, 57,
+	 49, 42, 34, 27, 19, 11, 3, -4,
+	 -12, -20, -28, -35, -43, -50, -58, -64,
+	 -71, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -123, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -113,
+	 -109, -105, -100, -95, -89, -84, -77, -71,
+	 -64, -57, -50, -43, -35, -27, -20, -12,
+	 -4, 3, 11, 19, 27, 35, 42, 49,
+	 57, 64, 70, 77, 83, 89, 94, 99,
+	 104, 108, 112, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 72, 65, 58, 51, 44, 36, 28,
+	 21, 13, 5, -2, -10, -18, -26, -34,
+	 -41, -49, -56, -63, -70, -76, -82, -88,
+	 -94, -99, -104, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -113, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -66, -59, -52, -44,
+	 -37, -29, -21, -14, -6, 1, 9, 17,
+	 25, 33, 40, 48, 55, 62, 69, 75,
+	 82, 88, 93, 98, 103, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 102, 97, 91, 86, 80, 73, 67, 60,
+	 53, 45, 38, 30, 22, 14, 7, 0,
+	 -8, -16, -24, -32, -40, -47, -54, -61,
+	 -68, -75, -81, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -120, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -111, -107, -102, -97, -92, -86, -80, -74,
+	 -67, -61, -53, -46, -39, -31, -23, -15,
+	 -7, 0, 7, 15, 23, 31, 39, 46,
+	 53, 61, 67, 74, 80, 86, 92, 97,
+	 102, 107, 111, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 120,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 81, 75, 68, 61, 54, 47, 40, 32,
+	 24, 16, 8, 0, -7, -14, -22, -30,
+	 -38, -45, -53, -60, -67, -73, -80, -86,
+	 -91, -97, -102, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -103, -98,
+	 -93, -88, -82, -75, -69, -62, -55, -48,
+	 -40, -33, -25, -17, -9, -1, 6, 14,
+	 21, 29, 37, 44, 52, 59, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 113,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 104, 99, 94, 88, 82, 76, 70, 63,
+	 56, 49, 41, 34, 26, 18, 10, 2,
+	 -5, -13, -21, -28, -36, -44, -51, -58,
+	 -65, -72, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -112, -108, -104, -99, -94, -89, -83, -77,
+	 -70, -64, -57, -49, -42, -35, -27, -19,
+	 -11, -3, 4, 12, 20, 27, 35, 43,
+	 50, 57, 64, 71, 77, 84, 89, 95,
+	 100, 105, 109, 113, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 123, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 71, 64, 58, 50, 43, 35,
+	 28, 20, 12, 4, -3, -11, -19, -27,
+	 -34, -42, -49, -57, -64, -70, -77, -83,
+	 -89, -94, -99, -104, -108, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -101,
+	 -96, -90, -84, -78, -72, -65, -58, -51,
+	 -44, -36, -29, -21, -13, -5, 2, 10,
+	 18, 26, 33, 41, 48, 56, 63, 70,
+	 76, 82, 88, 94, 99, 104, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 101, 96, 91, 85, 79, 73, 66,
+	 59, 52, 45, 37, 29, 22, 14, 6,
+	 -1, -9, -17, -25, -33, -40, -48, -55,
+	 -62, -69, -75, -82, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -121, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -102, -97, -91, -86, -80,
+	 -73, -67, -60, -53, -45, -38, -30, -23,
+	 -15, -7, 0, 8, 16, 24, 32, 39,
+	 47, 54, 61, 68, 75, 81, 87, 92,
+	 98, 103, 107, 111, 115, 118, 120, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 118, 114, 111, 107, 102, 97, 92,
+	 86, 80, 74, 68, 61, 54, 46, 39,
+	 31, 23, 16, 8, 0, -7, -15, -23,
+	 -31, -38, -46, -53, -60, -67, -74, -80,
+	 -86, -92, -97, -102, -106, -111, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -121, -118, -115, -111, -107, -103,
+	 -98, -93, -87, -81, -75, -68, -62, -54,
+	 -47, -40, -32, -24, -16, -9, -1, 6,
+	 14, 22, 30, 38, 45, 52, 60, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 103, 98, 93, 88, 82, 76, 69,
+	 62, 55, 48, 41, 33, 25, 17, 9,
+	 1, -5, -13, -21, -29, -37, -44, -52,
+	 -59, -66, -72, -79, -85, -91, -96, -101,
+	 -105, -110, -113, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -104, -99, -94, -88, -83,
+	 -76, -70, -63, -56, -49, -41, -34, -26,
+	 -18, -10, -2, 5, 13, 20, 28, 36,
+	 43, 51, 58, 65, 72, 78, 84, 90,
+	 95, 100, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 109, 104, 100, 94,
+	 89, 83, 77, 71, 64, 57, 50, 42,
+	 35, 27, 19, 11, 3, -4, -12, -20,
+	 -27, -35, -43, -50, -57, -64, -71, -77,
+	 -83, -89, -95, -100, -104, -109, -113, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -100, -95, -90, -84, -78, -71, -65, -58,
+	 -50, -43, -36, -28, -20, -12, -4, 3,
+	 11, 19, 26, 34, 42, 49, 56, 63,
+	 70, 77, 83, 89, 94, 99, 104, 108,
+	 112, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 117, 113,
+	 109, 105, 101, 96, 90, 85, 78, 72,
+	 65, 58, 51, 44, 36, 29, 21, 13,
+	 5, -2, -10, -18, -26, -33, -41, -48,
+	 -56, -63, -69, -76, -82, -88, -94, -99,
+	 -103, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -59, -52, -45, -37, -30,
+	 -22, -14, -6, 1, 9, 17, 25, 32,
+	 40, 47, 55, 62, 69, 75, 81, 87,
+	 93, 98, 103, 107, 111, 115, 118, 121,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 102, 97,
+	 92, 86, 80, 74, 67, 60, 53, 46,
+	 38, 30, 23, 15, 7, 0, -8, -16,
+	 -24, -32, -39, -47, -54, -61, -68, -74,
+	 -81, -87, -92, -98, -102, -107, -111, -115,
+	 -118, -120, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -123, -120, -118, -114, -111, -107,
+	 -102, -97, -92, -87, -81, -74, -68, -61,
+	 -54, -46, -39, -31, -24, -16, -8, 0,
+	 7, 15, 23, 31, 38, 46, 53, 60,
+	 67, 74, 80, 86, 92, 97, 102, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 121, 118, 115,
+	 111, 107, 103, 98, 93, 87, 81, 75,
+	 68, 62, 55, 47, 40, 32, 24, 17,
+	 9, 1, -6, -14, -22, -30, -37, -45,
+	 -52, -59, -66, -73, -79, -85, -91, -96,
+	 -101, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -103, -99, -93, -88,
+	 -82, -76, -69, -62, -55, -48, -41, -33,
+	 -25, -18, -10, -2, 5, 13, 21, 29,
+	 37, 44, 51, 59, 66, 72, 79, 85,
+	 90, 96, 101, 105, 110, 113, 117, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 108, 104, 99,
+	 94, 88, 83, 76, 70, 63, 56, 49,
+	 42, 34, 26, 18, 11, 3, -4, -12,
+	 -20, -28, -36, -43, -51, -58, -65, -71,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -113, -109,
+	 -104, -100, -95, -89, -83, -77, -71, -64,
+	 -57, -50, -42, -35, -27, -19, -11, -3,
+	 3, 11, 19, 27, 35, 42, 50, 57,
+	 64, 71, 77, 83, 89, 95, 100, 104,
+	 109, 113, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 71, 65, 58, 51, 43, 36, 28, 20,
+	 12, 4, -3, -11, -18, -26, -34, -42,
+	 -49, -56, -63, -70, -76, -83, -88, -94,
+	 -99, -104, -108, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -117, -113, -110, -105, -101, -96, -90,
+	 -85, -79, -72, -66, -59, -51, -44, -37,
+	 -29, -21, -13, -5, 2, 10, 18, 25,
+	 33, 41, 48, 55, 62, 69, 76, 82,
+	 88, 93, 99, 103, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 101,
+	 96, 91, 85, 79, 73, 66, 59, 52,
+	 45, 37, 30, 22, 14, 6, -1, -9,
+	 -17, -24, -32, -40, -47, -55, -62, -68,
+	 -75, -81, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -121, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -102, -97, -92, -86, -80, -74, -67,
+	 -60, -53, -46, -38, -31, -23, -15, -7,
+	 0, 8, 16, 24, 31, 39, 46, 54,
+	 61, 68, 74, 81, 87, 92, 97, 102,
+	 107, 111, 114, 118, 120, 123, 124, 125,
+	 126, 126, 126, 126, 124, 123, 120, 118,
+	 115, 111, 107, 102, 98, 92, 87, 81,
+	 74, 68, 61, 54, 47, 39, 32, 24,
+	 16, 8, 0, -7, -15, -23, -30, -38,
+	 -46, -53, -60, -67, -74, -80, -86, -92,
+	 -97, -102, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -121, -118, -115, -111, -107, -103, -98, -93,
+	 -87, -81, -75, -69, -62, -55, -47, -40,
+	 -32, -25, -17, -9, -1, 6, 14, 22,
+	 30, 37, 45, 52, 59, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 103,
+	 99, 94, 88, 82, 76, 69, 63, 56,
+	 48, 41, 33, 26, 18, 10, 2, -5,
+	 -13, -21, -29, -36, -44, -51, -58, -65,
+	 -72, -78, -85, -90, -96, -101, -105, -109,
+	 -113, -117, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -112,
+	 -108, -104, -99, -94, -89, -83, -77, -70,
+	 -63, -56, -49, -42, -34, -26, -19, -11,
+	 -3, 4, 12, 20, 28, 36, 43, 50,
+	 58, 65, 71, 78, 84, 90, 95, 100,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 113, 109, 104, 100, 95, 89, 83,
+	 77, 71, 64, 57, 50, 43, 35, 27,
+	 20, 12, 4, -3, -11, -19, -27, -35,
+	 -42, -50, -57, -64, -71, -77, -83, -89,
+	 -94, -100, -104, -109, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -100, -95,
+	 -90, -84, -78, -72, -65, -58, -51, -43,
+	 -36, -28, -20, -13, -5, 2, 10, 18,
+	 26, 34, 41, 49, 56, 63, 70, 76,
+	 83, 88, 94, 99, 104, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 113, 110, 105,
+	 101, 96, 91, 85, 79, 72, 66, 59,
+	 52, 44, 37, 29, 21, 13, 5, -1,
+	 -9, -17, -25, -33, -41, -48, -55, -62,
+	 -69, -76, -82, -88, -93, -98, -103, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -60, -52, -45, -38, -30, -22, -14,
+	 -6, 1, 9, 16, 24, 32, 40, 47,
+	 54, 62, 68, 75, 81, 87, 93, 98,
+	 103, 107, 111, 115, 118, 121, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 111, 106, 102, 97, 92, 86,
+	 80, 74, 67, 60, 53, 46, 38, 31,
+	 23, 15, 7, 0, -8, -16, -23, -31,
+	 -39, -46, -54, -61, -68, -74, -80, -86,
+	 -92, -97, -102, -107, -111, -114, -118, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -120, -118, -115, -111, -107, -103, -98,
+	 -92, -87, -81, -75, -68, -61, -54, -47,
+	 -39, -32, -24, -16, -8, 0, 7, 15,
+	 23, 30, 38, 45, 53, 60, 67, 73,
+	 80, 86, 91, 97, 102, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 121, 118, 115, 111, 107,
+	 103, 98, 93, 87, 82, 75, 69, 62,
+	 55, 48, 40, 33, 25, 17, 9, 1,
+	 -6, -14, -22, -29, -37, -45, -52, -59,
+	 -66, -73, -79, -85, -91, -96, -101, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -104, -99, -94, -88, -82, -76,
+	 -70, -63, -56, -49, -41, -33, -26, -18,
+	 -10, -2, 5, 13, 21, 29, 36, 44,
+	 51, 58, 65, 72, 78, 84, 90, 96,
+	 101, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 108, 104, 99, 94, 89,
+	 83, 77, 70, 64, 57, 49, 42, 34,
+	 27, 19, 11, 3, -4, -12, -20, -28,
+	 -35, -43, -50, -58, -64, -71, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -123, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -113, -109, -105, -100,
+	 -95, -89, -84, -77, -71, -64, -57, -50,
+	 -43, -35, -27, -20, -12, -4, 3, 11,
+	 19, 27, 35, 42, 49, 57, 64, 70,
+	 77, 83, 89, 94, 99, 104, 108, 112,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 72, 65,
+	 58, 51, 44, 36, 28, 21, 13, 5,
+	 -2, -10, -18, -26, -34, -41, -49, -56,
+	 -63, -70, -76, -82, -88, -94, -99, -104,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -113, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -59, -52, -44, -37, -29, -21,
+	 -14, -6, 1, 9, 17, 25, 33, 40,
+	 48, 55, 62, 69, 75, 82, 88, 93,
+	 98, 103, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 102, 97, 91,
+	 86, 80, 73, 67, 60, 53, 45, 38,
+	 30, 22, 14, 7, 0, -8, -16, -24,
+	 -32, -40, -47, -54, -61, -68, -75, -81,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -120, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -111, -107, -102,
+	 -97, -92, -86, -80, -74, -67, -61, -53,
+	 -46, -39, -31, -23, -15, -7, 0, 7,
+	 15, 23, 31, 39, 46, 53, 61, 67,
+	 74, 80, 86, 92, 97, 102, 107, 111,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 120, 118, 115, 111,
+	 107, 103, 98, 93, 87, 81, 75, 68,
+	 61, 54, 47, 40, 32, 24, 16, 8,
+	 0, -7, -14, -22, -30, -38, -45, -53,
+	 -60, -67, -73, -80, -86, -91, -97, -102,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -103, -98, -93, -88, -82,
+	 -75, -69, -62, -55, -48, -40, -33, -25,
+	 -17, -9, -1, 6, 14, 21, 29, 37,
+	 44, 52, 59, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 113, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 104, 99, 94,
+	 88, 82, 76, 70, 63, 56, 49, 41,
+	 34, 26, 18, 10, 2, -5, -13, -21,
+	 -28, -36, -44, -51, -58, -65, -72, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -112, -108, -104,
+	 -99, -94, -89, -83, -77, -70, -64, -57,
+	 -49, -42, -35, -27, -19, -11, -3, 4,
+	 12, 20, 27, 35, 43, 50, 57, 64,
+	 71, 77, 84, 89, 95, 100, 105, 109,
+	 113, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 123, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 71,
+	 64, 58, 50, 43, 35, 28, 20, 12,
+	 4, -3, -11, -19, -27, -34, -42, -49,
+	 -57, -64, -70, -77, -83, -89, -94, -99,
+	 -104, -108, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -101, -96, -90, -84,
+	 -78, -72, -65, -58, -51, -44, -36, -29,
+	 -21, -13, -5, 2, 10, 18, 26, 33,
+	 41, 48, 56, 63, 70, 76, 82, 88,
+	 94, 99, 104, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 101, 96,
+	 91, 85, 79, 73, 66, 59, 52, 45,
+	 37, 29, 22, 14, 6, -1, -9, -17,
+	 -25, -33, -40, -48, -55, -62, -69, -75,
+	 -82, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -121, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -102, -97, -91, -86, -80, -73, -67, -60,
+	 -53, -45, -38, -30, -23, -15, -7, 0,
+	 8, 16, 24, 32, 39, 47, 54, 61,
+	 68, 75, 81, 87, 92, 98, 103, 107,
+	 111, 115, 118, 120, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 118, 114,
+	 111, 107, 102, 97, 92, 86, 80, 74,
+	 68, 61, 54, 46, 39, 31, 23, 16,
+	 8, 0, -7, -15, -23, -31, -38, -46,
+	 -53, -60, -67, -74, -80, -86, -92, -97,
+	 -102, -106, -111, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -121,
+	 -118, -115, -111, -107, -103, -98, -93, -87,
+	 -81, -75, -68, -61, -54, -47, -40, -32,
+	 -24, -16, -9, -1, 6, 14, 22, 30,
+	 38, 45, 52, 60, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 103, 98,
+	 93, 88, 82, 76, 69, 62, 55, 48,
+	 41, 33, 25, 17, 9, 1, -5, -13,
+	 -21, -29, -37, -44, -52, -59, -66, -72,
+	 -79, -85, -91, -96, -101, -105, -110, -113,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -104, -99, -94, -88, -83, -76, -70, -63,
+	 -56, -49, -41, -34, -26, -18, -10, -2,
+	 5, 13, 20, 28, 36, 43, 51, 58,
+	 65, 72, 78, 84, 90, 95, 100, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 109, 104, 100, 94, 89, 83, 77,
+	 71, 64, 57, 50, 42, 35, 27, 19,
+	 11, 3, -4, -12, -20, -27, -35, -43,
+	 -50, -57, -64, -71, -77, -83, -89, -95,
+	 -100, -104, -109, -113, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -100, -95, -90,
+	 -84, -78, -71, -65, -58, -50, -43, -36,
+	 -28, -20, -12, -4, 3, 11, 19, 26,
+	 34, 42, 49, 56, 63, 70, 77, 83,
+	 89, 94, 99, 104, 108, 112, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 117, 113, 109, 105, 101,
+	 96, 90, 85, 78, 72, 65, 58, 51,
+	 44, 36, 29, 21, 13, 5, -2, -10,
+	 -18, -26, -33, -41, -48, -56, -63, -69,
+	 -76, -82, -88, -94, -99, -103, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -59, -52, -45, -37, -30, -22, -14, -6,
+	 1, 9, 17, 25, 32, 40, 47, 55,
+	 62, 69, 75, 81, 87, 93, 98, 103,
+	 107, 111, 115, 118, 121, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 102, 97, 92, 86, 80,
+	 74, 67, 60, 53, 46, 38, 30, 23,
+	 15, 7, 0, -8, -16, -24, -32, -39,
+	 -47, -54, -61, -68, -74, -81, -87, -92,
+	 -98, -102, -107, -111, -115, -118, -120, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -123,
+	 -120, -118, -114, -111, -107, -102, -97, -92,
+	 -87, -81, -74, -68, -61, -54, -46, -39,
+	 -31, -24, -16, -8, 0, 7, 15, 23,
+	 31, 38, 46, 53, 60, 67, 74, 80,
+	 86, 92, 97, 102, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 121, 118, 115, 111, 107, 103,
+	 98, 93, 87, 81, 75, 68, 62, 55,
+	 47, 40, 32, 24, 17, 9, 1, -6,
+	 -14, -22, -30, -37, -45, -52, -59, -66,
+	 -73, -79, -85, -91, -96, -101, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -103, -99, -93, -88, -82, -76, -69,
+	 -62, -55, -48, -41, -33, -25, -18, -10,
+	 -2, 5, 13, 21, 29, 37, 44, 51,
+	 59, 66, 72, 79, 85, 90, 96, 101,
+	 105, 110, 113, 117, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 108, 104, 99, 94, 89, 83,
+	 76, 70, 63, 56, 49, 42, 34, 26,
+	 18, 11, 3, -4, -12, -20, -28, -36,
+	 -43, -51, -58, -65, -71, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -113, -109, -104, -100, -95,
+	 -89, -83, -77, -71, -64, -57, -50, -42,
+	 -35, -27, -19, -11, -3, 3, 11, 19,
+	 27, 35, 42, 50, 57, 64, 71, 77,
+	 83, 89, 95, 100, 104, 109, 113, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 71, 65, 58,
+	 51, 43, 36, 28, 20, 12, 4, -3,
+	 -11, -18, -26, -34, -42, -49, -56, -63,
+	 -70, -76, -83, -89, -94, -99, -104, -108,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -117, -113,
+	 -110, -105, -101, -96, -90, -85, -79, -72,
+	 -66, -59, -51, -44, -37, -29, -21, -13,
+	 -5, 2, 10, 18, 25, 33, 41, 48,
+	 55, 62, 69, 76, 82, 88, 93, 99,
+	 103, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 101, 96, 91, 85,
+	 79, 73, 66, 59, 52, 45, 37, 30,
+	 22, 14, 6, -1, -9, -17, -24, -32,
+	 -40, -47, -55, -62, -68, -75, -81, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -121,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -102, -97,
+	 -92, -86, -80, -74, -67, -60, -53, -46,
+	 -38, -31, -23, -15, -7, 0, 8, 16,
+	 24, 31, 39, 46, 54, 61, 68, 74,
+	 81, 87, 92, 97, 102, 107, 111, 114,
+	 118, 120, 123, 124, 125, 126, 126, 126,
+	 126, 124, 123, 120, 118, 115, 111, 107,
+	 102, 98, 92, 87, 81, 74, 68, 61,
+	 54, 47, 39, 32, 24, 16, 8, 0,
+	 -7, -15, -23, -30, -38, -46, -53, -60,
+	 -67, -74, -80, -86, -92, -97, -102, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -121, -118, -115,
+	 -111, -107, -103, -98, -93, -87, -81, -75,
+	 -69, -62, -55, -47, -40, -32, -25, -17,
+	 -9, -1, 6, 14, 22, 30, 37, 45,
+	 52, 59, 66, 73, 79, 85, 91, 96,
+	 101, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 103, 99, 94, 88,
+	 82, 76, 69, 63, 56, 48, 41, 33,
+	 26, 18, 10, 2, -5, -13, -21, -29,
+	 -36, -44, -51, -58, -65, -72, -78, -85,
+	 -90, -96, -101, -105, -109, -113, -117, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -112, -108, -104, -99,
+	 -94, -89, -83, -77, -70, -63, -56, -49,
+	 -42, -34, -26, -19, -11, -3, 4, 12,
+	 20, 28, 36, 43, 50, 58, 65, 71,
+	 78, 84, 90, 95, 100, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 113, 109,
+	 104, 100, 95, 89, 83, 77, 71, 64,
+	 57, 50, 43, 35, 27, 19, 12, 4,
+	 -3, -11, -19, -27, -35, -42, -50, -57,
+	 -64, -71, -77, -83, -89, -94, -100, -104,
+	 -109, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -100, -95, -90, -84, -78,
+	 -72, -65, -58, -51, -43, -36, -28, -20,
+	 -12, -5, 2, 10, 18, 26, 34, 41,
+	 49, 56, 63, 70, 76, 83, 88, 94,
+	 99, 104, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 113, 110, 105, 101, 96, 91,
+	 85, 79, 72, 66, 59, 52, 44, 37,
+	 29, 21, 13, 5, -1, -9, -17, -25,
+	 -33, -41, -48, -55, -62, -69, -76, -82,
+	 -88, -93, -98, -103, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -66, -60, -52,
+	 -45, -38, -30, -22, -14, -6, 1, 9,
+	 16, 24, 32, 40, 47, 54, 62, 68,
+	 75, 81, 87, 93, 98, 103, 107, 111,
+	 115, 118, 121, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 111,
+	 106, 102, 97, 92, 86, 80, 74, 67,
+	 60, 53, 46, 38, 31, 23, 15, 7,
+	 0, -8, -16, -23, -31, -39, -46, -54,
+	 -61, -68, -74, -80, -86, -92, -97, -102,
+	 -107, -111, -114, -118, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -120, -118,
+	 -115, -111, -107, -103, -98, -92, -87, -81,
+	 -75, -68, -61, -54, -47, -39, -32, -24,
+	 -16, -8, 0, 7, 15, 23, 30, 38,
+	 45, 53, 60, 67, 73, 80, 86, 91,
+	 97, 102, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 121, 118, 115, 111, 107, 103, 98, 93,
+	 87, 82, 75, 69, 62, 55, 48, 40,
+	 33, 25, 17, 9, 1, -6, -14, -22,
+	 -29, -37, -45, -52, -59, -66, -73, -79,
+	 -85, -91, -96, -101, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -104,
+	 -99, -94, -88, -82, -76, -70, -63, -56,
+	 -49, -41, -33, -26, -18, -10, -2, 5,
+	 13, 21, 29, 36, 44, 51, 58, 65,
+	 72, 78, 84, 90, 96, 101, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 108, 104, 99, 94, 89, 83, 77, 70,
+	 64, 57, 49, 42, 34, 27, 19, 11,
+	 3, -4, -12, -20, -28, -35, -43, -50,
+	 -58, -64, -71, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -123, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -113, -109, -105, -100, -95, -89, -84,
+	 -77, -71, -64, -57, -50, -43, -35, -27,
+	 -20, -12, -4, 3, 11, 19, 27, 35,
+	 42, 49, 57, 64, 70, 77, 83, 89,
+	 94, 99, 104, 108, 112, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 72, 65, 58, 51, 44,
+	 36, 28, 21, 13, 5, -2, -10, -18,
+	 -26, -34, -41, -49, -56, -63, -70, -76,
+	 -82, -88, -94, -99, -104, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -113, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -59,
+	 -52, -44, -37, -29, -21, -14, -6, 1,
+	 9, 17, 25, 33, 40, 48, 55, 62,
+	 69, 75, 82, 88, 93, 98, 103, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 102, 97, 91, 86, 80, 73,
+	 67, 60, 53, 45, 38, 30, 22, 14,
+	 7, 0, -8, -16, -24, -32, -40, -47,
+	 -54, -61, -68, -75, -81, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -120, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -111, -107, -102, -97, -92, -86,
+	 -80, -74, -67, -61, -53, -46, -39, -31,
+	 -23, -15, -7, 0, 7, 15, 23, 31,
+	 39, 46, 53, 61, 67, 74, 80, 86,
+	 92, 97, 102, 107, 111, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 120, 118, 115, 111, 107, 103, 98,
+	 93, 87, 81, 75, 68, 61, 54, 47,
+	 40, 32, 24, 16, 8, 0, -7, -14,
+	 -22, -30, -38, -45, -53, -60, -67, -73,
+	 -80, -86, -91, -97, -102, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -103, -98, -93, -88, -82, -75, -69, -62,
+	 -55, -48, -40, -33, -25, -17, -9, -1,
+	 6, 14, 21, 29, 37, 44, 52, 59,
+	 66, 73, 79, 85, 91, 96, 101, 106,
+	 110, 113, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 104, 99, 94, 88, 82, 76,
+	 70, 63, 56, 49, 41, 34, 26, 18,
+	 10, 2, -5, -13, -21, -28, -36, -44,
+	 -51, -58, -65, -72, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -112, -108, -104, -99, -94, -89,
+	 -83, -77, -70, -64, -57, -50, -42, -34,
+	 -27, -19, -11, -3, 4, 12, 20, 27,
+	 35, 43, 50, 57, 64, 71, 77, 84,
+	 89, 95, 100, 105, 109, 113, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 123, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 71, 64, 58, 50,
+	 43, 35, 28, 20, 12, 4, -3, -11,
+	 -19, -27, -34, -42, -49, -57, -64, -70,
+	 -77, -83, -89, -94, -99, -104, -108, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -101, -96, -90, -84, -78, -72, -65,
+	 -58, -51, -44, -36, -29, -21, -13, -5,
+	 2, 10, 18, 26, 33, 41, 48, 56,
+	 63, 70, 76, 82, 88, 94, 99, 104,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 101, 96, 91, 85, 79,
+	 73, 66, 59, 52, 45, 37, 29, 22,
+	 14, 6, -1, -9, -17, -25, -33, -40,
+	 -48, -55, -62, -69, -75, -82, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -121, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -102, -97, -91,
+	 -86, -80, -73, -67, -60, -53, -45, -38,
+	 -30, -23, -15, -7, 0, 8, 16, 24,
+	 32, 39, 47, 54, 61, 68, 75, 81,
+	 87, 92, 98, 103, 107, 111, 115, 118,
+	 120, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 118, 114, 111, 107, 102,
+	 97, 92, 86, 80, 74, 68, 61, 54,
+	 46, 39, 31, 23, 16, 8, 0, -7,
+	 -15, -23, -31, -38, -46, -53, -60, -67,
+	 -74, -80, -86, -92, -97, -102, -106, -111,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -121, -118, -115, -111,
+	 -107, -103, -98, -93, -87, -81, -75, -68,
+	 -62, -54, -47, -40, -32, -24, -16, -9,
+	 -1, 6, 14, 22, 30, 38, 45, 52,
+	 60, 66, 73, 79, 85, 91, 97, 101,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 103, 98, 93, 88, 82,
+	 76, 69, 62, 55, 48, 41, 33, 25,
+	 17, 9, 1, -5, -13, -21, -29, -37,
+	 -44, -52, -59, -66, -72, -79, -85, -91,
+	 -96, -101, -105, -110, -113, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -104, -99, -94,
+	 -88, -83, -76, -70, -63, -56, -49, -41,
+	 -34, -26, -18, -10, -2, 5, 13, 20,
+	 28, 36, 43, 51, 58, 65, 72, 78,
+	 84, 90, 95, 100, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 109, 104,
+	 100, 94, 89, 83, 77, 71, 64, 57,
+	 50, 42, 35, 27, 19, 11, 3, -4,
+	 -12, -20, -27, -35, -43, -50, -57, -64,
+	 -71, -77, -83, -89, -95, -100, -104, -109,
+	 -113, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -100, -95, -90, -84, -78, -71,
+	 -65, -58, -50, -43, -36, -28, -20, -12,
+	 -4, 3, 11, 19, 26, 34, 42, 49,
+	 56, 63, 70, 77, 83, 89, 94, 99,
+	 104, 108, 112, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 117, 113, 109, 105, 101, 96, 90, 85,
+	 78, 72, 65, 58, 51, 44, 36, 29,
+	 21, 13, 5, -2, -10, -18, -26, -33,
+	 -41, -48, -56, -63, -69, -76, -82, -88,
+	 -94, -99, -103, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -66, -59, -52, -45,
+	 -37, -30, -22, -14, -6, 1, 9, 17,
+	 25, 32, 40, 48, 55, 62, 69, 75,
+	 81, 87, 93, 98, 103, 107, 111, 115,
+	 118, 121, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 102, 97, 92, 86, 80, 74, 67, 60,
+	 53, 46, 38, 30, 23, 15, 7, 0,
+	 -8, -16, -24, -32, -39, -47, -54, -61,
+	 -68, -74, -81, -87, -92, -98, -102, -107,
+	 -111, -115, -118, -120, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -123, -120, -118, -114,
+	 -111, -107, -102, -97, -92, -87, -81, -74,
+	 -68, -61, -54, -46, -39, -31, -24, -16,
+	 -8, 0, 7, 15, 23, 31, 38, 46,
+	 53, 60, 67, 74, 80, 86, 92, 97,
+	 102, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 121,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 81, 75, 68, 62, 55, 47, 40, 32,
+	 24, 17, 9, 1, -6, -14, -22, -30,
+	 -37, -45, -52, -59, -66, -73, -79, -85,
+	 -91, -96, -101, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -103, -99,
+	 -93, -88, -82, -76, -69, -62, -55, -48,
+	 -41, -33, -25, -18, -10, -2, 5, 13,
+	 21, 29, 37, 44, 51, 59, 66, 72,
+	 79, 85, 90, 96, 101, 105, 110, 113,
+	 117, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 108,
+	 104, 99, 94, 88, 83, 76, 70, 63,
+	 56, 49, 42, 34, 26, 18, 11, 3,
+	 -4, -12, -20, -28, -36, -43, -51, -58,
+	 -65, -71, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -113, -109, -104, -100, -95, -89, -83, -77,
+	 -71, -64, -57, -50, -42, -35, -27, -19,
+	 -11, -3, 3, 11, 19, 27, 35, 42,
+	 50, 57, 64, 71, 77, 83, 89, 95,
+	 100, 104, 109, 113, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 71, 65, 58, 51, 43, 36,
+	 28, 20, 12, 4, -3, -11, -18, -26,
+	 -34, -42, -49, -56, -63, -70, -76, -83,
+	 -89, -94, -99, -104, -108, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -117, -113, -110, -105, -101,
+	 -96, -90, -85, -79, -72, -66, -59, -51,
+	 -44, -37, -29, -21, -13, -5, 2, 10,
+	 18, 25, 33, 41, 48, 55, 62, 69,
+	 76, 82, 88, 93, 99, 103, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 101, 96, 91, 85, 79, 73, 66,
+	 59, 52, 45, 37, 30, 22, 14, 6,
+	 -1, -9, -17, -24, -32, -40, -47, -55,
+	 -62, -68, -75, -81, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -121, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -102, -97, -92, -86, -80,
+	 -74, -67, -60, -53, -46, -38, -31, -23,
+	 -15, -7, 0, 8, 16, 24, 31, 39,
+	 46, 54, 61, 68, 74, 81, 87, 92,
+	 97, 102, 107, 111, 114, 118, 120, 123,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 120, 118, 115, 111, 107, 102, 98, 92,
+	 87, 81, 74, 68, 61, 54, 47, 39,
+	 32, 24, 16, 8, 0, -7, -15, -23,
+	 -30, -38, -46, -53, -60, -67, -74, -80,
+	 -86, -92, -97, -102, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -121, -118, -115, -111, -107, -103,
+	 -98, -93, -87, -81, -75, -69, -62, -55,
+	 -47, -40, -32, -25, -17, -9, -1, 6,
+	 14, 22, 30, 37, 45, 52, 59, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 103, 99, 94, 88, 82, 76, 69,
+	 63, 56, 48, 41, 33, 26, 18, 10,
+	 2, -5, -13, -21, -29, -36, -44, -51,
+	 -58, -65, -72, -78, -85, -90, -96, -101,
+	 -105, -109, -113, -117, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -112, -108, -104, -99, -94, -89, -83,
+	 -77, -70, -63, -56, -49, -42, -34, -26,
+	 -19, -11, -3, 4, 12, 20, 28, 36,
+	 43, 50, 58, 65, 71, 78, 84, 90,
+	 95, 100, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 113, 109, 104, 100, 95,
+	 89, 83, 77, 71, 64, 57, 50, 43,
+	 35, 27, 19, 12, 4, -3, -11, -19,
+	 -27, -35, -42, -50, -57, -64, -71, -77,
+	 -83, -89, -94, -100, -104, -109, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -100, -95, -90, -84, -78, -72, -65, -58,
+	 -51, -43, -36, -28, -20, -13, -5, 2,
+	 10, 18, 26, 34, 41, 49, 56, 63,
+	 70, 76, 83, 88, 94, 99, 104, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 113,
+	 110, 105, 101, 96, 91, 85, 79, 72,
+	 66, 59, 52, 44, 37, 29, 21, 13,
+	 5, -1, -9, -17, -25, -33, -41, -48,
+	 -55, -62, -69, -76, -82, -88, -93, -98,
+	 -103, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -60, -52, -45, -38, -30,
+	 -22, -14, -6, 1, 9, 16, 24, 32,
+	 40, 47, 54, 62, 68, 75, 81, 87,
+	 93, 98, 103, 107, 111, 115, 118, 121,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 111, 106, 102, 97,
+	 92, 86, 80, 74, 67, 60, 53, 46,
+	 38, 31, 23, 15, 7, 0, -8, -16,
+	 -23, -31, -39, -46, -54, -61, -68, -74,
+	 -80, -86, -92, -97, -102, -107, -111, -114,
+	 -118, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -120, -118, -115, -111, -107,
+	 -103, -98, -92, -87, -81, -75, -68, -61,
+	 -54, -47, -39, -32, -24, -16, -8, 0,
+	 7, 15, 23, 30, 38, 45, 53, 60,
+	 67, 73, 80, 86, 91, 97, 102, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 121, 118, 115,
+	 111, 107, 103, 98, 93, 87, 82, 75,
+	 69, 62, 55, 48, 40, 33, 25, 17,
+	 9, 1, -6, -14, -22, -29, -37, -45,
+	 -52, -59, -66, -73, -79, -85, -91, -96,
+	 -101, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -104, -99, -94, -88,
+	 -82, -76, -70, -63, -56, -48, -41, -33,
+	 -26, -18, -10, -2, 5, 13, 21, 29,
+	 36, 44, 51, 58, 65, 72, 78, 84,
+	 90, 96, 101, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 108, 104, 99,
+	 94, 89, 83, 77, 70, 64, 57, 49,
+	 42, 34, 27, 19, 11, 3, -4, -12,
+	 -20, -28, -35, -43, -50, -58, -64, -71,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -123, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -113, -109,
+	 -105, -100, -95, -89, -84, -77, -71, -64,
+	 -57, -50, -43, -35, -27, -20, -12, -4,
+	 3, 11, 19, 27, 35, 42, 50, 57,
+	 64, 70, 77, 83, 89, 94, 99, 104,
+	 108, 112, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 72, 65, 58, 51, 44, 36, 28, 21,
+	 13, 5, -2, -10, -18, -26, -34, -41,
+	 -49, -56, -63, -70, -76, -82, -88, -94,
+	 -99, -104, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -113, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -59, -52, -44, -37,
+	 -29, -21, -14, -6, 1, 9, 17, 25,
+	 33, 40, 48, 55, 62, 69, 75, 82,
+	 88, 93, 98, 103, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 102,
+	 97, 91, 86, 80, 73, 67, 60, 53,
+	 45, 38, 30, 22, 14, 7, 0, -8,
+	 -16, -24, -32, -40, -47, -54, -61, -68,
+	 -75, -81, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -120, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -111,
+	 -107, -102, -97, -92, -86, -80, -74, -67,
+	 -61, -53, -46, -39, -31, -23, -15, -7,
+	 0, 7, 15, 23, 31, 39, 46, 53,
+	 61, 67, 74, 80, 86, 92, 97, 102,
+	 107, 111, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 120, 118,
+	 115, 111, 107, 103, 98, 93, 87, 81,
+	 75, 68, 61, 54, 47, 40, 32, 24,
+	 16, 8, 0, -7, -14, -22, -30, -38,
+	 -45, -53, -60, -67, -73, -80, -86, -91,
+	 -97, -102, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -103, -98, -93,
+	 -88, -82, -75, -69, -62, -55, -48, -40,
+	 -33, -25, -17, -9, -1, 6, 14, 21,
+	 29, 37, 44, 52, 59, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 113, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 104,
+	 99, 94, 88, 82, 76, 70, 63, 56,
+	 49, 41, 34, 26, 18, 10, 2, -5,
+	 -13, -21, -28, -36, -44, -51, -58, -65,
+	 -72, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -112,
+	 -108, -104, -99, -94, -89, -83, -77, -70,
+	 -64, -57, -50, -42, -35, -27, -19, -11,
+	 -3, 4, 12, 20, 27, 35, 43, 50,
+	 57, 64, 71, 77, 84, 89, 95, 100,
+	 105, 109, 113, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 123, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 71, 64, 58, 50, 43, 35, 28,
+	 20, 12, 4, -3, -11, -19, -27, -34,
+	 -42, -49, -57, -64, -70, -77, -83, -89,
+	 -94, -99, -104, -108, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -101, -96,
+	 -90, -84, -78, -72, -65, -58, -51, -44,
+	 -36, -29, -21, -13, -5, 2, 10, 18,
+	 26, 33, 41, 49, 56, 63, 70, 76,
+	 82, 88, 94, 99, 104, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 101, 96, 91, 85, 79, 73, 66, 59,
+	 52, 45, 37, 29, 22, 14, 6, -1,
+	 -9, -17, -25, -33, -40, -48, -55, -62,
+	 -69, -75, -82, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -121, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -102, -97, -91, -86, -80, -73,
+	 -67, -60, -53, -45, -38, -30, -23, -15,
+	 -7, 0, 8, 16, 24, 32, 39, 47,
+	 54, 61, 68, 75, 81, 87, 92, 98,
+	 103, 107, 111, 115, 118, 120, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 118, 114, 111, 107, 102, 97, 92, 86,
+	 80, 74, 68, 61, 54, 46, 39, 31,
+	 23, 16, 8, 0, -7, -15, -23, -31,
+	 -38, -46, -53, -60, -67, -74, -80, -86,
+	 -92, -97, -102, -106, -111, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -121, -118, -115, -111, -107, -103, -98,
+	 -93, -87, -81, -75, -68, -62, -54, -47,
+	 -40, -32, -24, -16, -9, -1, 6, 14,
+	 22, 30, 38, 45, 52, 60, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 103, 98, 93, 88, 82, 76, 69, 62,
+	 55, 48, 41, 33, 25, 17, 9, 1,
+	 -5, -13, -21, -29, -37, -44, -52, -59,
+	 -66, -72, -79, -85, -91, -96, -101, -105,
+	 -110, -113, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -104, -99, -94, -88, -83, -76,
+	 -70, -63, -56, -49, -41, -34, -26, -18,
+	 -10, -2, 5, 13, 20, 28, 36, 43,
+	 51, 58, 65, 72, 78, 84, 90, 95,
+	 100, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 109, 104, 100, 94, 89,
+	 83, 77, 71, 64, 57, 50, 42, 35,
+	 27, 19, 11, 3, -4, -12, -20, -27,
+	 -35, -43, -50, -57, -64, -71, -77, -83,
+	 -89, -95, -100, -104, -109, -113, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -100,
+	 -95, -90, -84, -78, -71, -65, -58, -51,
+	 -43, -36, -28, -20, -12, -4, 3, 11,
+	 19, 26, 34, 42, 49, 56, 63, 70,
+	 77, 83, 89, 94, 99, 104, 108, 112,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 117, 113, 109,
+	 105, 101, 96, 90, 85, 78, 72, 65,
+	 58, 51, 44, 36, 29, 21, 13, 5,
+	 -2, -10, -18, -26, -33, -41, -48, -56,
+	 -63, -69, -76, -82, -88, -94, -99, -103,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -59, -52, -45, -37, -30, -22,
+	 -14, -6, 1, 9, 17, 25, 32, 40,
+	 47, 55, 62, 69, 75, 81, 87, 93,
+	 98, 103, 107, 111, 115, 118, 121, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 102, 97, 92,
+	 86, 80, 74, 67, 60, 53, 46, 38,
+	 30, 23, 15, 7, 0, -8, -16, -24,
+	 -32, -39, -47, -54, -61, -68, -74, -81,
+	 -87, -92, -98, -102, -107, -111, -115, -118,
+	 -120, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -123, -120, -118, -114, -111, -107, -102,
+	 -97, -92, -87, -81, -74, -68, -61, -54,
+	 -46, -39, -31, -24, -16, -8, 0, 7,
+	 15, 23, 31, 38, 46, 53, 60, 67,
+	 74, 80, 86, 92, 97, 102, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 121, 118, 115, 111,
+	 107, 103, 98, 93, 87, 81, 75, 68,
+	 62, 55, 47, 40, 32, 24, 17, 9,
+	 1, -6, -14, -22, -30, -37, -45, -52,
+	 -59, -66, -73, -79, -85, -91, -96, -101,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -103, -99, -93, -88, -82,
+	 -76, -69, -62, -55, -48, -41, -33, -25,
+	 -18, -10, -2, 5, 13, 21, 29, 37,
+	 44, 51, 59, 66, 72, 79, 85, 90,
+	 96, 101, 105, 110, 113, 117, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 108, 104, 99, 94,
+	 88, 83, 76, 70, 63, 56, 49, 42,
+	 34, 26, 18, 11, 3, -4, -12, -20,
+	 -28, -36, -43, -51, -58, -65, -71, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -113, -109, -104,
+	 -100, -95, -89, -83, -77, -71, -64, -57,
+	 -50, -42, -35, -27, -19, -11, -3, 3,
+	 11, 19, 27, 35, 42, 50, 57, 64,
+	 71, 77, 83, 89, 95, 100, 104, 109,
+	 113, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 71,
+	 65, 58, 51, 43, 36, 28, 20, 12,
+	 4, -3, -11, -18, -26, -34, -42, -49,
+	 -56, -63, -70, -76, -83, -89, -94, -99,
+	 -104, -108, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -117, -113, -110, -105, -101, -96, -90, -85,
+	 -79, -72, -66, -59, -51, -44, -37, -29,
+	 -21, -13, -5, 2, 10, 18, 25, 33,
+	 41, 48, 55, 62, 69, 76, 82, 88,
+	 93, 99, 103, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 101, 96,
+	 91, 85, 79, 73, 66, 59, 52, 45,
+	 37, 30, 22, 14, 6, -1, -9, -17,
+	 -24, -32, -40, -47, -55, -62, -68, -75,
+	 -81, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -121, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -102, -97, -92, -86, -80, -74, -67, -60,
+	 -53, -46, -38, -31, -23, -15, -7, 0,
+	 8, 16, 24, 31, 39, 46, 54, 61,
+	 68, 74, 81, 87, 92, 97, 102, 107,
+	 111, 114, 118, 120, 123, 124, 125, 126,
+	 126, 126, 126, 124, 123, 120, 118, 115,
+	 111, 107, 102, 98, 92, 87, 81, 74,
+	 68, 61, 54, 47, 39, 32, 24, 16,
+	 8, 0, -7, -15, -23, -30, -38, -46,
+	 -53, -60, -67, -74, -80, -86, -92, -97,
+	 -102, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -121,
+	 -118, -115, -111, -107, -103, -98, -93, -87,
+	 -81, -75, -69, -62, -55, -47, -40, -32,
+	 -25, -17, -9, -1, 6, 14, 22, 30,
+	 37, 45, 52, 59, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 103, 99,
+	 94, 88, 82, 76, 69, 63, 56, 48,
+	 41, 33, 26, 18, 10, 2, -5, -13,
+	 -21, -29, -36, -44, -51, -58, -65, -72,
+	 -78, -85, -90, -96, -101, -105, -109, -113,
+	 -117, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -112, -108,
+	 -104, -99, -94, -89, -83, -77, -70, -63,
+	 -56, -49, -42, -34, -26, -19, -11, -3,
+	 4, 12, 20, 28, 36, 43, 50, 58,
+	 65, 71, 78, 84, 90, 95, 100, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 113, 109, 104, 100, 95, 89, 83, 77,
+	 71, 64, 57, 50, 43, 35, 27, 20,
+	 12, 4, -3, -11, -19, -27, -35, -42,
+	 -50, -57, -64, -71, -77, -83, -89, -94,
+	 -100, -104, -109, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -100, -95, -90,
+	 -84, -78, -72, -65, -58, -51, -43, -36,
+	 -28, -20, -12, -5, 2, 10, 18, 26,
+	 34, 41, 49, 56, 63, 70, 76, 83,
+	 88, 94, 99, 104, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 113, 110, 105, 101,
+	 96, 91, 85, 79, 72, 66, 59, 52,
+	 44, 37, 29, 21, 13, 5, -2, -9,
+	 -17, -25, -33, -41, -48, -55, -62, -69,
+	 -76, -82, -88, -93, -98, -103, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -60, -52, -45, -38, -30, -22, -14, -6,
+	 1, 9, 16, 24, 32, 40, 47, 54,
+	 62, 68, 75, 81, 87, 93, 98, 103,
+	 107, 111, 115, 118, 121, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 111, 106, 102, 97, 92, 86, 80,
+	 74, 67, 60, 53, 46, 38, 31, 23,
+	 15, 7, 0, -8, -16, -23, -31, -39,
+	 -46, -54, -61, -68, -74, -80, -86, -92,
+	 -97, -102, -107, -111, -114, -118, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -120, -118, -115, -111, -107, -103, -98, -92,
+	 -87, -81, -75, -68, -61, -54, -47, -39,
+	 -32, -24, -16, -8, 0, 7, 15, 23,
+	 30, 38, 45, 53, 60, 67, 73, 80,
+	 86, 91, 97, 102, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 121, 118, 115, 111, 107, 103,
+	 98, 93, 87, 82, 75, 69, 62, 55,
+	 48, 40, 33, 25, 17, 9, 1, -6,
+	 -14, -22, -29, -37, -45, -52, -59, -66,
+	 -73, -79, -85, -91, -96, -101, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -104, -99, -94, -88, -82, -76, -70,
+	 -63, -56, -48, -41, -33, -26, -18, -10,
+	 -2, 5, 13, 21, 29, 36, 44, 51,
+	 58, 65, 72, 78, 84, 90, 96, 101,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 108, 104, 99, 94, 89, 83,
+	 77, 70, 64, 57, 49, 42, 34, 27,
+	 19, 11, 3, -4, -12, -20, -28, -35,
+	 -43, -50, -58, -64, -71, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -123, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -113, -109, -105, -100, -95,
+	 -89, -84, -77, -71, -64, -57, -50, -43,
+	 -35, -27, -20, -12, -4, 3, 11, 19,
+	 27, 35, 42, 49, 57, 64, 70, 77,
+	 83, 89, 94, 99, 104, 108, 112, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 72, 65, 58,
+	 51, 44, 36, 28, 21, 13, 5, -2,
+	 -10, -18, -26, -34, -41, -49, -56, -63,
+	 -70, -76, -82, -88, -94, -99, -104, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -113,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -59, -52, -44, -37, -29, -21, -14,
+	 -6, 1, 9, 17, 25, 33, 40, 48,
+	 55, 62, 69, 75, 82, 88, 93, 98,
+	 103, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 102, 97, 91, 86,
+	 80, 73, 67, 60, 53, 45, 38, 30,
+	 22, 14, 7, 0, -8, -16, -24, -32,
+	 -40, -47, -54, -61, -68, -75, -81, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -120,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -111, -107, -102, -97,
+	 -92, -86, -80, -74, -67, -61, -53, -46,
+	 -39, -31, -23, -15, -7, 0, 7, 15,
+	 23, 31, 39, 46, 53, 61, 67, 74,
+	 80, 86, 92, 97, 102, 107, 111, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 120, 118, 115, 111, 107,
+	 103, 98, 93, 87, 81, 75, 68, 61,
+	 54, 47, 40, 32, 24, 16, 8, 0,
+	 -7, -14, -22, -30, -38, -45, -53, -60,
+	 -67, -73, -80, -86, -91, -97, -102, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -103, -98, -93, -88, -82, -75,
+	 -69, -62, -55, -48, -40, -33, -25, -17,
+	 -9, -1, 6, 14, 21, 29, 37, 44,
+	 52, 59, 66, 73, 79, 85, 91, 96,
+	 101, 106, 110, 113, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 104, 99, 94, 88,
+	 82, 76, 70, 63, 56, 49, 41, 34,
+	 26, 18, 10, 2, -5, -13, -21, -28,
+	 -36, -44, -51, -58, -65, -72, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -112, -108, -104, -99,
+	 -94, -89, -83, -77, -70, -64, -57, -49,
+	 -42, -35, -27, -19, -11, -3, 4, 12,
+	 20, 28, 35, 43, 50, 57, 64, 71,
+	 77, 84, 89, 95, 100, 105, 109, 113,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 123, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 71, 64,
+	 58, 50, 43, 35, 28, 20, 12, 4,
+	 -3, -11, -19, -27, -34, -42, -49, -57,
+	 -64, -70, -77, -83, -89, -94, -99, -104,
+	 -108, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -101, -96, -90, -84, -78,
+	 -72, -65, -58, -51, -44, -36, -29, -21,
+	 -13, -5, 2, 10, 18, 26, 33, 41,
+	 49, 56, 63, 70, 76, 82, 88, 94,
+	 99, 104, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 101, 96, 91,
+	 85, 79, 73, 66, 59, 52, 45, 37,
+	 29, 22, 14, 6, -1, -9, -17, -25,
+	 -33, -40, -48, -55, -62, -69, -75, -82,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -121, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -102,
+	 -97, -91, -86, -80, -73, -67, -60, -53,
+	 -45, -38, -30, -23, -15, -7, 0, 8,
+	 16, 24, 32, 39, 47, 54, 61, 68,
+	 75, 81, 87, 92, 98, 103, 107, 111,
+	 115, 118, 120, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 118, 114, 111,
+	 107, 102, 97, 92, 86, 80, 74, 68,
+	 61, 54, 46, 39, 31, 23, 16, 8,
+	 0, -7, -15, -23, -31, -38, -46, -53,
+	 -60, -67, -74, -80, -86, -92, -97, -102,
+	 -106, -111, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -121, -118,
+	 -115, -111, -107, -103, -98, -93, -87, -81,
+	 -75, -68, -62, -54, -47, -40, -32, -24,
+	 -16, -9, -1, 6, 14, 22, 30, 38,
+	 45, 52, 60, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 103, 98, 93,
+	 88, 82, 76, 69, 62, 55, 48, 41,
+	 33, 25, 17, 9, 1, -5, -13, -21,
+	 -29, -37, -44, -52, -59, -66, -72, -79,
+	 -85, -91, -96, -101, -105, -110, -113, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -104,
+	 -99, -94, -88, -83, -76, -70, -63, -56,
+	 -49, -41, -34, -26, -18, -10, -2, 5,
+	 13, 20, 28, 36, 43, 51, 58, 65,
+	 72, 78, 84, 90, 95, 100, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 109, 104, 100, 94, 89, 83, 77, 71,
+	 64, 57, 50, 42, 35, 27, 19, 11,
+	 3, -4, -12, -20, -27, -35, -43, -50,
+	 -57, -64, -71, -77, -83, -89, -95, -100,
+	 -104, -109, -113, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -100, -95, -90, -84,
+	 -78, -71, -65, -58, -50, -43, -36, -28,
+	 -20, -12, -4, 3, 11, 19, 26, 34,
+	 42, 49, 56, 63, 70, 77, 83, 89,
+	 94, 99, 104, 108, 112, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 117, 113, 109, 105, 101, 96,
+	 90, 85, 78, 72, 65, 58, 51, 44,
+	 36, 29, 21, 13, 5, -2, -10, -18,
+	 -26, -33, -41, -48, -56, -63, -69, -76,
+	 -82, -88, -94, -99, -103, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -59,
+	 -52, -45, -37, -30, -22, -14, -6, 1,
+	 9, 17, 25, 32, 40, 47, 55, 62,
+	 69, 75, 81, 87, 93, 98, 103, 107,
+	 111, 115, 118, 121, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 102, 97, 92, 86, 80, 74,
+	 67, 60, 53, 46, 38, 30, 23, 15,
+	 7, 0, -8, -16, -24, -32, -39, -47,
+	 -54, -61, -68, -74, -81, -87, -92, -98,
+	 -102, -107, -111, -115, -118, -120, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -123, -120,
+	 -118, -114, -111, -107, -102, -97, -92, -87,
+	 -81, -74, -68, -61, -54, -46, -39, -31,
+	 -24, -16, -8, 0, 7, 15, 23, 31,
+	 38, 46, 53, 60, 67, 74, 80, 86,
+	 92, 97, 102, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 121, 118, 115, 111, 107, 103, 98,
+	 93, 87, 81, 75, 68, 62, 55, 47,
+	 40, 32, 24, 17, 9, 1, -6, -14,
+	 -22, -30, -37, -45, -52, -59, -66, -73,
+	 -79, -85, -91, -96, -101, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -103, -99, -93, -88, -82, -76, -69, -62,
+	 -55, -48, -41, -33, -25, -18, -10, -2,
+	 5, 13, 21, 29, 37, 44, 51, 59,
+	 66, 72, 79, 85, 90, 96, 101, 105,
+	 110, 113, 117, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 108, 104, 99, 94, 88, 83, 76,
+	 70, 63, 56, 49, 42, 34, 26, 18,
+	 11, 3, -4, -12, -20, -28, -36, -43,
+	 -51, -58, -65, -71, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -113, -109, -104, -100, -95, -89,
+	 -83, -77, -71, -64, -57, -50, -42, -35,
+	 -27, -19, -11, -3, 3, 11, 19, 27,
+	 35, 42, 50, 57, 64, 71, 77, 83,
+	 89, 95, 100, 104, 109, 113, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 71, 65, 58, 51,
+	 43, 36, 28, 20, 12, 4, -3, -11,
+	 -18, -26, -34, -42, -49, -56, -63, -70,
+	 -76, -83, -89, -94, -99, -104, -108, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -117, -113, -110,
+	 -105, -101, -96, -90, -85, -79, -72, -66,
+	 -59, -51, -44, -37, -29, -21, -13, -5,
+	 2, 10, 18, 25, 33, 41, 48, 55,
+	 62, 69, 76, 82, 88, 93, 99, 103,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 101, 96, 91, 85, 79,
+	 73, 66, 59, 52, 45, 37, 30, 22,
+	 14, 6, -1, -9, -17, -24, -32, -40,
+	 -47, -55, -62, -68, -75, -81, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -121, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -102, -97, -92,
+	 -86, -80, -74, -67, -60, -53, -46, -38,
+	 -31, -23, -15, -7, 0, 8, 16, 24,
+	 31, 39, 46, 54, 61, 68, 74, 81,
+	 87, 92, 97, 102, 107, 111, 114, 118,
+	 120, 123, 124, 125, 126, 126, 126, 126,
+	 124, 123, 120, 118, 115, 111, 107, 102,
+	 98, 92, 87, 81, 74, 68, 61, 54,
+	 47, 39, 32, 24, 16, 8, 0, -7,
+	 -15, -23, -30, -38, -46, -53, -60, -67,
+	 -74, -80, -86, -92, -97, -102, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -121, -118, -115, -111,
+	 -107, -103, -98, -93, -87, -81, -75, -69,
+	 -62, -55, -47, -40, -32, -25, -17, -9,
+	 -1, 6, 14, 22, 30, 37, 45, 52,
+	 59, 66, 73, 79, 85, 91, 96, 101,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 103, 99, 94, 88, 82,
+	 76, 69, 63, 56, 48, 41, 33, 26,
+	 18, 10, 2, -5, -13, -21, -29, -36,
+	 -44, -51, -58, -65, -72, -78, -85, -90,
+	 -96, -101, -105, -109, -113, -117, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -112, -108, -104, -99, -94,
+	 -89, -83, -77, -70, -63, -56, -49, -42,
+	 -34, -26, -19, -11, -3, 4, 12, 20,
+	 28, 36, 43, 50, 58, 65, 71, 78,
+	 84, 90, 95, 100, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 113, 109, 104,
+	 100, 95, 89, 83, 77, 71, 64, 57,
+	 50, 43, 35, 27, 19, 12, 4, -3,
+	 -11, -19, -27, -35, -42, -50, -57, -64,
+	 -71, -77, -83, -89, -94, -100, -104, -109,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -100, -95, -90, -84, -78, -72,
+	 -65, -58, -51, -43, -36, -28, -20, -13,
+	 -5, 2, 10, 18, 26, 34, 41, 49,
+	 56, 63, 70, 76, 83, 88, 94, 99,
+	 104, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 113, 110, 105, 101, 96, 91, 85,
+	 79, 72, 66, 59, 52, 44, 37, 29,
+	 21, 13, 5, -1, -9, -17, -25, -33,
+	 -41, -48, -55, -62, -69, -76, -82, -88,
+	 -93, -98, -103, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -67, -60, -52, -45,
+	 -38, -30, -22, -14, -6, 1, 9, 16,
+	 24, 32, 40, 47, 54, 62, 68, 75,
+	 81, 87, 93, 98, 103, 107, 111, 115,
+	 118, 121, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 111, 106,
+	 102, 97, 92, 86, 80, 74, 67, 60,
+	 53, 46, 38, 31, 23, 15, 7, 0,
+	 -8, -16, -23, -31, -39, -46, -54, -61,
+	 -68, -74, -80, -86, -92, -97, -102, -107,
+	 -111, -114, -118, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -120, -118, -115,
+	 -111, -107, -103, -98, -92, -87, -81, -75,
+	 -68, -61, -54, -47, -39, -32, -24, -16,
+	 -8, 0, 7, 15, 23, 30, 38, 45,
+	 53, 60, 67, 73, 80, 86, 91, 97,
+	 102, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 121,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 82, 75, 69, 62, 55, 48, 40, 33,
+	 25, 17, 9, 1, -6, -14, -22, -29,
+	 -37, -45, -52, -59, -66, -73, -79, -85,
+	 -91, -96, -101, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -104, -99,
+	 -94, -88, -82, -76, -70, -63, -56, -48,
+	 -41, -33, -26, -18, -10, -2, 5, 13,
+	 21, 29, 36, 44, 51, 58, 65, 72,
+	 78, 84, 90, 96, 101, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 108,
+	 104, 99, 94, 89, 83, 77, 70, 64,
+	 57, 49, 42, 34, 27, 19, 11, 3,
+	 -4, -12, -20, -28, -35, -43, -50, -58,
+	 -64, -71, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -123, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -113, -109, -105, -100, -95, -89, -84, -77,
+	 -71, -64, -57, -50, -43, -35, -28, -20,
+	 -12, -4, 3, 11, 19, 27, 35, 42,
+	 49, 57, 64, 70, 77, 83, 89, 94,
+	 99, 104, 108, 112, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 72, 65, 58, 51, 44, 36,
+	 28, 21, 13, 5, -2, -10, -18, -26,
+	 -34, -41, -49, -56, -63, -70, -76, -82,
+	 -88, -94, -99, -104, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -113, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -66, -59, -52,
+	 -44, -37, -29, -21, -14, -6, 1, 9,
+	 17, 25, 33, 40, 48, 55, 62, 69,
+	 75, 82, 88, 93, 98, 103, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 102, 97, 91, 86, 80, 73, 67,
+	 60, 53, 45, 38, 30, 22, 14, 7,
+	 0, -8, -16, -24, -32, -40, -47, -54,
+	 -61, -68, -75, -81, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -120, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -111, -107, -102, -97, -92, -86, -80,
+	 -74, -67, -61, -53, -46, -39, -31, -23,
+	 -15, -7, 0, 7, 15, 23, 31, 39,
+	 46, 53, 61, 67, 74, 80, 86, 92,
+	 97, 102, 107, 111, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 120, 118, 115, 111, 107, 103, 98, 93,
+	 87, 81, 75, 68, 61, 54, 47, 40,
+	 32, 24, 16, 8, 0, -7, -14, -22,
+	 -30, -38, -45, -53, -60, -67, -73, -80,
+	 -86, -91, -97, -102, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -103,
+	 -98, -93, -88, -82, -75, -69, -62, -55,
+	 -48, -40, -33, -25, -17, -9, -1, 6,
+	 14, 21, 29, 37, 44, 52, 59, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 113, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 104, 99, 94, 88, 82, 76, 70,
+	 63, 56, 49, 41, 34, 26, 18, 10,
+	 2, -5, -13, -21, -28, -36, -44, -51,
+	 -58, -65, -72, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -112, -108, -104, -99, -94, -89, -83,
+	 -77, -70, -64, -57, -49, -42, -35, -27,
+	 -19, -11, -3, 4, 12, 20, 28, 35,
+	 43, 50, 57, 64, 71, 77, 84, 89,
+	 95, 100, 105, 109, 113, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 123,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 71, 64, 58, 50, 43,
+	 35, 28, 20, 12, 4, -3, -11, -19,
+	 -27, -34, -42, -49, -57, -64, -70, -77,
+	 -83, -89, -94, -99, -104, -108, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -101, -96, -90, -84, -78, -72, -65, -58,
+	 -51, -44, -36, -29, -21, -13, -5, 2,
+	 10, 18, 26, 33, 41, 48, 56, 63,
+	 70, 76, 82, 88, 94, 99, 104, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 101, 96, 91, 85, 79, 73,
+	 66, 59, 52, 45, 37, 29, 22, 14,
+	 6, -1, -9, -17, -25, -33, -40, -48,
+	 -55, -62, -69, -75, -82, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -121, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -102, -97, -91, -86,
+	 -80, -73, -67, -60, -53, -45, -38, -30,
+	 -23, -15, -7, 0, 8, 16, 24, 32,
+	 39, 47, 54, 61, 68, 75, 81, 87,
+	 92, 98, 103, 107, 111, 115, 118, 120,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 118, 114, 111, 107, 102, 97,
+	 92, 86, 80, 74, 68, 61, 54, 46,
+	 39, 31, 23, 16, 8, 0, -7, -15,
+	 -23, -31, -38, -46, -53, -60, -67, -74,
+	 -80, -86, -92, -97, -102, -106, -111, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -121, -118, -115, -111, -107,
+	 -103, -98, -93, -87, -81, -75, -68, -62,
+	 -54, -47, -40, -32, -24, -16, -9, -1,
+	 6, 14, 22, 30, 38, 45, 52, 60,
+	 66, 73, 79, 85, 91, 96, 101, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 103, 98, 93, 88, 82, 76,
+	 69, 62, 55, 48, 41, 33, 25, 17,
+	 9, 1, -5, -13, -21, -29, -37, -44,
+	 -52, -59, -66, -72, -79, -85, -91, -96,
+	 -101, -105, -110, -113, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -104, -99, -94, -88,
+	 -83, -76, -70, -63, -56, -49, -41, -34,
+	 -26, -18, -10, -2, 5, 13, 20, 28,
+	 36, 43, 51, 58, 65, 72, 78, 84,
+	 90, 95, 100, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 109, 104, 100,
+	 94, 89, 83, 77, 71, 64, 57, 50,
+	 42, 35, 27, 19, 11, 3, -4, -12,
+	 -19, -27, -35, -43, -50, -57, -64, -71,
+	 -77, -83, -89, -95, -100, -104, -109, -113,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -100, -95, -90, -84, -78, -71, -65,
+	 -58, -50, -43, -36, -28, -20, -12, -4,
+	 3, 11, 19, 26, 34, 42, 49, 56,
+	 63, 70, 77, 83, 89, 94, 99, 104,
+	 108, 112, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 117,
+	 113, 109, 105, 101, 96, 90, 85, 78,
+	 72, 65, 58, 51, 44, 36, 29, 21,
+	 13, 5, -2, -10, -18, -26, -33, -41,
+	 -48, -56, -63, -69, -76, -82, -88, -94,
+	 -99, -103, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -59, -52, -45, -37,
+	 -30, -22, -14, -6, 1, 9, 17, 25,
+	 32, 40, 48, 55, 62, 69, 75, 81,
+	 87, 93, 98, 103, 107, 111, 115, 118,
+	 121, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 102,
+	 97, 92, 86, 80, 74, 67, 60, 53,
+	 46, 38, 30, 23, 15, 7, 0, -8,
+	 -16, -24, -32, -39, -47, -54, -61, -68,
+	 -74, -81, -87, -92, -98, -102, -107, -111,
+	 -115, -118, -120, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -123, -120, -118, -114, -111,
+	 -107, -102, -97, -92, -87, -81, -74, -68,
+	 -61, -54, -46, -39, -31, -24, -16, -8,
+	 0, 7, 15, 23, 31, 38, 46, 53,
+	 60, 67, 74, 80, 86, 92, 97, 102,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 121, 118,
+	 115, 111, 107, 103, 98, 93, 87, 81,
+	 75, 68, 62, 55, 47, 40, 32, 24,
+	 17, 9, 1, -6, -14, -22, -30, -37,
+	 -45, -52, -59, -66, -73, -79, -85, -91,
+	 -96, -101, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -103, -99, -93,
+	 -88, -82, -76, -69, -62, -55, -48, -41,
+	 -33, -25, -18, -10, -2, 5, 13, 21,
+	 29, 37, 44, 51, 59, 66, 72, 79,
+	 85, 90, 96, 101, 105, 110, 113, 117,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 108, 104,
+	 99, 94, 88, 83, 76, 70, 63, 56,
+	 49, 42, 34, 26, 18, 11, 3, -4,
+	 -12, -20, -28, -36, -43, -51, -58, -65,
+	 -71, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -113,
+	 -109, -104, -100, -95, -89, -83, -77, -71,
+	 -64, -57, -50, -42, -35, -27, -19, -11,
+	 -3, 3, 11, 19, 27, 35, 42, 50,
+	 57, 64, 71, 77, 83, 89, 95, 100,
+	 104, 109, 113, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 71, 65, 58, 51, 43, 36, 28,
+	 20, 12, 4, -3, -11, -18, -26, -34,
+	 -42, -49, -56, -63, -70, -76, -83, -88,
+	 -94, -99, -104, -108, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -117, -113, -110, -105, -101, -96,
+	 -90, -85, -79, -72, -66, -59, -51, -44,
+	 -37, -29, -21, -13, -5, 2, 10, 18,
+	 25, 33, 41, 48, 55, 62, 69, 76,
+	 82, 88, 93, 99, 103, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 101, 96, 91, 85, 79, 73, 66, 59,
+	 52, 45, 37, 30, 22, 14, 6, -1,
+	 -9, -17, -24, -32, -40, -47, -55, -62,
+	 -68, -75, -81, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -121, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -102, -97, -92, -86, -80, -74,
+	 -67, -60, -53, -46, -38, -31, -23, -15,
+	 -7, 0, 8, 16, 24, 31, 39, 46,
+	 54, 61, 68, 74, 81, 87, 92, 97,
+	 102, 107, 111, 114, 118, 120, 123, 124,
+	 125, 126, 126, 126, 126, 124, 123, 120,
+	 118, 115, 111, 107, 102, 98, 92, 87,
+	 81, 74, 68, 61, 54, 47, 39, 32,
+	 24, 16, 8, 0, -7, -15, -23, -30,
+	 -38, -46, -53, -60, -67, -74, -80, -86,
+	 -92, -97, -102, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -121, -118, -115, -111, -107, -103, -98,
+	 -93, -87, -81, -75, -69, -62, -55, -47,
+	 -40, -32, -25, -17, -9, -1, 6, 14,
+	 22, 30, 37, 45, 52, 59, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 103, 99, 94, 88, 82, 76, 69, 63,
+	 56, 48, 41, 33, 26, 18, 10, 2,
+	 -5, -13, -21, -29, -36, -44, -51, -58,
+	 -65, -72, -78, -85, -90, -96, -101, -105,
+	 -109, -113, -117, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -112, -108, -104, -99, -94, -89, -83, -77,
+	 -70, -63, -56, -49, -42, -34, -26, -19,
+	 -11, -3, 4, 12, 20, 28, 36, 43,
+	 50, 58, 65, 71, 78, 84, 90, 95,
+	 100, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 113, 109, 104, 100, 95, 89,
+	 83, 77, 71, 64, 57, 50, 43, 35,
+	 27, 20, 12, 4, -3, -11, -19, -27,
+	 -35, -42, -50, -57, -64, -71, -77, -83,
+	 -89, -94, -100, -104, -109, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -100,
+	 -95, -90, -84, -78, -72, -65, -58, -51,
+	 -43, -36, -28, -20, -13, -5, 2, 10,
+	 18, 26, 34, 41, 49, 56, 63, 70,
+	 76, 83, 88, 94, 99, 104, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 113, 110,
+	 105, 101, 96, 91, 85, 79, 72, 66,
+	 59, 52, 44, 37, 29, 21, 13, 5,
+	 -1, -9, -17, -25, -33, -41, -48, -55,
+	 -62, -69, -76, -82, -88, -93, -98, -103,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -60, -52, -45, -38, -30, -22,
+	 -14, -6, 1, 9, 16, 24, 32, 40,
+	 47, 54, 62, 68, 75, 81, 87, 93,
+	 98, 103, 107, 111, 115, 118, 121, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 111, 106, 102, 97, 92,
+	 86, 80, 74, 67, 60, 53, 46, 38,
+	 31, 23, 15, 7, 0, -8, -16, -23,
+	 -31, -39, -46, -54, -61, -68, -74, -80,
+	 -86, -92, -97, -102, -107, -111, -114, -118,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -120, -118, -115, -111, -107, -103,
+	 -98, -92, -87, -81, -75, -68, -61, -54,
+	 -47, -39, -32, -24, -16, -8, 0, 7,
+	 15, 23, 30, 38, 45, 53, 60, 67,
+	 73, 80, 86, 91, 97, 102, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 121, 118, 115, 111,
+	 107, 103, 98, 93, 87, 82, 75, 69,
+	 62, 55, 48, 40, 33, 25, 17, 9,
+	 1, -6, -14, -22, -29, -37, -45, -52,
+	 -59, -66, -73, -79, -85, -91, -96, -101,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -104, -99, -94, -88, -82,
+	 -76, -70, -63, -56, -48, -41, -33, -26,
+	 -18, -10, -2, 5, 13, 21, 29, 36,
+	 44, 51, 58, 65, 72, 78, 84, 90,
+	 96, 101, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 108, 104, 99, 94,
+	 89, 83, 77, 70, 64, 57, 49, 42,
+	 34, 27, 19, 11, 3, -4, -12, -20,
+	 -28, -35, -43, -50, -58, -64, -71, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -123, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -113, -109, -105,
+	 -100, -95, -89, -84, -77, -71, -64, -57,
+	 -50, -43, -35, -27, -20, -12, -4, 3,
+	 11, 19, 27, 35, 42, 50, 57, 64,
+	 70, 77, 83, 89, 94, 99, 104, 108,
+	 112, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 72,
+	 65, 58, 51, 44, 36, 28, 21, 13,
+	 5, -2, -10, -18, -26, -34, -41, -49,
+	 -56, -63, -70, -76, -82, -88, -94, -99,
+	 -104, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -113, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -59, -52, -44, -37, -29,
+	 -21, -14, -6, 1, 9, 17, 25, 33,
+	 40, 48, 55, 62, 69, 75, 82, 88,
+	 93, 98, 103, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 102, 97,
+	 91, 86, 80, 73, 67, 60, 53, 45,
+	 38, 30, 22, 14, 7, 0, -8, -16,
+	 -24, -32, -40, -47, -54, -61, -68, -75,
+	 -81, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -120, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -111, -107,
+	 -102, -97, -92, -86, -80, -74, -67, -61,
+	 -53, -46, -39, -31, -23, -15, -7, 0,
+	 7, 15, 23, 31, 39, 46, 53, 61,
+	 67, 74, 80, 86, 92, 97, 102, 107,
+	 111, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 120, 118, 115,
+	 111, 107, 103, 98, 93, 87, 81, 75,
+	 68, 61, 54, 47, 40, 32, 24, 16,
+	 8, 0, -7, -14, -22, -30, -38, -45,
+	 -53, -60, -67, -73, -80, -86, -91, -97,
+	 -102, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -103, -98, -93, -88,
+	 -82, -75, -69, -62, -55, -48, -40, -33,
+	 -25, -17, -9, -1, 6, 14, 21, 29,
+	 37, 44, 52, 59, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 113, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 104, 99,
+	 94, 88, 82, 76, 70, 63, 56, 49,
+	 41, 34, 26, 18, 10, 2, -5, -13,
+	 -21, -28, -36, -44, -51, -58, -65, -72,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -112, -108,
+	 -104, -99, -94, -89, -83, -77, -70, -64,
+	 -57, -49, -42, -35, -27, -19, -11, -3,
+	 4, 12, 20, 28, 35, 43, 50, 57,
+	 64, 71, 77, 84, 89, 95, 100, 105,
+	 109, 113, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 123, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 71, 64, 58, 50, 43, 35, 28, 20,
+	 12, 4, -3, -11, -19, -27, -34, -42,
+	 -49, -57, -64, -70, -77, -83, -89, -94,
+	 -99, -104, -108, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -101, -96, -90,
+	 -84, -78, -72, -65, -58, -51, -44, -36,
+	 -29, -21, -13, -5, 2, 10, 18, 26,
+	 33, 41, 48, 56, 63, 70, 76, 82,
+	 88, 94, 99, 104, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 101,
+	 96, 91, 85, 79, 73, 66, 59, 52,
+	 45, 37, 29, 22, 14, 6, -1, -9,
+	 -17, -25, -33, -40, -48, -55, -62, -69,
+	 -75, -82, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -121, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -102, -97, -91, -86, -80, -73, -67,
+	 -60, -53, -45, -38, -30, -23, -15, -7,
+	 0, 8, 16, 24, 32, 39, 47, 54,
+	 61, 68, 75, 81, 87, 92, 98, 103,
+	 107, 111, 115, 118, 120, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 118,
+	 114, 111, 107, 102, 97, 92, 86, 80,
+	 74, 68, 61, 54, 46, 39, 31, 23,
+	 16, 8, 0, -7, -15, -23, -31, -39,
+	 -46, -53, -60, -67, -74, -80, -86, -92,
+	 -97, -102, -106, -111, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -121, -118, -115, -111, -107, -103, -98, -93,
+	 -87, -81, -75, -68, -61, -54, -47, -40,
+	 -32, -24, -16, -9, -1, 6, 14, 22,
+	 30, 38, 45, 52, 60, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 103,
+	 98, 93, 88, 82, 76, 69, 62, 55,
+	 48, 41, 33, 25, 17, 9, 1, -5,
+	 -13, -21, -29, -37, -44, -52, -59, -66,
+	 -72, -79, -85, -91, -96, -101, -105, -110,
+	 -113, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -104, -99, -94, -88, -82, -76, -70,
+	 -63, -56, -49, -41, -34, -26, -18, -10,
+	 -2, 5, 13, 20, 28, 36, 43, 51,
+	 58, 65, 72, 78, 84, 90, 95, 100,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 109, 104, 100, 94, 89, 83,
+	 77, 71, 64, 57, 50, 42, 35, 27,
+	 19, 11, 3, -4, -12, -20, -27, -35,
+	 -43, -50, -57, -64, -71, -77, -83, -89,
+	 -95, -100, -104, -109, -113, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -100, -95,
+	 -90, -84, -78, -71, -65, -58, -50, -43,
+	 -36, -28, -20, -12, -4, 3, 11, 19,
+	 26, 34, 42, 49, 56, 63, 70, 77,
+	 83, 89, 94, 99, 104, 108, 112, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 117, 113, 109, 105,
+	 101, 96, 90, 85, 78, 72, 65, 58,
+	 51, 44, 36, 29, 21, 13, 5, -2,
+	 -10, -18, -26, -33, -41, -48, -56, -63,
+	 -69, -76, -82, -88, -94, -99, -103, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -59, -52, -45, -37, -30, -22, -14,
+	 -6, 1, 9, 17, 25, 32, 40, 47,
+	 55, 62, 69, 75, 81, 87, 93, 98,
+	 103, 107, 111, 115, 118, 121, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 102, 97, 92, 86,
+	 80, 74, 67, 60, 53, 46, 38, 30,
+	 23, 15, 7, 0, -8, -16, -24, -32,
+	 -39, -47, -54, -61, -68, -74, -81, -87,
+	 -92, -98, -102, -107, -111, -115, -118, -120,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -123, -120, -118, -114, -111, -107, -102, -97,
+	 -92, -87, -81, -74, -68, -61, -54, -46,
+	 -39, -31, -24, -16, -8, 0, 7, 15,
+	 23, 31, 38, 46, 53, 60, 67, 74,
+	 80, 86, 92, 97, 102, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 121, 118, 115, 111, 107,
+	 103, 98, 93, 87, 81, 75, 68, 62,
+	 55, 47, 40, 32, 24, 17, 9, 1,
+	 -6, -14, -22, -30, -37, -45, -52, -59,
+	 -66, -73, -79, -85, -91, -96, -101, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -103, -99, -93, -88, -82, -76,
+	 -69, -62, -55, -48, -41, -33, -25, -18,
+	 -10, -2, 5, 13, 21, 29, 37, 44,
+	 51, 59, 66, 72, 79, 85, 90, 96,
+	 101, 105, 110, 113, 117, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 108, 104, 99, 94, 88,
+	 83, 76, 70, 63, 56, 49, 42, 34,
+	 26, 18, 11, 3, -4, -12, -20, -28,
+	 -36, -43, -51, -58, -65, -71, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -113, -109, -104, -100,
+	 -95, -89, -83, -77, -71, -64, -57, -50,
+	 -42, -35, -27, -19, -11, -3, 3, 11,
+	 19, 27, 35, 42, 50, 57, 64, 71,
+	 77, 83, 89, 95, 100, 104, 109, 113,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 71, 65,
+	 58, 51, 43, 36, 28, 20, 12, 4,
+	 -3, -11, -18, -26, -34, -42, -49, -56,
+	 -63, -70, -76, -83, -88, -94, -99, -104,
+	 -108, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -117,
+	 -113, -110, -105, -101, -96, -90, -85, -79,
+	 -72, -66, -59, -51, -44, -37, -29, -21,
+	 -13, -5, 2, 10, 18, 25, 33, 41,
+	 48, 55, 62, 69, 76, 82, 88, 93,
+	 99, 103, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 101, 96, 91,
+	 85, 79, 73, 66, 59, 52, 45, 37,
+	 30, 22, 14, 6, -1, -9, -17, -24,
+	 -32, -40, -47, -55, -62, -68, -75, -81,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -121, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -102,
+	 -97, -92, -86, -80, -74, -67, -60, -53,
+	 -46, -38, -31, -23, -15, -7, 0, 8,
+	 16, 24, 31, 39, 46, 54, 61, 68,
+	 74, 81, 87, 92, 97, 102, 107, 111,
+	 114, 118, 120, 123, 124, 125, 126, 126,
+	 126, 126, 124, 123, 120, 118, 115, 111,
+	 107, 102, 98, 92, 87, 81, 74, 68,
+	 61, 54, 47, 39, 32, 24, 16, 8,
+	 0, -7, -15, -23, -30, -38, -46, -53,
+	 -60, -67, -74, -80, -86, -92, -97, -102,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -121, -118,
+	 -115, -111, -107, -103, -98, -93, -87, -81,
+	 -75, -69, -62, -55, -47, -40, -32, -25,
+	 -17, -9, -1, 6, 14, 22, 30, 37,
+	 45, 52, 59, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 103, 99, 94,
+	 88, 82, 76, 69, 63, 56, 48, 41,
+	 33, 26, 18, 10, 2, -5, -13, -21,
+	 -29, -36, -44, -51, -58, -65, -72, -78,
+	 -85, -90, -96, -101, -105, -109, -113, -117,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -112, -108, -104,
+	 -99, -94, -89, -83, -77, -70, -63, -56,
+	 -49, -42, -34, -26, -19, -11, -3, 4,
+	 12, 20, 28, 36, 43, 51, 58, 65,
+	 71, 78, 84, 90, 95, 100, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 113,
+	 109, 104, 100, 95, 89, 83, 77, 71,
+	 64, 57, 50, 43, 35, 27, 20, 12,
+	 4, -3, -11, -19, -27, -35, -42, -50,
+	 -57, -64, -71, -77, -83, -89, -94, -100,
+	 -104, -109, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -100, -95, -90, -84,
+	 -78, -72, -65, -58, -51, -43, -36, -28,
+	 -20, -13, -5, 2, 10, 18, 26, 34,
+	 41, 49, 56, 63, 70, 76, 83, 88,
+	 94, 99, 104, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 113, 110, 105, 101, 96,
+	 91, 85, 79, 72, 66, 59, 52, 44,
+	 37, 29, 21, 13, 5, -1, -9, -17,
+	 -25, -33, -41, -48, -55, -62, -69, -76,
+	 -82, -88, -93, -98, -103, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -101, -97, -91, -85, -79, -73, -66, -60,
+	 -52, -45, -38, -30, -22, -14, -6, 1,
+	 9, 16, 24, 32, 40, 47, 54, 62,
+	 68, 75, 81, 87, 93, 98, 103, 107,
+	 111, 115, 118, 121, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 111, 106, 102, 97, 92, 86, 80, 74,
+	 67, 60, 53, 46, 38, 31, 23, 15,
+	 7, 0, -8, -16, -23, -31, -39, -46,
+	 -54, -61, -68, -74, -80, -86, -92, -97,
+	 -102, -107, -111, -114, -118, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -120,
+	 -118, -115, -111, -107, -103, -98, -92, -87,
+	 -81, -75, -68, -61, -54, -47, -39, -32,
+	 -24, -16, -8, 0, 7, 15, 23, 30,
+	 38, 45, 53, 60, 67, 73, 80, 86,
+	 91, 97, 102, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 121, 118, 115, 111, 107, 103, 98,
+	 93, 87, 82, 75, 69, 62, 55, 48,
+	 40, 33, 25, 17, 9, 1, -6, -14,
+	 -22, -29, -37, -45, -52, -59, -66, -73,
+	 -79, -85, -91, -96, -101, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -104, -99, -94, -88, -82, -76, -70, -63,
+	 -56, -49, -41, -33, -26, -18, -10, -2,
+	 5, 13, 21, 29, 36, 44, 51, 58,
+	 65, 72, 78, 84, 90, 96, 101, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 108, 104, 99, 94, 89, 83, 77,
+	 70, 64, 57, 49, 42, 34, 27, 19,
+	 11, 3, -4, -12, -20, -28, -35, -43,
+	 -50, -58, -64, -71, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -123,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -113, -109, -105, -100, -95, -89,
+	 -84, -77, -71, -64, -57, -50, -43, -35,
+	 -27, -20, -12, -4, 3, 11, 19, 27,
+	 35, 42, 50, 57, 64, 70, 77, 83,
+	 89, 94, 99, 104, 108, 112, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 72, 65, 58, 51,
+	 44, 36, 28, 21, 13, 5, -2, -10,
+	 -18, -26, -34, -41, -49, -56, -63, -70,
+	 -76, -82, -88, -94, -99, -104, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -113, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -59, -52, -44, -37, -29, -21, -14, -6,
+	 1, 9, 17, 25, 33, 40, 48, 55,
+	 62, 69, 75, 82, 88, 93, 98, 103,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 102, 97, 91, 86, 80,
+	 73, 67, 60, 53, 45, 38, 30, 22,
+	 14, 7, 0, -8, -16, -24, -32, -40,
+	 -47, -54, -61, -68, -75, -81, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -120, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -111, -107, -102, -97, -92,
+	 -86, -80, -74, -67, -61, -53, -46, -39,
+	 -31, -23, -15, -7, 0, 7, 15, 23,
+	 31, 39, 46, 53, 61, 67, 74, 80,
+	 86, 92, 97, 102, 107, 111, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 120, 118, 115, 111, 107, 103,
+	 98, 93, 87, 81, 75, 68, 61, 54,
+	 47, 40, 32, 24, 16, 8, 0, -7,
+	 -14, -22, -30, -38, -45, -53, -60, -67,
+	 -73, -80, -86, -91, -97, -102, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -103, -98, -93, -88, -82, -75, -69,
+	 -62, -55, -48, -40, -33, -25, -17, -9,
+	 -1, 6, 14, 21, 29, 37, 44, 52,
+	 59, 66, 73, 79, 85, 91, 96, 101,
+	 106, 110, 113, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 104, 99, 94, 88, 82,
+	 76, 70, 63, 56, 49, 41, 34, 26,
+	 18, 10, 2, -5, -13, -21, -28, -36,
+	 -44, -51, -58, -65, -72, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -112, -108, -104, -99, -94,
+	 -89, -83, -77, -70, -64, -57, -50, -42,
+	 -35, -27, -19, -11, -3, 4, 12, 20,
+	 27, 35, 43, 50, 57, 64, 71, 77,
+	 84, 89, 95, 100, 105, 109, 113, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 123, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 71, 64, 58,
+	 50, 43, 35, 28, 20, 12, 4, -3,
+	 -11, -19, -27, -34, -42, -49, -57, -64,
+	 -70, -77, -83, -89, -94, -99, -104, -108,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -101, -96, -90, -84, -78, -72,
+	 -65, -58, -51, -44, -36, -29, -21, -13,
+	 -5, 2, 10, 18, 26, 33, 41, 48,
+	 56, 63, 70, 76, 82, 88, 94, 99,
+	 104, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 101, 96, 91, 85,
+	 79, 73, 66, 59, 52, 45, 37, 29,
+	 22, 14, 6, -1, -9, -17, -25, -33,
+	 -40, -48, -55, -62, -69, -75, -82, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -121,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -102, -97,
+	 -91, -86, -80, -73, -67, -60, -53, -45,
+	 -38, -30, -23, -15, -7, 0, 8, 16,
+	 24, 32, 39, 47, 54, 61, 68, 75,
+	 81, 87, 92, 98, 103, 107, 111, 115,
+	 118, 120, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 118, 114, 111, 107,
+	 102, 97, 92, 86, 80, 74, 68, 61,
+	 54, 46, 39, 31, 23, 16, 8, 0,
+	 -7, -15, -23, -31, -38, -46, -53, -60,
+	 -67, -74, -80, -86, -92, -97, -102, -106,
+	 -111, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -121, -118, -115,
+	 -111, -107, -103, -98, -93, -87, -81, -75,
+	 -68, -62, -54, -47, -40, -32, -24, -16,
+	 -9, -1, 6, 14, 22, 30, 38, 45,
+	 52, 60, 66, 73, 79, 85, 91, 97,
+	 101, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 103, 98, 93, 88,
+	 82, 76, 69, 62, 55, 48, 41, 33,
+	 25, 17, 9, 1, -5, -13, -21, -29,
+	 -37, -44, -52, -59, -66, -72, -79, -85,
+	 -91, -96, -101, -105, -110, -113, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -104, -99,
+	 -94, -88, -83, -76, -70, -63, -56, -49,
+	 -41, -34, -26, -18, -10, -2, 5, 13,
+	 20, 28, 36, 43, 51, 58, 65, 72,
+	 78, 84, 90, 95, 100, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 109,
+	 104, 100, 94, 89, 83, 77, 71, 64,
+	 57, 50, 42, 35, 27, 19, 11, 3,
+	 -4, -12, -20, -27, -35, -43, -50, -57,
+	 -64, -71, -77, -83, -89, -95, -100, -104,
+	 -109, -113, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -100, -95, -90, -84, -78,
+	 -71, -65, -58, -51, -43, -36, -28, -20,
+	 -12, -4, 3, 11, 19, 26, 34, 42,
+	 49, 56, 63, 70, 77, 83, 89, 94,
+	 99, 104, 108, 112, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 117, 113, 109, 105, 101, 96, 90,
+	 85, 78, 72, 65, 58, 51, 44, 36,
+	 29, 21, 13, 5, -2, -10, -18, -26,
+	 -33, -41, -48, -56, -63, -69, -76, -82,
+	 -88, -94, -99, -103, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -66, -59, -52,
+	 -45, -37, -30, -22, -14, -6, 1, 9,
+	 17, 25, 32, 40, 47, 55, 62, 69,
+	 75, 81, 87, 93, 98, 103, 107, 111,
+	 115, 118, 121, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 102, 97, 92, 86, 80, 74, 67,
+	 60, 53, 46, 38, 30, 23, 15, 7,
+	 0, -8, -16, -24, -32, -39, -47, -54,
+	 -61, -68, -74, -81, -87, -92, -98, -102,
+	 -107, -111, -115, -118, -120, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -123, -120, -118,
+	 -114, -111, -107, -102, -97, -92, -87, -81,
+	 -74, -68, -61, -54, -46, -39, -31, -24,
+	 -16, -8, 0, 7, 15, 23, 31, 38,
+	 46, 53, 60, 67, 74, 80, 86, 92,
+	 97, 102, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 121, 118, 115, 111, 107, 103, 98, 93,
+	 87, 81, 75, 68, 62, 55, 47, 40,
+	 32, 24, 17, 9, 1, -6, -14, -22,
+	 -30, -37, -45, -52, -59, -66, -73, -79,
+	 -85, -91, -96, -101, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -103,
+	 -99, -93, -88, -82, -76, -69, -62, -55,
+	 -48, -41, -33, -25, -18, -10, -2, 5,
+	 13, 21, 29, 37, 44, 51, 59, 66,
+	 72, 79, 85, 90, 96, 101, 105, 110,
+	 113, 117, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 108, 104, 99, 94, 88, 83, 76, 70,
+	 63, 56, 49, 42, 34, 26, 18, 11,
+	 3, -4, -12, -20, -28, -36, -43, -51,
+	 -58, -65, -72, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -113, -109, -104, -100, -95, -89, -83,
+	 -77, -71, -64, -57, -50, -42, -35, -27,
+	 -19, -11, -3, 3, 11, 19, 27, 35,
+	 42, 50, 57, 64, 71, 77, 83, 89,
+	 95, 100, 104, 109, 113, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 71, 65, 58, 51, 43,
+	 36, 28, 20, 12, 4, -3, -11, -18,
+	 -26, -34, -42, -49, -56, -63, -70, -76,
+	 -83, -89, -94, -99, -104, -108, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -117, -113, -110, -105,
+	 -101, -96, -90, -85, -79, -72, -66, -59,
+	 -51, -44, -37, -29, -21, -13, -5, 2,
+	 10, 18, 25, 33, 41, 48, 55, 62,
+	 69, 76, 82, 88, 93, 99, 103, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 101, 96, 91, 85, 79, 73,
+	 66, 59, 52, 45, 37, 30, 22, 14,
+	 6, -1, -9, -17, -24, -32, -40, -47,
+	 -55, -62, -68, -75, -81, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -121, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -102, -97, -92, -86,
+	 -80, -74, -67, -60, -53, -46, -38, -31,
+	 -23, -15, -7, 0, 8, 16, 24, 31,
+	 39, 46, 54, 61, 68, 74, 81, 87,
+	 92, 97, 102, 107, 111, 114, 118, 120,
+	 123, 124, 125, 126, 126, 126, 126, 124,
+	 123, 120, 118, 115, 111, 107, 102, 98,
+	 92, 87, 81, 74, 68, 61, 54, 47,
+	 39, 32, 24, 16, 8, 0, -7, -15,
+	 -23, -30, -38, -46, -53, -60, -67, -74,
+	 -80, -86, -92, -97, -102, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -121, -118, -115, -111, -107,
+	 -103, -98, -93, -87, -81, -75, -69, -62,
+	 -55, -47, -40, -32, -25, -17, -9, -1,
+	 6, 14, 22, 30, 37, 45, 52, 59,
+	 66, 73, 79, 85, 91, 96, 101, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 103, 99, 94, 88, 82, 76,
+	 69, 63, 56, 48, 41, 33, 26, 18,
+	 10, 2, -5, -13, -21, -29, -36, -44,
+	 -51, -58, -65, -72, -78, -85, -90, -96,
+	 -101, -105, -109, -113, -117, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -112, -108, -104, -99, -94, -89,
+	 -83, -77, -70, -63, -56, -49, -42, -34,
+	 -26, -19, -11, -3, 4, 12, 20, 28,
+	 36, 43, 50, 58, 65, 71, 78, 84,
+	 90, 95, 100, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 113, 109, 104, 100,
+	 95, 89, 83, 77, 71, 64, 57, 50,
+	 43, 35, 27, 19, 12, 4, -3, -11,
+	 -19, -27, -35, -42, -50, -57, -64, -71,
+	 -77, -83, -89, -94, -100, -104, -109, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -100, -95, -90, -84, -78, -72, -65,
+	 -58, -51, -43, -36, -28, -20, -13, -5,
+	 2, 10, 18, 26, 34, 41, 49, 56,
+	 63, 70, 76, 83, 88, 94, 99, 104,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 113, 110, 105, 101, 96, 91, 85, 79,
+	 72, 66, 59, 52, 44, 37, 29, 21,
+	 13, 5, -1, -9, -17, -25, -33, -41,
+	 -48, -55, -62, -69, -76, -82, -88, -93,
+	 -98, -103, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -60, -52, -45, -38,
+	 -30, -22, -14, -6, 1, 9, 16, 24,
+	 32, 40, 47, 54, 61, 68, 75, 81,
+	 87, 93, 98, 103, 107, 111, 115, 118,
+	 121, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 111, 106, 102,
+	 97, 92, 86, 80, 74, 67, 60, 53,
+	 46, 38, 31, 23, 15, 7, 0, -8,
+	 -16, -23, -31, -39, -46, -54, -61, -68,
+	 -74, -80, -86, -92, -97, -102, -107, -111,
+	 -114, -118, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -120, -118, -115, -111,
+	 -107, -103, -98, -92, -87, -81, -75, -68,
+	 -61, -54, -47, -39, -32, -24, -16, -8,
+	 0, 7, 15, 23, 30, 38, 45, 53,
+	 60, 67, 73, 80, 86, 91, 97, 102,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 121, 118,
+	 115, 111, 107, 103, 98, 93, 87, 82,
+	 75, 69, 62, 55, 48, 40, 33, 25,
+	 17, 9, 1, -6, -14, -22, -29, -37,
+	 -45, -52, -59, -66, -73, -79, -85, -91,
+	 -96, -101, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -104, -99, -94,
+	 -88, -82, -76, -70, -63, -56, -48, -41,
+	 -33, -26, -18, -10, -2, 5, 13, 21,
+	 29, 36, 44, 51, 58, 65, 72, 78,
+	 84, 90, 96, 101, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 108, 104,
+	 99, 94, 89, 83, 77, 70, 64, 57,
+	 49, 42, 34, 27, 19, 11, 3, -4,
+	 -12, -20, -28, -35, -43, -50, -58, -64,
+	 -71, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -123, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -113,
+	 -109, -105, -100, -95, -89, -84, -77, -71,
+	 -64, -57, -50, -43, -35, -27, -20, -12,
+	 -4, 3, 11, 19, 27, 35, 42, 49,
+	 57, 64, 70, 77, 83, 89, 94, 99,
+	 104, 108, 112, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 72, 65, 58, 51, 44, 36, 28,
+	 21, 13, 5, -2, -10, -18, -26, -34,
+	 -41, -49, -56, -63, -70, -76, -82, -88,
+	 -94, -99, -104, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -113, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -66, -59, -52, -44,
+	 -37, -29, -21, -14, -6, 1, 9, 17,
+	 25, 33, 40, 48, 55, 62, 69, 75,
+	 82, 88, 93, 98, 103, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 102, 97, 91, 86, 80, 73, 67, 60,
+	 53, 45, 38, 30, 22, 14, 7, 0,
+	 -8, -16, -24, -32, -40, -47, -54, -61,
+	 -68, -75, -81, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -120, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -111, -107, -102, -97, -92, -86, -80, -74,
+	 -67, -61, -53, -46, -39, -31, -23, -15,
+	 -7, 0, 7, 15, 23, 31, 39, 46,
+	 53, 61, 67, 74, 80, 86, 92, 97,
+	 102, 107, 111, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 120,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 81, 75, 68, 61, 54, 47, 40, 32,
+	 24, 16, 8, 0, -7, -14, -22, -30,
+	 -38, -45, -53, -60, -67, -73, -80, -86,
+	 -91, -97, -102, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -111, -108, -103, -98,
+	 -93, -88, -82, -75, -69, -62, -55, -48,
+	 -40, -33, -25, -17, -9, -1, 6, 14,
+	 21, 29, 37, 44, 52, 59, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 113,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 104, 99, 94, 88, 82, 76, 70, 63,
+	 56, 49, 41, 34, 26, 18, 10, 2,
+	 -5, -13, -21, -28, -36, -44, -51, -58,
+	 -65, -72, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -112, -108, -104, -99, -94, -89, -83, -77,
+	 -70, -64, -57, -49, -42, -34, -27, -19,
+	 -11, -3, 4, 12, 20, 27, 35, 43,
+	 50, 57, 64, 71, 77, 84, 89, 95,
+	 100, 105, 109, 113, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 123, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 71, 64, 58, 50, 43, 35,
+	 28, 20, 12, 4, -3, -11, -19, -27,
+	 -34, -42, -49, -57, -64, -70, -77, -83,
+	 -89, -94, -99, -104, -108, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -101,
+	 -96, -90, -84, -78, -72, -65, -58, -51,
+	 -44, -36, -29, -21, -13, -5, 2, 10,
+	 18, 26, 33, 41, 49, 56, 63, 70,
+	 76, 82, 88, 94, 99, 104, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 101, 96, 91, 85, 79, 73, 66,
+	 59, 52, 45, 37, 29, 22, 14, 6,
+	 -1, -9, -17, -25, -33, -40, -48, -55,
+	 -62, -69, -75, -82, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -121, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -102, -97, -91, -86, -80,
+	 -73, -67, -60, -53, -45, -38, -30, -23,
+	 -15, -7, 0, 8, 16, 24, 32, 39,
+	 47, 54, 61, 68, 75, 81, 87, 92,
+	 98, 103, 107, 111, 115, 118, 120, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 118, 114, 111, 107, 102, 97, 92,
+	 86, 80, 74, 68, 61, 54, 46, 39,
+	 31, 23, 16, 8, 0, -7, -15, -23,
+	 -31, -38, -46, -53, -60, -67, -74, -80,
+	 -86, -92, -97, -102, -106, -111, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -121, -118, -115, -111, -107, -103,
+	 -98, -93, -87, -81, -75, -68, -61, -54,
+	 -47, -40, -32, -24, -16, -9, -1, 6,
+	 14, 22, 30, 38, 45, 52, 60, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 103, 98, 93, 88, 82, 76, 69,
+	 62, 55, 48, 41, 33, 25, 17, 9,
+	 1, -5, -13, -21, -29, -37, -44, -52,
+	 -59, -66, -72, -79, -85, -91, -96, -101,
+	 -105, -110, -113, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -104, -99, -94, -88, -83,
+	 -76, -70, -63, -56, -49, -41, -34, -26,
+	 -18, -10, -2, 5, 13, 20, 28, 36,
+	 43, 51, 58, 65, 72, 78, 84, 90,
+	 95, 100, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 109, 104, 100, 94,
+	 89, 83, 77, 71, 64, 57, 50, 42,
+	 35, 27, 19, 11, 3, -4, -12, -20,
+	 -27, -35, -43, -50, -57, -64, -71, -77,
+	 -83, -89, -95, -100, -104, -109, -113, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -100, -95, -90, -84, -78, -71, -65, -58,
+	 -50, -43, -36, -28, -20, -12, -4, 3,
+	 11, 19, 26, 34, 42, 49, 56, 63,
+	 70, 77, 83, 89, 94, 99, 104, 108,
+	 112, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 117, 113,
+	 109, 105, 101, 96, 90, 85, 78, 72,
+	 65, 58, 51, 44, 36, 29, 21, 13,
+	 5, -2, -10, -18, -26, -33, -41, -48,
+	 -56, -63, -69, -76, -82, -88, -94, -99,
+	 -103, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -59, -52, -45, -37, -30,
+	 -22, -14, -6, 1, 9, 17, 25, 32,
+	 40, 47, 55, 62, 69, 75, 81, 87,
+	 93, 98, 103, 107, 111, 115, 118, 121,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 102, 97,
+	 92, 86, 80, 74, 67, 60, 53, 46,
+	 38, 30, 23, 15, 7, 0, -8, -16,
+	 -24, -32, -39, -47, -54, -61, -68, -74,
+	 -81, -87, -92, -98, -102, -107, -111, -115,
+	 -118, -120, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -123, -120, -118, -114, -111, -107,
+	 -102, -97, -92, -87, -81, -74, -68, -61,
+	 -54, -46, -39, -31, -24, -16, -8, 0,
+	 7, 15, 23, 31, 38, 46, 53, 60,
+	 67, 74, 80, 86, 92, 97, 102, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 121, 118, 115,
+	 111, 107, 103, 98, 93, 87, 81, 75,
+	 68, 62, 55, 47, 40, 32, 24, 17,
+	 9, 1, -6, -14, -22, -30, -37, -45,
+	 -52, -59, -66, -73, -79, -85, -91, -96,
+	 -101, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -103, -99, -93, -88,
+	 -82, -76, -69, -62, -55, -48, -41, -33,
+	 -25, -18, -10, -2, 5, 13, 21, 29,
+	 37, 44, 51, 59, 66, 72, 79, 85,
+	 90, 96, 101, 105, 110, 113, 117, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 108, 104, 99,
+	 94, 88, 83, 76, 70, 63, 56, 49,
+	 42, 34, 26, 18, 11, 3, -4, -12,
+	 -20, -28, -36, -43, -51, -58, -65, -71,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -113, -109,
+	 -104, -100, -95, -89, -83, -77, -71, -64,
+	 -57, -50, -42, -35, -27, -19, -11, -3,
+	 3, 11, 19, 27, 35, 42, 50, 57,
+	 64, 71, 77, 83, 89, 95, 100, 104,
+	 109, 113, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 71, 65, 58, 51, 43, 36, 28, 20,
+	 12, 4, -3, -11, -18, -26, -34, -42,
+	 -49, -56, -63, -70, -76, -83, -89, -94,
+	 -99, -104, -108, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -117, -113, -110, -105, -101, -96, -90,
+	 -85, -79, -72, -66, -59, -51, -44, -37,
+	 -29, -21, -13, -5, 2, 10, 18, 25,
+	 33, 41, 48, 55, 62, 69, 76, 82,
+	 88, 93, 99, 103, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 101,
+	 96, 91, 85, 79, 73, 66, 59, 52,
+	 45, 37, 30, 22, 14, 6, -1, -9,
+	 -17, -24, -32, -40, -47, -55, -62, -68,
+	 -75, -81, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -121, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -102, -97, -92, -86, -80, -74, -67,
+	 -60, -53, -46, -38, -31, -23, -15, -7,
+	 0, 8, 16, 24, 31, 39, 46, 54,
+	 61, 68, 74, 81, 87, 92, 97, 102,
+	 107, 111, 114, 118, 120, 123, 124, 125,
+	 126, 126, 126, 126, 124, 123, 120, 118,
+	 115, 111, 107, 102, 98, 92, 87, 81,
+	 74, 68, 61, 54, 47, 39, 32, 24,
+	 16, 8, 0, -7, -15, -23, -30, -38,
+	 -46, -53, -60, -67, -74, -80, -86, -92,
+	 -97, -102, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -121, -118, -115, -111, -107, -103, -98, -93,
+	 -87, -81, -75, -69, -62, -55, -47, -40,
+	 -32, -25, -17, -9, -1, 6, 14, 22,
+	 30, 37, 45, 52, 59, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 103,
+	 99, 94, 88, 82, 76, 69, 63, 56,
+	 48, 41, 33, 26, 18, 10, 2, -5,
+	 -13, -21, -29, -36, -44, -51, -58, -65,
+	 -72, -78, -85, -90, -96, -101, -105, -109,
+	 -113, -117, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -112,
+	 -108, -104, -99, -94, -89, -83, -77, -70,
+	 -63, -56, -49, -42, -34, -26, -19, -11,
+	 -3, 4, 12, 20, 28, 36, 43, 51,
+	 58, 65, 71, 78, 84, 90, 95, 100,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 113, 109, 104, 100, 95, 89, 83,
+	 77, 71, 64, 57, 50, 43, 35, 27,
+	 20, 12, 4, -3, -11, -19, -27, -35,
+	 -42, -50, -57, -64, -71, -77, -83, -89,
+	 -94, -100, -104, -109, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -100, -95,
+	 -90, -84, -78, -72, -65, -58, -51, -43,
+	 -36, -28, -20, -13, -5, 2, 10, 18,
+	 26, 34, 41, 49, 56, 63, 70, 76,
+	 83, 88, 94, 99, 104, 108, 112, 115,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 113, 110, 105,
+	 101, 96, 91, 85, 79, 72, 66, 59,
+	 52, 44, 37, 29, 21, 13, 5, -1,
+	 -9, -17, -25, -33, -41, -48, -55, -62,
+	 -69, -76, -82, -88, -93, -98, -103, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -60, -52, -45, -38, -30, -22, -14,
+	 -6, 1, 9, 16, 24, 32, 40, 47,
+	 54, 62, 68, 75, 81, 87, 93, 98,
+	 103, 107, 111, 115, 118, 121, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 111, 106, 102, 97, 92, 86,
+	 80, 74, 67, 60, 53, 46, 38, 31,
+	 23, 15, 7, 0, -8, -16, -23, -31,
+	 -39, -46, -54, -61, -68, -74, -80, -86,
+	 -92, -97, -102, -107, -111, -114, -118, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -120, -118, -115, -111, -107, -103, -98,
+	 -92, -87, -81, -75, -68, -61, -54, -47,
+	 -39, -32, -24, -16, -8, 0, 7, 15,
+	 23, 30, 38, 45, 53, 60, 67, 73,
+	 80, 86, 91, 97, 102, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 121, 118, 115, 111, 107,
+	 103, 98, 93, 87, 82, 75, 69, 62,
+	 55, 48, 40, 33, 25, 17, 9, 1,
+	 -6, -14, -22, -29, -37, -45, -52, -59,
+	 -66, -73, -79, -85, -91, -96, -101, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -104, -99, -94, -88, -82, -76,
+	 -70, -63, -56, -48, -41, -33, -26, -18,
+	 -10, -2, 5, 13, 21, 29, 36, 44,
+	 51, 58, 65, 72, 78, 84, 90, 96,
+	 101, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 108, 104, 99, 94, 89,
+	 83, 77, 70, 64, 57, 49, 42, 34,
+	 27, 19, 11, 3, -4, -12, -20, -28,
+	 -35, -43, -50, -58, -64, -71, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -123, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -113, -109, -105, -100,
+	 -95, -89, -84, -77, -71, -64, -57, -50,
+	 -43, -35, -27, -20, -12, -4, 3, 11,
+	 19, 27, 35, 42, 50, 57, 64, 70,
+	 77, 83, 89, 94, 99, 104, 108, 112,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 72, 65,
+	 58, 51, 44, 36, 28, 21, 13, 5,
+	 -2, -10, -18, -26, -34, -41, -49, -56,
+	 -63, -70, -76, -82, -88, -94, -99, -104,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -113, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -59, -52, -44, -37, -29, -21,
+	 -14, -6, 1, 9, 17, 25, 33, 40,
+	 48, 55, 62, 69, 75, 82, 88, 93,
+	 98, 103, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 102, 97, 91,
+	 86, 80, 73, 67, 60, 53, 45, 38,
+	 30, 22, 14, 7, 0, -8, -16, -24,
+	 -32, -40, -47, -54, -61, -68, -75, -81,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -120, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -111, -107, -102,
+	 -97, -92, -86, -80, -74, -67, -61, -53,
+	 -46, -39, -31, -23, -15, -7, 0, 7,
+	 15, 23, 31, 39, 46, 53, 61, 67,
+	 74, 80, 86, 92, 97, 102, 107, 111,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 120, 118, 115, 111,
+	 107, 103, 98, 93, 87, 81, 75, 68,
+	 61, 54, 47, 40, 32, 24, 16, 8,
+	 0, -7, -14, -22, -30, -38, -45, -53,
+	 -60, -67, -73, -80, -86, -91, -97, -102,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -103, -98, -93, -88, -82,
+	 -75, -69, -62, -55, -48, -40, -33, -25,
+	 -17, -9, -1, 6, 14, 21, 29, 37,
+	 44, 52, 59, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 113, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 104, 99, 94,
+	 88, 82, 76, 70, 63, 56, 49, 41,
+	 34, 26, 18, 10, 2, -5, -13, -21,
+	 -28, -36, -44, -51, -58, -65, -72, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -112, -108, -104,
+	 -99, -94, -89, -83, -77, -70, -64, -57,
+	 -49, -42, -35, -27, -19, -11, -3, 4,
+	 12, 20, 28, 35, 43, 50, 57, 64,
+	 71, 77, 84, 89, 95, 100, 105, 109,
+	 113, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 123, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 71,
+	 64, 58, 50, 43, 35, 28, 20, 12,
+	 4, -3, -11, -19, -27, -34, -42, -49,
+	 -57, -64, -70, -77, -83, -89, -94, -99,
+	 -104, -108, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -101, -96, -90, -84,
+	 -78, -72, -65, -58, -51, -44, -36, -29,
+	 -21, -13, -5, 2, 10, 18, 26, 33,
+	 41, 49, 56, 63, 70, 76, 82, 88,
+	 94, 99, 104, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 101, 96,
+	 91, 85, 79, 73, 66, 59, 52, 45,
+	 37, 29, 22, 14, 6, -1, -9, -17,
+	 -25, -33, -40, -48, -55, -62, -69, -75,
+	 -82, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -121, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -102, -97, -91, -86, -80, -73, -67, -60,
+	 -53, -45, -38, -30, -23, -15, -7, 0,
+	 8, 16, 24, 32, 39, 47, 54, 61,
+	 68, 75, 81, 87, 92, 98, 103, 107,
+	 111, 115, 118, 120, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 118, 114,
+	 111, 107, 102, 97, 92, 86, 80, 74,
+	 68, 61, 54, 46, 39, 31, 23, 16,
+	 8, 0, -7, -15, -23, -31, -38, -46,
+	 -53, -60, -67, -74, -80, -86, -92, -97,
+	 -102, -106, -111, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -121,
+	 -118, -115, -111, -107, -103, -98, -93, -87,
+	 -81, -75, -68, -62, -54, -47, -40, -32,
+	 -24, -16, -9, -1, 6, 14, 22, 30,
+	 38, 45, 52, 60, 67, 73, 79, 85,
+	 91, 97, 101, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 103, 98,
+	 93, 88, 82, 76, 69, 62, 55, 48,
+	 41, 33, 25, 17, 9, 1, -5, -13,
+	 -21, -29, -37, -44, -52, -59, -66, -72,
+	 -79, -85, -91, -96, -101, -105, -110, -113,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -104, -99, -94, -88, -83, -76, -70, -63,
+	 -56, -49, -41, -34, -26, -18, -10, -2,
+	 5, 13, 20, 28, 36, 43, 51, 58,
+	 65, 72, 78, 84, 90, 95, 100, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 109, 104, 100, 94, 89, 83, 77,
+	 71, 64, 57, 50, 42, 35, 27, 19,
+	 11, 3, -4, -12, -19, -27, -35, -43,
+	 -50, -57, -64, -71, -77, -83, -89, -95,
+	 -100, -104, -109, -113, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -100, -95, -90,
+	 -84, -78, -71, -65, -58, -50, -43, -36,
+	 -28, -20, -12, -4, 3, 11, 19, 26,
+	 34, 42, 49, 56, 63, 70, 77, 83,
+	 89, 94, 99, 104, 108, 112, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 117, 113, 109, 105, 101,
+	 96, 90, 85, 78, 72, 65, 58, 51,
+	 44, 36, 29, 21, 13, 5, -2, -10,
+	 -18, -26, -33, -41, -48, -56, -63, -69,
+	 -76, -82, -88, -94, -99, -103, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -59, -52, -45, -37, -30, -22, -14, -6,
+	 1, 9, 17, 25, 32, 40, 48, 55,
+	 62, 69, 75, 81, 87, 93, 98, 103,
+	 107, 111, 115, 118, 121, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 102, 97, 92, 86, 80,
+	 74, 67, 60, 53, 46, 38, 30, 23,
+	 15, 7, 0, -8, -16, -24, -32, -39,
+	 -47, -54, -61, -68, -74, -81, -87, -92,
+	 -98, -102, -107, -111, -115, -118, -120, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -123,
+	 -120, -118, -114, -111, -107, -102, -97, -92,
+	 -87, -81, -74, -68, -61, -54, -46, -39,
+	 -31, -24, -16, -8, 0, 7, 15, 23,
+	 31, 38, 46, 53, 60, 67, 74, 80,
+	 86, 92, 97, 102, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 121, 118, 115, 111, 107, 103,
+	 98, 93, 87, 81, 75, 68, 62, 55,
+	 47, 40, 32, 24, 17, 9, 1, -6,
+	 -14, -22, -30, -37, -45, -52, -59, -66,
+	 -73, -79, -85, -91, -96, -101, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -103, -99, -93, -88, -82, -76, -69,
+	 -62, -55, -48, -41, -33, -25, -18, -10,
+	 -2, 5, 13, 21, 29, 37, 44, 51,
+	 59, 66, 72, 79, 85, 90, 96, 101,
+	 105, 110, 113, 117, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 108, 104, 99, 94, 89, 83,
+	 76, 70, 63, 56, 49, 42, 34, 26,
+	 18, 11, 3, -4, -12, -20, -28, -36,
+	 -43, -51, -58, -65, -71, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -113, -109, -104, -100, -95,
+	 -89, -83, -77, -71, -64, -57, -50, -42,
+	 -35, -27, -19, -11, -3, 3, 11, 19,
+	 27, 35, 42, 50, 57, 64, 71, 77,
+	 83, 89, 95, 100, 104, 109, 113, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 71, 65, 58,
+	 51, 43, 36, 28, 20, 12, 4, -3,
+	 -11, -18, -26, -34, -42, -49, -56, -63,
+	 -70, -76, -83, -88, -94, -99, -104, -108,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -117, -113,
+	 -110, -105, -101, -96, -90, -85, -79, -72,
+	 -66, -59, -51, -44, -37, -29, -21, -13,
+	 -5, 2, 10, 18, 25, 33, 41, 48,
+	 55, 62, 69, 76, 82, 88, 93, 99,
+	 103, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 101, 96, 91, 85,
+	 79, 73, 66, 59, 52, 45, 37, 30,
+	 22, 14, 6, -1, -9, -17, -24, -32,
+	 -40, -47, -55, -62, -68, -75, -81, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -121,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -102, -97,
+	 -92, -86, -80, -74, -67, -60, -53, -46,
+	 -38, -31, -23, -15, -7, 0, 8, 16,
+	 24, 31, 39, 46, 54, 61, 68, 74,
+	 81, 87, 92, 97, 102, 107, 111, 114,
+	 118, 120, 123, 124, 125, 126, 126, 126,
+	 126, 124, 123, 120, 118, 115, 111, 107,
+	 102, 98, 92, 87, 81, 74, 68, 61,
+	 54, 47, 39, 32, 24, 16, 8, 0,
+	 -7, -15, -23, -30, -38, -46, -53, -60,
+	 -67, -74, -80, -86, -92, -97, -102, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -121, -118, -115,
+	 -111, -107, -103, -98, -93, -87, -81, -75,
+	 -69, -62, -55, -47, -40, -32, -25, -17,
+	 -9, -1, 6, 14, 22, 30, 37, 45,
+	 52, 59, 66, 73, 79, 85, 91, 96,
+	 101, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 103, 99, 94, 88,
+	 82, 76, 69, 63, 56, 48, 41, 33,
+	 26, 18, 10, 2, -5, -13, -21, -29,
+	 -36, -44, -51, -58, -65, -72, -78, -85,
+	 -90, -96, -101, -105, -109, -113, -117, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -112, -108, -104, -99,
+	 -94, -89, -83, -77, -70, -63, -56, -49,
+	 -42, -34, -26, -19, -11, -3, 4, 12,
+	 20, 28, 36, 43, 50, 58, 65, 71,
+	 78, 84, 90, 95, 100, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 113, 109,
+	 104, 100, 95, 89, 83, 77, 71, 64,
+	 57, 50, 43, 35, 27, 20, 12, 4,
+	 -3, -11, -19, -27, -35, -42, -50, -57,
+	 -64, -71, -77, -83, -89, -94, -100, -104,
+	 -109, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -100, -95, -90, -84, -78,
+	 -72, -65, -58, -51, -43, -36, -28, -20,
+	 -12, -5, 2, 10, 18, 26, 34, 41,
+	 49, 56, 63, 70, 76, 83, 88, 94,
+	 99, 104, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 113, 110, 105, 101, 96, 91,
+	 85, 79, 72, 66, 59, 52, 44, 37,
+	 29, 21, 13, 5, -2, -9, -17, -25,
+	 -33, -41, -48, -55, -62, -69, -76, -82,
+	 -88, -93, -98, -103, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -67, -60, -52,
+	 -45, -38, -30, -22, -14, -6, 1, 9,
+	 16, 24, 32, 40, 47, 54, 62, 68,
+	 75, 81, 87, 93, 98, 103, 107, 111,
+	 115, 118, 121, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 111,
+	 106, 102, 97, 92, 86, 80, 74, 67,
+	 60, 53, 46, 38, 31, 23, 15, 7,
+	 0, -8, -16, -23, -31, -39, -46, -54,
+	 -61, -68, -74, -80, -86, -92, -97, -102,
+	 -107, -111, -114, -118, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -120, -118,
+	 -115, -111, -107, -103, -98, -92, -87, -81,
+	 -75, -68, -61, -54, -47, -39, -32, -24,
+	 -16, -8, 0, 7, 15, 23, 30, 38,
+	 45, 53, 60, 67, 73, 80, 86, 91,
+	 97, 102, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 121, 118, 115, 111, 107, 103, 98, 93,
+	 87, 82, 75, 69, 62, 55, 48, 40,
+	 33, 25, 17, 9, 1, -6, -14, -22,
+	 -29, -37, -45, -52, -59, -66, -73, -79,
+	 -85, -91, -96, -101, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -104,
+	 -99, -94, -88, -82, -76, -70, -63, -56,
+	 -48, -41, -33, -26, -18, -10, -2, 5,
+	 13, 21, 29, 36, 44, 51, 58, 65,
+	 72, 78, 84, 90, 96, 101, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 108, 104, 99, 94, 89, 83, 77, 70,
+	 64, 57, 49, 42, 34, 27, 19, 11,
+	 3, -4, -12, -20, -28, -35, -43, -50,
+	 -58, -64, -71, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -123, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -113, -109, -105, -100, -95, -89, -84,
+	 -77, -71, -64, -57, -50, -43, -35, -27,
+	 -20, -12, -4, 3, 11, 19, 27, 35,
+	 42, 50, 57, 64, 70, 77, 83, 89,
+	 94, 99, 104, 108, 112, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 72, 65, 58, 51, 44,
+	 36, 28, 21, 13, 5, -2, -10, -18,
+	 -26, -34, -41, -49, -56, -63, -70, -76,
+	 -82, -88, -94, -99, -104, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -113, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -59,
+	 -52, -44, -37, -29, -21, -14, -6, 1,
+	 9, 17, 25, 33, 40, 48, 55, 62,
+	 69, 75, 82, 88, 93, 98, 103, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 102, 97, 91, 86, 80, 73,
+	 67, 60, 53, 45, 38, 30, 22, 14,
+	 7, 0, -8, -16, -24, -32, -40, -47,
+	 -54, -61, -68, -75, -81, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -120, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -111, -107, -102, -97, -92, -86,
+	 -80, -74, -67, -61, -53, -46, -39, -31,
+	 -23, -15, -7, 0, 7, 15, 23, 31,
+	 39, 46, 53, 61, 67, 74, 80, 86,
+	 92, 97, 102, 107, 111, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 120, 118, 115, 111, 107, 103, 98,
+	 93, 87, 81, 75, 68, 61, 54, 47,
+	 40, 32, 24, 16, 8, 0, -7, -14,
+	 -22, -30, -38, -45, -53, -60, -67, -73,
+	 -80, -86, -91, -97, -102, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -103, -98, -93, -88, -82, -75, -69, -62,
+	 -55, -48, -40, -33, -25, -17, -9, -1,
+	 6, 14, 21, 29, 37, 44, 52, 59,
+	 66, 73, 79, 85, 91, 96, 101, 106,
+	 110, 113, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 104, 99, 94, 88, 82, 76,
+	 70, 63, 56, 49, 41, 34, 26, 18,
+	 10, 2, -5, -13, -21, -28, -36, -44,
+	 -51, -58, -65, -72, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -112, -108, -104, -99, -94, -89,
+	 -83, -77, -70, -64, -57, -50, -42, -35,
+	 -27, -19, -11, -3, 4, 12, 20, 27,
+	 35, 43, 50, 57, 64, 71, 77, 84,
+	 89, 95, 100, 105, 109, 113, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 123, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 71, 64, 58, 50,
+	 43, 35, 28, 20, 12, 4, -3, -11,
+	 -19, -27, -34, -42, -49, -57, -64, -70,
+	 -77, -83, -89, -94, -99, -104, -108, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -101, -96, -90, -84, -78, -72, -65,
+	 -58, -51, -44, -36, -29, -21, -13, -5,
+	 2, 10, 18, 26, 33, 41, 48, 56,
+	 63, 70, 76, 82, 88, 94, 99, 104,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 101, 96, 91, 85, 79,
+	 73, 66, 59, 52, 45, 37, 29, 22,
+	 14, 6, -1, -9, -17, -25, -33, -40,
+	 -48, -55, -62, -69, -75, -82, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -121, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -102, -97, -91,
+	 -86, -80, -73, -67, -60, -53, -45, -38,
+	 -30, -23, -15, -7, 0, 8, 16, 24,
+	 32, 39, 47, 54, 61, 68, 75, 81,
+	 87, 92, 98, 103, 107, 111, 115, 118,
+	 120, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 118, 114, 111, 107, 102,
+	 97, 92, 86, 80, 74, 68, 61, 54,
+	 46, 39, 31, 23, 16, 8, 0, -7,
+	 -15, -23, -31, -38, -46, -53, -60, -67,
+	 -74, -80, -86, -92, -97, -102, -106, -111,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -121, -118, -115, -111,
+	 -107, -103, -98, -93, -87, -81, -75, -68,
+	 -62, -54, -47, -40, -32, -24, -16, -9,
+	 -1, 6, 14, 22, 30, 38, 45, 52,
+	 60, 67, 73, 79, 85, 91, 97, 101,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 103, 98, 93, 88, 82,
+	 76, 69, 62, 55, 48, 41, 33, 25,
+	 17, 9, 1, -5, -13, -21, -29, -37,
+	 -44, -52, -59, -66, -72, -79, -85, -91,
+	 -96, -101, -105, -110, -113, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -115, -112, -108, -104, -99, -94,
+	 -88, -83, -76, -70, -63, -56, -49, -41,
+	 -34, -26, -18, -10, -2, 5, 12, 20,
+	 28, 36, 43, 51, 58, 65, 72, 78,
+	 84, 90, 95, 100, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 109, 104,
+	 100, 94, 89, 83, 77, 71, 64, 57,
+	 50, 42, 35, 27, 19, 11, 3, -4,
+	 -12, -19, -27, -35, -43, -50, -57, -64,
+	 -71, -77, -83, -89, -95, -100, -104, -109,
+	 -113, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -100, -95, -90, -84, -78, -71,
+	 -65, -58, -50, -43, -36, -28, -20, -12,
+	 -4, 3, 11, 19, 26, 34, 42, 49,
+	 56, 63, 70, 77, 83, 89, 94, 99,
+	 104, 108, 112, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 117, 113, 109, 105, 101, 96, 90, 85,
+	 78, 72, 65, 58, 51, 44, 36, 29,
+	 21, 13, 5, -2, -10, -18, -26, -33,
+	 -41, -48, -56, -63, -69, -76, -82, -88,
+	 -94, -99, -103, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -66, -59, -52, -45,
+	 -37, -30, -22, -14, -6, 1, 9, 17,
+	 25, 32, 40, 48, 55, 62, 69, 75,
+	 81, 87, 93, 98, 103, 107, 111, 115,
+	 118, 121, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 102, 97, 92, 86, 80, 74, 67, 60,
+	 53, 46, 38, 30, 23, 15, 7, 0,
+	 -8, -16, -24, -32, -39, -47, -54, -61,
+	 -68, -74, -81, -87, -92, -98, -102, -107,
+	 -111, -115, -118, -120, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -123, -120, -118, -114,
+	 -111, -107, -102, -97, -92, -87, -81, -74,
+	 -68, -61, -54, -46, -39, -31, -24, -16,
+	 -8, 0, 7, 15, 23, 31, 38, 46,
+	 53, 60, 67, 74, 80, 86, 92, 97,
+	 102, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 121,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 81, 75, 68, 62, 55, 47, 40, 32,
+	 24, 17, 9, 1, -6, -14, -22, -30,
+	 -37, -45, -52, -59, -66, -73, -79, -85,
+	 -91, -96, -101, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -103, -99,
+	 -93, -88, -82, -76, -69, -62, -55, -48,
+	 -41, -33, -25, -18, -10, -2, 5, 13,
+	 21, 29, 37, 44, 51, 59, 66, 72,
+	 79, 85, 90, 96, 101, 105, 110, 113,
+	 117, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 108,
+	 104, 99, 94, 88, 83, 76, 70, 63,
+	 56, 49, 42, 34, 26, 18, 11, 3,
+	 -4, -12, -20, -28, -36, -43, -51, -58,
+	 -65, -71, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -113, -109, -104, -100, -95, -89, -83, -77,
+	 -71, -64, -57, -50, -42, -35, -27, -19,
+	 -11, -4, 3, 11, 19, 27, 35, 42,
+	 50, 57, 64, 71, 77, 83, 89, 95,
+	 100, 104, 109, 113, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 71, 65, 58, 51, 43, 36,
+	 28, 20, 12, 4, -3, -11, -18, -26,
+	 -34, -42, -49, -56, -63, -70, -76, -83,
+	 -89, -94, -99, -104, -108, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -117, -113, -110, -105, -101,
+	 -96, -90, -85, -79, -72, -66, -59, -51,
+	 -44, -37, -29, -21, -13, -5, 2, 10,
+	 18, 25, 33, 41, 48, 55, 62, 69,
+	 76, 82, 88, 93, 99, 103, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 101, 96, 91, 85, 79, 73, 66,
+	 59, 52, 45, 37, 30, 22, 14, 6,
+	 -1, -9, -17, -24, -32, -40, -47, -55,
+	 -62, -68, -75, -81, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -121, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -102, -97, -92, -86, -80,
+	 -74, -67, -60, -53, -46, -38, -31, -23,
+	 -15, -7, 0, 8, 16, 24, 31, 39,
+	 47, 54, 61, 68, 74, 81, 87, 92,
+	 97, 102, 107, 111, 114, 118, 120, 123,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 120, 118, 115, 111, 107, 102, 98, 92,
+	 87, 81, 74, 68, 61, 54, 47, 39,
+	 32, 24, 16, 8, 0, -7, -15, -23,
+	 -31, -38, -46, -53, -60, -67, -74, -80,
+	 -86, -92, -97, -102, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -121, -118, -115, -111, -107, -103,
+	 -98, -93, -87, -81, -75, -69, -62, -55,
+	 -47, -40, -32, -25, -17, -9, -1, 6,
+	 14, 22, 30, 37, 45, 52, 59, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 103, 99, 94, 88, 82, 76, 69,
+	 63, 56, 48, 41, 33, 26, 18, 10,
+	 2, -5, -13, -21, -29, -36, -44, -51,
+	 -58, -65, -72, -78, -85, -90, -96, -101,
+	 -105, -109, -113, -117, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -112, -108, -104, -99, -94, -89, -83,
+	 -77, -70, -63, -56, -49, -42, -34, -26,
+	 -19, -11, -3, 4, 12, 20, 28, 36,
+	 43, 50, 58, 65, 71, 78, 84, 90,
+	 95, 100, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 113, 109, 104, 100, 95,
+	 89, 83, 77, 71, 64, 57, 50, 43,
+	 35, 27, 20, 12, 4, -3, -11, -19,
+	 -27, -35, -42, -50, -57, -64, -71, -77,
+	 -83, -89, -94, -100, -104, -109, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -100, -95, -90, -84, -78, -72, -65, -58,
+	 -51, -43, -36, -28, -20, -12, -5, 2,
+	 10, 18, 26, 34, 41, 49, 56, 63,
+	 70, 76, 83, 88, 94, 99, 104, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 113,
+	 110, 105, 101, 96, 91, 85, 79, 72,
+	 66, 59, 52, 44, 37, 29, 21, 13,
+	 5, -1, -9, -17, -25, -33, -41, -48,
+	 -55, -62, -69, -76, -82, -88, -93, -98,
+	 -103, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -60, -52, -45, -38, -30,
+	 -22, -14, -6, 1, 9, 16, 24, 32,
+	 40, 47, 54, 62, 68, 75, 81, 87,
+	 93, 98, 103, 107, 111, 115, 118, 121,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 111, 106, 102, 97,
+	 92, 86, 80, 74, 67, 60, 53, 46,
+	 38, 31, 23, 15, 7, 0, -8, -16,
+	 -23, -31, -39, -46, -54, -61, -68, -74,
+	 -80, -86, -92, -97, -102, -107, -111, -114,
+	 -118, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -120, -118, -115, -111, -107,
+	 -103, -98, -92, -87, -81, -75, -68, -61,
+	 -54, -47, -39, -32, -24, -16, -8, 0,
+	 7, 15, 23, 30, 38, 45, 53, 60,
+	 67, 73, 80, 86, 91, 97, 102, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 121, 118, 115,
+	 111, 107, 103, 98, 93, 87, 82, 75,
+	 69, 62, 55, 48, 40, 33, 25, 17,
+	 9, 1, -6, -14, -22, -29, -37, -45,
+	 -52, -59, -66, -73, -79, -85, -91, -96,
+	 -101, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -104, -99, -94, -88,
+	 -82, -76, -70, -63, -56, -49, -41, -33,
+	 -26, -18, -10, -2, 5, 13, 21, 29,
+	 36, 44, 51, 58, 65, 72, 78, 84,
+	 90, 96, 101, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 108, 104, 99,
+	 94, 89, 83, 77, 70, 64, 57, 49,
+	 42, 34, 27, 19, 11, 3, -4, -12,
+	 -20, -28, -35, -43, -50, -58, -64, -71,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -123, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -113, -109,
+	 -105, -100, -95, -89, -84, -77, -71, -64,
+	 -57, -50, -43, -35, -27, -20, -12, -4,
+	 3, 11, 19, 27, 34, 42, 50, 57,
+	 64, 70, 77, 83, 89, 94, 99, 104,
+	 108, 112, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 72, 65, 58, 51, 44, 36, 28, 21,
+	 13, 5, -2, -10, -18, -26, -34, -41,
+	 -49, -56, -63, -70, -76, -82, -88, -94,
+	 -99, -104, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -113, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -59, -52, -44, -37,
+	 -29, -21, -14, -6, 1, 9, 17, 25,
+	 33, 40, 48, 55, 62, 69, 75, 82,
+	 88, 93, 98, 103, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 102,
+	 97, 91, 86, 80, 73, 67, 60, 53,
+	 45, 38, 30, 22, 14, 7, 0, -8,
+	 -16, -24, -32, -40, -47, -54, -61, -68,
+	 -75, -81, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -120, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -111,
+	 -107, -102, -97, -92, -86, -80, -74, -67,
+	 -61, -53, -46, -39, -31, -23, -15, -7,
+	 0, 7, 15, 23, 31, 39, 46, 53,
+	 61, 67, 74, 80, 86, 92, 97, 102,
+	 107, 111, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 120, 118,
+	 115, 111, 107, 103, 98, 93, 87, 81,
+	 75, 68, 61, 54, 47, 40, 32, 24,
+	 16, 8, 0, -7, -14, -22, -30, -38,
+	 -45, -53, -60, -67, -73, -80, -86, -91,
+	 -97, -102, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -103, -98, -93,
+	 -88, -82, -75, -69, -62, -55, -48, -40,
+	 -33, -25, -17, -9, -1, 6, 14, 21,
+	 29, 37, 44, 52, 59, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 113, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 104,
+	 99, 94, 88, 82, 76, 70, 63, 56,
+	 49, 41, 34, 26, 18, 10, 2, -5,
+	 -13, -21, -28, -36, -44, -51, -58, -65,
+	 -72, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -112,
+	 -108, -104, -99, -94, -89, -83, -77, -70,
+	 -64, -57, -49, -42, -34, -27, -19, -11,
+	 -3, 4, 12, 20, 27, 35, 43, 50,
+	 57, 64, 71, 77, 84, 89, 95, 100,
+	 105, 109, 113, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 123, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 71, 64, 58, 50, 43, 35, 28,
+	 20, 12, 4, -3, -11, -19, -27, -34,
+	 -42, -49, -57, -64, -70, -77, -83, -89,
+	 -94, -99, -104, -108, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -101, -96,
+	 -90, -84, -78, -72, -65, -58, -51, -44,
+	 -36, -29, -21, -13, -5, 2, 10, 18,
+	 26, 33, 41, 48, 56, 63, 70, 76,
+	 82, 88, 94, 99, 104, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 101, 96, 91, 85, 79, 73, 66, 59,
+	 52, 45, 37, 29, 22, 14, 6, -1,
+	 -9, -17, -25, -33, -40, -48, -55, -62,
+	 -69, -75, -82, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -121, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -102, -97, -91, -86, -80, -73,
+	 -67, -60, -53, -45, -38, -30, -23, -15,
+	 -7, 0, 8, 16, 24, 32, 39, 47,
+	 54, 61, 68, 75, 81, 87, 92, 98,
+	 103, 107, 111, 115, 118, 120, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 118, 114, 111, 107, 102, 97, 92, 86,
+	 80, 74, 68, 61, 54, 46, 39, 31,
+	 23, 16, 8, 0, -7, -15, -23, -31,
+	 -38, -46, -53, -60, -67, -74, -80, -86,
+	 -92, -97, -102, -106, -111, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -121, -118, -115, -111, -107, -103, -98,
+	 -93, -87, -81, -75, -68, -62, -54, -47,
+	 -40, -32, -24, -16, -9, -1, 6, 14,
+	 22, 30, 38, 45, 52, 60, 66, 73,
+	 79, 85, 91, 97, 101, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 103, 98, 93, 88, 82, 76, 69, 62,
+	 55, 48, 41, 33, 25, 17, 9, 1,
+	 -5, -13, -21, -29, -37, -44, -52, -59,
+	 -66, -72, -79, -85, -91, -96, -101, -105,
+	 -110, -113, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -104, -99, -94, -88, -83, -76,
+	 -70, -63, -56, -49, -41, -34, -26, -18,
+	 -10, -2, 5, 13, 20, 28, 36, 43,
+	 51, 58, 65, 72, 78, 84, 90, 95,
+	 100, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 109, 104, 100, 94, 89,
+	 83, 77, 71, 64, 57, 50, 42, 35,
+	 27, 19, 11, 3, -4, -12, -20, -27,
+	 -35, -43, -50, -57, -64, -71, -77, -83,
+	 -89, -95, -100, -104, -109, -113, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -100,
+	 -95, -90, -84, -78, -71, -65, -58, -50,
+	 -43, -36, -28, -20, -12, -4, 3, 11,
+	 19, 26, 34, 42, 49, 56, 63, 70,
+	 77, 83, 89, 94, 99, 104, 108, 112,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 117, 113, 109,
+	 105, 101, 96, 90, 85, 78, 72, 65,
+	 58, 51, 44, 36, 29, 21, 13, 5,
+	 -2, -10, -18, -26, -33, -41, -48, -56,
+	 -63, -69, -76, -82, -88, -94, -99, -103,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -59, -52, -45, -37, -30, -22,
+	 -14, -6, 1, 9, 17, 25, 32, 40,
+	 47, 55, 62, 69, 75, 81, 87, 93,
+	 98, 103, 107, 111, 115, 118, 121, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 102, 97, 92,
+	 86, 80, 74, 67, 60, 53, 46, 38,
+	 30, 23, 15, 7, 0, -8, -16, -24,
+	 -32, -39, -47, -54, -61, -68, -74, -81,
+	 -87, -92, -98, -102, -107, -111, -115, -118,
+	 -120, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -123, -120, -118, -114, -111, -107, -102,
+	 -97, -92, -87, -81, -74, -68, -61, -54,
+	 -46, -39, -31, -24, -16, -8, 0, 7,
+	 15, 23, 31, 38, 46, 53, 60, 67,
+	 74, 80, 86, 92, 97, 102, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 121, 118, 115, 111,
+	 107, 103, 98, 93, 87, 81, 75, 68,
+	 62, 55, 47, 40, 32, 24, 17, 9,
+	 1, -6, -14, -22, -30, -37, -45, -52,
+	 -59, -66, -73, -79, -85, -91, -96, -101,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -103, -99, -93, -88, -82,
+	 -76, -69, -62, -55, -48, -41, -33, -25,
+	 -18, -10, -2, 5, 13, 21, 29, 37,
+	 44, 51, 59, 66, 72, 79, 85, 90,
+	 96, 101, 105, 110, 113, 117, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 108, 104, 99, 94,
+	 88, 83, 76, 70, 63, 56, 49, 42,
+	 34, 26, 18, 11, 3, -4, -12, -20,
+	 -28, -36, -43, -51, -58, -65, -71, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -113, -109, -104,
+	 -100, -95, -89, -83, -77, -71, -64, -57,
+	 -50, -42, -35, -27, -19, -11, -3, 3,
+	 11, 19, 27, 35, 42, 50, 57, 64,
+	 71, 77, 83, 89, 95, 100, 104, 109,
+	 113, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 71,
+	 65, 58, 51, 43, 36, 28, 20, 12,
+	 4, -3, -11, -18, -26, -34, -42, -49,
+	 -56, -63, -70, -76, -83, -88, -94, -99,
+	 -104, -108, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -117, -113, -110, -105, -101, -96, -90, -85,
+	 -79, -72, -66, -59, -51, -44, -37, -29,
+	 -21, -13, -5, 2, 10, 18, 25, 33,
+	 41, 48, 55, 62, 69, 76, 82, 88,
+	 93, 99, 103, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 101, 96,
+	 91, 85, 79, 73, 66, 59, 52, 45,
+	 37, 30, 22, 14, 6, -1, -9, -17,
+	 -24, -32, -40, -47, -55, -62, -68, -75,
+	 -81, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -121, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -102, -97, -92, -86, -80, -74, -67, -60,
+	 -53, -46, -38, -31, -23, -15, -7, 0,
+	 8, 16, 24, 31, 39, 46, 54, 61,
+	 68, 74, 81, 87, 92, 97, 102, 107,
+	 111, 114, 118, 120, 123, 124, 125, 126,
+	 126, 126, 126, 124, 123, 120, 118, 115,
+	 111, 107, 102, 98, 92, 87, 81, 74,
+	 68, 61, 54, 47, 39, 32, 24, 16,
+	 8, 0, -7, -15, -23, -30, -38, -46,
+	 -53, -60, -67, -74, -80, -86, -92, -97,
+	 -102, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -121,
+	 -118, -115, -111, -107, -103, -98, -93, -87,
+	 -81, -75, -69, -62, -55, -47, -40, -32,
+	 -25, -17, -9, -1, 6, 14, 22, 30,
+	 37, 45, 52, 59, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 103, 99,
+	 94, 88, 82, 76, 69, 63, 56, 48,
+	 41, 33, 26, 18, 10, 2, -5, -13,
+	 -21, -29, -36, -44, -51, -58, -65, -72,
+	 -78, -85, -90, -96, -101, -105, -109, -113,
+	 -117, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -112, -108,
+	 -104, -99, -94, -89, -83, -77, -70, -63,
+	 -56, -49, -42, -34, -26, -19, -11, -3,
+	 4, 12, 20, 28, 36, 43, 50, 58,
+	 65, 71, 78, 84, 90, 95, 100, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 113, 109, 104, 100, 95, 89, 83, 77,
+	 71, 64, 57, 50, 43, 35, 27, 20,
+	 12, 4, -3, -11, -19, -27, -35, -42,
+	 -50, -57, -64, -71, -77, -83, -89, -94,
+	 -100, -104, -109, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -100, -95, -90,
+	 -84, -78, -72, -65, -58, -51, -43, -36,
+	 -28, -20, -13, -5, 2, 10, 18, 26,
+	 34, 41, 49, 56, 63, 70, 76, 83,
+	 88, 94, 99, 104, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 113, 110, 105, 101,
+	 96, 91, 85, 79, 72, 66, 59, 52,
+	 44, 37, 29, 21, 13, 5, -1, -9,
+	 -17, -25, -33, -41, -48, -55, -62, -69,
+	 -76, -82, -88, -93, -98, -103, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -101, -97, -91, -85, -79, -73, -66,
+	 -60, -52, -45, -38, -30, -22, -14, -6,
+	 1, 9, 16, 24, 32, 40, 47, 54,
+	 62, 68, 75, 81, 87, 93, 98, 103,
+	 107, 111, 115, 118, 121, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 111, 106, 102, 97, 92, 86, 80,
+	 74, 67, 60, 53, 46, 38, 31, 23,
+	 15, 7, 0, -8, -16, -23, -31, -39,
+	 -46, -54, -61, -68, -74, -80, -86, -92,
+	 -97, -102, -107, -111, -114, -118, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -120, -118, -115, -111, -107, -103, -98, -92,
+	 -87, -81, -75, -68, -61, -54, -47, -39,
+	 -32, -24, -16, -8, 0, 7, 15, 23,
+	 30, 38, 45, 53, 60, 67, 73, 80,
+	 86, 91, 97, 102, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 124, 123, 121, 118, 115, 111, 107, 103,
+	 98, 93, 87, 82, 75, 69, 62, 55,
+	 48, 40, 33, 25, 17, 9, 1, -6,
+	 -14, -22, -29, -37, -45, -52, -59, -66,
+	 -73, -79, -85, -91, -96, -101, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -104, -99, -94, -88, -82, -76, -70,
+	 -63, -56, -48, -41, -33, -26, -18, -10,
+	 -2, 5, 13, 21, 29, 36, 44, 51,
+	 58, 65, 72, 78, 84, 90, 96, 101,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 108, 104, 99, 94, 89, 83,
+	 77, 70, 64, 57, 49, 42, 34, 27,
+	 19, 11, 3, -4, -12, -20, -28, -35,
+	 -43, -50, -58, -64, -71, -78, -84, -90,
+	 -95, -100, -105, -109, -113, -116, -119, -122,
+	 -123, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -113, -109, -105, -100, -95,
+	 -89, -84, -77, -71, -64, -57, -50, -43,
+	 -35, -27, -20, -12, -4, 3, 11, 19,
+	 27, 34, 42, 50, 57, 64, 70, 77,
+	 83, 89, 94, 99, 104, 108, 112, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 116, 113, 109, 105,
+	 100, 95, 90, 84, 78, 72, 65, 58,
+	 51, 44, 36, 28, 21, 13, 5, -2,
+	 -10, -18, -26, -34, -41, -49, -56, -63,
+	 -70, -76, -82, -88, -94, -99, -104, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -113,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -59, -52, -44, -37, -29, -21, -14,
+	 -6, 1, 9, 17, 25, 33, 40, 48,
+	 55, 62, 69, 75, 82, 88, 93, 98,
+	 103, 108, 112, 115, 118, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 102, 97, 91, 86,
+	 80, 73, 67, 60, 53, 45, 38, 30,
+	 22, 14, 7, 0, -8, -16, -24, -32,
+	 -40, -47, -54, -61, -68, -75, -81, -87,
+	 -93, -98, -103, -107, -111, -115, -118, -120,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -111, -107, -102, -97,
+	 -92, -86, -80, -74, -67, -61, -53, -46,
+	 -39, -31, -23, -15, -7, 0, 7, 15,
+	 23, 31, 39, 46, 53, 61, 67, 74,
+	 80, 86, 92, 97, 102, 107, 111, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 120, 118, 115, 111, 107,
+	 103, 98, 93, 87, 81, 75, 68, 61,
+	 54, 47, 40, 32, 24, 16, 8, 0,
+	 -7, -14, -22, -30, -38, -45, -53, -60,
+	 -67, -73, -80, -86, -91, -97, -102, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -103, -98, -93, -88, -82, -75,
+	 -69, -62, -55, -48, -40, -33, -25, -17,
+	 -9, -1, 6, 14, 21, 29, 37, 44,
+	 52, 59, 66, 73, 79, 85, 91, 96,
+	 101, 106, 110, 113, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 118, 115, 112, 108, 104, 99, 94, 88,
+	 82, 76, 70, 63, 56, 49, 41, 34,
+	 26, 18, 10, 2, -5, -13, -21, -28,
+	 -36, -44, -51, -58, -65, -72, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -112, -108, -104, -99,
+	 -94, -89, -83, -77, -70, -64, -57, -50,
+	 -42, -34, -27, -19, -11, -3, 4, 12,
+	 20, 27, 35, 43, 50, 57, 64, 71,
+	 77, 84, 89, 95, 100, 105, 109, 113,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 123, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 71, 64,
+	 58, 50, 43, 35, 28, 20, 12, 4,
+	 -3, -11, -19, -27, -34, -42, -49, -57,
+	 -64, -70, -77, -83, -89, -94, -99, -104,
+	 -108, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -116,
+	 -113, -109, -105, -101, -96, -90, -84, -78,
+	 -72, -65, -58, -51, -44, -36, -29, -21,
+	 -13, -5, 2, 10, 18, 26, 33, 41,
+	 49, 56, 63, 70, 76, 82, 88, 94,
+	 99, 104, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 101, 96, 91,
+	 85, 79, 73, 66, 59, 52, 45, 37,
+	 29, 22, 14, 6, -1, -9, -17, -25,
+	 -33, -40, -48, -55, -62, -69, -75, -82,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -121, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -102,
+	 -97, -91, -86, -80, -73, -67, -60, -53,
+	 -45, -38, -30, -23, -15, -7, 0, 8,
+	 16, 24, 32, 39, 47, 54, 61, 68,
+	 75, 81, 87, 92, 98, 103, 107, 111,
+	 115, 118, 120, 123, 124, 126, 126, 126,
+	 126, 125, 124, 122, 120, 118, 114, 111,
+	 107, 102, 97, 92, 86, 80, 74, 68,
+	 61, 54, 46, 39, 31, 23, 16, 8,
+	 0, -7, -15, -23, -31, -38, -46, -53,
+	 -60, -67, -74, -80, -86, -92, -97, -102,
+	 -106, -111, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -121, -118,
+	 -115, -111, -107, -103, -98, -93, -87, -81,
+	 -75, -68, -62, -54, -47, -40, -32, -24,
+	 -16, -9, -1, 6, 14, 22, 30, 38,
+	 45, 52, 60, 67, 73, 79, 85, 91,
+	 96, 101, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 103, 98, 93,
+	 88, 82, 76, 69, 62, 55, 48, 41,
+	 33, 25, 17, 9, 1, -5, -13, -21,
+	 -29, -37, -44, -52, -59, -66, -72, -79,
+	 -85, -91, -96, -101, -105, -110, -113, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -104,
+	 -99, -94, -88, -82, -76, -70, -63, -56,
+	 -49, -41, -34, -26, -18, -10, -2, 5,
+	 13, 20, 28, 36, 43, 51, 58, 65,
+	 72, 78, 84, 90, 95, 100, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 112,
+	 109, 104, 100, 94, 89, 83, 77, 71,
+	 64, 57, 50, 42, 35, 27, 19, 11,
+	 3, -4, -12, -20, -27, -35, -43, -50,
+	 -57, -64, -71, -77, -83, -89, -95, -100,
+	 -104, -109, -113, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -100, -95, -90, -84,
+	 -78, -71, -65, -58, -50, -43, -36, -28,
+	 -20, -12, -4, 3, 11, 19, 26, 34,
+	 42, 49, 56, 63, 70, 77, 83, 89,
+	 94, 99, 104, 108, 112, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 119, 117, 113, 109, 105, 101, 96,
+	 90, 85, 78, 72, 65, 58, 51, 44,
+	 36, 29, 21, 13, 5, -2, -10, -18,
+	 -26, -33, -41, -48, -56, -63, -69, -76,
+	 -82, -88, -94, -99, -103, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -59,
+	 -52, -45, -37, -30, -22, -14, -6, 1,
+	 9, 17, 25, 32, 40, 48, 55, 62,
+	 69, 75, 81, 87, 93, 98, 103, 107,
+	 111, 115, 118, 121, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 102, 97, 92, 86, 80, 74,
+	 67, 60, 53, 46, 38, 30, 23, 15,
+	 7, 0, -8, -16, -24, -32, -39, -47,
+	 -54, -61, -68, -74, -81, -87, -92, -98,
+	 -102, -107, -111, -115, -118, -120, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -123, -120,
+	 -118, -114, -111, -107, -102, -97, -92, -87,
+	 -81, -74, -68, -61, -54, -46, -39, -31,
+	 -24, -16, -8, 0, 7, 15, 23, 31,
+	 38, 46, 53, 60, 67, 74, 80, 86,
+	 92, 97, 102, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 121, 118, 115, 111, 107, 103, 98,
+	 93, 87, 81, 75, 68, 62, 55, 47,
+	 40, 32, 24, 17, 9, 1, -6, -14,
+	 -22, -30, -37, -45, -52, -59, -66, -73,
+	 -79, -85, -91, -96, -101, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -103, -99, -93, -88, -82, -76, -69, -62,
+	 -55, -48, -41, -33, -25, -18, -10, -2,
+	 5, 13, 21, 29, 37, 44, 52, 59,
+	 66, 72, 79, 85, 90, 96, 101, 105,
+	 110, 113, 117, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 108, 104, 99, 94, 89, 83, 76,
+	 70, 63, 56, 49, 42, 34, 26, 18,
+	 11, 3, -4, -12, -20, -28, -36, -43,
+	 -51, -58, -65, -72, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -113, -109, -104, -100, -95, -89,
+	 -83, -77, -71, -64, -57, -50, -42, -35,
+	 -27, -19, -11, -3, 4, 11, 19, 27,
+	 35, 42, 50, 57, 64, 71, 77, 83,
+	 89, 95, 100, 104, 109, 113, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 71, 65, 58, 51,
+	 43, 36, 28, 20, 12, 4, -3, -11,
+	 -18, -26, -34, -42, -49, -56, -63, -70,
+	 -76, -83, -88, -94, -99, -104, -108, -112,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -117, -113, -110,
+	 -105, -101, -96, -90, -85, -79, -72, -66,
+	 -59, -51, -44, -37, -29, -21, -13, -5,
+	 2, 10, 18, 25, 33, 41, 48, 55,
+	 62, 69, 76, 82, 88, 93, 99, 103,
+	 108, 112, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 101, 96, 91, 85, 79,
+	 73, 66, 59, 52, 45, 37, 30, 22,
+	 14, 6, -1, -9, -17, -24, -32, -40,
+	 -47, -55, -62, -68, -75, -81, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -121, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -102, -97, -92,
+	 -86, -80, -74, -67, -60, -53, -46, -38,
+	 -31, -23, -15, -7, 0, 8, 16, 24,
+	 31, 39, 46, 54, 61, 68, 74, 81,
+	 87, 92, 97, 102, 107, 111, 114, 118,
+	 120, 123, 124, 125, 126, 126, 126, 126,
+	 124, 123, 120, 118, 115, 111, 107, 102,
+	 98, 92, 87, 81, 74, 68, 61, 54,
+	 47, 39, 32, 24, 16, 8, 0, -7,
+	 -15, -23, -31, -38, -46, -53, -60, -67,
+	 -74, -80, -86, -92, -97, -102, -106, -110,
+	 -114, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -124, -123, -121, -118, -115, -111,
+	 -107, -103, -98, -93, -87, -81, -75, -69,
+	 -62, -55, -47, -40, -32, -25, -17, -9,
+	 -1, 6, 14, 22, 30, 37, 45, 52,
+	 59, 66, 73, 79, 85, 91, 96, 101,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 118,
+	 115, 112, 108, 103, 99, 94, 88, 82,
+	 76, 69, 63, 56, 48, 41, 33, 26,
+	 18, 10, 2, -5, -13, -21, -29, -36,
+	 -44, -51, -58, -65, -72, -78, -85, -90,
+	 -96, -101, -105, -109, -113, -117, -119, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -119, -116, -112, -108, -104, -99, -94,
+	 -89, -83, -77, -70, -63, -56, -49, -42,
+	 -34, -26, -19, -11, -3, 4, 12, 20,
+	 28, 36, 43, 50, 58, 65, 71, 78,
+	 84, 90, 95, 100, 105, 109, 113, 116,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 113, 109, 104,
+	 100, 95, 89, 83, 77, 71, 64, 57,
+	 50, 43, 35, 27, 19, 12, 4, -3,
+	 -11, -19, -27, -35, -42, -50, -57, -64,
+	 -71, -77, -83, -89, -94, -100, -104, -109,
+	 -112, -116, -119, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -119, -116, -113,
+	 -109, -105, -100, -95, -90, -84, -78, -72,
+	 -65, -58, -51, -43, -36, -28, -20, -12,
+	 -5, 2, 10, 18, 26, 34, 41, 49,
+	 56, 63, 70, 76, 83, 88, 94, 99,
+	 104, 108, 112, 115, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 113, 110, 105, 101, 96, 91, 85,
+	 79, 72, 66, 59, 52, 44, 37, 29,
+	 21, 13, 5, -1, -9, -17, -25, -33,
+	 -41, -48, -55, -62, -69, -76, -82, -88,
+	 -93, -98, -103, -108, -112, -115, -118, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -120, -117, -114, -110, -106, -101, -96,
+	 -91, -85, -79, -73, -67, -60, -52, -45,
+	 -38, -30, -22, -14, -6, 1, 9, 16,
+	 24, 32, 40, 47, 54, 62, 68, 75,
+	 81, 87, 93, 98, 103, 107, 111, 115,
+	 118, 121, 123, 124, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 111, 106,
+	 102, 97, 92, 86, 80, 74, 67, 60,
+	 53, 46, 38, 31, 23, 15, 7, 0,
+	 -8, -16, -23, -31, -39, -46, -54, -61,
+	 -68, -74, -80, -86, -92, -97, -102, -107,
+	 -111, -114, -118, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -124, -123, -120, -118, -115,
+	 -111, -107, -103, -98, -92, -87, -81, -75,
+	 -68, -61, -54, -47, -39, -32, -24, -16,
+	 -8, 0, 7, 15, 23, 30, 38, 45,
+	 53, 60, 67, 73, 80, 86, 91, 97,
+	 102, 106, 110, 114, 117, 120, 122, 124,
+	 125, 126, 126, 126, 126, 124, 123, 121,
+	 118, 115, 111, 107, 103, 98, 93, 87,
+	 82, 75, 69, 62, 55, 48, 40, 33,
+	 25, 17, 9, 1, -6, -14, -22, -29,
+	 -37, -45, -52, -59, -66, -73, -79, -85,
+	 -91, -96, -101, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -118, -115, -112, -108, -104, -99,
+	 -94, -88, -82, -76, -70, -63, -56, -48,
+	 -41, -33, -26, -18, -10, -2, 5, 13,
+	 21, 29, 36, 44, 51, 58, 65, 72,
+	 78, 84, 90, 96, 101, 105, 109, 113,
+	 116, 119, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 119, 116, 112, 108,
+	 104, 99, 94, 89, 83, 77, 70, 64,
+	 57, 49, 42, 34, 27, 19, 11, 3,
+	 -4, -12, -20, -28, -35, -43, -50, -58,
+	 -64, -71, -78, -84, -90, -95, -100, -105,
+	 -109, -113, -116, -119, -122, -123, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -113, -109, -105, -100, -95, -89, -84, -77,
+	 -71, -64, -57, -50, -43, -35, -27, -20,
+	 -12, -4, 3, 11, 19, 27, 35, 42,
+	 50, 57, 64, 70, 77, 83, 89, 94,
+	 99, 104, 108, 112, 116, 119, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 119, 116, 113, 109, 105, 100, 95, 90,
+	 84, 78, 72, 65, 58, 51, 44, 36,
+	 28, 21, 13, 5, -2, -10, -18, -26,
+	 -34, -41, -49, -56, -63, -70, -76, -82,
+	 -88, -94, -99, -104, -108, -112, -115, -118,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -113, -110, -106, -101,
+	 -96, -91, -85, -79, -73, -66, -59, -52,
+	 -44, -37, -29, -21, -14, -6, 1, 9,
+	 17, 25, 33, 40, 48, 55, 62, 69,
+	 75, 82, 88, 93, 98, 103, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 114, 110,
+	 106, 102, 97, 91, 86, 80, 73, 67,
+	 60, 53, 45, 38, 30, 22, 14, 7,
+	 0, -8, -16, -24, -32, -40, -47, -54,
+	 -61, -68, -75, -81, -87, -93, -98, -103,
+	 -107, -111, -115, -118, -120, -123, -124, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -111, -107, -102, -97, -92, -86, -80,
+	 -74, -67, -61, -53, -46, -39, -31, -23,
+	 -15, -7, 0, 7, 15, 23, 31, 39,
+	 46, 53, 61, 67, 74, 80, 86, 92,
+	 97, 102, 107, 111, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 124, 123,
+	 120, 118, 115, 111, 107, 103, 98, 93,
+	 87, 81, 75, 68, 61, 54, 47, 40,
+	 32, 24, 16, 8, 0, -7, -14, -22,
+	 -30, -38, -45, -53, -60, -67, -73, -80,
+	 -86, -91, -97, -102, -106, -110, -114, -117,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -118, -115, -112, -108, -103,
+	 -98, -93, -88, -82, -75, -69, -62, -55,
+	 -48, -40, -33, -25, -17, -9, -1, 6,
+	 14, 21, 29, 37, 44, 52, 59, 66,
+	 73, 79, 85, 91, 96, 101, 106, 110,
+	 113, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 118, 115, 112,
+	 108, 104, 99, 94, 88, 82, 76, 70,
+	 63, 56, 49, 41, 34, 26, 18, 10,
+	 2, -5, -13, -21, -28, -36, -44, -51,
+	 -58, -65, -72, -78, -84, -90, -95, -100,
+	 -105, -109, -113, -116, -119, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -119,
+	 -116, -112, -108, -104, -99, -94, -89, -83,
+	 -77, -70, -64, -57, -49, -42, -35, -27,
+	 -19, -11, -3, 4, 12, 20, 28, 35,
+	 43, 50, 57, 64, 71, 77, 84, 89,
+	 95, 100, 105, 109, 113, 116, 119, 121,
+	 123, 125, 126, 126, 126, 126, 125, 123,
+	 122, 119, 116, 113, 109, 105, 100, 95,
+	 90, 84, 78, 71, 64, 58, 50, 43,
+	 35, 28, 20, 12, 4, -3, -11, -19,
+	 -27, -34, -42, -49, -57, -64, -70, -77,
+	 -83, -89, -94, -99, -104, -108, -112, -116,
+	 -119, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -119, -116, -113, -109, -105,
+	 -101, -96, -90, -84, -78, -72, -65, -58,
+	 -51, -44, -36, -29, -21, -13, -5, 2,
+	 10, 18, 26, 33, 41, 48, 56, 63,
+	 70, 76, 82, 88, 94, 99, 104, 108,
+	 112, 115, 118, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 110, 106, 101, 96, 91, 85, 79, 73,
+	 66, 59, 52, 45, 37, 29, 22, 14,
+	 6, -1, -9, -17, -25, -33, -40, -48,
+	 -55, -62, -69, -75, -82, -87, -93, -98,
+	 -103, -107, -111, -115, -118, -121, -123, -124,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -114, -110, -106, -102, -97, -91, -86,
+	 -80, -73, -67, -60, -53, -45, -38, -30,
+	 -23, -15, -7, 0, 8, 16, 24, 32,
+	 39, 47, 54, 61, 68, 75, 81, 87,
+	 92, 98, 103, 107, 111, 115, 118, 120,
+	 123, 124, 126, 126, 126, 126, 125, 124,
+	 122, 120, 118, 114, 111, 107, 102, 97,
+	 92, 86, 80, 74, 68, 61, 54, 46,
+	 39, 31, 23, 16, 8, 0, -7, -15,
+	 -23, -31, -38, -46, -53, -60, -67, -74,
+	 -80, -86, -92, -97, -102, -106, -111, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -124, -123, -121, -118, -115, -111, -107,
+	 -103, -98, -93, -87, -81, -75, -68, -61,
+	 -54, -47, -40, -32, -24, -16, -9, -1,
+	 6, 14, 22, 30, 38, 45, 52, 60,
+	 67, 73, 79, 85, 91, 97, 101, 106,
+	 110, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 118, 115,
+	 112, 108, 103, 98, 93, 88, 82, 76,
+	 69, 62, 55, 48, 41, 33, 25, 17,
+	 9, 2, -5, -13, -21, -29, -37, -44,
+	 -52, -59, -66, -72, -79, -85, -91, -96,
+	 -101, -105, -110, -113, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -115, -112, -108, -104, -99, -94, -88,
+	 -83, -76, -70, -63, -56, -49, -41, -34,
+	 -26, -18, -10, -2, 5, 13, 20, 28,
+	 36, 43, 51, 58, 65, 72, 78, 84,
+	 90, 95, 100, 105, 109, 113, 116, 119,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 119, 116, 112, 109, 104, 100,
+	 94, 89, 83, 77, 71, 64, 57, 50,
+	 42, 35, 27, 19, 11, 3, -4, -12,
+	 -20, -27, -35, -43, -50, -57, -64, -71,
+	 -77, -83, -89, -95, -100, -104, -109, -113,
+	 -116, -119, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -119, -116, -113, -109,
+	 -105, -100, -95, -90, -84, -78, -71, -65,
+	 -58, -50, -43, -36, -28, -20, -12, -4,
+	 3, 11, 19, 26, 34, 42, 49, 56,
+	 63, 70, 77, 83, 89, 94, 99, 104,
+	 108, 112, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 119, 117,
+	 113, 109, 105, 101, 96, 90, 85, 78,
+	 72, 65, 58, 51, 44, 36, 29, 21,
+	 13, 5, -2, -10, -18, -26, -33, -41,
+	 -48, -56, -63, -69, -76, -82, -88, -94,
+	 -99, -103, -108, -112, -115, -118, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -110, -106, -101, -96, -91,
+	 -85, -79, -73, -66, -59, -52, -45, -37,
+	 -30, -22, -14, -6, 1, 9, 17, 25,
+	 32, 40, 47, 55, 62, 69, 75, 81,
+	 87, 93, 98, 103, 107, 111, 115, 118,
+	 121, 123, 124, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 102,
+	 97, 92, 86, 80, 74, 67, 60, 53,
+	 46, 38, 30, 23, 15, 7, 0, -8,
+	 -16, -24, -32, -39, -47, -54, -61, -68,
+	 -74, -81, -87, -92, -98, -102, -107, -111,
+	 -115, -118, -120, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -123, -120, -118, -114, -111,
+	 -107, -102, -97, -92, -87, -81, -74, -68,
+	 -61, -54, -46, -39, -31, -24, -16, -8,
+	 0, 7, 15, 23, 31, 38, 46, 53,
+	 60, 67, 74, 80, 86, 92, 97, 102,
+	 106, 110, 114, 117, 120, 122, 124, 125,
+	 126, 126, 126, 126, 124, 123, 121, 118,
+	 115, 111, 107, 103, 98, 93, 87, 81,
+	 75, 68, 62, 55, 47, 40, 32, 24,
+	 17, 9, 1, -6, -14, -22, -30, -37,
+	 -45, -52, -59, -66, -73, -79, -85, -91,
+	 -96, -101, -106, -110, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -125, -123,
+	 -121, -118, -115, -112, -108, -103, -99, -93,
+	 -88, -82, -76, -69, -62, -55, -48, -41,
+	 -33, -25, -18, -10, -2, 5, 13, 21,
+	 29, 37, 44, 51, 59, 66, 72, 79,
+	 85, 90, 96, 101, 105, 110, 113, 117,
+	 119, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 119, 116, 112, 108, 104,
+	 99, 94, 88, 83, 76, 70, 63, 56,
+	 49, 42, 34, 26, 18, 11, 3, -4,
+	 -12, -20, -28, -36, -43, -51, -58, -65,
+	 -71, -78, -84, -90, -95, -100, -105, -109,
+	 -113, -116, -119, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -119, -116, -113,
+	 -109, -104, -100, -95, -89, -83, -77, -71,
+	 -64, -57, -50, -42, -35, -27, -19, -11,
+	 -3, 3, 11, 19, 27, 35, 42, 50,
+	 57, 64, 71, 77, 83, 89, 95, 100,
+	 104, 109, 113, 116, 119, 121, 123, 125,
+	 126, 126, 126, 126, 125, 124, 122, 119,
+	 116, 113, 109, 105, 100, 95, 90, 84,
+	 78, 71, 65, 58, 51, 43, 36, 28,
+	 20, 12, 4, -3, -11, -18, -26, -34,
+	 -42, -49, -56, -63, -70, -76, -83, -89,
+	 -94, -99, -104, -108, -112, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -117, -113, -110, -105, -101, -96,
+	 -90, -85, -79, -72, -66, -59, -51, -44,
+	 -37, -29, -21, -13, -5, 2, 10, 18,
+	 25, 33, 41, 48, 55, 62, 69, 76,
+	 82, 88, 93, 99, 103, 108, 112, 115,
+	 118, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 120, 117, 114, 110, 106,
+	 101, 96, 91, 85, 79, 73, 66, 59,
+	 52, 45, 37, 30, 22, 14, 6, -1,
+	 -9, -17, -25, -32, -40, -47, -55, -62,
+	 -68, -75, -81, -87, -93, -98, -103, -107,
+	 -111, -115, -118, -121, -123, -124, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -102, -97, -92, -86, -80, -74,
+	 -67, -60, -53, -46, -38, -31, -23, -15,
+	 -7, 0, 8, 16, 24, 31, 39, 46,
+	 54, 61, 68, 74, 81, 87, 92, 97,
+	 102, 107, 111, 114, 118, 120, 123, 124,
+	 125, 126, 126, 126, 126, 124, 123, 120,
+	 118, 115, 111, 107, 102, 98, 92, 87,
+	 81, 74, 68, 61, 54, 47, 39, 32,
+	 24, 16, 8, 0, -7, -15, -23, -30,
+	 -38, -46, -53, -60, -67, -74, -80, -86,
+	 -92, -97, -102, -106, -110, -114, -117, -120,
+	 -122, -124, -125, -126, -126, -126, -126, -124,
+	 -123, -121, -118, -115, -111, -107, -103, -98,
+	 -93, -87, -81, -75, -69, -62, -55, -47,
+	 -40, -32, -25, -17, -9, -1, 6, 14,
+	 22, 30, 37, 45, 52, 59, 66, 73,
+	 79, 85, 91, 96, 101, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 125, 123, 121, 118, 115, 112, 108,
+	 103, 99, 94, 88, 82, 76, 69, 63,
+	 56, 48, 41, 33, 26, 18, 10, 2,
+	 -5, -13, -21, -29, -36, -44, -51, -58,
+	 -65, -72, -78, -85, -90, -96, -101, -105,
+	 -109, -113, -117, -119, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -119, -116,
+	 -112, -108, -104, -99, -94, -89, -83, -77,
+	 -70, -63, -56, -49, -42, -34, -26, -19,
+	 -11, -3, 4, 12, 20, 28, 36, 43,
+	 50, 58, 65, 71, 78, 84, 90, 95,
+	 100, 105, 109, 113, 116, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 113, 109, 104, 100, 95, 89,
+	 83, 77, 71, 64, 57, 50, 43, 35,
+	 27, 19, 12, 4, -3, -11, -19, -27,
+	 -35, -42, -50, -57, -64, -71, -77, -83,
+	 -89, -94, -100, -104, -109, -112, -116, -119,
+	 -121, -123, -125, -126, -126, -126, -126, -125,
+	 -124, -122, -119, -116, -113, -109, -105, -100,
+	 -95, -90, -84, -78, -72, -65, -58, -51,
+	 -43, -36, -28, -20, -12, -5, 2, 10,
+	 18, 26, 34, 41, 49, 56, 63, 70,
+	 76, 83, 88, 94, 99, 104, 108, 112,
+	 115, 118, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 120, 117, 113, 110,
+	 105, 101, 96, 91, 85, 79, 72, 66,
+	 59, 52, 44, 37, 29, 21, 13, 5,
+	 -1, -9, -17, -25, -33, -41, -48, -55,
+	 -62, -69, -76, -82, -88, -93, -98, -103,
+	 -108, -112, -115, -118, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -120, -117,
+	 -114, -110, -106, -101, -96, -91, -85, -79,
+	 -73, -66, -60, -52, -45, -38, -30, -22,
+	 -14, -6, 1, 9, 16, 24, 32, 40,
+	 47, 54, 62, 68, 75, 81, 87, 93,
+	 98, 103, 107, 111, 115, 118, 121, 123,
+	 124, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 111, 106, 102, 97, 92,
+	 86, 80, 74, 67, 60, 53, 46, 38,
+	 31, 23, 15, 7, 0, -8, -16, -23,
+	 -31, -39, -46, -54, -61, -68, -74, -80,
+	 -86, -92, -97, -102, -107, -111, -114, -118,
+	 -120, -122, -124, -125, -126, -126, -126, -126,
+	 -124, -123, -120, -118, -115, -111, -107, -103,
+	 -98, -92, -87, -81, -75, -68, -61, -54,
+	 -47, -39, -32, -24, -16, -8, 0, 7,
+	 15, 23, 30, 38, 45, 53, 60, 67,
+	 73, 80, 86, 91, 97, 102, 106, 110,
+	 114, 117, 120, 122, 124, 125, 126, 126,
+	 126, 126, 124, 123, 121, 118, 115, 111,
+	 107, 103, 98, 93, 87, 82, 75, 69,
+	 62, 55, 48, 40, 33, 25, 17, 9,
+	 1, -6, -14, -22, -29, -37, -45, -52,
+	 -59, -66, -73, -79, -85, -91, -96, -101,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -125, -123, -121, -118,
+	 -115, -112, -108, -104, -99, -94, -88, -82,
+	 -76, -70, -63, -56, -49, -41, -33, -26,
+	 -18, -10, -2, 5, 13, 21, 29, 36,
+	 44, 51, 58, 65, 72, 78, 84, 90,
+	 96, 101, 105, 109, 113, 116, 119, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 119, 116, 112, 108, 104, 99, 94,
+	 89, 83, 77, 70, 64, 57, 49, 42,
+	 34, 27, 19, 11, 3, -4, -12, -20,
+	 -28, -35, -43, -50, -58, -64, -71, -78,
+	 -84, -90, -95, -100, -105, -109, -113, -116,
+	 -119, -122, -123, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -113, -109, -105,
+	 -100, -95, -89, -84, -77, -71, -64, -57,
+	 -50, -43, -35, -28, -20, -12, -4, 3,
+	 11, 19, 27, 35, 42, 49, 57, 64,
+	 70, 77, 83, 89, 94, 99, 104, 108,
+	 112, 116, 119, 121, 123, 125, 126, 126,
+	 126, 126, 125, 124, 122, 119, 116, 113,
+	 109, 105, 100, 95, 90, 84, 78, 72,
+	 65, 58, 51, 44, 36, 28, 21, 13,
+	 5, -2, -10, -18, -26, -34, -41, -49,
+	 -56, -63, -70, -76, -82, -88, -94, -99,
+	 -104, -108, -112, -115, -118, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -120,
+	 -117, -113, -110, -106, -101, -96, -91, -85,
+	 -79, -73, -66, -59, -52, -44, -37, -29,
+	 -21, -14, -6, 1, 9, 17, 25, 33,
+	 40, 48, 55, 62, 69, 75, 82, 88,
+	 93, 98, 103, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 114, 110, 106, 102, 97,
+	 91, 86, 80, 73, 67, 60, 53, 45,
+	 38, 30, 22, 14, 7, 0, -8, -16,
+	 -24, -32, -40, -47, -54, -61, -68, -75,
+	 -81, -87, -93, -98, -103, -107, -111, -115,
+	 -118, -120, -123, -124, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -111, -107,
+	 -102, -97, -92, -86, -80, -74, -67, -61,
+	 -53, -46, -39, -31, -23, -15, -7, 0,
+	 7, 15, 23, 31, 39, 46, 53, 61,
+	 67, 74, 80, 86, 92, 97, 102, 107,
+	 111, 114, 117, 120, 122, 124, 125, 126,
+	 126, 126, 126, 124, 123, 120, 118, 115,
+	 111, 107, 103, 98, 93, 87, 81, 75,
+	 68, 61, 54, 47, 40, 32, 24, 16,
+	 8, 0, -7, -14, -22, -30, -38, -45,
+	 -53, -60, -67, -73, -80, -86, -91, -97,
+	 -102, -106, -110, -114, -117, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -118, -115, -112, -108, -103, -98, -93, -88,
+	 -82, -75, -69, -62, -55, -48, -40, -33,
+	 -25, -17, -9, -1, 6, 14, 21, 29,
+	 37, 44, 52, 59, 66, 73, 79, 85,
+	 91, 96, 101, 106, 110, 113, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 125,
+	 123, 121, 118, 115, 112, 108, 104, 99,
+	 94, 88, 82, 76, 70, 63, 56, 49,
+	 41, 34, 26, 18, 10, 2, -5, -13,
+	 -21, -28, -36, -44, -51, -58, -65, -72,
+	 -78, -84, -90, -95, -100, -105, -109, -113,
+	 -116, -119, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -119, -116, -112, -108,
+	 -104, -99, -94, -89, -83, -77, -70, -64,
+	 -57, -49, -42, -34, -27, -19, -11, -3,
+	 4, 12, 20, 28, 35, 43, 50, 57,
+	 64, 71, 77, 84, 89, 95, 100, 105,
+	 109, 113, 116, 119, 121, 123, 125, 126,
+	 126, 126, 126, 125, 123, 122, 119, 116,
+	 113, 109, 105, 100, 95, 90, 84, 78,
+	 71, 64, 58, 50, 43, 35, 28, 20,
+	 12, 4, -3, -11, -19, -27, -34, -42,
+	 -49, -57, -64, -70, -77, -83, -89, -94,
+	 -99, -104, -108, -112, -116, -119, -121, -123,
+	 -125, -126, -126, -126, -126, -125, -124, -122,
+	 -119, -116, -113, -109, -105, -101, -96, -90,
+	 -84, -78, -72, -65, -58, -51, -44, -36,
+	 -29, -21, -13, -5, 2, 10, 18, 26,
+	 33, 41, 48, 56, 63, 70, 76, 82,
+	 88, 94, 99, 104, 108, 112, 115, 118,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 120, 117, 114, 110, 106, 101,
+	 96, 91, 85, 79, 73, 66, 59, 52,
+	 45, 37, 29, 22, 14, 6, -1, -9,
+	 -17, -25, -33, -40, -48, -55, -62, -69,
+	 -75, -82, -87, -93, -98, -103, -107, -111,
+	 -115, -118, -121, -123, -124, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -114, -110,
+	 -106, -102, -97, -91, -86, -80, -73, -67,
+	 -60, -53, -45, -38, -30, -23, -15, -7,
+	 0, 8, 16, 24, 32, 39, 47, 54,
+	 61, 68, 75, 81, 87, 92, 98, 103,
+	 107, 111, 115, 118, 120, 123, 124, 126,
+	 126, 126, 126, 125, 124, 122, 120, 118,
+	 114, 111, 107, 102, 97, 92, 86, 80,
+	 74, 68, 61, 54, 46, 39, 31, 23,
+	 16, 8, 0, -7, -15, -23, -31, -38,
+	 -46, -53, -60, -67, -74, -80, -86, -92,
+	 -97, -102, -106, -111, -114, -117, -120, -122,
+	 -124, -125, -126, -126, -126, -126, -124, -123,
+	 -121, -118, -115, -111, -107, -103, -98, -93,
+	 -87, -81, -75, -68, -62, -54, -47, -40,
+	 -32, -24, -16, -9, -1, 6, 14, 22,
+	 30, 38, 45, 52, 60, 66, 73, 79,
+	 85, 91, 96, 101, 106, 110, 114, 117,
+	 120, 122, 124, 125, 126, 126, 126, 126,
+	 125, 123, 121, 118, 115, 112, 108, 103,
+	 98, 93, 88, 82, 76, 69, 62, 55,
+	 48, 41, 33, 25, 17, 9, 1, -5,
+	 -13, -21, -29, -37, -44, -52, -59, -66,
+	 -72, -79, -85, -91, -96, -101, -105, -110,
+	 -113, -117, -120, -122, -124, -125, -126, -126,
+	 -126, -126, -125, -123, -121, -118, -115, -112,
+	 -108, -104, -99, -94, -88, -83, -76, -70,
+	 -63, -56, -49, -41, -34, -26, -18, -10,
+	 -2, 5, 13, 20, 28, 36, 43, 51,
+	 58, 65, 72, 78, 84, 90, 95, 100,
+	 105, 109, 113, 116, 119, 122, 124, 125,
+	 126, 126, 126, 126, 125, 123, 121, 119,
+	 116, 112, 109, 104, 100, 94, 89, 83,
+	 77, 71, 64, 57, 50, 42, 35, 27,
+	 19, 11, 3, -4, -12, -20, -27, -35,
+	 -43, -50, -57, -64, -71, -77, -83, -89,
+	 -95, -100, -104, -109, -113, -116, -119, -121,
+	 -123, -125, -126, -126, -126, -126, -125, -124,
+	 -122, -119, -116, -113, -109, -105, -100, -95,
+	 -90, -84, -78, -71, -65, -58, -50, -43,
+	 -36, -28, -20, -12, -4, 3, 11, 19,
+	 26, 34, 42, 49, 56, 63, 70, 77,
+	 83, 89, 94, 99, 104, 108, 112, 116,
+	 119, 121, 123, 125, 126, 126, 126, 126,
+	 125, 124, 122, 119, 117, 113, 109, 105,
+	 101, 96, 90, 85, 78, 72, 65, 58,
+	 51, 44, 36, 29, 21, 13, 5, -2,
+	 -10, -18, -26, -33, -41, -48, -56, -63,
+	 -69, -76, -82, -88, -94, -99, -103, -108,
+	 -112, -115, -118, -121, -123, -125, -126, -126,
+	 -126, -126, -125, -124, -122, -120, -117, -114,
+	 -110, -106, -101, -96, -91, -85, -79, -73,
+	 -66, -59, -52, -45, -37, -30, -22, -14,
+	 -6, 1, 9, 17, 25, 32, 40, 48,
+	 55, 62, 69, 75, 81, 87, 93, 98,
+	 103, 107, 111, 115, 118, 121, 123, 124,
+	 126, 126, 126, 126, 125, 124, 122, 120,
+	 117, 114, 110, 106, 102, 97, 92, 86,
+	 80, 74, 67, 60, 53, 46, 38, 30,
+	 23, 15, 7, 0, -8, -16, -24, -32,
+	 -39, -47, -54, -61, -68, -74, -81, -87,
+	 -92, -98, -102, -107, -111, -115, -118, -120,
+	 -123, -124, -126, -126, -126, -126, -125, -124,
+	 -123, -120, -118, -114, -111, -107, -102, -97,
+	 -92, -87, -81, -74, -68, -61, -54, -47,
+	 -39, -31, -24, -16, -8, 0, 7, 15,
+	 23, 31, 38, 46, 53, 60, 67, 74,
+	 80, 86, 92, 97, 102, 106, 110, 114,
+	 117, 120, 122, 124, 125, 126, 126, 126,
+	 126, 124, 123, 121, 118, 115, 111, 107,
+	 103, 98, 93, 87, 81, 75, 68, 62,
+	 55, 47, 40, 32, 24, 17, 9, 1,
+	 -6, -14, -22, -30, -37, -45, -52, -59,
+	 -66, -73, -79, -85, -91, -96, -101, -106,
+	 -110, -114, -117, -120, -122, -124, -125, -126,
+	 -126, -126, -126, -125, -123, -121, -118, -115,
+	 -112, -108, -103, -99, -93, -88, -82, -76,
+	 -69, -62, -55, -48, -41, -33, -25, -18,
+	 -10, -2, 5, 13, 21, 29, 37, 44,
+	 51, 59, 66, 72, 79, 85, 90, 96,
+	 101, 105, 110, 113, 117, 119, 122, 124,
+	 125, 126, 126, 126, 126, 125, 123, 121,
+	 119, 116, 112, 108, 104, 99, 94, 89,
+	 83, 76, 70, 63, 56, 49, 42, 34,
+	 26, 18, 11, 3, -4, -12, -20, -28,
+	 -36, -43, -51, -58, -65, -72, -78, -84,
+	 -90, -95, -100, -105, -109, -113, -116, -119,
+	 -122, -124, -125, -126, -126, -126, -126, -125,
+	 -123, -121, -119, -116, -113, -109, -104, -100,
+	 -95, -89, -83, -77, -71, -64, -57, -50,
+	 -42, -35, -27, -19, -11, -3, 3, 11,
+	 19, 27, 35, 42, 50, 57, 64, 71,
+	 77, 83, 89, 95, 100, 104, 109, 113,
+	 116, 119, 121, 123, 125, 126, 126, 126,
+	 126, 125, 124, 122, 119, 116, 113, 109,
+	 105, 100, 95, 90, 84, 78, 71, 65,
+	 58, 51, 43, 36, 28, 20, 12, 4,
+	 -3, -11, -18, -26, -34, -42, -49, -56,
+	 -63, -70, -76, -83, -88, -94, -99, -104,
+	 -108, -112, -116, -119, -121, -123, -125, -126,
+	 -126, -126, -126, -125, -124, -122, -119, -117,
+	 -113, -110, -105, -101, -96, -90, -85, -79,
+	 -72, -66, -59, -51, -44, -37, -29, -21,
+	 -13, -5, 2, 10, 18, 25, 33, 41,
+	 48, 55, 62, 69, 76, 82, 88, 93,
+	 99, 103, 108, 112, 115, 118, 121, 123,
+	 125, 126, 126, 126, 126, 125, 124, 122,
+	 120, 117, 114, 110, 106, 101, 96, 91,
+	 85, 79, 73, 66, 59, 52, 45, 37,
+	 30, 22, 14, 6, -1, -9, -17, -24,
+	 -32, -40, -47, -55, -62, -68, -75, -81,
+	 -87, -93, -98, -103, -107, -111, -115, -118,
+	 -121, -123, -124, -126, -126, -126, -126, -125,
+	 -124, -122, -120, -117, -114, -110, -106, -102,
+	 -97, -92, -86, -80, -74, -67, -60, -53,
+	 -46, -38, -31, -23, -15, -7, 0, 8,
+	 16, 24, 31, 39, 46, 54, 61, 68,
+	 74, 81, 87, 92, 97, 102, 107, 111,
+	 114, 118, 120, 123, 124, 125, 126, 126,
+	 126, 126, 124, 123, 120, 118, 115, 111,
+	 107, 102, 98, 92, 87, 81, 74, 68,
+	 61, 54, 47, 39, 32, 24, 16, 8,
+	 0, -7, -15, -23, -30, -38, -46, -53,
+	 -60, -67, -74, -80, -86, -92, -97, -102,
+	 -106, -110, -114, -117, -120, -122, -124, -125,
+	 -126, -126, -126, -126, -124, -123, -121, -118,
+	 -115, -111, -107, -103, -98, -93, -87, -81,
+	 -75, -69, -62, -55, -47, -40, -32, -25,
+	 -17, -9, -1, 6, 14, 22, 30, 37,
+	 45, 52, 59, 66, 73, 79, 85, 91,
+	 96, 101, 106, 110, 114, 117, 120, 122,
+	 124, 125, 126, 126, 126, 126, 125, 123,
+	 121, 118, 115, 112, 108, 103, 99, 94,
+	 88, 82, 76, 69, 63, 56, 48, 41,
+	 33, 26, 18, 10, 2, -5, -13, -21,
+	 -29, -36, -44, -51, -58, -65, -72, -78,
+	 -85, -90, -96, -101, -105, -109, -113, -117,
+	 -119, -122, -124, -125, -126, -126, -126, -126,
+	 -125, -123, -121, -119, -116, -112, -108, -104,
+	 -99, -94, -89, -83, -77, -70, -63, -56,
+	 -49, -42, -34, -26, -19, -11, -3, 4,
+	 12, 20, 28, 36, 43, 50, 58, 65,
+	 71, 78, 84, 90, 95, 100, 105, 109,
+	 113, 116, 119, 122, 124, 125, 126, 126,
+	 126, 126, 125, 123, 121, 119, 116, 113,
+	 109, 104, 100, 95, 89, 83, 77, 71,
+	 64, 57, 50, 43, 35, 27, 19, 12,
+	 4, -3, -11, -19, -27, -35, -42, -50,
+	 -57, -64, -71, -77, -83, -89, -94, -100,
+	 -104, -109, -112, -116, -119, -121, -123, -125,
+	 -126, -126, -126, -126, -125, -124, -122, -119,
+	 -116, -113, -109, -105, -100, -95, -90, -84,
+	 -78, -72, -65, -58, -51, -43, -36, -28,
+	 -20, -13, -5, 2, 10, 18, 26, 34,
+	 41, 49, 56, 63, 70, 76, 83, 88,
+	 94, 99, 104, 108, 112, 115, 118, 121,
+	 123, 125, 126, 126, 126, 126, 125, 124,
+	 122, 120, 117, 113, 110, 105, 101, 96,
+	 91, 85, 79, 72, 66, 59, 52, 44,
+	 37, 29, 21, 13, 5, -1, -9, -17,
+	 -25, -33, -41, -48, -55, -62, -69, -76,
+	 -82, -88, -93, -98, -103, -108, -112, -115,
+	 -118, -121, -123, -125, -126, -126, -126, -126,
+	 -125, -124, -122, -120, -117, -114, -110, -106,
+	 -101, -96, -91, -85, -79, -73, -66, -60,
+	 -52, -45, -38, -30, -22, -14, -6, 1,
+	 9, 16, 24, 32, 40, 47, 54, 62,
+	 68, 75, 81, 87, 93, 98, 103, 107,
+	 111, 115, 118, 121, 123, 124, 126, 126,
+	 126, 126, 125, 124, 122, 120, 117, 114,
+	 111, 106, 102, 97, 92, 86, 80, 74,
+	 67, 60, 53, 46, 38, 31, 23, 15,
+	 7, 0, -8, -16, -23, -31, -39, -46,
+	 -54, -61, -68, -74, -80, -86, -92, -97,
+	 -102, -107, -111, -114, -118, -120, -122, -124,
+	 -125, -126, -126, -126, -126, -124, -123, -120,
+	 -118, -115, -111, -107, -103, -98, -92, -87,
+	 -81, -75, -68, -61, -54, -47, -39, -32,
+	 -24, -16, -8, 0, 7, 15, 23, 30,
+	 38, 45, 53, 60, 67, 73, 80, 86,
+	 91, 97, 102, 106, 110, 114, 117, 120,
+	 122, 124, 125, 126, 126, 126, 126, 124,
+	 123, 121, 118, 115, 111, 107, 103, 98,
+	 93, 87, 82, 75, 69, 62, 55, 48,
+	 40, 33, 25, 17, 9, 1, -6, -14,
+	 -22, -29, -37, -45, -52, -59, -66, -73,
+	 -79, -85, -91, -96, -101, -106, -110, -114,
+	 -117, -120, -122, -124, -125, -126, -126, -126,
+	 -126, -125, -123, -121, -118, -115, -112, -108,
+	 -104, -99, -94, -88, -82, -76, -70, -63,
+	 -56, -48, -41, -33, -26, -18, -10, -2,
+	 5, 13, 21, 29, 36, 44, 51, 58,
+	 65, 72, 78, 84, 90, 96, 101, 105,
+	 109, 113, 116, 119, 122, 124, 125, 126,
+	 126, 126, 126, 125, 123, 121, 119, 116,
+	 112, 108, 104, 99, 94, 89, 83, 77,
+	 70, 64, 57, 49, 42, 34, 27, 19,
+	 11, 3, -4, -12, -20, -28, -35, -43,
+	 -50, -58, -64, -71, -78, -84, -90, -95,
+	 -100, -105, -109, -113, -116, -119, -122, -123,
+	 -125, -126, -126, -126, -126, -125, -123, -121,
+	 -119, -116, -113, -109, -105, -100, -95, -89,
+	 -84, -77, -71, -64, -57, -50, -43, -35,
+	 -27, -20, -12, -4, 3, 11, 19, 27,
+	 35, 42, 50, 57, 64, 70, 77, 83,
+	 89, 94, 99, 104, 108, 112, 116, 119,
+	 121, 123, 125, 126, 126, 126, 126, 125,
+	 124, 122, 119, 116, 113, 109, 105, 100,
+	 95, 90, 84, 78, 72, 65, 58, 51,
+	 44, 36, 28, 21, 13, 5, -2, -10,
+	 -18, -26, -34, -41, -49, -56, -63, -70,
+	 -76, -82, -88, -94, -99, -104, -108, -112,
+	 -115, -118, -121, -123, -125, -126, -126, -126,
+	 -126, -125, -124, -122, -120, -117, -113, -110,
+	 -106, -101, -96, -91, -85, -79, -73, -66,
+	 -59, -52, -44, -37, -29, -21, -14, -6,
+	 1, 9, 17, 25, 33, 40, 48, 55,
+	 62, 69, 75, 82, 88, 93, 98, 103,
+	 108, 111, 115, 118, 121, 123, 125, 126,
+	 126, 126, 126, 125, 124, 122, 120, 117,
+	 114, 110, 106, 102, 97, 91, 86, 80,
+	 73, 67, 60, 53, 45, 38, 30, 22,
+	 14, 7, 0, -8, -16, -24, -32, -40,
+	 -47, -54, -61, -68, -75, -81, -87, -93,
+	 -98, -103, -107, -111, -115, -118, -120, -123,
+	 -124, -126, -126, -126, -126, -125, -124, -122,
+	 -120, -117, -114, -111, -107, -102, -97, -92,
+	 -86, -80, -74, -67, -61, -53, -46, -39,
+	 -31, -23, -15, -7,
+};
\ No newline at end of file
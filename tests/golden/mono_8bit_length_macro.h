@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_LENGTH_MACRO_H_
+#define _MONO_8BIT_LENGTH_MACRO_H_
+
+#define MONO_8BIT_LENGTH_MACRO_SAMPLE_NO 44100
+extern const int8_t mono_8bit_length_macro[];
+
+#define MONO_8BIT_LENGTH_MACRO_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_LENGTH_SIZEOF_H_
+#define _MONO_8BIT_LENGTH_SIZEOF_H_
+
+#define MONO_8BIT_LENGTH_SIZEOF_SAMPLE_NO 44100
+extern const int8_t mono_8bit_length_sizeof[];
+
+#define MONO_8BIT_LENGTH_SIZEOF_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_MUTABLE_H_
+#define _MONO_8BIT_MUTABLE_H_
+
+extern const size_t MONO_8BIT_MUTABLE_SAMPLE_NO;
+extern int8_t mono_8bit_mutable[];
+
+#define MONO_8BIT_MUTABLE_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
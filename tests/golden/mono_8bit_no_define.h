@@ -0,0 +1,6 @@
+#ifndef _MONO_8BIT_NO_DEFINE_H_
+#define _MONO_8BIT_NO_DEFINE_H_
+
+extern const int8_t mono_8bit_no_define[];
+
+#endif
\ No newline at end of file
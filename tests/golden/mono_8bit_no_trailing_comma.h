@@ -0,0 +1,9 @@
+#ifndef _MONO_8BIT_NO_TRAILING_COMMA_H_
+#define _MONO_8BIT_NO_TRAILING_COMMA_H_
+
+extern const size_t MONO_8BIT_NO_TRAILING_COMMA_SAMPLE_NO;
+extern const int8_t mono_8bit_no_trailing_comma[];
+
+#define MONO_8BIT_NO_TRAILING_COMMA_SAMPLE_RATE 44100
+
+#endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t STEREO_BIT_SAMPLE_NO = 44100;
+const size_t STEREO_16BIT_SAMPLE_NO = 44100;
 
-const int16_t stereo_bit[44100] = {
+#define STEREO_16BIT_SAMPLE_RATE 44100
+
+const int16_t stereo_16bit[44100] = {
 	 0, 2052, 4097, 6126, 8130, 10103, 12036, 13921,
 	 15752, 17521, 19222, 20846, 22389, 23844, 25205, 26467,
 	 27625, 28675, 29612, 30433, 31134, 31713, 32167, 32494,
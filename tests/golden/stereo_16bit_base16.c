@@ -1,6 +1,8 @@
-const size_t STEREO_BIT_BASE_SAMPLE_NO = 44100;
+const size_t STEREO_16BIT_BASE16_SAMPLE_NO = 44100;
 
-const int16_t stereo_bit_base[44100] = {
+#define STEREO_16BIT_BASE16_SAMPLE_RATE 44100
+
+const int16_t stereo_16bit_base16[44100] = {
 	 0x0000, 0x0804, 0x1001, 0x17ee, 0x1fc2, 0x2777, 0x2f04, 0x3661,
 	 0x3d88, 0x4471, 0x4b16, 0x516e, 0x5775, 0x5d24, 0x6275, 0x6763,
 	 0x6be9, 0x7003, 0x73ac, 0x76e1, 0x799e, 0x7be1, 0x7da7, 0x7eee,
@@ -4,4 +4,6 @@
 extern const size_t STEREO_16BIT_BASE16_SAMPLE_NO;
 extern const int16_t stereo_16bit_base16[];
 
+#define STEREO_16BIT_BASE16_SAMPLE_RATE 44100
+
 #endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t STEREO_BIT_LOW_SAMPLE_NO = 11025;
+const size_t STEREO_8BIT_LOW_SAMPLE_NO = 11025;
 
-const int8_t stereo_bit_low[11025] = {
+#define STEREO_8BIT_LOW_SAMPLE_RATE 11025
+
+const int8_t stereo_8bit_low[11025] = {
 	 0, 31, 61, 86, 107, 120, 126, 124,
 	 115, 98, 75, 47, 16, -14, -45, -73,
 	 -97, -114, -124, -126, -121, -108, -88, -62,
@@ -4,4 +4,6 @@
 extern const size_t STEREO_8BIT_LOW_SAMPLE_NO;
 extern const int8_t stereo_8bit_low[];
 
+#define STEREO_8BIT_LOW_SAMPLE_RATE 11025
+
 #endif
\ No newline at end of file
@@ -1,6 +1,8 @@
-const size_t STEREO_BIT_LOW_BASE_SAMPLE_NO = 11025;
+const size_t STEREO_8BIT_LOW_BASE16_SAMPLE_NO = 11025;
 
-const int8_t stereo_bit_low_base[11025] = {
+#define STEREO_8BIT_LOW_BASE16_SAMPLE_RATE 11025
+
+const int8_t stereo_8bit_low_base16[11025] = {
 	 0x00, 0x1f, 0x3d, 0x56, 0x6b, 0x78, 0x7e, 0x7c,
 	 0x73, 0x62, 0x4b, 0x2f, 0x10, 0xf2, 0xd3, 0xb7,
 	 0x9f, 0x8e, 0x84, 0x82, 0x87, 0x94, 0xa8, 0xc2,
@@ -4,4 +4,6 @@
 extern const size_t STEREO_8BIT_LOW_BASE16_SAMPLE_NO;
 extern const int8_t stereo_8bit_low_base16[];
 
+#define STEREO_8BIT_LOW_BASE16_SAMPLE_RATE 11025
+
 #endif
\ No newline at end of file
@@ -0,0 +1,1392 @@
+const size_t STEREO_8BIT_LOW_FRAMES_SAMPLE_NO = 22050;
+
+const size_t STEREO_8BIT_LOW_FRAMES_FRAME_NO = 11025;
+
+#define STEREO_8BIT_LOW_FRAMES_SAMPLE_RATE 11025
+
+#ifndef STEREO_8BIT_LOW_FRAMES_FRAME_T
+#define STEREO_8BIT_LOW_FRAMES_FRAME_T
+typedef struct { int8_t l, r; } stereo_8bit_low_frames_frame_t;
+#endif
+
+const stereo_8bit_low_frames_frame_t stereo_8bit_low_frames[11025] = {
+	 {0, 0}, {31, 31}, {61, 61}, {86, 86}, {107, 107}, {120, 120}, {126, 126}, {124, 124},
+	 {115, 115}, {98, 98}, {75, 75}, {47, 47}, {16, 16}, {-14, -14}, {-45, -45}, {-73, -73},
+	 {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62},
+	 {-33, -33}, {-1, -1}, {29, 29}, {59, 59}, {85, 85}, {106, 106}, {120, 120}, {126, 126},
+	 {125, 125}, {115, 115}, {99, 99}, {76, 76}, {49, 49}, {18, 18}, {-13, -13}, {-44, -44},
+	 {-72, -72}, {-95, -95}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89},
+	 {-64, -64}, {-35, -35}, {-3, -3}, {27, 27}, {57, 57}, {84, 84}, {105, 105}, {119, 119},
+	 {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78}, {50, 50}, {20, 20}, {-11, -11},
+	 {-42, -42}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109},
+	 {-90, -90}, {-65, -65}, {-36, -36}, {-5, -5}, {26, 26}, {56, 56}, {82, 82}, {104, 104},
+	 {118, 118}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79}, {52, 52}, {22, 22},
+	 {-9, -9}, {-40, -40}, {-69, -69}, {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122},
+	 {-110, -110}, {-91, -91}, {-67, -67}, {-38, -38}, {-7, -7}, {24, 24}, {54, 54}, {81, 81},
+	 {103, 103}, {118, 118}, {126, 126}, {125, 125}, {118, 118}, {102, 102}, {80, 80}, {54, 54},
+	 {23, 23}, {-7, -7}, {-38, -38}, {-67, -67}, {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-111, -111}, {-93, -93}, {-68, -68}, {-40, -40}, {-9, -9}, {22, 22}, {52, 52},
+	 {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82},
+	 {55, 55}, {25, 25}, {-5, -5}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122},
+	 {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-41, -41}, {-10, -10}, {20, 20},
+	 {51, 51}, {78, 78}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104},
+	 {83, 83}, {57, 57}, {27, 27}, {-4, -4}, {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12},
+	 {19, 19}, {49, 49}, {77, 77}, {99, 99}, {116, 116}, {125, 125}, {126, 126}, {119, 119},
+	 {105, 105}, {85, 85}, {58, 58}, {29, 29}, {-2, -2}, {-33, -33}, {-63, -63}, {-88, -88},
+	 {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45},
+	 {-14, -14}, {17, 17}, {47, 47}, {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126},
+	 {120, 120}, {106, 106}, {86, 86}, {60, 60}, {30, 30}, {0, 0}, {-32, -32}, {-61, -61},
+	 {-87, -87}, {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74},
+	 {-46, -46}, {-16, -16}, {15, 15}, {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124},
+	 {126, 126}, {121, 121}, {107, 107}, {87, 87}, {62, 62}, {32, 32}, {1, 1}, {-30, -30},
+	 {-59, -59}, {-85, -85}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99},
+	 {-76, -76}, {-48, -48}, {-18, -18}, {13, 13}, {44, 44}, {72, 72}, {96, 96}, {113, 113},
+	 {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63}, {34, 34}, {3, 3},
+	 {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116},
+	 {-100, -100}, {-77, -77}, {-50, -50}, {-19, -19}, {11, 11}, {42, 42}, {71, 71}, {95, 95},
+	 {113, 113}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36},
+	 {4, 4}, {-26, -26}, {-56, -56}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-101, -101}, {-79, -79}, {-51, -51}, {-21, -21}, {10, 10}, {41, 41}, {69, 69},
+	 {93, 93}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66},
+	 {37, 37}, {6, 6}, {-24, -24}, {-55, -55}, {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126},
+	 {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23}, {8, 8}, {39, 39},
+	 {68, 68}, {92, 92}, {111, 111}, {123, 123}, {126, 126}, {123, 123}, {111, 111}, {92, 92},
+	 {68, 68}, {39, 39}, {8, 8}, {-23, -23}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117},
+	 {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-55, -55}, {-25, -25}, {6, 6},
+	 {37, 37}, {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112},
+	 {94, 94}, {69, 69}, {41, 41}, {10, 10}, {-21, -21}, {-51, -51}, {-78, -78}, {-101, -101},
+	 {-117, -117}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26},
+	 {4, 4}, {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123},
+	 {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-19, -19}, {-50, -50}, {-77, -77},
+	 {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58},
+	 {-28, -28}, {2, 2}, {34, 34}, {63, 63}, {88, 88}, {108, 108}, {121, 121}, {126, 126},
+	 {124, 124}, {113, 113}, {96, 96}, {72, 72}, {44, 44}, {13, 13}, {-17, -17}, {-48, -48},
+	 {-76, -76}, {-98, -98}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85},
+	 {-60, -60}, {-30, -30}, {1, 1}, {32, 32}, {62, 62}, {87, 87}, {107, 107}, {121, 121},
+	 {126, 126}, {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46}, {15, 15}, {-16, -16},
+	 {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107},
+	 {-87, -87}, {-61, -61}, {-32, -32}, {0, 0}, {30, 30}, {60, 60}, {86, 86}, {106, 106},
+	 {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75}, {48, 48}, {17, 17},
+	 {-14, -14}, {-45, -45}, {-73, -73}, {-96, -96}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121},
+	 {-108, -108}, {-88, -88}, {-63, -63}, {-33, -33}, {-2, -2}, {29, 29}, {58, 58}, {84, 84},
+	 {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {99, 99}, {77, 77}, {49, 49},
+	 {19, 19}, {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126},
+	 {-121, -121}, {-109, -109}, {-89, -89}, {-64, -64}, {-35, -35}, {-4, -4}, {27, 27}, {57, 57},
+	 {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78},
+	 {51, 51}, {21, 21}, {-10, -10}, {-41, -41}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123},
+	 {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-37, -37}, {-6, -6}, {25, 25},
+	 {55, 55}, {82, 82}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102},
+	 {80, 80}, {53, 53}, {22, 22}, {-8, -8}, {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111},
+	 {-123, -123}, {-126, -126}, {-122, -122}, {-111, -111}, {-92, -92}, {-67, -67}, {-39, -39}, {-7, -7},
+	 {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118},
+	 {103, 103}, {81, 81}, {54, 54}, {24, 24}, {-7, -7}, {-38, -38}, {-67, -67}, {-91, -91},
+	 {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-93, -93}, {-69, -69}, {-40, -40},
+	 {-9, -9}, {21, 21}, {52, 52}, {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126},
+	 {118, 118}, {104, 104}, {82, 82}, {56, 56}, {26, 26}, {-5, -5}, {-36, -36}, {-65, -65},
+	 {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70},
+	 {-42, -42}, {-11, -11}, {20, 20}, {50, 50}, {77, 77}, {100, 100}, {116, 116}, {125, 125},
+	 {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28}, {-3, -3}, {-34, -34},
+	 {-64, -64}, {-89, -89}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96},
+	 {-72, -72}, {-44, -44}, {-13, -13}, {18, 18}, {48, 48}, {76, 76}, {99, 99}, {115, 115},
+	 {125, 125}, {126, 126}, {120, 120}, {106, 106}, {85, 85}, {59, 59}, {29, 29}, {-1, -1},
+	 {-33, -33}, {-62, -62}, {-87, -87}, {-107, -107}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114},
+	 {-97, -97}, {-73, -73}, {-45, -45}, {-15, -15}, {16, 16}, {47, 47}, {75, 75}, {98, 98},
+	 {115, 115}, {124, 124}, {126, 126}, {120, 120}, {107, 107}, {86, 86}, {61, 61}, {31, 31},
+	 {0, 0}, {-31, -31}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124},
+	 {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47}, {-16, -16}, {14, 14}, {45, 45}, {73, 73},
+	 {96, 96}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {62, 62},
+	 {33, 33}, {1, 1}, {-29, -29}, {-59, -59}, {-85, -85}, {-105, -105}, {-120, -120}, {-126, -126},
+	 {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76}, {-49, -49}, {-18, -18}, {13, 13}, {43, 43},
+	 {72, 72}, {95, 95}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {109, 109}, {89, 89},
+	 {64, 64}, {35, 35}, {3, 3}, {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119},
+	 {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-78, -78}, {-50, -50}, {-20, -20}, {11, 11},
+	 {42, 42}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {109, 109},
+	 {90, 90}, {65, 65}, {36, 36}, {5, 5}, {-26, -26}, {-56, -56}, {-82, -82}, {-103, -103},
+	 {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22},
+	 {9, 9}, {40, 40}, {69, 69}, {93, 93}, {111, 111}, {123, 123}, {126, 126}, {122, 122},
+	 {110, 110}, {92, 92}, {67, 67}, {38, 38}, {7, 7}, {-24, -24}, {-54, -54}, {-81, -81},
+	 {-102, -102}, {-118, -118}, {-126, -126}, {-125, -125}, {-118, -118}, {-102, -102}, {-81, -81}, {-54, -54},
+	 {-24, -24}, {7, 7}, {38, 38}, {67, 67}, {92, 92}, {110, 110}, {122, 122}, {126, 126},
+	 {123, 123}, {111, 111}, {93, 93}, {68, 68}, {40, 40}, {9, 9}, {-22, -22}, {-52, -52},
+	 {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82},
+	 {-55, -55}, {-25, -25}, {5, 5}, {37, 37}, {66, 66}, {90, 90}, {110, 110}, {122, 122},
+	 {126, 126}, {123, 123}, {112, 112}, {94, 94}, {70, 70}, {42, 42}, {11, 11}, {-20, -20},
+	 {-51, -51}, {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104},
+	 {-83, -83}, {-57, -57}, {-27, -27}, {3, 3}, {35, 35}, {64, 64}, {89, 89}, {109, 109},
+	 {121, 121}, {126, 126}, {124, 124}, {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12},
+	 {-18, -18}, {-49, -49}, {-76, -76}, {-99, -99}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119},
+	 {-105, -105}, {-85, -85}, {-59, -59}, {-29, -29}, {2, 2}, {33, 33}, {62, 62}, {88, 88},
+	 {108, 108}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {96, 96}, {73, 73}, {45, 45},
+	 {14, 14}, {-17, -17}, {-47, -47}, {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126},
+	 {-120, -120}, {-106, -106}, {-86, -86}, {-60, -60}, {-31, -31}, {0, 0}, {31, 31}, {61, 61},
+	 {87, 87}, {107, 107}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {74, 74},
+	 {47, 47}, {16, 16}, {-15, -15}, {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-107, -107}, {-87, -87}, {-62, -62}, {-32, -32}, {-1, -1}, {30, 30},
+	 {59, 59}, {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125}, {115, 115}, {99, 99},
+	 {76, 76}, {48, 48}, {18, 18}, {-13, -13}, {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113},
+	 {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89}, {-63, -63}, {-34, -34}, {-3, -3},
+	 {28, 28}, {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116},
+	 {100, 100}, {77, 77}, {50, 50}, {20, 20}, {-11, -11}, {-42, -42}, {-71, -71}, {-94, -94},
+	 {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36},
+	 {-5, -5}, {26, 26}, {56, 56}, {83, 83}, {104, 104}, {118, 118}, {126, 126}, {125, 125},
+	 {117, 117}, {101, 101}, {79, 79}, {52, 52}, {21, 21}, {-9, -9}, {-41, -41}, {-69, -69},
+	 {-93, -93}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66},
+	 {-38, -38}, {-6, -6}, {24, 24}, {54, 54}, {81, 81}, {103, 103}, {118, 118}, {126, 126},
+	 {125, 125}, {117, 117}, {102, 102}, {80, 80}, {53, 53}, {23, 23}, {-8, -8}, {-39, -39},
+	 {-68, -68}, {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92},
+	 {-68, -68}, {-39, -39}, {-8, -8}, {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117},
+	 {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82}, {55, 55}, {25, 25}, {-6, -6},
+	 {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112},
+	 {-94, -94}, {-70, -70}, {-41, -41}, {-10, -10}, {21, 21}, {51, 51}, {78, 78}, {101, 101},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27},
+	 {-4, -4}, {-35, -35}, {-64, -64}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123},
+	 {-113, -113}, {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12}, {19, 19}, {49, 49}, {77, 77},
+	 {99, 99}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58},
+	 {28, 28}, {-2, -2}, {-34, -34}, {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126},
+	 {-124, -124}, {-113, -113}, {-96, -96}, {-73, -73}, {-44, -44}, {-14, -14}, {17, 17}, {48, 48},
+	 {75, 75}, {98, 98}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {106, 106}, {86, 86},
+	 {60, 60}, {30, 30}, {0, 0}, {-32, -32}, {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120},
+	 {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46}, {-15, -15}, {15, 15},
+	 {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {120, 120}, {107, 107},
+	 {87, 87}, {61, 61}, {32, 32}, {0, 0}, {-30, -30}, {-60, -60}, {-86, -86}, {-106, -106},
+	 {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-98, -98}, {-75, -75}, {-48, -48}, {-17, -17},
+	 {14, 14}, {44, 44}, {73, 73}, {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121},
+	 {108, 108}, {88, 88}, {63, 63}, {34, 34}, {2, 2}, {-28, -28}, {-58, -58}, {-84, -84},
+	 {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49},
+	 {-19, -19}, {12, 12}, {43, 43}, {71, 71}, {95, 95}, {113, 113}, {123, 123}, {126, 126},
+	 {122, 122}, {109, 109}, {90, 90}, {64, 64}, {35, 35}, {4, 4}, {-27, -27}, {-57, -57},
+	 {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-101, -101}, {-78, -78},
+	 {-51, -51}, {-21, -21}, {10, 10}, {41, 41}, {70, 70}, {94, 94}, {112, 112}, {123, 123},
+	 {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37}, {6, 6}, {-25, -25},
+	 {-55, -55}, {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102},
+	 {-80, -80}, {-53, -53}, {-23, -23}, {8, 8}, {39, 39}, {68, 68}, {92, 92}, {111, 111},
+	 {123, 123}, {126, 126}, {122, 122}, {111, 111}, {92, 92}, {68, 68}, {39, 39}, {8, 8},
+	 {-23, -23}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118},
+	 {-103, -103}, {-81, -81}, {-54, -54}, {-24, -24}, {6, 6}, {38, 38}, {66, 66}, {91, 91},
+	 {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {93, 93}, {69, 69}, {41, 41},
+	 {9, 9}, {-21, -21}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126},
+	 {-118, -118}, {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26}, {5, 5}, {36, 36}, {65, 65},
+	 {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {71, 71},
+	 {42, 42}, {11, 11}, {-20, -20}, {-50, -50}, {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125},
+	 {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-28, -28}, {3, 3}, {34, 34},
+	 {63, 63}, {89, 89}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {113, 113}, {96, 96},
+	 {72, 72}, {44, 44}, {13, 13}, {-18, -18}, {-48, -48}, {-76, -76}, {-99, -99}, {-115, -115},
+	 {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-59, -59}, {-30, -30}, {1, 1},
+	 {32, 32}, {62, 62}, {87, 87}, {107, 107}, {121, 121}, {126, 126}, {124, 124}, {114, 114},
+	 {97, 97}, {74, 74}, {46, 46}, {15, 15}, {-16, -16}, {-47, -47}, {-74, -74}, {-98, -98},
+	 {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-87, -87}, {-61, -61}, {-31, -31},
+	 {0, 0}, {31, 31}, {60, 60}, {86, 86}, {106, 106}, {120, 120}, {126, 126}, {124, 124},
+	 {115, 115}, {98, 98}, {75, 75}, {47, 47}, {17, 17}, {-14, -14}, {-45, -45}, {-73, -73},
+	 {-96, -96}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62},
+	 {-33, -33}, {-2, -2}, {29, 29}, {59, 59}, {85, 85}, {105, 105}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {99, 99}, {76, 76}, {49, 49}, {18, 18}, {-12, -12}, {-43, -43},
+	 {-71, -71}, {-95, -95}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89},
+	 {-64, -64}, {-35, -35}, {-3, -3}, {27, 27}, {57, 57}, {83, 83}, {104, 104}, {119, 119},
+	 {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78}, {51, 51}, {20, 20}, {-11, -11},
+	 {-42, -42}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110},
+	 {-90, -90}, {-66, -66}, {-37, -37}, {-5, -5}, {25, 25}, {55, 55}, {82, 82}, {103, 103},
+	 {118, 118}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79}, {52, 52}, {22, 22},
+	 {-9, -9}, {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122},
+	 {-110, -110}, {-92, -92}, {-67, -67}, {-38, -38}, {-7, -7}, {24, 24}, {54, 54}, {81, 81},
+	 {102, 102}, {118, 118}, {125, 125}, {126, 126}, {118, 118}, {102, 102}, {81, 81}, {54, 54},
+	 {24, 24}, {-7, -7}, {-38, -38}, {-67, -67}, {-92, -92}, {-110, -110}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-111, -111}, {-93, -93}, {-69, -69}, {-40, -40}, {-9, -9}, {22, 22}, {52, 52},
+	 {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82},
+	 {56, 56}, {26, 26}, {-5, -5}, {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122},
+	 {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-42, -42}, {-11, -11}, {20, 20},
+	 {50, 50}, {78, 78}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104},
+	 {83, 83}, {57, 57}, {27, 27}, {-3, -3}, {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-95, -95}, {-72, -72}, {-43, -43}, {-13, -13},
+	 {18, 18}, {49, 49}, {76, 76}, {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120},
+	 {105, 105}, {85, 85}, {59, 59}, {29, 29}, {-1, -1}, {-33, -33}, {-62, -62}, {-88, -88},
+	 {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45},
+	 {-14, -14}, {16, 16}, {47, 47}, {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126},
+	 {120, 120}, {106, 106}, {86, 86}, {60, 60}, {31, 31}, {0, 0}, {-31, -31}, {-61, -61},
+	 {-86, -86}, {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75},
+	 {-47, -47}, {-16, -16}, {15, 15}, {45, 45}, {73, 73}, {97, 97}, {114, 114}, {124, 124},
+	 {126, 126}, {121, 121}, {107, 107}, {87, 87}, {62, 62}, {33, 33}, {1, 1}, {-29, -29},
+	 {-59, -59}, {-85, -85}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99},
+	 {-76, -76}, {-48, -48}, {-18, -18}, {13, 13}, {44, 44}, {72, 72}, {96, 96}, {113, 113},
+	 {124, 124}, {126, 126}, {121, 121}, {108, 108}, {89, 89}, {64, 64}, {34, 34}, {3, 3},
+	 {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116},
+	 {-100, -100}, {-77, -77}, {-50, -50}, {-20, -20}, {11, 11}, {42, 42}, {70, 70}, {94, 94},
+	 {112, 112}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36},
+	 {5, 5}, {-26, -26}, {-56, -56}, {-82, -82}, {-104, -104}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-21, -21}, {9, 9}, {40, 40}, {69, 69},
+	 {93, 93}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {67, 67},
+	 {38, 38}, {7, 7}, {-24, -24}, {-54, -54}, {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126},
+	 {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23}, {7, 7}, {39, 39},
+	 {67, 67}, {92, 92}, {111, 111}, {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93},
+	 {68, 68}, {40, 40}, {8, 8}, {-22, -22}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117},
+	 {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82}, {-55, -55}, {-25, -25}, {6, 6},
+	 {37, 37}, {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112},
+	 {94, 94}, {70, 70}, {41, 41}, {10, 10}, {-21, -21}, {-51, -51}, {-78, -78}, {-100, -100},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27},
+	 {4, 4}, {35, 35}, {64, 64}, {89, 89}, {109, 109}, {121, 121}, {126, 126}, {123, 123},
+	 {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-19, -19}, {-49, -49}, {-77, -77},
+	 {-99, -99}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58},
+	 {-29, -29}, {2, 2}, {33, 33}, {63, 63}, {88, 88}, {108, 108}, {121, 121}, {126, 126},
+	 {124, 124}, {114, 114}, {96, 96}, {73, 73}, {45, 45}, {14, 14}, {-17, -17}, {-48, -48},
+	 {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86},
+	 {-60, -60}, {-30, -30}, {0, 0}, {32, 32}, {61, 61}, {87, 87}, {107, 107}, {120, 120},
+	 {126, 126}, {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46}, {16, 16}, {-15, -15},
+	 {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107},
+	 {-87, -87}, {-62, -62}, {-32, -32}, {-1, -1}, {30, 30}, {60, 60}, {85, 85}, {106, 106},
+	 {120, 120}, {126, 126}, {125, 125}, {115, 115}, {98, 98}, {76, 76}, {48, 48}, {17, 17},
+	 {-13, -13}, {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121},
+	 {-108, -108}, {-88, -88}, {-63, -63}, {-34, -34}, {-2, -2}, {28, 28}, {58, 58}, {84, 84},
+	 {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {77, 77}, {50, 50},
+	 {19, 19}, {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-4, -4}, {26, 26}, {56, 56},
+	 {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {78, 78},
+	 {51, 51}, {21, 21}, {-10, -10}, {-41, -41}, {-69, -69}, {-94, -94}, {-112, -112}, {-123, -123},
+	 {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-37, -37}, {-6, -6}, {25, 25},
+	 {55, 55}, {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102},
+	 {80, 80}, {53, 53}, {23, 23}, {-8, -8}, {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111},
+	 {-123, -123}, {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8},
+	 {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118},
+	 {103, 103}, {81, 81}, {55, 55}, {24, 24}, {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91},
+	 {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-93, -93}, {-69, -69}, {-41, -41},
+	 {-10, -10}, {21, 21}, {51, 51}, {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126},
+	 {119, 119}, {104, 104}, {83, 83}, {56, 56}, {26, 26}, {-4, -4}, {-36, -36}, {-65, -65},
+	 {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71},
+	 {-42, -42}, {-11, -11}, {19, 19}, {50, 50}, {77, 77}, {100, 100}, {116, 116}, {125, 125},
+	 {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28}, {-3, -3}, {-34, -34},
+	 {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96},
+	 {-72, -72}, {-44, -44}, {-13, -13}, {18, 18}, {48, 48}, {76, 76}, {99, 99}, {115, 115},
+	 {125, 125}, {126, 126}, {120, 120}, {106, 106}, {85, 85}, {59, 59}, {30, 30}, {-1, -1},
+	 {-32, -32}, {-62, -62}, {-87, -87}, {-107, -107}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114},
+	 {-97, -97}, {-74, -74}, {-46, -46}, {-15, -15}, {16, 16}, {46, 46}, {74, 74}, {97, 97},
+	 {114, 114}, {124, 124}, {126, 126}, {120, 120}, {107, 107}, {87, 87}, {61, 61}, {32, 32},
+	 {0, 0}, {-30, -30}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124},
+	 {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47}, {-17, -17}, {14, 14}, {45, 45}, {73, 73},
+	 {96, 96}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63},
+	 {33, 33}, {2, 2}, {-29, -29}, {-58, -58}, {-85, -85}, {-105, -105}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49}, {-19, -19}, {12, 12}, {43, 43},
+	 {71, 71}, {95, 95}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {109, 109}, {89, 89},
+	 {64, 64}, {35, 35}, {4, 4}, {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119},
+	 {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-78, -78}, {-51, -51}, {-20, -20}, {10, 10},
+	 {41, 41}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110},
+	 {91, 91}, {66, 66}, {37, 37}, {5, 5}, {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103},
+	 {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22},
+	 {9, 9}, {40, 40}, {68, 68}, {93, 93}, {111, 111}, {123, 123}, {126, 126}, {122, 122},
+	 {111, 111}, {92, 92}, {67, 67}, {38, 38}, {7, 7}, {-23, -23}, {-54, -54}, {-80, -80},
+	 {-102, -102}, {-118, -118}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54},
+	 {-24, -24}, {7, 7}, {38, 38}, {67, 67}, {91, 91}, {110, 110}, {122, 122}, {126, 126},
+	 {123, 123}, {111, 111}, {93, 93}, {69, 69}, {40, 40}, {9, 9}, {-22, -22}, {-52, -52},
+	 {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-104, -104}, {-82, -82},
+	 {-56, -56}, {-26, -26}, {5, 5}, {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122},
+	 {126, 126}, {123, 123}, {112, 112}, {94, 94}, {70, 70}, {42, 42}, {11, 11}, {-20, -20},
+	 {-50, -50}, {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105},
+	 {-84, -84}, {-57, -57}, {-27, -27}, {3, 3}, {35, 35}, {64, 64}, {89, 89}, {108, 108},
+	 {121, 121}, {126, 126}, {124, 124}, {113, 113}, {95, 95}, {72, 72}, {44, 44}, {13, 13},
+	 {-18, -18}, {-49, -49}, {-76, -76}, {-99, -99}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120},
+	 {-106, -106}, {-85, -85}, {-59, -59}, {-29, -29}, {1, 1}, {33, 33}, {62, 62}, {88, 88},
+	 {108, 108}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97}, {73, 73}, {45, 45},
+	 {14, 14}, {-16, -16}, {-47, -47}, {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126},
+	 {-120, -120}, {-107, -107}, {-86, -86}, {-61, -61}, {-31, -31}, {0, 0}, {31, 31}, {61, 61},
+	 {86, 86}, {107, 107}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75},
+	 {47, 47}, {16, 16}, {-14, -14}, {-45, -45}, {-73, -73}, {-97, -97}, {-114, -114}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62}, {-33, -33}, {-1, -1}, {29, 29},
+	 {59, 59}, {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125}, {115, 115}, {99, 99},
+	 {76, 76}, {49, 49}, {18, 18}, {-13, -13}, {-44, -44}, {-72, -72}, {-95, -95}, {-113, -113},
+	 {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89}, {-64, -64}, {-35, -35}, {-3, -3},
+	 {27, 27}, {57, 57}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116},
+	 {100, 100}, {78, 78}, {50, 50}, {20, 20}, {-11, -11}, {-42, -42}, {-70, -70}, {-94, -94},
+	 {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36},
+	 {-5, -5}, {26, 26}, {56, 56}, {82, 82}, {104, 104}, {118, 118}, {126, 126}, {125, 125},
+	 {117, 117}, {101, 101}, {79, 79}, {52, 52}, {22, 22}, {-9, -9}, {-40, -40}, {-69, -69},
+	 {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-67, -67},
+	 {-38, -38}, {-7, -7}, {24, 24}, {54, 54}, {81, 81}, {103, 103}, {118, 118}, {126, 126},
+	 {125, 125}, {118, 118}, {102, 102}, {80, 80}, {54, 54}, {23, 23}, {-7, -7}, {-38, -38},
+	 {-67, -67}, {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93},
+	 {-68, -68}, {-40, -40}, {-9, -9}, {22, 22}, {52, 52}, {79, 79}, {101, 101}, {117, 117},
+	 {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82}, {55, 55}, {25, 25}, {-5, -5},
+	 {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112},
+	 {-94, -94}, {-70, -70}, {-41, -41}, {-10, -10}, {20, 20}, {51, 51}, {78, 78}, {100, 100},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27},
+	 {-4, -4}, {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-113, -113}, {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12}, {19, 19}, {49, 49}, {77, 77},
+	 {99, 99}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {85, 85}, {58, 58},
+	 {29, 29}, {-2, -2}, {-33, -33}, {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126},
+	 {-124, -124}, {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45}, {-14, -14}, {17, 17}, {47, 47},
+	 {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120}, {106, 106}, {86, 86},
+	 {60, 60}, {30, 30}, {0, 0}, {-32, -32}, {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120},
+	 {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46}, {-16, -16}, {15, 15},
+	 {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {107, 107},
+	 {87, 87}, {62, 62}, {32, 32}, {1, 1}, {-30, -30}, {-59, -59}, {-85, -85}, {-106, -106},
+	 {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76}, {-48, -48}, {-18, -18},
+	 {13, 13}, {44, 44}, {72, 72}, {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121},
+	 {108, 108}, {89, 89}, {63, 63}, {34, 34}, {3, 3}, {-28, -28}, {-58, -58}, {-84, -84},
+	 {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-77, -77}, {-50, -50},
+	 {-19, -19}, {11, 11}, {42, 42}, {71, 71}, {95, 95}, {113, 113}, {123, 123}, {126, 126},
+	 {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36}, {4, 4}, {-26, -26}, {-56, -56},
+	 {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79},
+	 {-51, -51}, {-21, -21}, {10, 10}, {41, 41}, {69, 69}, {93, 93}, {112, 112}, {123, 123},
+	 {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37}, {6, 6}, {-24, -24},
+	 {-55, -55}, {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102},
+	 {-80, -80}, {-53, -53}, {-23, -23}, {8, 8}, {39, 39}, {68, 68}, {92, 92}, {111, 111},
+	 {123, 123}, {126, 126}, {123, 123}, {111, 111}, {92, 92}, {68, 68}, {39, 39}, {8, 8},
+	 {-23, -23}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118},
+	 {-103, -103}, {-81, -81}, {-55, -55}, {-25, -25}, {6, 6}, {37, 37}, {66, 66}, {91, 91},
+	 {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {69, 69}, {41, 41},
+	 {10, 10}, {-21, -21}, {-51, -51}, {-78, -78}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26}, {4, 4}, {36, 36}, {65, 65},
+	 {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123}, {113, 113}, {95, 95}, {71, 71},
+	 {43, 43}, {12, 12}, {-19, -19}, {-50, -50}, {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125},
+	 {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-28, -28}, {2, 2}, {34, 34},
+	 {63, 63}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {113, 113}, {96, 96},
+	 {72, 72}, {44, 44}, {13, 13}, {-17, -17}, {-48, -48}, {-76, -76}, {-98, -98}, {-115, -115},
+	 {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-60, -60}, {-30, -30}, {1, 1},
+	 {32, 32}, {62, 62}, {87, 87}, {107, 107}, {121, 121}, {126, 126}, {124, 124}, {114, 114},
+	 {97, 97}, {74, 74}, {46, 46}, {15, 15}, {-16, -16}, {-46, -46}, {-74, -74}, {-97, -97},
+	 {-114, -114}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-87, -87}, {-61, -61}, {-32, -32},
+	 {0, 0}, {30, 30}, {60, 60}, {86, 86}, {106, 106}, {120, 120}, {126, 126}, {124, 124},
+	 {115, 115}, {98, 98}, {75, 75}, {48, 48}, {17, 17}, {-14, -14}, {-45, -45}, {-73, -73},
+	 {-96, -96}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-63, -63},
+	 {-33, -33}, {-2, -2}, {29, 29}, {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {99, 99}, {77, 77}, {49, 49}, {19, 19}, {-12, -12}, {-43, -43},
+	 {-71, -71}, {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89},
+	 {-64, -64}, {-35, -35}, {-4, -4}, {27, 27}, {57, 57}, {83, 83}, {104, 104}, {119, 119},
+	 {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78}, {51, 51}, {21, 21}, {-10, -10},
+	 {-41, -41}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110},
+	 {-91, -91}, {-66, -66}, {-37, -37}, {-6, -6}, {25, 25}, {55, 55}, {82, 82}, {103, 103},
+	 {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80}, {53, 53}, {22, 22},
+	 {-8, -8}, {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122},
+	 {-111, -111}, {-92, -92}, {-67, -67}, {-39, -39}, {-7, -7}, {23, 23}, {53, 53}, {80, 80},
+	 {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {81, 81}, {54, 54},
+	 {24, 24}, {-7, -7}, {-38, -38}, {-67, -67}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-112, -112}, {-93, -93}, {-69, -69}, {-40, -40}, {-9, -9}, {21, 21}, {52, 52},
+	 {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {104, 104}, {82, 82},
+	 {56, 56}, {26, 26}, {-5, -5}, {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122},
+	 {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-42, -42}, {-11, -11}, {20, 20},
+	 {50, 50}, {77, 77}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105},
+	 {84, 84}, {58, 58}, {28, 28}, {-3, -3}, {-34, -34}, {-64, -64}, {-89, -89}, {-108, -108},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-72, -72}, {-44, -44}, {-13, -13},
+	 {18, 18}, {48, 48}, {76, 76}, {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120},
+	 {106, 106}, {85, 85}, {59, 59}, {29, 29}, {-1, -1}, {-33, -33}, {-62, -62}, {-87, -87},
+	 {-107, -107}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-73, -73}, {-45, -45},
+	 {-15, -15}, {16, 16}, {47, 47}, {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126},
+	 {120, 120}, {107, 107}, {86, 86}, {61, 61}, {31, 31}, {0, 0}, {-31, -31}, {-60, -60},
+	 {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75},
+	 {-47, -47}, {-16, -16}, {14, 14}, {45, 45}, {73, 73}, {96, 96}, {114, 114}, {124, 124},
+	 {126, 126}, {121, 121}, {108, 108}, {88, 88}, {62, 62}, {33, 33}, {1, 1}, {-29, -29},
+	 {-59, -59}, {-85, -85}, {-105, -105}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99},
+	 {-76, -76}, {-49, -49}, {-18, -18}, {13, 13}, {43, 43}, {72, 72}, {95, 95}, {113, 113},
+	 {124, 124}, {126, 126}, {121, 121}, {109, 109}, {89, 89}, {64, 64}, {35, 35}, {3, 3},
+	 {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116},
+	 {-100, -100}, {-78, -78}, {-50, -50}, {-20, -20}, {11, 11}, {42, 42}, {70, 70}, {94, 94},
+	 {112, 112}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36},
+	 {5, 5}, {-26, -26}, {-56, -56}, {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22}, {9, 9}, {40, 40}, {69, 69},
+	 {93, 93}, {111, 111}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {92, 92}, {67, 67},
+	 {38, 38}, {7, 7}, {-24, -24}, {-54, -54}, {-81, -81}, {-102, -102}, {-118, -118}, {-126, -126},
+	 {-125, -125}, {-118, -118}, {-102, -102}, {-81, -81}, {-54, -54}, {-24, -24}, {7, 7}, {38, 38},
+	 {67, 67}, {92, 92}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93},
+	 {68, 68}, {40, 40}, {9, 9}, {-22, -22}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117},
+	 {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82}, {-55, -55}, {-25, -25}, {5, 5},
+	 {37, 37}, {66, 66}, {90, 90}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112},
+	 {94, 94}, {70, 70}, {42, 42}, {11, 11}, {-20, -20}, {-51, -51}, {-78, -78}, {-100, -100},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27},
+	 {3, 3}, {35, 35}, {64, 64}, {89, 89}, {109, 109}, {121, 121}, {126, 126}, {124, 124},
+	 {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-18, -18}, {-49, -49}, {-76, -76},
+	 {-99, -99}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-85, -85}, {-59, -59},
+	 {-29, -29}, {2, 2}, {33, 33}, {62, 62}, {88, 88}, {108, 108}, {121, 121}, {126, 126},
+	 {124, 124}, {114, 114}, {96, 96}, {73, 73}, {45, 45}, {14, 14}, {-17, -17}, {-47, -47},
+	 {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86},
+	 {-60, -60}, {-31, -31}, {0, 0}, {31, 31}, {61, 61}, {87, 87}, {107, 107}, {120, 120},
+	 {126, 126}, {124, 124}, {115, 115}, {98, 98}, {74, 74}, {47, 47}, {16, 16}, {-15, -15},
+	 {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107},
+	 {-87, -87}, {-62, -62}, {-32, -32}, {-1, -1}, {30, 30}, {59, 59}, {85, 85}, {106, 106},
+	 {120, 120}, {126, 126}, {125, 125}, {115, 115}, {99, 99}, {76, 76}, {48, 48}, {18, 18},
+	 {-13, -13}, {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121},
+	 {-108, -108}, {-89, -89}, {-63, -63}, {-34, -34}, {-3, -3}, {28, 28}, {58, 58}, {84, 84},
+	 {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {77, 77}, {50, 50},
+	 {20, 20}, {-11, -11}, {-42, -42}, {-71, -71}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-5, -5}, {26, 26}, {56, 56},
+	 {83, 83}, {104, 104}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79},
+	 {52, 52}, {21, 21}, {-9, -9}, {-41, -41}, {-69, -69}, {-93, -93}, {-112, -112}, {-123, -123},
+	 {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-38, -38}, {-6, -6}, {24, 24},
+	 {54, 54}, {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102},
+	 {80, 80}, {53, 53}, {23, 23}, {-8, -8}, {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111},
+	 {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8},
+	 {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118},
+	 {103, 103}, {82, 82}, {55, 55}, {25, 25}, {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91},
+	 {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-41, -41},
+	 {-10, -10}, {21, 21}, {51, 51}, {78, 78}, {101, 101}, {116, 116}, {125, 125}, {126, 126},
+	 {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-4, -4}, {-35, -35}, {-64, -64},
+	 {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71},
+	 {-43, -43}, {-12, -12}, {19, 19}, {49, 49}, {77, 77}, {99, 99}, {116, 116}, {125, 125},
+	 {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28}, {-2, -2}, {-34, -34},
+	 {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96},
+	 {-73, -73}, {-44, -44}, {-14, -14}, {17, 17}, {48, 48}, {75, 75}, {98, 98}, {115, 115},
+	 {125, 125}, {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60}, {30, 30}, {0, 0},
+	 {-32, -32}, {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-114, -114},
+	 {-97, -97}, {-74, -74}, {-46, -46}, {-15, -15}, {15, 15}, {46, 46}, {74, 74}, {97, 97},
+	 {114, 114}, {124, 124}, {126, 126}, {120, 120}, {107, 107}, {87, 87}, {61, 61}, {32, 32},
+	 {0, 0}, {-30, -30}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125},
+	 {-115, -115}, {-98, -98}, {-75, -75}, {-48, -48}, {-17, -17}, {14, 14}, {44, 44}, {73, 73},
+	 {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63},
+	 {34, 34}, {2, 2}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49}, {-19, -19}, {12, 12}, {43, 43},
+	 {71, 71}, {95, 95}, {113, 113}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90},
+	 {64, 64}, {35, 35}, {4, 4}, {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119},
+	 {-126, -126}, {-125, -125}, {-116, -116}, {-101, -101}, {-78, -78}, {-51, -51}, {-21, -21}, {10, 10},
+	 {41, 41}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110},
+	 {91, 91}, {66, 66}, {37, 37}, {6, 6}, {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103},
+	 {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23},
+	 {8, 8}, {39, 39}, {68, 68}, {92, 92}, {111, 111}, {123, 123}, {126, 126}, {122, 122},
+	 {111, 111}, {92, 92}, {68, 68}, {39, 39}, {8, 8}, {-23, -23}, {-53, -53}, {-80, -80},
+	 {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54},
+	 {-24, -24}, {6, 6}, {38, 38}, {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126},
+	 {123, 123}, {112, 112}, {93, 93}, {69, 69}, {41, 41}, {9, 9}, {-21, -21}, {-52, -52},
+	 {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-104, -104}, {-83, -83},
+	 {-56, -56}, {-26, -26}, {5, 5}, {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122},
+	 {126, 126}, {123, 123}, {112, 112}, {94, 94}, {71, 71}, {42, 42}, {11, 11}, {-20, -20},
+	 {-50, -50}, {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105},
+	 {-84, -84}, {-58, -58}, {-28, -28}, {3, 3}, {34, 34}, {63, 63}, {89, 89}, {108, 108},
+	 {121, 121}, {126, 126}, {124, 124}, {113, 113}, {96, 96}, {72, 72}, {44, 44}, {13, 13},
+	 {-18, -18}, {-48, -48}, {-76, -76}, {-99, -99}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120},
+	 {-106, -106}, {-85, -85}, {-59, -59}, {-30, -30}, {1, 1}, {32, 32}, {62, 62}, {87, 87},
+	 {107, 107}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46},
+	 {15, 15}, {-16, -16}, {-47, -47}, {-74, -74}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126},
+	 {-120, -120}, {-107, -107}, {-87, -87}, {-61, -61}, {-31, -31}, {0, 0}, {31, 31}, {60, 60},
+	 {86, 86}, {106, 106}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75},
+	 {47, 47}, {17, 17}, {-14, -14}, {-45, -45}, {-73, -73}, {-96, -96}, {-114, -114}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62}, {-33, -33}, {-2, -2}, {29, 29},
+	 {59, 59}, {85, 85}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {99, 99},
+	 {76, 76}, {49, 49}, {18, 18}, {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113},
+	 {-124, -124}, {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89}, {-64, -64}, {-35, -35}, {-3, -3},
+	 {27, 27}, {57, 57}, {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {116, 116},
+	 {100, 100}, {78, 78}, {51, 51}, {20, 20}, {-11, -11}, {-42, -42}, {-70, -70}, {-94, -94},
+	 {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-90, -90}, {-66, -66}, {-37, -37},
+	 {-5, -5}, {25, 25}, {55, 55}, {82, 82}, {103, 103}, {118, 118}, {126, 126}, {125, 125},
+	 {117, 117}, {101, 101}, {79, 79}, {52, 52}, {22, 22}, {-9, -9}, {-40, -40}, {-68, -68},
+	 {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-92, -92}, {-67, -67},
+	 {-38, -38}, {-7, -7}, {24, 24}, {54, 54}, {81, 81}, {102, 102}, {118, 118}, {125, 125},
+	 {126, 126}, {118, 118}, {102, 102}, {81, 81}, {54, 54}, {24, 24}, {-7, -7}, {-38, -38},
+	 {-67, -67}, {-92, -92}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93},
+	 {-69, -69}, {-40, -40}, {-9, -9}, {22, 22}, {52, 52}, {79, 79}, {101, 101}, {117, 117},
+	 {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82}, {56, 56}, {26, 26}, {-5, -5},
+	 {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112},
+	 {-94, -94}, {-70, -70}, {-42, -42}, {-11, -11}, {20, 20}, {50, 50}, {78, 78}, {100, 100},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27},
+	 {-3, -3}, {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-113, -113}, {-95, -95}, {-72, -72}, {-43, -43}, {-12, -12}, {18, 18}, {49, 49}, {76, 76},
+	 {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {105, 105}, {85, 85}, {59, 59},
+	 {29, 29}, {-1, -1}, {-33, -33}, {-62, -62}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126},
+	 {-124, -124}, {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45}, {-14, -14}, {16, 16}, {47, 47},
+	 {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120}, {106, 106}, {86, 86},
+	 {60, 60}, {31, 31}, {0, 0}, {-31, -31}, {-61, -61}, {-86, -86}, {-107, -107}, {-120, -120},
+	 {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47}, {-16, -16}, {15, 15},
+	 {45, 45}, {73, 73}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {107, 107},
+	 {87, 87}, {62, 62}, {33, 33}, {1, 1}, {-29, -29}, {-59, -59}, {-85, -85}, {-106, -106},
+	 {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76}, {-49, -49}, {-18, -18},
+	 {13, 13}, {44, 44}, {72, 72}, {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121},
+	 {108, 108}, {89, 89}, {64, 64}, {34, 34}, {3, 3}, {-28, -28}, {-58, -58}, {-84, -84},
+	 {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-77, -77}, {-50, -50},
+	 {-20, -20}, {11, 11}, {42, 42}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126},
+	 {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36}, {5, 5}, {-26, -26}, {-56, -56},
+	 {-82, -82}, {-104, -104}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79},
+	 {-52, -52}, {-21, -21}, {9, 9}, {40, 40}, {69, 69}, {93, 93}, {112, 112}, {123, 123},
+	 {126, 126}, {122, 122}, {110, 110}, {91, 91}, {67, 67}, {38, 38}, {7, 7}, {-24, -24},
+	 {-54, -54}, {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102},
+	 {-80, -80}, {-53, -53}, {-23, -23}, {7, 7}, {39, 39}, {67, 67}, {92, 92}, {111, 111},
+	 {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93}, {68, 68}, {40, 40}, {8, 8},
+	 {-22, -22}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118},
+	 {-103, -103}, {-82, -82}, {-55, -55}, {-25, -25}, {6, 6}, {37, 37}, {66, 66}, {91, 91},
+	 {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {70, 70}, {41, 41},
+	 {10, 10}, {-21, -21}, {-51, -51}, {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27}, {4, 4}, {35, 35}, {64, 64},
+	 {89, 89}, {109, 109}, {121, 121}, {126, 126}, {123, 123}, {113, 113}, {95, 95}, {71, 71},
+	 {43, 43}, {12, 12}, {-19, -19}, {-49, -49}, {-77, -77}, {-99, -99}, {-116, -116}, {-125, -125},
+	 {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-29, -29}, {2, 2}, {33, 33},
+	 {63, 63}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {96, 96},
+	 {73, 73}, {45, 45}, {14, 14}, {-17, -17}, {-48, -48}, {-75, -75}, {-98, -98}, {-115, -115},
+	 {-124, -124}, {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86}, {-60, -60}, {-30, -30}, {0, 0},
+	 {32, 32}, {61, 61}, {87, 87}, {107, 107}, {120, 120}, {126, 126}, {124, 124}, {114, 114},
+	 {97, 97}, {74, 74}, {46, 46}, {16, 16}, {-15, -15}, {-46, -46}, {-74, -74}, {-97, -97},
+	 {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107}, {-87, -87}, {-62, -62}, {-32, -32},
+	 {-1, -1}, {30, 30}, {60, 60}, {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125},
+	 {115, 115}, {98, 98}, {76, 76}, {48, 48}, {17, 17}, {-13, -13}, {-44, -44}, {-72, -72},
+	 {-96, -96}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-63, -63},
+	 {-34, -34}, {-2, -2}, {28, 28}, {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {100, 100}, {77, 77}, {50, 50}, {19, 19}, {-12, -12}, {-43, -43},
+	 {-71, -71}, {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90},
+	 {-65, -65}, {-36, -36}, {-4, -4}, {26, 26}, {56, 56}, {83, 83}, {104, 104}, {119, 119},
+	 {126, 126}, {125, 125}, {117, 117}, {101, 101}, {78, 78}, {51, 51}, {21, 21}, {-10, -10},
+	 {-41, -41}, {-69, -69}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110},
+	 {-91, -91}, {-66, -66}, {-37, -37}, {-6, -6}, {25, 25}, {55, 55}, {81, 81}, {103, 103},
+	 {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80}, {53, 53}, {23, 23},
+	 {-8, -8}, {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111}, {-123, -123}, {-126, -126}, {-123, -123},
+	 {-111, -111}, {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8}, {23, 23}, {53, 53}, {80, 80},
+	 {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {81, 81}, {55, 55},
+	 {24, 24}, {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-112, -112}, {-93, -93}, {-69, -69}, {-41, -41}, {-10, -10}, {21, 21}, {51, 51},
+	 {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83},
+	 {56, 56}, {26, 26}, {-4, -4}, {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122},
+	 {-126, -126}, {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71}, {-42, -42}, {-11, -11}, {19, 19},
+	 {50, 50}, {77, 77}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105},
+	 {84, 84}, {58, 58}, {28, 28}, {-3, -3}, {-34, -34}, {-63, -63}, {-89, -89}, {-108, -108},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-72, -72}, {-44, -44}, {-13, -13},
+	 {18, 18}, {48, 48}, {76, 76}, {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120},
+	 {106, 106}, {85, 85}, {59, 59}, {30, 30}, {-1, -1}, {-32, -32}, {-62, -62}, {-87, -87},
+	 {-107, -107}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46},
+	 {-15, -15}, {16, 16}, {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126},
+	 {120, 120}, {107, 107}, {87, 87}, {61, 61}, {32, 32}, {0, 0}, {-30, -30}, {-60, -60},
+	 {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75},
+	 {-47, -47}, {-17, -17}, {14, 14}, {45, 45}, {73, 73}, {96, 96}, {114, 114}, {124, 124},
+	 {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63}, {33, 33}, {2, 2}, {-29, -29},
+	 {-58, -58}, {-85, -85}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-99, -99},
+	 {-77, -77}, {-49, -49}, {-19, -19}, {12, 12}, {43, 43}, {71, 71}, {95, 95}, {113, 113},
+	 {124, 124}, {126, 126}, {121, 121}, {109, 109}, {89, 89}, {64, 64}, {35, 35}, {4, 4},
+	 {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116},
+	 {-100, -100}, {-78, -78}, {-51, -51}, {-20, -20}, {10, 10}, {41, 41}, {70, 70}, {94, 94},
+	 {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37},
+	 {5, 5}, {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22}, {9, 9}, {40, 40}, {68, 68},
+	 {93, 93}, {111, 111}, {123, 123}, {126, 126}, {122, 122}, {111, 111}, {92, 92}, {67, 67},
+	 {38, 38}, {7, 7}, {-23, -23}, {-54, -54}, {-80, -80}, {-102, -102}, {-118, -118}, {-125, -125},
+	 {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54}, {-24, -24}, {7, 7}, {38, 38},
+	 {67, 67}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93},
+	 {69, 69}, {40, 40}, {9, 9}, {-22, -22}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117},
+	 {-125, -125}, {-126, -126}, {-118, -118}, {-104, -104}, {-82, -82}, {-56, -56}, {-26, -26}, {5, 5},
+	 {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123}, {112, 112},
+	 {94, 94}, {70, 70}, {42, 42}, {11, 11}, {-20, -20}, {-50, -50}, {-78, -78}, {-100, -100},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-57, -57}, {-27, -27},
+	 {3, 3}, {35, 35}, {64, 64}, {89, 89}, {108, 108}, {121, 121}, {126, 126}, {124, 124},
+	 {113, 113}, {95, 95}, {72, 72}, {44, 44}, {13, 13}, {-18, -18}, {-49, -49}, {-76, -76},
+	 {-99, -99}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-59, -59},
+	 {-29, -29}, {1, 1}, {33, 33}, {62, 62}, {88, 88}, {108, 108}, {121, 121}, {126, 126},
+	 {124, 124}, {114, 114}, {97, 97}, {73, 73}, {45, 45}, {14, 14}, {-16, -16}, {-47, -47},
+	 {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-86, -86},
+	 {-61, -61}, {-31, -31}, {0, 0}, {31, 31}, {61, 61}, {86, 86}, {107, 107}, {120, 120},
+	 {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75}, {47, 47}, {16, 16}, {-14, -14},
+	 {-45, -45}, {-73, -73}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108},
+	 {-88, -88}, {-62, -62}, {-33, -33}, {-1, -1}, {29, 29}, {59, 59}, {85, 85}, {106, 106},
+	 {120, 120}, {126, 126}, {125, 125}, {115, 115}, {99, 99}, {76, 76}, {49, 49}, {18, 18},
+	 {-13, -13}, {-44, -44}, {-72, -72}, {-95, -95}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121},
+	 {-108, -108}, {-89, -89}, {-64, -64}, {-35, -35}, {-3, -3}, {27, 27}, {57, 57}, {84, 84},
+	 {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78}, {50, 50},
+	 {20, 20}, {-11, -11}, {-42, -42}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-5, -5}, {26, 26}, {56, 56},
+	 {82, 82}, {104, 104}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79},
+	 {52, 52}, {22, 22}, {-9, -9}, {-40, -40}, {-69, -69}, {-93, -93}, {-111, -111}, {-123, -123},
+	 {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-67, -67}, {-38, -38}, {-7, -7}, {24, 24},
+	 {54, 54}, {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {118, 118}, {102, 102},
+	 {80, 80}, {54, 54}, {23, 23}, {-7, -7}, {-38, -38}, {-67, -67}, {-92, -92}, {-111, -111},
+	 {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93}, {-68, -68}, {-40, -40}, {-9, -9},
+	 {22, 22}, {52, 52}, {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118},
+	 {103, 103}, {82, 82}, {55, 55}, {25, 25}, {-5, -5}, {-37, -37}, {-66, -66}, {-91, -91},
+	 {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-41, -41},
+	 {-10, -10}, {20, 20}, {51, 51}, {78, 78}, {100, 100}, {116, 116}, {125, 125}, {126, 126},
+	 {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-4, -4}, {-35, -35}, {-64, -64},
+	 {-89, -89}, {-109, -109}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-95, -95}, {-71, -71},
+	 {-43, -43}, {-12, -12}, {19, 19}, {49, 49}, {77, 77}, {99, 99}, {116, 116}, {125, 125},
+	 {126, 126}, {119, 119}, {105, 105}, {85, 85}, {58, 58}, {29, 29}, {-2, -2}, {-33, -33},
+	 {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-96, -96},
+	 {-73, -73}, {-45, -45}, {-14, -14}, {17, 17}, {47, 47}, {75, 75}, {98, 98}, {115, 115},
+	 {124, 124}, {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60}, {30, 30}, {0, 0},
+	 {-32, -32}, {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-114, -114},
+	 {-97, -97}, {-74, -74}, {-46, -46}, {-16, -16}, {15, 15}, {46, 46}, {74, 74}, {97, 97},
+	 {114, 114}, {124, 124}, {126, 126}, {121, 121}, {107, 107}, {87, 87}, {62, 62}, {32, 32},
+	 {1, 1}, {-30, -30}, {-59, -59}, {-85, -85}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125},
+	 {-115, -115}, {-99, -99}, {-76, -76}, {-48, -48}, {-18, -18}, {13, 13}, {44, 44}, {72, 72},
+	 {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63},
+	 {34, 34}, {3, 3}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-100, -100}, {-77, -77}, {-50, -50}, {-19, -19}, {11, 11}, {42, 42},
+	 {71, 71}, {95, 95}, {113, 113}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90},
+	 {65, 65}, {36, 36}, {4, 4}, {-26, -26}, {-56, -56}, {-83, -83}, {-104, -104}, {-119, -119},
+	 {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-51, -51}, {-21, -21}, {10, 10},
+	 {41, 41}, {69, 69}, {93, 93}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110},
+	 {91, 91}, {66, 66}, {37, 37}, {6, 6}, {-24, -24}, {-55, -55}, {-81, -81}, {-103, -103},
+	 {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23},
+	 {8, 8}, {39, 39}, {68, 68}, {92, 92}, {111, 111}, {123, 123}, {126, 126}, {123, 123},
+	 {111, 111}, {92, 92}, {68, 68}, {39, 39}, {8, 8}, {-23, -23}, {-53, -53}, {-80, -80},
+	 {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-55, -55},
+	 {-25, -25}, {6, 6}, {37, 37}, {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126},
+	 {123, 123}, {112, 112}, {94, 94}, {69, 69}, {41, 41}, {10, 10}, {-21, -21}, {-51, -51},
+	 {-78, -78}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83},
+	 {-56, -56}, {-26, -26}, {4, 4}, {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122},
+	 {126, 126}, {123, 123}, {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-19, -19},
+	 {-50, -50}, {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105},
+	 {-84, -84}, {-58, -58}, {-28, -28}, {2, 2}, {34, 34}, {63, 63}, {88, 88}, {108, 108},
+	 {121, 121}, {126, 126}, {124, 124}, {113, 113}, {96, 96}, {72, 72}, {44, 44}, {13, 13},
+	 {-17, -17}, {-48, -48}, {-76, -76}, {-98, -98}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120},
+	 {-106, -106}, {-85, -85}, {-60, -60}, {-30, -30}, {1, 1}, {32, 32}, {62, 62}, {87, 87},
+	 {107, 107}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46},
+	 {15, 15}, {-16, -16}, {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126},
+	 {-120, -120}, {-107, -107}, {-87, -87}, {-61, -61}, {-32, -32}, {0, 0}, {30, 30}, {60, 60},
+	 {86, 86}, {106, 106}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75},
+	 {48, 48}, {17, 17}, {-14, -14}, {-45, -45}, {-73, -73}, {-96, -96}, {-114, -114}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-63, -63}, {-33, -33}, {-2, -2}, {29, 29},
+	 {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {99, 99},
+	 {77, 77}, {49, 49}, {19, 19}, {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113},
+	 {-123, -123}, {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89}, {-64, -64}, {-35, -35}, {-4, -4},
+	 {27, 27}, {57, 57}, {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {116, 116},
+	 {100, 100}, {78, 78}, {51, 51}, {21, 21}, {-10, -10}, {-41, -41}, {-70, -70}, {-94, -94},
+	 {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-37, -37},
+	 {-6, -6}, {25, 25}, {55, 55}, {82, 82}, {103, 103}, {118, 118}, {126, 126}, {125, 125},
+	 {117, 117}, {102, 102}, {80, 80}, {53, 53}, {22, 22}, {-8, -8}, {-40, -40}, {-68, -68},
+	 {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-111, -111}, {-92, -92}, {-67, -67},
+	 {-39, -39}, {-7, -7}, {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125},
+	 {126, 126}, {118, 118}, {103, 103}, {81, 81}, {54, 54}, {24, 24}, {-7, -7}, {-38, -38},
+	 {-67, -67}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-93, -93},
+	 {-69, -69}, {-40, -40}, {-9, -9}, {21, 21}, {52, 52}, {79, 79}, {101, 101}, {117, 117},
+	 {125, 125}, {126, 126}, {118, 118}, {104, 104}, {82, 82}, {56, 56}, {26, 26}, {-5, -5},
+	 {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112},
+	 {-94, -94}, {-70, -70}, {-42, -42}, {-11, -11}, {20, 20}, {50, 50}, {77, 77}, {100, 100},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28},
+	 {-3, -3}, {-34, -34}, {-64, -64}, {-89, -89}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-113, -113}, {-96, -96}, {-72, -72}, {-44, -44}, {-13, -13}, {18, 18}, {49, 49}, {76, 76},
+	 {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {106, 106}, {85, 85}, {59, 59},
+	 {29, 29}, {-1, -1}, {-33, -33}, {-62, -62}, {-87, -87}, {-107, -107}, {-121, -121}, {-126, -126},
+	 {-124, -124}, {-114, -114}, {-97, -97}, {-73, -73}, {-45, -45}, {-15, -15}, {16, 16}, {47, 47},
+	 {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120}, {107, 107}, {86, 86},
+	 {61, 61}, {31, 31}, {0, 0}, {-31, -31}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120},
+	 {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47}, {-16, -16}, {14, 14},
+	 {45, 45}, {73, 73}, {96, 96}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {108, 108},
+	 {88, 88}, {62, 62}, {33, 33}, {1, 1}, {-29, -29}, {-59, -59}, {-85, -85}, {-105, -105},
+	 {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76}, {-49, -49}, {-18, -18},
+	 {13, 13}, {43, 43}, {72, 72}, {95, 95}, {113, 113}, {124, 124}, {126, 126}, {121, 121},
+	 {109, 109}, {89, 89}, {64, 64}, {35, 35}, {3, 3}, {-27, -27}, {-57, -57}, {-83, -83},
+	 {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-78, -78}, {-50, -50},
+	 {-20, -20}, {11, 11}, {42, 42}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126},
+	 {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36}, {5, 5}, {-26, -26}, {-56, -56},
+	 {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79},
+	 {-52, -52}, {-22, -22}, {9, 9}, {40, 40}, {69, 69}, {93, 93}, {111, 111}, {123, 123},
+	 {126, 126}, {122, 122}, {110, 110}, {92, 92}, {67, 67}, {38, 38}, {7, 7}, {-24, -24},
+	 {-54, -54}, {-81, -81}, {-102, -102}, {-118, -118}, {-126, -126}, {-125, -125}, {-118, -118}, {-102, -102},
+	 {-81, -81}, {-54, -54}, {-24, -24}, {7, 7}, {38, 38}, {67, 67}, {92, 92}, {110, 110},
+	 {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93}, {68, 68}, {40, 40}, {9, 9},
+	 {-22, -22}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118},
+	 {-103, -103}, {-82, -82}, {-55, -55}, {-25, -25}, {5, 5}, {37, 37}, {66, 66}, {90, 90},
+	 {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {70, 70}, {42, 42},
+	 {11, 11}, {-20, -20}, {-51, -51}, {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27}, {3, 3}, {35, 35}, {64, 64},
+	 {89, 89}, {109, 109}, {121, 121}, {126, 126}, {124, 124}, {113, 113}, {95, 95}, {71, 71},
+	 {43, 43}, {12, 12}, {-18, -18}, {-49, -49}, {-76, -76}, {-99, -99}, {-116, -116}, {-125, -125},
+	 {-126, -126}, {-119, -119}, {-105, -105}, {-85, -85}, {-59, -59}, {-29, -29}, {2, 2}, {33, 33},
+	 {62, 62}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {96, 96},
+	 {73, 73}, {45, 45}, {14, 14}, {-17, -17}, {-47, -47}, {-75, -75}, {-98, -98}, {-115, -115},
+	 {-124, -124}, {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86}, {-60, -60}, {-31, -31}, {0, 0},
+	 {31, 31}, {61, 61}, {87, 87}, {107, 107}, {120, 120}, {126, 126}, {124, 124}, {115, 115},
+	 {98, 98}, {74, 74}, {47, 47}, {16, 16}, {-15, -15}, {-46, -46}, {-74, -74}, {-97, -97},
+	 {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107}, {-87, -87}, {-62, -62}, {-32, -32},
+	 {-1, -1}, {30, 30}, {59, 59}, {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125},
+	 {115, 115}, {99, 99}, {76, 76}, {48, 48}, {18, 18}, {-13, -13}, {-44, -44}, {-72, -72},
+	 {-96, -96}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89}, {-63, -63},
+	 {-34, -34}, {-3, -3}, {28, 28}, {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {100, 100}, {77, 77}, {50, 50}, {19, 19}, {-11, -11}, {-42, -42},
+	 {-71, -71}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90},
+	 {-65, -65}, {-36, -36}, {-5, -5}, {26, 26}, {56, 56}, {83, 83}, {104, 104}, {118, 118},
+	 {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79}, {52, 52}, {21, 21}, {-9, -9},
+	 {-41, -41}, {-69, -69}, {-93, -93}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110},
+	 {-91, -91}, {-67, -67}, {-38, -38}, {-6, -6}, {24, 24}, {54, 54}, {81, 81}, {103, 103},
+	 {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80}, {53, 53}, {23, 23},
+	 {-8, -8}, {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126}, {-123, -123},
+	 {-111, -111}, {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8}, {23, 23}, {53, 53}, {80, 80},
+	 {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82}, {55, 55},
+	 {25, 25}, {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-41, -41}, {-10, -10}, {21, 21}, {51, 51},
+	 {78, 78}, {101, 101}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83},
+	 {57, 57}, {27, 27}, {-4, -4}, {-35, -35}, {-64, -64}, {-90, -90}, {-109, -109}, {-122, -122},
+	 {-126, -126}, {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12}, {19, 19},
+	 {49, 49}, {77, 77}, {99, 99}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105},
+	 {84, 84}, {58, 58}, {28, 28}, {-2, -2}, {-34, -34}, {-63, -63}, {-88, -88}, {-108, -108},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-73, -73}, {-44, -44}, {-14, -14},
+	 {17, 17}, {48, 48}, {75, 75}, {98, 98}, {115, 115}, {125, 125}, {126, 126}, {120, 120},
+	 {106, 106}, {86, 86}, {60, 60}, {30, 30}, {0, 0}, {-32, -32}, {-61, -61}, {-87, -87},
+	 {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46},
+	 {-15, -15}, {15, 15}, {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126},
+	 {120, 120}, {107, 107}, {87, 87}, {61, 61}, {32, 32}, {0, 0}, {-30, -30}, {-60, -60},
+	 {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-98, -98}, {-75, -75},
+	 {-48, -48}, {-17, -17}, {14, 14}, {44, 44}, {73, 73}, {96, 96}, {113, 113}, {124, 124},
+	 {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63}, {34, 34}, {2, 2}, {-28, -28},
+	 {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-99, -99},
+	 {-77, -77}, {-49, -49}, {-19, -19}, {12, 12}, {43, 43}, {71, 71}, {95, 95}, {113, 113},
+	 {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {64, 64}, {35, 35}, {4, 4},
+	 {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116},
+	 {-101, -101}, {-78, -78}, {-51, -51}, {-21, -21}, {10, 10}, {41, 41}, {70, 70}, {94, 94},
+	 {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37},
+	 {6, 6}, {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23}, {8, 8}, {39, 39}, {68, 68},
+	 {92, 92}, {111, 111}, {123, 123}, {126, 126}, {122, 122}, {111, 111}, {92, 92}, {68, 68},
+	 {39, 39}, {8, 8}, {-23, -23}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125},
+	 {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54}, {-24, -24}, {6, 6}, {38, 38},
+	 {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {93, 93},
+	 {69, 69}, {41, 41}, {9, 9}, {-21, -21}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117},
+	 {-125, -125}, {-126, -126}, {-118, -118}, {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26}, {5, 5},
+	 {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123}, {112, 112},
+	 {94, 94}, {71, 71}, {42, 42}, {11, 11}, {-19, -19}, {-50, -50}, {-77, -77}, {-100, -100},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-28, -28},
+	 {3, 3}, {34, 34}, {63, 63}, {89, 89}, {108, 108}, {121, 121}, {126, 126}, {124, 124},
+	 {113, 113}, {96, 96}, {72, 72}, {44, 44}, {13, 13}, {-18, -18}, {-48, -48}, {-76, -76},
+	 {-99, -99}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-59, -59},
+	 {-30, -30}, {1, 1}, {32, 32}, {62, 62}, {87, 87}, {107, 107}, {121, 121}, {126, 126},
+	 {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46}, {15, 15}, {-16, -16}, {-47, -47},
+	 {-74, -74}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-87, -87},
+	 {-61, -61}, {-31, -31}, {0, 0}, {31, 31}, {60, 60}, {86, 86}, {106, 106}, {120, 120},
+	 {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75}, {47, 47}, {17, 17}, {-14, -14},
+	 {-45, -45}, {-73, -73}, {-96, -96}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108},
+	 {-88, -88}, {-62, -62}, {-33, -33}, {-2, -2}, {29, 29}, {59, 59}, {85, 85}, {105, 105},
+	 {119, 119}, {126, 126}, {125, 125}, {116, 116}, {99, 99}, {76, 76}, {49, 49}, {18, 18},
+	 {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121},
+	 {-109, -109}, {-89, -89}, {-64, -64}, {-35, -35}, {-3, -3}, {27, 27}, {57, 57}, {83, 83},
+	 {104, 104}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78}, {51, 51},
+	 {20, 20}, {-11, -11}, {-42, -42}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-110, -110}, {-90, -90}, {-66, -66}, {-37, -37}, {-5, -5}, {25, 25}, {55, 55},
+	 {82, 82}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79},
+	 {52, 52}, {22, 22}, {-9, -9}, {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111}, {-123, -123},
+	 {-126, -126}, {-122, -122}, {-110, -110}, {-92, -92}, {-67, -67}, {-38, -38}, {-7, -7}, {24, 24},
+	 {54, 54}, {81, 81}, {102, 102}, {118, 118}, {125, 125}, {126, 126}, {118, 118}, {102, 102},
+	 {81, 81}, {54, 54}, {24, 24}, {-7, -7}, {-38, -38}, {-67, -67}, {-92, -92}, {-110, -110},
+	 {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93}, {-69, -69}, {-40, -40}, {-9, -9},
+	 {22, 22}, {52, 52}, {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118},
+	 {103, 103}, {82, 82}, {56, 56}, {26, 26}, {-5, -5}, {-36, -36}, {-65, -65}, {-90, -90},
+	 {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-42, -42},
+	 {-11, -11}, {20, 20}, {50, 50}, {78, 78}, {100, 100}, {116, 116}, {125, 125}, {126, 126},
+	 {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-3, -3}, {-35, -35}, {-64, -64},
+	 {-89, -89}, {-109, -109}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-95, -95}, {-72, -72},
+	 {-43, -43}, {-13, -13}, {18, 18}, {49, 49}, {76, 76}, {99, 99}, {115, 115}, {125, 125},
+	 {126, 126}, {120, 120}, {105, 105}, {85, 85}, {59, 59}, {29, 29}, {-1, -1}, {-33, -33},
+	 {-62, -62}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-96, -96},
+	 {-73, -73}, {-45, -45}, {-14, -14}, {16, 16}, {47, 47}, {75, 75}, {98, 98}, {115, 115},
+	 {124, 124}, {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60}, {31, 31}, {0, 0},
+	 {-31, -31}, {-61, -61}, {-86, -86}, {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115},
+	 {-98, -98}, {-75, -75}, {-47, -47}, {-16, -16}, {15, 15}, {45, 45}, {73, 73}, {97, 97},
+	 {114, 114}, {124, 124}, {126, 126}, {121, 121}, {107, 107}, {87, 87}, {62, 62}, {33, 33},
+	 {1, 1}, {-29, -29}, {-59, -59}, {-85, -85}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125},
+	 {-115, -115}, {-99, -99}, {-76, -76}, {-48, -48}, {-18, -18}, {13, 13}, {44, 44}, {72, 72},
+	 {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {89, 89}, {64, 64},
+	 {34, 34}, {3, 3}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-100, -100}, {-77, -77}, {-50, -50}, {-20, -20}, {11, 11}, {42, 42},
+	 {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90},
+	 {65, 65}, {36, 36}, {5, 5}, {-26, -26}, {-56, -56}, {-82, -82}, {-104, -104}, {-118, -118},
+	 {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-21, -21}, {9, 9},
+	 {40, 40}, {69, 69}, {93, 93}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110},
+	 {91, 91}, {67, 67}, {38, 38}, {7, 7}, {-24, -24}, {-54, -54}, {-81, -81}, {-103, -103},
+	 {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23},
+	 {7, 7}, {39, 39}, {67, 67}, {92, 92}, {111, 111}, {122, 122}, {126, 126}, {123, 123},
+	 {111, 111}, {93, 93}, {68, 68}, {40, 40}, {8, 8}, {-22, -22}, {-53, -53}, {-80, -80},
+	 {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82}, {-55, -55},
+	 {-25, -25}, {6, 6}, {37, 37}, {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126},
+	 {123, 123}, {112, 112}, {94, 94}, {70, 70}, {41, 41}, {10, 10}, {-21, -21}, {-51, -51},
+	 {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83},
+	 {-57, -57}, {-27, -27}, {4, 4}, {35, 35}, {64, 64}, {89, 89}, {109, 109}, {121, 121},
+	 {126, 126}, {123, 123}, {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-19, -19},
+	 {-49, -49}, {-77, -77}, {-99, -99}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105},
+	 {-84, -84}, {-58, -58}, {-29, -29}, {2, 2}, {33, 33}, {63, 63}, {88, 88}, {108, 108},
+	 {121, 121}, {126, 126}, {124, 124}, {114, 114}, {96, 96}, {73, 73}, {45, 45}, {14, 14},
+	 {-17, -17}, {-48, -48}, {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120},
+	 {-106, -106}, {-86, -86}, {-60, -60}, {-30, -30}, {0, 0}, {32, 32}, {61, 61}, {87, 87},
+	 {107, 107}, {120, 120}, {126, 126}, {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46},
+	 {16, 16}, {-15, -15}, {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126},
+	 {-121, -121}, {-107, -107}, {-87, -87}, {-61, -61}, {-32, -32}, {-1, -1}, {30, 30}, {60, 60},
+	 {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125}, {115, 115}, {98, 98}, {76, 76},
+	 {48, 48}, {17, 17}, {-13, -13}, {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-63, -63}, {-34, -34}, {-2, -2}, {28, 28},
+	 {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100},
+	 {77, 77}, {50, 50}, {19, 19}, {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113},
+	 {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-4, -4},
+	 {26, 26}, {56, 56}, {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {117, 117},
+	 {101, 101}, {78, 78}, {51, 51}, {21, 21}, {-10, -10}, {-41, -41}, {-69, -69}, {-94, -94},
+	 {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-37, -37},
+	 {-6, -6}, {25, 25}, {55, 55}, {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125},
+	 {117, 117}, {102, 102}, {80, 80}, {53, 53}, {23, 23}, {-8, -8}, {-39, -39}, {-68, -68},
+	 {-92, -92}, {-111, -111}, {-123, -123}, {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92}, {-68, -68},
+	 {-39, -39}, {-8, -8}, {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125},
+	 {126, 126}, {118, 118}, {103, 103}, {81, 81}, {55, 55}, {24, 24}, {-6, -6}, {-37, -37},
+	 {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-93, -93},
+	 {-69, -69}, {-41, -41}, {-10, -10}, {21, 21}, {51, 51}, {79, 79}, {101, 101}, {117, 117},
+	 {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {56, 56}, {26, 26}, {-4, -4},
+	 {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-113, -113},
+	 {-95, -95}, {-71, -71}, {-42, -42}, {-11, -11}, {19, 19}, {50, 50}, {77, 77}, {100, 100},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28},
+	 {-3, -3}, {-34, -34}, {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-113, -113}, {-96, -96}, {-72, -72}, {-44, -44}, {-13, -13}, {18, 18}, {48, 48}, {76, 76},
+	 {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {106, 106}, {85, 85}, {59, 59},
+	 {30, 30}, {-1, -1}, {-32, -32}, {-62, -62}, {-87, -87}, {-107, -107}, {-121, -121}, {-126, -126},
+	 {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46}, {-15, -15}, {16, 16}, {46, 46},
+	 {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {120, 120}, {107, 107}, {87, 87},
+	 {61, 61}, {32, 32}, {0, 0}, {-30, -30}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120},
+	 {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47}, {-17, -17}, {14, 14},
+	 {45, 45}, {73, 73}, {96, 96}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {108, 108},
+	 {88, 88}, {63, 63}, {33, 33}, {2, 2}, {-29, -29}, {-58, -58}, {-85, -85}, {-105, -105},
+	 {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49}, {-19, -19},
+	 {12, 12}, {43, 43}, {71, 71}, {95, 95}, {113, 113}, {124, 124}, {126, 126}, {121, 121},
+	 {109, 109}, {89, 89}, {64, 64}, {35, 35}, {4, 4}, {-27, -27}, {-57, -57}, {-83, -83},
+	 {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-78, -78}, {-51, -51},
+	 {-20, -20}, {10, 10}, {41, 41}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126},
+	 {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37}, {5, 5}, {-25, -25}, {-55, -55},
+	 {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79},
+	 {-52, -52}, {-22, -22}, {9, 9}, {40, 40}, {68, 68}, {93, 93}, {111, 111}, {123, 123},
+	 {126, 126}, {122, 122}, {111, 111}, {92, 92}, {67, 67}, {38, 38}, {7, 7}, {-23, -23},
+	 {-54, -54}, {-80, -80}, {-102, -102}, {-118, -118}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103},
+	 {-81, -81}, {-54, -54}, {-24, -24}, {7, 7}, {38, 38}, {67, 67}, {91, 91}, {110, 110},
+	 {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93}, {69, 69}, {40, 40}, {9, 9},
+	 {-22, -22}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118},
+	 {-104, -104}, {-82, -82}, {-56, -56}, {-26, -26}, {5, 5}, {36, 36}, {65, 65}, {90, 90},
+	 {109, 109}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {70, 70}, {42, 42},
+	 {11, 11}, {-20, -20}, {-50, -50}, {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-105, -105}, {-84, -84}, {-57, -57}, {-27, -27}, {3, 3}, {35, 35}, {64, 64},
+	 {89, 89}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {113, 113}, {95, 95}, {72, 72},
+	 {44, 44}, {13, 13}, {-18, -18}, {-49, -49}, {-76, -76}, {-99, -99}, {-115, -115}, {-125, -125},
+	 {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-59, -59}, {-29, -29}, {1, 1}, {33, 33},
+	 {62, 62}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97},
+	 {73, 73}, {45, 45}, {14, 14}, {-16, -16}, {-47, -47}, {-75, -75}, {-98, -98}, {-115, -115},
+	 {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-86, -86}, {-61, -61}, {-31, -31}, {0, 0},
+	 {31, 31}, {61, 61}, {86, 86}, {107, 107}, {120, 120}, {126, 126}, {124, 124}, {115, 115},
+	 {98, 98}, {75, 75}, {47, 47}, {16, 16}, {-14, -14}, {-45, -45}, {-73, -73}, {-97, -97},
+	 {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62}, {-33, -33},
+	 {-1, -1}, {29, 29}, {59, 59}, {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125},
+	 {115, 115}, {99, 99}, {76, 76}, {49, 49}, {18, 18}, {-13, -13}, {-44, -44}, {-72, -72},
+	 {-95, -95}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89}, {-64, -64},
+	 {-35, -35}, {-3, -3}, {27, 27}, {57, 57}, {84, 84}, {105, 105}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {100, 100}, {78, 78}, {50, 50}, {20, 20}, {-11, -11}, {-42, -42},
+	 {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90},
+	 {-65, -65}, {-36, -36}, {-5, -5}, {26, 26}, {56, 56}, {82, 82}, {104, 104}, {118, 118},
+	 {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79}, {52, 52}, {22, 22}, {-9, -9},
+	 {-40, -40}, {-69, -69}, {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110},
+	 {-91, -91}, {-67, -67}, {-38, -38}, {-7, -7}, {24, 24}, {54, 54}, {81, 81}, {103, 103},
+	 {118, 118}, {126, 126}, {125, 125}, {118, 118}, {102, 102}, {80, 80}, {54, 54}, {23, 23},
+	 {-7, -7}, {-38, -38}, {-67, -67}, {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126}, {-123, -123},
+	 {-111, -111}, {-93, -93}, {-68, -68}, {-40, -40}, {-9, -9}, {22, 22}, {52, 52}, {79, 79},
+	 {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82}, {55, 55},
+	 {25, 25}, {-5, -5}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-41, -41}, {-10, -10}, {20, 20}, {51, 51},
+	 {78, 78}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83},
+	 {57, 57}, {27, 27}, {-4, -4}, {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109}, {-121, -121},
+	 {-126, -126}, {-124, -124}, {-113, -113}, {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12}, {19, 19},
+	 {49, 49}, {77, 77}, {99, 99}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105},
+	 {85, 85}, {58, 58}, {29, 29}, {-2, -2}, {-33, -33}, {-63, -63}, {-88, -88}, {-108, -108},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45}, {-14, -14},
+	 {17, 17}, {47, 47}, {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120},
+	 {106, 106}, {86, 86}, {60, 60}, {30, 30}, {0, 0}, {-32, -32}, {-61, -61}, {-87, -87},
+	 {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46},
+	 {-16, -16}, {15, 15}, {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126},
+	 {121, 121}, {107, 107}, {87, 87}, {62, 62}, {32, 32}, {1, 1}, {-30, -30}, {-59, -59},
+	 {-85, -85}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76},
+	 {-48, -48}, {-18, -18}, {13, 13}, {44, 44}, {72, 72}, {96, 96}, {113, 113}, {124, 124},
+	 {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63}, {34, 34}, {3, 3}, {-28, -28},
+	 {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100},
+	 {-77, -77}, {-50, -50}, {-19, -19}, {11, 11}, {42, 42}, {71, 71}, {95, 95}, {113, 113},
+	 {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36}, {4, 4},
+	 {-26, -26}, {-56, -56}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-117, -117},
+	 {-101, -101}, {-79, -79}, {-51, -51}, {-21, -21}, {10, 10}, {41, 41}, {69, 69}, {93, 93},
+	 {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37},
+	 {6, 6}, {-24, -24}, {-55, -55}, {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23}, {8, 8}, {39, 39}, {68, 68},
+	 {92, 92}, {111, 111}, {123, 123}, {126, 126}, {123, 123}, {111, 111}, {92, 92}, {68, 68},
+	 {39, 39}, {8, 8}, {-23, -23}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125},
+	 {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-55, -55}, {-25, -25}, {6, 6}, {37, 37},
+	 {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94},
+	 {69, 69}, {41, 41}, {10, 10}, {-21, -21}, {-51, -51}, {-78, -78}, {-101, -101}, {-117, -117},
+	 {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26}, {4, 4},
+	 {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123}, {113, 113},
+	 {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-19, -19}, {-50, -50}, {-77, -77}, {-100, -100},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-28, -28},
+	 {2, 2}, {34, 34}, {63, 63}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124},
+	 {113, 113}, {96, 96}, {72, 72}, {44, 44}, {13, 13}, {-17, -17}, {-48, -48}, {-76, -76},
+	 {-98, -98}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-60, -60},
+	 {-30, -30}, {1, 1}, {32, 32}, {61, 61}, {87, 87}, {107, 107}, {121, 121}, {126, 126},
+	 {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46}, {15, 15}, {-16, -16}, {-46, -46},
+	 {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-87, -87},
+	 {-61, -61}, {-32, -32}, {0, 0}, {30, 30}, {60, 60}, {86, 86}, {106, 106}, {120, 120},
+	 {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75}, {48, 48}, {17, 17}, {-14, -14},
+	 {-45, -45}, {-73, -73}, {-96, -96}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108},
+	 {-88, -88}, {-63, -63}, {-33, -33}, {-2, -2}, {29, 29}, {58, 58}, {84, 84}, {105, 105},
+	 {119, 119}, {126, 126}, {125, 125}, {116, 116}, {99, 99}, {77, 77}, {49, 49}, {19, 19},
+	 {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126}, {-121, -121},
+	 {-109, -109}, {-89, -89}, {-64, -64}, {-35, -35}, {-4, -4}, {27, 27}, {57, 57}, {83, 83},
+	 {104, 104}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {78, 78}, {51, 51},
+	 {21, 21}, {-10, -10}, {-41, -41}, {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-37, -37}, {-6, -6}, {25, 25}, {55, 55},
+	 {82, 82}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80},
+	 {53, 53}, {22, 22}, {-8, -8}, {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111}, {-123, -123},
+	 {-126, -126}, {-122, -122}, {-111, -111}, {-92, -92}, {-67, -67}, {-39, -39}, {-7, -7}, {23, 23},
+	 {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103},
+	 {81, 81}, {54, 54}, {24, 24}, {-7, -7}, {-38, -38}, {-67, -67}, {-91, -91}, {-110, -110},
+	 {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93}, {-69, -69}, {-40, -40}, {-9, -9},
+	 {21, 21}, {52, 52}, {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118},
+	 {104, 104}, {82, 82}, {56, 56}, {26, 26}, {-5, -5}, {-36, -36}, {-65, -65}, {-90, -90},
+	 {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-42, -42},
+	 {-11, -11}, {20, 20}, {50, 50}, {77, 77}, {100, 100}, {116, 116}, {125, 125}, {126, 126},
+	 {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28}, {-3, -3}, {-34, -34}, {-64, -64},
+	 {-89, -89}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-72, -72},
+	 {-44, -44}, {-13, -13}, {18, 18}, {49, 49}, {76, 76}, {99, 99}, {115, 115}, {125, 125},
+	 {126, 126}, {120, 120}, {106, 106}, {85, 85}, {59, 59}, {29, 29}, {-1, -1}, {-33, -33},
+	 {-62, -62}, {-87, -87}, {-107, -107}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97},
+	 {-73, -73}, {-45, -45}, {-15, -15}, {16, 16}, {47, 47}, {75, 75}, {98, 98}, {115, 115},
+	 {124, 124}, {126, 126}, {120, 120}, {107, 107}, {86, 86}, {61, 61}, {31, 31}, {0, 0},
+	 {-31, -31}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115},
+	 {-98, -98}, {-75, -75}, {-47, -47}, {-16, -16}, {14, 14}, {45, 45}, {73, 73}, {96, 96},
+	 {114, 114}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {62, 62}, {33, 33},
+	 {1, 1}, {-29, -29}, {-59, -59}, {-85, -85}, {-105, -105}, {-120, -120}, {-126, -126}, {-125, -125},
+	 {-115, -115}, {-99, -99}, {-76, -76}, {-49, -49}, {-18, -18}, {13, 13}, {43, 43}, {72, 72},
+	 {95, 95}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {109, 109}, {89, 89}, {64, 64},
+	 {35, 35}, {3, 3}, {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-100, -100}, {-78, -78}, {-50, -50}, {-20, -20}, {11, 11}, {42, 42},
+	 {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90},
+	 {65, 65}, {36, 36}, {5, 5}, {-26, -26}, {-56, -56}, {-82, -82}, {-103, -103}, {-118, -118},
+	 {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22}, {9, 9},
+	 {40, 40}, {69, 69}, {93, 93}, {111, 111}, {123, 123}, {126, 126}, {122, 122}, {110, 110},
+	 {92, 92}, {67, 67}, {38, 38}, {7, 7}, {-24, -24}, {-54, -54}, {-81, -81}, {-102, -102},
+	 {-118, -118}, {-126, -126}, {-125, -125}, {-118, -118}, {-102, -102}, {-81, -81}, {-54, -54}, {-24, -24},
+	 {7, 7}, {38, 38}, {67, 67}, {92, 92}, {110, 110}, {122, 122}, {126, 126}, {123, 123},
+	 {111, 111}, {93, 93}, {68, 68}, {40, 40}, {9, 9}, {-22, -22}, {-52, -52}, {-79, -79},
+	 {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82}, {-55, -55},
+	 {-25, -25}, {5, 5}, {37, 37}, {66, 66}, {90, 90}, {110, 110}, {122, 122}, {126, 126},
+	 {123, 123}, {112, 112}, {94, 94}, {70, 70}, {42, 42}, {11, 11}, {-20, -20}, {-51, -51},
+	 {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83},
+	 {-57, -57}, {-27, -27}, {3, 3}, {35, 35}, {64, 64}, {89, 89}, {109, 109}, {121, 121},
+	 {126, 126}, {124, 124}, {113, 113}, {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-18, -18},
+	 {-49, -49}, {-76, -76}, {-99, -99}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105},
+	 {-85, -85}, {-59, -59}, {-29, -29}, {2, 2}, {33, 33}, {62, 62}, {88, 88}, {108, 108},
+	 {121, 121}, {126, 126}, {124, 124}, {114, 114}, {96, 96}, {73, 73}, {45, 45}, {14, 14},
+	 {-17, -17}, {-47, -47}, {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120},
+	 {-106, -106}, {-86, -86}, {-60, -60}, {-31, -31}, {0, 0}, {31, 31}, {61, 61}, {87, 87},
+	 {107, 107}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {74, 74}, {47, 47},
+	 {16, 16}, {-15, -15}, {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126},
+	 {-121, -121}, {-107, -107}, {-87, -87}, {-62, -62}, {-32, -32}, {-1, -1}, {30, 30}, {59, 59},
+	 {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125}, {115, 115}, {99, 99}, {76, 76},
+	 {48, 48}, {18, 18}, {-13, -13}, {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89}, {-63, -63}, {-34, -34}, {-3, -3}, {28, 28},
+	 {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100},
+	 {77, 77}, {50, 50}, {20, 20}, {-11, -11}, {-42, -42}, {-71, -71}, {-94, -94}, {-112, -112},
+	 {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-5, -5},
+	 {26, 26}, {56, 56}, {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {117, 117},
+	 {101, 101}, {79, 79}, {52, 52}, {21, 21}, {-9, -9}, {-41, -41}, {-69, -69}, {-93, -93},
+	 {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-38, -38},
+	 {-6, -6}, {24, 24}, {54, 54}, {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125},
+	 {117, 117}, {102, 102}, {80, 80}, {53, 53}, {23, 23}, {-8, -8}, {-39, -39}, {-68, -68},
+	 {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92}, {-68, -68},
+	 {-39, -39}, {-8, -8}, {23, 23}, {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125},
+	 {126, 126}, {118, 118}, {103, 103}, {82, 82}, {55, 55}, {25, 25}, {-6, -6}, {-37, -37},
+	 {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94},
+	 {-70, -70}, {-41, -41}, {-10, -10}, {21, 21}, {51, 51}, {78, 78}, {101, 101}, {116, 116},
+	 {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-4, -4},
+	 {-35, -35}, {-64, -64}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-113, -113},
+	 {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12}, {19, 19}, {50, 50}, {77, 77}, {99, 99},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28},
+	 {-2, -2}, {-34, -34}, {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-113, -113}, {-96, -96}, {-73, -73}, {-44, -44}, {-14, -14}, {17, 17}, {48, 48}, {75, 75},
+	 {98, 98}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60},
+	 {30, 30}, {0, 0}, {-32, -32}, {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120}, {-126, -126},
+	 {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46}, {-15, -15}, {15, 15}, {46, 46},
+	 {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {120, 120}, {107, 107}, {87, 87},
+	 {61, 61}, {32, 32}, {0, 0}, {-30, -30}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120},
+	 {-126, -126}, {-125, -125}, {-115, -115}, {-98, -98}, {-75, -75}, {-48, -48}, {-17, -17}, {14, 14},
+	 {44, 44}, {73, 73}, {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108},
+	 {88, 88}, {63, 63}, {34, 34}, {2, 2}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105},
+	 {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49}, {-19, -19},
+	 {12, 12}, {43, 43}, {71, 71}, {95, 95}, {113, 113}, {123, 123}, {126, 126}, {122, 122},
+	 {109, 109}, {90, 90}, {64, 64}, {35, 35}, {4, 4}, {-27, -27}, {-57, -57}, {-83, -83},
+	 {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-101, -101}, {-78, -78}, {-51, -51},
+	 {-21, -21}, {10, 10}, {41, 41}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126},
+	 {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37}, {6, 6}, {-25, -25}, {-55, -55},
+	 {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80},
+	 {-53, -53}, {-23, -23}, {8, 8}, {39, 39}, {68, 68}, {92, 92}, {111, 111}, {123, 123},
+	 {126, 126}, {122, 122}, {111, 111}, {92, 92}, {68, 68}, {39, 39}, {8, 8}, {-23, -23},
+	 {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103},
+	 {-81, -81}, {-54, -54}, {-24, -24}, {6, 6}, {38, 38}, {67, 67}, {91, 91}, {110, 110},
+	 {122, 122}, {126, 126}, {123, 123}, {112, 112}, {93, 93}, {69, 69}, {41, 41}, {9, 9},
+	 {-21, -21}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118},
+	 {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26}, {5, 5}, {36, 36}, {65, 65}, {90, 90},
+	 {109, 109}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {71, 71}, {42, 42},
+	 {11, 11}, {-19, -19}, {-50, -50}, {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-28, -28}, {3, 3}, {34, 34}, {63, 63},
+	 {89, 89}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {113, 113}, {96, 96}, {72, 72},
+	 {44, 44}, {13, 13}, {-18, -18}, {-48, -48}, {-76, -76}, {-99, -99}, {-115, -115}, {-125, -125},
+	 {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-59, -59}, {-30, -30}, {1, 1}, {32, 32},
+	 {62, 62}, {87, 87}, {107, 107}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97},
+	 {74, 74}, {46, 46}, {15, 15}, {-16, -16}, {-47, -47}, {-74, -74}, {-98, -98}, {-115, -115},
+	 {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-87, -87}, {-61, -61}, {-31, -31}, {0, 0},
+	 {31, 31}, {60, 60}, {86, 86}, {106, 106}, {120, 120}, {126, 126}, {124, 124}, {115, 115},
+	 {98, 98}, {75, 75}, {47, 47}, {17, 17}, {-14, -14}, {-45, -45}, {-73, -73}, {-96, -96},
+	 {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62}, {-33, -33},
+	 {-2, -2}, {29, 29}, {59, 59}, {85, 85}, {105, 105}, {119, 119}, {126, 126}, {125, 125},
+	 {116, 116}, {99, 99}, {76, 76}, {49, 49}, {18, 18}, {-12, -12}, {-43, -43}, {-71, -71},
+	 {-95, -95}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89}, {-64, -64},
+	 {-35, -35}, {-3, -3}, {27, 27}, {57, 57}, {83, 83}, {104, 104}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {100, 100}, {78, 78}, {51, 51}, {20, 20}, {-11, -11}, {-42, -42},
+	 {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-90, -90},
+	 {-66, -66}, {-37, -37}, {-5, -5}, {25, 25}, {55, 55}, {82, 82}, {103, 103}, {118, 118},
+	 {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79}, {52, 52}, {22, 22}, {-9, -9},
+	 {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110},
+	 {-92, -92}, {-67, -67}, {-38, -38}, {-7, -7}, {24, 24}, {54, 54}, {81, 81}, {102, 102},
+	 {118, 118}, {125, 125}, {126, 126}, {118, 118}, {102, 102}, {81, 81}, {54, 54}, {24, 24},
+	 {-7, -7}, {-38, -38}, {-67, -67}, {-92, -92}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123},
+	 {-111, -111}, {-93, -93}, {-69, -69}, {-40, -40}, {-9, -9}, {22, 22}, {52, 52}, {79, 79},
+	 {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {82, 82}, {56, 56},
+	 {26, 26}, {-5, -5}, {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-42, -42}, {-11, -11}, {20, 20}, {50, 50},
+	 {78, 78}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83},
+	 {57, 57}, {27, 27}, {-3, -3}, {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109}, {-121, -121},
+	 {-126, -126}, {-124, -124}, {-113, -113}, {-95, -95}, {-72, -72}, {-43, -43}, {-12, -12}, {18, 18},
+	 {49, 49}, {76, 76}, {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {105, 105},
+	 {85, 85}, {59, 59}, {29, 29}, {-2, -2}, {-33, -33}, {-62, -62}, {-88, -88}, {-108, -108},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45}, {-14, -14},
+	 {16, 16}, {47, 47}, {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120},
+	 {106, 106}, {86, 86}, {60, 60}, {31, 31}, {0, 0}, {-31, -31}, {-61, -61}, {-86, -86},
+	 {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47},
+	 {-16, -16}, {15, 15}, {45, 45}, {73, 73}, {97, 97}, {114, 114}, {124, 124}, {126, 126},
+	 {121, 121}, {107, 107}, {87, 87}, {62, 62}, {33, 33}, {1, 1}, {-29, -29}, {-59, -59},
+	 {-85, -85}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76},
+	 {-48, -48}, {-18, -18}, {13, 13}, {44, 44}, {72, 72}, {96, 96}, {113, 113}, {124, 124},
+	 {126, 126}, {121, 121}, {108, 108}, {89, 89}, {64, 64}, {34, 34}, {3, 3}, {-28, -28},
+	 {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100},
+	 {-77, -77}, {-50, -50}, {-20, -20}, {11, 11}, {42, 42}, {70, 70}, {94, 94}, {112, 112},
+	 {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36}, {5, 5},
+	 {-26, -26}, {-56, -56}, {-82, -82}, {-104, -104}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117},
+	 {-101, -101}, {-79, -79}, {-52, -52}, {-21, -21}, {9, 9}, {40, 40}, {69, 69}, {93, 93},
+	 {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {67, 67}, {38, 38},
+	 {7, 7}, {-24, -24}, {-54, -54}, {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23}, {7, 7}, {39, 39}, {67, 67},
+	 {92, 92}, {111, 111}, {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93}, {68, 68},
+	 {40, 40}, {8, 8}, {-22, -22}, {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125},
+	 {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82}, {-55, -55}, {-25, -25}, {6, 6}, {37, 37},
+	 {66, 66}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94},
+	 {70, 70}, {41, 41}, {10, 10}, {-21, -21}, {-51, -51}, {-78, -78}, {-100, -100}, {-116, -116},
+	 {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27}, {4, 4},
+	 {35, 35}, {64, 64}, {89, 89}, {109, 109}, {121, 121}, {126, 126}, {123, 123}, {113, 113},
+	 {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-19, -19}, {-49, -49}, {-77, -77}, {-99, -99},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-29, -29},
+	 {2, 2}, {33, 33}, {63, 63}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124},
+	 {114, 114}, {96, 96}, {73, 73}, {45, 45}, {14, 14}, {-17, -17}, {-48, -48}, {-75, -75},
+	 {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86}, {-60, -60},
+	 {-30, -30}, {0, 0}, {32, 32}, {61, 61}, {87, 87}, {107, 107}, {120, 120}, {126, 126},
+	 {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46}, {16, 16}, {-15, -15}, {-46, -46},
+	 {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107}, {-87, -87},
+	 {-62, -62}, {-32, -32}, {-1, -1}, {30, 30}, {60, 60}, {85, 85}, {106, 106}, {120, 120},
+	 {126, 126}, {125, 125}, {115, 115}, {98, 98}, {76, 76}, {48, 48}, {17, 17}, {-13, -13},
+	 {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108},
+	 {-88, -88}, {-63, -63}, {-34, -34}, {-2, -2}, {28, 28}, {58, 58}, {84, 84}, {105, 105},
+	 {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {77, 77}, {50, 50}, {19, 19},
+	 {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126}, {-122, -122},
+	 {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-4, -4}, {26, 26}, {56, 56}, {83, 83},
+	 {104, 104}, {119, 119}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {78, 78}, {51, 51},
+	 {21, 21}, {-10, -10}, {-41, -41}, {-69, -69}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-110, -110}, {-91, -91}, {-66, -66}, {-37, -37}, {-6, -6}, {25, 25}, {55, 55},
+	 {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80},
+	 {53, 53}, {23, 23}, {-8, -8}, {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111}, {-123, -123},
+	 {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8}, {23, 23},
+	 {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103},
+	 {81, 81}, {55, 55}, {24, 24}, {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110},
+	 {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-93, -93}, {-69, -69}, {-41, -41}, {-10, -10},
+	 {21, 21}, {51, 51}, {79, 79}, {101, 101}, {117, 117}, {125, 125}, {126, 126}, {119, 119},
+	 {104, 104}, {83, 83}, {56, 56}, {26, 26}, {-4, -4}, {-36, -36}, {-65, -65}, {-90, -90},
+	 {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71}, {-42, -42},
+	 {-11, -11}, {19, 19}, {50, 50}, {77, 77}, {100, 100}, {116, 116}, {125, 125}, {126, 126},
+	 {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28}, {-3, -3}, {-34, -34}, {-63, -63},
+	 {-89, -89}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-72, -72},
+	 {-44, -44}, {-13, -13}, {18, 18}, {48, 48}, {76, 76}, {99, 99}, {115, 115}, {125, 125},
+	 {126, 126}, {120, 120}, {106, 106}, {85, 85}, {59, 59}, {30, 30}, {-1, -1}, {-32, -32},
+	 {-62, -62}, {-87, -87}, {-107, -107}, {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97},
+	 {-74, -74}, {-46, -46}, {-15, -15}, {16, 16}, {47, 47}, {74, 74}, {97, 97}, {114, 114},
+	 {124, 124}, {126, 126}, {120, 120}, {107, 107}, {87, 87}, {61, 61}, {32, 32}, {0, 0},
+	 {-31, -31}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115},
+	 {-98, -98}, {-75, -75}, {-47, -47}, {-17, -17}, {14, 14}, {45, 45}, {73, 73}, {96, 96},
+	 {114, 114}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63}, {33, 33},
+	 {2, 2}, {-29, -29}, {-58, -58}, {-85, -85}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125},
+	 {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49}, {-19, -19}, {12, 12}, {43, 43}, {71, 71},
+	 {95, 95}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {109, 109}, {89, 89}, {64, 64},
+	 {35, 35}, {4, 4}, {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-100, -100}, {-78, -78}, {-51, -51}, {-20, -20}, {10, 10}, {41, 41},
+	 {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91},
+	 {66, 66}, {37, 37}, {5, 5}, {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103}, {-118, -118},
+	 {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22}, {9, 9},
+	 {40, 40}, {68, 68}, {93, 93}, {111, 111}, {123, 123}, {126, 126}, {122, 122}, {111, 111},
+	 {92, 92}, {67, 67}, {38, 38}, {7, 7}, {-23, -23}, {-54, -54}, {-80, -80}, {-102, -102},
+	 {-118, -118}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54}, {-24, -24},
+	 {7, 7}, {38, 38}, {67, 67}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123},
+	 {111, 111}, {93, 93}, {69, 69}, {40, 40}, {9, 9}, {-22, -22}, {-52, -52}, {-79, -79},
+	 {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-104, -104}, {-82, -82}, {-56, -56},
+	 {-26, -26}, {5, 5}, {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126},
+	 {123, 123}, {112, 112}, {94, 94}, {70, 70}, {42, 42}, {11, 11}, {-20, -20}, {-50, -50},
+	 {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84},
+	 {-57, -57}, {-27, -27}, {3, 3}, {34, 34}, {64, 64}, {89, 89}, {108, 108}, {121, 121},
+	 {126, 126}, {124, 124}, {113, 113}, {95, 95}, {72, 72}, {44, 44}, {13, 13}, {-18, -18},
+	 {-49, -49}, {-76, -76}, {-99, -99}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106},
+	 {-85, -85}, {-59, -59}, {-29, -29}, {1, 1}, {33, 33}, {62, 62}, {88, 88}, {108, 108},
+	 {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97}, {73, 73}, {45, 45}, {14, 14},
+	 {-16, -16}, {-47, -47}, {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120},
+	 {-107, -107}, {-86, -86}, {-61, -61}, {-31, -31}, {0, 0}, {31, 31}, {61, 61}, {86, 86},
+	 {107, 107}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75}, {47, 47},
+	 {16, 16}, {-14, -14}, {-45, -45}, {-73, -73}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126},
+	 {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62}, {-33, -33}, {-1, -1}, {29, 29}, {59, 59},
+	 {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125}, {115, 115}, {99, 99}, {76, 76},
+	 {49, 49}, {18, 18}, {-13, -13}, {-44, -44}, {-72, -72}, {-95, -95}, {-113, -113}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-108, -108}, {-89, -89}, {-64, -64}, {-34, -34}, {-3, -3}, {27, 27},
+	 {57, 57}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100},
+	 {78, 78}, {50, 50}, {20, 20}, {-11, -11}, {-42, -42}, {-70, -70}, {-94, -94}, {-112, -112},
+	 {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-5, -5},
+	 {26, 26}, {56, 56}, {82, 82}, {104, 104}, {118, 118}, {126, 126}, {125, 125}, {117, 117},
+	 {101, 101}, {79, 79}, {52, 52}, {22, 22}, {-9, -9}, {-40, -40}, {-69, -69}, {-93, -93},
+	 {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91}, {-67, -67}, {-38, -38},
+	 {-7, -7}, {24, 24}, {54, 54}, {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125},
+	 {118, 118}, {102, 102}, {80, 80}, {54, 54}, {23, 23}, {-7, -7}, {-38, -38}, {-67, -67},
+	 {-92, -92}, {-111, -111}, {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93}, {-68, -68},
+	 {-40, -40}, {-9, -9}, {22, 22}, {52, 52}, {79, 79}, {101, 101}, {117, 117}, {125, 125},
+	 {126, 126}, {118, 118}, {103, 103}, {82, 82}, {55, 55}, {25, 25}, {-5, -5}, {-37, -37},
+	 {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94},
+	 {-70, -70}, {-41, -41}, {-10, -10}, {20, 20}, {51, 51}, {78, 78}, {100, 100}, {116, 116},
+	 {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-4, -4},
+	 {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113},
+	 {-95, -95}, {-71, -71}, {-43, -43}, {-12, -12}, {19, 19}, {49, 49}, {77, 77}, {99, 99},
+	 {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {85, 85}, {58, 58}, {29, 29},
+	 {-2, -2}, {-33, -33}, {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45}, {-14, -14}, {17, 17}, {47, 47}, {75, 75},
+	 {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60},
+	 {30, 30}, {0, 0}, {-32, -32}, {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120}, {-126, -126},
+	 {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46}, {-16, -16}, {15, 15}, {46, 46},
+	 {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {107, 107}, {87, 87},
+	 {62, 62}, {32, 32}, {1, 1}, {-30, -30}, {-59, -59}, {-85, -85}, {-106, -106}, {-120, -120},
+	 {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76}, {-48, -48}, {-18, -18}, {13, 13},
+	 {44, 44}, {72, 72}, {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108},
+	 {88, 88}, {63, 63}, {34, 34}, {3, 3}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105},
+	 {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-77, -77}, {-50, -50}, {-19, -19},
+	 {11, 11}, {42, 42}, {71, 71}, {95, 95}, {113, 113}, {123, 123}, {126, 126}, {122, 122},
+	 {109, 109}, {90, 90}, {65, 65}, {36, 36}, {4, 4}, {-26, -26}, {-56, -56}, {-83, -83},
+	 {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-51, -51},
+	 {-21, -21}, {10, 10}, {41, 41}, {69, 69}, {93, 93}, {112, 112}, {123, 123}, {126, 126},
+	 {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37}, {6, 6}, {-24, -24}, {-55, -55},
+	 {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80},
+	 {-53, -53}, {-23, -23}, {8, 8}, {39, 39}, {68, 68}, {92, 92}, {111, 111}, {123, 123},
+	 {126, 126}, {123, 123}, {111, 111}, {92, 92}, {68, 68}, {39, 39}, {8, 8}, {-23, -23},
+	 {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103},
+	 {-81, -81}, {-55, -55}, {-25, -25}, {6, 6}, {37, 37}, {66, 66}, {91, 91}, {110, 110},
+	 {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {69, 69}, {41, 41}, {10, 10},
+	 {-21, -21}, {-51, -51}, {-78, -78}, {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-119, -119},
+	 {-104, -104}, {-83, -83}, {-56, -56}, {-26, -26}, {4, 4}, {36, 36}, {65, 65}, {90, 90},
+	 {109, 109}, {122, 122}, {126, 126}, {123, 123}, {113, 113}, {95, 95}, {71, 71}, {43, 43},
+	 {12, 12}, {-19, -19}, {-50, -50}, {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-28, -28}, {2, 2}, {34, 34}, {63, 63},
+	 {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {113, 113}, {96, 96}, {72, 72},
+	 {44, 44}, {13, 13}, {-17, -17}, {-48, -48}, {-76, -76}, {-98, -98}, {-115, -115}, {-125, -125},
+	 {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-60, -60}, {-30, -30}, {1, 1}, {32, 32},
+	 {62, 62}, {87, 87}, {107, 107}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97},
+	 {74, 74}, {46, 46}, {15, 15}, {-16, -16}, {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114},
+	 {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-87, -87}, {-61, -61}, {-32, -32}, {0, 0},
+	 {30, 30}, {60, 60}, {86, 86}, {106, 106}, {120, 120}, {126, 126}, {124, 124}, {115, 115},
+	 {98, 98}, {75, 75}, {48, 48}, {17, 17}, {-14, -14}, {-45, -45}, {-73, -73}, {-96, -96},
+	 {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-63, -63}, {-33, -33},
+	 {-2, -2}, {29, 29}, {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125},
+	 {116, 116}, {99, 99}, {77, 77}, {49, 49}, {19, 19}, {-12, -12}, {-43, -43}, {-71, -71},
+	 {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89}, {-64, -64},
+	 {-35, -35}, {-4, -4}, {27, 27}, {57, 57}, {83, 83}, {104, 104}, {119, 119}, {126, 126},
+	 {125, 125}, {116, 116}, {100, 100}, {78, 78}, {51, 51}, {21, 21}, {-10, -10}, {-41, -41},
+	 {-70, -70}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91},
+	 {-66, -66}, {-37, -37}, {-6, -6}, {25, 25}, {55, 55}, {82, 82}, {103, 103}, {118, 118},
+	 {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80}, {53, 53}, {22, 22}, {-8, -8},
+	 {-40, -40}, {-68, -68}, {-93, -93}, {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-111, -111},
+	 {-92, -92}, {-67, -67}, {-39, -39}, {-7, -7}, {23, 23}, {53, 53}, {80, 80}, {102, 102},
+	 {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {81, 81}, {54, 54}, {24, 24},
+	 {-7, -7}, {-38, -38}, {-67, -67}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123},
+	 {-112, -112}, {-93, -93}, {-69, -69}, {-40, -40}, {-9, -9}, {21, 21}, {52, 52}, {79, 79},
+	 {101, 101}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {104, 104}, {82, 82}, {56, 56},
+	 {26, 26}, {-5, -5}, {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-42, -42}, {-11, -11}, {20, 20}, {50, 50},
+	 {77, 77}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {84, 84},
+	 {58, 58}, {28, 28}, {-3, -3}, {-34, -34}, {-64, -64}, {-89, -89}, {-108, -108}, {-121, -121},
+	 {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-72, -72}, {-44, -44}, {-13, -13}, {18, 18},
+	 {49, 49}, {76, 76}, {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {106, 106},
+	 {85, 85}, {59, 59}, {29, 29}, {-1, -1}, {-33, -33}, {-62, -62}, {-87, -87}, {-107, -107},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-73, -73}, {-45, -45}, {-15, -15},
+	 {16, 16}, {47, 47}, {75, 75}, {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120},
+	 {107, 107}, {86, 86}, {61, 61}, {31, 31}, {0, 0}, {-31, -31}, {-60, -60}, {-86, -86},
+	 {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47},
+	 {-16, -16}, {14, 14}, {45, 45}, {73, 73}, {96, 96}, {114, 114}, {124, 124}, {126, 126},
+	 {121, 121}, {108, 108}, {88, 88}, {62, 62}, {33, 33}, {1, 1}, {-29, -29}, {-59, -59},
+	 {-85, -85}, {-105, -105}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76},
+	 {-49, -49}, {-18, -18}, {13, 13}, {43, 43}, {72, 72}, {95, 95}, {113, 113}, {124, 124},
+	 {126, 126}, {121, 121}, {109, 109}, {89, 89}, {64, 64}, {35, 35}, {3, 3}, {-27, -27},
+	 {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100},
+	 {-78, -78}, {-50, -50}, {-20, -20}, {11, 11}, {42, 42}, {70, 70}, {94, 94}, {112, 112},
+	 {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {65, 65}, {36, 36}, {5, 5},
+	 {-26, -26}, {-56, -56}, {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117},
+	 {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22}, {9, 9}, {40, 40}, {69, 69}, {93, 93},
+	 {111, 111}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {92, 92}, {67, 67}, {38, 38},
+	 {7, 7}, {-24, -24}, {-54, -54}, {-81, -81}, {-102, -102}, {-118, -118}, {-126, -126}, {-125, -125},
+	 {-118, -118}, {-102, -102}, {-81, -81}, {-54, -54}, {-24, -24}, {7, 7}, {38, 38}, {67, 67},
+	 {92, 92}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93}, {68, 68},
+	 {40, 40}, {9, 9}, {-22, -22}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125},
+	 {-126, -126}, {-118, -118}, {-103, -103}, {-82, -82}, {-55, -55}, {-25, -25}, {5, 5}, {37, 37},
+	 {66, 66}, {90, 90}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94},
+	 {70, 70}, {42, 42}, {11, 11}, {-20, -20}, {-51, -51}, {-78, -78}, {-100, -100}, {-116, -116},
+	 {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27}, {4, 4},
+	 {35, 35}, {64, 64}, {89, 89}, {109, 109}, {121, 121}, {126, 126}, {124, 124}, {113, 113},
+	 {95, 95}, {71, 71}, {43, 43}, {12, 12}, {-18, -18}, {-49, -49}, {-76, -76}, {-99, -99},
+	 {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-85, -85}, {-59, -59}, {-29, -29},
+	 {2, 2}, {33, 33}, {62, 62}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124},
+	 {114, 114}, {96, 96}, {73, 73}, {45, 45}, {14, 14}, {-17, -17}, {-47, -47}, {-75, -75},
+	 {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86}, {-60, -60},
+	 {-31, -31}, {0, 0}, {31, 31}, {61, 61}, {87, 87}, {107, 107}, {120, 120}, {126, 126},
+	 {124, 124}, {115, 115}, {98, 98}, {74, 74}, {47, 47}, {16, 16}, {-15, -15}, {-46, -46},
+	 {-74, -74}, {-97, -97}, {-114, -114}, {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107}, {-87, -87},
+	 {-62, -62}, {-32, -32}, {-1, -1}, {30, 30}, {59, 59}, {85, 85}, {106, 106}, {120, 120},
+	 {126, 126}, {125, 125}, {115, 115}, {99, 99}, {76, 76}, {48, 48}, {18, 18}, {-13, -13},
+	 {-44, -44}, {-72, -72}, {-96, -96}, {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108},
+	 {-89, -89}, {-63, -63}, {-34, -34}, {-3, -3}, {28, 28}, {58, 58}, {84, 84}, {105, 105},
+	 {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100}, {77, 77}, {50, 50}, {19, 19},
+	 {-11, -11}, {-42, -42}, {-71, -71}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122},
+	 {-109, -109}, {-90, -90}, {-65, -65}, {-36, -36}, {-5, -5}, {26, 26}, {56, 56}, {83, 83},
+	 {104, 104}, {119, 119}, {126, 126}, {125, 125}, {117, 117}, {101, 101}, {79, 79}, {52, 52},
+	 {21, 21}, {-9, -9}, {-41, -41}, {-69, -69}, {-93, -93}, {-112, -112}, {-123, -123}, {-126, -126},
+	 {-122, -122}, {-110, -110}, {-91, -91}, {-67, -67}, {-38, -38}, {-6, -6}, {24, 24}, {54, 54},
+	 {81, 81}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80},
+	 {53, 53}, {23, 23}, {-8, -8}, {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111}, {-122, -122},
+	 {-126, -126}, {-123, -123}, {-111, -111}, {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8}, {23, 23},
+	 {53, 53}, {80, 80}, {102, 102}, {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103},
+	 {82, 82}, {55, 55}, {25, 25}, {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110},
+	 {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94}, {-70, -70}, {-41, -41}, {-10, -10},
+	 {21, 21}, {51, 51}, {78, 78}, {101, 101}, {116, 116}, {125, 125}, {126, 126}, {119, 119},
+	 {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-4, -4}, {-35, -35}, {-64, -64}, {-90, -90},
+	 {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71}, {-43, -43},
+	 {-12, -12}, {19, 19}, {50, 50}, {77, 77}, {99, 99}, {116, 116}, {125, 125}, {126, 126},
+	 {119, 119}, {105, 105}, {84, 84}, {58, 58}, {28, 28}, {-2, -2}, {-34, -34}, {-63, -63},
+	 {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-73, -73},
+	 {-44, -44}, {-14, -14}, {17, 17}, {48, 48}, {75, 75}, {98, 98}, {115, 115}, {125, 125},
+	 {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60}, {30, 30}, {0, 0}, {-32, -32},
+	 {-61, -61}, {-87, -87}, {-107, -107}, {-120, -120}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97},
+	 {-74, -74}, {-46, -46}, {-15, -15}, {15, 15}, {46, 46}, {74, 74}, {97, 97}, {114, 114},
+	 {124, 124}, {126, 126}, {120, 120}, {107, 107}, {87, 87}, {61, 61}, {32, 32}, {0, 0},
+	 {-30, -30}, {-60, -60}, {-86, -86}, {-106, -106}, {-120, -120}, {-126, -126}, {-125, -125}, {-115, -115},
+	 {-98, -98}, {-75, -75}, {-48, -48}, {-17, -17}, {14, 14}, {44, 44}, {73, 73}, {96, 96},
+	 {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108}, {88, 88}, {63, 63}, {34, 34},
+	 {2, 2}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125},
+	 {-116, -116}, {-99, -99}, {-77, -77}, {-49, -49}, {-19, -19}, {12, 12}, {43, 43}, {71, 71},
+	 {95, 95}, {113, 113}, {123, 123}, {126, 126}, {122, 122}, {109, 109}, {90, 90}, {64, 64},
+	 {35, 35}, {4, 4}, {-27, -27}, {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126},
+	 {-125, -125}, {-116, -116}, {-101, -101}, {-78, -78}, {-51, -51}, {-21, -21}, {10, 10}, {41, 41},
+	 {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91},
+	 {66, 66}, {37, 37}, {6, 6}, {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103}, {-118, -118},
+	 {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80}, {-53, -53}, {-23, -23}, {8, 8},
+	 {39, 39}, {68, 68}, {92, 92}, {111, 111}, {123, 123}, {126, 126}, {122, 122}, {111, 111},
+	 {92, 92}, {68, 68}, {39, 39}, {8, 8}, {-23, -23}, {-53, -53}, {-80, -80}, {-102, -102},
+	 {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54}, {-24, -24},
+	 {6, 6}, {38, 38}, {67, 67}, {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123},
+	 {112, 112}, {93, 93}, {69, 69}, {41, 41}, {9, 9}, {-21, -21}, {-52, -52}, {-79, -79},
+	 {-101, -101}, {-117, -117}, {-125, -125}, {-126, -126}, {-119, -119}, {-104, -104}, {-83, -83}, {-56, -56},
+	 {-26, -26}, {5, 5}, {36, 36}, {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126},
+	 {123, 123}, {112, 112}, {94, 94}, {71, 71}, {42, 42}, {11, 11}, {-20, -20}, {-50, -50},
+	 {-77, -77}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84},
+	 {-58, -58}, {-28, -28}, {3, 3}, {34, 34}, {63, 63}, {89, 89}, {108, 108}, {121, 121},
+	 {126, 126}, {124, 124}, {113, 113}, {96, 96}, {72, 72}, {44, 44}, {13, 13}, {-18, -18},
+	 {-48, -48}, {-76, -76}, {-99, -99}, {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106},
+	 {-85, -85}, {-59, -59}, {-30, -30}, {1, 1}, {32, 32}, {62, 62}, {87, 87}, {107, 107},
+	 {121, 121}, {126, 126}, {124, 124}, {114, 114}, {97, 97}, {74, 74}, {46, 46}, {15, 15},
+	 {-16, -16}, {-47, -47}, {-74, -74}, {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120},
+	 {-107, -107}, {-87, -87}, {-61, -61}, {-31, -31}, {0, 0}, {31, 31}, {60, 60}, {86, 86},
+	 {106, 106}, {120, 120}, {126, 126}, {124, 124}, {115, 115}, {98, 98}, {75, 75}, {47, 47},
+	 {17, 17}, {-14, -14}, {-45, -45}, {-73, -73}, {-96, -96}, {-114, -114}, {-124, -124}, {-126, -126},
+	 {-121, -121}, {-108, -108}, {-88, -88}, {-62, -62}, {-33, -33}, {-2, -2}, {29, 29}, {59, 59},
+	 {85, 85}, {105, 105}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {99, 99}, {76, 76},
+	 {49, 49}, {18, 18}, {-12, -12}, {-43, -43}, {-71, -71}, {-95, -95}, {-113, -113}, {-124, -124},
+	 {-126, -126}, {-121, -121}, {-109, -109}, {-89, -89}, {-64, -64}, {-35, -35}, {-3, -3}, {27, 27},
+	 {57, 57}, {83, 83}, {104, 104}, {119, 119}, {126, 126}, {125, 125}, {116, 116}, {100, 100},
+	 {78, 78}, {51, 51}, {20, 20}, {-11, -11}, {-42, -42}, {-70, -70}, {-94, -94}, {-112, -112},
+	 {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-90, -90}, {-66, -66}, {-37, -37}, {-5, -5},
+	 {25, 25}, {55, 55}, {82, 82}, {103, 103}, {118, 118}, {126, 126}, {125, 125}, {117, 117},
+	 {101, 101}, {79, 79}, {52, 52}, {22, 22}, {-9, -9}, {-40, -40}, {-68, -68}, {-93, -93},
+	 {-111, -111}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-92, -92}, {-67, -67}, {-38, -38},
+	 {-7, -7}, {24, 24}, {54, 54}, {81, 81}, {102, 102}, {118, 118}, {125, 125}, {126, 126},
+	 {118, 118}, {102, 102}, {81, 81}, {54, 54}, {24, 24}, {-7, -7}, {-38, -38}, {-67, -67},
+	 {-92, -92}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123}, {-111, -111}, {-93, -93}, {-69, -69},
+	 {-40, -40}, {-9, -9}, {22, 22}, {52, 52}, {79, 79}, {101, 101}, {117, 117}, {125, 125},
+	 {126, 126}, {118, 118}, {103, 103}, {82, 82}, {56, 56}, {26, 26}, {-5, -5}, {-36, -36},
+	 {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126}, {-123, -123}, {-112, -112}, {-94, -94},
+	 {-70, -70}, {-42, -42}, {-11, -11}, {20, 20}, {50, 50}, {78, 78}, {100, 100}, {116, 116},
+	 {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {57, 57}, {27, 27}, {-3, -3},
+	 {-35, -35}, {-64, -64}, {-89, -89}, {-109, -109}, {-121, -121}, {-126, -126}, {-124, -124}, {-113, -113},
+	 {-95, -95}, {-72, -72}, {-43, -43}, {-12, -12}, {18, 18}, {49, 49}, {76, 76}, {99, 99},
+	 {115, 115}, {125, 125}, {126, 126}, {120, 120}, {105, 105}, {85, 85}, {59, 59}, {29, 29},
+	 {-1, -1}, {-33, -33}, {-62, -62}, {-88, -88}, {-108, -108}, {-121, -121}, {-126, -126}, {-124, -124},
+	 {-114, -114}, {-96, -96}, {-73, -73}, {-45, -45}, {-14, -14}, {16, 16}, {47, 47}, {75, 75},
+	 {98, 98}, {115, 115}, {124, 124}, {126, 126}, {120, 120}, {106, 106}, {86, 86}, {60, 60},
+	 {31, 31}, {0, 0}, {-31, -31}, {-61, -61}, {-86, -86}, {-107, -107}, {-120, -120}, {-126, -126},
+	 {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47}, {-16, -16}, {15, 15}, {45, 45},
+	 {73, 73}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {121, 121}, {107, 107}, {87, 87},
+	 {62, 62}, {33, 33}, {1, 1}, {-29, -29}, {-59, -59}, {-85, -85}, {-106, -106}, {-120, -120},
+	 {-126, -126}, {-125, -125}, {-115, -115}, {-99, -99}, {-76, -76}, {-49, -49}, {-18, -18}, {13, 13},
+	 {44, 44}, {72, 72}, {96, 96}, {113, 113}, {124, 124}, {126, 126}, {121, 121}, {108, 108},
+	 {89, 89}, {64, 64}, {34, 34}, {3, 3}, {-28, -28}, {-58, -58}, {-84, -84}, {-105, -105},
+	 {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100}, {-77, -77}, {-50, -50}, {-20, -20},
+	 {11, 11}, {42, 42}, {70, 70}, {94, 94}, {112, 112}, {123, 123}, {126, 126}, {122, 122},
+	 {109, 109}, {90, 90}, {65, 65}, {36, 36}, {5, 5}, {-26, -26}, {-56, -56}, {-82, -82},
+	 {-104, -104}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-101, -101}, {-79, -79}, {-52, -52},
+	 {-21, -21}, {9, 9}, {40, 40}, {69, 69}, {93, 93}, {112, 112}, {123, 123}, {126, 126},
+	 {122, 122}, {110, 110}, {91, 91}, {67, 67}, {38, 38}, {7, 7}, {-24, -24}, {-54, -54},
+	 {-81, -81}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117}, {-102, -102}, {-80, -80},
+	 {-53, -53}, {-23, -23}, {7, 7}, {39, 39}, {67, 67}, {92, 92}, {111, 111}, {122, 122},
+	 {126, 126}, {123, 123}, {111, 111}, {93, 93}, {68, 68}, {40, 40}, {8, 8}, {-22, -22},
+	 {-53, -53}, {-80, -80}, {-102, -102}, {-117, -117}, {-125, -125}, {-126, -126}, {-118, -118}, {-103, -103},
+	 {-82, -82}, {-55, -55}, {-25, -25}, {6, 6}, {37, 37}, {66, 66}, {91, 91}, {110, 110},
+	 {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94}, {70, 70}, {41, 41}, {10, 10},
+	 {-21, -21}, {-51, -51}, {-78, -78}, {-100, -100}, {-116, -116}, {-125, -125}, {-126, -126}, {-119, -119},
+	 {-104, -104}, {-83, -83}, {-57, -57}, {-27, -27}, {4, 4}, {35, 35}, {64, 64}, {89, 89},
+	 {109, 109}, {121, 121}, {126, 126}, {123, 123}, {113, 113}, {95, 95}, {71, 71}, {43, 43},
+	 {12, 12}, {-19, -19}, {-49, -49}, {-77, -77}, {-99, -99}, {-116, -116}, {-125, -125}, {-126, -126},
+	 {-119, -119}, {-105, -105}, {-84, -84}, {-58, -58}, {-29, -29}, {2, 2}, {33, 33}, {63, 63},
+	 {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {114, 114}, {96, 96}, {73, 73},
+	 {45, 45}, {14, 14}, {-17, -17}, {-48, -48}, {-75, -75}, {-98, -98}, {-115, -115}, {-124, -124},
+	 {-126, -126}, {-120, -120}, {-106, -106}, {-86, -86}, {-60, -60}, {-30, -30}, {0, 0}, {32, 32},
+	 {61, 61}, {87, 87}, {107, 107}, {120, 120}, {126, 126}, {124, 124}, {114, 114}, {97, 97},
+	 {74, 74}, {46, 46}, {16, 16}, {-15, -15}, {-46, -46}, {-74, -74}, {-97, -97}, {-114, -114},
+	 {-124, -124}, {-126, -126}, {-121, -121}, {-107, -107}, {-87, -87}, {-62, -62}, {-32, -32}, {-1, -1},
+	 {30, 30}, {60, 60}, {85, 85}, {106, 106}, {120, 120}, {126, 126}, {125, 125}, {115, 115},
+	 {98, 98}, {76, 76}, {48, 48}, {17, 17}, {-13, -13}, {-44, -44}, {-72, -72}, {-96, -96},
+	 {-113, -113}, {-124, -124}, {-126, -126}, {-121, -121}, {-108, -108}, {-88, -88}, {-63, -63}, {-34, -34},
+	 {-2, -2}, {28, 28}, {58, 58}, {84, 84}, {105, 105}, {119, 119}, {126, 126}, {125, 125},
+	 {116, 116}, {100, 100}, {77, 77}, {50, 50}, {19, 19}, {-12, -12}, {-43, -43}, {-71, -71},
+	 {-95, -95}, {-113, -113}, {-123, -123}, {-126, -126}, {-122, -122}, {-109, -109}, {-90, -90}, {-65, -65},
+	 {-36, -36}, {-4, -4}, {26, 26}, {56, 56}, {83, 83}, {104, 104}, {119, 119}, {126, 126},
+	 {125, 125}, {117, 117}, {101, 101}, {78, 78}, {51, 51}, {21, 21}, {-10, -10}, {-41, -41},
+	 {-69, -69}, {-94, -94}, {-112, -112}, {-123, -123}, {-126, -126}, {-122, -122}, {-110, -110}, {-91, -91},
+	 {-66, -66}, {-37, -37}, {-6, -6}, {25, 25}, {55, 55}, {81, 81}, {103, 103}, {118, 118},
+	 {126, 126}, {125, 125}, {117, 117}, {102, 102}, {80, 80}, {53, 53}, {23, 23}, {-8, -8},
+	 {-39, -39}, {-68, -68}, {-92, -92}, {-111, -111}, {-123, -123}, {-126, -126}, {-123, -123}, {-111, -111},
+	 {-92, -92}, {-68, -68}, {-39, -39}, {-8, -8}, {23, 23}, {53, 53}, {80, 80}, {102, 102},
+	 {117, 117}, {125, 125}, {126, 126}, {118, 118}, {103, 103}, {81, 81}, {55, 55}, {24, 24},
+	 {-6, -6}, {-37, -37}, {-66, -66}, {-91, -91}, {-110, -110}, {-122, -122}, {-126, -126}, {-123, -123},
+	 {-112, -112}, {-93, -93}, {-69, -69}, {-41, -41}, {-10, -10}, {21, 21}, {51, 51}, {79, 79},
+	 {101, 101}, {117, 117}, {125, 125}, {126, 126}, {119, 119}, {104, 104}, {83, 83}, {56, 56},
+	 {26, 26}, {-4, -4}, {-36, -36}, {-65, -65}, {-90, -90}, {-109, -109}, {-122, -122}, {-126, -126},
+	 {-123, -123}, {-113, -113}, {-95, -95}, {-71, -71}, {-42, -42}, {-11, -11}, {19, 19}, {50, 50},
+	 {77, 77}, {100, 100}, {116, 116}, {125, 125}, {126, 126}, {119, 119}, {105, 105}, {84, 84},
+	 {58, 58}, {28, 28}, {-3, -3}, {-34, -34}, {-63, -63}, {-88, -88}, {-108, -108}, {-121, -121},
+	 {-126, -126}, {-124, -124}, {-113, -113}, {-96, -96}, {-72, -72}, {-44, -44}, {-13, -13}, {18, 18},
+	 {48, 48}, {76, 76}, {99, 99}, {115, 115}, {125, 125}, {126, 126}, {120, 120}, {106, 106},
+	 {85, 85}, {59, 59}, {30, 30}, {-1, -1}, {-32, -32}, {-62, -62}, {-87, -87}, {-107, -107},
+	 {-121, -121}, {-126, -126}, {-124, -124}, {-114, -114}, {-97, -97}, {-74, -74}, {-46, -46}, {-15, -15},
+	 {16, 16}, {46, 46}, {74, 74}, {97, 97}, {114, 114}, {124, 124}, {126, 126}, {120, 120},
+	 {107, 107}, {87, 87}, {61, 61}, {32, 32}, {0, 0}, {-30, -30}, {-60, -60}, {-86, -86},
+	 {-106, -106}, {-120, -120}, {-126, -126}, {-124, -124}, {-115, -115}, {-98, -98}, {-75, -75}, {-47, -47},
+	 {-17, -17}, {14, 14}, {45, 45}, {73, 73}, {96, 96}, {114, 114}, {124, 124}, {126, 126},
+	 {121, 121}, {108, 108}, {88, 88}, {63, 63}, {33, 33}, {2, 2}, {-29, -29}, {-58, -58},
+	 {-85, -85}, {-105, -105}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-99, -99}, {-77, -77},
+	 {-49, -49}, {-19, -19}, {12, 12}, {43, 43}, {71, 71}, {95, 95}, {113, 113}, {124, 124},
+	 {126, 126}, {121, 121}, {109, 109}, {89, 89}, {64, 64}, {35, 35}, {4, 4}, {-27, -27},
+	 {-57, -57}, {-83, -83}, {-104, -104}, {-119, -119}, {-126, -126}, {-125, -125}, {-116, -116}, {-100, -100},
+	 {-78, -78}, {-51, -51}, {-20, -20}, {10, 10}, {41, 41}, {70, 70}, {94, 94}, {112, 112},
+	 {123, 123}, {126, 126}, {122, 122}, {110, 110}, {91, 91}, {66, 66}, {37, 37}, {5, 5},
+	 {-25, -25}, {-55, -55}, {-82, -82}, {-103, -103}, {-118, -118}, {-126, -126}, {-125, -125}, {-117, -117},
+	 {-101, -101}, {-79, -79}, {-52, -52}, {-22, -22}, {9, 9}, {40, 40}, {68, 68}, {93, 93},
+	 {111, 111}, {123, 123}, {126, 126}, {122, 122}, {111, 111}, {92, 92}, {67, 67}, {38, 38},
+	 {7, 7}, {-23, -23}, {-54, -54}, {-80, -80}, {-102, -102}, {-118, -118}, {-125, -125}, {-126, -126},
+	 {-118, -118}, {-103, -103}, {-81, -81}, {-54, -54}, {-24, -24}, {7, 7}, {38, 38}, {67, 67},
+	 {91, 91}, {110, 110}, {122, 122}, {126, 126}, {123, 123}, {111, 111}, {93, 93}, {69, 69},
+	 {40, 40}, {9, 9}, {-22, -22}, {-52, -52}, {-79, -79}, {-101, -101}, {-117, -117}, {-125, -125},
+	 {-126, -126}, {-118, -118}, {-104, -104}, {-82, -82}, {-56, -56}, {-26, -26}, {5, 5}, {36, 36},
+	 {65, 65}, {90, 90}, {109, 109}, {122, 122}, {126, 126}, {123, 123}, {112, 112}, {94, 94},
+	 {70, 70}, {42, 42}, {11, 11}, {-20, -20}, {-50, -50}, {-78, -78}, {-100, -100}, {-116, -116},
+	 {-125, -125}, {-126, -126}, {-119, -119}, {-105, -105}, {-84, -84}, {-57, -57}, {-27, -27}, {3, 3},
+	 {35, 35}, {64, 64}, {89, 89}, {108, 108}, {121, 121}, {126, 126}, {124, 124}, {113, 113},
+	 {95, 95}, {72, 72}, {44, 44}, {13, 13}, {-18, -18}, {-49, -49}, {-76, -76}, {-99, -99},
+	 {-115, -115}, {-125, -125}, {-126, -126}, {-120, -120}, {-106, -106}, {-85, -85}, {-59, -59}, {-29, -29},
+	 {1, 1}, {33, 33}, {62, 62}, {88, 88}, {108, 108}, {121, 121}, {126, 126}, {124, 124},
+	 {114, 114}, {97, 97}, {73, 73}, {45, 45}, {14, 14}, {-16, -16}, {-47, -47}, {-75, -75},
+	 {-98, -98}, {-115, -115}, {-124, -124}, {-126, -126}, {-120, -120}, {-107, -107}, {-86, -86}, {-61, -61},
+	 {-31, -31},
+};
\ No newline at end of file
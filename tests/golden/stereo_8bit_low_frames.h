@@ -0,0 +1,16 @@
+#ifndef _STEREO_8BIT_LOW_FRAMES_H_
+#define _STEREO_8BIT_LOW_FRAMES_H_
+
+extern const size_t STEREO_8BIT_LOW_FRAMES_SAMPLE_NO;
+extern const size_t STEREO_8BIT_LOW_FRAMES_FRAME_NO;
+
+#ifndef STEREO_8BIT_LOW_FRAMES_FRAME_T
+#define STEREO_8BIT_LOW_FRAMES_FRAME_T
+typedef struct { int8_t l, r; } stereo_8bit_low_frames_frame_t;
+#endif
+
+extern const stereo_8bit_low_frames_frame_t stereo_8bit_low_frames[];
+
+#define STEREO_8BIT_LOW_FRAMES_SAMPLE_RATE 11025
+
+#endif
\ No newline at end of file
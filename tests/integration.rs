@@ -67,6 +67,44 @@ fn wav_to_c_case(input_path: &Path, golden_path: &Path, output_path: Option<&Pat
     }
 }
 
+/// Generate `output_path` with the gen_wav binary and return its integer samples
+fn gen_wav_samples(output_path: &Path, args: &[&str]) -> Vec<i32> {
+    Command::cargo_bin("gen_wav")
+        .unwrap()
+        .args(args)
+        .arg(output_path)
+        .assert()
+        .success();
+    hound::WavReader::open(output_path)
+        .unwrap()
+        .samples::<i32>()
+        .map(Result::unwrap)
+        .collect()
+}
+
+/// Run the wav2c binary with the given input and args expecting success, returning stdout
+fn run_stdout(input_path: &Path, args: &[&str]) -> String {
+    init();
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(input_path)
+        .args(args)
+        .assert()
+        .success();
+    String::from_utf8(cmd.get_output().stdout.clone()).unwrap()
+}
+
+/// Array values from generated C source
+fn array_values(output: &str) -> Vec<i64> {
+    let body = &output[output.rfind('{').unwrap() + 1..output.rfind('}').unwrap()];
+    body.split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().unwrap())
+        .collect()
+}
+
 /// Compile the generated C file with GCC - compile only, no linking (-c) since no entry function
 fn compile_with_gcc(file_path: &Path) {
     let temp_file = file_path.with_extension("o");
@@ -150,9 +188,58 @@ fn test_wav_to_c_array_file_header() {
     }
 }
 
+#[test]
+fn test_output_dir() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    // created if it does not exist
+    let output_dir = temp_dir.path().join("assets");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .args(["--no-comment", "--header", "--output-dir"])
+        .arg(&output_dir)
+        .assert()
+        .success();
+    let output = std::fs::read_to_string(output_dir.join("mono_8bit.c")).unwrap();
+    let golden = std::fs::read_to_string("tests/golden/mono_8bit.c").unwrap();
+    assert_eq!(output, golden);
+    assert!(output_dir.join("mono_8bit.h").exists());
+
+    // existing files are kept without --force
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .arg("--output-dir")
+        .arg(&output_dir)
+        .assert()
+        .failure();
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .args(["--lang", "cpp", "--output-dir"])
+        .arg(&output_dir)
+        .assert()
+        .success();
+    assert!(output_dir.join("mono_8bit.hpp").exists());
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .args(["--output", "out.c", "--output-dir"])
+        .arg(&output_dir)
+        .assert()
+        .failure();
+}
+
 #[test]
 fn test_wav_to_c_array_file_base16() {
-    let test_cases = vec![("mono_8bit.wav", "mono_8bit_base16.c")];
+    let test_cases = vec![
+        ("mono_8bit.wav", "mono_8bit_base16.c"),
+        ("stereo_16bit.wav", "stereo_16bit_base16.c"),
+        ("mono_32bit.wav", "mono_32bit_base16.c"),
+    ];
 
     for (input, golden) in test_cases {
         let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
@@ -167,6 +254,19 @@ fn test_wav_to_c_array_file_base16() {
             &["--no-comment", "--format", "base16"],
         );
         compile_with_gcc(&output_path);
+
+        // negative samples are the two's complement of the element width, not -0x literals
+        let output = fs::read_to_string(&output_path).unwrap();
+        assert!(!output.contains("-0x"), "{}", golden);
+        let width = match golden {
+            g if g.starts_with("mono_8bit") => 2,
+            g if g.starts_with("stereo_16bit") => 4,
+            _ => 8,
+        };
+        assert!(output
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter_map(|token| token.strip_prefix("0x"))
+            .all(|digits| digits.len() == width));
     }
 }
 
@@ -205,6 +305,46 @@ fn test_max_samples() {
         .failure();
 }
 
+#[test]
+fn test_truncate() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let output = run_stdout(
+        &input_path,
+        &["--no-comment", "--max-samples", "1000", "--truncate"],
+    );
+    assert!(output.contains("MONO_8BIT_SAMPLE_NO = 1000;"), "{}", output);
+    assert_eq!(array_values(&output), samples[..1000]);
+
+    // whole stereo frames are kept
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let output = run_stdout(
+        &input_path,
+        &[
+            "--group-stereo-as-struct-array",
+            "--max-samples",
+            "1001",
+            "--truncate",
+        ],
+    );
+    assert!(
+        output.contains("STEREO_16BIT_SAMPLE_NO = 1000;"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains("STEREO_16BIT_FRAME_NO = 500;"),
+        "{}",
+        output
+    );
+
+    // cue points past the new end are dropped
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit_cue.wav");
+    let output = run_stdout(&input_path, &["--max-samples", "2500", "--truncate"]);
+    assert!(output.contains("_CUE_COUNT 2\n"), "{}", output);
+}
+
 #[test]
 fn test_invalid_pcm_float() {
     let input = "mono_8bit_float.wav";
@@ -218,6 +358,384 @@ fn test_invalid_pcm_float() {
         .failure();
 }
 
+#[test]
+fn test_float_64bit() {
+    let input_path = PathBuf::from("tests/fixtures/mono_64bit_float.wav");
+
+    let output = run_stdout(&input_path, &["--no-comment"]);
+    assert!(output.contains("const int16_t mono_64bit_float[800]"));
+    let values = array_values(&output);
+    assert_eq!(values.len(), 800);
+    assert_eq!(values[0], 0);
+    // full scale sine peaks close to the int16 maximum
+    let peak = values.iter().map(|v| v.abs()).max().unwrap();
+    assert!(peak > 32000 && peak <= 32767);
+}
+
+#[test]
+fn test_stdin() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let expected = run_stdout(&input_path, &["--no-comment", "--array-name", "audio"]);
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .args(["-", "--no-comment"])
+        .write_stdin(std::fs::read(&input_path).unwrap())
+        .assert()
+        .success();
+    let output = String::from_utf8(cmd.get_output().stdout.clone()).unwrap();
+
+    assert!(output.contains("const int16_t audio[44100] = {"));
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_extern_c() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("audio.c");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--header", "--extern-c", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success();
+    let header = std::fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains("#ifdef __cplusplus\nextern \"C\" {\n#endif\n"));
+
+    // the header must work from both C and C++ and link to the C definitions without mangling
+    let main = "#include \"audio.h\"\nint main(void) { return (int)AUDIO_SAMPLE_NO - 44100 + audio[0]; }\n";
+    for (compiler, source) in [("gcc", "main.c"), ("g++", "main.cpp")] {
+        let source_path = temp_dir.path().join(source);
+        std::fs::write(&source_path, main).unwrap();
+        let binary_path = source_path.with_extension("out");
+        Command::new(compiler)
+            .arg("--include")
+            .arg("stdint.h")
+            .arg("--include")
+            .arg("stddef.h")
+            .arg("-o")
+            .arg(&binary_path)
+            .arg(&source_path)
+            .arg("-x")
+            .arg("c")
+            .arg(&output_path)
+            .assert()
+            .success();
+        Command::new(&binary_path).assert().success();
+    }
+
+    // requires a header
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--extern-c")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_quiet() {
+    init();
+
+    // above the progress threshold
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("long.wav");
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: 44100,
+        bits_per_sample: 8,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(&input_path, spec).unwrap();
+    for i in 0..100_000 {
+        writer.write_sample((i % 256 - 128) as i8).unwrap();
+    }
+    writer.finalize().unwrap();
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--quiet")
+        .assert()
+        .success();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(!error.contains('%'));
+    assert!(!error.contains('\r'));
+
+    // only errors are logged, whatever RUST_LOG enables
+    let merged = |args: &[&str]| {
+        let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg("tests/fixtures/stereo_16bit.wav")
+            .args(args)
+            .env("RUST_LOG", "warn")
+            .assert()
+            .success();
+        String::from_utf8_lossy(&cmd.get_output().stderr).contains("Merging stereo channels")
+    };
+    assert!(merged(&[]));
+    assert!(!merged(&["--quiet"]));
+    assert!(!merged(&["-q"]));
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/stereo_16bit.wav")
+        .args(["-q", "-v"])
+        .assert()
+        .failure();
+
+    // nor is anything else written to stderr
+    for other in ["--stats", "--preview"] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg("tests/fixtures/stereo_16bit.wav")
+            .args(["--quiet", other])
+            .assert()
+            .failure()
+            .code(2);
+    }
+}
+
+/// Fields of the flat JSON object output, samples as the raw array text
+fn json_fields(output: &str) -> std::collections::HashMap<String, String> {
+    let body = output
+        .trim()
+        .strip_prefix('{')
+        .unwrap()
+        .strip_suffix('}')
+        .unwrap();
+    let (fields, samples) = body.split_once("\"samples\":").unwrap();
+    let mut map: std::collections::HashMap<String, String> = fields
+        .split(',')
+        .filter(|field| !field.trim().is_empty())
+        .map(|field| {
+            let (key, value) = field.split_once(':').unwrap();
+            (
+                key.trim().trim_matches('"').to_string(),
+                value.trim().trim_matches('"').to_string(),
+            )
+        })
+        .collect();
+    map.insert("samples".to_string(), samples.trim().to_string());
+    map
+}
+
+#[test]
+fn test_lang_json() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let c_samples = array_values(&run_stdout(&input_path, &["--gain", "0.5"]));
+
+    let output = run_stdout(&input_path, &["--lang", "json", "--gain", "0.5"]);
+    let fields = json_fields(&output);
+    assert_eq!(fields["name"], "stereo_16bit");
+    assert_eq!(fields["sample_rate"], "44100");
+    assert_eq!(fields["channels"], "1");
+    assert_eq!(fields["bits"], "16");
+    let samples = &fields["samples"];
+    let samples: Vec<i64> = samples
+        .strip_prefix('[')
+        .unwrap()
+        .strip_suffix(']')
+        .unwrap()
+        .split(',')
+        .map(|v| v.trim().parse().unwrap())
+        .collect();
+    assert_eq!(samples.len(), 44100);
+    assert_eq!(samples, c_samples);
+
+    // interleaved frames
+    let output = run_stdout(
+        &input_path,
+        &["--lang", "json", "--group-stereo-as-struct-array"],
+    );
+    let fields = json_fields(&output);
+    assert_eq!(fields["channels"], "2");
+    assert_eq!(fields["samples"].matches(',').count() + 1, 88200);
+}
+
+#[test]
+fn test_template() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_1000.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let template_path = temp_dir.path().join("template.txt");
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+
+    std::fs::write(
+        &template_path,
+        "# {{name}}: {{ count }} x {{bits}} bit at {{sample_rate}} Hz, {{channels}} channel\n\
+        {{name}}: .{{type}} [{{samples}}]\n\
+        \\{{not_a_field}}\n",
+    )
+    .unwrap();
+    let output = run_stdout(
+        &input_path,
+        &["--template", template_path.to_str().unwrap()],
+    );
+    let expected = format!(
+        "# mono_16bit_1000: 1000 x 16 bit at 10000 Hz, 1 channel\n\
+        mono_16bit_1000: .int16_t [{}]\n\
+        {{{{not_a_field}}}}\n\n",
+        samples
+            .iter()
+            .map(i64::to_string)
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+    assert_eq!(output, expected);
+
+    // the array number format applies to the samples
+    std::fs::write(&template_path, "{{samples}}").unwrap();
+    let output = run_stdout(
+        &input_path,
+        &[
+            "--template",
+            template_path.to_str().unwrap(),
+            "-F",
+            "base16",
+        ],
+    );
+    assert!(output.starts_with("0x0000, 0x"));
+
+    for template in ["{{unknown}}", "{{samples}} {{samples}}", "{{name"] {
+        std::fs::write(&template_path, template).unwrap();
+        let output_path = temp_dir.path().join("output.txt");
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .arg("--template")
+            .arg(&template_path)
+            .arg("--output")
+            .arg(&output_path)
+            .assert()
+            .failure();
+        assert!(!output_path.exists());
+    }
+}
+
+#[test]
+fn test_post_process() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    let output = run_stdout(&input_path, &[]);
+    assert_eq!(run_stdout(&input_path, &["--post-process", "cat"]), output);
+    assert_eq!(
+        run_stdout(&input_path, &["--post-process", "tr a-z A-Z"]),
+        output.to_uppercase()
+    );
+
+    // header is processed too
+    let output_path = temp_dir.path().join("output.c");
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--header", "--post-process", "tr a-z A-Z", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success();
+    let source = std::fs::read_to_string(&output_path).unwrap();
+    assert!(source.contains("CONST INT8_T OUTPUT[44100] = {"));
+    let header = std::fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains("EXTERN CONST INT8_T OUTPUT[];"));
+
+    // a failing command leaves no output
+    let output_path = temp_dir.path().join("failed.c");
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--post-process", "false", "--output"])
+        .arg(&output_path)
+        .assert()
+        .failure();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(error.contains("Post-process failed: 'false' exited with"));
+    assert!(!output_path.exists());
+    assert!(!temp_dir.path().join("failed.c.tmp").exists());
+}
+
+#[test]
+fn test_verify_against() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--verify-against")
+        .arg(&input_path)
+        .assert()
+        .success();
+
+    // change the last sample of a copy
+    let mut bytes = std::fs::read(&input_path).unwrap();
+    *bytes.last_mut().unwrap() ^= 0x04;
+    let modified_path = temp_dir.path().join("modified.wav");
+    std::fs::write(&modified_path, bytes).unwrap();
+
+    let output_path = temp_dir.path().join("output.c");
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--verify-against")
+        .arg(&modified_path)
+        .arg("--output")
+        .arg(&output_path)
+        .assert()
+        .failure();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(error.contains("1 samples differ by more than 0"));
+    assert!(!output_path.exists());
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--verify-against")
+        .arg(&modified_path)
+        .args(["--verify-tolerance", "4"])
+        .assert()
+        .success();
+
+    // transforms are verified too
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--verify-against")
+        .arg(&input_path)
+        .arg("--reverse")
+        .assert()
+        .failure();
+
+    // the reference is mixed to mono the same way, warning of it only once
+    let panned_path = PathBuf::from("tests/fixtures/stereo_16bit_panned.wav");
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&panned_path)
+        .args(["--mono-mix", "left", "--verify-against"])
+        .arg(&panned_path)
+        .env("RUST_LOG", "warn")
+        .assert()
+        .success();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert_eq!(
+        error.matches("Merging stereo channels").count(),
+        1,
+        "{}",
+        error
+    );
+}
+
 #[test]
 fn test_invalid_file() {
     let input_path = PathBuf::from("src/main.rs");
@@ -228,3 +746,3155 @@ fn test_invalid_file() {
         .assert()
         .failure();
 }
+
+/// Custom emitter that sums the samples to exercise the library API
+#[derive(Default)]
+struct SumEmitter {
+    begun: Option<usize>,
+    count: usize,
+    sum: i64,
+    ended: bool,
+}
+
+impl wav2c::SampleEmitter for SumEmitter {
+    fn begin(&mut self, meta: &wav2c::WavMetadata) {
+        self.begun = Some(meta.sample_count);
+    }
+
+    fn sample(&mut self, value: i64) {
+        self.count += 1;
+        self.sum += value;
+    }
+
+    fn end(&mut self) {
+        self.ended = true;
+    }
+}
+
+#[test]
+fn test_custom_emitter() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let expected: i64 = hound::WavReader::open(&input_path)
+        .unwrap()
+        .samples::<i32>()
+        .map(|s| s.unwrap() as i64)
+        .sum();
+
+    let mut emitter = SumEmitter::default();
+    let options = wav2c::WavToCOptions::default();
+    let meta = wav2c::convert(&input_path, "mono_8bit", &options, &mut emitter)
+        .unwrap()
+        .meta;
+
+    assert_eq!(emitter.begun, Some(meta.sample_count));
+    assert_eq!(emitter.count, meta.sample_count);
+    assert_eq!(emitter.sum, expected);
+    assert!(emitter.ended);
+}
+
+#[test]
+fn test_convert_warnings() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let options = wav2c::WavToCOptions::default();
+    let conversion = wav2c::convert(
+        &input_path,
+        "stereo_16bit",
+        &options,
+        &mut wav2c::StatsEmitter::default(),
+    )
+    .unwrap();
+    assert_eq!(
+        conversion.warnings,
+        vec!["Merging stereo channels into mono by Average.".to_string()]
+    );
+
+    // mono needs no merge
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_1000.wav");
+    let conversion = wav2c::convert(
+        &input_path,
+        "mono_16bit_1000",
+        &options,
+        &mut wav2c::StatsEmitter::default(),
+    )
+    .unwrap();
+    assert!(conversion.warnings.is_empty());
+}
+
+#[test]
+fn test_fit_to_type() {
+    for (bits, min, max) in [
+        (8, i8::MIN as i64, i8::MAX as i64),
+        (16, i16::MIN as i64, i16::MAX as i64),
+        // 24-bit samples are held in a 32-bit type
+        (24, i32::MIN as i64, i32::MAX as i64),
+        (32, i32::MIN as i64, i32::MAX as i64),
+    ] {
+        assert_eq!(wav2c::fit_to_type(max, bits), (max, false));
+        assert_eq!(wav2c::fit_to_type(min, bits), (min, false));
+        assert_eq!(wav2c::fit_to_type(0, bits), (0, false));
+        assert_eq!(wav2c::fit_to_type(max + 1, bits), (max, true));
+        assert_eq!(wav2c::fit_to_type(min - 1, bits), (min, true));
+        assert_eq!(wav2c::fit_to_type(i64::MAX, bits), (max, true));
+        assert_eq!(wav2c::fit_to_type(i64::MIN, bits), (min, true));
+    }
+
+    // summing full scale channels exceeds the type and warns rather than wrapping
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let options = wav2c::WavToCOptions {
+        mono_mix: wav2c::MonoMix::SumClip,
+        ..Default::default()
+    };
+    let conversion = wav2c::convert(
+        &input_path,
+        "stereo_16bit",
+        &options,
+        &mut wav2c::StatsEmitter::default(),
+    )
+    .unwrap();
+    assert!(
+        conversion
+            .warnings
+            .iter()
+            .any(|w| w.starts_with("Clipped ")
+                && w.ends_with(" samples outside the sample type range.")),
+        "{:?}",
+        conversion.warnings
+    );
+}
+
+#[test]
+fn test_force_overwrite() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("mono_8bit.c");
+
+    let run = |force: bool| {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        cmd.arg(&input_path)
+            .arg("--output")
+            .arg(&output_path)
+            .arg("--header");
+        if force {
+            cmd.arg("--force");
+        }
+        cmd.assert()
+    };
+
+    run(false).success();
+    // the documented exit code for an existing output
+    run(false).failure().code(3);
+    // header alone existing also requires --force
+    fs::remove_file(&output_path).unwrap();
+    run(false).failure().code(3);
+    run(true).success();
+    assert!(output_path.exists());
+    assert!(output_path.with_extension("h").exists());
+}
+
+#[test]
+fn test_exit_codes() {
+    init();
+
+    let run = |input: &str, args: &[&str]| {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(input)
+            .args(args)
+            .assert()
+    };
+    run("tests/fixtures/mono_8bit.wav", &["--repeat", "0"]).code(2);
+    run("tests/fixtures/missing.wav", &[]).code(2);
+    run("Cargo.toml", &[]).code(5);
+}
+
+#[test]
+fn test_progmem_accessor() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("tone.c");
+
+    // stub of avr/pgmspace.h so the output can be compiled on the host
+    fs::write(
+        temp_dir.path().join("pgmspace.h"),
+        "#include <stdint.h>\n\
+        #define PROGMEM\n\
+        #define pgm_read_byte(addr) (*(const uint8_t *)(addr))\n\
+        #define pgm_read_word(addr) (*(const uint16_t *)(addr))\n\
+        #define pgm_read_dword(addr) (*(const uint32_t *)(addr))\n",
+    )
+    .unwrap();
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--output")
+        .arg(&output_path)
+        .args([
+            "--no-comment",
+            "--header",
+            "--wrap-progmem-accessor",
+            "--prefix",
+            "#include \"pgmspace.h\"",
+        ])
+        .assert()
+        .success();
+
+    let header = fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains("extern const int16_t tone[] PROGMEM;"));
+    assert!(header.contains("#define TONE_GET(i) ((int16_t)pgm_read_word(&tone[i]))"));
+
+    let user_path = temp_dir.path().join("user.c");
+    fs::write(
+        &user_path,
+        "#include <stddef.h>\n\
+        #include \"pgmspace.h\"\n\
+        #include \"tone.h\"\n\
+        int16_t second(void) { return TONE_GET(1); }\n",
+    )
+    .unwrap();
+
+    compile_with_gcc(&output_path);
+    compile_with_gcc(&user_path);
+}
+
+#[test]
+fn test_loop_points() {
+    init();
+
+    let output = run_stdout(
+        Path::new("tests/fixtures/mono_8bit_loop.wav"),
+        &["--no-comment", "--array-name", "tone"],
+    );
+
+    assert!(output.contains("#define TONE_LOOP_START 100\n"));
+    assert!(output.contains("#define TONE_LOOP_END 3000\n"));
+
+    // the inclusive loop range of the 4000 samples is mirrored sample for sample when reversed
+    let output = run_stdout(
+        Path::new("tests/fixtures/mono_8bit_loop.wav"),
+        &["--no-comment", "--array-name", "tone", "--reverse"],
+    );
+    assert!(
+        output.contains("#define TONE_LOOP_START 999\n"),
+        "{}",
+        output
+    );
+    assert!(output.contains("#define TONE_LOOP_END 3899\n"));
+
+    // the loop end sample must be inside a clip to keep the loop
+    let output = run_stdout(
+        Path::new("tests/fixtures/mono_8bit_loop.wav"),
+        &[
+            "--no-comment",
+            "--array-name",
+            "tone",
+            "--end-sample",
+            "3001",
+        ],
+    );
+    assert!(
+        output.contains("#define TONE_LOOP_END 3000\n"),
+        "{}",
+        output
+    );
+    let output = run_stdout(
+        Path::new("tests/fixtures/mono_8bit_loop.wav"),
+        &[
+            "--no-comment",
+            "--array-name",
+            "tone",
+            "--end-sample",
+            "3000",
+        ],
+    );
+    assert!(!output.contains("_LOOP_"), "{}", output);
+
+    // no smpl chunk, no defines
+    let output = run_stdout(Path::new("tests/fixtures/mono_8bit.wav"), &[]);
+
+    assert!(!output.contains("_LOOP_"));
+}
+
+#[test]
+fn test_no_sanitize() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--array-name", "bad name", "--no-sanitize"])
+        .assert()
+        .failure();
+
+    let output = run_stdout(&input_path, &["--array-name", "bad name"]);
+    assert!(output.contains("bad_name[44100]"));
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--array-name", "good_1", "--no-sanitize"])
+        .assert()
+        .success();
+}
+
+#[test]
+fn test_sanitize() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    for (name, expected) in [
+        ("3bad", "_3bad"),
+        ("good_1", "good_1"),
+        ("spaces here", "spaces_here"),
+    ] {
+        let output = run_stdout(&input_path, &["--no-comment", "--array-name", name]);
+        assert!(output.contains(&format!(" {}[44100] = {{", expected)));
+    }
+
+    assert_eq!(wav2c::sanitize_c_identifier("sound123"), "sound123");
+    assert!(wav2c::is_c_identifier(&wav2c::sanitize_c_identifier(
+        "9 lives!"
+    )));
+
+    // altered names are warned about
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["-v", "--array-name", "3bad"])
+        .assert()
+        .success();
+    let log = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(log.contains("using '_3bad'"));
+}
+
+#[test]
+fn test_gain() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let output = run_stdout(&input_path, &["--no-comment", "--gain", "0.0"]);
+    let values = array_values(&output);
+    assert_eq!(values.len(), 44100);
+    assert!(values.iter().all(|&v| v == 0));
+
+    let output = run_stdout(&input_path, &["--no-comment", "--gain", "2.0"]);
+    let values = array_values(&output);
+    assert_eq!(values.iter().max(), Some(&127));
+    assert_eq!(values.iter().min(), Some(&-128));
+
+    for gain in ["nan", "inf", "-inf"] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .arg(format!("--gain={}", gain))
+            .assert()
+            .failure()
+            .code(2);
+    }
+}
+
+#[test]
+fn test_reverse() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let mut forward = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let reversed = array_values(&run_stdout(&input_path, &["--no-comment", "--reverse"]));
+
+    // frames are merged to the same values so L/R pairing is preserved
+    forward.reverse();
+    assert_eq!(forward, reversed);
+}
+
+#[test]
+fn test_repeat() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let output = run_stdout(&input_path, &["--no-comment", "--repeat", "3"]);
+    let repeated = array_values(&output);
+    assert_eq!(repeated, samples.repeat(3));
+    assert!(
+        output.contains(&format!("MONO_8BIT_SAMPLE_NO = {};", samples.len() * 3)),
+        "{}",
+        output
+    );
+
+    // the limit is of the repeated array
+    let max = samples.len().to_string();
+    run_stdout(&input_path, &["--repeat", "1", "--max-samples", &max]);
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--repeat", "2", "--max-samples", &max])
+        .assert()
+        .failure();
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--repeat", "0"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_input_clipping_warning() {
+    let clipped = |input: &str, args: &[&str]| {
+        let input_path = Path::new("tests/fixtures").join(input);
+        let assert = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(input_path)
+            .args(args)
+            .env("RUST_LOG", "warn")
+            .assert()
+            .success();
+        String::from_utf8_lossy(&assert.get_output().stderr).contains("may be clipped")
+    };
+
+    // a square wave at full scale is a run of clipped samples
+    assert!(clipped("mono_8bit_clipped.wav", &[]));
+    assert!(!clipped("mono_8bit_clipped.wav", &["--quiet"]));
+    // the single sample peaks of a sine are not clipping
+    assert!(!clipped("mono_8bit.wav", &[]));
+    assert!(!clipped("mono_8bit_2s.wav", &[]));
+
+    // full scale of 24-bit samples is that of the depth, not the 32-bit type holding them
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let square_path = temp_dir.path().join("square_24bit.wav");
+    gen_wav_samples(
+        &square_path,
+        &["-b", "24", "-s", "8000", "-d", "0.1", "-w", "square"],
+    );
+    assert!(clipped(square_path.to_str().unwrap(), &[]));
+}
+
+#[test]
+fn test_fact_chunk_mismatch() {
+    let stderr = |input_path: &Path| {
+        let assert = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(input_path)
+            .arg("-v")
+            .assert()
+            .success();
+        String::from_utf8_lossy(&assert.get_output().stderr).into_owned()
+    };
+
+    let warning = stderr(Path::new("tests/fixtures/mono_8bit_fact.wav"));
+    assert!(
+        warning.contains("fact chunk counts 1000 frames but the data holds 800"),
+        "{}",
+        warning
+    );
+
+    // a matching count is fine
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("fact.wav");
+    gen_wav_samples(
+        &input_path,
+        &["-b", "8", "-s", "8000", "-d", "0.1", "--fact-frames", "800"],
+    );
+    assert!(!stderr(&input_path).contains("fact chunk"));
+}
+
+#[test]
+fn test_fade() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let faded = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--fade-in", "10", "--fade-out", "10"],
+    ));
+
+    assert_eq!(faded.len(), samples.len());
+    assert_eq!(faded[0], 0);
+    assert_eq!(faded[faded.len() - 1], 0);
+    // 10 ms at 44.1 kHz, untouched in between
+    assert_eq!(
+        faded[441..faded.len() - 441],
+        samples[441..samples.len() - 441]
+    );
+    assert!(faded[..441]
+        .iter()
+        .zip(&samples)
+        .all(|(f, s)| f.abs() <= s.abs()));
+
+    // longer than the clip clamps to it
+    let faded = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--fade-in", "100000"],
+    ));
+    assert_eq!(faded[0], 0);
+    assert_eq!(faded.len(), samples.len());
+}
+
+#[test]
+fn test_fft_ready() {
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_1000.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    assert_eq!(samples.len(), 1000);
+
+    let output = run_stdout(&input_path, &["--no-comment", "--fft-ready"]);
+    assert!(output.contains("#define MONO_16BIT_1000_FFT_SIZE 1024\n"));
+    assert!(output.contains("const int16_t mono_16bit_1000[1024] = {"));
+
+    let padded = array_values(&output);
+    assert_eq!(padded.len(), 1024);
+    assert_eq!(padded[..1000], samples[..]);
+    assert!(padded[1000..].iter().all(|&v| v == 0));
+}
+
+#[test]
+fn test_block_size() {
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_1000.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+
+    let output = run_stdout(&input_path, &["--no-comment", "--block-size", "256"]);
+    assert!(output.contains("#define MONO_16BIT_1000_BLOCK_SIZE 256\n"));
+    assert!(output.contains("#define MONO_16BIT_1000_BLOCK_COUNT 4\n"));
+    assert!(output.contains("const int16_t mono_16bit_1000[1024] = {"));
+
+    let padded = array_values(&output);
+    assert_eq!(padded.len() % 256, 0);
+    assert_eq!(padded[..1000], samples[..]);
+    assert!(padded[1000..].iter().all(|&v| v == 0));
+
+    // already a multiple
+    let output = run_stdout(&input_path, &["--no-comment", "--block-size", "250"]);
+    assert!(output.contains("#define MONO_16BIT_1000_BLOCK_COUNT 4\n"));
+    assert_eq!(array_values(&output), samples);
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--block-size", "0"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_remove_dc() {
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_dc.wav");
+    let mean = |values: &[i64]| values.iter().sum::<i64>() as f64 / values.len() as f64;
+
+    // biased by a quarter of full scale
+    let biased = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    assert!((mean(&biased) - 8192.0).abs() < 10.0);
+
+    let removed = array_values(&run_stdout(&input_path, &["--no-comment", "--remove-dc"]));
+    assert_eq!(removed.len(), biased.len());
+    assert!(mean(&removed).abs() < 1.0);
+}
+
+#[test]
+fn test_highpass() {
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_dc.wav");
+    let mean = |values: &[i64]| values.iter().sum::<i64>() as f64 / values.len() as f64;
+
+    let output = run_stdout(&input_path, &["--highpass", "20"]);
+    assert!(output.contains("* High-pass: 2nd order Butterworth biquad at 20 Hz\n"));
+    let filtered = array_values(&output);
+    assert_eq!(filtered.len(), 4000);
+    // the quarter scale bias is gone once the filter settles, the 440 Hz tone passes
+    let settled = &filtered[2000..];
+    assert!(mean(settled).abs() < 50.0, "{}", mean(settled));
+    assert!(settled.iter().max().unwrap() > &20000);
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--highpass", "4000"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_resample() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let rms = |values: &[i64]| {
+        (values.iter().map(|&v| (v * v) as f64).sum::<f64>() / values.len() as f64).sqrt()
+    };
+
+    // 440 Hz passes the low-pass at 8 kHz with the length scaled to the new rate
+    let tone_path = temp_dir.path().join("tone.wav");
+    gen_wav_samples(&tone_path, &["-s", "44100", "-d", "1"]);
+    let output = run_stdout(&tone_path, &["--resample", "8000"]);
+    assert!(output.contains("_SAMPLE_RATE 8000\n"));
+    let tone = array_values(&output);
+    assert_eq!(tone.len(), 8000);
+    assert!(tone.iter().max().unwrap() > &20000);
+
+    // 15 kHz is above the new Nyquist so would alias to 7 kHz without the low-pass
+    let high_path = temp_dir.path().join("high.wav");
+    gen_wav_samples(&high_path, &["-s", "44100", "-d", "1", "-p", "15000"]);
+    let filtered = array_values(&run_stdout(&high_path, &["--resample", "8000"]));
+    let aliased = array_values(&run_stdout(
+        &high_path,
+        &["--resample", "8000", "--no-antialias"],
+    ));
+    assert!(
+        rms(&filtered[1000..]) * 10.0 < rms(&aliased[1000..]),
+        "{} {}",
+        rms(&filtered[1000..]),
+        rms(&aliased[1000..])
+    );
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&tone_path)
+        .args(["--resample", "0"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_sample_rate_define() {
+    for fixture in ["mono_8bit", "stereo_8bit_low", "mono_8bit_2s"] {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}.wav", fixture));
+        let rate = hound::WavReader::open(&input_path)
+            .unwrap()
+            .spec()
+            .sample_rate;
+
+        let output = run_stdout(&input_path, &["--no-comment"]);
+        assert!(output.contains(&format!(
+            "#define {}_SAMPLE_RATE {}\n",
+            fixture.to_uppercase(),
+            rate
+        )));
+
+        let output = run_stdout(&input_path, &["--no-comment", "--no-define"]);
+        assert!(!output.contains("_SAMPLE_RATE"));
+    }
+}
+
+#[test]
+fn test_cue_points() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit_cue.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("cue.c");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--no-comment", "--header", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success();
+    compile_with_gcc(&output_path);
+
+    // ordered by offset, labels from the LIST adtl chunk
+    let defines = "#define CUE_CUE_COUNT 3\n\
+        #define CUE_CUE_0 0\n\
+        #define CUE_CUE_1 2000\n\
+        #define CUE_CUE_ATTACK CUE_CUE_1\n\
+        #define CUE_CUE_2 3000\n\
+        #define CUE_CUE_RELEASE CUE_CUE_2\n";
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    assert!(output.contains(defines));
+    let header = std::fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains(defines));
+
+    // offsets follow the clip range
+    let output = run_stdout(&input_path, &["--no-comment", "--start-sample", "1000"]);
+    assert!(output.contains(
+        "#define MONO_8BIT_CUE_CUE_COUNT 2\n\
+        #define MONO_8BIT_CUE_CUE_0 1000\n"
+    ));
+
+    // mirrored to the same samples of the 4000 when reversed, still in order
+    let output = run_stdout(&input_path, &["--no-comment", "--reverse"]);
+    assert!(
+        output.contains(
+            "#define MONO_8BIT_CUE_CUE_COUNT 3\n\
+            #define MONO_8BIT_CUE_CUE_0 999\n\
+            #define MONO_8BIT_CUE_CUE_RELEASE MONO_8BIT_CUE_CUE_0\n\
+            #define MONO_8BIT_CUE_CUE_1 1999\n\
+            #define MONO_8BIT_CUE_CUE_ATTACK MONO_8BIT_CUE_CUE_1\n\
+            #define MONO_8BIT_CUE_CUE_2 3999\n"
+        ),
+        "{}",
+        output
+    );
+
+    // labels colliding with the count or an earlier label are not aliased
+    let collide_path = temp_dir.path().join("collide.wav");
+    gen_wav_samples(
+        &collide_path,
+        &[
+            "-c", "1", "-b", "8", "-s", "8000", "-d", "0.5", "--cue", "0:count", "--cue",
+            "1000:hit", "--cue", "2000:Hit",
+        ],
+    );
+    let output_path = collide_path.with_extension("c");
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&collide_path)
+        .args(["--no-comment", "--output"])
+        .arg(&output_path)
+        .env("RUST_LOG", "warn")
+        .assert()
+        .success();
+    let stderr = String::from_utf8_lossy(&cmd.get_output().stderr);
+    assert!(stderr.contains("Cue label 'count' collides"), "{}", stderr);
+    assert!(stderr.contains("Cue label 'Hit' collides"), "{}", stderr);
+    compile_with_gcc(&output_path);
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    assert!(output.contains(
+        "#define COLLIDE_CUE_COUNT 3\n\
+        #define COLLIDE_CUE_0 0\n\
+        #define COLLIDE_CUE_1 1000\n\
+        #define COLLIDE_CUE_HIT COLLIDE_CUE_1\n\
+        #define COLLIDE_CUE_2 2000\n"
+    ));
+    assert_eq!(output.matches("#define COLLIDE_CUE_COUNT ").count(), 1);
+    assert_eq!(output.matches("#define COLLIDE_CUE_HIT ").count(), 1);
+
+    // absent without a cue chunk
+    let output = run_stdout(&PathBuf::from("tests/fixtures/mono_8bit.wav"), &[]);
+    assert!(!output.contains("_CUE_"));
+}
+
+#[test]
+fn test_start_end() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit_2s.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    assert_eq!(samples.len(), 16000);
+
+    // middle second
+    let output = run_stdout(
+        &input_path,
+        &["--no-comment", "--start", "0.5", "--end", "1.5"],
+    );
+    assert!(output.contains("const int8_t mono_8bit_2s[8000] = {"));
+    assert_eq!(array_values(&output), samples[4000..12000]);
+
+    let output = run_stdout(
+        &input_path,
+        &[
+            "--no-comment",
+            "--start-sample",
+            "4000",
+            "--end-sample",
+            "12000",
+        ],
+    );
+    assert_eq!(array_values(&output), samples[4000..12000]);
+
+    // applied before the maximum sample check
+    run_stdout(&input_path, &["--start", "1", "--max-samples", "8000"]);
+
+    for args in [
+        ["--start", "1.5", "--end", "0.5"],
+        ["--start", "0", "--end", "3"],
+    ] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .args(args)
+            .assert()
+            .failure();
+    }
+
+    // an empty input converts without a range but any range is outside it
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let empty_path = temp_dir.path().join("empty.wav");
+    gen_wav_samples(&empty_path, &["-d", "0"]);
+    let output = run_stdout(&empty_path, &["--no-comment"]);
+    assert!(
+        output.contains("const size_t EMPTY_SAMPLE_NO = 0;\n"),
+        "{}",
+        output
+    );
+    assert!(array_values(&output).is_empty());
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&empty_path)
+        .args(["--start-sample", "0"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_limit() {
+    let input_path = PathBuf::from("tests/fixtures/mono_32bit.wav");
+    let max = i32::MAX as i64;
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    // boosting full scale input clips without the limiter
+    let clipped = array_values(&run_stdout(&input_path, &["--no-comment", "--gain", "2"]));
+    assert!(clipped.contains(&max));
+
+    let limited = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--gain", "2", "--limit", "-1"],
+    ));
+    let ceiling = (max as f64 * 10f64.powf(-1.0 / 20.0)) as i64;
+    assert!(limited.iter().all(|v| v.abs() <= ceiling));
+    // quiet samples are below the knee so only scaled
+    for (sample, limited) in samples.iter().zip(&limited) {
+        if sample.abs() * 2 < ceiling / 2 {
+            assert_eq!(*limited, sample * 2);
+        }
+    }
+
+    // full scale input alone stays below a 0 dB ceiling
+    let limited = array_values(&run_stdout(&input_path, &["--no-comment", "--limit", "0"]));
+    assert!(limited.iter().all(|v| v.abs() < max));
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--limit", "3"])
+        .assert()
+        .failure();
+
+    // the ceiling is relative to the full scale of 24-bit samples, not their 32-bit type
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let square_path = temp_dir.path().join("square_24bit.wav");
+    gen_wav_samples(
+        &square_path,
+        &["-b", "24", "-s", "8000", "-d", "0.1", "-w", "square"],
+    );
+    let limited = array_values(&run_stdout(
+        &square_path,
+        &["--no-comment", "--limit", "-6"],
+    ));
+    let ceiling = (8388607.0 * 10f64.powf(-6.0 / 20.0)) as i64;
+    assert!(limited.iter().all(|v| v.abs() <= ceiling));
+    assert!(limited.iter().any(|v| v.abs() > ceiling / 2));
+}
+
+#[test]
+fn test_clamp_amplitude() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let half = 32767 / 2;
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let clamped = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--clamp-amplitude", &half.to_string()],
+    ));
+    // the full scale sine peaks at exactly the clamp, below it nothing changes
+    assert_eq!(clamped.iter().max(), Some(&half));
+    assert_eq!(clamped.iter().min(), Some(&-half));
+    for (sample, clamped) in samples.iter().zip(&clamped) {
+        assert_eq!(*clamped, (*sample).clamp(-half, half));
+    }
+
+    let assert = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["-v", "--clamp-amplitude", &half.to_string()])
+        .assert()
+        .success();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    let count = samples.iter().filter(|v| v.abs() > half).count();
+    assert!(
+        stderr.contains(&format!(
+            "Clamped {} samples to an amplitude of {}.",
+            count, half
+        )),
+        "{}",
+        stderr
+    );
+}
+
+#[test]
+fn test_bit_depth() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+
+    let output = run_stdout(&input_path, &["--no-comment", "--bit-depth", "8"]);
+    assert!(output.contains("const int8_t stereo_16bit[44100] = {"));
+    let reduced = array_values(&output);
+    assert!(reduced.iter().all(|&v| (-128..=127).contains(&v)));
+    // rounded to the nearest step rather than truncated
+    let expected: Vec<i64> = samples
+        .iter()
+        .map(|&v| ((v + 128) >> 8).clamp(-128, 127))
+        .collect();
+    assert_eq!(reduced, expected);
+    assert!(samples.iter().zip(&reduced).any(|(&v, &r)| r != v >> 8));
+
+    // depths between the type sizes keep the type of the next size up
+    let output = run_stdout(&input_path, &["--no-comment", "--bit-depth", "12"]);
+    assert!(output.contains("const int16_t stereo_16bit[44100] = {"));
+    assert!(array_values(&output)
+        .iter()
+        .all(|&v| (-2048..=2047).contains(&v)));
+
+    for bits in ["0", "24"] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .args(["--bit-depth", bits])
+            .assert()
+            .failure();
+    }
+}
+
+#[test]
+fn test_dither() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+    let reduce = |args: &[&str]| {
+        let mut args = args.to_vec();
+        args.extend(["--no-comment", "--bit-depth", "8"]);
+        array_values(&run_stdout(&input_path, &args))
+    };
+
+    let plain = reduce(&[]);
+    let dithered = reduce(&["--dither", "--dither-seed", "42"]);
+    assert_eq!(dithered.len(), plain.len());
+    assert_ne!(dithered, plain);
+    // within +-1 LSB of the undithered rounding
+    assert!(plain.iter().zip(&dithered).all(|(p, d)| (p - d).abs() <= 1));
+
+    // reproducible for a seed
+    assert_eq!(reduce(&["--dither", "--dither-seed", "42"]), dithered);
+    assert_ne!(reduce(&["--dither", "--dither-seed", "7"]), dithered);
+    assert_eq!(reduce(&["--dither"]), reduce(&["--dither"]));
+
+    // spanning more bits than the 8 dropped adds more noise
+    let noise = |values: &[i64]| -> i64 {
+        plain
+            .iter()
+            .zip(values)
+            .map(|(p, d)| (p - d) * (p - d))
+            .sum()
+    };
+    let over = reduce(&["--dither", "--dither-seed", "42", "--dither-depth", "11"]);
+    assert!(noise(&over) > 4 * noise(&dithered));
+    assert_eq!(
+        reduce(&["--dither", "--dither-seed", "42", "--dither-depth", "8"]),
+        dithered
+    );
+    for depth in ["0", "17"] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .args(["--bit-depth", "8", "--dither", "--dither-depth", depth])
+            .assert()
+            .failure();
+    }
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--dither")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_multichannel() {
+    let input_path = PathBuf::from("tests/fixtures/quad_16bit.wav");
+
+    // the same tone on every channel mixes to the tone
+    let output = run_stdout(&input_path, &["--no-comment"]);
+    assert!(output.contains("const int16_t quad_16bit[800] = {"));
+    let left = run_stdout(&input_path, &["--no-comment", "--mono-mix", "left"]);
+    assert_eq!(array_values(&output), array_values(&left));
+
+    // distinct channels
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let distinct_path = temp_dir.path().join("distinct.wav");
+    let spec = hound::WavSpec {
+        channels: 4,
+        sample_rate: 8000,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(&distinct_path, spec).unwrap();
+    for frame in 0..16i16 {
+        for channel in [-1000, 2000, 3000, 30000] {
+            writer.write_sample(channel + frame).unwrap();
+        }
+    }
+    writer.finalize().unwrap();
+
+    let mix = |mode: &str| {
+        array_values(&run_stdout(
+            &distinct_path,
+            &["--no-comment", "--mono-mix", mode],
+        ))
+    };
+    let frames = 0..16i64;
+    assert_eq!(
+        mix("average"),
+        frames.clone().map(|f| 8500 + f).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        mix("left"),
+        frames.clone().map(|f| -1000 + f).collect::<Vec<_>>()
+    );
+    assert_eq!(
+        mix("right"),
+        frames.clone().map(|f| 2000 + f).collect::<Vec<_>>()
+    );
+    assert_eq!(mix("sum-clip"), vec![i16::MAX as i64; 16]);
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--group-stereo-as-struct-array")
+        .assert()
+        .failure();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(error.contains("Input has 4 channels"));
+}
+
+#[test]
+fn test_mono_mix() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    // left and right of each frame from the struct array
+    let output = run_stdout(
+        &input_path,
+        &["--no-comment", "--group-stereo-as-struct-array"],
+    );
+    let body = &output[output.find("= {").unwrap() + 3..output.rfind("};").unwrap()];
+    let frames: Vec<i64> = body
+        .split([',', '{', '}'])
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().unwrap())
+        .collect();
+    let left: Vec<i64> = frames.iter().step_by(2).copied().collect();
+    let right: Vec<i64> = frames.iter().skip(1).step_by(2).copied().collect();
+
+    let mix = |mode: &str| {
+        array_values(&run_stdout(
+            &input_path,
+            &["--no-comment", "--mono-mix", mode],
+        ))
+    };
+    let average: Vec<i64> = left.iter().zip(&right).map(|(l, r)| (l + r) / 2).collect();
+    assert_eq!(mix("average"), average);
+    assert_eq!(
+        array_values(&run_stdout(&input_path, &["--no-comment"])),
+        average
+    );
+    assert_eq!(mix("left"), left);
+    assert_eq!(mix("right"), right);
+    let sum: Vec<i64> = left
+        .iter()
+        .zip(&right)
+        .map(|(l, r)| (l + r).clamp(i16::MIN as i64, i16::MAX as i64))
+        .collect();
+    let sum_clip = mix("sum-clip");
+    assert_eq!(sum_clip, sum);
+    assert!(sum_clip.contains(&(i16::MAX as i64)));
+}
+
+#[test]
+fn test_stereo_width() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    // the downmix is the mid, which width 0 keeps in both channels
+    let mixed = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let narrowed = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--stereo-width", "0"],
+    ));
+    assert_eq!(narrowed, mixed);
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--stereo-width=-1"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_stereo_to_ms() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit_panned.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("panned.c");
+    let frames: Vec<[i64; 2]> = hound::WavReader::open(&input_path)
+        .unwrap()
+        .samples::<i16>()
+        .map(|s| s.unwrap() as i64)
+        .collect::<Vec<_>>()
+        .chunks_exact(2)
+        .map(|frame| [frame[0], frame[1]])
+        .collect();
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--no-comment", "--stereo-to-ms", "--header", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success();
+    compile_with_gcc(&output_path);
+    let output = fs::read_to_string(&output_path).unwrap();
+    assert!(output.contains("const size_t PANNED_SAMPLE_NO = 800;\n"));
+    let side_start = output.find("const int16_t panned_side[800] = {").unwrap();
+    let (mid, side) = output.split_at(side_start);
+    assert!(mid.contains("const int16_t panned_mid[800] = {"));
+    let (mid, side) = (array_values(mid), array_values(side));
+
+    let expected_mid: Vec<i64> = frames
+        .iter()
+        .map(|[l, r]| ((l + r) as f64 / 2.0).round() as i64)
+        .collect();
+    let expected_side: Vec<i64> = frames
+        .iter()
+        .map(|[l, r]| ((l - r) as f64 / 2.0).round() as i64)
+        .collect();
+    assert_eq!(mid, expected_mid);
+    assert_eq!(side, expected_side);
+    // panned right, so the side is the inverse of a third of the mid
+    let peak = |values: &[i64]| values.iter().map(|v| v.abs()).max().unwrap();
+    assert!(peak(&side) > 0);
+    assert_eq!((peak(&mid) as f64 / peak(&side) as f64).round(), 3.0);
+
+    let header = fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains("extern const int16_t panned_mid[];\n"));
+    assert!(header.contains("extern const int16_t panned_side[];\n"));
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .arg("--stereo-to-ms")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_stats() {
+    let input_path = PathBuf::from("tests/fixtures/mono_32bit.wav");
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--stats")
+        .assert()
+        .success();
+    let stats = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+
+    // full scale sine, less the f32 rounding of the generator
+    assert!(stats.contains("Peak: 2147483392 (-0.00 dBFS)\n"));
+    // RMS of a sine is peak / sqrt(2), -3 dB
+    assert!(stats.contains("(-3.01 dBFS)\n"));
+    assert!(stats.contains("DC offset: "));
+    assert!(stats.contains("samples at full scale"));
+
+    // squares of 44100 full scale 32-bit samples sum beyond i64
+    let rms: f64 = stats
+        .lines()
+        .find_map(|line| line.strip_prefix("RMS: "))
+        .and_then(|rms| rms.split_whitespace().next())
+        .unwrap()
+        .parse()
+        .unwrap();
+    let expected = 2147483392.0 / 2f64.sqrt();
+    assert!((rms - expected).abs() < expected * 1e-4);
+
+    // full scale is of the 24-bit depth rather than the 32-bit type holding it
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let square_path = temp_dir.path().join("square.wav");
+    gen_wav_samples(
+        &square_path,
+        &["-b", "24", "-s", "8000", "-d", "0.1", "-w", "square"],
+    );
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&square_path)
+        .arg("--stats")
+        .assert()
+        .success();
+    let stats = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(stats.contains("Peak: 8388607 (0.00 dBFS)\n"), "{}", stats);
+    assert!(
+        stats.contains("Clipped: 400 samples at full scale"),
+        "{}",
+        stats
+    );
+}
+
+#[test]
+fn test_rotate_to_peak() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let rotated = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--rotate-to-peak"],
+    ));
+
+    let peak = samples.iter().map(|v| v.abs()).max().unwrap();
+    let index = samples.iter().position(|v| v.abs() == peak).unwrap();
+    assert_eq!(rotated.len(), samples.len());
+    assert_eq!(rotated[0], samples[index]);
+    assert_eq!(rotated[..samples.len() - index], samples[index..]);
+}
+
+#[test]
+fn test_wav_to_c_array_line_bytes() {
+    let test_cases = vec![("mono_8bit.wav", "mono_8bit_line_bytes.c")];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &["--no-comment", "--sample-limit-per-line-bytes", "24"],
+        );
+        compile_with_gcc(&output_path);
+
+        let output = fs::read_to_string(&output_path).unwrap();
+        let array = &output[output.find('{').unwrap()..];
+        assert!(array.lines().all(|line| line.len() <= 24));
+    }
+}
+
+#[test]
+fn test_wav_to_c_array_pad_width() {
+    let input_path = PathBuf::from("tests/fixtures/mono_32bit.wav");
+    let golden_path = PathBuf::from("tests/golden/mono_32bit_pad_width.c");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("mono_32bit_pad_width.c");
+
+    wav_to_c_case(
+        &input_path,
+        &golden_path,
+        Some(&output_path),
+        &["--no-comment", "--pad-width", "11", "--header"],
+    );
+    compile_with_gcc(&output_path);
+
+    // every value fills the width so all full lines are the same length
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    let lines: Vec<&str> = output.lines().filter(|l| l.starts_with('\t')).collect();
+    assert!(lines[..lines.len() - 1]
+        .iter()
+        .all(|l| l.len() == lines[0].len()));
+    assert!(lines[0].starts_with("\t           0, "));
+}
+
+#[test]
+fn test_wav_to_c_array_no_trailing_comma() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let golden_path = PathBuf::from("tests/golden/mono_8bit_no_trailing_comma.c");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("mono_8bit_no_trailing_comma.c");
+
+    wav_to_c_case(
+        &input_path,
+        &golden_path,
+        Some(&output_path),
+        &["--no-comment", "--no-trailing-comma", "--header"],
+    );
+    compile_with_gcc(&output_path);
+
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    assert!(
+        output.ends_with("-7\n};"),
+        "{}",
+        &output[output.len() - 40..]
+    );
+    assert!(!output.contains(",\n}"));
+
+    // the last frame of a struct array has no comma either
+    let output = run_stdout(
+        Path::new("tests/fixtures/stereo_8bit_low.wav"),
+        &["--group-stereo-as-struct-array", "--no-trailing-comma"],
+    );
+    assert!(output.trim_end().ends_with("}\n};"), "{}", output);
+}
+
+#[test]
+fn test_wav_to_c_array_indent() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let golden_path = PathBuf::from("tests/golden/mono_8bit_indent.c");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("mono_8bit_indent.c");
+
+    wav_to_c_case(
+        &input_path,
+        &golden_path,
+        Some(&output_path),
+        &["--no-comment", "--indent", "  ", "--header"],
+    );
+    compile_with_gcc(&output_path);
+
+    let output = std::fs::read_to_string(&output_path).unwrap();
+    assert!(!output.contains('\t'));
+    assert!(output.contains("= {\n   0, 7, 15,"));
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--indent", "xx"])
+        .assert()
+        .failure();
+    let error = String::from_utf8_lossy(&cmd.get_output().stderr).into_owned();
+    assert!(
+        error.contains("Indent must only be spaces or tabs"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_wav_to_c_array_last_line_pad() {
+    let test_cases = vec![("mono_8bit.wav", "mono_8bit_last_line_pad.c")];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &[
+                "--no-comment",
+                "--format",
+                "base16",
+                "--last-line-pad",
+                "--header",
+            ],
+        );
+        compile_with_gcc(&output_path);
+
+        // 44100 samples leaves a final line of 4, padded to the 8 sample lines
+        let output = std::fs::read_to_string(&output_path).unwrap();
+        let lines: Vec<&str> = output.lines().filter(|l| l.starts_with('\t')).collect();
+        let last = lines.last().unwrap();
+        assert_eq!(last.trim_end().matches(',').count(), 4);
+        assert!(lines.iter().all(|l| l.len() == last.len()));
+    }
+}
+
+#[test]
+fn test_streamed_matches_buffered() {
+    init();
+
+    let test_cases = vec![
+        ("mono_8bit.wav", "mono_8bit.c"),
+        ("stereo_16bit.wav", "stereo_16bit.c"),
+        ("mono_32bit.wav", "mono_32bit_base16.c"),
+    ];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let array_name = golden_path.file_stem().unwrap().to_string_lossy();
+        let options = wav2c::WavToCOptions {
+            no_comment: true,
+            format: if golden.contains("base16") {
+                wav2c::ArrayFormat::Base16
+            } else {
+                wav2c::ArrayFormat::Base10
+            },
+            ..Default::default()
+        };
+
+        // golden references were generated by the buffered implementation
+        let (_, streamed) =
+            wav2c::wav_to_c(&input_path, &array_name, &options, Vec::new()).unwrap();
+        let buffered = fs::read(&golden_path).unwrap();
+
+        assert!(streamed == buffered, "Mismatch for {}", golden);
+    }
+}
+
+#[test]
+fn test_asset_version() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let output = run_stdout(&input_path, &["--asset-version", "7"]);
+    assert!(
+        output.contains("#define MONO_8BIT_VERSION 7\n"),
+        "{}",
+        output
+    );
+
+    // the header CRC-32 matches the harness check of the same data
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("mono_8bit.c");
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args([
+            "--asset-version",
+            "7",
+            "--checksum",
+            "--header",
+            "--emit-test-harness",
+            "--output",
+        ])
+        .arg(&output_path)
+        .assert()
+        .success();
+    compile_with_gcc(&output_path);
+    let header = std::fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains("#define MONO_8BIT_VERSION 7\n"));
+    assert!(header.contains("/* Asset version 7, CRC-32 of the samples"));
+    let crc = &header[header.find("#define MONO_8BIT_CRC32 ").unwrap() + 24..];
+    let crc = &crc[..crc.find('\n').unwrap()];
+    let harness = std::fs::read_to_string(temp_dir.path().join("mono_8bit_harness.c")).unwrap();
+    assert!(
+        harness.contains(&format!("#define MONO_8BIT_EXPECTED_CRC32 {}\n", crc)),
+        "{}",
+        crc
+    );
+
+    // the CRC-32 of the samples is not that of the emitted bytes
+    for args in [["--bytes", "be"].as_slice(), &["--adpcm"]] {
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .args(["--checksum", "--header", "--output"])
+            .arg(&output_path)
+            .args(args)
+            .arg("--force")
+            .assert()
+            .failure()
+            .code(2);
+    }
+    let options = wav2c::WavToCOptions {
+        checksum: true,
+        header: true,
+        adpcm: true,
+        ..Default::default()
+    };
+    assert!(matches!(
+        wav2c::wav_to_c_array(&input_path, "mono_8bit", Some(&output_path), options),
+        Err(wav2c::WavToCError::InvalidInput(_))
+    ));
+}
+
+#[test]
+fn test_emit_test_harness() {
+    init();
+
+    let test_cases = vec![
+        ("mono_8bit.wav", vec![]),
+        ("stereo_16bit.wav", vec![]),
+        ("mono_32bit.wav", vec![]),
+        // elements wider than the samples are checked at the type width
+        ("mono_8bit.wav", vec!["--ctype", "int32_t"]),
+        ("mono_16bit_1000.wav", vec!["--ctype", "int64_t"]),
+    ];
+
+    for (input, args) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join("tone.c");
+
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .arg("--output")
+            .arg(&output_path)
+            .arg("--emit-test-harness")
+            .args(&args)
+            .assert()
+            .success();
+
+        let harness_path = temp_dir.path().join("tone_harness.c");
+        let harness = fs::read_to_string(&harness_path).unwrap();
+        let expected = harness
+            .lines()
+            .find_map(|line| line.strip_prefix("#define TONE_EXPECTED_CRC32 "))
+            .unwrap()
+            .trim_end_matches('u')
+            .to_string();
+
+        // link this time since the harness has main
+        let binary_path = temp_dir.path().join("harness");
+        Command::new("gcc")
+            .arg(&harness_path)
+            .arg("-o")
+            .arg(&binary_path)
+            .assert()
+            .success();
+        let run = Command::new(&binary_path).assert().success();
+        let printed = String::from_utf8(run.get_output().stdout.clone()).unwrap();
+
+        assert!(
+            printed.trim().ends_with(&format!("crc32 {}", expected)),
+            "Mismatch for {}: {}",
+            input,
+            printed
+        );
+    }
+}
+
+#[test]
+fn test_crc32_emitter() {
+    use wav2c::SampleEmitter;
+
+    let meta = wav2c::WavMetadata {
+        name: "check".to_string(),
+        source: "check.wav".to_string(),
+        sample_rate: 8000,
+        channels: 1,
+        bits_per_sample: 8,
+        sample_count: 9,
+        frame_channels: 1,
+        c_type: "int8_t".to_string(),
+        size_type: "size_t".to_string(),
+        loop_points: None,
+        cue_points: Vec::new(),
+        pitch: None,
+        onset: None,
+        info_tags: Vec::new(),
+    };
+
+    // standard CRC-32 check value
+    let mut emitter = wav2c::Crc32Emitter::default();
+    emitter.begin(&meta);
+    for byte in b"123456789" {
+        emitter.sample(*byte as i64);
+    }
+    emitter.end();
+
+    assert_eq!(emitter.crc32(), 0xcbf4_3926);
+}
+
+#[test]
+fn test_parity() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_16bit_1000.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("tone.c");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--parity", "64", "--header", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success();
+    let output = fs::read_to_string(&output_path).unwrap();
+    assert!(output.contains("#define TONE_PARITY_BLOCK 64\n#define TONE_PARITY_NO 16\n"));
+    let parity_start = output.find("const uint8_t tone_parity[16] = {").unwrap();
+    let (samples, parity) = output.split_at(parity_start);
+
+    // XOR of the little endian bytes of each block, the last of 40 samples
+    let expected: Vec<i64> = array_values(samples)
+        .chunks(64)
+        .map(|block| {
+            block
+                .iter()
+                .flat_map(|&value| (value as i16).to_le_bytes())
+                .fold(0, |parity, byte| parity ^ byte) as i64
+        })
+        .collect();
+    assert_eq!(array_values(parity), expected);
+
+    // recomputed on target from the header
+    let main_path = temp_dir.path().join("main.c");
+    fs::write(
+        &main_path,
+        "#include \"tone.h\"\n\
+        int main(void) {\n\
+        \tfor (size_t i = 0; i < TONE_SAMPLE_NO; i++) {\n\
+        \t\tif (i % TONE_PARITY_BLOCK == 0) {\n\
+        \t\t\tuint8_t parity = 0;\n\
+        \t\t\tfor (size_t j = i; j < i + TONE_PARITY_BLOCK && j < TONE_SAMPLE_NO; j++) {\n\
+        \t\t\t\tparity ^= (uint8_t)tone[j] ^ (uint8_t)((uint16_t)tone[j] >> 8);\n\
+        \t\t\t}\n\
+        \t\t\tif (parity != tone_parity[i / TONE_PARITY_BLOCK]) return 1;\n\
+        \t\t}\n\
+        \t}\n\
+        \treturn 0;\n}\n",
+    )
+    .unwrap();
+    let binary_path = temp_dir.path().join("main.out");
+    Command::new("gcc")
+        .args(["--include", "stdint.h", "--include", "stddef.h", "-o"])
+        .arg(&binary_path)
+        .arg(&main_path)
+        .arg(&output_path)
+        .assert()
+        .success();
+    Command::new(&binary_path).assert().success();
+
+    // of the bytes of the array type, including the sign extension of a wider type
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let output = run_stdout(&input_path, &["--parity", "64", "--ctype", "int16_t"]);
+    let parity_start = output.find("_parity[").unwrap();
+    let (samples, parity) = output.split_at(parity_start);
+    let expected: Vec<i64> = array_values(samples)
+        .chunks(64)
+        .map(|block| {
+            block
+                .iter()
+                .flat_map(|&value| (value as i16).to_le_bytes())
+                .fold(0, |parity, byte| parity ^ byte) as i64
+        })
+        .collect();
+    assert_eq!(array_values(parity), expected);
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--parity", "0"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_ctype() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let output = run_stdout(
+        &input_path,
+        &["--no-comment", "--array-name", "foo", "--ctype", "q15_t"],
+    );
+    assert!(output.contains("const q15_t foo[44100] = {"));
+
+    // overridden type still formats hex by bit depth
+    let output = run_stdout(&input_path, &["--ctype", "short", "--format", "base16"]);
+    assert!(output.contains("const short "));
+}
+
+#[test]
+fn test_wav_to_c_array_no_define() {
+    let test_cases = vec![("mono_8bit.wav", "mono_8bit_no_define.c")];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &["--no-comment", "--no-define", "--header"],
+        );
+        compile_with_gcc(&output_path);
+
+        let output = fs::read_to_string(&output_path).unwrap();
+        assert!(output.starts_with("const int8_t mono_8bit_no_define[44100] = {"));
+    }
+}
+
+#[test]
+fn test_wav_to_c_array_mutable() {
+    let test_cases = vec![
+        ("mono_8bit.wav", "mono_8bit_mutable.c", &[][..]),
+        (
+            "mono_16bit_1000.wav",
+            "mono_16bit_1000_mutable_align.c",
+            &["--align", "4"][..],
+        ),
+    ];
+
+    for (input, golden, args) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &[&["--no-comment", "--mutable", "--header"], args].concat(),
+        );
+        compile_with_gcc(&output_path);
+
+        // the attributes follow the declarator without the const qualifier
+        let output = fs::read_to_string(&output_path).unwrap();
+        assert!(!output.contains("const int"));
+        let header = fs::read_to_string(output_path.with_extension("h")).unwrap();
+        assert!(header.contains("extern int"), "{}", header);
+    }
+}
+
+#[test]
+fn test_wav_to_c_array_length_style() {
+    let test_cases = vec![
+        ("mono_8bit.wav", "mono_8bit.c", "const"),
+        ("mono_8bit.wav", "mono_8bit_length_macro.c", "macro"),
+        ("mono_8bit.wav", "mono_8bit_length_sizeof.c", "sizeof"),
+    ];
+
+    for (input, golden, style) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &["--no-comment", "--length-style", style, "--header"],
+        );
+        compile_with_gcc(&output_path);
+    }
+
+    // the array length is not the sample count of bytes
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .args(["--length-style", "sizeof", "--bytes", "le"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_emit_length_in_ms() {
+    let output = run_stdout(
+        &PathBuf::from("tests/fixtures/mono_8bit_loop.wav"),
+        &["--no-comment", "--emit-length-in-ms"],
+    );
+    // 4000 samples at 8 kHz
+    assert!(output.contains("#define MONO_8BIT_LOOP_DURATION_MS 500\n"));
+
+    // padded length is what plays back
+    let output = run_stdout(
+        &PathBuf::from("tests/fixtures/mono_16bit_1000.wav"),
+        &["--no-comment", "--emit-length-in-ms", "--fft-ready"],
+    );
+    assert!(output.contains("#define MONO_16BIT_1000_DURATION_MS 102\n"));
+}
+
+#[test]
+fn test_wav_to_c_array_stereo_frames() {
+    let test_cases = vec![("stereo_8bit_low.wav", "stereo_8bit_low_frames.c")];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &["--no-comment", "--group-stereo-as-struct-array", "--header"],
+        );
+        compile_with_gcc(&output_path);
+    }
+
+    // mono has no frames to group
+    let output = run_stdout(
+        &PathBuf::from("tests/fixtures/mono_8bit.wav"),
+        &["--no-comment", "--group-stereo-as-struct-array"],
+    );
+    assert!(output.contains("const int8_t mono_8bit[44100] = {"));
+    assert!(!output.contains("_frame_t"));
+}
+
+#[test]
+fn test_detect_pitch() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let output = run_stdout(&input_path, &["--detect-pitch"]);
+    let pitch: f64 = output
+        .lines()
+        .find_map(|line| line.strip_prefix("#define STEREO_16BIT_PITCH_HZ "))
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((pitch - 440.0).abs() < 1.0, "detected {} Hz", pitch);
+    assert!(output.contains("* Pitch: 440."));
+
+    // silence has no pitch
+    let output = run_stdout(
+        &PathBuf::from("tests/fixtures/mono_8bit_silence.wav"),
+        &["--detect-pitch"],
+    );
+    assert!(output.contains("* Pitch: unknown\n"));
+    assert!(output.contains("#define MONO_8BIT_SILENCE_PITCH_HZ 0.0\n"));
+}
+
+#[test]
+fn test_wav_to_c_array_align() {
+    let test_cases = vec![("mono_8bit.wav", "mono_8bit_align.c")];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &["--no-comment", "--align", "4", "--header"],
+        );
+        compile_with_gcc(&output_path);
+    }
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_8bit.wav")
+        .args(["--align", "3"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_record_command() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let output = run_stdout(
+        &input_path,
+        &["--record-command", "--gain", "0.5", "--prefix", "/* a b */"],
+    );
+    let command = output
+        .lines()
+        .find_map(|line| line.strip_prefix("* Command: "))
+        .unwrap();
+
+    assert!(command.starts_with("wav2c "));
+    assert!(command.contains("tests/fixtures/mono_8bit.wav"));
+    assert!(command.contains("--record-command --gain 0.5 --prefix '/* a b *\\/'"));
+
+    // no comment, nothing to record
+    let output = run_stdout(&input_path, &["--record-command", "--no-comment"]);
+    assert!(!output.contains("Command:"));
+}
+
+#[test]
+fn test_wav_to_c_array_suffix() {
+    let test_cases = vec![("mono_8bit.wav", "mono_8bit_suffix.c")];
+
+    for (input, golden) in test_cases {
+        let input_path = PathBuf::from(format!("tests/fixtures/{}", input));
+        let golden_path = PathBuf::from(format!("tests/golden/{}", golden));
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let output_path = temp_dir.path().join(golden);
+
+        wav_to_c_case(
+            &input_path,
+            &golden_path,
+            Some(&output_path),
+            &["--no-comment", "--suffix", "/* john was here too */"],
+        );
+        compile_with_gcc(&output_path);
+
+        let output = fs::read_to_string(&output_path).unwrap();
+        assert!(output.ends_with("};\n\n/* john was here too */"));
+    }
+}
+
+#[test]
+fn test_wav_to_c_array_banners() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let golden_path = PathBuf::from("tests/golden/mono_8bit_banners.c");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("mono_8bit_banners.c");
+
+    wav_to_c_case(
+        &input_path,
+        &golden_path,
+        Some(&output_path),
+        &[
+            "--no-comment",
+            "--header",
+            "--banners",
+            "--prefix",
+            "/* john was here */",
+            "--suffix",
+            "/* john was here too */",
+        ],
+    );
+    compile_with_gcc(&output_path);
+
+    // in order, each section following its banner
+    let output = fs::read_to_string(&output_path).unwrap();
+    let positions: Vec<usize> = ["PREFIX", "DEFINES", "ARRAY", "SUFFIX"]
+        .iter()
+        .map(|title| {
+            output
+                .find(&format!("/* ===== {} ===== */\n", title))
+                .unwrap()
+        })
+        .collect();
+    assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(output.contains("/* ===== ARRAY ===== */\nconst int8_t mono_8bit_banners[44100]"));
+    assert!(output.ends_with("};\n\n/* ===== SUFFIX ===== */\n/* john was here too */"));
+
+    let output = run_stdout(&input_path, &["--no-comment"]);
+    assert!(!output.contains("====="));
+}
+
+#[test]
+fn test_bytes() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let le = run_stdout(&input_path, &["--no-comment", "--bytes", "le"]);
+    let be = run_stdout(&input_path, &["--no-comment", "--bytes", "be"]);
+
+    assert!(le.contains("const size_t STEREO_16BIT_BYTE_NO = 88200;"));
+    assert!(le.contains("const uint8_t stereo_16bit[88200] = {"));
+
+    let le = array_values(&le);
+    let be = array_values(&be);
+    assert_eq!(le.len(), samples.len() * 2);
+
+    // a negative sample so both bytes are non-trivial
+    let i = samples.iter().position(|&v| v < -1000).unwrap();
+    let value = samples[i] as i16 as u16;
+    assert_eq!(
+        le[2 * i..2 * i + 2],
+        [(value & 0xff) as i64, (value >> 8) as i64]
+    );
+    assert_eq!(
+        be[2 * i..2 * i + 2],
+        [(value >> 8) as i64, (value & 0xff) as i64]
+    );
+}
+
+#[test]
+fn test_byte_swap() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let output = run_stdout(
+        &input_path,
+        &["--no-comment", "--byte-swap", "--format", "base16"],
+    );
+    assert!(output.contains("const int16_t stereo_16bit[44100] = {"));
+    let swapped = array_values(&run_stdout(&input_path, &["--no-comment", "--byte-swap"]));
+    assert_eq!(swapped.len(), samples.len());
+
+    // 0x0804 is the second sample of the golden
+    assert_eq!(samples[1], 0x0804);
+    assert_eq!(swapped[1], 0x0408);
+    assert!(
+        output.contains(" 0x0000, 0x0408, 0x0110,"),
+        "{}",
+        &output[..300]
+    );
+    for (sample, swapped) in samples.iter().zip(&swapped) {
+        assert_eq!((*sample as i16).swap_bytes() as i64, *swapped);
+    }
+
+    // 32-bit values reverse all four bytes
+    let input_path = PathBuf::from("tests/fixtures/mono_32bit.wav");
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let swapped = array_values(&run_stdout(&input_path, &["--no-comment", "--byte-swap"]));
+    let i = samples.iter().position(|&v| v < -1000).unwrap();
+    assert_eq!((samples[i] as i32).swap_bytes() as i64, swapped[i]);
+}
+
+#[test]
+fn test_offset_binary() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let input_path = temp_dir.path().join("square.wav");
+    // silence then a square the gain saturates to both extremes
+    gen_wav_samples(
+        &input_path,
+        &[
+            "-b",
+            "16",
+            "-s",
+            "8000",
+            "-d",
+            "0.01",
+            "-w",
+            "square",
+            "--lead-silence",
+            "0.01",
+        ],
+    );
+
+    let signed = array_values(&run_stdout(&input_path, &["--no-comment", "--gain", "2"]));
+    let output = run_stdout(
+        &input_path,
+        &[
+            "--no-comment",
+            "--gain",
+            "2",
+            "--offset-binary",
+            "--format",
+            "base16",
+        ],
+    );
+    assert!(
+        output.contains("const uint16_t square[160] = {"),
+        "{}",
+        output
+    );
+    assert!(
+        output.contains(" 0x8000,") && output.contains(" 0x0000,") && output.contains(" 0xffff,")
+    );
+    let offset = array_values(&run_stdout(
+        &input_path,
+        &["--no-comment", "--gain", "2", "--offset-binary"],
+    ));
+    for (from, to) in [(-32768, 0x0000), (0, 0x8000), (32767, 0xffff)] {
+        let i = signed.iter().position(|&v| v == from).unwrap();
+        assert_eq!(offset[i], to, "{}", from);
+    }
+    for (signed, offset) in signed.iter().zip(&offset) {
+        assert_eq!(*offset, signed + 32768);
+    }
+
+    // samples below the type width are offset by the midpoint of their depth
+    let offset = array_values(&run_stdout(
+        &input_path,
+        &[
+            "--no-comment",
+            "--gain",
+            "2",
+            "--bit-depth",
+            "12",
+            "--offset-binary",
+        ],
+    ));
+    assert_eq!(offset[0], 0x800);
+    assert_eq!(offset.iter().min(), Some(&0));
+    assert_eq!(offset.iter().max(), Some(&0xfff));
+}
+
+#[test]
+fn test_raw_bytes_only() {
+    let input_path = PathBuf::from("tests/fixtures/stereo_16bit.wav");
+
+    let raw = run_stdout(&input_path, &["--raw-bytes-only"]);
+    assert!(
+        raw.chars()
+            .all(|c| c.is_ascii_digit() || c == ',' || c.is_ascii_whitespace()),
+        "{}",
+        &raw[..200]
+    );
+
+    // the same values as the little endian byte array
+    let bytes = run_stdout(&input_path, &["--no-comment", "--bytes", "le"]);
+    let values: Vec<i64> = raw
+        .split(',')
+        .map(str::trim)
+        .filter(|v| !v.is_empty())
+        .map(|v| v.parse().unwrap())
+        .collect();
+    assert_eq!(values, array_values(&bytes));
+
+    let be = run_stdout(&input_path, &["--raw-bytes-only", "--bytes", "be"]);
+    assert!(!be.contains('{'));
+    assert_ne!(be, raw);
+}
+
+#[test]
+fn test_name_template() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let output = run_stdout(&input_path, &["--name-template", "{stem}_{rate}"]);
+    assert!(output.contains("const int8_t mono_8bit_44100[44100] = {"));
+    assert!(output.contains("MONO_8BIT_44100_SAMPLE_NO"));
+
+    // the array name is the stem and the result is sanitized
+    let output = run_stdout(
+        &input_path,
+        &[
+            "--array-name",
+            "bell",
+            "--name-template",
+            "{stem} {channels}ch {bits}-bit",
+        ],
+    );
+    assert!(output.contains("const int8_t bell_1ch_8bit[44100] = {"));
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--name-template", "{stem}_{hz}"])
+        .assert()
+        .failure();
+    let error = String::from_utf8_lossy(&cmd.get_output().stderr).into_owned();
+    assert!(
+        error.contains("Unknown name template placeholder 'hz'"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_mark_onset() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit_padded.wav");
+
+    // 0.05 s of lead silence at 8000 Hz then a sine starting at zero
+    let output = run_stdout(&input_path, &["--no-comment", "--mark-onset"]);
+    assert!(
+        output.contains("#define MONO_8BIT_PADDED_ONSET 401\n"),
+        "{}",
+        output
+    );
+    let values = array_values(&output);
+    assert!(values[..401].iter().all(|&v| v == 0));
+    assert_ne!(values[401], 0);
+    // the array is not modified
+    assert_eq!(
+        values,
+        array_values(&run_stdout(&input_path, &["--no-comment"]))
+    );
+
+    // nothing reaches full scale so the onset is the end
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["-v", "--no-comment", "--mark-onset", "0"])
+        .assert()
+        .success();
+    let output = String::from_utf8_lossy(&cmd.get_output().stdout).into_owned();
+    assert!(
+        output.contains("#define MONO_8BIT_PADDED_ONSET 1600\n"),
+        "{}",
+        output
+    );
+    let error = String::from_utf8_lossy(&cmd.get_output().stderr).into_owned();
+    assert!(
+        error.contains("No sample above the onset threshold"),
+        "{}",
+        error
+    );
+
+    // the threshold is relative to the full scale of 24-bit samples, not their 32-bit type
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let padded_path = temp_dir.path().join("padded_24bit.wav");
+    gen_wav_samples(
+        &padded_path,
+        &[
+            "-b",
+            "24",
+            "-s",
+            "8000",
+            "-d",
+            "0.1",
+            "--lead-silence",
+            "0.05",
+        ],
+    );
+    let output = run_stdout(&padded_path, &["--no-comment", "--mark-onset"]);
+    assert!(
+        output.contains("#define PADDED_24BIT_ONSET 401\n"),
+        "{}",
+        output
+    );
+}
+
+#[test]
+fn test_comment_extra() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let output = run_stdout(
+        &input_path,
+        &[
+            "--comment-extra",
+            "SPDX-License-Identifier: CC0-1.0",
+            "--comment-extra",
+            "Author: John",
+        ],
+    );
+    let comment = &output[..output.find("*/").unwrap()];
+
+    assert!(comment.contains("\n* SPDX-License-Identifier: CC0-1.0\n* Author: John\n"));
+    assert!(output.contains("*/\n\nconst size_t"));
+}
+
+/// Reference IMA ADPCM encoder after the widely used adpcm.c by Jack Jansen
+fn reference_ima_adpcm(samples: &[i64]) -> Vec<u8> {
+    const INDEX_TABLE: [i32; 16] = [-1, -1, -1, -1, 2, 4, 6, 8, -1, -1, -1, -1, 2, 4, 6, 8];
+    const STEPSIZE_TABLE: [i32; 89] = [
+        7, 8, 9, 10, 11, 12, 13, 14, 16, 17, 19, 21, 23, 25, 28, 31, 34, 37, 41, 45, 50, 55, 60,
+        66, 73, 80, 88, 97, 107, 118, 130, 143, 157, 173, 190, 209, 230, 253, 279, 307, 337, 371,
+        408, 449, 494, 544, 598, 658, 724, 796, 876, 963, 1060, 1166, 1282, 1411, 1552, 1707, 1878,
+        2066, 2272, 2499, 2749, 3024, 3327, 3660, 4026, 4428, 4871, 5358, 5894, 6484, 7132, 7845,
+        8630, 9493, 10442, 11487, 12635, 13899, 15289, 16818, 18500, 20350, 22385, 24623, 27086,
+        29794, 32767,
+    ];
+
+    let mut valpred: i32 = 0;
+    let mut index: i32 = 0;
+    let mut step = STEPSIZE_TABLE[0];
+    let mut output = Vec::new();
+    let mut outputbuffer = 0u8;
+    let mut bufferstep = false;
+
+    for &val in samples {
+        let mut diff = val as i32 - valpred;
+        let sign = if diff < 0 { 8 } else { 0 };
+        if sign != 0 {
+            diff = -diff;
+        }
+
+        let mut delta = 0;
+        let mut vpdiff = step >> 3;
+        if diff >= step {
+            delta = 4;
+            diff -= step;
+            vpdiff += step;
+        }
+        step >>= 1;
+        if diff >= step {
+            delta |= 2;
+            diff -= step;
+            vpdiff += step;
+        }
+        step >>= 1;
+        if diff >= step {
+            delta |= 1;
+            vpdiff += step;
+        }
+
+        if sign != 0 {
+            valpred -= vpdiff;
+        } else {
+            valpred += vpdiff;
+        }
+        valpred = valpred.clamp(-32768, 32767);
+
+        delta |= sign;
+        index = (index + INDEX_TABLE[delta as usize]).clamp(0, 88);
+        step = STEPSIZE_TABLE[index as usize];
+
+        if bufferstep {
+            output.push(outputbuffer | ((delta as u8) << 4));
+        } else {
+            outputbuffer = delta as u8;
+        }
+        bufferstep = !bufferstep;
+    }
+    if bufferstep {
+        output.push(outputbuffer);
+    }
+
+    output
+}
+
+#[test]
+fn test_adpcm() {
+    let input_path = PathBuf::from("tests/fixtures/mono_64bit_float.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let output = run_stdout(&input_path, &["--no-comment", "--adpcm"]);
+
+    assert!(output.contains("#define MONO_64BIT_FLOAT_PREDICTOR 0\n"));
+    assert!(output.contains("#define MONO_64BIT_FLOAT_STEP_INDEX 0\n"));
+    assert!(output.contains("const uint8_t mono_64bit_float[400] = {"));
+
+    let encoded: Vec<u8> = array_values(&output).iter().map(|&b| b as u8).collect();
+    assert_eq!(encoded, reference_ima_adpcm(&samples));
+
+    // 24-bit samples are shifted down by 8 bits to the 16-bit range of the encoder
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let wide_path = temp_dir.path().join("adpcm_24bit.wav");
+    gen_wav_samples(&wide_path, &["-b", "24", "-s", "8000", "-d", "0.1"]);
+    let samples: Vec<i64> = array_values(&run_stdout(&wide_path, &["--no-comment"]))
+        .iter()
+        .map(|&v| v >> 8)
+        .collect();
+    let output = run_stdout(&wide_path, &["--no-comment", "--adpcm"]);
+    let encoded: Vec<u8> = array_values(&output).iter().map(|&b| b as u8).collect();
+    assert_eq!(encoded, reference_ima_adpcm(&samples));
+}
+
+#[test]
+fn test_pack_bits() {
+    let input_path = PathBuf::from("tests/fixtures/mono_12bit_extensible.wav");
+
+    let samples = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let output = run_stdout(&input_path, &["--no-comment", "--pack-bits", "12"]);
+
+    // two 12-bit samples per three bytes
+    assert_eq!(samples.len(), 800);
+    assert!(output.contains("const size_t MONO_12BIT_EXTENSIBLE_BYTE_NO = 1200;\n"));
+    assert!(output.contains("#define MONO_12BIT_EXTENSIBLE_PACK_BITS 12\n"));
+    assert!(output.contains("const uint8_t mono_12bit_extensible[1200] = {"));
+
+    let bytes: Vec<u8> = array_values(&output).iter().map(|&b| b as u8).collect();
+    assert_eq!(bytes.len(), 1200);
+    let unpacked: Vec<i64> = bytes
+        .chunks_exact(3)
+        .flat_map(|b| {
+            let s0 = b[0] as i64 | (b[1] as i64 & 0xf) << 8;
+            let s1 = (b[1] as i64) >> 4 | (b[2] as i64) << 4;
+            // sign extend from bit 11
+            [s0, s1].map(|s| (s << 52) >> 52)
+        })
+        .collect();
+    assert_eq!(unpacked, samples);
+
+    // an odd count of 12-bit samples ends with a half filled byte
+    let output = run_stdout(
+        &input_path,
+        &["--no-comment", "--pack-bits", "12", "--end-sample", "799"],
+    );
+    assert!(output.contains("const uint8_t mono_12bit_extensible[1199] = {"));
+
+    // narrower than the samples
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--pack-bits", "10"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_info() {
+    let output = run_stdout(Path::new("tests/fixtures/mono_8bit.wav"), &["--info"]);
+    assert!(output.contains("Sample rate: 44100 Hz\n"), "{}", output);
+    assert!(output.contains("Channels: 1\n"));
+    assert!(output.contains("Bits per sample: 8\n"));
+    assert!(output.contains("Sample format: int\n"));
+    // nothing is converted
+    assert!(!output.contains("const"));
+
+    let output = run_stdout(Path::new("tests/fixtures/stereo_16bit.wav"), &["--info"]);
+    assert!(output.contains("Channels: 2\n"), "{}", output);
+    assert!(output.contains("Bits per sample: 16\n"));
+}
+
+#[test]
+fn test_list_formats() {
+    // no input is needed
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("--list-formats")
+        .assert()
+        .success();
+    let output = String::from_utf8(cmd.get_output().stdout.clone()).unwrap();
+    assert!(output.contains("  base16  "), "{}", output);
+    assert!(output.contains("  json  "));
+    assert!(output.contains("  --adpcm  "));
+}
+
+#[test]
+fn test_preview_dry_run() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("audio.c");
+
+    let assert = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_16bit_1000.wav")
+        .args(["--preview", "--dry-run", "--output"])
+        .arg(&output_path)
+        .env("COLUMNS", "41")
+        .assert()
+        .success();
+    let output = assert.get_output();
+    assert!(output.stdout.is_empty());
+    assert!(!output_path.exists());
+
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let plot: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with('|'))
+        .collect();
+    assert_eq!(plot.len(), 11, "{}", stderr);
+    assert!(plot.iter().any(|line| line.contains('#')));
+    // one column per sample bucket within the terminal width
+    assert!(plot.iter().all(|line| line.len() <= 41));
+    assert!(stderr.contains("1000 samples, 25 per column"), "{}", stderr);
+}
+
+#[cfg(feature = "waveform-png")]
+#[test]
+fn test_waveform_png() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let png_path = temp_dir.path().join("waveform.png");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_16bit_1000.wav")
+        .arg("--waveform-png")
+        .arg(&png_path)
+        .assert()
+        .success();
+    let png = std::fs::read(&png_path).unwrap();
+    assert!(png.starts_with(b"\x89PNG\r\n\x1a\n"));
+    assert_eq!(&png[12..16], b"IHDR");
+    // 1000 samples decimated to at most 800 columns are 2 per column
+    assert_eq!(u32::from_be_bytes(png[16..20].try_into().unwrap()), 500);
+    assert_eq!(u32::from_be_bytes(png[20..24].try_into().unwrap()), 201);
+    assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+
+    // not overwritten without --force, nor written on a dry run
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_16bit_1000.wav")
+        .arg("--waveform-png")
+        .arg(&png_path)
+        .assert()
+        .failure()
+        .code(3);
+    let dry_path = temp_dir.path().join("dry.png");
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_16bit_1000.wav")
+        .arg("--dry-run")
+        .arg("--waveform-png")
+        .arg(&dry_path)
+        .assert()
+        .success();
+    assert!(!dry_path.exists());
+}
+
+#[test]
+fn test_profile() {
+    let assert = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_16bit_1000.wav")
+        .args(["--profile", "-vv"])
+        .assert()
+        .success();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    for stage in ["decode", "transform", "format", "write"] {
+        assert!(
+            stderr
+                .lines()
+                .any(|line| line.contains("Profile:") && line.contains(stage)),
+            "{}",
+            stderr
+        );
+    }
+
+    // the timings are debug level only
+    let assert = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_16bit_1000.wav")
+        .args(["--profile", "-v"])
+        .assert()
+        .success();
+    let stderr = String::from_utf8_lossy(&assert.get_output().stderr);
+    assert!(!stderr.contains("Profile:"), "{}", stderr);
+}
+
+#[test]
+fn test_split_files() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("audio.c");
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--split-files", "10000", "--output"])
+        .arg(&output_path)
+        .assert()
+        .success();
+    // the parts are written in place of the output
+    assert!(!output_path.exists());
+    let header = fs::read_to_string(output_path.with_extension("h")).unwrap();
+    assert!(header.contains("#define AUDIO_SAMPLE_NO 44100\n"));
+    assert!(header.contains("#define AUDIO_PART_COUNT 5\n"));
+
+    let expected = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    let part_paths: Vec<PathBuf> = (0..5)
+        .map(|i| temp_dir.path().join(format!("audio_part{}.c", i)))
+        .collect();
+    let mut values = Vec::new();
+    for part_path in &part_paths {
+        values.extend(array_values(&fs::read_to_string(part_path).unwrap()));
+    }
+    assert_eq!(values, expected);
+    assert!(!temp_dir.path().join("audio_part5.c").exists());
+
+    // the parts link together and the helper rebuilds the whole array
+    let main = format!(
+        "#include \"audio.h\"\n\
+        static int8_t whole[AUDIO_SAMPLE_NO];\n\
+        int main(void) {{\n\
+        \taudio_concat(whole);\n\
+        \treturn whole[10000] != {} || whole[AUDIO_SAMPLE_NO - 1] != {};\n\
+        }}\n",
+        expected[10000],
+        expected[expected.len() - 1]
+    );
+    let source_path = temp_dir.path().join("main.c");
+    fs::write(&source_path, main).unwrap();
+    let binary_path = source_path.with_extension("out");
+    Command::new("gcc")
+        .arg("--include")
+        .arg("stdint.h")
+        .arg("--include")
+        .arg("stddef.h")
+        .arg("-o")
+        .arg(&binary_path)
+        .arg(&source_path)
+        .args(&part_paths)
+        .assert()
+        .success();
+    Command::new(&binary_path).assert().success();
+
+    // each part has the CRC-32 of its little endian sample bytes
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args([
+            "--split-files",
+            "10000",
+            "--checksum",
+            "--force",
+            "--output",
+        ])
+        .arg(&output_path)
+        .assert()
+        .success();
+    let header = fs::read_to_string(output_path.with_extension("h")).unwrap();
+    let table = header
+        .lines()
+        .find_map(|line| {
+            line.strip_prefix("static const uint32_t audio_chunk_crc[AUDIO_PART_COUNT] = {")
+        })
+        .unwrap_or_else(|| panic!("{}", header));
+    let crcs: Vec<u32> = table
+        .trim_end_matches("};")
+        .split(", ")
+        .map(|crc| u32::from_str_radix(&crc[2..crc.len() - 1], 16).unwrap())
+        .collect();
+    // table driven so independent of the bitwise implementation
+    let table: Vec<u32> = (0..256u32)
+        .map(|n| {
+            (0..8).fold(n, |c, _| {
+                if c & 1 == 1 {
+                    0xedb8_8320 ^ (c >> 1)
+                } else {
+                    c >> 1
+                }
+            })
+        })
+        .collect();
+    let crc32 = |values: &[i64]| {
+        !values.iter().fold(0xffff_ffffu32, |crc, &v| {
+            table[((crc ^ v as u8 as u32) & 0xff) as usize] ^ (crc >> 8)
+        })
+    };
+    assert_eq!(crcs.len(), 5);
+    for (part_path, crc) in part_paths.iter().zip(&crcs) {
+        let part = array_values(&fs::read_to_string(part_path).unwrap());
+        assert_eq!(*crc, crc32(&part), "{}", part_path.display());
+    }
+    assert!(header.contains(&format!(
+        "#define AUDIO_CRC32 0x{:08x}u\n",
+        crc32(&expected)
+    )));
+    // the table compiles in the header
+    compile_with_gcc(&source_path);
+}
+
+#[test]
+fn test_single_header() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--single-header", "--output-dir"])
+        .arg(temp_dir.path())
+        .assert()
+        .success();
+    let header_path = temp_dir.path().join("mono_8bit.h");
+    let header = fs::read_to_string(&header_path).unwrap();
+    assert!(header.starts_with("#ifndef _MONO_8BIT_H_\n#define _MONO_8BIT_H_\n"));
+    assert!(header.contains("static const size_t MONO_8BIT_SAMPLE_NO = 44100;\n"));
+    assert!(header.contains("static const int8_t mono_8bit[44100] = {"));
+    assert!(header.trim_end().ends_with("#endif"));
+    assert!(!temp_dir.path().join("mono_8bit.c").exists());
+
+    let expected = array_values(&run_stdout(&input_path, &["--no-comment"]));
+    assert_eq!(array_values(&header), expected);
+
+    // included from two translation units, and twice in one, without duplicate symbols
+    let other = "#include \"mono_8bit.h\"\n\
+        #include \"mono_8bit.h\"\n\
+        int other(size_t i) { return mono_8bit[i]; }\n";
+    let main = format!(
+        "#include \"mono_8bit.h\"\n\
+        int other(size_t i);\n\
+        int main(void) {{\n\
+        \treturn MONO_8BIT_SAMPLE_NO != 44100 || other(10) != {} || mono_8bit[20] != {};\n\
+        }}\n",
+        expected[10], expected[20]
+    );
+    let other_path = temp_dir.path().join("other.c");
+    let main_path = temp_dir.path().join("main.c");
+    fs::write(&other_path, other).unwrap();
+    fs::write(&main_path, main).unwrap();
+    let binary_path = main_path.with_extension("out");
+    Command::new("gcc")
+        .arg("-o")
+        .arg(&binary_path)
+        .arg(&main_path)
+        .arg(&other_path)
+        .assert()
+        .success();
+    Command::new(&binary_path).assert().success();
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--single-header", "--lang", "cpp"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_bank_entry() {
+    init();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let mut sources = Vec::new();
+    for input in ["mono_8bit", "mono_8bit_info"] {
+        let output_path = temp_dir.path().join(format!("{}.c", input));
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(format!("tests/fixtures/{}.wav", input))
+            .args(["--bank-entry", "--header", "--output"])
+            .arg(&output_path)
+            .assert()
+            .success();
+        sources.push(output_path);
+    }
+    let output = fs::read_to_string(&sources[1]).unwrap();
+    let entry = "#define MONO_8BIT_INFO_BANK_ENTRY { .name = \"mono_8bit_info\", .rate = 8000, \
+        .len = 800, .data = mono_8bit_info }";
+    assert!(output.trim_end().ends_with(entry), "{}", output);
+    let header = fs::read_to_string(sources[1].with_extension("h")).unwrap();
+    assert!(header.contains(entry), "{}", header);
+
+    // the entries initialize a table of clips from the headers
+    let bank = "#include \"mono_8bit.h\"\n\
+        #include \"mono_8bit_info.h\"\n\
+        #include <string.h>\n\
+        struct sound { const char *name; uint32_t rate; size_t len; const int8_t *data; };\n\
+        static const struct sound bank[] = { MONO_8BIT_BANK_ENTRY, MONO_8BIT_INFO_BANK_ENTRY };\n\
+        int main(void) {\n\
+        \treturn bank[0].len != MONO_8BIT_SAMPLE_NO || bank[1].rate != 8000\n\
+        \t\t|| strcmp(bank[1].name, \"mono_8bit_info\") || bank[1].data[1] != mono_8bit_info[1];\n\
+        }\n";
+    let bank_path = temp_dir.path().join("bank.c");
+    fs::write(&bank_path, bank).unwrap();
+    let binary_path = bank_path.with_extension("out");
+    Command::new("gcc")
+        .arg("--include")
+        .arg("stdint.h")
+        .arg("--include")
+        .arg("stddef.h")
+        .arg("-o")
+        .arg(&binary_path)
+        .arg(&bank_path)
+        .args(&sources)
+        .assert()
+        .success();
+    Command::new(&binary_path).assert().success();
+}
+
+#[test]
+fn test_gen_wav_waveform() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("square.wav");
+
+    // a naive square is only ever the two full scale levels, half the time each
+    let samples = gen_wav_samples(&output_path, &["-b", "8", "-s", "8000", "-w", "square"]);
+    let high = samples.iter().filter(|&&v| v == 127).count();
+    let low = samples.iter().filter(|&&v| v == -127).count();
+    assert_eq!(high + low, samples.len());
+    assert!(
+        high.abs_diff(low) <= samples.len() / 100,
+        "{} {}",
+        high,
+        low
+    );
+
+    // band limited it rings around the levels instead
+    let samples = gen_wav_samples(
+        &output_path,
+        &["-b", "8", "-s", "8000", "-w", "square", "--band-limit"],
+    );
+    assert!(samples.iter().any(|v| v.abs() != 127));
+
+    // the f64 phase sine is that of the sample index, without the f32 rounding of the default
+    let samples = gen_wav_samples(&output_path, &["-b", "16", "-d", "0.1", "--f64-phase"]);
+    let expected: Vec<i32> = (0..samples.len())
+        .map(|t| {
+            let phase = (440.0 * t as f64 / 44100.0).fract();
+            (32767.0 * (2.0 * std::f64::consts::PI * phase).sin()) as i32
+        })
+        .collect();
+    assert_eq!(samples, expected);
+
+    for waveform in ["saw", "triangle", "noise"] {
+        let samples = gen_wav_samples(&output_path, &["-b", "16", "-w", waveform]);
+        assert!(samples.iter().any(|&v| v > 30000), "{}", waveform);
+        assert!(samples.iter().any(|&v| v < -30000), "{}", waveform);
+    }
+}
+
+#[test]
+fn test_wave_format_extensible() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    for (fixture, container, valid, c_type) in [
+        ("mono_12bit_extensible.wav", "16", 12, "int16_t"),
+        ("mono_20bit_extensible.wav", "24", 20, "int32_t"),
+    ] {
+        let input_path = PathBuf::from(format!("{}/{}", FIXTURES_DIR, fixture));
+        let output = run_stdout(&input_path, &["--no-comment"]);
+        assert!(output.contains(&format!("const {} ", c_type)), "{}", output);
+
+        // the valid bits are the top of the same tone at the container depth
+        let container_path = temp_dir.path().join(fixture);
+        let full = gen_wav_samples(
+            &container_path,
+            &[
+                "-c",
+                "1",
+                "-b",
+                container,
+                "-s",
+                "8000",
+                "-d",
+                "0.1",
+                "--f64-phase",
+            ],
+        );
+        let expected: Vec<i64> = full
+            .iter()
+            .map(|&v| (v >> (container.parse::<u32>().unwrap() - valid)) as i64)
+            .collect();
+        assert_eq!(array_values(&output), expected);
+
+        let output = run_stdout(&input_path, &["--info"]);
+        assert!(output.contains(&format!("Bits per sample: {}\n", container)));
+        assert!(output.contains(&format!("Valid bits per sample: {}\n", valid)));
+    }
+
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg("tests/fixtures/mono_12bit_extensible.wav")
+        .arg("-v")
+        .assert()
+        .success();
+    let error = String::from_utf8_lossy(&cmd.get_output().stderr).into_owned();
+    assert!(
+        error.contains("16 bit container holds 12 valid bits"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn test_gen_wav_amplitude() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("half.wav");
+
+    let samples = gen_wav_samples(&output_path, &["-b", "16", "--amplitude", "0.5"]);
+    let peak = samples.iter().map(|v| v.abs()).max().unwrap();
+    assert!((16380..=16384).contains(&peak), "{}", peak);
+
+    Command::cargo_bin("gen_wav")
+        .unwrap()
+        .args(["--amplitude", "1.5"])
+        .arg(&output_path)
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_gen_wav_silence() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("padded.wav");
+
+    let samples = gen_wav_samples(
+        &output_path,
+        &[
+            "-s",
+            "8000",
+            "-d",
+            "0.5",
+            "--lead-silence",
+            "0.25",
+            "--trail-silence",
+            "0.125",
+        ],
+    );
+    assert_eq!(samples.len(), 2000 + 4000 + 1000);
+    assert!(samples[..2000].iter().all(|&v| v == 0));
+    // the tone starts from zero phase so its second sample is the first non-zero
+    assert_eq!(samples[2000], 0);
+    assert_ne!(samples[2001], 0);
+    assert!(samples[6000..].iter().all(|&v| v == 0));
+}
+
+#[test]
+fn test_gen_wav_pan_phase() {
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("stereo.wav");
+    let peaks = |samples: &[i32]| {
+        let peak = |channel: usize| {
+            samples
+                .iter()
+                .skip(channel)
+                .step_by(2)
+                .map(|v| v.abs())
+                .max()
+                .unwrap()
+        };
+        (peak(0), peak(1))
+    };
+
+    // panned right the left is attenuated but the right stays at full scale
+    let samples = gen_wav_samples(&output_path, &["-c", "2", "--pan", "0.5"]);
+    let (left, right) = peaks(&samples);
+    assert!(left < right * 6 / 10, "{} {}", left, right);
+    assert!(right > 32_000);
+
+    // a phase offset gives the same level but different content in each channel
+    let samples = gen_wav_samples(&output_path, &["-c", "2", "--channel-phase", "90"]);
+    let (left, right) = peaks(&samples);
+    assert!(left.abs_diff(right) < 10, "{} {}", left, right);
+    assert!(samples.chunks(2).any(|frame| frame[0] != frame[1]));
+    // a quarter period ahead the right starts at the peak
+    assert_eq!(samples[0], 0);
+    assert_eq!(samples[1], 32_767);
+}
+
+#[test]
+fn test_max_bytes() {
+    init();
+
+    // 22050 samples are 88200 bytes as int32 but only 22050 as int8
+    let input_path = PathBuf::from("tests/fixtures/mono_32bit.wav");
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--max-bytes", "50000"])
+        .assert()
+        .failure();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(error.contains("88200 bytes"), "{}", error);
+
+    let output = run_stdout(&input_path, &["--max-bytes", "50000", "--bit-depth", "8"]);
+    assert_eq!(array_values(&output).len(), 22050);
+    run_stdout(&input_path, &["--max-bytes", "88200"]);
+
+    // packed 800 12-bit samples are 1200 bytes rather than 1600 as int16
+    let input_path = PathBuf::from("tests/fixtures/mono_12bit_extensible.wav");
+    run_stdout(&input_path, &["--max-bytes", "1200", "--pack-bits", "12"]);
+    let cmd = Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--max-bytes", "1199", "--pack-bits", "12"])
+        .assert()
+        .failure();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(error.contains("1200 bytes"), "{}", error);
+}
+
+#[test]
+fn test_comment_fields() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+
+    let output = run_stdout(&input_path, &["--comment-fields", "spec"]);
+    assert!(
+        output.starts_with("/*\n* Sample rate: 44100 Hz, Channels: 1, Bits per sample: 8\n*/\n\n"),
+        "{}",
+        output
+    );
+
+    let output = run_stdout(&input_path, &["--comment-fields", "tool,source,repo"]);
+    let comment = &output[..output.find("*/").unwrap()];
+    assert!(comment.contains("* Generated by wav2c from mono_8bit.wav\n"));
+    assert!(comment.contains("https://github.com/tuna-f1sh/wav2c"));
+    assert!(!comment.contains("Sample rate"));
+
+    // all fields by default
+    let output = run_stdout(&input_path, &[]);
+    let expected = format!(
+        "* Generated by wav2c v{} from mono_8bit.wav\n",
+        env!("CARGO_PKG_VERSION")
+    );
+    assert!(output.contains(&expected));
+}
+
+#[test]
+fn test_info_tags() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit_info.wav");
+
+    let output = run_stdout(&input_path, &[]);
+    let comment = &output[..output.find("*/").unwrap()];
+    assert!(
+        comment.contains(
+            "* Sample rate: 8000 Hz, Channels: 1, Bits per sample: 8\n\
+            * Title: Test Tone\n\
+            * Artist: wav2c\n\
+            * Software: gen_wav\n"
+        ),
+        "{}",
+        comment
+    );
+    assert_eq!(array_values(&output).len(), 800);
+
+    let output = run_stdout(&input_path, &["--comment-fields", "tool,spec,repo"]);
+    assert!(!output.contains("Title:"));
+
+    let output = run_stdout(&input_path, &["--info"]);
+    assert!(output.contains("Title: Test Tone\n"), "{}", output);
+
+    // absent without an INFO chunk
+    let output = run_stdout(Path::new("tests/fixtures/mono_8bit_cue.wav"), &[]);
+    let comment = &output[..output.find("*/").unwrap()];
+    assert!(!comment.contains("Title:"));
+}
+
+#[test]
+fn test_lang_cpp() {
+    init();
+
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    for (input, args) in [
+        ("mono_8bit.wav", &[][..]),
+        ("stereo_16bit.wav", &["--group-stereo-as-struct-array"][..]),
+        ("mono_8bit_loop.wav", &["--mutable", "--align", "4"][..]),
+        (
+            "mono_8bit_cue.wav",
+            &[
+                "--asset-version",
+                "3",
+                "--emit-length-in-ms",
+                "--detect-pitch",
+                "--mark-onset",
+            ][..],
+        ),
+    ] {
+        let input_path = PathBuf::from(format!("{}/{}", FIXTURES_DIR, input));
+        let output_path = temp_dir.path().join("audio.hpp");
+        Command::cargo_bin(env!("CARGO_PKG_NAME"))
+            .unwrap()
+            .arg(&input_path)
+            .args(["-f", "--lang", "cpp", "--array-name", "audio", "--output"])
+            .arg(&output_path)
+            .args(args)
+            .assert()
+            .success();
+        let output = fs::read_to_string(&output_path).unwrap();
+        assert!(output.contains("namespace audio {\n"), "{}", output);
+        assert!(output.contains(" samples"));
+
+        // inline definitions so it links when included from more than one source
+        let main = "#include \"audio.hpp\"\n\
+            static_assert(audio::SAMPLE_NO > 0);\n\
+            int main() { return audio::samples.size() == 0; }\n";
+        let other =
+            "#include \"audio.hpp\"\nstd::size_t other() { return audio::samples.size(); }\n";
+        let main_path = temp_dir.path().join("main.cpp");
+        let other_path = temp_dir.path().join("other.cpp");
+        fs::write(&main_path, main).unwrap();
+        fs::write(&other_path, other).unwrap();
+        let binary_path = temp_dir.path().join("main.out");
+        Command::new("g++")
+            .arg("-std=c++17")
+            .arg("-o")
+            .arg(&binary_path)
+            .arg(&main_path)
+            .arg(&other_path)
+            .assert()
+            .success();
+        Command::new(&binary_path).assert().success();
+    }
+
+    // the defines of C are constants in the namespace
+    let output = run_stdout(
+        &PathBuf::from(format!("{}/mono_8bit_cue.wav", FIXTURES_DIR)),
+        &[
+            "--lang",
+            "cpp",
+            "--array-name",
+            "audio",
+            "--asset-version",
+            "3",
+            "--emit-length-in-ms",
+            "--detect-pitch",
+            "--mark-onset",
+        ],
+    );
+    assert!(
+        output.contains(
+            "inline constexpr std::uint32_t VERSION = 3;\n\
+            inline constexpr std::size_t CUE_COUNT = 3;\n\
+            inline constexpr std::size_t CUE_0 = 0;\n\
+            inline constexpr std::size_t CUE_1 = 2000;\n\
+            inline constexpr std::size_t CUE_ATTACK = CUE_1;\n"
+        ),
+        "{}",
+        output
+    );
+    assert!(output.contains("inline constexpr std::uint64_t DURATION_MS = 500;\n"));
+    assert!(output.contains("inline constexpr double PITCH_HZ = 440.0;\n"));
+    assert!(output.contains("inline constexpr std::size_t ONSET = 1;\n"));
+
+    // negative hex values would narrow in the initializer
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(format!("{}/mono_8bit.wav", FIXTURES_DIR))
+        .args(["--lang", "cpp", "--format", "base16"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_lang_inc() {
+    init();
+
+    let input_path = PathBuf::from(format!("{}/mono_8bit.wav", FIXTURES_DIR));
+    let temp_dir = tempfile::TempDir::new().unwrap();
+
+    // just the initializer, with no comment, defines or declaration
+    let output = run_stdout(&input_path, &["--lang", "inc"]);
+    assert!(output.starts_with("{\n\t 0, 7, 15,"), "{}", output);
+    assert!(output.ends_with(",\n}\n"));
+    assert!(!output.contains("/*"));
+    assert!(!output.contains("SAMPLE_NO"));
+    assert_eq!(
+        array_values(&output),
+        array_values(&run_stdout(&input_path, &["--no-comment"]))
+    );
+
+    let bare = run_stdout(&input_path, &["--lang", "inc", "--no-braces"]);
+    assert!(bare.starts_with("\t 0, 7, 15,"), "{}", bare);
+    let body = output.trim().strip_prefix('{').unwrap().strip_suffix('}');
+    assert_eq!(Some(bare.trim()), body.map(str::trim));
+
+    // included inside a declaration of the user's
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--lang", "inc", "--output-dir"])
+        .arg(temp_dir.path())
+        .assert()
+        .success();
+    let source_path = temp_dir.path().join("sound.c");
+    fs::write(
+        &source_path,
+        "const int8_t sound[] =\n#include \"mono_8bit.inc\"\n;\n\
+        const size_t sound_len = sizeof(sound) / sizeof(sound[0]);\n",
+    )
+    .unwrap();
+    compile_with_gcc(&source_path);
+
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .arg("--no-braces")
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_verify_compile() {
+    init();
+
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let temp_dir = tempfile::TempDir::new().unwrap();
+    let output_path = temp_dir.path().join("audio.c");
+    let convert = |compiler: &str, args: &[&str]| {
+        let mut cmd = Command::cargo_bin(env!("CARGO_PKG_NAME")).unwrap();
+        cmd.arg(&input_path)
+            .args(["-f", "--output"])
+            .arg(&output_path)
+            .args(args)
+            .env("CC", compiler)
+            .env("CXX", compiler);
+        cmd.assert()
+    };
+
+    convert("gcc", &["--verify"]).success();
+    assert!(output_path.exists());
+    convert("g++", &["--verify", "--lang", "cpp"]).success();
+    convert("gcc", &["--verify", "--split-files", "10000"]).success();
+    assert!(!temp_dir.path().join("audio_part0.o").exists());
+
+    // a failing compiler fails the conversion without writing the output
+    fs::remove_file(&output_path).unwrap();
+    let cmd = convert("false", &["--verify"]).failure();
+    let error = String::from_utf8(cmd.get_output().stderr.clone()).unwrap();
+    assert!(error.contains("'false' failed to compile"), "{}", error);
+    assert!(!output_path.exists());
+
+    // the step only runs when asked for
+    convert("false", &[]).success();
+    assert!(output_path.exists());
+}
+
+#[test]
+fn test_upscale() {
+    let input_path = PathBuf::from("tests/fixtures/mono_8bit.wav");
+    let source = array_values(&run_stdout(&input_path, &["--no-comment"]));
+
+    let output = run_stdout(&input_path, &["--no-comment", "--upscale", "16"]);
+    assert!(
+        output.contains("const int16_t mono_8bit[44100]"),
+        "{}",
+        output
+    );
+    let values = array_values(&output);
+    assert_eq!(values, source.iter().map(|v| v << 8).collect::<Vec<_>>());
+
+    // the low byte repeats the sample, so 127 would become 0x7f7f and zero stays zero
+    let output = run_stdout(&input_path, &["--upscale", "16", "--replicate-lsb"]);
+    let values = array_values(&output);
+    let expected: Vec<i64> = source.iter().map(|v| (v << 8) | (v & 0xff)).collect();
+    assert_eq!(values, expected);
+    assert_eq!(values[0], 0);
+    // not full scale, the peak of 126 becomes 0x7e7e
+    assert_eq!(values.iter().max(), Some(&0x7e7e));
+
+    // only up from the source depth
+    Command::cargo_bin(env!("CARGO_PKG_NAME"))
+        .unwrap()
+        .arg(&input_path)
+        .args(["--upscale", "8"])
+        .assert()
+        .failure();
+}
+
+#[test]
+fn test_no_default_features() {
+    // the library and binary build without the cli logging, in their own target directory
+    // so as not to invalidate the default build
+    Command::new(env!("CARGO"))
+        .current_dir(env!("CARGO_MANIFEST_DIR"))
+        .args(["build", "--quiet", "--no-default-features", "--target-dir"])
+        .arg(Path::new(env!("CARGO_TARGET_TMPDIR")).join("no-default-features"))
+        .assert()
+        .success();
+}